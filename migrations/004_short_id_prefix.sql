@@ -0,0 +1 @@
+ALTER TABLE projects ADD COLUMN short_id_prefix TEXT;
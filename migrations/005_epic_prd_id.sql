@@ -0,0 +1,3 @@
+ALTER TABLE epics ADD COLUMN prd_id TEXT REFERENCES prds(id) ON DELETE SET NULL;
+
+CREATE INDEX IF NOT EXISTS idx_epics_prd_id ON epics(prd_id);
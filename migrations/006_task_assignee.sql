@@ -0,0 +1,3 @@
+ALTER TABLE tasks ADD COLUMN assignee TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_tasks_assignee ON tasks(assignee);
@@ -0,0 +1,13 @@
+-- Append-only log of status transitions, used to reconstruct progress over
+-- time. Like `dependencies`, rows are polymorphic over epics and tasks and
+-- carry no foreign key; readers join against the live tables.
+CREATE TABLE IF NOT EXISTS status_history (
+    id          INTEGER PRIMARY KEY AUTOINCREMENT,
+    entity_type TEXT NOT NULL CHECK(entity_type IN ('epic', 'task')),
+    entity_id   TEXT NOT NULL,
+    from_status TEXT NOT NULL,
+    to_status   TEXT NOT NULL,
+    changed_at  TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_status_history_entity ON status_history(entity_type, entity_id);
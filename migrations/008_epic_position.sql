@@ -0,0 +1,12 @@
+-- Manual ordering of epics within a project, 0 first. New epics are inserted
+-- at 0, so until an epic is moved the order stays newest first.
+ALTER TABLE epics ADD COLUMN position INTEGER NOT NULL DEFAULT 0;
+
+UPDATE epics SET position = (
+    SELECT COUNT(*) FROM epics newer
+    WHERE newer.project_id = epics.project_id
+      AND (newer.created_at > epics.created_at
+           OR (newer.created_at = epics.created_at AND newer.id > epics.id))
+);
+
+CREATE INDEX IF NOT EXISTS idx_epics_project_position ON epics(project_id, position);
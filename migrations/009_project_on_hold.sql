@@ -0,0 +1,18 @@
+-- Allow the 'on_hold' project status. SQLite cannot change a CHECK
+-- constraint in place, so the table is rebuilt. Migrations run with foreign
+-- keys off, so dropping the old table leaves epics and PRDs alone.
+CREATE TABLE projects_new (
+    id              TEXT PRIMARY KEY,
+    name            TEXT NOT NULL,
+    description     TEXT NOT NULL DEFAULT '',
+    status          TEXT NOT NULL DEFAULT 'active' CHECK(status IN ('active', 'on_hold', 'archived')),
+    created_at      TEXT NOT NULL DEFAULT (datetime('now')),
+    updated_at      TEXT NOT NULL DEFAULT (datetime('now')),
+    short_id_prefix TEXT
+);
+
+INSERT INTO projects_new (id, name, description, status, created_at, updated_at, short_id_prefix)
+SELECT id, name, description, status, created_at, updated_at, short_id_prefix FROM projects;
+
+DROP TABLE projects;
+ALTER TABLE projects_new RENAME TO projects;
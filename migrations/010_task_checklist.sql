@@ -0,0 +1,11 @@
+-- Lightweight checklist items inside a task, listed by position (0 first).
+CREATE TABLE IF NOT EXISTS task_checklist (
+    id         TEXT PRIMARY KEY,
+    task_id    TEXT NOT NULL REFERENCES tasks(id) ON DELETE CASCADE,
+    text       TEXT NOT NULL,
+    done       INTEGER NOT NULL DEFAULT 0,
+    position   INTEGER NOT NULL,
+    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+);
+
+CREATE INDEX IF NOT EXISTS idx_task_checklist_task ON task_checklist(task_id, position);
@@ -0,0 +1,3 @@
+-- Archived epics and tasks stay in the database but drop out of default listings.
+ALTER TABLE epics ADD COLUMN archived INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE tasks ADD COLUMN archived INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,2 @@
+-- Why a transition happened, when the caller gave a reason (e.g. `reopen_task`).
+ALTER TABLE status_history ADD COLUMN reason TEXT;
@@ -0,0 +1,3 @@
+-- Who last changed a task or epic, when the caller passed an `actor`.
+ALTER TABLE tasks ADD COLUMN updated_by TEXT;
+ALTER TABLE epics ADD COLUMN updated_by TEXT;
@@ -0,0 +1,12 @@
+-- Reusable task structures applied to epics. The tasks are stored as one
+-- JSON array (title, description, depends_on) since a template is always
+-- read and applied whole.
+CREATE TABLE IF NOT EXISTS task_templates (
+    id          TEXT PRIMARY KEY,
+    project_id  TEXT NOT NULL REFERENCES projects(id) ON DELETE CASCADE,
+    name        TEXT NOT NULL,
+    description TEXT NOT NULL DEFAULT '',
+    tasks       TEXT NOT NULL,
+    created_at  TEXT NOT NULL DEFAULT (datetime('now')),
+    UNIQUE (project_id, name)
+);
@@ -0,0 +1,513 @@
+//! `blueprint export`: a project's epics, tasks and dependencies as JSON,
+//! Graphviz DOT or Mermaid, or its tasks as CSV, written to a file or stdout.
+//! With `--layout`, JSON and DOT also carry the graph layers the TUI draws.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::io::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::db::Database;
+use crate::db::dependency::get_all_dependencies;
+use crate::db::epic::list_epics;
+use crate::db::project::resolve_project_fuzzy;
+use crate::db::task::list_tasks;
+use crate::models::{
+    BlueTask, Dependency, DependencyType, Epic, EpicListFilter, ItemStatus, Project, TaskListFilter,
+};
+use crate::graph::{DagLayout, Edge, Node};
+
+/// Output path meaning "write to stdout".
+pub const STDOUT: &str = "-";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    Json,
+    Dot,
+    Mermaid,
+    /// One row per task with the [`CSV_COLUMNS`]; epics and dependencies
+    /// are left out.
+    Csv,
+}
+
+/// Columns of the CSV export, in order. `epic` is the parent epic's title.
+/// Spreadsheets refer to these by name, so only ever append to the list.
+pub const CSV_COLUMNS: [&str; 5] = ["short_id", "title", "status", "epic", "assignee"];
+
+/// Everything exported for one project. Epics and tasks are in creation order.
+#[derive(Debug, Serialize)]
+pub struct ProjectExport {
+    pub project: Project,
+    pub epics: Vec<EpicExport>,
+    /// Dependencies between this project's epics and tasks.
+    pub dependencies: Vec<Dependency>,
+    /// Layers computed by [`add_layout`]; absent unless requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub layout: Option<ExportLayout>,
+}
+
+/// Node keys grouped by the layer they get in the TUI's dependency graph:
+/// band `i` holds the nodes drawn on row `i`, and unconnected nodes form
+/// the last band, as on screen.
+#[derive(Debug, Serialize)]
+pub struct ExportLayout {
+    pub epics: Vec<Vec<String>>,
+    /// Task layers per epic, keyed by the epic's node key. Each epic's
+    /// tasks are laid out on their own, like the TUI's task graph.
+    pub tasks: BTreeMap<String, Vec<Vec<String>>>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EpicExport {
+    #[serde(flatten)]
+    pub epic: Epic,
+    pub tasks: Vec<BlueTask>,
+}
+
+/// Sort key for creation order. `created_at` only has second resolution, so
+/// ties fall back to the number at the end of the short ID (`E3`, `E1-T4`).
+fn creation_key(created_at: &str, short_id: &Option<String>) -> (String, u64) {
+    let number = short_id.as_deref().map_or(0, |s| {
+        let stem = s.trim_end_matches(|c: char| c.is_ascii_digit());
+        s[stem.len()..].parse().unwrap_or(0)
+    });
+    (created_at.to_string(), number)
+}
+
+pub fn load_project_export(db: &Database, project: Project) -> Result<ProjectExport> {
+    let mut epics = Vec::new();
+    let mut listed = list_epics(
+        db,
+        &EpicListFilter {
+            project_id: Some(&project.id),
+            include_archived: true,
+            ..Default::default()
+        },
+    )?;
+    listed.sort_by_key(|e| creation_key(&e.created_at, &e.short_id));
+    for epic in listed {
+        let mut tasks = list_tasks(
+            db,
+            &TaskListFilter {
+                epic_id: Some(&epic.id),
+                include_archived: true,
+                ..Default::default()
+            },
+        )?;
+        tasks.sort_by_key(|t| creation_key(&t.created_at, &t.short_id));
+        epics.push(EpicExport { epic, tasks });
+    }
+
+    let ids: HashSet<&str> = epics
+        .iter()
+        .flat_map(|e| {
+            std::iter::once(e.epic.id.as_str()).chain(e.tasks.iter().map(|t| t.id.as_str()))
+        })
+        .collect();
+    let dependencies = get_all_dependencies(db)?
+        .into_iter()
+        .filter(|d| ids.contains(d.blocker_id.as_str()) && ids.contains(d.blocked_id.as_str()))
+        .collect();
+
+    Ok(ProjectExport { project, epics, dependencies, layout: None })
+}
+
+/// Lay out `items` (ID, node key, status) with the edges of `dep_type`
+/// between them and return the bands of node keys.
+fn layer_bands(
+    items: Vec<(&str, &str, ItemStatus)>,
+    dependencies: &[Dependency],
+    dep_type: &DependencyType,
+) -> Vec<Vec<String>> {
+    let keys: HashMap<&str, &str> = items.iter().map(|(id, key, _)| (*id, *key)).collect();
+    let nodes = items
+        .iter()
+        .map(|(_, key, status)| Node {
+            id: key.to_string(),
+            label: key.to_string(),
+            status: status.clone(),
+            layer: None,
+            x_position: 0,
+        })
+        .collect();
+    let edges = dependencies
+        .iter()
+        .filter(|d| &d.blocker_type == dep_type && &d.blocked_type == dep_type)
+        .filter_map(|d| {
+            Some(Edge {
+                from: keys.get(d.blocker_id.as_str())?.to_string(),
+                to: keys.get(d.blocked_id.as_str())?.to_string(),
+            })
+        })
+        .collect();
+    let layout = DagLayout::new(nodes, edges);
+    let mut bands = layout.layers;
+    if !layout.orphans.is_empty() {
+        bands.push(layout.orphans);
+    }
+    bands
+}
+
+/// Compute the layers the TUI would draw and attach them to `export`.
+pub fn add_layout(export: &mut ProjectExport) {
+    let epics = export
+        .epics
+        .iter()
+        .map(|e| {
+            let key = node_key(&e.epic.short_id, &e.epic.id);
+            (e.epic.id.as_str(), key, e.epic.status.clone())
+        })
+        .collect();
+    let epic_bands = layer_bands(epics, &export.dependencies, &DependencyType::Epic);
+
+    let mut tasks = BTreeMap::new();
+    for e in &export.epics {
+        let items = e
+            .tasks
+            .iter()
+            .map(|t| (t.id.as_str(), node_key(&t.short_id, &t.id), t.status.clone()))
+            .collect();
+        let bands = layer_bands(items, &export.dependencies, &DependencyType::Task);
+        if !bands.is_empty() {
+            tasks.insert(node_key(&e.epic.short_id, &e.epic.id).to_string(), bands);
+        }
+    }
+    export.layout = Some(ExportLayout { epics: epic_bands, tasks });
+}
+
+/// `{ rank=same; ... }` for every band, so Graphviz keeps the same rows.
+fn dot_rank_groups(out: &mut String, indent: &str, bands: &[Vec<String>]) {
+    for band in bands {
+        let members: Vec<String> = band.iter().map(|k| format!("\"{k}\";")).collect();
+        out.push_str(&format!("{indent}{{ rank=same; {} }}\n", members.join(" ")));
+    }
+}
+
+pub fn render(export: &ProjectExport, format: ExportFormat) -> Result<String> {
+    match format {
+        ExportFormat::Json => {
+            serde_json::to_string_pretty(export).context("failed to serialize project export")
+        }
+        ExportFormat::Dot => Ok(render_dot(export)),
+        ExportFormat::Mermaid => Ok(render_mermaid(export)),
+        ExportFormat::Csv => Ok(render_csv(export)),
+    }
+}
+
+/// Short ID when assigned, otherwise the ULID.
+fn node_key<'a>(short_id: &'a Option<String>, id: &'a str) -> &'a str {
+    short_id.as_deref().unwrap_or(id)
+}
+
+/// Map every epic and task ID to its node key, for drawing edges.
+fn node_keys(export: &ProjectExport) -> HashMap<&str, &str> {
+    let mut keys = HashMap::new();
+    for e in &export.epics {
+        keys.insert(e.epic.id.as_str(), node_key(&e.epic.short_id, &e.epic.id));
+        for t in &e.tasks {
+            keys.insert(t.id.as_str(), node_key(&t.short_id, &t.id));
+        }
+    }
+    keys
+}
+
+fn dot_escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn render_dot(export: &ProjectExport) -> String {
+    let mut out = String::new();
+    out.push_str("digraph blueprint {\n");
+    out.push_str(&format!("  label=\"{}\";\n", dot_escape(&export.project.name)));
+    out.push_str("  node [shape=box];\n");
+
+    for (i, e) in export.epics.iter().enumerate() {
+        let key = node_key(&e.epic.short_id, &e.epic.id);
+        let label = dot_escape(&format!("{key} {}", e.epic.title));
+        out.push_str(&format!(
+            "  \"{key}\" [shape=folder, label=\"{label}\\n[{}]\"];\n",
+            e.epic.status
+        ));
+        if e.tasks.is_empty() {
+            continue;
+        }
+        out.push_str(&format!("  subgraph cluster_{i} {{\n    label=\"{label}\";\n"));
+        for t in &e.tasks {
+            let task_key = node_key(&t.short_id, &t.id);
+            out.push_str(&format!(
+                "    \"{task_key}\" [label=\"{}\\n[{}]\"];\n",
+                dot_escape(&format!("{task_key} {}", t.title)),
+                t.status
+            ));
+        }
+        if let Some(bands) = export.layout.as_ref().and_then(|l| l.tasks.get(key)) {
+            dot_rank_groups(&mut out, "    ", bands);
+        }
+        out.push_str("  }\n");
+    }
+    if let Some(layout) = &export.layout {
+        dot_rank_groups(&mut out, "  ", &layout.epics);
+    }
+
+    let keys = node_keys(export);
+    for d in &export.dependencies {
+        out.push_str(&format!(
+            "  \"{}\" -> \"{}\";\n",
+            keys[d.blocker_id.as_str()],
+            keys[d.blocked_id.as_str()]
+        ));
+    }
+    out.push_str("}\n");
+    out
+}
+
+/// Mermaid node IDs may not contain `-`, so `E1-T2` becomes `E1_T2`.
+fn mermaid_id(key: &str) -> String {
+    key.replace('-', "_")
+}
+
+fn mermaid_escape(s: &str) -> String {
+    s.replace('"', "#quot;")
+}
+
+fn render_mermaid(export: &ProjectExport) -> String {
+    let mut out = String::from("flowchart TD\n");
+    for e in &export.epics {
+        let key = node_key(&e.epic.short_id, &e.epic.id);
+        out.push_str(&format!(
+            "  subgraph {}[\"{} [{}]\"]\n",
+            mermaid_id(key),
+            mermaid_escape(&format!("{key} {}", e.epic.title)),
+            e.epic.status
+        ));
+        for t in &e.tasks {
+            let task_key = node_key(&t.short_id, &t.id);
+            out.push_str(&format!(
+                "    {}[\"{} [{}]\"]\n",
+                mermaid_id(task_key),
+                mermaid_escape(&format!("{task_key} {}", t.title)),
+                t.status
+            ));
+        }
+        out.push_str("  end\n");
+    }
+
+    let keys = node_keys(export);
+    for d in &export.dependencies {
+        out.push_str(&format!(
+            "  {} --> {}\n",
+            mermaid_id(keys[d.blocker_id.as_str()]),
+            mermaid_id(keys[d.blocked_id.as_str()])
+        ));
+    }
+    out
+}
+
+/// Quote a CSV field when it holds a comma, quote or line break, doubling
+/// any quotes inside (RFC 4180).
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn render_csv(export: &ProjectExport) -> String {
+    let mut out = CSV_COLUMNS.join(",");
+    out.push('\n');
+    for e in &export.epics {
+        for t in &e.tasks {
+            let row = [
+                t.short_id.as_deref().unwrap_or(&t.id),
+                &t.title,
+                t.status.as_str(),
+                &e.epic.title,
+                t.assignee.as_deref().unwrap_or(""),
+            ];
+            let fields: Vec<String> = row.iter().map(|f| csv_field(f)).collect();
+            out.push_str(&fields.join(","));
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// Write `content` to `output`, or to `stdout` when `output` is [`STDOUT`].
+pub fn write_output(content: &str, output: &str, stdout: &mut impl Write) -> Result<()> {
+    if output == STDOUT {
+        stdout.write_all(content.as_bytes()).context("failed to write export to stdout")?;
+        return stdout.flush().context("failed to write export to stdout");
+    }
+    std::fs::write(Path::new(output), content)
+        .with_context(|| format!("failed to write export to {output}"))
+}
+
+/// Entry point for `blueprint export`.
+pub fn run(
+    db: &Database,
+    project: &str,
+    format: ExportFormat,
+    output: &str,
+    layout: bool,
+) -> Result<()> {
+    let project = resolve_project_fuzzy(db, project)?;
+    let mut export = load_project_export(db, project)?;
+    if layout {
+        add_layout(&mut export);
+    }
+    let content = render(&export, format)?;
+    write_output(&content, output, &mut std::io::stdout().lock())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::seed::{seed_demo_data, DEMO_PROJECT_NAME};
+    use tempfile::TempDir;
+
+    fn seeded_db() -> (Database, TempDir) {
+        let dir = TempDir::new().unwrap();
+        let db = Database::open(&dir.path().join("test.db")).unwrap();
+        db.migrate().unwrap();
+        seed_demo_data(&db).unwrap();
+        (db, dir)
+    }
+
+    fn demo_export(db: &Database) -> ProjectExport {
+        let project = resolve_project_fuzzy(db, DEMO_PROJECT_NAME).unwrap();
+        load_project_export(db, project).unwrap()
+    }
+
+    #[test]
+    fn json_export_to_file_round_trips() {
+        let (db, dir) = seeded_db();
+        let project = resolve_project_fuzzy(&db, DEMO_PROJECT_NAME).unwrap();
+        let path = dir.path().join("out.json");
+
+        run(&db, &project.id, ExportFormat::Json, path.to_str().unwrap(), false).unwrap();
+
+        let written: serde_json::Value =
+            serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(written["project"]["id"], project.id);
+        let epics = written["epics"].as_array().unwrap();
+        let titles: Vec<&str> = epics.iter().map(|e| e["title"].as_str().unwrap()).collect();
+        assert_eq!(titles, ["Foundation", "Authentication", "Dashboard", "Launch"]);
+        assert_eq!(epics[1]["tasks"].as_array().unwrap().len(), 4);
+        assert_eq!(epics[1]["tasks"][0]["title"], "Design auth schema");
+        assert_eq!(written["dependencies"].as_array().unwrap().len(), 7);
+    }
+
+    #[test]
+    fn csv_has_header_and_one_row_per_task() {
+        let (db, _dir) = seeded_db();
+        let mut export = demo_export(&db);
+        let task = &mut export.epics[1].tasks[0];
+        task.title = "Login, \"remember me\" box".to_string();
+        task.assignee = Some("ana".to_string());
+
+        let csv = render(&export, ExportFormat::Csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "short_id,title,status,epic,assignee");
+        let task_count: usize = export.epics.iter().map(|e| e.tasks.len()).sum();
+        assert_eq!(lines.len(), task_count + 1);
+        assert!(
+            lines.contains(&"E2-T1,\"Login, \"\"remember me\"\" box\",done,Authentication,ana"),
+            "{csv}"
+        );
+    }
+
+    #[test]
+    fn csv_field_quotes_only_when_needed() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+        assert_eq!(csv_field("two\nlines"), "\"two\nlines\"");
+    }
+
+    #[test]
+    fn dot_and_mermaid_draw_nodes_and_edges() {
+        let (db, _dir) = seeded_db();
+        let export = demo_export(&db);
+
+        let dot = render(&export, ExportFormat::Dot).unwrap();
+        assert!(dot.starts_with("digraph blueprint {\n"));
+        assert!(dot.contains("\"E1\" [shape=folder, label=\"E1 Foundation\\n[done]\"];"));
+        assert!(dot.contains("\"E1\" -> \"E2\";"));
+        assert_eq!(dot.matches(" -> ").count(), 7);
+
+        let mermaid = render(&export, ExportFormat::Mermaid).unwrap();
+        assert!(mermaid.starts_with("flowchart TD\n"));
+        assert!(mermaid.contains("  subgraph E2[\"E2 Authentication [in_progress]\"]\n"));
+        assert!(mermaid.contains("    E2_T1[\"E2-T1 Design auth schema [done]\"]\n"));
+        assert!(mermaid.contains("  E1 --> E2\n"));
+        assert_eq!(mermaid.matches(" --> ").count(), 7);
+    }
+
+    #[test]
+    fn layout_groups_nodes_by_dag_layer() {
+        let (db, _dir) = seeded_db();
+        let mut export = demo_export(&db);
+        let plain = render(&export, ExportFormat::Json).unwrap();
+        assert!(!plain.contains("\"layout\""));
+
+        add_layout(&mut export);
+        let layout = export.layout.as_ref().unwrap();
+        // The demo epics form one chain, so each sits on its own layer.
+        assert_eq!(layout.epics, vec![vec!["E1"], vec!["E2"], vec!["E3"], vec!["E4"]]);
+
+        // Within each epic a task's blockers sit on earlier layers.
+        let keys = node_keys(&export);
+        let band_of = |key: &str| {
+            layout
+                .tasks
+                .values()
+                .find_map(|bands| bands.iter().position(|b| b.iter().any(|k| k == key)))
+                .unwrap()
+        };
+        for d in &export.dependencies {
+            let (from, to) = (keys[d.blocker_id.as_str()], keys[d.blocked_id.as_str()]);
+            if d.blocker_type == DependencyType::Task && from[..2] == to[..2] {
+                assert!(band_of(from) < band_of(to), "{from} -> {to}");
+            }
+        }
+
+        let json: serde_json::Value =
+            serde_json::from_str(&render(&export, ExportFormat::Json).unwrap()).unwrap();
+        assert_eq!(json["layout"]["epics"][1], serde_json::json!(["E2"]));
+
+        let dot = render(&export, ExportFormat::Dot).unwrap();
+        assert!(dot.contains("\n  { rank=same; \"E1\"; }\n"), "{dot}");
+        let bands = layout.epics.len() + layout.tasks.values().map(Vec::len).sum::<usize>();
+        assert_eq!(dot.matches("rank=same").count(), bands);
+    }
+
+    #[test]
+    fn dash_writes_to_stdout() {
+        let (db, dir) = seeded_db();
+        let content = render(&demo_export(&db), ExportFormat::Mermaid).unwrap();
+
+        let mut stdout = Vec::new();
+        write_output(&content, STDOUT, &mut stdout).unwrap();
+        assert_eq!(String::from_utf8(stdout).unwrap(), content);
+        assert!(!dir.path().join(STDOUT).exists());
+    }
+
+    #[test]
+    fn unwritable_path_names_the_path() {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("missing").join("out.dot");
+        let err = write_output("x", path.to_str().unwrap(), &mut Vec::new()).unwrap_err();
+        assert!(format!("{err}").contains(path.to_str().unwrap()), "{err}");
+    }
+
+    #[test]
+    fn unknown_project_is_an_error() {
+        let (db, _dir) = seeded_db();
+        let err = resolve_project_fuzzy(&db, "nope").unwrap_err();
+        assert!(format!("{err}").contains("nope"));
+    }
+}
@@ -0,0 +1,323 @@
+use anyhow::{Context, Result};
+use rusqlite::{OptionalExtension, Row, Transaction};
+use serde::Serialize;
+
+use crate::db::Database;
+use crate::db::task::{get_task, insert_task};
+use crate::models::{BlueTask, ChecklistItem, CreateTaskInput};
+
+const SELECT_COLUMNS: &str = "id, task_id, text, done, position, created_at";
+
+fn row_to_item(row: &Row) -> rusqlite::Result<ChecklistItem> {
+    Ok(ChecklistItem {
+        id: row.get("id")?,
+        task_id: row.get("task_id")?,
+        text: row.get("text")?,
+        done: row.get("done")?,
+        position: row.get("position")?,
+        created_at: row.get("created_at")?,
+    })
+}
+
+/// Checklist edits change what the task shows, so they count as task
+/// updates for change detection and `updated_by`.
+fn touch_task(tx: &Transaction, task_id: &str, actor: Option<&str>) -> Result<()> {
+    tx.execute(
+        "UPDATE tasks SET updated_by = ?2, updated_at = datetime('now') WHERE id = ?1",
+        rusqlite::params![task_id, actor],
+    )
+    .context("failed to touch task")?;
+    Ok(())
+}
+
+/// Append an unchecked item to the end of a task's checklist.
+pub fn add_checklist_item(
+    db: &Database,
+    task_id: &str,
+    text: &str,
+    actor: Option<&str>,
+) -> Result<ChecklistItem> {
+    let id = ulid::Ulid::new().to_string();
+    db.write_transaction("add checklist item", |tx| {
+        let sql = format!(
+            "INSERT INTO task_checklist (id, task_id, text, position) \
+             SELECT ?1, ?2, ?3, COALESCE(MAX(position) + 1, 0) \
+             FROM task_checklist WHERE task_id = ?2 \
+             RETURNING {SELECT_COLUMNS}"
+        );
+        let item = tx
+            .query_row(&sql, [&id, task_id, text], row_to_item)
+            .context("failed to insert checklist item")?;
+        touch_task(tx, task_id, actor)?;
+        Ok(item)
+    })
+}
+
+/// Set an item's `done` flag, or flip it when `done` is `None`. Returns
+/// `None` when no item has that ID.
+pub fn toggle_checklist_item(
+    db: &Database,
+    id: &str,
+    done: Option<bool>,
+    actor: Option<&str>,
+) -> Result<Option<ChecklistItem>> {
+    db.write_transaction("toggle checklist item", |tx| {
+        let sql = format!(
+            "UPDATE task_checklist SET done = COALESCE(?2, NOT done) WHERE id = ?1 \
+             RETURNING {SELECT_COLUMNS}"
+        );
+        let item = tx
+            .query_row(&sql, rusqlite::params![id, done], row_to_item)
+            .optional()
+            .context("failed to update checklist item")?;
+        if let Some(item) = &item {
+            touch_task(tx, &item.task_id, actor)?;
+        }
+        Ok(item)
+    })
+}
+
+pub fn get_checklist_item(db: &Database, id: &str) -> Result<Option<ChecklistItem>> {
+    let sql = format!("SELECT {SELECT_COLUMNS} FROM task_checklist WHERE id = ?1");
+    db.conn()
+        .query_row(&sql, [id], row_to_item)
+        .optional()
+        .context("failed to query checklist item")
+}
+
+/// What [`promote_checklist_item`] does with the item once its task exists.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum AfterPromotion {
+    #[default]
+    Keep,
+    Check,
+    Delete,
+}
+
+/// Outcome of [`promote_checklist_item`].
+#[derive(Debug, Serialize)]
+pub struct Promotion {
+    /// The task created from the item.
+    pub task: BlueTask,
+    /// Task whose checklist held the item.
+    pub source_task_id: String,
+    /// The item as it was left; `None` once deleted.
+    pub item: Option<ChecklistItem>,
+}
+
+/// Turn a checklist item into a task in the same epic as the task holding
+/// it, titled with the item's text, then keep, check or delete the item,
+/// all in one transaction. A `description` of `None` notes where the task
+/// came from. Returns `None` when no item has that ID.
+pub fn promote_checklist_item(
+    db: &Database,
+    id: &str,
+    description: Option<&str>,
+    after: AfterPromotion,
+    actor: Option<&str>,
+) -> Result<Option<Promotion>> {
+    let task_id = ulid::Ulid::new().to_string();
+    let promoted = db.write_transaction("checklist item promotion", |tx| {
+        let sql = format!("SELECT {SELECT_COLUMNS} FROM task_checklist WHERE id = ?1");
+        let Some(item) = tx
+            .query_row(&sql, [id], row_to_item)
+            .optional()
+            .context("failed to query checklist item")?
+        else {
+            return Ok(None);
+        };
+        let (epic_id, source_short_id): (String, Option<String>) = tx
+            .query_row("SELECT epic_id, short_id FROM tasks WHERE id = ?1", [&item.task_id], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .context("failed to fetch the checklist's task")?;
+        let description = description.map_or_else(
+            || {
+                let source = source_short_id.as_deref().unwrap_or(&item.task_id);
+                format!("Promoted from the checklist of {source}.")
+            },
+            str::to_string,
+        );
+        let input = CreateTaskInput {
+            epic_id,
+            title: item.text.clone(),
+            description,
+            session_id: None,
+            assignee: None,
+        };
+        insert_task(tx, db, &task_id, &input, actor)?;
+
+        let left = match after {
+            AfterPromotion::Keep => Some(item.clone()),
+            AfterPromotion::Check => {
+                tx.execute("UPDATE task_checklist SET done = 1 WHERE id = ?1", [id])
+                    .context("failed to check checklist item")?;
+                Some(ChecklistItem { done: true, ..item.clone() })
+            }
+            AfterPromotion::Delete => {
+                tx.execute("DELETE FROM task_checklist WHERE id = ?1", [id])
+                    .context("failed to delete checklist item")?;
+                None
+            }
+        };
+        if after != AfterPromotion::Keep {
+            touch_task(tx, &item.task_id, actor)?;
+        }
+        Ok(Some((item.task_id, left)))
+    })?;
+
+    let Some((source_task_id, item)) = promoted else {
+        return Ok(None);
+    };
+    let task = get_task(db, &task_id)?.context("task not found after promotion")?;
+    Ok(Some(Promotion { task, source_task_id, item }))
+}
+
+/// A task's checklist in position order.
+pub fn list_checklist_items(db: &Database, task_id: &str) -> Result<Vec<ChecklistItem>> {
+    let sql = format!(
+        "SELECT {SELECT_COLUMNS} FROM task_checklist WHERE task_id = ?1 ORDER BY position"
+    );
+    let conn = db.conn();
+    let mut stmt = conn.prepare(&sql)?;
+    let rows = stmt.query_map([task_id], row_to_item)?;
+
+    rows.collect::<rusqlite::Result<Vec<_>>>()
+        .context("failed to list checklist items")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::epic::create_epic;
+    use crate::db::project::create_project;
+    use crate::db::task::{create_task, delete_task, get_task};
+    use crate::models::{
+        checklist_progress, CreateEpicInput, CreateProjectInput, CreateTaskInput,
+    };
+    use tempfile::TempDir;
+
+    fn open_temp_db() -> (Database, TempDir) {
+        let dir = TempDir::new().unwrap();
+        let db = Database::open(&dir.path().join("test.db")).unwrap();
+        db.migrate().unwrap();
+        (db, dir)
+    }
+
+    fn make_task(db: &Database) -> String {
+        let project = create_project(
+            db,
+            CreateProjectInput {
+                name: "P".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let epic = create_epic(
+            db,
+            CreateEpicInput {
+                project_id: project.id,
+                title: "E".to_string(),
+                description: String::new(),
+                prd_id: None,
+            },
+            None,
+        )
+        .unwrap();
+        create_task(
+            db,
+            CreateTaskInput {
+                epic_id: epic.id,
+                title: "T".to_string(),
+                description: String::new(),
+                session_id: None,
+                assignee: None,
+            },
+        )
+        .unwrap()
+        .id
+    }
+
+    #[test]
+    fn test_items_are_listed_in_the_order_added() {
+        let (db, _dir) = open_temp_db();
+        let task_id = make_task(&db);
+        let other_task = make_task(&db);
+        for text in ["Write docs", "Add tests", "Ship"] {
+            add_checklist_item(&db, &task_id, text, None).unwrap();
+        }
+        add_checklist_item(&db, &other_task, "Elsewhere", None).unwrap();
+
+        let items = list_checklist_items(&db, &task_id).unwrap();
+        let texts: Vec<&str> = items.iter().map(|i| i.text.as_str()).collect();
+        assert_eq!(texts, ["Write docs", "Add tests", "Ship"]);
+        assert_eq!(items.iter().map(|i| i.position).collect::<Vec<_>>(), [0, 1, 2]);
+        assert!(items.iter().all(|i| !i.done));
+        assert_eq!(list_checklist_items(&db, &other_task).unwrap()[0].position, 0);
+    }
+
+    #[test]
+    fn test_toggle_flips_or_sets_done_and_updates_progress() {
+        let (db, _dir) = open_temp_db();
+        let task_id = make_task(&db);
+        let first = add_checklist_item(&db, &task_id, "a", None).unwrap();
+        let second = add_checklist_item(&db, &task_id, "b", None).unwrap();
+        add_checklist_item(&db, &task_id, "c", None).unwrap();
+
+        assert!(toggle_checklist_item(&db, &first.id, None, None).unwrap().unwrap().done);
+        assert!(toggle_checklist_item(&db, &second.id, Some(true), None).unwrap().unwrap().done);
+        // Setting the same value again leaves it alone.
+        assert!(toggle_checklist_item(&db, &second.id, Some(true), None).unwrap().unwrap().done);
+        assert_eq!(checklist_progress(&list_checklist_items(&db, &task_id).unwrap()), (2, 3));
+
+        assert!(!toggle_checklist_item(&db, &first.id, None, None).unwrap().unwrap().done);
+        assert_eq!(checklist_progress(&list_checklist_items(&db, &task_id).unwrap()), (1, 3));
+
+        assert!(toggle_checklist_item(&db, "missing", None, None).unwrap().is_none());
+    }
+
+    #[test]
+    fn test_checklist_changes_touch_the_task_and_go_with_it() {
+        let (db, _dir) = open_temp_db();
+        let task_id = make_task(&db);
+        let stale = "2000-01-01 00:00:00";
+        let backdate = || {
+            db.conn()
+                .execute("UPDATE tasks SET updated_at = ?1 WHERE id = ?2", [stale, &task_id])
+                .unwrap();
+        };
+
+        backdate();
+        let item = add_checklist_item(&db, &task_id, "a", None).unwrap();
+        assert_ne!(get_task(&db, &task_id).unwrap().unwrap().updated_at, stale);
+        backdate();
+        toggle_checklist_item(&db, &item.id, None, None).unwrap();
+        assert_ne!(get_task(&db, &task_id).unwrap().unwrap().updated_at, stale);
+
+        delete_task(&db, &task_id).unwrap();
+        assert!(list_checklist_items(&db, &task_id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_promoted_item_becomes_the_next_task_of_the_epic() {
+        let (db, _dir) = open_temp_db();
+        let task_id = make_task(&db);
+        let item = add_checklist_item(&db, &task_id, "Split the parser", None).unwrap();
+        let source = get_task(&db, &task_id).unwrap().unwrap();
+
+        let promotion = promote_checklist_item(&db, &item.id, None, AfterPromotion::Delete, None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(promotion.task.title, "Split the parser");
+        assert_eq!(promotion.task.epic_id, source.epic_id);
+        assert_eq!(promotion.task.short_id.as_deref(), Some("E1-T2"));
+        assert_eq!(promotion.task.description, "Promoted from the checklist of E1-T1.");
+        assert_eq!(promotion.source_task_id, task_id);
+        assert!(promotion.item.is_none());
+        assert!(list_checklist_items(&db, &task_id).unwrap().is_empty());
+
+        let missing =
+            promote_checklist_item(&db, &item.id, None, AfterPromotion::Keep, None).unwrap();
+        assert!(missing.is_none());
+    }
+}
@@ -1,8 +1,11 @@
 use anyhow::{Context, Result};
-use rusqlite::Row;
+use rusqlite::{Connection, Row};
 
 use crate::db::Database;
-use crate::models::{AddDependencyInput, Dependency, DependencyType};
+use crate::models::{
+    AddDependencyInput, Dependency, DependencyDirection, DependencyItem, DependencyQuery,
+    DependencyType,
+};
 
 const SELECT_COLUMNS: &str = "id, blocker_type, blocker_id, blocked_type, blocked_id";
 
@@ -45,7 +48,35 @@ fn validate_item_exists(db: &Database, item_type: &DependencyType, item_id: &str
     Ok(())
 }
 
-pub fn add_dependency(db: &Database, input: AddDependencyInput) -> Result<Dependency> {
+/// Bump `updated_at` on both ends of a dependency that was added or removed,
+/// so the change moves the watermark the TUI polls for, and record `actor`
+/// as their `updated_by`.
+fn touch_items(
+    conn: &Connection,
+    items: &[(&DependencyType, &str)],
+    actor: Option<&str>,
+) -> Result<()> {
+    for (item_type, item_id) in items {
+        let table = match item_type {
+            DependencyType::Epic => "epics",
+            DependencyType::Task => "tasks",
+        };
+        conn.execute(
+            &format!(
+                "UPDATE {table} SET updated_by = ?2, updated_at = datetime('now') WHERE id = ?1"
+            ),
+            rusqlite::params![item_id, actor],
+        )
+        .with_context(|| format!("failed to touch {item_type} {item_id}"))?;
+    }
+    Ok(())
+}
+
+pub fn add_dependency(
+    db: &Database,
+    input: AddDependencyInput,
+    actor: Option<&str>,
+) -> Result<Dependency> {
     if input.blocker_type == input.blocked_type && input.blocker_id == input.blocked_id {
         anyhow::bail!("cannot create self-referencing dependency");
     }
@@ -53,7 +84,23 @@ pub fn add_dependency(db: &Database, input: AddDependencyInput) -> Result<Depend
     validate_item_exists(db, &input.blocker_type, &input.blocker_id)?;
     validate_item_exists(db, &input.blocked_type, &input.blocked_id)?;
 
-    if let Err(e) = db.conn().execute(
+    let id = db.write_transaction("adding dependency", |tx| insert_dependency(tx, &input, actor))?;
+
+    db.conn()
+        .prepare(&format!("SELECT {SELECT_COLUMNS} FROM dependencies WHERE id = ?1"))?
+        .query_row([id], row_to_dependency)
+        .context("dependency not found after insert")
+}
+
+/// Insert a dependency inside an open transaction and touch both ends,
+/// returning the new row id. The body of [`add_dependency`], which also
+/// checks that both items exist.
+pub(crate) fn insert_dependency(
+    tx: &Connection,
+    input: &AddDependencyInput,
+    actor: Option<&str>,
+) -> Result<i64> {
+    if let Err(e) = tx.execute(
         "INSERT INTO dependencies (blocker_type, blocker_id, blocked_type, blocked_id) VALUES (?1, ?2, ?3, ?4)",
         [
             input.blocker_type.as_str(),
@@ -71,12 +118,16 @@ pub fn add_dependency(db: &Database, input: AddDependencyInput) -> Result<Depend
             _ => Err(e).context("failed to insert dependency"),
         };
     }
-
-    let id = db.conn().last_insert_rowid();
-    db.conn()
-        .prepare(&format!("SELECT {SELECT_COLUMNS} FROM dependencies WHERE id = ?1"))?
-        .query_row([id], row_to_dependency)
-        .context("dependency not found after insert")
+    let id = tx.last_insert_rowid();
+    touch_items(
+        tx,
+        &[
+            (&input.blocker_type, &input.blocker_id),
+            (&input.blocked_type, &input.blocked_id),
+        ],
+        actor,
+    )?;
+    Ok(id)
 }
 
 pub fn remove_dependency(
@@ -85,45 +136,241 @@ pub fn remove_dependency(
     blocker_id: &str,
     blocked_type: &DependencyType,
     blocked_id: &str,
+    actor: Option<&str>,
 ) -> Result<bool> {
-    let rows_affected = db
-        .conn()
-        .execute(
-            "DELETE FROM dependencies WHERE blocker_type = ?1 AND blocker_id = ?2 AND blocked_type = ?3 AND blocked_id = ?4",
-            [blocker_type.as_str(), blocker_id, blocked_type.as_str(), blocked_id],
-        )
-        .context("failed to delete dependency")?;
+    let rows_affected = db.write_transaction("removing dependency", |tx| {
+        let rows = tx
+            .execute(
+                "DELETE FROM dependencies WHERE blocker_type = ?1 AND blocker_id = ?2 AND blocked_type = ?3 AND blocked_id = ?4",
+                [blocker_type.as_str(), blocker_id, blocked_type.as_str(), blocked_id],
+            )
+            .context("failed to delete dependency")?;
+        if rows > 0 {
+            let touched = [(blocker_type, blocker_id), (blocked_type, blocked_id)];
+            touch_items(tx, &touched, actor)?;
+        }
+        Ok(rows)
+    })?;
 
     Ok(rows_affected > 0)
 }
 
+/// Delete every dependency touching an item in the given direction, in one
+/// transaction. Returns the number of rows removed.
+pub fn clear_dependencies(
+    db: &Database,
+    item_type: &DependencyType,
+    item_id: &str,
+    direction: DependencyDirection,
+    actor: Option<&str>,
+) -> Result<usize> {
+    db.write_transaction("clearing dependencies", |tx| {
+        // The other end of every removed edge, to touch along with the item.
+        let mut others: Vec<(DependencyType, String)> = Vec::new();
+        let mut delete = |sql: &str, what: &str| -> Result<()> {
+            let mut stmt = tx.prepare(sql)?;
+            let rows = stmt.query_map([item_type.as_str(), item_id], |row| {
+                Ok((parse_dependency_type(&row.get::<_, String>(0)?)?, row.get(1)?))
+            })?;
+            for row in rows {
+                others.push(row.with_context(|| format!("failed to clear {what} dependencies"))?);
+            }
+            Ok(())
+        };
+        if direction != DependencyDirection::Outgoing {
+            delete(
+                "DELETE FROM dependencies WHERE blocked_type = ?1 AND blocked_id = ?2 \
+                 RETURNING blocker_type, blocker_id",
+                "incoming",
+            )?;
+        }
+        if direction != DependencyDirection::Incoming {
+            delete(
+                "DELETE FROM dependencies WHERE blocker_type = ?1 AND blocker_id = ?2 \
+                 RETURNING blocked_type, blocked_id",
+                "outgoing",
+            )?;
+        }
+        if !others.is_empty() {
+            let mut touched: Vec<(&DependencyType, &str)> = vec![(item_type, item_id)];
+            touched.extend(others.iter().map(|(t, id)| (t, id.as_str())));
+            touch_items(tx, &touched, actor)?;
+        }
+        Ok(others.len())
+    })
+}
+
+/// The item's own blockers.
 pub fn get_blockers(
     db: &Database,
     item_type: &DependencyType,
     item_id: &str,
 ) -> Result<Vec<Dependency>> {
-    let sql = format!("SELECT {SELECT_COLUMNS} FROM dependencies WHERE blocked_type = ?1 AND blocked_id = ?2");
-    let mut stmt = db.conn().prepare(&sql)?;
-    let rows = stmt.query_map([item_type.as_str(), item_id], row_to_dependency)?;
-    rows.collect::<rusqlite::Result<Vec<_>>>()
-        .context("failed to list blockers")
+    let query = DependencyQuery::direct(DependencyDirection::Incoming);
+    get_dependencies(db, item_type, item_id, &query)
 }
 
+/// The dependencies on items the given item blocks.
 pub fn get_blocked_by(
     db: &Database,
     item_type: &DependencyType,
     item_id: &str,
 ) -> Result<Vec<Dependency>> {
-    let sql = format!("SELECT {SELECT_COLUMNS} FROM dependencies WHERE blocker_type = ?1 AND blocker_id = ?2");
-    let mut stmt = db.conn().prepare(&sql)?;
-    let rows = stmt.query_map([item_type.as_str(), item_id], row_to_dependency)?;
+    let query = DependencyQuery::direct(DependencyDirection::Outgoing);
+    get_dependencies(db, item_type, item_id, &query)
+}
+
+/// Like [`get_blockers`], with each blocker resolved to its short ID, title
+/// and status.
+pub fn get_blocker_items(
+    db: &Database,
+    item_type: &DependencyType,
+    item_id: &str,
+) -> Result<Vec<DependencyItem>> {
+    let query = DependencyQuery::direct(DependencyDirection::Incoming);
+    get_dependency_items(db, item_type, item_id, &query)
+}
+
+/// Like [`get_blocked_by`], with each blocked item resolved to its short ID,
+/// title and status.
+pub fn get_blocked_by_items(
+    db: &Database,
+    item_type: &DependencyType,
+    item_id: &str,
+) -> Result<Vec<DependencyItem>> {
+    let query = DependencyQuery::direct(DependencyDirection::Outgoing);
+    get_dependency_items(db, item_type, item_id, &query)
+}
+
+/// `(this, other)` column prefixes for the sides of the item that `direction`
+/// covers: the item is the `blocked` end of its incoming edges and the
+/// `blocker` end of its outgoing ones.
+fn sides(direction: DependencyDirection) -> &'static [(&'static str, &'static str)] {
+    match direction {
+        DependencyDirection::Incoming => &[("blocked", "blocker")],
+        DependencyDirection::Outgoing => &[("blocker", "blocked")],
+        DependencyDirection::Both => &[("blocked", "blocker"), ("blocker", "blocked")],
+    }
+}
+
+/// A query for the dependency rows whose `this` end is the item (`?1`,
+/// `?2`) or, when `transitive`, is reachable from it by walking from `this`
+/// ends to `other` ends.
+fn edges_sql(this: &str, other: &str, transitive: bool) -> String {
+    let direct = format!(
+        "SELECT {SELECT_COLUMNS} FROM dependencies WHERE {this}_type = ?1 AND {this}_id = ?2"
+    );
+    if !transitive {
+        return direct;
+    }
+    // UNION (not UNION ALL) discards revisited edges, so cycles terminate.
+    format!(
+        "SELECT {SELECT_COLUMNS} FROM ( \
+             WITH RECURSIVE reached({SELECT_COLUMNS}) AS ( \
+                 {direct} \
+                 UNION \
+                 SELECT d.id, d.blocker_type, d.blocker_id, d.blocked_type, d.blocked_id \
+                 FROM dependencies d \
+                 JOIN reached r ON d.{this}_type = r.{other}_type AND d.{this}_id = r.{other}_id \
+             ) \
+             SELECT {SELECT_COLUMNS} FROM reached \
+         )"
+    )
+}
+
+/// `LIMIT`/`OFFSET` parameters for a query; SQLite reads a negative limit
+/// as no limit.
+fn page_params(query: &DependencyQuery) -> (i64, i64) {
+    let limit = query.limit.map_or(-1, |n| n as i64);
+    (limit, query.offset as i64)
+}
+
+/// Dependencies of an item in `query.direction`, direct or transitive,
+/// ordered by ID and paged by `query.limit` and `query.offset`.
+pub fn get_dependencies(
+    db: &Database,
+    item_type: &DependencyType,
+    item_id: &str,
+    query: &DependencyQuery,
+) -> Result<Vec<Dependency>> {
+    let edges: Vec<String> = sides(query.direction)
+        .iter()
+        .map(|(this, other)| edges_sql(this, other, query.transitive))
+        .collect();
+    let sql = format!(
+        "SELECT {SELECT_COLUMNS} FROM ({}) ORDER BY id LIMIT ?3 OFFSET ?4",
+        edges.join(" UNION ")
+    );
+    let (limit, offset) = page_params(query);
+    let conn = db.conn();
+    let mut stmt = conn.prepare(&sql)?;
+    let rows = stmt.query_map(
+        rusqlite::params![item_type.as_str(), item_id, limit, offset],
+        row_to_dependency,
+    )?;
+    rows.collect::<rusqlite::Result<Vec<_>>>()
+        .context("failed to list dependencies")
+}
+
+/// Like [`get_dependencies`], with the far end of each dependency resolved
+/// to its short ID, title and status: the blocker for incoming edges, the
+/// blocked item for outgoing ones.
+pub fn get_dependency_items(
+    db: &Database,
+    item_type: &DependencyType,
+    item_id: &str,
+    query: &DependencyQuery,
+) -> Result<Vec<DependencyItem>> {
+    let linked: Vec<String> = sides(query.direction)
+        .iter()
+        .map(|(this, other)| {
+            format!(
+                "SELECT d.id, d.{other}_type AS item_type, d.{other}_id AS item_id, \
+                        COALESCE(e.short_id, t.short_id) AS short_id, \
+                        COALESCE(e.title, t.title) AS title, \
+                        COALESCE(e.status, t.status) AS status \
+                 FROM ({}) d \
+                 LEFT JOIN epics e ON d.{other}_type = 'epic' AND e.id = d.{other}_id \
+                 LEFT JOIN tasks t ON d.{other}_type = 'task' AND t.id = d.{other}_id \
+                 WHERE COALESCE(e.id, t.id) IS NOT NULL",
+                edges_sql(this, other, query.transitive)
+            )
+        })
+        .collect();
+    let sql = format!(
+        "SELECT * FROM ({}) ORDER BY id LIMIT ?3 OFFSET ?4",
+        linked.join(" UNION ")
+    );
+    let (limit, offset) = page_params(query);
+    let conn = db.conn();
+    let mut stmt = conn.prepare(&sql)?;
+    let params = rusqlite::params![item_type.as_str(), item_id, limit, offset];
+    let rows = stmt.query_map(params, |row| {
+        let item_type: String = row.get("item_type")?;
+        let status: String = row.get("status")?;
+        Ok(DependencyItem {
+            dependency_id: row.get("id")?,
+            item_type: parse_dependency_type(&item_type)?,
+            id: row.get("item_id")?,
+            short_id: row.get("short_id")?,
+            title: row.get("title")?,
+            status: status.parse().map_err(|e: anyhow::Error| {
+                rusqlite::Error::FromSqlConversionFailure(
+                    5,
+                    rusqlite::types::Type::Text,
+                    Box::new(std::io::Error::new(std::io::ErrorKind::InvalidData, e.to_string())),
+                )
+            })?,
+        })
+    })?;
     rows.collect::<rusqlite::Result<Vec<_>>>()
-        .context("failed to list blocked items")
+        .context("failed to list dependencies")
 }
 
 pub fn get_all_dependencies(db: &Database) -> Result<Vec<Dependency>> {
     let sql = format!("SELECT {SELECT_COLUMNS} FROM dependencies");
-    let mut stmt = db.conn().prepare(&sql)?;
+    let conn = db.conn();
+    let mut stmt = conn.prepare(&sql)?;
     let rows = stmt.query_map([], row_to_dependency)?;
     rows.collect::<rusqlite::Result<Vec<_>>>()
         .context("failed to list all dependencies")
@@ -153,6 +400,37 @@ pub fn is_blocked(
         .context("failed to check if item is blocked")
 }
 
+/// Like [`is_blocked`], but follows blockers of blockers at any depth: the
+/// item is blocked if anything upstream of it is not done.
+pub fn is_transitively_blocked(
+    db: &Database,
+    item_type: &DependencyType,
+    item_id: &str,
+) -> Result<bool> {
+    // UNION (not UNION ALL) discards revisited items, so cycles terminate.
+    let sql = "
+        WITH RECURSIVE upstream(item_type, item_id) AS (
+            SELECT blocker_type, blocker_id FROM dependencies
+            WHERE blocked_type = ?1 AND blocked_id = ?2
+
+            UNION
+
+            SELECT d.blocker_type, d.blocker_id FROM dependencies d
+            JOIN upstream u ON d.blocked_type = u.item_type AND d.blocked_id = u.item_id
+        )
+        SELECT EXISTS(
+            SELECT 1 FROM upstream u
+            LEFT JOIN epics e ON u.item_type = 'epic' AND u.item_id = e.id
+            LEFT JOIN tasks t ON u.item_type = 'task' AND u.item_id = t.id
+            WHERE COALESCE(e.status, t.status) != 'done'
+        )
+    ";
+
+    db.conn()
+        .query_row(sql, [item_type.as_str(), item_id], |row| row.get(0))
+        .context("failed to check if item is transitively blocked")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -191,7 +469,9 @@ mod tests {
                 project_id: project_id.to_string(),
                 title: "Test Epic".to_string(),
                 description: "For dependency tests".to_string(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap()
     }
@@ -204,11 +484,75 @@ mod tests {
                 title: "Test Task".to_string(),
                 description: "For dependency tests".to_string(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap()
     }
 
+    fn task_updated_at(db: &Database, id: &str) -> String {
+        db.conn()
+            .query_row("SELECT updated_at FROM tasks WHERE id = ?1", [id], |row| row.get(0))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_dependency_changes_touch_both_ends() {
+        let (db, _dir) = open_temp_db();
+        let project = create_test_project(&db);
+        let epic = create_test_epic(&db, &project.id);
+        let tasks: Vec<_> = (0..3).map(|_| create_test_task(&db, &epic.id)).collect();
+        let age = || {
+            db.conn()
+                .execute("UPDATE tasks SET updated_at = '2000-01-01 00:00:00'", [])
+                .unwrap();
+        };
+        let old = "2000-01-01 00:00:00";
+        let block = |from: usize, to: usize| {
+            add_dependency(
+                &db,
+                AddDependencyInput {
+                    blocker_type: DependencyType::Task,
+                    blocker_id: tasks[from].id.clone(),
+                    blocked_type: DependencyType::Task,
+                    blocked_id: tasks[to].id.clone(),
+                },
+                None,
+            )
+        };
+
+        age();
+        block(0, 1).unwrap();
+        assert_ne!(task_updated_at(&db, &tasks[0].id), old);
+        assert_ne!(task_updated_at(&db, &tasks[1].id), old);
+        assert_eq!(task_updated_at(&db, &tasks[2].id), old);
+
+        // A rejected duplicate leaves timestamps alone.
+        age();
+        assert!(block(0, 1).is_err());
+        assert_eq!(task_updated_at(&db, &tasks[0].id), old);
+
+        remove_dependency(
+            &db,
+            &DependencyType::Task,
+            &tasks[0].id,
+            &DependencyType::Task,
+            &tasks[1].id,
+            None,
+        )
+        .unwrap();
+        assert_ne!(task_updated_at(&db, &tasks[0].id), old);
+        assert_ne!(task_updated_at(&db, &tasks[1].id), old);
+
+        block(1, 2).unwrap();
+        age();
+        let both = DependencyDirection::Both;
+        clear_dependencies(&db, &DependencyType::Task, &tasks[2].id, both, None).unwrap();
+        assert_ne!(task_updated_at(&db, &tasks[1].id), old);
+        assert_ne!(task_updated_at(&db, &tasks[2].id), old);
+        assert_eq!(task_updated_at(&db, &tasks[0].id), old);
+    }
+
     #[test]
     fn test_add_between_epics() {
         let (db, _dir) = open_temp_db();
@@ -224,6 +568,7 @@ mod tests {
                 blocked_type: DependencyType::Epic,
                 blocked_id: e2.id.clone(),
             },
+            None,
         )
         .unwrap();
 
@@ -250,6 +595,7 @@ mod tests {
                 blocked_type: DependencyType::Task,
                 blocked_id: t2.id.clone(),
             },
+            None,
         )
         .unwrap();
 
@@ -274,6 +620,7 @@ mod tests {
                 blocked_type: DependencyType::Task,
                 blocked_id: task.id.clone(),
             },
+            None,
         )
         .unwrap();
 
@@ -295,6 +642,7 @@ mod tests {
                 blocked_type: DependencyType::Epic,
                 blocked_id: epic.id.clone(),
             },
+            None,
         );
 
         assert!(result.is_err());
@@ -315,8 +663,8 @@ mod tests {
             blocked_id: e2.id.clone(),
         };
 
-        add_dependency(&db, input()).unwrap();
-        let result = add_dependency(&db, input());
+        add_dependency(&db, input(), None).unwrap();
+        let result = add_dependency(&db, input(), None);
 
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("already exists"));
@@ -336,6 +684,7 @@ mod tests {
                 blocked_type: DependencyType::Epic,
                 blocked_id: epic.id.clone(),
             },
+            None,
         );
 
         assert!(result.is_err());
@@ -356,6 +705,7 @@ mod tests {
                 blocked_type: DependencyType::Epic,
                 blocked_id: "nonexistent".to_string(),
             },
+            None,
         );
 
         assert!(result.is_err());
@@ -377,12 +727,12 @@ mod tests {
                 blocked_type: DependencyType::Epic,
                 blocked_id: e2.id.clone(),
             },
+            None,
         )
         .unwrap();
 
-        let removed =
-            remove_dependency(&db, &DependencyType::Epic, &e1.id, &DependencyType::Epic, &e2.id)
-                .unwrap();
+        let epic = DependencyType::Epic;
+        let removed = remove_dependency(&db, &epic, &e1.id, &epic, &e2.id, None).unwrap();
         assert!(removed);
     }
 
@@ -396,6 +746,7 @@ mod tests {
             "a",
             &DependencyType::Epic,
             "b",
+            None,
         )
         .unwrap();
         assert!(!removed);
@@ -418,6 +769,7 @@ mod tests {
                 blocked_type: DependencyType::Epic,
                 blocked_id: e3.id.clone(),
             },
+            None,
         )
         .unwrap();
         add_dependency(
@@ -428,6 +780,7 @@ mod tests {
                 blocked_type: DependencyType::Epic,
                 blocked_id: e3.id.clone(),
             },
+            None,
         )
         .unwrap();
 
@@ -456,6 +809,7 @@ mod tests {
                 blocked_type: DependencyType::Epic,
                 blocked_id: e2.id.clone(),
             },
+            None,
         )
         .unwrap();
         add_dependency(
@@ -466,6 +820,7 @@ mod tests {
                 blocked_type: DependencyType::Epic,
                 blocked_id: e3.id.clone(),
             },
+            None,
         )
         .unwrap();
 
@@ -494,6 +849,7 @@ mod tests {
                 blocked_type: DependencyType::Epic,
                 blocked_id: e2.id.clone(),
             },
+            None,
         )
         .unwrap();
         add_dependency(
@@ -504,6 +860,7 @@ mod tests {
                 blocked_type: DependencyType::Task,
                 blocked_id: t1.id.clone(),
             },
+            None,
         )
         .unwrap();
 
@@ -528,6 +885,7 @@ mod tests {
                 blocked_type: DependencyType::Task,
                 blocked_id: t2.id.clone(),
             },
+            None,
         )
         .unwrap();
 
@@ -550,6 +908,7 @@ mod tests {
                 blocked_type: DependencyType::Task,
                 blocked_id: t2.id.clone(),
             },
+            None,
         )
         .unwrap();
 
@@ -561,6 +920,7 @@ mod tests {
                 status: Some(ItemStatus::Done),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
 
@@ -585,6 +945,7 @@ mod tests {
                 blocked_type: DependencyType::Task,
                 blocked_id: t3.id.clone(),
             },
+            None,
         )
         .unwrap();
         add_dependency(
@@ -595,6 +956,7 @@ mod tests {
                 blocked_type: DependencyType::Task,
                 blocked_id: t3.id.clone(),
             },
+            None,
         )
         .unwrap();
 
@@ -606,6 +968,7 @@ mod tests {
                 status: Some(ItemStatus::Done),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
 
@@ -623,6 +986,140 @@ mod tests {
         assert!(!is_blocked(&db, &DependencyType::Task, &task.id).unwrap());
     }
 
+    #[test]
+    fn test_is_transitively_blocked_follows_chain() {
+        let (db, _dir) = open_temp_db();
+        let project = create_test_project(&db);
+        let epic = create_test_epic(&db, &project.id);
+        let root = create_test_task(&db, &epic.id);
+        let middle = create_test_task(&db, &epic.id);
+        let leaf = create_test_task(&db, &epic.id);
+        let free = create_test_task(&db, &epic.id);
+
+        // root (todo) → middle (done) → leaf
+        for (blocker, blocked) in [(&root, &middle), (&middle, &leaf)] {
+            add_dependency(
+                &db,
+                AddDependencyInput {
+                    blocker_type: DependencyType::Task,
+                    blocker_id: blocker.id.clone(),
+                    blocked_type: DependencyType::Task,
+                    blocked_id: blocked.id.clone(),
+                },
+                None,
+            )
+            .unwrap();
+        }
+        update_task(
+            &db,
+            &middle.id,
+            UpdateTaskInput {
+                status: Some(ItemStatus::Done),
+                ..Default::default()
+            },
+            None,
+        )
+        .unwrap();
+
+        // Directly blocked.
+        assert!(is_blocked(&db, &DependencyType::Task, &middle.id).unwrap());
+        assert!(is_transitively_blocked(&db, &DependencyType::Task, &middle.id).unwrap());
+        // Only blocked through middle's unfinished blocker.
+        assert!(!is_blocked(&db, &DependencyType::Task, &leaf.id).unwrap());
+        assert!(is_transitively_blocked(&db, &DependencyType::Task, &leaf.id).unwrap());
+        // No blockers at all.
+        assert!(!is_transitively_blocked(&db, &DependencyType::Task, &free.id).unwrap());
+        assert!(!is_transitively_blocked(&db, &DependencyType::Task, &root.id).unwrap());
+
+        update_task(
+            &db,
+            &root.id,
+            UpdateTaskInput {
+                status: Some(ItemStatus::Done),
+                ..Default::default()
+            },
+            None,
+        )
+        .unwrap();
+        assert!(!is_transitively_blocked(&db, &DependencyType::Task, &leaf.id).unwrap());
+    }
+
+    #[test]
+    fn test_get_dependencies_by_direction_depth_and_page() {
+        let (db, _dir) = open_temp_db();
+        let project = create_test_project(&db);
+        let epic = create_test_epic(&db, &project.id);
+        let tasks: Vec<_> = (0..5).map(|_| create_test_task(&db, &epic.id)).collect();
+        let [a, b, c, d, e] = [0, 1, 2, 3, 4].map(|i| tasks[i].id.as_str());
+
+        // a → b → c, b → d, e → b, added in that order.
+        for (blocker, blocked) in [(a, b), (b, c), (b, d), (e, b)] {
+            add_dependency(
+                &db,
+                AddDependencyInput {
+                    blocker_type: DependencyType::Task,
+                    blocker_id: blocker.to_string(),
+                    blocked_type: DependencyType::Task,
+                    blocked_id: blocked.to_string(),
+                },
+                None,
+            )
+            .unwrap();
+        }
+
+        let name = |id: &str| {
+            let i = tasks.iter().position(|t| t.id == id).unwrap();
+            ["a", "b", "c", "d", "e"][i]
+        };
+        let edges = |id: &str, query: DependencyQuery| -> Vec<(&str, &str)> {
+            get_dependencies(&db, &DependencyType::Task, id, &query)
+                .unwrap()
+                .iter()
+                .map(|dep| (name(&dep.blocker_id), name(&dep.blocked_id)))
+                .collect()
+        };
+        let query = |direction, transitive| DependencyQuery {
+            transitive,
+            ..DependencyQuery::direct(direction)
+        };
+        use DependencyDirection::{Both, Incoming, Outgoing};
+
+        assert_eq!(edges(b, query(Incoming, false)), [("a", "b"), ("e", "b")]);
+        assert_eq!(edges(b, query(Outgoing, false)), [("b", "c"), ("b", "d")]);
+        assert_eq!(edges(b, query(Both, false)).len(), 4);
+        assert_eq!(edges(c, query(Incoming, false)), [("b", "c")]);
+        assert_eq!(
+            edges(c, query(Incoming, true)),
+            [("a", "b"), ("b", "c"), ("e", "b")]
+        );
+        assert_eq!(
+            edges(a, query(Outgoing, true)),
+            [("a", "b"), ("b", "c"), ("b", "d")]
+        );
+        assert_eq!(edges(c, query(Outgoing, true)), []);
+        assert_eq!(edges(d, query(Both, true)), [("a", "b"), ("b", "d"), ("e", "b")]);
+
+        let page = |limit, offset| DependencyQuery {
+            limit,
+            offset,
+            ..query(Outgoing, true)
+        };
+        assert_eq!(edges(a, page(Some(2), 0)), [("a", "b"), ("b", "c")]);
+        assert_eq!(edges(a, page(Some(2), 2)), [("b", "d")]);
+        assert_eq!(edges(a, page(None, 1)), [("b", "c"), ("b", "d")]);
+        assert_eq!(edges(a, page(Some(2), 5)), []);
+
+        // Expanded items are the far end of each edge.
+        let items = get_dependency_items(&db, &DependencyType::Task, c, &query(Incoming, true))
+            .unwrap();
+        let ids: Vec<&str> = items.iter().map(|item| item.id.as_str()).collect();
+        assert_eq!(ids, [a, b, e]);
+        let items = get_dependency_items(&db, &DependencyType::Task, b, &query(Both, false))
+            .unwrap();
+        let ids: Vec<&str> = items.iter().map(|item| item.id.as_str()).collect();
+        assert_eq!(ids, [a, c, d, e]);
+    }
+
     #[test]
     fn test_full_lifecycle() {
         let (db, _dir) = open_temp_db();
@@ -640,6 +1137,7 @@ mod tests {
                 blocked_type: DependencyType::Task,
                 blocked_id: t2.id.clone(),
             },
+            None,
         )
         .unwrap();
 
@@ -654,6 +1152,7 @@ mod tests {
                 status: Some(ItemStatus::Done),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
 
@@ -667,6 +1166,7 @@ mod tests {
             &t1.id,
             &DependencyType::Task,
             &t2.id,
+            None,
         )
         .unwrap();
         assert!(removed);
@@ -1,11 +1,16 @@
 use anyhow::{Context, Result};
-use rusqlite::{params_from_iter, OptionalExtension, Row};
-
-use crate::db::Database;
-use crate::db::resolve::{classify_id, IdKind};
-use crate::models::{CreateEpicInput, Epic, ItemStatus, UpdateEpicInput};
-
-const SELECT_COLUMNS: &str = "e.id, e.project_id, e.title, e.description, e.status, e.short_id, e.created_at, e.updated_at";
+use rusqlite::{params_from_iter, Connection, OptionalExtension, Row};
+
+use crate::db::{status_conditions, Database};
+use crate::db::history::record_status_change;
+use crate::db::resolve::{
+    classify_id, epic_short_id_number, format_epic_short_id, single_match, IdKind,
+};
+use crate::models::{
+    CreateEpicInput, DependencyType, Epic, EpicListFilter, ItemStatus, UpdateEpicInput,
+};
+
+const SELECT_COLUMNS: &str = "e.id, e.project_id, e.title, e.description, e.status, e.short_id, e.prd_id, e.position, e.archived, e.created_at, e.updated_at, e.updated_by";
 const TASK_AGGREGATES: &str =
     "COUNT(t.id) AS task_count, SUM(CASE WHEN t.status = 'done' THEN 1 ELSE 0 END) AS done_count";
 
@@ -26,41 +31,75 @@ fn row_to_epic(row: &Row) -> rusqlite::Result<Epic> {
         description: row.get("description")?,
         status,
         short_id: row.get("short_id")?,
+        prd_id: row.get("prd_id")?,
+        position: row.get("position")?,
+        archived: row.get("archived")?,
         created_at: row.get("created_at")?,
         updated_at: row.get("updated_at")?,
+        updated_by: row.get("updated_by")?,
         task_count: row.get("task_count")?,
         done_count: row.get("done_count")?,
     })
 }
 
-pub fn create_epic(db: &Database, input: CreateEpicInput) -> Result<Epic> {
+pub fn create_epic(db: &Database, input: CreateEpicInput, actor: Option<&str>) -> Result<Epic> {
     let id = ulid::Ulid::new().to_string();
+    db.write_transaction("epic creation", |tx| insert_epic(tx, &id, &input, actor))?;
+    get_epic(db, &id)?.context("epic not found after insert")
+}
 
-    let tx = db
-        .conn()
-        .unchecked_transaction()
-        .context("failed to begin transaction for epic creation")?;
-
-    let max_num: i64 = tx
+/// Insert an epic at the top of its project inside an open transaction.
+/// The body of [`create_epic`].
+pub(crate) fn insert_epic(
+    tx: &Connection,
+    id: &str,
+    input: &CreateEpicInput,
+    actor: Option<&str>,
+) -> Result<()> {
+    let prefix: Option<String> = tx
         .query_row(
-            "SELECT COALESCE(MAX(CAST(SUBSTR(short_id, 2) AS INTEGER)), 0) \
-             FROM epics \
-             WHERE project_id = ?1 AND short_id IS NOT NULL",
+            "SELECT short_id_prefix FROM projects WHERE id = ?1",
             [&input.project_id],
             |row| row.get(0),
         )
+        .optional()
+        .context("failed to query project short_id_prefix")?
+        .flatten();
+
+    // Parse numbers in Rust: the prefix may have changed over the project's lifetime.
+    let existing: Vec<String> = tx
+        .prepare("SELECT short_id FROM epics WHERE project_id = ?1 AND short_id IS NOT NULL")?
+        .query_map([&input.project_id], |row| row.get(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()
         .context("failed to query next epic short_id")?;
-    let short_id = format!("E{}", max_num + 1);
-
+    let max_num = existing
+        .iter()
+        .filter_map(|s| epic_short_id_number(s))
+        .max()
+        .unwrap_or(0);
+    let short_id = format_epic_short_id(prefix.as_deref(), max_num + 1);
+
+    // New epics go first; make room at position 0.
     tx.execute(
-        "INSERT INTO epics (id, project_id, title, description, short_id) VALUES (?1, ?2, ?3, ?4, ?5)",
-        [&id, &input.project_id, &input.title, &input.description, &short_id],
+        "UPDATE epics SET position = position + 1 WHERE project_id = ?1",
+        [&input.project_id],
+    )
+    .context("failed to shift epic positions")?;
+    tx.execute(
+        "INSERT INTO epics (id, project_id, title, description, short_id, prd_id, updated_by) \
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        rusqlite::params![
+            id,
+            input.project_id,
+            input.title,
+            input.description,
+            short_id,
+            input.prd_id,
+            actor
+        ],
     )
     .context("failed to insert epic (check that project_id is valid)")?;
-
-    tx.commit().context("failed to commit epic creation")?;
-
-    get_epic(db, &id)?.context("epic not found after insert")
+    Ok(())
 }
 
 pub fn get_epic(db: &Database, id: &str) -> Result<Option<Epic>> {
@@ -77,16 +116,20 @@ pub fn get_epic(db: &Database, id: &str) -> Result<Option<Epic>> {
         .context("failed to query epic")
 }
 
-pub fn list_epics(
-    db: &Database,
-    project_id: Option<&str>,
-    status: Option<ItemStatus>,
-) -> Result<Vec<Epic>> {
+/// List epics matching every field of `filter`, in board order.
+pub fn list_epics(db: &Database, filter: &EpicListFilter) -> Result<Vec<Epic>> {
+    let EpicListFilter {
+        project_id,
+        status,
+        include_archived,
+        created,
+        updated,
+    } = filter;
     let base = format!(
         "SELECT {SELECT_COLUMNS}, {TASK_AGGREGATES} \
          FROM epics e LEFT JOIN tasks t ON t.epic_id = e.id"
     );
-    let tail = "GROUP BY e.id ORDER BY e.created_at DESC";
+    let tail = "GROUP BY e.id ORDER BY e.position, e.created_at DESC, e.id DESC";
 
     let mut conditions: Vec<String> = Vec::new();
     let mut params: Vec<Box<dyn rusqlite::types::ToSql>> = Vec::new();
@@ -95,9 +138,17 @@ pub fn list_epics(
         params.push(Box::new(pid.to_string()));
         conditions.push(format!("e.project_id = ?{}", params.len()));
     }
-    if let Some(s) = status {
-        params.push(Box::new(s.as_str().to_string()));
-        conditions.push(format!("e.status = ?{}", params.len()));
+    conditions.extend(status_conditions("e.status", status, &mut params));
+    if !include_archived {
+        conditions.push("e.archived = 0".to_string());
+    }
+    for (column, range) in [("e.created_at", created), ("e.updated_at", updated)] {
+        if let Some(range) = range {
+            params.push(Box::new(range.start.clone()));
+            params.push(Box::new(range.end.clone()));
+            let (start, end) = (params.len() - 1, params.len());
+            conditions.push(format!("{column} BETWEEN ?{start} AND ?{end}"));
+        }
     }
 
     let sql = if conditions.is_empty() {
@@ -106,14 +157,85 @@ pub fn list_epics(
         format!("{base} WHERE {} {tail}", conditions.join(" AND "))
     };
 
-    let mut stmt = db.conn().prepare(&sql)?;
+    let conn = db.conn();
+    let mut stmt = conn.prepare(&sql)?;
     let rows = stmt.query_map(params_from_iter(params.iter()), row_to_epic)?;
 
     rows.collect::<rusqlite::Result<Vec<_>>>()
         .context("failed to list epics")
 }
 
-pub fn update_epic(db: &Database, id: &str, input: UpdateEpicInput) -> Result<Epic> {
+/// Put the project's epics in a new order: `ordered_ids` first, in the given
+/// order, followed by the remaining epics in their current order. Positions
+/// are renumbered from 0 so no gaps are left behind by deletions.
+/// Returns the project's epics in their new order.
+pub fn reorder_epics(db: &Database, project_id: &str, ordered_ids: &[String]) -> Result<Vec<Epic>> {
+    db.write_transaction("epic reorder", |tx| {
+        let current: Vec<String> = tx
+            .prepare(
+                "SELECT id FROM epics WHERE project_id = ?1 \
+                 ORDER BY position, created_at DESC, id DESC",
+            )?
+            .query_map([project_id], |row| row.get(0))?
+            .collect::<rusqlite::Result<Vec<_>>>()
+            .context("failed to query epic order")?;
+
+        let mut order: Vec<&str> = Vec::with_capacity(current.len());
+        for id in ordered_ids {
+            if !current.contains(id) {
+                anyhow::bail!("epic {id} is not in project {project_id}");
+            }
+            if order.contains(&id.as_str()) {
+                anyhow::bail!("epic {id} is listed more than once");
+            }
+            order.push(id);
+        }
+        let rest: Vec<&str> = current
+            .iter()
+            .map(String::as_str)
+            .filter(|id| !order.contains(id))
+            .collect();
+        order.extend(rest);
+
+        let mut stmt = tx.prepare("UPDATE epics SET position = ?1 WHERE id = ?2")?;
+        for (position, id) in order.iter().enumerate() {
+            stmt.execute(rusqlite::params![position as i64, id])
+                .context("failed to update epic position")?;
+        }
+        Ok(())
+    })?;
+
+    list_epics(
+        db,
+        &EpicListFilter {
+            project_id: Some(project_id),
+            ..Default::default()
+        },
+    )
+}
+
+/// Epics broken down from the given PRD, in creation order.
+pub fn list_epics_for_prd(db: &Database, prd_id: &str) -> Result<Vec<Epic>> {
+    let sql = format!(
+        "SELECT {SELECT_COLUMNS}, {TASK_AGGREGATES} \
+         FROM epics e LEFT JOIN tasks t ON t.epic_id = e.id \
+         WHERE e.prd_id = ?1 \
+         GROUP BY e.id ORDER BY e.created_at, e.id"
+    );
+    let conn = db.conn();
+    let mut stmt = conn.prepare(&sql)?;
+    let rows = stmt.query_map([prd_id], row_to_epic)?;
+
+    rows.collect::<rusqlite::Result<Vec<_>>>()
+        .context("failed to list epics for prd")
+}
+
+pub fn update_epic(
+    db: &Database,
+    id: &str,
+    input: UpdateEpicInput,
+    actor: Option<&str>,
+) -> Result<Epic> {
     let mut set_clauses: Vec<String> = Vec::new();
     let mut params: Vec<Box<dyn rusqlite::types::ToSql>> = Vec::new();
 
@@ -128,9 +250,10 @@ pub fn update_epic(db: &Database, id: &str, input: UpdateEpicInput) -> Result<Ep
     if let Some(description) = input.description {
         bind("description", Box::new(description));
     }
-    if let Some(status) = input.status {
+    if let Some(status) = &input.status {
         bind("status", Box::new(status.as_str().to_string()));
     }
+    bind("updated_by", Box::new(actor.map(str::to_string)));
 
     set_clauses.push("updated_at = datetime('now')".to_string());
     params.push(Box::new(id.to_string()));
@@ -141,10 +264,13 @@ pub fn update_epic(db: &Database, id: &str, input: UpdateEpicInput) -> Result<Ep
         params.len(),
     );
 
-    let rows_affected = db
-        .conn()
-        .execute(&sql, params_from_iter(params.iter()))
-        .context("failed to update epic")?;
+    let rows_affected = db.write_transaction("epic update", |tx| {
+        if let Some(status) = &input.status {
+            record_status_change(tx, &DependencyType::Epic, id, status, None)?;
+        }
+        tx.execute(&sql, params_from_iter(params.iter()))
+            .context("failed to update epic")
+    })?;
 
     if rows_affected == 0 {
         anyhow::bail!("epic not found: {id}");
@@ -153,12 +279,36 @@ pub fn update_epic(db: &Database, id: &str, input: UpdateEpicInput) -> Result<Ep
     get_epic(db, id)?.context("epic not found after update")
 }
 
+/// Archive or unarchive an epic, returning it as it now stands. Its tasks
+/// keep their own archived flags.
+pub fn set_epic_archived(
+    db: &Database,
+    id: &str,
+    archived: bool,
+    actor: Option<&str>,
+) -> Result<Epic> {
+    let rows_affected = db.write_transaction("epic archive", |tx| {
+        tx.execute(
+            "UPDATE epics SET archived = ?1, updated_by = ?2, updated_at = datetime('now') \
+             WHERE id = ?3",
+            rusqlite::params![archived, actor, id],
+        )
+        .context("failed to update epic archived flag")
+    })?;
+    if rows_affected == 0 {
+        anyhow::bail!("epic not found: {id}");
+    }
+    get_epic(db, id)?.context("epic not found after archiving")
+}
+
 pub fn delete_epic(db: &Database, id: &str) -> Result<bool> {
-    let tx = db
-        .conn()
-        .unchecked_transaction()
-        .context("failed to begin transaction for epic deletion")?;
+    let rows_affected = db.write_transaction("epic deletion", |tx| delete_epic_in(tx, id))?;
+    Ok(rows_affected > 0)
+}
 
+/// [`delete_epic`] inside the caller's transaction, returning the number of
+/// epic rows deleted.
+pub(crate) fn delete_epic_in(tx: &Connection, id: &str) -> Result<usize> {
     // Clean up dependencies referencing the epic itself
     tx.execute(
         "DELETE FROM dependencies WHERE (blocker_type = 'epic' AND blocker_id = ?1) OR (blocked_type = 'epic' AND blocked_id = ?1)",
@@ -173,24 +323,27 @@ pub fn delete_epic(db: &Database, id: &str) -> Result<bool> {
     )
     .context("failed to clean up child task dependencies")?;
 
-    let rows_affected = tx
-        .execute("DELETE FROM epics WHERE id = ?1", [id])
-        .context("failed to delete epic")?;
+    tx.execute("DELETE FROM epics WHERE id = ?1", [id])
+        .context("failed to delete epic")
+}
 
-    tx.commit().context("failed to commit epic deletion")?;
-    Ok(rows_affected > 0)
+/// [`sync_epic_status_in`] in a transaction of its own.
+#[cfg(test)]
+pub fn sync_epic_status(db: &Database, epic_id: &str) -> Result<()> {
+    db.write_transaction("epic status sync", |tx| sync_epic_status_in(tx, epic_id))
 }
 
-/// Derive and apply the epic's status based on its tasks' statuses.
+/// Derive and apply the epic's status based on its tasks' statuses, inside
+/// the caller's transaction so the epic moves together with the task change
+/// that triggered it.
 ///
 /// Rules:
 /// - All tasks done -> epic done
 /// - Any task in_progress OR any task done (but not all) -> epic in_progress
 /// - All tasks todo -> epic todo
 /// - No tasks (0 tasks) -> no change
-pub fn sync_epic_status(db: &Database, epic_id: &str) -> Result<()> {
-    let (current_status, total, done_count, in_progress_count): (String, i64, i64, i64) = db
-        .conn()
+pub(crate) fn sync_epic_status_in(conn: &Connection, epic_id: &str) -> Result<()> {
+    let (current_status, total, done_count, in_progress_count): (String, i64, i64, i64) = conn
         .query_row(
             "SELECT e.status, \
                     COUNT(t.id), \
@@ -217,57 +370,41 @@ pub fn sync_epic_status(db: &Database, epic_id: &str) -> Result<()> {
     };
 
     if current_status != new_status.as_str() {
-        db.conn()
-            .execute(
-                "UPDATE epics SET status = ?1, updated_at = datetime('now') WHERE id = ?2",
-                [new_status.as_str(), epic_id],
-            )
-            .context("failed to update epic status")?;
+        record_status_change(conn, &DependencyType::Epic, epic_id, &new_status, None)?;
+        conn.execute(
+            "UPDATE epics SET status = ?1, updated_at = datetime('now') WHERE id = ?2",
+            [new_status.as_str(), epic_id],
+        )
+        .context("failed to update epic status")?;
     }
 
     Ok(())
 }
 
+/// Resolve a ULID or short ID to an epic ULID. Without a `project_id` a
+/// short ID is looked up across all projects and must match only one epic.
 pub fn resolve_epic_id(
     db: &Database,
     id_or_short: &str,
-    default_project_id: Option<&str>,
+    project_id: Option<&str>,
 ) -> Result<String> {
     match classify_id(id_or_short) {
         IdKind::Ulid => Ok(id_or_short.to_string()),
         IdKind::EpicShortId => {
             let short = id_or_short.to_uppercase();
-            match default_project_id {
-                Some(pid) => db
-                    .conn()
-                    .query_row(
-                        "SELECT id FROM epics WHERE short_id = ?1 AND project_id = ?2",
-                        [short.as_str(), pid],
-                        |row| row.get::<_, String>(0),
-                    )
-                    .optional()
-                    .context("failed to resolve epic short ID")?
-                    .ok_or_else(|| anyhow::anyhow!("Epic not found: {id_or_short}")),
-                None => {
-                    let mut stmt = db
-                        .conn()
-                        .prepare("SELECT id FROM epics WHERE short_id = ?1")?;
-                    let ids: Vec<String> = stmt
-                        .query_map([short.as_str()], |row| row.get(0))?
-                        .collect::<rusqlite::Result<Vec<_>>>()
-                        .context("failed to resolve epic short ID")?;
-                    match ids.as_slice() {
-                        [] => anyhow::bail!("Epic not found: {id_or_short}"),
-                        [single] => Ok(single.clone()),
-                        multiple => anyhow::bail!(
-                            "Ambiguous short ID '{}': matches {} epics across projects. \
-                             Provide a default project or use the full ULID.",
-                            id_or_short,
-                            multiple.len()
-                        ),
-                    }
-                }
-            }
+            // A bare `E1` also matches a prefixed `PROJ-E1`.
+            let conn = db.conn();
+            let mut stmt = conn.prepare(
+                "SELECT id FROM epics \
+                 WHERE (short_id = ?1 OR short_id LIKE '%-' || ?1) \
+                   AND (?2 IS NULL OR project_id = ?2)",
+            )?;
+            let ids: Vec<String> = stmt
+                .query_map(rusqlite::params![short, project_id], |row| row.get(0))?
+                .collect::<rusqlite::Result<Vec<_>>>()
+                .context("failed to resolve epic short ID")?;
+            single_match(ids, id_or_short, "epic")?
+                .ok_or_else(|| anyhow::anyhow!("Epic not found: {id_or_short}"))
         }
         IdKind::TaskShortId => {
             anyhow::bail!("Expected epic ID, got task short ID: {id_or_short}")
@@ -279,7 +416,7 @@ pub fn resolve_epic_id(
 mod tests {
     use super::*;
     use crate::db::project::create_project;
-    use crate::models::{CreateProjectInput, Project};
+    use crate::models::{CreateProjectInput, DateRange, Project, StatusFilter};
     use tempfile::TempDir;
 
     fn open_temp_db() -> (Database, TempDir) {
@@ -312,7 +449,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "My Epic".to_string(),
                 description: "Epic description".to_string(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
 
@@ -335,7 +474,9 @@ mod tests {
                 project_id: "nonexistent".to_string(),
                 title: "Orphan".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         );
 
         assert!(result.is_err());
@@ -352,7 +493,9 @@ mod tests {
                 project_id: project.id,
                 title: "Lookup".to_string(),
                 description: "desc".to_string(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
 
@@ -375,7 +518,9 @@ mod tests {
                 project_id: project.id,
                 title: "With Tasks".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
 
@@ -405,7 +550,9 @@ mod tests {
                 project_id: p1.id.clone(),
                 title: "Epic A".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         create_epic(
@@ -414,15 +561,31 @@ mod tests {
                 project_id: p2.id.clone(),
                 title: "Epic B".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
 
-        let p1_epics = list_epics(&db, Some(&p1.id), None).unwrap();
+        let p1_epics = list_epics(
+            &db,
+            &EpicListFilter {
+                project_id: Some(&p1.id),
+                ..Default::default()
+            },
+        )
+        .unwrap();
         assert_eq!(p1_epics.len(), 1);
         assert_eq!(p1_epics[0].title, "Epic A");
 
-        let p2_epics = list_epics(&db, Some(&p2.id), None).unwrap();
+        let p2_epics = list_epics(
+            &db,
+            &EpicListFilter {
+                project_id: Some(&p2.id),
+                ..Default::default()
+            },
+        )
+        .unwrap();
         assert_eq!(p2_epics.len(), 1);
         assert_eq!(p2_epics[0].title, "Epic B");
     }
@@ -438,7 +601,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "Epic A".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
 
@@ -448,7 +613,9 @@ mod tests {
                 project_id: project.id,
                 title: "Epic B".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
 
@@ -459,18 +626,92 @@ mod tests {
                 status: Some(ItemStatus::InProgress),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
 
-        let in_progress = list_epics(&db, None, Some(ItemStatus::InProgress)).unwrap();
+        let in_progress = list_epics(
+            &db,
+            &EpicListFilter {
+                status: StatusFilter::only(ItemStatus::InProgress),
+                ..Default::default()
+            },
+        )
+        .unwrap();
         assert_eq!(in_progress.len(), 1);
         assert_eq!(in_progress[0].title, "Epic A");
 
-        let todo = list_epics(&db, None, Some(ItemStatus::Todo)).unwrap();
+        let todo = list_epics(
+            &db,
+            &EpicListFilter {
+                status: StatusFilter::only(ItemStatus::Todo),
+                ..Default::default()
+            },
+        )
+        .unwrap();
         assert_eq!(todo.len(), 1);
         assert_eq!(todo[0].title, "Epic B");
     }
 
+    #[test]
+    fn test_list_by_status_sets() {
+        let (db, _dir) = open_temp_db();
+        let project = create_test_project(&db);
+        for (title, status) in [
+            ("Todo", ItemStatus::Todo),
+            ("Doing", ItemStatus::InProgress),
+            ("Done", ItemStatus::Done),
+        ] {
+            let epic = create_epic(
+                &db,
+                CreateEpicInput {
+                    project_id: project.id.clone(),
+                    title: title.to_string(),
+                    description: String::new(),
+                    prd_id: None,
+                },
+                None,
+            )
+            .unwrap();
+            update_epic(
+                &db,
+                &epic.id,
+                UpdateEpicInput { status: Some(status), ..Default::default() },
+                None,
+            )
+            .unwrap();
+        }
+        let titles = |filter: StatusFilter| -> Vec<String> {
+            let mut titles: Vec<String> = list_epics(
+                &db,
+                &EpicListFilter {
+                    status: filter,
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+            .into_iter()
+            .map(|e| e.title)
+            .collect();
+            titles.sort();
+            titles
+        };
+
+        let include = vec![ItemStatus::InProgress, ItemStatus::Done];
+        assert_eq!(
+            titles(StatusFilter { include: include.clone(), ..Default::default() }),
+            vec!["Doing", "Done"]
+        );
+        assert_eq!(
+            titles(StatusFilter { exclude: vec![ItemStatus::Todo], ..Default::default() }),
+            vec!["Doing", "Done"]
+        );
+        assert_eq!(
+            titles(StatusFilter { include, exclude: vec![ItemStatus::Done] }),
+            vec!["Doing"]
+        );
+    }
+
     #[test]
     fn test_list_no_filter() {
         let (db, _dir) = open_temp_db();
@@ -483,15 +724,63 @@ mod tests {
                     project_id: project.id.clone(),
                     title: format!("Epic {i}"),
                     description: String::new(),
+                    prd_id: None,
                 },
+                None,
             )
             .unwrap();
         }
 
-        let all = list_epics(&db, None, None).unwrap();
+        let all = list_epics(&db, &EpicListFilter::default()).unwrap();
         assert_eq!(all.len(), 3);
     }
 
+    #[test]
+    fn test_archived_epics_are_hidden_by_default() {
+        let (db, _dir) = open_temp_db();
+        let project = create_test_project(&db);
+        let mut ids = Vec::new();
+        for title in ["Keep", "Shelve"] {
+            let epic = create_epic(
+                &db,
+                CreateEpicInput {
+                    project_id: project.id.clone(),
+                    title: title.to_string(),
+                    description: String::new(),
+                    prd_id: None,
+                },
+                None,
+            )
+            .unwrap();
+            ids.push(epic.id);
+        }
+        let titles = |include_archived| -> Vec<String> {
+            let mut titles: Vec<String> =
+                list_epics(
+                    &db,
+                    &EpicListFilter {
+                        project_id: Some(&project.id),
+                        include_archived,
+                        ..Default::default()
+                    },
+                )
+                .unwrap()
+                .into_iter()
+                .map(|e| e.title)
+                .collect();
+            titles.sort();
+            titles
+        };
+
+        assert!(set_epic_archived(&db, &ids[1], true, None).unwrap().archived);
+        assert_eq!(titles(false), vec!["Keep"]);
+        assert_eq!(titles(true), vec!["Keep", "Shelve"]);
+
+        assert!(!set_epic_archived(&db, &ids[1], false, None).unwrap().archived);
+        assert_eq!(titles(false), vec!["Keep", "Shelve"]);
+        assert!(set_epic_archived(&db, "missing", true, None).is_err());
+    }
+
     #[test]
     fn test_update_partial_fields() {
         let (db, _dir) = open_temp_db();
@@ -503,7 +792,9 @@ mod tests {
                 project_id: project.id,
                 title: "Original".to_string(),
                 description: "original desc".to_string(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
 
@@ -514,6 +805,7 @@ mod tests {
                 title: Some("Renamed".to_string()),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
 
@@ -534,6 +826,7 @@ mod tests {
                 title: Some("Name".to_string()),
                 ..Default::default()
             },
+            None,
         );
 
         assert!(result.is_err());
@@ -551,7 +844,9 @@ mod tests {
                 project_id: project.id,
                 title: "Parent Epic".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
 
@@ -591,7 +886,9 @@ mod tests {
                 project_id: project.id,
                 title: "Lifecycle".to_string(),
                 description: "testing".to_string(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         assert_eq!(epic.status, ItemStatus::Todo);
@@ -609,6 +906,7 @@ mod tests {
                 status: Some(ItemStatus::Done),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
         assert_eq!(updated.title, "Updated");
@@ -631,7 +929,9 @@ mod tests {
                     project_id: project.id.clone(),
                     title: title.to_string(),
                     description: String::new(),
+                    prd_id: None,
                 },
+                None,
             )
             .unwrap()
         };
@@ -645,6 +945,149 @@ mod tests {
         assert_eq!(e3.short_id, Some("E3".to_string()));
     }
 
+    #[test]
+    fn test_list_epics_date_windows() {
+        let (db, _dir) = open_temp_db();
+        let project = create_test_project(&db);
+        let epics = make_epics(&db, &project, &["Old", "New"]);
+        for (epic, at) in epics.iter().zip(["2026-03-01 08:00:00", "2026-03-02 08:00:00"]) {
+            db.conn()
+                .execute(
+                    "UPDATE epics SET created_at = ?1, updated_at = ?1 WHERE id = ?2",
+                    [at, &epic.id],
+                )
+                .unwrap();
+        }
+
+        let day = DateRange::parse("2026-03-01", "2026-03-01").unwrap();
+        let listed = list_epics(
+            &db,
+            &EpicListFilter {
+                project_id: Some(&project.id),
+                created: Some(day.clone()),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(listed.iter().map(|e| e.title.as_str()).collect::<Vec<_>>(), ["Old"]);
+        let listed = list_epics(
+            &db,
+            &EpicListFilter {
+                project_id: Some(&project.id),
+                updated: Some(day),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(listed.len(), 1);
+    }
+
+    // --- position / reorder_epics tests ---
+
+    fn make_epics(db: &Database, project: &Project, titles: &[&str]) -> Vec<Epic> {
+        titles
+            .iter()
+            .map(|title| {
+                create_epic(
+                    db,
+                    CreateEpicInput {
+                        project_id: project.id.clone(),
+                        title: title.to_string(),
+                        description: String::new(),
+                        prd_id: None,
+                    },
+                    None,
+                )
+                .unwrap()
+            })
+            .collect()
+    }
+
+    fn listed_titles(db: &Database, project: &Project) -> Vec<String> {
+        list_epics(
+            db,
+            &EpicListFilter {
+                project_id: Some(&project.id),
+                ..Default::default()
+            },
+        )
+        .unwrap()
+        .into_iter()
+        .map(|e| e.title)
+        .collect()
+    }
+
+    #[test]
+    fn test_new_epics_are_listed_first() {
+        let (db, _dir) = open_temp_db();
+        let project = create_test_project(&db);
+        make_epics(&db, &project, &["A", "B", "C"]);
+
+        // Created within the same second, so only `position` orders them.
+        assert_eq!(listed_titles(&db, &project), ["C", "B", "A"]);
+        let positions: Vec<i64> = list_epics(
+            &db,
+            &EpicListFilter {
+                project_id: Some(&project.id),
+                ..Default::default()
+            },
+        )
+        .unwrap()
+            .iter()
+            .map(|e| e.position)
+            .collect();
+        assert_eq!(positions, [0, 1, 2]);
+    }
+
+    #[test]
+    fn test_reorder_epics_moves_listed_epics_first() {
+        let (db, _dir) = open_temp_db();
+        let project = create_test_project(&db);
+        let epics = make_epics(&db, &project, &["A", "B", "C", "D"]);
+
+        let reordered =
+            reorder_epics(&db, &project.id, &[epics[0].id.clone(), epics[2].id.clone()]).unwrap();
+
+        let titles: Vec<&str> = reordered.iter().map(|e| e.title.as_str()).collect();
+        assert_eq!(titles, ["A", "C", "D", "B"]);
+        let positions: Vec<i64> = reordered.iter().map(|e| e.position).collect();
+        assert_eq!(positions, [0, 1, 2, 3]);
+        assert_eq!(listed_titles(&db, &project), ["A", "C", "D", "B"]);
+    }
+
+    #[test]
+    fn test_reorder_epics_renumbers_after_delete() {
+        let (db, _dir) = open_temp_db();
+        let project = create_test_project(&db);
+        let epics = make_epics(&db, &project, &["A", "B", "C"]);
+        delete_epic(&db, &epics[1].id).unwrap();
+
+        let reordered = reorder_epics(&db, &project.id, &[epics[0].id.clone()]).unwrap();
+
+        let listed: Vec<(&str, i64)> =
+            reordered.iter().map(|e| (e.title.as_str(), e.position)).collect();
+        assert_eq!(listed, [("A", 0), ("C", 1)]);
+    }
+
+    #[test]
+    fn test_reorder_epics_rejects_foreign_and_duplicate_ids() {
+        let (db, _dir) = open_temp_db();
+        let project = create_test_project(&db);
+        let other = create_test_project(&db);
+        let epics = make_epics(&db, &project, &["A", "B"]);
+        let foreign = make_epics(&db, &other, &["X"]);
+
+        let err = reorder_epics(&db, &project.id, &[foreign[0].id.clone()]).unwrap_err();
+        assert!(err.to_string().contains("is not in project"), "{err}");
+
+        let dup = [epics[0].id.clone(), epics[0].id.clone()];
+        let err = reorder_epics(&db, &project.id, &dup).unwrap_err();
+        assert!(err.to_string().contains("more than once"), "{err}");
+
+        // Failed reorders leave the order untouched.
+        assert_eq!(listed_titles(&db, &project), ["B", "A"]);
+    }
+
     // --- resolve_epic_id tests ---
 
     #[test]
@@ -664,7 +1107,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "Test".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
 
@@ -682,7 +1127,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "Test".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
 
@@ -701,7 +1148,9 @@ mod tests {
                 project_id: p1.id.clone(),
                 title: "P1 Epic".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         create_epic(
@@ -710,13 +1159,29 @@ mod tests {
                 project_id: p2.id.clone(),
                 title: "P2 Epic".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
 
         let result = resolve_epic_id(&db, "E1", None);
         assert!(result.is_err());
-        assert!(result.unwrap_err().to_string().contains("Ambiguous"));
+        let err = result.unwrap_err().to_string();
+        assert!(err.starts_with("CONFLICT: Ambiguous"), "{err}");
+        for project in [&p1, &p2] {
+            let epic = list_epics(
+                &db,
+                &EpicListFilter {
+                    project_id: Some(&project.id),
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+            .remove(0);
+            assert!(err.contains(&epic.id), "{err} should list {}", epic.id);
+            assert_eq!(resolve_epic_id(&db, "E1", Some(&project.id)).unwrap(), epic.id);
+        }
     }
 
     #[test]
@@ -745,7 +1210,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "Test".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
 
@@ -766,7 +1233,9 @@ mod tests {
                 project_id: project.id,
                 title: "Sync Test".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         (db, dir, epic)
@@ -828,6 +1297,7 @@ mod tests {
                 status: Some(ItemStatus::InProgress),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
 
@@ -851,6 +1321,7 @@ mod tests {
                 status: Some(ItemStatus::InProgress),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
 
@@ -872,7 +1343,9 @@ mod tests {
                     project_id: project_id.to_string(),
                     title: title.to_string(),
                     description: String::new(),
+                    prd_id: None,
                 },
+                None,
             )
             .unwrap()
         };
@@ -883,4 +1356,53 @@ mod tests {
         assert_eq!(p1_e1.short_id, Some("E1".to_string()));
         assert_eq!(p2_e1.short_id, Some("E1".to_string()));
     }
+
+    #[test]
+    fn test_epic_prd_provenance_survives_prd_delete() {
+        let (db, _dir) = open_temp_db();
+        let project = create_test_project(&db);
+        let prd = crate::db::prd::create_prd(
+            &db,
+            crate::models::CreatePrdInput {
+                project_id: project.id.clone(),
+                title: "Spec".to_string(),
+                content: "content".to_string(),
+            },
+        )
+        .unwrap();
+
+        let derived = create_epic(
+            &db,
+            CreateEpicInput {
+                project_id: project.id.clone(),
+                title: "From PRD".to_string(),
+                description: String::new(),
+                prd_id: Some(prd.id.clone()),
+            },
+            None,
+        )
+        .unwrap();
+        create_epic(
+            &db,
+            CreateEpicInput {
+                project_id: project.id.clone(),
+                title: "Manual".to_string(),
+                description: String::new(),
+                prd_id: None,
+            },
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(derived.prd_id.as_deref(), Some(prd.id.as_str()));
+        let from_prd = list_epics_for_prd(&db, &prd.id).unwrap();
+        assert_eq!(from_prd.len(), 1);
+        assert_eq!(from_prd[0].id, derived.id);
+
+        db.conn()
+            .execute("DELETE FROM prds WHERE id = ?1", [&prd.id])
+            .unwrap();
+        let kept = get_epic(&db, &derived.id).unwrap().expect("epic must survive PRD deletion");
+        assert_eq!(kept.prd_id, None);
+    }
 }
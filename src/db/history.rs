@@ -0,0 +1,634 @@
+use anyhow::{Context, Result};
+use chrono::{Days, NaiveDate};
+use rusqlite::Connection;
+use serde::Serialize;
+
+use crate::db::Database;
+use crate::models::{DependencyType, ItemStatus};
+
+/// Remaining work on one day of a burndown series.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct BurndownPoint {
+    pub date: String,
+    /// Tasks that existed by the end of the day.
+    pub total: i64,
+    /// Of those, tasks not done by the end of the day.
+    pub remaining: i64,
+}
+
+/// One event in a project's activity feed.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct TimelineEntry {
+    pub timestamp: String,
+    /// `epic` or `task`.
+    pub entity_type: String,
+    pub entity_id: String,
+    pub short_id: Option<String>,
+    pub title: String,
+    /// `created`, `completed`, or `moved to <status>`.
+    pub verb: String,
+    /// Reason given for a status change, e.g. by `reopen_task`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Log a transition of `entity_id` to `to`, with an optional `reason`,
+/// unless it already has that status. Must run before the row itself is
+/// updated, since the previous status is read from it.
+pub(crate) fn record_status_change(
+    conn: &Connection,
+    entity_type: &DependencyType,
+    entity_id: &str,
+    to: &ItemStatus,
+    reason: Option<&str>,
+) -> Result<()> {
+    let table = match entity_type {
+        DependencyType::Epic => "epics",
+        DependencyType::Task => "tasks",
+    };
+    conn.execute(
+        &format!(
+            "INSERT INTO status_history (entity_type, entity_id, from_status, to_status, reason) \
+             SELECT ?1, id, status, ?3, ?4 FROM {table} WHERE id = ?2 AND status != ?3"
+        ),
+        rusqlite::params![entity_type.as_str(), entity_id, to.as_str(), reason],
+    )
+    .context("failed to record status change")?;
+    Ok(())
+}
+
+/// Daily task counts for a project from `from` through `to` (inclusive),
+/// replayed from the status history. A task counts from the day it was
+/// created, with the status of its last transition on or before each day
+/// (`todo` before any transition). Deleted and archived tasks are not
+/// counted, as in [`get_forecast`].
+pub fn get_burndown(
+    db: &Database,
+    project_id: &str,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<BurndownPoint>> {
+    let sql = "\
+        WITH RECURSIVE days(day) AS ( \
+            SELECT date(?2) \
+            UNION ALL \
+            SELECT date(day, '+1 day') FROM days WHERE day < date(?3) \
+        ), \
+        project_tasks AS ( \
+            SELECT t.id, date(t.created_at) AS created_day \
+            FROM tasks t JOIN epics e ON e.id = t.epic_id \
+            WHERE e.project_id = ?1 AND t.archived = 0 \
+        ) \
+        SELECT d.day, \
+               COUNT(pt.id), \
+               COALESCE(SUM(CASE WHEN pt.id IS NOT NULL AND COALESCE(( \
+                   SELECT h.to_status FROM status_history h \
+                   WHERE h.entity_type = 'task' AND h.entity_id = pt.id \
+                     AND date(h.changed_at) <= d.day \
+                   ORDER BY h.changed_at DESC, h.id DESC LIMIT 1 \
+               ), 'todo') != 'done' THEN 1 ELSE 0 END), 0) \
+        FROM days d LEFT JOIN project_tasks pt ON pt.created_day <= d.day \
+        GROUP BY d.day ORDER BY d.day";
+
+    let from = from.format("%Y-%m-%d").to_string();
+    let to = to.format("%Y-%m-%d").to_string();
+    let conn = db.conn();
+    let mut stmt = conn.prepare(sql)?;
+    let rows = stmt.query_map([project_id, from.as_str(), to.as_str()], |row| {
+        Ok(BurndownPoint {
+            date: row.get(0)?,
+            total: row.get(1)?,
+            remaining: row.get(2)?,
+        })
+    })?;
+
+    rows.collect::<rusqlite::Result<Vec<_>>>()
+        .context("failed to compute burndown")
+}
+
+/// The `limit` most recent creations and status changes of a project's
+/// epics and tasks, oldest first. Titles are the current ones; deleted
+/// items drop out of the feed. Within one second, creations come before
+/// status changes, and each kind keeps the order it happened in.
+pub fn get_timeline(db: &Database, project_id: &str, limit: usize) -> Result<Vec<TimelineEntry>> {
+    let sql = "\
+        WITH items AS ( \
+            SELECT 'epic' AS kind, rowid AS seq, id, short_id, title, created_at \
+            FROM epics WHERE project_id = ?1 \
+            UNION ALL \
+            SELECT 'task', t.rowid, t.id, t.short_id, t.title, t.created_at \
+            FROM tasks t JOIN epics e ON e.id = t.epic_id WHERE e.project_id = ?1 \
+        ), \
+        events AS ( \
+            SELECT created_at AS at, 0 AS phase, kind, seq, id, short_id, title, \
+                   NULL AS to_status, NULL AS reason \
+            FROM items \
+            UNION ALL \
+            SELECT h.changed_at, 1, i.kind, h.id, i.id, i.short_id, i.title, h.to_status, \
+                   h.reason \
+            FROM status_history h JOIN items i ON i.kind = h.entity_type AND i.id = h.entity_id \
+        ) \
+        SELECT at, kind, id, short_id, title, to_status, reason FROM ( \
+            SELECT * FROM events ORDER BY at DESC, phase DESC, kind DESC, seq DESC LIMIT ?2 \
+        ) ORDER BY at, phase, kind, seq";
+
+    let conn = db.conn();
+    let mut stmt = conn.prepare(sql)?;
+    let rows = stmt.query_map(rusqlite::params![project_id, limit as i64], |row| {
+        let to_status: Option<String> = row.get(5)?;
+        Ok(TimelineEntry {
+            timestamp: row.get(0)?,
+            entity_type: row.get(1)?,
+            entity_id: row.get(2)?,
+            short_id: row.get(3)?,
+            title: row.get(4)?,
+            verb: match to_status.as_deref() {
+                None => "created".to_string(),
+                Some("done") => "completed".to_string(),
+                Some(status) => format!("moved to {status}"),
+            },
+            reason: row.get(6)?,
+        })
+    })?;
+
+    rows.collect::<rusqlite::Result<Vec<_>>>()
+        .context("failed to load timeline")
+}
+
+/// Days of completions [`get_forecast`] measures velocity over, ending today.
+pub const FORECAST_WINDOW_DAYS: u32 = 14;
+
+/// Completions in the window below which a forecast carries a caveat.
+const MIN_FORECAST_COMPLETIONS: i64 = 5;
+
+/// Projected completion of the remaining work at the recent task velocity.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct Forecast {
+    /// Unarchived tasks not yet done.
+    pub remaining_tasks: i64,
+    /// Distinct unarchived tasks moved to done during the window.
+    pub completed_recently: i64,
+    pub window_days: u32,
+    /// Average tasks completed per day over the window, to two decimals.
+    pub tasks_per_day: f64,
+    /// Day (`YYYY-MM-DD`) the remaining tasks would be done at that rate;
+    /// `None` when nothing was completed in the window.
+    pub estimated_completion: Option<String>,
+    /// Why the estimate should be taken loosely, when history is sparse.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub caveat: Option<String>,
+}
+
+impl Forecast {
+    /// Extrapolate `completed` tasks over the `window_days` days ending
+    /// `today` against `remaining` tasks.
+    pub fn extrapolate(
+        remaining: i64,
+        completed: i64,
+        window_days: u32,
+        today: NaiveDate,
+    ) -> Self {
+        let window = i64::from(window_days.max(1));
+        let rate = completed as f64 / window as f64;
+        let (estimated_completion, caveat) = if remaining == 0 {
+            (Some(today), None)
+        } else if completed == 0 {
+            let caveat = format!(
+                "No tasks were completed in the last {window} days, so there is no recent \
+                 velocity to extrapolate"
+            );
+            (None, Some(caveat))
+        } else {
+            // Whole days needed at `completed / window` tasks per day, rounded up.
+            let days = (remaining * window + completed - 1) / completed;
+            let caveat = (completed < MIN_FORECAST_COMPLETIONS).then(|| {
+                format!(
+                    "Only {completed} task(s) completed in the last {window} days; treat the \
+                     estimate as rough"
+                )
+            });
+            (today.checked_add_days(Days::new(days as u64)), caveat)
+        };
+
+        Self {
+            remaining_tasks: remaining,
+            completed_recently: completed,
+            window_days,
+            tasks_per_day: (rate * 100.0).round() / 100.0,
+            estimated_completion: estimated_completion.map(|d| d.format("%Y-%m-%d").to_string()),
+            caveat,
+        }
+    }
+}
+
+/// Forecast when the remaining tasks of a project (or of every project)
+/// will be done, from the tasks completed in the [`FORECAST_WINDOW_DAYS`]
+/// ending `today`. Archived tasks count neither as remaining nor as
+/// completed.
+pub fn get_forecast(
+    db: &Database,
+    project_id: Option<&str>,
+    today: NaiveDate,
+) -> Result<Forecast> {
+    let since = today - Days::new(u64::from(FORECAST_WINDOW_DAYS) - 1);
+    let (remaining, completed): (i64, i64) = db
+        .conn()
+        .query_row(
+            "WITH project_tasks AS ( \
+                 SELECT t.id, t.status FROM tasks t JOIN epics e ON e.id = t.epic_id \
+                 WHERE (?1 IS NULL OR e.project_id = ?1) AND t.archived = 0 \
+             ) \
+             SELECT \
+                 (SELECT COUNT(*) FROM project_tasks WHERE status != 'done'), \
+                 (SELECT COUNT(DISTINCT h.entity_id) FROM status_history h \
+                  JOIN project_tasks pt ON pt.id = h.entity_id \
+                  WHERE h.entity_type = 'task' AND h.to_status = 'done' \
+                    AND date(h.changed_at) BETWEEN ?2 AND ?3)",
+            rusqlite::params![
+                project_id,
+                since.format("%Y-%m-%d").to_string(),
+                today.format("%Y-%m-%d").to_string()
+            ],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .context("failed to count recent completions")?;
+
+    Ok(Forecast::extrapolate(remaining, completed, FORECAST_WINDOW_DAYS, today))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::epic::{create_epic, update_epic};
+    use crate::db::project::create_project;
+    use crate::db::task::{create_task, update_task};
+    use crate::models::{
+        CreateEpicInput, CreateProjectInput, CreateTaskInput, UpdateEpicInput, UpdateTaskInput,
+    };
+    use tempfile::TempDir;
+
+    fn open_temp_db() -> (Database, TempDir) {
+        let dir = TempDir::new().unwrap();
+        let db = Database::open(&dir.path().join("test.db")).unwrap();
+        db.migrate().unwrap();
+        (db, dir)
+    }
+
+    fn history(db: &Database) -> Vec<(String, String, String, String)> {
+        let conn = db.conn();
+        let mut stmt = conn
+            .prepare(
+                "SELECT entity_type, entity_id, from_status, to_status \
+                 FROM status_history ORDER BY id",
+            )
+            .unwrap();
+        stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)))
+            .unwrap()
+            .collect::<rusqlite::Result<_>>()
+            .unwrap()
+    }
+
+    fn row(entity_type: &str, id: &str, from: &str, to: &str) -> (String, String, String, String) {
+        (entity_type.into(), id.into(), from.into(), to.into())
+    }
+
+    #[test]
+    fn status_changes_are_logged_for_tasks_and_synced_epics() {
+        let (db, _dir) = open_temp_db();
+        db.set_auto_epic_status(true);
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "P".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let epic = create_epic(
+            &db,
+            CreateEpicInput {
+                project_id: project.id,
+                title: "E".to_string(),
+                description: String::new(),
+                prd_id: None,
+            },
+            None,
+        )
+        .unwrap();
+        let task = create_task(
+            &db,
+            CreateTaskInput {
+                epic_id: epic.id.clone(),
+                title: "T".to_string(),
+                description: String::new(),
+                session_id: None,
+                assignee: None,
+            },
+        )
+        .unwrap();
+
+        let set_status = |status| {
+            update_task(
+                &db,
+                &task.id,
+                UpdateTaskInput {
+                    status: Some(status),
+                    ..Default::default()
+                },
+                None,
+            )
+            .unwrap();
+        };
+        set_status(ItemStatus::Done);
+        // Setting the same status again is not a transition.
+        set_status(ItemStatus::Done);
+        update_task(
+            &db,
+            &task.id,
+            UpdateTaskInput {
+                title: Some("Renamed".to_string()),
+                ..Default::default()
+            },
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(
+            history(&db),
+            [
+                row("task", &task.id, "todo", "done"),
+                row("epic", &epic.id, "todo", "done"),
+            ]
+        );
+
+        update_epic(
+            &db,
+            &epic.id,
+            UpdateEpicInput {
+                status: Some(ItemStatus::InProgress),
+                ..Default::default()
+            },
+            None,
+        )
+        .unwrap();
+        assert_eq!(history(&db).last().unwrap(), &row("epic", &epic.id, "done", "in_progress"));
+    }
+
+    #[test]
+    fn timeline_lists_creations_and_transitions_in_order() {
+        let (db, _dir) = open_temp_db();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "P".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let epic = create_epic(
+            &db,
+            CreateEpicInput {
+                project_id: project.id.clone(),
+                title: "E".to_string(),
+                description: String::new(),
+                prd_id: None,
+            },
+            None,
+        )
+        .unwrap();
+        let new_task = |title: &str| {
+            create_task(
+                &db,
+                CreateTaskInput {
+                    epic_id: epic.id.clone(),
+                    title: title.to_string(),
+                    description: String::new(),
+                    session_id: None,
+                    assignee: None,
+                },
+            )
+            .unwrap()
+        };
+        let first = new_task("First");
+        let second = new_task("Second");
+        let set_status = |id: &str, status| {
+            update_task(
+                &db,
+                id,
+                UpdateTaskInput {
+                    status: Some(status),
+                    ..Default::default()
+                },
+                None,
+            )
+            .unwrap();
+        };
+        set_status(&first.id, ItemStatus::InProgress);
+        set_status(&second.id, ItemStatus::Done);
+        set_status(&first.id, ItemStatus::Done);
+
+        let feed = |limit| -> Vec<(String, String)> {
+            get_timeline(&db, &project.id, limit)
+                .unwrap()
+                .into_iter()
+                .map(|e| (e.short_id.unwrap(), e.verb))
+                .collect()
+        };
+        let entry = |id: &str, verb: &str| (id.to_string(), verb.to_string());
+        // Everything above happens within a second or two; ties keep the
+        // order of the mutations.
+        assert_eq!(
+            feed(50),
+            [
+                entry("E1", "created"),
+                entry("E1-T1", "created"),
+                entry("E1-T2", "created"),
+                entry("E1-T1", "moved to in_progress"),
+                entry("E1-T2", "completed"),
+                entry("E1-T1", "completed"),
+            ]
+        );
+        assert_eq!(feed(2), [entry("E1-T2", "completed"), entry("E1-T1", "completed")]);
+
+        // A creation dated after a transition is listed after it.
+        db.conn()
+            .execute(
+                "UPDATE tasks SET created_at = '2999-01-01 00:00:00' WHERE id = ?1",
+                [&second.id],
+            )
+            .unwrap();
+        assert_eq!(feed(50).last().unwrap(), &entry("E1-T2", "created"));
+    }
+
+    #[test]
+    fn forecast_extrapolates_recent_velocity() {
+        let today = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+
+        // 7 tasks in 14 days is half a task a day: 3 remaining take 6 days.
+        let forecast = Forecast::extrapolate(3, 7, 14, today);
+        assert_eq!(forecast.tasks_per_day, 0.5);
+        assert_eq!(forecast.estimated_completion.as_deref(), Some("2025-03-16"));
+        assert_eq!(forecast.caveat, None);
+
+        // Partial days round up.
+        let forecast = Forecast::extrapolate(4, 6, 14, today);
+        assert_eq!(forecast.tasks_per_day, 0.43);
+        assert_eq!(forecast.estimated_completion.as_deref(), Some("2025-03-20"));
+
+        // Nothing left is done today, whatever the velocity.
+        let forecast = Forecast::extrapolate(0, 0, 14, today);
+        assert_eq!(forecast.estimated_completion.as_deref(), Some("2025-03-10"));
+        assert_eq!(forecast.caveat, None);
+    }
+
+    #[test]
+    fn forecast_falls_back_when_history_is_sparse() {
+        let today = NaiveDate::from_ymd_opt(2025, 3, 10).unwrap();
+
+        let forecast = Forecast::extrapolate(5, 0, 14, today);
+        assert_eq!(forecast.tasks_per_day, 0.0);
+        assert_eq!(forecast.estimated_completion, None);
+        assert!(forecast.caveat.unwrap().contains("no recent velocity"));
+
+        // Two completions still give a date, flagged as rough.
+        let forecast = Forecast::extrapolate(2, 2, 14, today);
+        assert_eq!(forecast.estimated_completion.as_deref(), Some("2025-03-24"));
+        assert!(forecast.caveat.unwrap().starts_with("Only 2 task(s) completed"));
+    }
+
+    #[test]
+    fn forecast_counts_completions_within_the_window() {
+        let (db, _dir) = open_temp_db();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "P".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let epic = create_epic(
+            &db,
+            CreateEpicInput {
+                project_id: project.id.clone(),
+                title: "E".to_string(),
+                description: String::new(),
+                prd_id: None,
+            },
+            None,
+        )
+        .unwrap();
+        let new_task = |title: String| {
+            create_task(
+                &db,
+                CreateTaskInput {
+                    epic_id: epic.id.clone(),
+                    title,
+                    description: String::new(),
+                    session_id: None,
+                    assignee: None,
+                },
+            )
+            .unwrap()
+        };
+        // Complete a task and date the completion `changed_at`.
+        let complete = |changed_at: &str| {
+            let task = new_task(format!("Done {changed_at}"));
+            update_task(
+                &db,
+                &task.id,
+                UpdateTaskInput {
+                    status: Some(ItemStatus::Done),
+                    ..Default::default()
+                },
+                None,
+            )
+            .unwrap();
+            db.conn()
+                .execute(
+                    "UPDATE status_history SET changed_at = ?1 WHERE entity_id = ?2",
+                    [changed_at, task.id.as_str()],
+                )
+                .unwrap();
+        };
+
+        let today = NaiveDate::from_ymd_opt(2025, 3, 14).unwrap();
+        for day in 1..=7 {
+            complete(&format!("2025-03-{:02} 12:00:00", day * 2));
+        }
+        // Before the 14-day window.
+        complete("2025-02-28 12:00:00");
+        for i in 0..3 {
+            new_task(format!("Open {i}"));
+        }
+        let archived = new_task("Archived".to_string());
+        crate::db::task::set_task_archived(&db, &archived.id, true, None).unwrap();
+
+        let forecast = get_forecast(&db, Some(&project.id), today).unwrap();
+        assert_eq!(forecast.remaining_tasks, 3);
+        assert_eq!(forecast.completed_recently, 7);
+        assert_eq!(forecast.estimated_completion.as_deref(), Some("2025-03-20"));
+        assert_eq!(forecast.caveat, None);
+
+        let elsewhere = get_forecast(&db, Some("other-project"), today).unwrap();
+        assert_eq!(elsewhere.remaining_tasks, 0);
+        assert_eq!(elsewhere.completed_recently, 0);
+    }
+
+    #[test]
+    fn archived_tasks_drop_out_of_burndown_and_forecast() {
+        let (db, _dir) = open_temp_db();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "P".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let epic = create_epic(
+            &db,
+            CreateEpicInput {
+                project_id: project.id.clone(),
+                title: "E".to_string(),
+                description: String::new(),
+                prd_id: None,
+            },
+            None,
+        )
+        .unwrap();
+        let new_task = |title: &str, done: bool, archived: bool| {
+            let task = create_task(
+                &db,
+                CreateTaskInput {
+                    epic_id: epic.id.clone(),
+                    title: title.to_string(),
+                    description: String::new(),
+                    session_id: None,
+                    assignee: None,
+                },
+            )
+            .unwrap();
+            if done {
+                let done = UpdateTaskInput {
+                    status: Some(ItemStatus::Done),
+                    ..Default::default()
+                };
+                update_task(&db, &task.id, done, None).unwrap();
+            }
+            crate::db::task::set_task_archived(&db, &task.id, archived, None).unwrap();
+        };
+        new_task("Done", true, false);
+        new_task("Done, archived", true, true);
+        new_task("Open", false, false);
+        new_task("Open, archived", false, true);
+
+        let today = chrono::Utc::now().date_naive();
+        let burndown = get_burndown(&db, &project.id, today, today).unwrap();
+        assert_eq!(burndown[0].total, 2);
+        assert_eq!(burndown[0].remaining, 1);
+        let forecast = get_forecast(&db, Some(&project.id), today).unwrap();
+        assert_eq!(forecast.remaining_tasks, 1);
+        assert_eq!(forecast.completed_recently, 1);
+    }
+}
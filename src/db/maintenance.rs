@@ -0,0 +1,116 @@
+//! Database housekeeping: WAL checkpointing and `VACUUM`.
+//!
+//! Both operations are safe while a TUI session has the database open. The
+//! TUI only holds short read transactions, so a `TRUNCATE` checkpoint either
+//! completes or reports `checkpoint_busy` and leaves the WAL for a later run;
+//! it never discards committed data. `VACUUM` needs a brief write lock and
+//! fails with "database is locked" if another connection is mid-write, in
+//! which case nothing is changed and the tool can simply be retried.
+
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::db::Database;
+
+#[derive(Debug, Serialize)]
+pub struct MaintenanceReport {
+    pub db_bytes_before: u64,
+    pub wal_bytes_before: u64,
+    pub db_bytes_after: u64,
+    pub wal_bytes_after: u64,
+    /// True when readers prevented the checkpoint from fully completing.
+    pub checkpoint_busy: bool,
+}
+
+/// Size of the database file and its `-wal` sidecar, or zero when missing
+/// (e.g. in-memory databases).
+fn file_sizes(path: Option<&str>) -> (u64, u64) {
+    let Some(path) = path.filter(|p| !p.is_empty()) else {
+        return (0, 0);
+    };
+    let size = |p: &Path| std::fs::metadata(p).map(|m| m.len()).unwrap_or(0);
+    (size(Path::new(path)), size(Path::new(&format!("{path}-wal"))))
+}
+
+/// Checkpoint the WAL into the main file, truncate it, then `VACUUM`.
+pub fn maintain(db: &Database) -> Result<MaintenanceReport> {
+    let path = db.conn().path().map(String::from);
+    let (db_bytes_before, wal_bytes_before) = file_sizes(path.as_deref());
+
+    let busy: i64 = db
+        .conn()
+        .query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |row| row.get(0))
+        .context("failed to checkpoint WAL")?;
+
+    db.conn().execute_batch("VACUUM").context("failed to vacuum database")?;
+
+    // VACUUM writes through the WAL; checkpoint again so the reclaimed space
+    // shows up in the main file and the WAL is left empty.
+    db.conn()
+        .query_row("PRAGMA wal_checkpoint(TRUNCATE)", [], |row| row.get::<_, i64>(0))
+        .context("failed to checkpoint WAL after vacuum")?;
+
+    let (db_bytes_after, wal_bytes_after) = file_sizes(path.as_deref());
+
+    Ok(MaintenanceReport {
+        db_bytes_before,
+        wal_bytes_before,
+        db_bytes_after,
+        wal_bytes_after,
+        checkpoint_busy: busy != 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::project::{delete_project, list_projects};
+    use crate::db::seed::seed_demo_data;
+    use crate::db::status::count_tasks_by_status;
+    use tempfile::TempDir;
+
+    fn open_temp_db() -> (Database, TempDir) {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("test.db");
+        let db = Database::open(&path).unwrap();
+        db.migrate().unwrap();
+        (db, dir)
+    }
+
+    #[test]
+    fn test_maintain_preserves_data() {
+        let (db, _dir) = open_temp_db();
+        let summary = seed_demo_data(&db).unwrap();
+        let before = count_tasks_by_status(&db, Some(&summary.project_id)).unwrap();
+
+        let report = maintain(&db).unwrap();
+        assert!(report.db_bytes_after > 0);
+        assert_eq!(report.wal_bytes_after, 0);
+        assert!(!report.checkpoint_busy);
+
+        let after = count_tasks_by_status(&db, Some(&summary.project_id)).unwrap();
+        assert_eq!(before, after);
+        assert_eq!(list_projects(&db, None, Default::default()).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_maintain_reclaims_space_after_delete() {
+        let (db, _dir) = open_temp_db();
+        let summary = seed_demo_data(&db).unwrap();
+        maintain(&db).unwrap();
+
+        delete_project(&db, &summary.project_id).unwrap();
+        let report = maintain(&db).unwrap();
+        assert!(report.db_bytes_after <= report.db_bytes_before + report.wal_bytes_before);
+        assert!(list_projects(&db, None, Default::default()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_maintain_in_memory_is_safe() {
+        let db = Database::open_in_memory().unwrap();
+        let report = maintain(&db).unwrap();
+        assert_eq!(report.db_bytes_after, 0);
+    }
+}
@@ -1,67 +1,249 @@
-use std::path::{Path, PathBuf};
+use std::ops::Deref;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Condvar, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
 
 use anyhow::{Context, Result};
-use rusqlite::Connection;
+use rusqlite::{Connection, ErrorCode, OptionalExtension, Transaction, TransactionBehavior};
+
+use crate::models::StatusFilter;
 
 const MIGRATIONS: &[(i32, &str)] = &[
     (1, include_str!("../../migrations/001_init.sql")),
     (2, include_str!("../../migrations/002_short_ids.sql")),
     (3, include_str!("../../migrations/003_session_id.sql")),
+    (4, include_str!("../../migrations/004_short_id_prefix.sql")),
+    (5, include_str!("../../migrations/005_epic_prd_id.sql")),
+    (6, include_str!("../../migrations/006_task_assignee.sql")),
+    (7, include_str!("../../migrations/007_status_history.sql")),
+    (8, include_str!("../../migrations/008_epic_position.sql")),
+    (9, include_str!("../../migrations/009_project_on_hold.sql")),
+    (10, include_str!("../../migrations/010_task_checklist.sql")),
+    (11, include_str!("../../migrations/011_archived.sql")),
+    (12, include_str!("../../migrations/012_status_reason.sql")),
+    (13, include_str!("../../migrations/013_updated_by.sql")),
+    (14, include_str!("../../migrations/014_task_templates.sql")),
 ];
 
+/// How long SQLite itself waits on a locked database before returning
+/// `SQLITE_BUSY`. Covers the common case of a TUI and a `serve` process
+/// sharing one file.
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Extra attempts [`Database::write_transaction`] makes when a write still
+/// fails with `SQLITE_BUSY` after the busy timeout.
+const MAX_BUSY_RETRIES: u32 = 3;
+const BUSY_RETRY_BACKOFF: Duration = Duration::from_millis(50);
+
+/// Connections a [`Database`] opens on a file. Checkouts beyond this wait
+/// for one to be returned.
+const POOL_SIZE: usize = 4;
+
+/// `IN` / `NOT IN` conditions on `column` for `filter`, with the statuses
+/// appended to `params`.
+pub(crate) fn status_conditions(
+    column: &str,
+    filter: &StatusFilter,
+    params: &mut Vec<Box<dyn rusqlite::types::ToSql>>,
+) -> Vec<String> {
+    let mut conditions = Vec::new();
+    for (statuses, op) in [(&filter.include, "IN"), (&filter.exclude, "NOT IN")] {
+        if statuses.is_empty() {
+            continue;
+        }
+        let placeholders: Vec<String> = statuses
+            .iter()
+            .map(|s| {
+                params.push(Box::new(s.as_str()));
+                format!("?{}", params.len())
+            })
+            .collect();
+        conditions.push(format!("{column} {op} ({})", placeholders.join(", ")));
+    }
+    conditions
+}
+
+/// True when `err` was caused by `SQLITE_BUSY` anywhere in its chain.
+fn is_busy(err: &anyhow::Error) -> bool {
+    err.chain().any(|cause| {
+        matches!(
+            cause.downcast_ref::<rusqlite::Error>(),
+            Some(rusqlite::Error::SqliteFailure(e, _)) if e.code == ErrorCode::DatabaseBusy
+        )
+    })
+}
+
+/// Run `op`, retrying up to [`MAX_BUSY_RETRIES`] times with linear backoff
+/// while it fails with `SQLITE_BUSY`. Other errors are returned immediately.
+fn retry_on_busy<T>(mut op: impl FnMut() -> Result<T>) -> Result<T> {
+    let mut attempt = 0;
+    loop {
+        match op() {
+            Err(e) if attempt < MAX_BUSY_RETRIES && is_busy(&e) => {
+                attempt += 1;
+                std::thread::sleep(BUSY_RETRY_BACKOFF * attempt);
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Open `path` with the settings every connection needs: WAL journaling,
+/// foreign key enforcement and a busy timeout.
+fn open_connection(path: &Path) -> Result<Connection> {
+    let conn = Connection::open(path)
+        .with_context(|| format!("failed to open database at {}", path.display()))?;
+
+    let mode: String =
+        conn.pragma_update_and_check(None, "journal_mode", "WAL", |row| row.get(0))?;
+    if mode != "wal" {
+        anyhow::bail!("failed to enable WAL mode, got: {mode}");
+    }
+    conn.pragma_update(None, "foreign_keys", "ON")?;
+    conn.busy_timeout(BUSY_TIMEOUT)?;
+    Ok(conn)
+}
+
+/// Idle connections of a [`Database`], and a signal for threads waiting
+/// on one to be returned.
+struct ConnectionPool {
+    idle: Mutex<Vec<Connection>>,
+    returned: Condvar,
+}
+
+impl ConnectionPool {
+    fn new(connections: Vec<Connection>) -> Self {
+        Self { idle: Mutex::new(connections), returned: Condvar::new() }
+    }
+
+    /// A poisoned lock only means another thread panicked while pushing or
+    /// popping; the list itself is still sound.
+    fn lock(&self) -> MutexGuard<'_, Vec<Connection>> {
+        self.idle.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn checkout(&self) -> Connection {
+        let mut idle = self.lock();
+        loop {
+            if let Some(conn) = idle.pop() {
+                return conn;
+            }
+            idle = self.returned.wait(idle).unwrap_or_else(PoisonError::into_inner);
+        }
+    }
+
+    fn checkin(&self, conn: Connection) {
+        self.lock().push(conn);
+        self.returned.notify_one();
+    }
+}
+
+/// A connection checked out of a [`Database`], returned to it on drop.
+pub struct PooledConnection<'a> {
+    pool: &'a ConnectionPool,
+    /// Always `Some` until dropped.
+    conn: Option<Connection>,
+}
+
+impl Deref for PooledConnection<'_> {
+    type Target = Connection;
+
+    fn deref(&self) -> &Connection {
+        self.conn.as_ref().expect("connection is only taken on drop")
+    }
+}
+
+impl Drop for PooledConnection<'_> {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            self.pool.checkin(conn);
+        }
+    }
+}
+
+/// A handle on the database, shareable between threads.
+///
+/// A file database keeps a pool of [`POOL_SIZE`] connections. Each call
+/// through the handle checks one out with [`Database::conn`] for as long as
+/// it runs, so threads sharing a handle read concurrently under WAL, while
+/// [`Database::write_transaction`] takes SQLite's write lock and retries when
+/// another connection or process holds it. An in-memory database lives in
+/// its one connection, so calls through it take turns.
 pub struct Database {
-    conn: Connection,
+    pool: ConnectionPool,
+    /// Whether task writes re-derive the parent epic's status.
+    auto_epic_status: AtomicBool,
 }
 
 impl Database {
     /// Open a database at the given path, creating parent directories as needed.
-    /// Enables WAL mode and foreign key enforcement.
+    /// Enables WAL mode, foreign key enforcement and a busy timeout on each
+    /// of its connections.
     pub fn open(path: &Path) -> Result<Self> {
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)
                 .with_context(|| format!("failed to create directory {}", parent.display()))?;
         }
 
-        let conn = Connection::open(path)
-            .with_context(|| format!("failed to open database at {}", path.display()))?;
+        let connections = (0..POOL_SIZE)
+            .map(|_| open_connection(path))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self {
+            pool: ConnectionPool::new(connections),
+            auto_epic_status: AtomicBool::new(false),
+        })
+    }
 
-        let mode: String =
-            conn.pragma_update_and_check(None, "journal_mode", "WAL", |row| row.get(0))?;
-        if mode != "wal" {
-            anyhow::bail!("failed to enable WAL mode, got: {mode}");
-        }
+    /// Open a fresh in-memory database with all migrations applied.
+    /// WAL is not available for memory databases, so only foreign key
+    /// enforcement is enabled. Data is lost when the value is dropped.
+    pub fn open_in_memory() -> Result<Self> {
+        let conn = Connection::open_in_memory().context("failed to open in-memory database")?;
         conn.pragma_update(None, "foreign_keys", "ON")?;
 
-        Ok(Self { conn })
+        let db = Self {
+            pool: ConnectionPool::new(vec![conn]),
+            auto_epic_status: AtomicBool::new(false),
+        };
+        db.migrate()?;
+        Ok(db)
+    }
+
+    /// Open the database at the default location; see
+    /// [`workspace::default_db_path`] for how `data_dir` and the environment
+    /// choose it.
+    pub fn open_default(data_dir: Option<&Path>) -> Result<Self> {
+        Self::open(&workspace::default_db_path(data_dir)?)
     }
 
-    /// Open the database at the default location.
-    /// Uses `BLUEPRINT_DB` env var if set, otherwise `~/.blueprint/blueprint.db`.
-    pub fn open_default() -> Result<Self> {
-        let path = match std::env::var("BLUEPRINT_DB") {
-            Ok(p) => PathBuf::from(p),
-            Err(_) => {
-                let home = std::env::var("HOME").context("HOME environment variable not set")?;
-                PathBuf::from(home).join(".blueprint").join("blueprint.db")
+    /// Open the named workspace, `~/.blueprint/<name>.db` or under the
+    /// configured base directory, or the default database when `name` is
+    /// `None`.
+    pub fn open_workspace(name: Option<&str>, data_dir: Option<&Path>) -> Result<Self> {
+        match name {
+            Some(name) => {
+                let dir = workspace::data_dir(workspace::configured_base(data_dir).as_deref())?;
+                Self::open(&workspace::workspace_path(&dir, name)?)
             }
-        };
-        Self::open(&path)
+            None => Self::open_default(data_dir),
+        }
     }
 
     /// Run all pending migrations. Uses a `_schema_version` table to track
     /// which migrations have been applied, and only runs new ones.
     pub fn migrate(&self) -> Result<()> {
-        self.conn
-            .execute_batch(
-                "CREATE TABLE IF NOT EXISTS _schema_version (
-                    version INTEGER PRIMARY KEY,
-                    applied_at TEXT NOT NULL DEFAULT (datetime('now'))
-                )",
-            )
-            .context("failed to create _schema_version table")?;
+        // The foreign key setting below is per connection, so hold one throughout.
+        let conn = self.conn();
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS _schema_version (
+                version INTEGER PRIMARY KEY,
+                applied_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )",
+        )
+        .context("failed to create _schema_version table")?;
 
-        let current_version: i32 = self
-            .conn
+        let current_version: i32 = conn
             .query_row(
                 "SELECT COALESCE(MAX(version), 0) FROM _schema_version",
                 [],
@@ -69,19 +251,42 @@ impl Database {
             )
             .context("failed to query schema version")?;
 
+        // Table rebuilds need foreign keys off, or dropping the old table
+        // would cascade into its children. The setting can only change
+        // outside a transaction; each migration checks the keys before it
+        // commits instead.
+        let foreign_keys: bool = conn
+            .query_row("PRAGMA foreign_keys", [], |row| row.get(0))
+            .context("failed to read foreign_keys setting")?;
+        conn.pragma_update(None, "foreign_keys", "OFF")?;
+        let result = Self::run_migrations(&conn, current_version);
+        if foreign_keys {
+            conn.pragma_update(None, "foreign_keys", "ON")?;
+        }
+        result
+    }
+
+    fn run_migrations(conn: &Connection, current_version: i32) -> Result<()> {
         for &(version, sql) in MIGRATIONS {
             if version <= current_version {
                 continue;
             }
 
-            let tx = self
-                .conn
+            let tx = conn
                 .unchecked_transaction()
                 .with_context(|| format!("failed to begin transaction for migration {version}"))?;
 
             tx.execute_batch(sql)
                 .with_context(|| format!("failed to run migration {version}"))?;
 
+            let violation: Option<String> = tx
+                .query_row("PRAGMA foreign_key_check", [], |row| row.get(0))
+                .optional()
+                .with_context(|| format!("failed to check foreign keys after migration {version}"))?;
+            if let Some(table) = violation {
+                anyhow::bail!("migration {version} left a broken foreign key in table {table}");
+            }
+
             tx.execute(
                 "INSERT INTO _schema_version (version) VALUES (?1)",
                 [version],
@@ -95,19 +300,60 @@ impl Database {
         Ok(())
     }
 
-    /// Access the underlying connection.
-    pub fn conn(&self) -> &Connection {
-        &self.conn
+    /// Check out a connection, waiting while all of them are in use; see
+    /// [`Database`]. Keep it only for the statements at hand: a thread that
+    /// checks out a second one before dropping the first can wait forever.
+    pub fn conn(&self) -> PooledConnection<'_> {
+        PooledConnection { pool: &self.pool, conn: Some(self.pool.checkout()) }
+    }
+
+    /// Whether creating, updating or deleting a task also updates the
+    /// parent epic's status (see [`epic::sync_epic_status_in`]). Off by default;
+    /// the MCP server and the TUI turn it on from the `auto_epic_status` setting.
+    pub fn auto_epic_status(&self) -> bool {
+        self.auto_epic_status.load(Ordering::Relaxed)
+    }
+
+    pub fn set_auto_epic_status(&self, enabled: bool) {
+        self.auto_epic_status.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Run `body` inside an `IMMEDIATE` transaction and commit it, retrying
+    /// the whole transaction if another process holds the write lock.
+    /// Taking the lock up front means SQLite's busy timeout applies, instead
+    /// of failing when a deferred transaction tries to upgrade mid-way.
+    /// `what` names the operation in error messages.
+    pub fn write_transaction<T>(
+        &self,
+        what: &str,
+        mut body: impl FnMut(&Transaction) -> Result<T>,
+    ) -> Result<T> {
+        let conn = self.conn();
+        retry_on_busy(|| {
+            let tx = Transaction::new_unchecked(&conn, TransactionBehavior::Immediate)
+                .with_context(|| format!("failed to begin transaction for {what}"))?;
+            let value = body(&tx)?;
+            tx.commit()
+                .with_context(|| format!("failed to commit {what}"))?;
+            Ok(value)
+        })
     }
 }
 
+pub mod checklist;
 pub mod dependency;
 pub mod epic;
+pub mod history;
+pub mod maintenance;
 pub mod prd;
 pub mod project;
 pub(crate) mod resolve;
+pub mod search;
+pub mod seed;
 pub mod status;
+pub mod workspace;
 pub mod task;
+pub mod template;
 
 #[cfg(test)]
 mod tests {
@@ -125,15 +371,15 @@ mod tests {
     /// Apply a single raw migration SQL and record its version.
     /// Mirrors the per-migration transaction logic in `Database::migrate`.
     fn apply_raw_migration(db: &Database, version: i32, sql: &str) {
-        db.conn()
-            .execute_batch(
-                "CREATE TABLE IF NOT EXISTS _schema_version (
-                    version INTEGER PRIMARY KEY,
-                    applied_at TEXT NOT NULL DEFAULT (datetime('now'))
-                )",
-            )
-            .unwrap();
-        let tx = db.conn().unchecked_transaction().unwrap();
+        let conn = db.conn();
+        conn.execute_batch(
+            "CREATE TABLE IF NOT EXISTS _schema_version (
+                version INTEGER PRIMARY KEY,
+                applied_at TEXT NOT NULL DEFAULT (datetime('now'))
+            )",
+        )
+        .unwrap();
+        let tx = conn.unchecked_transaction().unwrap();
         tx.execute_batch(sql).unwrap();
         tx.execute(
             "INSERT INTO _schema_version (version) VALUES (?1)",
@@ -162,7 +408,19 @@ mod tests {
             .unwrap()
             .collect::<Result<_, _>>()
             .unwrap();
-        assert_eq!(tables, ["dependencies", "epics", "prds", "projects", "tasks"]);
+        assert_eq!(
+            tables,
+            [
+                "dependencies",
+                "epics",
+                "prds",
+                "projects",
+                "status_history",
+                "task_checklist",
+                "task_templates",
+                "tasks",
+            ]
+        );
     }
 
     #[test]
@@ -181,10 +439,15 @@ mod tests {
             [
                 "idx_deps_blocked",
                 "idx_deps_blocker",
+                "idx_epics_prd_id",
                 "idx_epics_project_id",
+                "idx_epics_project_position",
                 "idx_epics_short_id",
                 "idx_epics_status",
                 "idx_prds_project_id",
+                "idx_status_history_entity",
+                "idx_task_checklist_task",
+                "idx_tasks_assignee",
                 "idx_tasks_epic_id",
                 "idx_tasks_short_id",
                 "idx_tasks_status",
@@ -198,6 +461,111 @@ mod tests {
         db.migrate().unwrap(); // second run should succeed
     }
 
+    #[test]
+    fn test_open_in_memory_runs_migrations() {
+        let db = Database::open_in_memory().unwrap();
+        let version: i32 = db
+            .conn()
+            .query_row("SELECT MAX(version) FROM _schema_version", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(version, MIGRATIONS.len() as i32);
+
+        let fk: i32 = db
+            .conn()
+            .query_row("PRAGMA foreign_keys", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(fk, 1);
+
+        db.migrate().unwrap(); // re-running is a no-op
+    }
+
+    #[test]
+    fn test_auto_epic_status_is_off_by_default() {
+        use crate::db::epic::{create_epic, get_epic};
+        use crate::db::project::create_project;
+        use crate::db::task::{create_task, update_task};
+        use crate::models::{
+            CreateEpicInput, CreateProjectInput, CreateTaskInput, ItemStatus, UpdateTaskInput,
+        };
+
+        let (file_db, _dir) = open_temp_db();
+        assert!(!file_db.auto_epic_status());
+
+        let db = Database::open_in_memory().unwrap();
+        assert!(!db.auto_epic_status());
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "P".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let epic = create_epic(
+            &db,
+            CreateEpicInput {
+                project_id: project.id.clone(),
+                title: "E".to_string(),
+                description: String::new(),
+                prd_id: None,
+            },
+            None,
+        )
+        .unwrap();
+        let task = create_task(
+            &db,
+            CreateTaskInput {
+                epic_id: epic.id.clone(),
+                title: "T".to_string(),
+                description: String::new(),
+                session_id: None,
+                assignee: None,
+            },
+        )
+        .unwrap();
+        let start = UpdateTaskInput {
+            status: Some(ItemStatus::InProgress),
+            ..Default::default()
+        };
+        update_task(&db, &task.id, start, None).unwrap();
+        assert_eq!(get_epic(&db, &epic.id).unwrap().unwrap().status, ItemStatus::Todo);
+    }
+
+    #[test]
+    fn test_open_in_memory_crud_round_trip() {
+        use crate::db::epic::{create_epic, get_epic};
+        use crate::db::project::{create_project, delete_project};
+        use crate::models::{CreateEpicInput, CreateProjectInput};
+
+        let db = Database::open_in_memory().unwrap();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "Ephemeral".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let epic = create_epic(
+            &db,
+            CreateEpicInput {
+                project_id: project.id.clone(),
+                title: "E".to_string(),
+                description: String::new(),
+                prd_id: None,
+            },
+            None,
+        )
+        .unwrap();
+        assert_eq!(get_epic(&db, &epic.id).unwrap().unwrap().title, "E");
+
+        // Foreign keys cascade in memory too.
+        assert!(delete_project(&db, &project.id).unwrap());
+        assert!(get_epic(&db, &epic.id).unwrap().is_none());
+    }
+
     #[test]
     fn test_migrate_tracks_schema_version() {
         let (db, _dir) = open_temp_db();
@@ -207,7 +575,7 @@ mod tests {
                 row.get(0)
             })
             .unwrap();
-        assert_eq!(version, 3);
+        assert_eq!(version, 14);
     }
 
     #[test]
@@ -303,6 +671,41 @@ mod tests {
             })
             .unwrap();
         assert_eq!(t3_sid, "E2-T1");
+
+        // Positions follow the old newest-first order
+        let positions: Vec<(String, i64)> = db
+            .conn()
+            .prepare("SELECT id, position FROM epics ORDER BY position")
+            .unwrap()
+            .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(positions, [("e2".to_string(), 0), ("e1".to_string(), 1)]);
+
+        // Rebuilding the projects table kept the project and its epics, and
+        // foreign keys are enforced again afterwards.
+        let (status, epics): (String, i64) = db
+            .conn()
+            .query_row(
+                "SELECT status, (SELECT COUNT(*) FROM epics WHERE project_id = 'p1') \
+                 FROM projects WHERE id = 'p1'",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!((status.as_str(), epics), ("active", 2));
+        let fk: bool = db
+            .conn()
+            .query_row("PRAGMA foreign_keys", [], |row| row.get(0))
+            .unwrap();
+        assert!(fk);
+        db.conn().execute("DELETE FROM projects WHERE id = 'p1'", []).unwrap();
+        let remaining: i64 = db
+            .conn()
+            .query_row("SELECT COUNT(*) FROM epics", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(remaining, 0);
     }
 
     #[test]
@@ -379,4 +782,157 @@ mod tests {
             .unwrap();
         assert_eq!(mode, "wal");
     }
+
+    #[test]
+    fn test_open_sets_busy_timeout() {
+        let (db, _dir) = open_temp_db();
+        let timeout: i64 = db
+            .conn()
+            .query_row("PRAGMA busy_timeout", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(timeout, BUSY_TIMEOUT.as_millis() as i64);
+    }
+
+    #[test]
+    fn test_retry_on_busy_retries_only_busy_errors() {
+        let busy = || {
+            anyhow::Error::new(rusqlite::Error::SqliteFailure(
+                rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_BUSY),
+                None,
+            ))
+            .context("failed to insert")
+        };
+
+        let mut calls = 0;
+        let value = retry_on_busy(|| {
+            calls += 1;
+            if calls < 3 { Err(busy()) } else { Ok(calls) }
+        })
+        .unwrap();
+        assert_eq!(value, 3);
+
+        calls = 0;
+        let err = retry_on_busy(|| -> Result<()> {
+            calls += 1;
+            Err(busy())
+        })
+        .unwrap_err();
+        assert!(is_busy(&err));
+        assert_eq!(calls, MAX_BUSY_RETRIES + 1);
+
+        calls = 0;
+        let _ = retry_on_busy(|| -> Result<()> {
+            calls += 1;
+            anyhow::bail!("not a busy error")
+        });
+        assert_eq!(calls, 1);
+    }
+
+    #[test]
+    fn test_write_transaction_waits_for_concurrent_writer() {
+        let (db, dir) = open_temp_db();
+        let path = dir.path().join("test.db");
+        let (locked_tx, locked_rx) = std::sync::mpsc::channel();
+
+        // A second connection, like a TUI next to `serve`, holds the write lock briefly.
+        let holder = std::thread::spawn(move || {
+            let other = Database::open(&path).unwrap();
+            other.conn().execute_batch("BEGIN IMMEDIATE").unwrap();
+            other
+                .conn()
+                .execute("INSERT INTO projects (id, name) VALUES ('other', 'Other')", [])
+                .unwrap();
+            locked_tx.send(()).unwrap();
+            std::thread::sleep(Duration::from_millis(200));
+            other.conn().execute_batch("COMMIT").unwrap();
+        });
+
+        locked_rx.recv().unwrap();
+        let inserted = db
+            .write_transaction("test insert", |tx| {
+                tx.execute("INSERT INTO projects (id, name) VALUES ('mine', 'Mine')", [])
+                    .context("insert failed")
+            })
+            .unwrap();
+        holder.join().unwrap();
+
+        assert_eq!(inserted, 1);
+        let count: i64 = db
+            .conn()
+            .query_row("SELECT COUNT(*) FROM projects", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn test_one_handle_reads_concurrently_while_it_writes() {
+        use crate::db::epic::create_epic;
+        use crate::db::project::create_project;
+        use crate::db::task::{create_task, list_tasks};
+        use crate::models::{CreateEpicInput, CreateProjectInput, CreateTaskInput, TaskListFilter};
+
+        const WRITES: usize = 20;
+        let (db, _dir) = open_temp_db();
+        let project = create_project(
+            &db,
+            CreateProjectInput { name: "Shared".to_string(), description: String::new() },
+        )
+        .unwrap();
+        let epic = create_epic(
+            &db,
+            CreateEpicInput {
+                project_id: project.id,
+                title: "Epic".to_string(),
+                description: String::new(),
+                prd_id: None,
+            },
+            None,
+        )
+        .unwrap();
+
+        // Every connection can be out at once, each reading.
+        let all_out = std::sync::Barrier::new(POOL_SIZE);
+        std::thread::scope(|scope| {
+            for _ in 0..POOL_SIZE {
+                scope.spawn(|| {
+                    let conn = db.conn();
+                    all_out.wait();
+                    let count: i64 = conn
+                        .query_row("SELECT COUNT(*) FROM epics", [], |row| row.get(0))
+                        .unwrap();
+                    assert_eq!(count, 1);
+                });
+            }
+        });
+
+        let filter = TaskListFilter { epic_id: Some(&epic.id), ..Default::default() };
+        std::thread::scope(|scope| {
+            for _ in 0..4 {
+                scope.spawn(|| {
+                    let mut seen = 0;
+                    for _ in 0..50 {
+                        let count = list_tasks(&db, &filter).unwrap().len();
+                        assert!(count >= seen && count <= WRITES, "{seen} then {count}");
+                        seen = count;
+                    }
+                });
+            }
+            scope.spawn(|| {
+                for i in 0..WRITES {
+                    create_task(
+                        &db,
+                        CreateTaskInput {
+                            epic_id: epic.id.clone(),
+                            title: format!("Task {i}"),
+                            description: String::new(),
+                            session_id: None,
+                            assignee: None,
+                        },
+                    )
+                    .unwrap();
+                }
+            });
+        });
+        assert_eq!(list_tasks(&db, &filter).unwrap().len(), WRITES);
+    }
 }
@@ -1,8 +1,13 @@
 use anyhow::{Context, Result};
-use rusqlite::{OptionalExtension, Row};
+use rusqlite::{Connection, OptionalExtension, Row};
 
+use crate::db::epic::{delete_epic_in, insert_epic, list_epics_for_prd};
+use crate::db::task::{delete_task_in, insert_task, list_tasks};
 use crate::db::Database;
-use crate::models::{CreatePrdInput, Prd};
+use crate::models::{
+    diff_outline, outline_prd, CreateEpicInput, CreatePrdInput, CreateTaskInput, Prd, PrdDiff,
+    PrdDiffItem, ProposedTask, TaskListFilter,
+};
 
 const SELECT_COLUMNS: &str = "id, project_id, title, content, created_at";
 
@@ -40,11 +45,110 @@ pub fn get_prd(db: &Database, id: &str) -> Result<Option<Prd>> {
     Ok(prd)
 }
 
+/// All PRDs for a project, newest first.
+pub fn list_prds(db: &Database, project_id: &str) -> Result<Vec<Prd>> {
+    let sql = format!(
+        "SELECT {SELECT_COLUMNS} FROM prds WHERE project_id = ?1 \
+         ORDER BY created_at DESC, id DESC"
+    );
+    let conn = db.conn();
+    let mut stmt = conn.prepare(&sql)?;
+    let rows = stmt.query_map([project_id], row_to_prd)?;
+
+    rows.collect::<rusqlite::Result<Vec<_>>>()
+        .context("failed to list prds")
+}
+
+/// Compare the epics and tasks broken down from a PRD with an outline of
+/// `content`. Read-only; see [`apply_prd_diff`].
+pub fn diff_prd(db: &Database, prd_id: &str, content: &str) -> Result<PrdDiff> {
+    let mut breakdown = Vec::new();
+    for epic in list_epics_for_prd(db, prd_id)? {
+        let tasks = list_tasks(
+            db,
+            &TaskListFilter {
+                epic_id: Some(&epic.id),
+                include_archived: true,
+                ..Default::default()
+            },
+        )?
+            .into_iter()
+            .map(|t| PrdDiffItem {
+                id: t.id,
+                short_id: t.short_id,
+                title: t.title,
+            })
+            .collect();
+        let item = PrdDiffItem {
+            id: epic.id,
+            short_id: epic.short_id,
+            title: epic.title,
+        };
+        breakdown.push((item, tasks));
+    }
+    Ok(diff_outline(breakdown, outline_prd(content)))
+}
+
+/// Carry out a diff from [`diff_prd`] in one transaction: delete removed
+/// epics and tasks, create added ones linked to the PRD, and store `content`
+/// as the PRD's new text so the next diff starts from it. On failure nothing
+/// is changed.
+pub fn apply_prd_diff(
+    db: &Database,
+    prd: &Prd,
+    diff: &PrdDiff,
+    content: &str,
+    actor: Option<&str>,
+) -> Result<()> {
+    let new_task = |tx: &Connection, epic_id: &str, task: &ProposedTask| {
+        let input = CreateTaskInput {
+            epic_id: epic_id.to_string(),
+            title: task.title.clone(),
+            description: task.description.clone(),
+            session_id: None,
+            assignee: None,
+        };
+        insert_task(tx, db, &ulid::Ulid::new().to_string(), &input, actor)
+    };
+
+    db.write_transaction("PRD diff", |tx| {
+        for epic in &diff.removed_epics {
+            delete_epic_in(tx, &epic.epic.id)?;
+        }
+        for epic in &diff.unchanged_epics {
+            for task in &epic.removed_tasks {
+                delete_task_in(tx, db, &task.id)?;
+            }
+            for task in &epic.added_tasks {
+                new_task(tx, &epic.epic.id, task)?;
+            }
+        }
+        // Created in reverse so they end up in document order, as new epics go first.
+        for proposed in diff.added_epics.iter().rev() {
+            let epic_id = ulid::Ulid::new().to_string();
+            let input = CreateEpicInput {
+                project_id: prd.project_id.clone(),
+                title: proposed.title.clone(),
+                description: proposed.description.clone(),
+                prd_id: Some(prd.id.clone()),
+            };
+            insert_epic(tx, &epic_id, &input, actor)?;
+            for task in &proposed.tasks {
+                new_task(tx, &epic_id, task)?;
+            }
+        }
+        tx.execute("UPDATE prds SET content = ?1 WHERE id = ?2", [content, &prd.id])
+            .context("failed to update prd content")?;
+        Ok(())
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::db::epic::{create_epic, list_epics};
     use crate::db::project::create_project;
-    use crate::models::CreateProjectInput;
+    use crate::models::{CreateProjectInput, EpicListFilter};
     use tempfile::TempDir;
 
     fn open_temp_db() -> (Database, TempDir) {
@@ -152,4 +256,162 @@ mod tests {
         crate::db::project::delete_project(&db, &project.id).unwrap();
         assert!(get_prd(&db, &prd.id).unwrap().is_none());
     }
+
+    #[test]
+    fn test_list_prds_scoped_and_newest_first() {
+        let (db, _dir) = open_temp_db();
+        let mut project_ids = Vec::new();
+        for name in ["A", "B"] {
+            let project = create_project(
+                &db,
+                CreateProjectInput {
+                    name: name.to_string(),
+                    description: String::new(),
+                },
+            )
+            .unwrap();
+            project_ids.push(project.id);
+        }
+
+        for title in ["First", "Second"] {
+            create_prd(
+                &db,
+                CreatePrdInput {
+                    project_id: project_ids[0].clone(),
+                    title: title.to_string(),
+                    content: "content".to_string(),
+                },
+            )
+            .unwrap();
+        }
+        db.conn()
+            .execute(
+                "UPDATE prds SET created_at = '2020-01-01 00:00:00' WHERE title = 'First'",
+                [],
+            )
+            .unwrap();
+
+        let prds = list_prds(&db, &project_ids[0]).unwrap();
+        let titles: Vec<&str> = prds.iter().map(|p| p.title.as_str()).collect();
+        assert_eq!(titles, vec!["Second", "First"]);
+
+        assert!(list_prds(&db, &project_ids[1]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_apply_prd_diff_adds_new_epics_in_order_and_drops_removed_ones() {
+        let (db, _dir) = open_temp_db();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "Proj".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let prd = create_prd(
+            &db,
+            CreatePrdInput {
+                project_id: project.id.clone(),
+                title: "Spec".to_string(),
+                content: String::new(),
+            },
+        )
+        .unwrap();
+        let old = create_epic(
+            &db,
+            CreateEpicInput {
+                project_id: project.id.clone(),
+                title: "Legacy".to_string(),
+                description: String::new(),
+                prd_id: Some(prd.id.clone()),
+            },
+            None,
+        )
+        .unwrap();
+
+        let content = "## Search\n- Index\n## Export\n- CSV\n- JSON\n";
+        let diff = diff_prd(&db, &prd.id, content).unwrap();
+        assert_eq!(diff.removed_epics[0].epic.id, old.id);
+        assert_eq!(diff.task_counts(), (3, 0, 0));
+        apply_prd_diff(&db, &prd, &diff, content, None).unwrap();
+
+        // Listed by position, the order a reader of the PRD expects.
+        let epics = list_epics(
+            &db,
+            &EpicListFilter {
+                project_id: Some(&project.id),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert!(epics.iter().all(|e| e.prd_id.as_deref() == Some(prd.id.as_str())));
+        let titles: Vec<&str> = epics.iter().map(|e| e.title.as_str()).collect();
+        assert_eq!(titles, ["Search", "Export"]);
+        assert_eq!(epics[1].task_count, 2);
+        let rediff = diff_prd(&db, &prd.id, content).unwrap();
+        assert!(rediff.added_epics.is_empty() && rediff.removed_epics.is_empty());
+        assert_eq!(rediff.task_counts(), (0, 3, 0));
+    }
+
+    #[test]
+    fn test_apply_prd_diff_failure_changes_nothing() {
+        let (db, _dir) = open_temp_db();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "Proj".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let prd = create_prd(
+            &db,
+            CreatePrdInput {
+                project_id: project.id.clone(),
+                title: "Spec".to_string(),
+                content: "## Legacy\n".to_string(),
+            },
+        )
+        .unwrap();
+        create_epic(
+            &db,
+            CreateEpicInput {
+                project_id: project.id.clone(),
+                title: "Legacy".to_string(),
+                description: String::new(),
+                prd_id: Some(prd.id.clone()),
+            },
+            None,
+        )
+        .unwrap();
+        // Storing the new content is the last step, after every epic and task change.
+        db.conn()
+            .execute_batch(
+                "CREATE TRIGGER fail_prd BEFORE UPDATE ON prds \
+                 BEGIN SELECT RAISE(ABORT, 'forced failure'); END;",
+            )
+            .unwrap();
+
+        let content = "## Search\n- Index\n";
+        let diff = diff_prd(&db, &prd.id, content).unwrap();
+        assert!(apply_prd_diff(&db, &prd, &diff, content, None).is_err());
+
+        let epics = list_epics(
+            &db,
+            &EpicListFilter {
+                project_id: Some(&project.id),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let titles: Vec<&str> = epics.iter().map(|e| e.title.as_str()).collect();
+        assert_eq!(titles, ["Legacy"]);
+        let tasks: i64 = db
+            .conn()
+            .query_row("SELECT COUNT(*) FROM tasks", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(tasks, 0);
+        assert_eq!(get_prd(&db, &prd.id).unwrap().unwrap().content, "## Legacy\n");
+    }
 }
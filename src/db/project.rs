@@ -1,10 +1,18 @@
 use anyhow::{Context, Result};
-use rusqlite::{params_from_iter, OptionalExtension, Row};
+use rusqlite::{params_from_iter, Connection, OptionalExtension, Row};
+use serde::Serialize;
 
 use crate::db::Database;
-use crate::models::{CreateProjectInput, Project, ProjectStatus, UpdateProjectInput};
+use crate::db::epic::sync_epic_status_in;
+use crate::db::history::record_status_change;
+use crate::db::resolve::normalize_short_id_prefix;
+use crate::models::{
+    CreateProjectInput, DependencyType, ItemStatus, Project, ProjectSort, ProjectStatus,
+    UpdateProjectInput,
+};
 
-const SELECT_COLUMNS: &str = "id, name, description, status, created_at, updated_at";
+const SELECT_COLUMNS: &str =
+    "id, name, description, status, short_id_prefix, created_at, updated_at";
 
 fn row_to_project(row: &Row) -> rusqlite::Result<Project> {
     let status_str: String = row.get("status")?;
@@ -21,6 +29,7 @@ fn row_to_project(row: &Row) -> rusqlite::Result<Project> {
         name: row.get("name")?,
         description: row.get("description")?,
         status,
+        short_id_prefix: row.get("short_id_prefix")?,
         created_at: row.get("created_at")?,
         updated_at: row.get("updated_at")?,
     })
@@ -28,16 +37,24 @@ fn row_to_project(row: &Row) -> rusqlite::Result<Project> {
 
 pub fn create_project(db: &Database, input: CreateProjectInput) -> Result<Project> {
     let id = ulid::Ulid::new().to_string();
-    db.conn()
-        .execute(
-            "INSERT INTO projects (id, name, description) VALUES (?1, ?2, ?3)",
-            [&id, &input.name, &input.description],
-        )
-        .context("failed to insert project")?;
-
+    insert_project(&db.conn(), &id, &input)?;
     get_project(db, &id)?.context("project not found after insert")
 }
 
+/// Insert a project row on `conn`, which may be an open transaction.
+pub(crate) fn insert_project(
+    conn: &Connection,
+    id: &str,
+    input: &CreateProjectInput,
+) -> Result<()> {
+    conn.execute(
+        "INSERT INTO projects (id, name, description) VALUES (?1, ?2, ?3)",
+        [id, &input.name, &input.description],
+    )
+    .context("failed to insert project")?;
+    Ok(())
+}
+
 pub fn get_project(db: &Database, id: &str) -> Result<Option<Project>> {
     let sql = format!("SELECT {SELECT_COLUMNS} FROM projects WHERE id = ?1");
     let project = db
@@ -50,17 +67,31 @@ pub fn get_project(db: &Database, id: &str) -> Result<Option<Project>> {
     Ok(project)
 }
 
+/// Latest `updated_at` of a project row, its epics and its tasks.
+const LAST_ACTIVITY: &str = "MAX(projects.updated_at, \
+    COALESCE((SELECT MAX(e.updated_at) FROM epics e WHERE e.project_id = projects.id), ''), \
+    COALESCE((SELECT MAX(t.updated_at) FROM tasks t JOIN epics e ON t.epic_id = e.id \
+              WHERE e.project_id = projects.id), ''))";
+
 pub fn list_projects(
     db: &Database,
     status: Option<ProjectStatus>,
+    sort: ProjectSort,
 ) -> Result<Vec<Project>> {
     let base = format!("SELECT {SELECT_COLUMNS} FROM projects");
+    let order = match sort {
+        ProjectSort::Name => "name COLLATE NOCASE, created_at DESC".to_string(),
+        ProjectSort::Created => "created_at DESC, rowid DESC".to_string(),
+        ProjectSort::Updated => "updated_at DESC, created_at DESC".to_string(),
+        ProjectSort::Activity => format!("{LAST_ACTIVITY} DESC, created_at DESC"),
+    };
     let sql = match &status {
-        Some(_) => format!("{base} WHERE status = ?1 ORDER BY created_at DESC"),
-        None => format!("{base} ORDER BY created_at DESC"),
+        Some(_) => format!("{base} WHERE status = ?1 ORDER BY {order}"),
+        None => format!("{base} ORDER BY {order}"),
     };
 
-    let mut stmt = db.conn().prepare(&sql)?;
+    let conn = db.conn();
+    let mut stmt = conn.prepare(&sql)?;
     let rows = match &status {
         Some(s) => stmt.query_map([s.as_str()], row_to_project)?,
         None => stmt.query_map([], row_to_project)?,
@@ -70,6 +101,39 @@ pub fn list_projects(
         .context("failed to list projects")
 }
 
+/// Find a project by ID or by name as typed on the command line: an exact
+/// name match first, then a case-insensitive one, then a case-insensitive
+/// substring. The first of those steps to match anything must match one
+/// project; when it matches several the error lists them.
+pub fn resolve_project_fuzzy(db: &Database, query: &str) -> Result<Project> {
+    if let Some(project) = get_project(db, query)? {
+        return Ok(project);
+    }
+    let projects = list_projects(db, None, ProjectSort::Name)?;
+    let lower = query.to_lowercase();
+    let steps: [&dyn Fn(&Project) -> bool; 3] = [
+        &|p| p.name == query,
+        &|p| p.name.to_lowercase() == lower,
+        &|p| p.name.to_lowercase().contains(&lower),
+    ];
+    for matches in steps {
+        let found: Vec<&Project> = projects.iter().filter(|p| matches(p)).collect();
+        match found.as_slice() {
+            [] => continue,
+            [single] => return Ok((*single).clone()),
+            several => {
+                let names: Vec<&str> = several.iter().map(|p| p.name.as_str()).collect();
+                anyhow::bail!(
+                    "project name '{query}' is ambiguous, it matches {} projects: {}",
+                    names.len(),
+                    names.join(", ")
+                );
+            }
+        }
+    }
+    anyhow::bail!("project not found: {query}")
+}
+
 pub fn update_project(db: &Database, id: &str, input: UpdateProjectInput) -> Result<Project> {
     let mut set_clauses: Vec<String> = Vec::new();
     let mut params: Vec<Box<dyn rusqlite::types::ToSql>> = Vec::new();
@@ -88,6 +152,13 @@ pub fn update_project(db: &Database, id: &str, input: UpdateProjectInput) -> Res
     if let Some(status) = input.status {
         bind("status", Box::new(status.as_str().to_string()));
     }
+    if let Some(prefix) = input.short_id_prefix {
+        let prefix = match prefix {
+            Some(p) => normalize_short_id_prefix(&p)?,
+            None => None,
+        };
+        bind("short_id_prefix", Box::new(prefix));
+    }
 
     set_clauses.push("updated_at = datetime('now')".to_string());
     params.push(Box::new(id.to_string()));
@@ -119,6 +190,75 @@ pub fn delete_project(db: &Database, id: &str) -> Result<bool> {
     Ok(rows_affected > 0)
 }
 
+/// How many items [`reset_project_tasks`] moved back to `todo`.
+#[derive(Debug, Serialize, PartialEq)]
+pub struct ResetCounts {
+    pub tasks: usize,
+    pub epics: usize,
+}
+
+/// IDs of the project's epics (`epic = true`) or tasks that are not `todo`.
+fn started_items(conn: &Connection, project_id: &str, epic: bool) -> Result<Vec<String>> {
+    let sql = if epic {
+        "SELECT id FROM epics WHERE project_id = ?1 AND status != 'todo'"
+    } else {
+        "SELECT t.id FROM tasks t JOIN epics e ON e.id = t.epic_id \
+         WHERE e.project_id = ?1 AND t.status != 'todo'"
+    };
+    conn.prepare(sql)?
+        .query_map([project_id], |row| row.get(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()
+        .context("failed to query started items")
+}
+
+/// Move every task of a project back to `todo` in one transaction, keeping
+/// epics, tasks and dependencies in place. With `include_epics` every epic
+/// is reset too; otherwise epics only follow their tasks when automatic
+/// epic status is on. Each change is logged in the status history.
+pub fn reset_project_tasks(
+    db: &Database,
+    project_id: &str,
+    include_epics: bool,
+    actor: Option<&str>,
+) -> Result<ResetCounts> {
+    db.write_transaction("project reset", |tx| {
+        let reset = |epic: bool| -> Result<usize> {
+            let (entity_type, table) = if epic {
+                (DependencyType::Epic, "epics")
+            } else {
+                (DependencyType::Task, "tasks")
+            };
+            let ids = started_items(tx, project_id, epic)?;
+            for id in &ids {
+                record_status_change(tx, &entity_type, id, &ItemStatus::Todo, None)?;
+                tx.execute(
+                    &format!(
+                        "UPDATE {table} SET status = 'todo', updated_by = ?2, \
+                         updated_at = datetime('now') WHERE id = ?1"
+                    ),
+                    rusqlite::params![id, actor],
+                )
+                .with_context(|| format!("failed to reset {table}"))?;
+            }
+            Ok(ids.len())
+        };
+
+        let tasks = reset(false)?;
+        let epics = if include_epics {
+            reset(true)?
+        } else if db.auto_epic_status() {
+            let before = started_items(tx, project_id, true)?;
+            for id in &before {
+                sync_epic_status_in(tx, id)?;
+            }
+            before.len() - started_items(tx, project_id, true)?.len()
+        } else {
+            0
+        };
+        Ok(ResetCounts { tasks, epics })
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -170,6 +310,99 @@ mod tests {
         assert!(missing.is_none());
     }
 
+    /// Projects "bravo", "Alpha" and "charlie", created in that order a day
+    /// apart. Alpha's row was edited last, while charlie has the newest task.
+    fn seed_sort_projects(db: &Database) -> Vec<Project> {
+        let projects: Vec<Project> = ["bravo", "Alpha", "charlie"]
+            .iter()
+            .map(|name| {
+                create_project(
+                    db,
+                    CreateProjectInput {
+                        name: name.to_string(),
+                        description: String::new(),
+                    },
+                )
+                .unwrap()
+            })
+            .collect();
+        for (project, day) in projects.iter().zip(1..) {
+            db.conn()
+                .execute(
+                    "UPDATE projects SET created_at = ?1, updated_at = ?1 WHERE id = ?2",
+                    rusqlite::params![format!("2024-01-0{day} 00:00:00"), project.id],
+                )
+                .unwrap();
+        }
+        db.conn()
+            .execute(
+                "UPDATE projects SET updated_at = '2024-02-01 00:00:00' WHERE id = ?1",
+                [&projects[1].id],
+            )
+            .unwrap();
+
+        // Work under bravo (an old epic) and charlie (the newest task).
+        db.conn()
+            .execute_batch(&format!(
+                "INSERT INTO epics (id, project_id, title, updated_at) \
+                     VALUES ('eb', '{b}', 'B', '2024-01-15 00:00:00'); \
+                 INSERT INTO epics (id, project_id, title, updated_at) \
+                     VALUES ('ec', '{c}', 'C', '2024-01-03 00:00:00'); \
+                 INSERT INTO tasks (id, epic_id, title, updated_at) \
+                     VALUES ('tc', 'ec', 'T', '2024-03-01 00:00:00');",
+                b = projects[0].id,
+                c = projects[2].id,
+            ))
+            .unwrap();
+        projects
+    }
+
+    fn sorted_names(db: &Database, sort: ProjectSort) -> Vec<String> {
+        list_projects(db, None, sort)
+            .unwrap()
+            .into_iter()
+            .map(|p| p.name)
+            .collect()
+    }
+
+    #[test]
+    fn test_list_sort_orders() {
+        let (db, _dir) = open_temp_db();
+        seed_sort_projects(&db);
+
+        assert_eq!(sorted_names(&db, ProjectSort::Name), ["Alpha", "bravo", "charlie"]);
+        assert_eq!(sorted_names(&db, ProjectSort::Created), ["charlie", "Alpha", "bravo"]);
+        assert_eq!(sorted_names(&db, ProjectSort::Updated), ["Alpha", "charlie", "bravo"]);
+        assert_eq!(sorted_names(&db, ProjectSort::Activity), ["charlie", "Alpha", "bravo"]);
+    }
+
+    #[test]
+    fn test_list_activity_sort_follows_epic_changes() {
+        let (db, _dir) = open_temp_db();
+        let projects = seed_sort_projects(&db);
+        db.conn()
+            .execute("UPDATE epics SET updated_at = '2024-04-01 00:00:00' WHERE id = 'eb'", [])
+            .unwrap();
+
+        assert_eq!(sorted_names(&db, ProjectSort::Activity), ["bravo", "charlie", "Alpha"]);
+        // The status filter still applies.
+        update_project(
+            &db,
+            &projects[0].id,
+            UpdateProjectInput {
+                status: Some(ProjectStatus::Archived),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let active: Vec<String> = list_projects(&db, Some(ProjectStatus::Active), ProjectSort::Activity)
+            .unwrap()
+            .into_iter()
+            .map(|p| p.name)
+            .collect();
+        assert_eq!(active, ["charlie", "Alpha"]);
+    }
+
     #[test]
     fn test_list_without_filter() {
         let (db, _dir) = open_temp_db();
@@ -184,7 +417,7 @@ mod tests {
             .unwrap();
         }
 
-        let all = list_projects(&db, None).unwrap();
+        let all = list_projects(&db, None, ProjectSort::Created).unwrap();
         assert_eq!(all.len(), 3);
     }
 
@@ -218,11 +451,11 @@ mod tests {
         )
         .unwrap();
 
-        let active = list_projects(&db, Some(ProjectStatus::Active)).unwrap();
+        let active = list_projects(&db, Some(ProjectStatus::Active), ProjectSort::Created).unwrap();
         assert_eq!(active.len(), 1);
         assert_eq!(active[0].id, p1.id);
 
-        let archived = list_projects(&db, Some(ProjectStatus::Archived)).unwrap();
+        let archived = list_projects(&db, Some(ProjectStatus::Archived), ProjectSort::Created).unwrap();
         assert_eq!(archived.len(), 1);
         assert_eq!(archived[0].id, p2.id);
     }
@@ -359,4 +592,115 @@ mod tests {
         assert!(delete_project(&db, &project.id).unwrap());
         assert!(get_project(&db, &project.id).unwrap().is_none());
     }
+
+    #[test]
+    fn test_reset_project_tasks_leaves_other_projects_and_logs_history() {
+        use crate::db::epic::{create_epic, get_epic, update_epic};
+        use crate::db::task::{create_task, get_task, update_task};
+        use crate::models::{CreateEpicInput, CreateTaskInput, UpdateEpicInput, UpdateTaskInput};
+
+        let (db, _dir) = open_temp_db();
+        let mut tasks = Vec::new();
+        let mut epics = Vec::new();
+        for name in ["Reset", "Other"] {
+            let project = create_project(
+                &db,
+                CreateProjectInput {
+                    name: name.to_string(),
+                    description: String::new(),
+                },
+            )
+            .unwrap();
+            let epic = create_epic(
+                &db,
+                CreateEpicInput {
+                    project_id: project.id,
+                    title: "E".to_string(),
+                    description: String::new(),
+                    prd_id: None,
+                },
+                None,
+            )
+            .unwrap();
+            for status in [ItemStatus::Done, ItemStatus::InProgress, ItemStatus::Todo] {
+                let task = create_task(
+                    &db,
+                    CreateTaskInput {
+                        epic_id: epic.id.clone(),
+                        title: "T".to_string(),
+                        description: String::new(),
+                        session_id: None,
+                        assignee: None,
+                    },
+                )
+                .unwrap();
+                let input = UpdateTaskInput { status: Some(status), ..Default::default() };
+                tasks.push(update_task(&db, &task.id, input, None).unwrap());
+            }
+            let input = UpdateEpicInput {
+                status: Some(ItemStatus::InProgress),
+                ..Default::default()
+            };
+            epics.push(update_epic(&db, &epic.id, input, None).unwrap());
+        }
+        let project_id = epics[0].project_id.clone();
+        let history_rows = || -> i64 {
+            db.conn()
+                .query_row("SELECT COUNT(*) FROM status_history", [], |row| row.get(0))
+                .unwrap()
+        };
+        let logged = history_rows();
+
+        // Epics are left alone unless asked for or kept in sync automatically.
+        let counts = reset_project_tasks(&db, &project_id, false, None).unwrap();
+        assert_eq!(counts, ResetCounts { tasks: 2, epics: 0 });
+        assert_eq!(history_rows(), logged + 2);
+        for task in &tasks[..3] {
+            assert_eq!(get_task(&db, &task.id).unwrap().unwrap().status, ItemStatus::Todo);
+        }
+        assert_eq!(get_task(&db, &tasks[3].id).unwrap().unwrap().status, ItemStatus::Done);
+        let epic = |i: usize| get_epic(&db, &epics[i].id).unwrap().unwrap().status;
+        assert_eq!(epic(0), ItemStatus::InProgress);
+
+        db.set_auto_epic_status(true);
+        assert_eq!(
+            reset_project_tasks(&db, &project_id, false, None).unwrap(),
+            ResetCounts { tasks: 0, epics: 1 }
+        );
+        assert_eq!(epic(0), ItemStatus::Todo);
+        assert_eq!(epic(1), ItemStatus::InProgress);
+    }
+
+    #[test]
+    fn test_resolve_project_fuzzy() {
+        let (db, _dir) = open_temp_db();
+        let mut ids = Vec::new();
+        for name in ["Blueprint", "blueprint", "Blueprint Docs", "Website Redesign"] {
+            let project = create_project(
+                &db,
+                CreateProjectInput {
+                    name: name.to_string(),
+                    description: String::new(),
+                },
+            )
+            .unwrap();
+            ids.push(project.id);
+        }
+        let resolve = |query: &str| resolve_project_fuzzy(&db, query).map(|p| p.name);
+        assert_eq!(resolve(&ids[3]).unwrap(), "Website Redesign");
+
+        // Exact beats the case-insensitive and substring matches.
+        assert_eq!(resolve("blueprint").unwrap(), "blueprint");
+        assert_eq!(resolve("Blueprint").unwrap(), "Blueprint");
+        assert_eq!(resolve("BLUEPRINT DOCS").unwrap(), "Blueprint Docs");
+        assert_eq!(resolve("redesign").unwrap(), "Website Redesign");
+
+        let err = resolve("BLUEPRINT").unwrap_err().to_string();
+        assert!(err.contains("matches 2 projects: "), "{err}");
+        assert!(err.contains("Blueprint") && err.contains("blueprint"), "{err}");
+        let err = resolve("print").unwrap_err().to_string();
+        assert!(err.contains("matches 3 projects"), "{err}");
+        let err = resolve("Mobile").unwrap_err().to_string();
+        assert_eq!(err, "project not found: Mobile");
+    }
 }
@@ -5,25 +5,92 @@ pub(crate) enum IdKind {
     Ulid,
 }
 
+/// True when `segment` is `letter` followed by one or more digits, e.g. `E12`.
+fn is_numbered(segment: &str, letter: char) -> bool {
+    segment
+        .strip_prefix(letter)
+        .is_some_and(|n| !n.is_empty() && n.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Classify an ID as an epic short ID (`E1`), task short ID (`E1-T3`) or
+/// anything else (treated as a ULID). Short IDs may carry a project prefix
+/// (`PROJ-E1`, `PROJ-E1-T3`); matching is case-insensitive.
 pub(crate) fn classify_id(input: &str) -> IdKind {
     let upper = input.to_uppercase();
-    if let Some(rest) = upper.strip_prefix('E') {
-        if let Some(dash_pos) = rest.find("-T") {
-            let epic_num = &rest[..dash_pos];
-            let task_num = &rest[dash_pos + 2..];
-            if !epic_num.is_empty()
-                && epic_num.chars().all(|c| c.is_ascii_digit())
-                && !task_num.is_empty()
-                && task_num.chars().all(|c| c.is_ascii_digit())
-            {
-                return IdKind::TaskShortId;
-            }
-        }
-        if !rest.is_empty() && rest.chars().all(|c| c.is_ascii_digit()) {
-            return IdKind::EpicShortId;
+    let segments: Vec<&str> = upper.split('-').collect();
+
+    let unprefixed = match segments.as_slice() {
+        [first, rest @ ..]
+            if !rest.is_empty()
+                && !is_numbered(first, 'E')
+                && !first.is_empty()
+                && first.chars().all(|c| c.is_ascii_alphanumeric()) =>
+        {
+            rest
         }
+        all => all,
+    };
+
+    match unprefixed {
+        [epic] if is_numbered(epic, 'E') => IdKind::EpicShortId,
+        [epic, task] if is_numbered(epic, 'E') && is_numbered(task, 'T') => IdKind::TaskShortId,
+        _ => IdKind::Ulid,
     }
-    IdKind::Ulid
+}
+
+/// The one ID a short ID resolved to, `None` when nothing matched, or a
+/// `CONFLICT` error listing every candidate when it matched items in
+/// several projects.
+pub(crate) fn single_match(
+    ids: Vec<String>,
+    id_or_short: &str,
+    kind: &str,
+) -> anyhow::Result<Option<String>> {
+    match ids.as_slice() {
+        [] => Ok(None),
+        [single] => Ok(Some(single.clone())),
+        multiple => anyhow::bail!(
+            "CONFLICT: Ambiguous short ID '{}' matches {} {kind}s across projects: {}. \
+             Pass project_id or use the full ID.",
+            id_or_short,
+            multiple.len(),
+            multiple.join(", ")
+        ),
+    }
+}
+
+/// Validate and normalize a project short-ID prefix: 1–10 ASCII letters or
+/// digits, stored uppercase. An empty string means "no prefix".
+pub(crate) fn normalize_short_id_prefix(prefix: &str) -> anyhow::Result<Option<String>> {
+    let trimmed = prefix.trim();
+    if trimmed.is_empty() {
+        return Ok(None);
+    }
+    let upper = trimmed.to_uppercase();
+    anyhow::ensure!(
+        upper.len() <= 10
+            && upper.chars().all(|c| c.is_ascii_alphanumeric())
+            && !is_numbered(&upper, 'E'),
+        "invalid short_id_prefix '{prefix}': use 1-10 letters or digits"
+    );
+    Ok(Some(upper))
+}
+
+/// Format an epic short ID, honoring an optional project prefix.
+pub(crate) fn format_epic_short_id(prefix: Option<&str>, number: i64) -> String {
+    match prefix {
+        Some(p) => format!("{p}-E{number}"),
+        None => format!("E{number}"),
+    }
+}
+
+/// Extract the epic number from a short ID such as `E3` or `PROJ-E3`.
+pub(crate) fn epic_short_id_number(short_id: &str) -> Option<i64> {
+    short_id
+        .rsplit('-')
+        .next()
+        .and_then(|s| s.strip_prefix('E'))
+        .and_then(|n| n.parse().ok())
 }
 
 #[cfg(test)]
@@ -52,6 +119,34 @@ mod tests {
         assert_eq!(classify_id("some-random-string"), IdKind::Ulid);
     }
 
+    #[test]
+    fn test_classify_prefixed_short_ids() {
+        assert_eq!(classify_id("PROJ-E1"), IdKind::EpicShortId);
+        assert_eq!(classify_id("proj-e1-t2"), IdKind::TaskShortId);
+        assert_eq!(classify_id("A1-E10-T42"), IdKind::TaskShortId);
+        assert_eq!(classify_id("PROJ-E"), IdKind::Ulid);
+        assert_eq!(classify_id("PROJ-T1"), IdKind::Ulid);
+        assert_eq!(classify_id("A-B-E1"), IdKind::Ulid);
+    }
+
+    #[test]
+    fn test_normalize_short_id_prefix() {
+        assert_eq!(normalize_short_id_prefix("proj").unwrap(), Some("PROJ".to_string()));
+        assert_eq!(normalize_short_id_prefix("  ").unwrap(), None);
+        assert!(normalize_short_id_prefix("MY-TEAM").is_err());
+        assert!(normalize_short_id_prefix("WAYTOOLONGPREFIX").is_err());
+        assert!(normalize_short_id_prefix("e2").is_err(), "would be ambiguous with epic IDs");
+    }
+
+    #[test]
+    fn test_epic_short_id_format_and_number() {
+        assert_eq!(format_epic_short_id(None, 3), "E3");
+        assert_eq!(format_epic_short_id(Some("PROJ"), 3), "PROJ-E3");
+        assert_eq!(epic_short_id_number("E12"), Some(12));
+        assert_eq!(epic_short_id_number("PROJ-E7"), Some(7));
+        assert_eq!(epic_short_id_number("garbage"), None);
+    }
+
     #[test]
     fn test_classify_edge_cases() {
         assert_eq!(classify_id("E"), IdKind::Ulid);
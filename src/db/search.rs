@@ -0,0 +1,478 @@
+use anyhow::{Context, Result};
+use rusqlite::params_from_iter;
+use serde::Serialize;
+
+use crate::db::Database;
+use crate::models::{DependencyType, ItemStatus};
+
+/// A parsed search query. Every populated criterion must match (AND), except
+/// that repeated `status:` or `type:` terms match any of their values (OR).
+///
+/// Syntax: whitespace-separated terms, where `field:value` scopes a term to
+/// `title`, `description`, `status` or `type`. Anything else — including
+/// unknown fields and invalid status/type values — is matched as free text
+/// against both title and description.
+#[derive(Debug, Default, PartialEq)]
+pub struct SearchQuery {
+    pub free_terms: Vec<String>,
+    pub title_terms: Vec<String>,
+    pub description_terms: Vec<String>,
+    pub statuses: Vec<ItemStatus>,
+    pub item_types: Vec<DependencyType>,
+}
+
+impl SearchQuery {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct SearchHit {
+    #[serde(rename = "type")]
+    pub item_type: DependencyType,
+    pub id: String,
+    pub short_id: Option<String>,
+    pub epic_id: Option<String>,
+    pub title: String,
+    pub status: ItemStatus,
+    /// Kept for [`description_snippet`]; not part of the result.
+    #[serde(skip)]
+    pub description: String,
+    /// Excerpt of the description around a matched term, when requested.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub snippet: Option<String>,
+}
+
+/// Characters of description kept on each side of the match in a snippet.
+pub const SNIPPET_CONTEXT: usize = 30;
+/// Markers placed around the matched text in a snippet.
+pub const SNIPPET_MARKERS: (&str, &str) = ("**", "**");
+
+pub fn parse_query(input: &str) -> SearchQuery {
+    let mut query = SearchQuery::default();
+
+    for token in input.split_whitespace() {
+        let scoped = token
+            .split_once(':')
+            .filter(|(_, value)| !value.is_empty());
+
+        let matched = match scoped {
+            Some(("title", value)) => {
+                query.title_terms.push(value.to_string());
+                true
+            }
+            Some(("description", value)) => {
+                query.description_terms.push(value.to_string());
+                true
+            }
+            Some(("status", value)) => value
+                .parse::<ItemStatus>()
+                .map(|s| query.statuses.push(s))
+                .is_ok(),
+            Some(("type", value)) => value
+                .parse::<DependencyType>()
+                .map(|t| query.item_types.push(t))
+                .is_ok(),
+            _ => false,
+        };
+        if !matched {
+            query.free_terms.push(token.to_string());
+        }
+    }
+
+    query
+}
+
+/// An excerpt of `description` centered on the first place a description or
+/// free-text term of `query` occurs, with the match wrapped in
+/// [`SNIPPET_MARKERS`] and at most [`SNIPPET_CONTEXT`] characters on either
+/// side. Cut ends are marked with `…` and line breaks become spaces. `None`
+/// when no term occurs in the description.
+///
+/// Matching ignores ASCII case, like the `LIKE` the search itself uses.
+pub fn description_snippet(description: &str, query: &SearchQuery) -> Option<String> {
+    let text: Vec<char> = description
+        .chars()
+        .map(|c| if c.is_whitespace() { ' ' } else { c })
+        .collect();
+    let (start, len) = query
+        .description_terms
+        .iter()
+        .chain(&query.free_terms)
+        .filter_map(|term| {
+            let needle: Vec<char> = term.chars().collect();
+            let at = text.windows(needle.len()).position(|w| {
+                w.iter().zip(&needle).all(|(a, b)| a.eq_ignore_ascii_case(b))
+            })?;
+            Some((at, needle.len()))
+        })
+        .min()?;
+
+    let from = start.saturating_sub(SNIPPET_CONTEXT);
+    let to = (start + len + SNIPPET_CONTEXT).min(text.len());
+    let slice = |a: usize, b: usize| text[a..b].iter().collect::<String>();
+    let (open, close) = SNIPPET_MARKERS;
+    Some(format!(
+        "{}{}{open}{}{close}{}{}",
+        if from > 0 { "…" } else { "" },
+        slice(from, start),
+        slice(start, start + len),
+        slice(start + len, to),
+        if to < text.len() { "…" } else { "" },
+    ))
+}
+
+/// Escape `LIKE` wildcards and wrap the term for a substring match.
+fn like_pattern(term: &str) -> String {
+    let escaped = term
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{escaped}%")
+}
+
+/// Build the WHERE conditions for `query` against a table aliased as `alias`.
+fn build_conditions(
+    query: &SearchQuery,
+    alias: &str,
+    params: &mut Vec<Box<dyn rusqlite::types::ToSql>>,
+) -> Vec<String> {
+    let mut conditions = Vec::new();
+
+    for term in &query.free_terms {
+        params.push(Box::new(like_pattern(term)));
+        let n = params.len();
+        conditions.push(format!(
+            "({alias}.title LIKE ?{n} ESCAPE '\\' OR {alias}.description LIKE ?{n} ESCAPE '\\')"
+        ));
+    }
+    for term in &query.title_terms {
+        params.push(Box::new(like_pattern(term)));
+        conditions.push(format!("{alias}.title LIKE ?{} ESCAPE '\\'", params.len()));
+    }
+    for term in &query.description_terms {
+        params.push(Box::new(like_pattern(term)));
+        conditions.push(format!("{alias}.description LIKE ?{} ESCAPE '\\'", params.len()));
+    }
+    if !query.statuses.is_empty() {
+        let placeholders: Vec<String> = query
+            .statuses
+            .iter()
+            .map(|status| {
+                params.push(Box::new(status.as_str().to_string()));
+                format!("?{}", params.len())
+            })
+            .collect();
+        conditions.push(format!("{alias}.status IN ({})", placeholders.join(", ")));
+    }
+
+    conditions
+}
+
+fn run_search(
+    db: &Database,
+    base_sql: &str,
+    alias: &str,
+    project_id: Option<&str>,
+    query: &SearchQuery,
+    item_type: DependencyType,
+) -> Result<Vec<SearchHit>> {
+    let mut params: Vec<Box<dyn rusqlite::types::ToSql>> = Vec::new();
+    let mut conditions = Vec::new();
+
+    if let Some(pid) = project_id {
+        params.push(Box::new(pid.to_string()));
+        conditions.push(format!("e.project_id = ?{}", params.len()));
+    }
+    conditions.extend(build_conditions(query, alias, &mut params));
+
+    let sql = if conditions.is_empty() {
+        format!("{base_sql} ORDER BY {alias}.created_at DESC")
+    } else {
+        format!(
+            "{base_sql} WHERE {} ORDER BY {alias}.created_at DESC",
+            conditions.join(" AND ")
+        )
+    };
+
+    let conn = db.conn();
+    let mut stmt = conn.prepare(&sql)?;
+    let rows = stmt.query_map(params_from_iter(params.iter()), |row| {
+        let status_str: String = row.get("status")?;
+        let status = status_str.parse::<ItemStatus>().map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(
+                0,
+                rusqlite::types::Type::Text,
+                e.into(),
+            )
+        })?;
+        Ok(SearchHit {
+            item_type: item_type.clone(),
+            id: row.get("id")?,
+            short_id: row.get("short_id")?,
+            epic_id: row.get("epic_id")?,
+            title: row.get("title")?,
+            status,
+            description: row.get("description")?,
+            snippet: None,
+        })
+    })?;
+
+    rows.collect::<rusqlite::Result<Vec<_>>>()
+        .with_context(|| format!("failed to search {item_type}s"))
+}
+
+/// Search epics and tasks matching every criterion in `query`, optionally
+/// restricted to one project. Epics are listed before tasks.
+pub fn search(
+    db: &Database,
+    project_id: Option<&str>,
+    query: &SearchQuery,
+) -> Result<Vec<SearchHit>> {
+    let mut hits = Vec::new();
+    let wants = |t: &DependencyType| query.item_types.is_empty() || query.item_types.contains(t);
+
+    if wants(&DependencyType::Epic) {
+        hits.extend(run_search(
+            db,
+            "SELECT e.id, e.short_id, NULL AS epic_id, e.title, e.status, e.description \
+             FROM epics e",
+            "e",
+            project_id,
+            query,
+            DependencyType::Epic,
+        )?);
+    }
+    if wants(&DependencyType::Task) {
+        hits.extend(run_search(
+            db,
+            "SELECT t.id, t.short_id, t.epic_id, t.title, t.status, t.description \
+             FROM tasks t JOIN epics e ON t.epic_id = e.id",
+            "t",
+            project_id,
+            query,
+            DependencyType::Task,
+        )?);
+    }
+
+    Ok(hits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::epic::create_epic;
+    use crate::db::project::create_project;
+    use crate::db::task::{create_task, update_task};
+    use crate::models::{CreateEpicInput, CreateProjectInput, CreateTaskInput, UpdateTaskInput};
+    use tempfile::TempDir;
+
+    fn open_temp_db() -> (Database, TempDir) {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("test.db");
+        let db = Database::open(&path).unwrap();
+        db.migrate().unwrap();
+        (db, dir)
+    }
+
+    /// Seeds one project with an "Auth" epic holding three tasks.
+    fn seed(db: &Database) -> String {
+        let project = create_project(
+            db,
+            CreateProjectInput {
+                name: "P".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let epic = create_epic(
+            db,
+            CreateEpicInput {
+                project_id: project.id.clone(),
+                title: "Auth overhaul".to_string(),
+                description: "Backend and frontend auth work".to_string(),
+                prd_id: None,
+            },
+            None,
+        )
+        .unwrap();
+        for (title, description) in [
+            ("Auth token refresh", "backend service"),
+            ("Login form", "frontend auth screen"),
+            ("Rate limiting", "backend middleware"),
+        ] {
+            create_task(
+                db,
+                CreateTaskInput {
+                    epic_id: epic.id.clone(),
+                    title: title.to_string(),
+                    description: description.to_string(),
+                    session_id: None,
+                    assignee: None,
+                },
+            )
+            .unwrap();
+        }
+        project.id
+    }
+
+    fn titles(hits: &[SearchHit]) -> Vec<&str> {
+        let mut titles: Vec<&str> = hits.iter().map(|h| h.title.as_str()).collect();
+        titles.sort();
+        titles
+    }
+
+    #[test]
+    fn test_parse_query_mixed_terms() {
+        let q = parse_query("title:auth status:todo backend foo:bar status:bogus type:task");
+        assert_eq!(q.title_terms, vec!["auth"]);
+        assert_eq!(q.statuses, vec![ItemStatus::Todo]);
+        assert_eq!(q.free_terms, vec!["backend", "foo:bar", "status:bogus"]);
+        assert_eq!(q.item_types, vec![DependencyType::Task]);
+        assert!(parse_query("   ").is_empty());
+    }
+
+    #[test]
+    fn test_search_multi_term_and() {
+        let (db, _dir) = open_temp_db();
+        let pid = seed(&db);
+
+        let hits = search(&db, Some(&pid), &parse_query("auth backend")).unwrap();
+        assert_eq!(titles(&hits), vec!["Auth overhaul", "Auth token refresh"]);
+    }
+
+    #[test]
+    fn test_search_field_scoped_term() {
+        let (db, _dir) = open_temp_db();
+        let pid = seed(&db);
+
+        // "auth" appears in the Login form description but not its title.
+        let hits = search(&db, Some(&pid), &parse_query("title:auth")).unwrap();
+        assert_eq!(titles(&hits), vec!["Auth overhaul", "Auth token refresh"]);
+
+        let hits = search(&db, Some(&pid), &parse_query("description:auth type:task")).unwrap();
+        assert_eq!(titles(&hits), vec!["Login form"]);
+    }
+
+    #[test]
+    fn test_search_mixed_query() {
+        let (db, _dir) = open_temp_db();
+        db.set_auto_epic_status(true);
+        let pid = seed(&db);
+
+        let rate = search(&db, Some(&pid), &parse_query("title:rate")).unwrap();
+        update_task(
+            &db,
+            &rate[0].id,
+            UpdateTaskInput {
+                status: Some(ItemStatus::InProgress),
+                ..Default::default()
+            },
+            None,
+        )
+        .unwrap();
+
+        let hits = search(&db, Some(&pid), &parse_query("status:todo backend type:task")).unwrap();
+        assert_eq!(titles(&hits), vec!["Auth token refresh"]);
+        assert_eq!(hits[0].item_type, DependencyType::Task);
+        assert!(hits[0].epic_id.is_some());
+
+        // Starting a task moves its epic to in_progress too.
+        let hits = search(&db, Some(&pid), &parse_query("status:in_progress backend")).unwrap();
+        assert_eq!(titles(&hits), vec!["Auth overhaul", "Rate limiting"]);
+    }
+
+    #[test]
+    fn test_search_repeated_status_terms_match_any() {
+        let (db, _dir) = open_temp_db();
+        let pid = seed(&db);
+
+        let rate = search(&db, Some(&pid), &parse_query("title:rate")).unwrap();
+        update_task(
+            &db,
+            &rate[0].id,
+            UpdateTaskInput {
+                status: Some(ItemStatus::Done),
+                ..Default::default()
+            },
+            None,
+        )
+        .unwrap();
+
+        let query = parse_query("status:todo status:done type:task");
+        assert_eq!(query.statuses, vec![ItemStatus::Todo, ItemStatus::Done]);
+        let hits = search(&db, Some(&pid), &query).unwrap();
+        assert_eq!(titles(&hits), vec!["Auth token refresh", "Login form", "Rate limiting"]);
+
+        let hits = search(&db, Some(&pid), &parse_query("status:done")).unwrap();
+        assert_eq!(titles(&hits), vec!["Rate limiting"]);
+    }
+
+    #[test]
+    fn test_search_repeated_type_terms_match_any() {
+        let (db, _dir) = open_temp_db();
+        let pid = seed(&db);
+
+        let query = parse_query("title:auth type:task type:epic");
+        assert_eq!(query.item_types, vec![DependencyType::Task, DependencyType::Epic]);
+        let hits = search(&db, Some(&pid), &query).unwrap();
+        assert_eq!(titles(&hits), vec!["Auth overhaul", "Auth token refresh"]);
+
+        let hits = search(&db, Some(&pid), &parse_query("title:auth type:epic type:epic")).unwrap();
+        assert_eq!(titles(&hits), vec!["Auth overhaul"]);
+    }
+
+    #[test]
+    fn test_search_escapes_like_wildcards() {
+        let (db, _dir) = open_temp_db();
+        let pid = seed(&db);
+
+        assert!(search(&db, Some(&pid), &parse_query("%")).unwrap().is_empty());
+        assert!(search(&db, Some(&pid), &parse_query("title:_")).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_search_scoped_to_project() {
+        let (db, _dir) = open_temp_db();
+        seed(&db);
+        let other = seed(&db);
+
+        assert_eq!(search(&db, Some(&other), &parse_query("login")).unwrap().len(), 1);
+        assert_eq!(search(&db, None, &parse_query("login")).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn snippet_centers_on_the_match_and_is_bounded() {
+        let description = format!(
+            "{}Supports OAuth\nrefresh flows{}",
+            "a".repeat(100),
+            " and more text".repeat(10)
+        );
+        let snippet = description_snippet(&description, &parse_query("oauth")).unwrap();
+        let context = "a".repeat(SNIPPET_CONTEXT - "Supports ".len());
+        assert!(snippet.starts_with(&format!("…{context}Supports **OAuth** refresh flows")));
+        assert!(snippet.ends_with('…'));
+        let (before, after) = snippet.split_once("**OAuth**").unwrap();
+        assert_eq!(before.chars().count(), SNIPPET_CONTEXT + 1);
+        assert_eq!(after.chars().count(), SNIPPET_CONTEXT + 1);
+
+        // Short descriptions are kept whole, the earliest term wins and
+        // scoped title terms never produce a snippet.
+        let query = parse_query("title:login backend description:form");
+        assert_eq!(
+            description_snippet("Login form for the backend", &query).unwrap(),
+            "Login **form** for the backend"
+        );
+        assert!(description_snippet("Login form", &parse_query("title:login")).is_none());
+        assert!(description_snippet("", &parse_query("auth")).is_none());
+    }
+
+    #[test]
+    fn snippet_handles_multibyte_text() {
+        // Non-ASCII letters only match in the same case, like LIKE.
+        assert!(description_snippet("Ünïcode naïve café", &parse_query("CAFÉ")).is_none());
+        let snippet = description_snippet("Ünïcode naïve café", &parse_query("naïve")).unwrap();
+        assert_eq!(snippet, "Ünïcode **naïve** café");
+    }
+}
@@ -0,0 +1,278 @@
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::db::Database;
+use crate::db::dependency::insert_dependency;
+use crate::db::epic::{insert_epic, sync_epic_status_in};
+use crate::db::history::record_status_change;
+use crate::db::project::insert_project;
+use crate::db::task::insert_task;
+use crate::models::{
+    AddDependencyInput, CreateEpicInput, CreateProjectInput, CreateTaskInput, DependencyType,
+    ItemStatus,
+};
+
+/// Name of the sample project. Seeding refuses to run when a project with
+/// this name already exists, so it is safe to call repeatedly.
+pub const DEMO_PROJECT_NAME: &str = "Blueprint Demo (sample data)";
+
+#[derive(Debug, Serialize)]
+pub struct SeedSummary {
+    pub project_id: String,
+    pub epics: usize,
+    pub tasks: usize,
+    pub dependencies: usize,
+}
+
+/// A demo epic: title, description and its tasks with their target status.
+type DemoEpic = (&'static str, &'static str, &'static [(&'static str, ItemStatus)]);
+
+/// Position of a demo task as (epic index, task index) into [`DEMO_EPICS`].
+type DemoTaskRef = (usize, usize);
+
+const DEMO_EPICS: &[DemoEpic] = &[
+    (
+        "Foundation",
+        "Repository, CI and tooling every other epic builds on.",
+        &[
+            ("Set up repository", ItemStatus::Done),
+            ("Configure CI pipeline", ItemStatus::Done),
+        ],
+    ),
+    (
+        "Authentication",
+        "Email/password login with server-side sessions.",
+        &[
+            ("Design auth schema", ItemStatus::Done),
+            ("Implement login API", ItemStatus::InProgress),
+            ("Build login form", ItemStatus::Todo),
+            ("Add session expiry", ItemStatus::Todo),
+        ],
+    ),
+    (
+        "Dashboard",
+        "Landing page summarising project health.",
+        &[
+            ("Sketch dashboard layout", ItemStatus::Todo),
+            ("Wire up status widgets", ItemStatus::Todo),
+        ],
+    ),
+    (
+        "Launch",
+        "Everything needed to ship v1.",
+        &[("Write release notes", ItemStatus::Todo)],
+    ),
+];
+
+/// Task dependencies as blocker -> blocked pairs.
+const DEMO_TASK_DEPS: &[(DemoTaskRef, DemoTaskRef)] = &[
+    ((1, 0), (1, 1)),
+    ((1, 1), (1, 2)),
+    ((1, 1), (1, 3)),
+    ((2, 0), (2, 1)),
+];
+
+pub fn demo_project_exists(db: &Database) -> Result<bool> {
+    db.conn()
+        .prepare("SELECT 1 FROM projects WHERE name = ?1")?
+        .exists([DEMO_PROJECT_NAME])
+        .context("failed to check for demo project")
+}
+
+/// Create a sample project with epics, tasks in mixed statuses and a
+/// chain of dependencies, all in one transaction. Errors if the demo
+/// project already exists.
+pub fn seed_demo_data(db: &Database) -> Result<SeedSummary> {
+    if demo_project_exists(db)? {
+        anyhow::bail!("demo data already seeded: project \"{DEMO_PROJECT_NAME}\" exists");
+    }
+
+    let project_id = ulid::Ulid::new().to_string();
+    let project = CreateProjectInput {
+        name: DEMO_PROJECT_NAME.to_string(),
+        description: "Sample project to explore Blueprint. Safe to delete.".to_string(),
+    };
+
+    let mut epic_ids = Vec::new();
+    let mut task_ids: Vec<Vec<String>> = Vec::new();
+    let mut dependencies = 0;
+    db.write_transaction("demo data seeding", |tx| {
+        epic_ids.clear();
+        task_ids.clear();
+        dependencies = 0;
+        insert_project(tx, &project_id, &project)?;
+
+        for (title, description, tasks) in DEMO_EPICS {
+            let epic_id = ulid::Ulid::new().to_string();
+            let epic = CreateEpicInput {
+                project_id: project_id.clone(),
+                title: title.to_string(),
+                description: description.to_string(),
+                prd_id: None,
+            };
+            insert_epic(tx, &epic_id, &epic, None)?;
+
+            let mut ids = Vec::new();
+            for (task_title, status) in *tasks {
+                let task_id = ulid::Ulid::new().to_string();
+                let task = CreateTaskInput {
+                    epic_id: epic_id.clone(),
+                    title: task_title.to_string(),
+                    description: String::new(),
+                    session_id: None,
+                    assignee: None,
+                };
+                insert_task(tx, db, &task_id, &task, None)?;
+                if *status != ItemStatus::Todo {
+                    record_status_change(tx, &DependencyType::Task, &task_id, status, None)?;
+                    tx.execute(
+                        "UPDATE tasks SET status = ?1 WHERE id = ?2",
+                        [status.as_str(), &task_id],
+                    )
+                    .context("failed to set demo task status")?;
+                }
+                ids.push(task_id);
+            }
+            // Give each epic the status its tasks imply, whatever the auto rule says.
+            sync_epic_status_in(tx, &epic_id)?;
+
+            epic_ids.push(epic_id);
+            task_ids.push(ids);
+        }
+
+        // Epics form a single chain: Foundation -> Authentication -> Dashboard -> Launch.
+        for pair in epic_ids.windows(2) {
+            let dependency = AddDependencyInput {
+                blocker_type: DependencyType::Epic,
+                blocker_id: pair[0].clone(),
+                blocked_type: DependencyType::Epic,
+                blocked_id: pair[1].clone(),
+            };
+            insert_dependency(tx, &dependency, None)?;
+            dependencies += 1;
+        }
+
+        for &((be, bt), (de, dt)) in DEMO_TASK_DEPS {
+            let dependency = AddDependencyInput {
+                blocker_type: DependencyType::Task,
+                blocker_id: task_ids[be][bt].clone(),
+                blocked_type: DependencyType::Task,
+                blocked_id: task_ids[de][dt].clone(),
+            };
+            insert_dependency(tx, &dependency, None)?;
+            dependencies += 1;
+        }
+        Ok(())
+    })?;
+
+    Ok(SeedSummary {
+        project_id,
+        epics: epic_ids.len(),
+        tasks: task_ids.iter().map(Vec::len).sum(),
+        dependencies,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::dependency::get_blockers;
+    use crate::db::status::{count_epics_by_status, count_tasks_by_status, get_blocked_items};
+    use crate::models::EpicListFilter;
+    use tempfile::TempDir;
+
+    fn open_temp_db() -> (Database, TempDir) {
+        let dir = TempDir::new().unwrap();
+        let path = dir.path().join("test.db");
+        let db = Database::open(&path).unwrap();
+        db.migrate().unwrap();
+        (db, dir)
+    }
+
+    #[test]
+    fn test_seed_demo_data_structure() {
+        let (db, _dir) = open_temp_db();
+        let summary = seed_demo_data(&db).unwrap();
+
+        assert_eq!(summary.epics, 4);
+        assert_eq!(summary.tasks, 9);
+        assert_eq!(summary.dependencies, 7);
+
+        let pid = Some(summary.project_id.as_str());
+        let epics = count_epics_by_status(&db, pid).unwrap();
+        assert_eq!(epics["done"], 1);
+        assert_eq!(epics["in_progress"], 1);
+        assert_eq!(epics["todo"], 2);
+
+        let tasks = count_tasks_by_status(&db, pid).unwrap();
+        assert_eq!(tasks["done"], 3);
+        assert_eq!(tasks["in_progress"], 1);
+        assert_eq!(tasks["todo"], 5);
+
+        assert!(!get_blocked_items(&db, pid).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_seed_demo_data_has_multi_layer_chain() {
+        let (db, _dir) = open_temp_db();
+        let summary = seed_demo_data(&db).unwrap();
+
+        // Walk blockers back from the last epic; each hop is one graph layer.
+        let epics =
+            crate::db::epic::list_epics(
+                &db,
+                &EpicListFilter {
+                    project_id: Some(&summary.project_id),
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        let launch = epics.iter().find(|e| e.title == "Launch").unwrap();
+        let mut current = launch.id.clone();
+        let mut depth = 0;
+        while let Some(dep) = get_blockers(&db, &DependencyType::Epic, &current)
+            .unwrap()
+            .into_iter()
+            .next()
+        {
+            current = dep.blocker_id;
+            depth += 1;
+        }
+        assert_eq!(depth, 3, "expected Foundation -> Authentication -> Dashboard -> Launch");
+    }
+
+    #[test]
+    fn test_seed_demo_data_refuses_double_seed() {
+        let (db, _dir) = open_temp_db();
+        seed_demo_data(&db).unwrap();
+
+        let err = seed_demo_data(&db).unwrap_err();
+        assert!(err.to_string().contains("already seeded"));
+
+        let projects = crate::db::project::list_projects(&db, None, Default::default()).unwrap();
+        assert_eq!(projects.len(), 1);
+    }
+
+    #[test]
+    fn test_seed_demo_data_failure_leaves_nothing_behind() {
+        let (db, _dir) = open_temp_db();
+        // Fail on the first dependency, after every project, epic and task row is written.
+        db.conn()
+            .execute_batch(
+                "CREATE TRIGGER fail_seed BEFORE INSERT ON dependencies \
+                 BEGIN SELECT RAISE(ABORT, 'forced failure'); END;",
+            )
+            .unwrap();
+
+        assert!(seed_demo_data(&db).is_err());
+        let count = |table: &str| -> i64 {
+            db.conn()
+                .query_row(&format!("SELECT COUNT(*) FROM {table}"), [], |row| row.get(0))
+                .unwrap()
+        };
+        for table in ["projects", "epics", "tasks", "status_history"] {
+            assert_eq!(count(table), 0, "{table} should be empty");
+        }
+        assert!(!demo_project_exists(&db).unwrap());
+    }
+}
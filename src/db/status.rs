@@ -7,10 +7,37 @@ use crate::db::Database;
 pub struct BlockedItemRow {
     pub item_type: String,
     pub item_id: String,
+    pub short_id: Option<String>,
     pub title: String,
+    pub status: String,
+    pub blocker_type: String,
     pub blocker_id: String,
 }
 
+/// Per-epic task counts for the `group_by: epic` status breakdown.
+pub struct EpicStatusRow {
+    pub epic_id: String,
+    pub short_id: Option<String>,
+    pub title: String,
+    pub status: String,
+    pub todo: i64,
+    pub in_progress: i64,
+    pub done: i64,
+    /// Tasks in this epic with at least one unfinished blocker.
+    pub blocked_tasks: i64,
+}
+
+/// A task left `in_progress` without updates for longer than a threshold.
+pub struct StaleTaskRow {
+    pub task_id: String,
+    pub short_id: Option<String>,
+    pub title: String,
+    pub epic_id: String,
+    pub updated_at: String,
+    /// Whole days since `updated_at`.
+    pub days_idle: i64,
+}
+
 pub struct DependencyDisplayRow {
     pub blocker_title: String,
     pub blocked_title: String,
@@ -48,7 +75,8 @@ fn count_by_status(
         None => (base_sql, vec![]),
     };
 
-    let mut stmt = db.conn().prepare(sql)?;
+    let conn = db.conn();
+    let mut stmt = conn.prepare(sql)?;
     let rows = stmt.query_map(rusqlite::params_from_iter(params.iter()), |row| {
         Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
     })?;
@@ -95,6 +123,93 @@ pub fn count_tasks_by_status(
     )
 }
 
+/// Task counts by status for every epic, keyed by epic ID, in a single grouped
+/// query. Epics without tasks report zero for all three statuses.
+pub fn count_tasks_by_status_per_epic(
+    db: &Database,
+    project_id: Option<&str>,
+) -> Result<HashMap<String, HashMap<String, i64>>> {
+    let (sql, params) = build_filtered_query(
+        "SELECT e.id, \
+                COALESCE(SUM(CASE WHEN t.status = 'todo' THEN 1 ELSE 0 END), 0), \
+                COALESCE(SUM(CASE WHEN t.status = 'in_progress' THEN 1 ELSE 0 END), 0), \
+                COALESCE(SUM(CASE WHEN t.status = 'done' THEN 1 ELSE 0 END), 0) \
+         FROM epics e LEFT JOIN tasks t ON t.epic_id = e.id",
+        " WHERE e.project_id = ?1 GROUP BY e.id",
+        " GROUP BY e.id",
+        project_id,
+    );
+
+    let conn = db.conn();
+    let mut stmt = conn.prepare(&sql)?;
+    let rows = stmt.query_map(rusqlite::params_from_iter(params.iter()), |row| {
+        Ok((
+            row.get::<_, String>(0)?,
+            row.get::<_, i64>(1)?,
+            row.get::<_, i64>(2)?,
+            row.get::<_, i64>(3)?,
+        ))
+    })?;
+
+    let mut map = HashMap::new();
+    for row in rows {
+        let (epic_id, todo, in_progress, done) =
+            row.context("failed to read per-epic task status counts")?;
+        map.insert(
+            epic_id,
+            HashMap::from([
+                ("todo".to_string(), todo),
+                ("in_progress".to_string(), in_progress),
+                ("done".to_string(), done),
+            ]),
+        );
+    }
+
+    Ok(map)
+}
+
+/// Status counts and blocked-task count for every epic, oldest first.
+pub fn get_epic_status_rows(
+    db: &Database,
+    project_id: Option<&str>,
+) -> Result<Vec<EpicStatusRow>> {
+    let (sql, params) = build_filtered_query(
+        "SELECT e.id, e.short_id, e.title, e.status, \
+                COALESCE(SUM(CASE WHEN t.status = 'todo' THEN 1 ELSE 0 END), 0), \
+                COALESCE(SUM(CASE WHEN t.status = 'in_progress' THEN 1 ELSE 0 END), 0), \
+                COALESCE(SUM(CASE WHEN t.status = 'done' THEN 1 ELSE 0 END), 0), \
+                COALESCE(SUM(CASE WHEN EXISTS ( \
+                    SELECT 1 FROM dependencies d \
+                    LEFT JOIN epics be ON d.blocker_type = 'epic' AND d.blocker_id = be.id \
+                    LEFT JOIN tasks bt ON d.blocker_type = 'task' AND d.blocker_id = bt.id \
+                    WHERE d.blocked_type = 'task' AND d.blocked_id = t.id \
+                      AND COALESCE(be.status, bt.status) != 'done' \
+                ) THEN 1 ELSE 0 END), 0) \
+         FROM epics e LEFT JOIN tasks t ON t.epic_id = e.id",
+        " WHERE e.project_id = ?1 GROUP BY e.id ORDER BY e.created_at, e.id",
+        " GROUP BY e.id ORDER BY e.created_at, e.id",
+        project_id,
+    );
+
+    let conn = db.conn();
+    let mut stmt = conn.prepare(&sql)?;
+    let rows = stmt.query_map(rusqlite::params_from_iter(params.iter()), |row| {
+        Ok(EpicStatusRow {
+            epic_id: row.get(0)?,
+            short_id: row.get(1)?,
+            title: row.get(2)?,
+            status: row.get(3)?,
+            todo: row.get(4)?,
+            in_progress: row.get(5)?,
+            done: row.get(6)?,
+            blocked_tasks: row.get(7)?,
+        })
+    })?;
+
+    rows.collect::<rusqlite::Result<Vec<_>>>()
+        .context("failed to query per-epic status")
+}
+
 /// SQL suffix to filter dependencies by project ownership.
 const PROJECT_FILTER_SUFFIX: &str = " \
     AND ( \
@@ -111,7 +226,9 @@ pub fn get_blocked_items(
         SELECT \
             d.blocked_type, d.blocked_id, \
             COALESCE(blocked_e.title, blocked_t.title) as title, \
-            d.blocker_id \
+            d.blocker_type, d.blocker_id, \
+            COALESCE(blocked_e.short_id, blocked_t.short_id) as short_id, \
+            COALESCE(blocked_e.status, blocked_t.status) as status \
         FROM dependencies d \
         LEFT JOIN epics blocker_e ON d.blocker_type = 'epic' AND d.blocker_id = blocker_e.id \
         LEFT JOIN tasks blocker_t ON d.blocker_type = 'task' AND d.blocker_id = blocker_t.id \
@@ -131,13 +248,17 @@ pub fn get_blocked_items(
         project_id,
     );
 
-    let mut stmt = db.conn().prepare(&sql)?;
+    let conn = db.conn();
+    let mut stmt = conn.prepare(&sql)?;
     let rows = stmt.query_map(rusqlite::params_from_iter(params.iter()), |row| {
         Ok(BlockedItemRow {
             item_type: row.get(0)?,
             item_id: row.get(1)?,
+            short_id: row.get(5)?,
             title: row.get(2)?,
-            blocker_id: row.get(3)?,
+            status: row.get(6)?,
+            blocker_type: row.get(3)?,
+            blocker_id: row.get(4)?,
         })
     })?;
 
@@ -173,7 +294,8 @@ pub fn get_dependency_display_rows(
         project_id,
     );
 
-    let mut stmt = db.conn().prepare(&sql)?;
+    let conn = db.conn();
+    let mut stmt = conn.prepare(&sql)?;
     let rows = stmt.query_map(rusqlite::params_from_iter(params.iter()), |row| {
         Ok(DependencyDisplayRow {
             blocker_title: row.get(0)?,
@@ -186,6 +308,44 @@ pub fn get_dependency_display_rows(
         .context("failed to query dependency display rows")
 }
 
+/// In-progress tasks whose `updated_at` is more than `stale_days` days ago,
+/// least recently touched first.
+pub fn get_stale_tasks(
+    db: &Database,
+    project_id: Option<&str>,
+    stale_days: u32,
+) -> Result<Vec<StaleTaskRow>> {
+    let mut sql = String::from(
+        "SELECT t.id, t.short_id, t.title, t.epic_id, t.updated_at, \
+                CAST(julianday('now') - julianday(t.updated_at) AS INTEGER) \
+         FROM tasks t JOIN epics e ON t.epic_id = e.id \
+         WHERE t.status = 'in_progress' AND t.updated_at < datetime('now', ?1)",
+    );
+    let mut params: Vec<Box<dyn rusqlite::types::ToSql>> =
+        vec![Box::new(format!("-{stale_days} days"))];
+    if let Some(pid) = project_id {
+        sql.push_str(" AND e.project_id = ?2");
+        params.push(Box::new(pid.to_string()));
+    }
+    sql.push_str(" ORDER BY t.updated_at, t.id");
+
+    let conn = db.conn();
+    let mut stmt = conn.prepare(&sql)?;
+    let rows = stmt.query_map(rusqlite::params_from_iter(params.iter()), |row| {
+        Ok(StaleTaskRow {
+            task_id: row.get(0)?,
+            short_id: row.get(1)?,
+            title: row.get(2)?,
+            epic_id: row.get(3)?,
+            updated_at: row.get(4)?,
+            days_idle: row.get(5)?,
+        })
+    })?;
+
+    rows.collect::<rusqlite::Result<Vec<_>>>()
+        .context("failed to query stale tasks")
+}
+
 pub fn get_max_updated_at(db: &Database) -> Result<String> {
     let sql = "\
         SELECT COALESCE(MAX(ts), '') || ':' || dep_count FROM ( \
@@ -203,7 +363,7 @@ pub fn get_max_updated_at(db: &Database) -> Result<String> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::db::dependency::add_dependency;
+    use crate::db::dependency::{add_dependency, remove_dependency};
     use crate::db::epic::create_epic;
     use crate::db::project::create_project;
     use crate::db::task::{create_task, update_task};
@@ -239,6 +399,66 @@ mod tests {
         assert!(blocked.is_empty());
     }
 
+    #[test]
+    fn test_task_counts_per_epic_scoped_and_zero_filled() {
+        let (db, _dir) = open_temp_db();
+        let mut epic_ids = Vec::new();
+        for name in ["P1", "P2"] {
+            let project = create_project(
+                &db,
+                CreateProjectInput {
+                    name: name.to_string(),
+                    description: String::new(),
+                },
+            )
+            .unwrap();
+            let epic = create_epic(
+                &db,
+                CreateEpicInput {
+                    project_id: project.id.clone(),
+                    title: "E".to_string(),
+                    description: String::new(),
+                    prd_id: None,
+                },
+                None,
+            )
+            .unwrap();
+            epic_ids.push((project.id, epic.id));
+        }
+        let t = create_task(
+            &db,
+            CreateTaskInput {
+                epic_id: epic_ids[0].1.clone(),
+                title: "T".to_string(),
+                description: String::new(),
+                session_id: None,
+                assignee: None,
+            },
+        )
+        .unwrap();
+        update_task(
+            &db,
+            &t.id,
+            UpdateTaskInput {
+                status: Some(ItemStatus::Done),
+                ..Default::default()
+            },
+            None,
+        )
+        .unwrap();
+
+        let all = count_tasks_by_status_per_epic(&db, None).unwrap();
+        assert_eq!(all.len(), 2);
+        assert_eq!(all[&epic_ids[0].1]["done"], 1);
+        assert_eq!(all[&epic_ids[0].1]["todo"], 0);
+        assert_eq!(all[&epic_ids[1].1]["done"], 0);
+        assert_eq!(all[&epic_ids[1].1]["in_progress"], 0);
+
+        let scoped = count_tasks_by_status_per_epic(&db, Some(&epic_ids[1].0)).unwrap();
+        assert_eq!(scoped.len(), 1);
+        assert!(scoped.contains_key(&epic_ids[1].1));
+    }
+
     #[test]
     fn test_epic_count_breakdown() {
         let (db, _dir) = open_temp_db();
@@ -258,7 +478,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "E1".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         create_epic(
@@ -267,7 +489,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "E2".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         let e3 = create_epic(
@@ -276,7 +500,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "E3".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
 
@@ -287,6 +513,7 @@ mod tests {
                 status: Some(ItemStatus::InProgress),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
 
@@ -322,7 +549,9 @@ mod tests {
                 project_id: p1.id.clone(),
                 title: "E1".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         create_epic(
@@ -331,7 +560,9 @@ mod tests {
                 project_id: p2.id.clone(),
                 title: "E2".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         create_epic(
@@ -340,7 +571,9 @@ mod tests {
                 project_id: p2.id.clone(),
                 title: "E3".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
 
@@ -368,7 +601,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "E".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
 
@@ -379,6 +614,7 @@ mod tests {
                 title: "T1".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -389,6 +625,7 @@ mod tests {
                 title: "T2".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -399,6 +636,7 @@ mod tests {
                 title: "T3".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -410,6 +648,7 @@ mod tests {
                 status: Some(ItemStatus::InProgress),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
         update_task(
@@ -419,6 +658,7 @@ mod tests {
                 status: Some(ItemStatus::Done),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
 
@@ -445,7 +685,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "E".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         let t1 = create_task(
@@ -455,6 +697,7 @@ mod tests {
                 title: "Blocker".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -465,6 +708,7 @@ mod tests {
                 title: "Blocked".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -477,6 +721,7 @@ mod tests {
                 blocked_type: DependencyType::Task,
                 blocked_id: t2.id.clone(),
             },
+            None,
         )
         .unwrap();
 
@@ -512,7 +757,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "E".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         let t1 = create_task(
@@ -522,6 +769,7 @@ mod tests {
                 title: "Blocker Task".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -532,6 +780,7 @@ mod tests {
                 title: "Blocked Task".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -544,6 +793,7 @@ mod tests {
                 blocked_type: DependencyType::Task,
                 blocked_id: t2.id.clone(),
             },
+            None,
         )
         .unwrap();
 
@@ -571,7 +821,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "E".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         let t1 = create_task(
@@ -581,6 +833,7 @@ mod tests {
                 title: "Blocker Task".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -591,6 +844,7 @@ mod tests {
                 title: "Blocked Task".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -603,6 +857,7 @@ mod tests {
                 blocked_type: DependencyType::Task,
                 blocked_id: t2.id.clone(),
             },
+            None,
         )
         .unwrap();
 
@@ -614,6 +869,7 @@ mod tests {
                 status: Some(ItemStatus::Done),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
 
@@ -646,6 +902,71 @@ mod tests {
         assert!(!result.is_empty());
     }
 
+    #[test]
+    fn test_get_max_updated_at_advances_on_dependency_changes() {
+        let (db, _dir) = open_temp_db();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "P".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let epics: Vec<String> = (0..3)
+            .map(|i| {
+                create_epic(
+                    &db,
+                    CreateEpicInput {
+                        project_id: project.id.clone(),
+                        title: format!("E{i}"),
+                        description: String::new(),
+                        prd_id: None,
+                    },
+                    None,
+                )
+                .unwrap()
+                .id
+            })
+            .collect();
+        let age_everything = || {
+            db.conn()
+                .execute_batch(
+                    "UPDATE projects SET updated_at = '2000-01-01 00:00:00'; \
+                     UPDATE epics SET updated_at = '2000-01-01 00:00:00';",
+                )
+                .unwrap();
+        };
+        let edge = |from: usize, to: usize| AddDependencyInput {
+            blocker_type: DependencyType::Epic,
+            blocker_id: epics[from].clone(),
+            blocked_type: DependencyType::Epic,
+            blocked_id: epics[to].clone(),
+        };
+
+        age_everything();
+        let before = get_max_updated_at(&db).unwrap();
+        add_dependency(&db, edge(0, 1), None).unwrap();
+        let after_add = get_max_updated_at(&db).unwrap();
+        assert_ne!(after_add, before);
+
+        // Swapping one edge for another keeps the dependency count the same,
+        // so only the touched timestamps can reveal the change.
+        age_everything();
+        let before = get_max_updated_at(&db).unwrap();
+        remove_dependency(
+            &db,
+            &DependencyType::Epic,
+            &epics[0],
+            &DependencyType::Epic,
+            &epics[1],
+            None,
+        )
+        .unwrap();
+        add_dependency(&db, edge(1, 2), None).unwrap();
+        assert_ne!(get_max_updated_at(&db).unwrap(), before);
+    }
+
     #[test]
     fn test_blocked_items_excludes_done_blockers() {
         let (db, _dir) = open_temp_db();
@@ -663,7 +984,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "E".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         let t1 = create_task(
@@ -673,6 +996,7 @@ mod tests {
                 title: "Blocker".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -683,6 +1007,7 @@ mod tests {
                 title: "Blocked".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -695,6 +1020,7 @@ mod tests {
                 blocked_type: DependencyType::Task,
                 blocked_id: t2.id.clone(),
             },
+            None,
         )
         .unwrap();
 
@@ -706,6 +1032,7 @@ mod tests {
                 status: Some(ItemStatus::Done),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
 
@@ -1,12 +1,20 @@
+use std::collections::{HashMap, HashSet};
+
 use anyhow::{Context, Result};
-use rusqlite::{params_from_iter, OptionalExtension, Row};
+use rusqlite::{params_from_iter, Connection, OptionalExtension, Row, Statement};
 
-use crate::db::Database;
-use crate::db::resolve::{classify_id, IdKind};
-use crate::models::{BlueTask, CreateTaskInput, ItemStatus, UpdateTaskInput};
+use crate::db::{status_conditions, Database};
+use crate::db::dependency::{insert_dependency, is_blocked};
+use crate::db::epic::sync_epic_status_in;
+use crate::db::history::record_status_change;
+use crate::db::resolve::{classify_id, single_match, IdKind};
+use crate::models::{
+    AddDependencyInput, BlueTask, CreateTaskInput, DependencyType, ItemStatus, TaskListFilter,
+    UpdateTaskInput,
+};
 
-const SELECT_COLUMNS: &str = "id, epic_id, title, description, status, short_id, session_id, created_at, updated_at";
-const SELECT_COLUMNS_QUALIFIED: &str = "tasks.id, tasks.epic_id, tasks.title, tasks.description, tasks.status, tasks.short_id, tasks.session_id, tasks.created_at, tasks.updated_at";
+const SELECT_COLUMNS: &str = "id, epic_id, title, description, status, short_id, session_id, assignee, archived, created_at, updated_at, updated_by";
+const SELECT_COLUMNS_QUALIFIED: &str = "tasks.id, tasks.epic_id, tasks.title, tasks.description, tasks.status, tasks.short_id, tasks.session_id, tasks.assignee, tasks.archived, tasks.created_at, tasks.updated_at, tasks.updated_by";
 
 fn row_to_task(row: &Row) -> rusqlite::Result<BlueTask> {
     let status_str: String = row.get("status")?;
@@ -26,19 +34,68 @@ fn row_to_task(row: &Row) -> rusqlite::Result<BlueTask> {
         status,
         short_id: row.get("short_id")?,
         session_id: row.get("session_id")?,
+        assignee: row.get("assignee")?,
+        archived: row.get("archived")?,
         created_at: row.get("created_at")?,
         updated_at: row.get("updated_at")?,
+        updated_by: row.get("updated_by")?,
     })
 }
 
+/// [`create_task_with_blockers`] without blockers; tests build fixtures with it.
+#[cfg(test)]
 pub fn create_task(db: &Database, input: CreateTaskInput) -> Result<BlueTask> {
     let id = ulid::Ulid::new().to_string();
+    db.write_transaction("task creation", |tx| insert_task(tx, db, &id, &input, None))?;
+    get_task(db, &id)?.context("task not found after insert")
+}
 
-    let tx = db
-        .conn()
-        .unchecked_transaction()
-        .context("failed to begin transaction for task creation")?;
+/// Create a task blocked by each task in `blocker_ids`, all in one
+/// transaction: if a blocker does not exist nothing is created. The new task
+/// has no dependents yet, so these edges cannot close a cycle. `actor` is
+/// recorded as the `updated_by` of everything written.
+pub fn create_task_with_blockers(
+    db: &Database,
+    input: CreateTaskInput,
+    blocker_ids: &[String],
+    actor: Option<&str>,
+) -> Result<BlueTask> {
+    let id = ulid::Ulid::new().to_string();
+    db.write_transaction("task creation", |tx| {
+        insert_task(tx, db, &id, &input, actor)?;
+        let mut seen = HashSet::new();
+        for blocker_id in blocker_ids {
+            if !seen.insert(blocker_id.as_str()) {
+                continue;
+            }
+            let exists = tx
+                .prepare("SELECT 1 FROM tasks WHERE id = ?1")?
+                .exists([blocker_id])
+                .context("failed to check blocker task")?;
+            anyhow::ensure!(exists, "task not found: {blocker_id}");
+            let dependency = AddDependencyInput {
+                blocker_type: DependencyType::Task,
+                blocker_id: blocker_id.clone(),
+                blocked_type: DependencyType::Task,
+                blocked_id: id.clone(),
+            };
+            insert_dependency(tx, &dependency, actor)?;
+        }
+        Ok(())
+    })?;
+    get_task(db, &id)?.context("task not found after insert")
+}
 
+/// Insert task `id` inside an open transaction, numbering it after the
+/// epic's last task. Shared by [`create_task_with_blockers`] and callers that
+/// create a task as part of a larger change.
+pub(crate) fn insert_task(
+    tx: &Connection,
+    db: &Database,
+    id: &str,
+    input: &CreateTaskInput,
+    actor: Option<&str>,
+) -> Result<()> {
     let epic_short_id: String = tx
         .query_row(
             "SELECT short_id FROM epics WHERE id = ?1",
@@ -60,16 +117,25 @@ pub fn create_task(db: &Database, input: CreateTaskInput) -> Result<BlueTask> {
     let short_id = format!("{epic_short_id}-T{}", max_num + 1);
 
     tx.execute(
-        "INSERT INTO tasks (id, epic_id, title, description, short_id, session_id) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
-        rusqlite::params![&id, &input.epic_id, &input.title, &input.description, &short_id, &input.session_id],
+        "INSERT INTO tasks \
+         (id, epic_id, title, description, short_id, session_id, assignee, updated_by) \
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+        rusqlite::params![
+            id,
+            &input.epic_id,
+            &input.title,
+            &input.description,
+            &short_id,
+            &input.session_id,
+            &input.assignee,
+            actor
+        ],
     )
     .context("failed to insert task (check that epic_id is valid)")?;
-
-    tx.commit().context("failed to commit task creation")?;
-
-    super::epic::sync_epic_status(db, &input.epic_id)?;
-
-    get_task(db, &id)?.context("task not found after insert")
+    if db.auto_epic_status() {
+        sync_epic_status_in(tx, &input.epic_id)?;
+    }
+    Ok(())
 }
 
 pub fn get_task(db: &Database, id: &str) -> Result<Option<BlueTask>> {
@@ -81,12 +147,17 @@ pub fn get_task(db: &Database, id: &str) -> Result<Option<BlueTask>> {
         .context("failed to query task")
 }
 
-pub fn list_tasks(
-    db: &Database,
-    epic_id: Option<&str>,
-    project_id: Option<&str>,
-    status: Option<ItemStatus>,
-) -> Result<Vec<BlueTask>> {
+/// List tasks matching every field of `filter`, newest first.
+pub fn list_tasks(db: &Database, filter: &TaskListFilter) -> Result<Vec<BlueTask>> {
+    let TaskListFilter {
+        epic_id,
+        project_id,
+        status,
+        include_archived,
+        assignee,
+        created,
+        updated,
+    } = filter;
     let base = if project_id.is_some() {
         format!("SELECT {SELECT_COLUMNS_QUALIFIED} FROM tasks JOIN epics ON tasks.epic_id = epics.id")
     } else {
@@ -105,9 +176,25 @@ pub fn list_tasks(
         params.push(Box::new(eid.to_string()));
         conditions.push(format!("tasks.epic_id = ?{}", params.len()));
     }
-    if let Some(s) = status {
-        params.push(Box::new(s.as_str().to_string()));
-        conditions.push(format!("tasks.status = ?{}", params.len()));
+    conditions.extend(status_conditions("tasks.status", status, &mut params));
+    if !include_archived {
+        conditions.push("tasks.archived = 0".to_string());
+    }
+    match assignee {
+        Some(Some(name)) => {
+            params.push(Box::new(name.to_string()));
+            conditions.push(format!("tasks.assignee = ?{}", params.len()));
+        }
+        Some(None) => conditions.push("tasks.assignee IS NULL".to_string()),
+        None => {}
+    }
+    for (column, range) in [("tasks.created_at", created), ("tasks.updated_at", updated)] {
+        if let Some(range) = range {
+            params.push(Box::new(range.start.clone()));
+            params.push(Box::new(range.end.clone()));
+            let (start, end) = (params.len() - 1, params.len());
+            conditions.push(format!("{column} BETWEEN ?{start} AND ?{end}"));
+        }
     }
 
     let sql = if conditions.is_empty() {
@@ -116,15 +203,71 @@ pub fn list_tasks(
         format!("{base} WHERE {} {tail}", conditions.join(" AND "))
     };
 
-    let mut stmt = db.conn().prepare(&sql)?;
+    let conn = db.conn();
+    let mut stmt = conn.prepare(&sql)?;
     let rows = stmt.query_map(params_from_iter(params.iter()), row_to_task)?;
 
     rows.collect::<rusqlite::Result<Vec<_>>>()
         .context("failed to list tasks")
 }
 
-pub fn update_task(db: &Database, id: &str, input: UpdateTaskInput) -> Result<BlueTask> {
-    let status_changed = input.status.is_some();
+/// Outcome of [`next_task`].
+#[derive(Debug)]
+pub enum NextTask {
+    /// The best ready task, and whether its epic is already in progress.
+    Ready { task: Box<BlueTask>, epic_in_progress: bool },
+    /// Every todo task is blocked, by its own blockers or its epic's.
+    AllBlocked { open: usize },
+    /// The project has no todo tasks.
+    NoOpenTasks,
+}
+
+/// Pick the todo task to work on next in `project_id`. Only ready tasks
+/// qualify: neither the task nor its epic may have an unfinished blocker.
+/// Among those, tasks in an in-progress epic come first, then the oldest.
+pub fn next_task(db: &Database, project_id: &str) -> Result<NextTask> {
+    let sql = format!(
+        "SELECT {SELECT_COLUMNS_QUALIFIED}, epics.status = 'in_progress' AS epic_in_progress \
+         FROM tasks JOIN epics ON tasks.epic_id = epics.id \
+         WHERE epics.project_id = ?1 AND tasks.status = 'todo' \
+         ORDER BY epic_in_progress DESC, tasks.created_at, tasks.rowid"
+    );
+    let candidates = db
+        .conn()
+        .prepare(&sql)?
+        .query_map([project_id], |row| Ok((row_to_task(row)?, row.get("epic_in_progress")?)))?
+        .collect::<rusqlite::Result<Vec<(BlueTask, bool)>>>()
+        .context("failed to query next task candidates")?;
+
+    if candidates.is_empty() {
+        return Ok(NextTask::NoOpenTasks);
+    }
+    let open = candidates.len();
+
+    let mut epic_blocked: HashMap<String, bool> = HashMap::new();
+    for (task, epic_in_progress) in candidates {
+        let blocked_epic = match epic_blocked.get(&task.epic_id) {
+            Some(&blocked) => blocked,
+            None => {
+                let blocked = is_blocked(db, &DependencyType::Epic, &task.epic_id)?;
+                epic_blocked.insert(task.epic_id.clone(), blocked);
+                blocked
+            }
+        };
+        if !blocked_epic && !is_blocked(db, &DependencyType::Task, &task.id)? {
+            return Ok(NextTask::Ready { task: Box::new(task), epic_in_progress });
+        }
+    }
+    Ok(NextTask::AllBlocked { open })
+}
+
+pub fn update_task(
+    db: &Database,
+    id: &str,
+    input: UpdateTaskInput,
+    actor: Option<&str>,
+) -> Result<BlueTask> {
+    let new_status = input.status.clone();
 
     let mut set_clauses: Vec<String> = Vec::new();
     let mut params: Vec<Box<dyn rusqlite::types::ToSql>> = Vec::new();
@@ -146,7 +289,11 @@ pub fn update_task(db: &Database, id: &str, input: UpdateTaskInput) -> Result<Bl
     if let Some(session_id) = input.session_id {
         bind("session_id", Box::new(session_id));
     }
+    if let Some(assignee) = input.assignee {
+        bind("assignee", Box::new(assignee));
+    }
 
+    bind("updated_by", Box::new(actor.map(str::to_string)));
     set_clauses.push("updated_at = datetime('now')".to_string());
     params.push(Box::new(id.to_string()));
 
@@ -156,28 +303,268 @@ pub fn update_task(db: &Database, id: &str, input: UpdateTaskInput) -> Result<Bl
         params.len(),
     );
 
-    let rows_affected = db
-        .conn()
-        .execute(&sql, params_from_iter(params.iter()))
-        .context("failed to update task")?;
+    let rows_affected = db.write_transaction("task update", |tx| {
+        if let Some(status) = &new_status {
+            record_status_change(tx, &DependencyType::Task, id, status, None)?;
+        }
+        let rows = tx
+            .execute(&sql, params_from_iter(params.iter()))
+            .context("failed to update task")?;
+        if rows > 0 && new_status.is_some() && db.auto_epic_status() {
+            let epic_id: String = tx
+                .query_row("SELECT epic_id FROM tasks WHERE id = ?1", [id], |row| row.get(0))
+                .context("failed to fetch task epic_id")?;
+            sync_epic_status_in(tx, &epic_id)?;
+        }
+        Ok(rows)
+    })?;
 
     if rows_affected == 0 {
         anyhow::bail!("task not found: {id}");
     }
 
-    let task = get_task(db, id)?.context("task not found after update")?;
+    get_task(db, id)?.context("task not found after update")
+}
+
+/// Outcome of [`reopen_task`].
+#[derive(Debug)]
+pub enum Reopen {
+    /// The task moved back from done; the task as it now stands.
+    Reopened(Box<BlueTask>),
+    /// The task was not done, so it was left untouched; its status.
+    NotDone(ItemStatus),
+}
+
+/// Move a done task back to `status`, logging `reason` with the transition
+/// in the status history. Tasks that are not done are left alone.
+pub fn reopen_task(
+    db: &Database,
+    id: &str,
+    status: &ItemStatus,
+    reason: Option<&str>,
+    actor: Option<&str>,
+) -> Result<Reopen> {
+    let current = db.write_transaction("task reopen", |tx| {
+        let current: Option<(String, String)> = tx
+            .query_row("SELECT status, epic_id FROM tasks WHERE id = ?1", [id], |row| {
+                Ok((row.get(0)?, row.get(1)?))
+            })
+            .optional()
+            .context("failed to fetch task status")?;
+        let Some((current, epic_id)) = current else {
+            return Ok(None);
+        };
+        if current == ItemStatus::Done.as_str() {
+            record_status_change(tx, &DependencyType::Task, id, status, reason)?;
+            tx.execute(
+                "UPDATE tasks SET status = ?1, updated_by = ?2, updated_at = datetime('now') \
+                 WHERE id = ?3",
+                rusqlite::params![status.as_str(), actor, id],
+            )
+            .context("failed to reopen task")?;
+            if db.auto_epic_status() {
+                sync_epic_status_in(tx, &epic_id)?;
+            }
+        }
+        Ok(Some(current))
+    })?;
+
+    match current {
+        None => anyhow::bail!("task not found: {id}"),
+        Some(s) if s == ItemStatus::Done.as_str() => {
+            let task = get_task(db, id)?.context("task not found after reopen")?;
+            Ok(Reopen::Reopened(Box::new(task)))
+        }
+        Some(s) => Ok(Reopen::NotDone(s.parse()?)),
+    }
+}
+
+/// Mark a task and every task upstream of it done in one transaction,
+/// each blocker before the tasks it blocks, so no done task is ever left
+/// behind a blocker that is not. Epic blockers are left as they are.
+/// Returns the tasks that changed, in the order they were marked.
+pub fn complete_chain(db: &Database, id: &str, actor: Option<&str>) -> Result<Vec<BlueTask>> {
+    let completed = db.write_transaction("chain completion", |tx| {
+        let found = tx
+            .query_row("SELECT 1 FROM tasks WHERE id = ?1", [id], |_| Ok(()))
+            .optional()
+            .context("failed to fetch task")?;
+        if found.is_none() {
+            anyhow::bail!("task not found: {id}");
+        }
+
+        let mut completed = Vec::new();
+        let mut epic_ids: Vec<String> = Vec::new();
+        for task_id in chain_in_order(tx, id)? {
+            let (status, epic_id): (String, String) = tx
+                .query_row("SELECT status, epic_id FROM tasks WHERE id = ?1", [&task_id], |row| {
+                    Ok((row.get(0)?, row.get(1)?))
+                })
+                .context("failed to fetch task status")?;
+            if status == ItemStatus::Done.as_str() {
+                continue;
+            }
+            record_status_change(tx, &DependencyType::Task, &task_id, &ItemStatus::Done, None)?;
+            tx.execute(
+                "UPDATE tasks SET status = 'done', updated_by = ?1, updated_at = datetime('now') \
+                 WHERE id = ?2",
+                rusqlite::params![actor, task_id],
+            )
+            .context("failed to complete task")?;
+            if !epic_ids.contains(&epic_id) {
+                epic_ids.push(epic_id);
+            }
+            completed.push(task_id);
+        }
+        if db.auto_epic_status() {
+            for epic_id in &epic_ids {
+                sync_epic_status_in(tx, epic_id)?;
+            }
+        }
+        Ok(completed)
+    })?;
+
+    completed
+        .iter()
+        .map(|id| get_task(db, id)?.context("task not found after completion"))
+        .collect()
+}
+
+/// `id` and the tasks transitively blocking it, each after all of its own
+/// task blockers. A cycle is cut where it first revisits a task.
+fn chain_in_order(conn: &Connection, id: &str) -> Result<Vec<String>> {
+    fn visit(
+        blockers: &mut Statement,
+        id: &str,
+        seen: &mut HashSet<String>,
+        order: &mut Vec<String>,
+    ) -> Result<()> {
+        if !seen.insert(id.to_string()) {
+            return Ok(());
+        }
+        let upstream: Vec<String> = blockers
+            .query_map([id], |row| row.get(0))?
+            .collect::<rusqlite::Result<_>>()
+            .context("failed to fetch task blockers")?;
+        for blocker in upstream {
+            visit(blockers, &blocker, seen, order)?;
+        }
+        order.push(id.to_string());
+        Ok(())
+    }
+
+    let mut blockers = conn.prepare(
+        "SELECT blocker_id FROM dependencies \
+         WHERE blocked_type = 'task' AND blocked_id = ?1 AND blocker_type = 'task' ORDER BY id",
+    )?;
+    let mut order = Vec::new();
+    visit(&mut blockers, id, &mut HashSet::new(), &mut order)?;
+    Ok(order)
+}
+
+/// Outcome of [`claim_task`] and [`release_task`].
+#[derive(Debug)]
+pub enum SessionChange {
+    /// The claim was taken or dropped; the task as it now stands.
+    Applied(Box<BlueTask>),
+    /// Another session holds the task, which was left untouched.
+    HeldBy(String),
+}
+
+/// Mark `session_id` as working on a task. Claiming a task the session
+/// already holds is a no-op; a task held by another session is refused.
+pub fn claim_task(
+    db: &Database,
+    id: &str,
+    session_id: &str,
+    actor: Option<&str>,
+) -> Result<SessionChange> {
+    change_session(db, id, Some(session_id), Some(session_id), actor)
+}
+
+/// Drop the claim on a task. With `session_id`, only that session's claim is
+/// dropped and a claim by another session is refused; without it, any claim
+/// is cleared. Releasing an unclaimed task is a no-op.
+pub fn release_task(
+    db: &Database,
+    id: &str,
+    session_id: Option<&str>,
+    actor: Option<&str>,
+) -> Result<SessionChange> {
+    change_session(db, id, session_id, None, actor)
+}
+
+/// Set the task's `session_id` to `new` unless it is held by a session other
+/// than `owner` (`None` owns every claim).
+fn change_session(
+    db: &Database,
+    id: &str,
+    owner: Option<&str>,
+    new: Option<&str>,
+    actor: Option<&str>,
+) -> Result<SessionChange> {
+    let holder = db.write_transaction("task session change", |tx| {
+        let holder: Option<String> = tx
+            .query_row("SELECT session_id FROM tasks WHERE id = ?1", [id], |row| row.get(0))
+            .optional()
+            .context("failed to fetch task session")?
+            .with_context(|| format!("task not found: {id}"))?;
+        match (holder.as_deref(), owner) {
+            (Some(current), Some(owner)) if current != owner => return Ok(holder),
+            (current, _) if current == new => {}
+            _ => {
+                tx.execute(
+                    "UPDATE tasks SET session_id = ?1, updated_by = ?2, \
+                     updated_at = datetime('now') WHERE id = ?3",
+                    rusqlite::params![new, actor, id],
+                )
+                .context("failed to update task session")?;
+            }
+        }
+        Ok(None)
+    })?;
 
-    if status_changed {
-        super::epic::sync_epic_status(db, &task.epic_id)?;
+    match holder {
+        Some(other) => Ok(SessionChange::HeldBy(other)),
+        None => Ok(SessionChange::Applied(Box::new(
+            get_task(db, id)?.context("task not found after session change")?,
+        ))),
     }
+}
 
-    Ok(task)
+/// Archive or unarchive a task, returning it as it now stands. Archived
+/// tasks keep their status and dependencies but drop out of default
+/// listings.
+pub fn set_task_archived(
+    db: &Database,
+    id: &str,
+    archived: bool,
+    actor: Option<&str>,
+) -> Result<BlueTask> {
+    let rows_affected = db.write_transaction("task archive", |tx| {
+        tx.execute(
+            "UPDATE tasks SET archived = ?1, updated_by = ?2, updated_at = datetime('now') \
+             WHERE id = ?3",
+            rusqlite::params![archived, actor, id],
+        )
+        .context("failed to update task archived flag")
+    })?;
+    if rows_affected == 0 {
+        anyhow::bail!("task not found: {id}");
+    }
+    get_task(db, id)?.context("task not found after archiving")
 }
 
 pub fn delete_task(db: &Database, id: &str) -> Result<bool> {
-    // Fetch epic_id before deletion so we can sync the epic afterwards
-    let epic_id: Option<String> = db
-        .conn()
+    let rows_affected = db.write_transaction("task deletion", |tx| delete_task_in(tx, db, id))?;
+    Ok(rows_affected > 0)
+}
+
+/// [`delete_task`] inside the caller's transaction, returning the number of
+/// task rows deleted.
+pub(crate) fn delete_task_in(tx: &Connection, db: &Database, id: &str) -> Result<usize> {
+    // Fetch epic_id before deletion so we can sync the epic in the same transaction
+    let epic_id: Option<String> = tx
         .query_row(
             "SELECT epic_id FROM tasks WHERE id = ?1",
             [id],
@@ -186,11 +573,6 @@ pub fn delete_task(db: &Database, id: &str) -> Result<bool> {
         .optional()
         .context("failed to fetch task epic_id before deletion")?;
 
-    let tx = db
-        .conn()
-        .unchecked_transaction()
-        .context("failed to begin transaction for task deletion")?;
-
     // Clean up polymorphic dependency rows (no FK cascade for these)
     tx.execute(
         "DELETE FROM dependencies WHERE (blocker_type = 'task' AND blocker_id = ?1) OR (blocked_type = 'task' AND blocked_id = ?1)",
@@ -198,41 +580,44 @@ pub fn delete_task(db: &Database, id: &str) -> Result<bool> {
     )
     .context("failed to clean up dependencies for task")?;
 
-    let rows_affected = tx
+    let rows = tx
         .execute("DELETE FROM tasks WHERE id = ?1", [id])
         .context("failed to delete task")?;
-
-    tx.commit().context("failed to commit task deletion")?;
-
-    let deleted = rows_affected > 0;
-    if let (true, Some(eid)) = (deleted, epic_id) {
-        super::epic::sync_epic_status(db, &eid)?;
+    if let (true, Some(eid)) = (rows > 0 && db.auto_epic_status(), &epic_id) {
+        sync_epic_status_in(tx, eid)?;
     }
-
-    Ok(deleted)
+    Ok(rows)
 }
 
+/// Resolve a ULID or short ID to a task ULID. Without a `project_id` a
+/// short ID is looked up across all projects and must match only one task.
 pub fn resolve_task_id(
     db: &Database,
     id_or_short: &str,
-    default_project_id: Option<&str>,
+    project_id: Option<&str>,
 ) -> Result<String> {
     match classify_id(id_or_short) {
         IdKind::Ulid => Ok(id_or_short.to_string()),
         IdKind::TaskShortId => {
             let upper = id_or_short.to_uppercase();
-            let dash_pos = upper.find("-T").expect("classify_id guaranteed -T present");
+            let dash_pos = upper.rfind("-T").expect("classify_id guaranteed -T present");
             let epic_short = &upper[..dash_pos];
-            let epic_id =
-                super::epic::resolve_epic_id(db, epic_short, default_project_id)?;
-            db.conn()
-                .query_row(
-                    "SELECT id FROM tasks WHERE short_id = ?1 AND epic_id = ?2",
-                    [upper.as_str(), epic_id.as_str()],
-                    |row| row.get::<_, String>(0),
-                )
-                .optional()
-                .context("failed to resolve task short ID")?
+            let task_part = &upper[dash_pos + 1..];
+            // Match on the `-T<n>` suffix so bare and prefixed forms both resolve.
+            let conn = db.conn();
+            let mut stmt = conn.prepare(
+                "SELECT t.id FROM tasks t JOIN epics e ON e.id = t.epic_id \
+                 WHERE (e.short_id = ?1 OR e.short_id LIKE '%-' || ?1) \
+                   AND t.short_id LIKE '%-' || ?2 \
+                   AND (?3 IS NULL OR e.project_id = ?3)",
+            )?;
+            let ids: Vec<String> = stmt
+                .query_map(rusqlite::params![epic_short, task_part, project_id], |row| {
+                    row.get(0)
+                })?
+                .collect::<rusqlite::Result<Vec<_>>>()
+                .context("failed to resolve task short ID")?;
+            single_match(ids, id_or_short, "task")?
                 .ok_or_else(|| anyhow::anyhow!("Task not found: {id_or_short}"))
         }
         IdKind::EpicShortId => {
@@ -245,8 +630,11 @@ pub fn resolve_task_id(
 mod tests {
     use super::*;
     use crate::db::epic::{create_epic, get_epic};
-    use crate::db::project::create_project;
-    use crate::models::{CreateEpicInput, CreateProjectInput, Epic, Project};
+    use crate::db::project::{create_project, update_project};
+    use crate::models::{
+        CreateEpicInput, CreateProjectInput, DateRange, Epic, Project, StatusFilter,
+        UpdateProjectInput,
+    };
     use tempfile::TempDir;
 
     fn open_temp_db() -> (Database, TempDir) {
@@ -275,7 +663,9 @@ mod tests {
                 project_id: project_id.to_string(),
                 title: "Test Epic".to_string(),
                 description: "For task tests".to_string(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap()
     }
@@ -293,6 +683,7 @@ mod tests {
                 title: "My Task".to_string(),
                 description: "Task description".to_string(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -316,6 +707,7 @@ mod tests {
                 title: "Orphan".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         );
 
@@ -335,6 +727,7 @@ mod tests {
                 title: "Lookup".to_string(),
                 description: "desc".to_string(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -361,6 +754,7 @@ mod tests {
                 title: "Task A".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -371,15 +765,30 @@ mod tests {
                 title: "Task B".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
 
-        let e1_tasks = list_tasks(&db, Some(&e1.id), None, None).unwrap();
+        let e1_tasks = list_tasks(
+            &db,
+            &TaskListFilter {
+                epic_id: Some(&e1.id),
+                ..Default::default()
+            },
+        )
+        .unwrap();
         assert_eq!(e1_tasks.len(), 1);
         assert_eq!(e1_tasks[0].title, "Task A");
 
-        let e2_tasks = list_tasks(&db, Some(&e2.id), None, None).unwrap();
+        let e2_tasks = list_tasks(
+            &db,
+            &TaskListFilter {
+                epic_id: Some(&e2.id),
+                ..Default::default()
+            },
+        )
+        .unwrap();
         assert_eq!(e2_tasks.len(), 1);
         assert_eq!(e2_tasks[0].title, "Task B");
     }
@@ -397,6 +806,7 @@ mod tests {
                 title: "Task A".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -408,6 +818,7 @@ mod tests {
                 title: "Task B".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -419,18 +830,94 @@ mod tests {
                 status: Some(ItemStatus::InProgress),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
 
-        let in_progress = list_tasks(&db, None, None, Some(ItemStatus::InProgress)).unwrap();
+        let in_progress = list_tasks(
+            &db,
+            &TaskListFilter {
+                status: StatusFilter::only(ItemStatus::InProgress),
+                ..Default::default()
+            },
+        )
+        .unwrap();
         assert_eq!(in_progress.len(), 1);
         assert_eq!(in_progress[0].title, "Task A");
 
-        let todo = list_tasks(&db, None, None, Some(ItemStatus::Todo)).unwrap();
+        let todo = list_tasks(
+            &db,
+            &TaskListFilter {
+                status: StatusFilter::only(ItemStatus::Todo),
+                ..Default::default()
+            },
+        )
+        .unwrap();
         assert_eq!(todo.len(), 1);
         assert_eq!(todo[0].title, "Task B");
     }
 
+    #[test]
+    fn test_list_by_status_sets() {
+        let (db, _dir) = open_temp_db();
+        let project = create_test_project(&db);
+        let epic = create_test_epic(&db, &project.id);
+        for (title, status) in [
+            ("Todo", ItemStatus::Todo),
+            ("Doing", ItemStatus::InProgress),
+            ("Done", ItemStatus::Done),
+        ] {
+            let task = create_task(
+                &db,
+                CreateTaskInput {
+                    epic_id: epic.id.clone(),
+                    title: title.to_string(),
+                    description: String::new(),
+                    session_id: None,
+                    assignee: None,
+                },
+            )
+            .unwrap();
+            update_task(
+                &db,
+                &task.id,
+                UpdateTaskInput { status: Some(status), ..Default::default() },
+                None,
+            )
+            .unwrap();
+        }
+        let titles = |filter: StatusFilter| -> Vec<String> {
+            let mut titles: Vec<String> = list_tasks(
+                &db,
+                &TaskListFilter {
+                    status: filter,
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+            .into_iter()
+            .map(|t| t.title)
+            .collect();
+            titles.sort();
+            titles
+        };
+
+        let include = vec![ItemStatus::Todo, ItemStatus::InProgress];
+        assert_eq!(
+            titles(StatusFilter { include: include.clone(), ..Default::default() }),
+            vec!["Doing", "Todo"]
+        );
+        assert_eq!(
+            titles(StatusFilter { exclude: vec![ItemStatus::Done], ..Default::default() }),
+            vec!["Doing", "Todo"]
+        );
+        assert_eq!(
+            titles(StatusFilter { include, exclude: vec![ItemStatus::InProgress] }),
+            vec!["Todo"]
+        );
+        assert_eq!(titles(StatusFilter::default()).len(), 3);
+    }
+
     #[test]
     fn test_list_no_filter() {
         let (db, _dir) = open_temp_db();
@@ -445,15 +932,117 @@ mod tests {
                     title: format!("Task {i}"),
                     description: String::new(),
                     session_id: None,
+                    assignee: None,
                 },
             )
             .unwrap();
         }
 
-        let all = list_tasks(&db, None, None, None).unwrap();
+        let all = list_tasks(&db, &TaskListFilter::default())
+        .unwrap();
         assert_eq!(all.len(), 3);
     }
 
+    #[test]
+    fn test_archived_tasks_are_hidden_by_default() {
+        let (db, _dir) = open_temp_db();
+        let project = create_test_project(&db);
+        let epic = create_test_epic(&db, &project.id);
+        let mut ids = Vec::new();
+        for title in ["Keep", "Shelve"] {
+            let task = create_task(
+                &db,
+                CreateTaskInput {
+                    epic_id: epic.id.clone(),
+                    title: title.to_string(),
+                    description: String::new(),
+                    session_id: None,
+                    assignee: None,
+                },
+            )
+            .unwrap();
+            ids.push(task.id);
+        }
+        let titles = |include_archived| -> Vec<String> {
+            let mut titles: Vec<String> =
+                list_tasks(
+                    &db,
+                    &TaskListFilter {
+                        epic_id: Some(&epic.id),
+                        include_archived,
+                        ..Default::default()
+                    },
+                )
+                .unwrap()
+                .into_iter()
+                .map(|t| t.title)
+                .collect();
+            titles.sort();
+            titles
+        };
+
+        let archived = set_task_archived(&db, &ids[1], true, None).unwrap();
+        assert!(archived.archived);
+        assert_eq!(archived.status, ItemStatus::Todo);
+        assert_eq!(titles(false), vec!["Keep"]);
+        assert_eq!(titles(true), vec!["Keep", "Shelve"]);
+        assert!(get_task(&db, &ids[1]).unwrap().unwrap().archived);
+
+        assert!(!set_task_archived(&db, &ids[1], false, None).unwrap().archived);
+        assert_eq!(titles(false), vec!["Keep", "Shelve"]);
+
+        assert!(set_task_archived(&db, "missing", true, None).is_err());
+    }
+
+    #[test]
+    fn test_list_tasks_date_windows_are_inclusive() {
+        let (db, _dir) = open_temp_db();
+        let project = create_test_project(&db);
+        let epic = create_test_epic(&db, &project.id);
+
+        let dates = [
+            ("Feb", "2026-02-28 23:59:59"),
+            ("Start", "2026-03-01 00:00:00"),
+            ("Mid", "2026-03-07 12:00:00"),
+            ("End", "2026-03-14 23:59:59"),
+            ("After", "2026-03-15 00:00:00"),
+        ];
+        for (title, at) in dates {
+            let task = make_task(&db, &epic.id, title);
+            db.conn()
+                .execute(
+                    "UPDATE tasks SET created_at = ?1, updated_at = ?1 WHERE id = ?2",
+                    [at, &task.id],
+                )
+                .unwrap();
+        }
+        let titles = |created: Option<&DateRange>, updated: Option<&DateRange>| {
+            let mut titles: Vec<String> = list_tasks(
+                &db,
+                &TaskListFilter {
+                    created: created.cloned(),
+                    updated: updated.cloned(),
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+                .into_iter()
+                .map(|t| t.title)
+                .collect();
+            titles.sort();
+            titles
+        };
+
+        let sprint = DateRange::parse("2026-03-01", "2026-03-14").unwrap();
+        assert_eq!(titles(Some(&sprint), None), ["End", "Mid", "Start"]);
+        assert_eq!(titles(None, Some(&sprint)), ["End", "Mid", "Start"]);
+
+        let exact = DateRange::parse("2026-03-07T12:00:00Z", "2026-03-07 12:00:00").unwrap();
+        assert_eq!(titles(Some(&exact), None), ["Mid"]);
+        let before = DateRange::parse("2026-01-01", "2026-02-28").unwrap();
+        assert_eq!(titles(Some(&before), Some(&sprint)), Vec::<String>::new());
+    }
+
     #[test]
     fn test_update_partial_fields() {
         let (db, _dir) = open_temp_db();
@@ -467,6 +1056,7 @@ mod tests {
                 title: "Original".to_string(),
                 description: "original desc".to_string(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -478,6 +1068,7 @@ mod tests {
                 title: Some("Renamed".to_string()),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
 
@@ -498,6 +1089,7 @@ mod tests {
                 title: Some("Name".to_string()),
                 ..Default::default()
             },
+            None,
         );
 
         assert!(result.is_err());
@@ -517,6 +1109,7 @@ mod tests {
                 title: "Blocker Task".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -568,6 +1161,7 @@ mod tests {
                 title: "Lifecycle".to_string(),
                 description: "testing".to_string(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -586,6 +1180,7 @@ mod tests {
                 status: Some(ItemStatus::Done),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
         assert_eq!(updated.title, "Updated");
@@ -610,6 +1205,7 @@ mod tests {
                     title: title.to_string(),
                     description: String::new(),
                     session_id: None,
+                    assignee: None,
                 },
             )
             .unwrap()
@@ -645,6 +1241,7 @@ mod tests {
                 title: "Test Task".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -653,6 +1250,83 @@ mod tests {
         assert_eq!(resolved, task.id);
     }
 
+    #[test]
+    fn test_short_id_prefix_generation_and_round_trip() {
+        let (db, _dir) = open_temp_db();
+        let project = create_test_project(&db);
+        // An epic created before the prefix is set keeps its plain short ID.
+        let before = create_test_epic(&db, &project.id);
+        update_project(
+            &db,
+            &project.id,
+            UpdateProjectInput {
+                short_id_prefix: Some(Some("proj".to_string())),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        let epic = create_test_epic(&db, &project.id);
+        let task = create_task(
+            &db,
+            CreateTaskInput {
+                epic_id: epic.id.clone(),
+                title: "Prefixed".to_string(),
+                description: String::new(),
+                session_id: None,
+                assignee: None,
+            },
+        )
+        .unwrap();
+
+        assert_eq!(before.short_id.as_deref(), Some("E1"));
+        assert_eq!(epic.short_id.as_deref(), Some("PROJ-E2"));
+        assert_eq!(task.short_id.as_deref(), Some("PROJ-E2-T1"));
+
+        let pid = Some(project.id.as_str());
+        assert_eq!(resolve_task_id(&db, "PROJ-E2-T1", pid).unwrap(), task.id);
+        assert_eq!(resolve_task_id(&db, "proj-e2-t1", None).unwrap(), task.id);
+        assert_eq!(resolve_task_id(&db, "E2-T1", pid).unwrap(), task.id);
+        assert_eq!(
+            crate::db::epic::resolve_epic_id(&db, "PROJ-E2", pid).unwrap(),
+            epic.id
+        );
+        assert_eq!(crate::db::epic::resolve_epic_id(&db, "E1", pid).unwrap(), before.id);
+        assert!(resolve_task_id(&db, "PROJ-E2-T2", pid).is_err());
+    }
+
+    #[test]
+    fn test_resolve_task_id_ambiguous_across_projects() {
+        let (db, _dir) = open_temp_db();
+        let tasks: Vec<(Project, BlueTask)> = (0..2)
+            .map(|_| {
+                let project = create_test_project(&db);
+                let epic = create_test_epic(&db, &project.id);
+                let task = create_task(
+                    &db,
+                    CreateTaskInput {
+                        epic_id: epic.id.clone(),
+                        title: "Same short ID".to_string(),
+                        description: String::new(),
+                        session_id: None,
+                        assignee: None,
+                    },
+                )
+                .unwrap();
+                (project, task)
+            })
+            .collect();
+
+        let err = resolve_task_id(&db, "E1-T1", None).unwrap_err().to_string();
+        assert!(err.starts_with("CONFLICT"), "{err}");
+        for (_, task) in &tasks {
+            assert!(err.contains(&task.id), "{err} should list {}", task.id);
+        }
+
+        for (project, task) in &tasks {
+            assert_eq!(resolve_task_id(&db, "E1-T1", Some(&project.id)).unwrap(), task.id);
+        }
+    }
+
     #[test]
     fn test_resolve_task_id_epic_not_found() {
         let (db, _dir) = open_temp_db();
@@ -692,6 +1366,7 @@ mod tests {
                 title: "Test Task".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -702,9 +1377,11 @@ mod tests {
 
     // --- sync_epic_status integration tests ---
 
-    /// Create a project, epic, and two tasks for epic-sync integration tests.
+    /// Create a project, epic, and two tasks for epic-sync integration tests,
+    /// with the auto epic status rule enabled.
     fn sync_fixture_with_two_tasks() -> (Database, TempDir, Epic, BlueTask, BlueTask) {
         let (db, dir) = open_temp_db();
+        db.set_auto_epic_status(true);
         let project = create_test_project(&db);
         let epic = create_test_epic(&db, &project.id);
         let t1 = create_task(
@@ -714,6 +1391,7 @@ mod tests {
                 title: "Task 1".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -724,6 +1402,7 @@ mod tests {
                 title: "Task 2".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -742,6 +1421,7 @@ mod tests {
                 status: Some(ItemStatus::Done),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
         let e = get_epic(&db, &epic.id).unwrap().unwrap();
@@ -755,6 +1435,7 @@ mod tests {
                 status: Some(ItemStatus::Done),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
         let e = get_epic(&db, &epic.id).unwrap().unwrap();
@@ -773,6 +1454,7 @@ mod tests {
                 status: Some(ItemStatus::Done),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
         let e = get_epic(&db, &epic.id).unwrap().unwrap();
@@ -784,12 +1466,80 @@ mod tests {
         assert_eq!(e.status, ItemStatus::Done);
     }
 
+    #[test]
+    fn test_reopening_task_moves_done_epic_back_to_in_progress() {
+        let (db, _dir, epic, t1, t2) = sync_fixture_with_two_tasks();
+        let set_status = |id: &str, status| {
+            update_task(
+                &db,
+                id,
+                UpdateTaskInput {
+                    status: Some(status),
+                    ..Default::default()
+                },
+                None,
+            )
+            .unwrap();
+        };
+
+        set_status(&t1.id, ItemStatus::Done);
+        set_status(&t2.id, ItemStatus::Done);
+        assert_eq!(get_epic(&db, &epic.id).unwrap().unwrap().status, ItemStatus::Done);
+
+        set_status(&t2.id, ItemStatus::Todo);
+        assert_eq!(get_epic(&db, &epic.id).unwrap().unwrap().status, ItemStatus::InProgress);
+    }
+
+    #[test]
+    fn test_epic_status_untouched_when_auto_rule_off() {
+        let (db, _dir, epic, t1, t2) = sync_fixture_with_two_tasks();
+        db.set_auto_epic_status(false);
+
+        for id in [&t1.id, &t2.id] {
+            update_task(
+                &db,
+                id,
+                UpdateTaskInput {
+                    status: Some(ItemStatus::Done),
+                    ..Default::default()
+                },
+                None,
+            )
+            .unwrap();
+        }
+        assert_eq!(get_epic(&db, &epic.id).unwrap().unwrap().status, ItemStatus::Todo);
+    }
+
+    #[test]
+    fn test_deleting_last_task_keeps_manually_done_epic() {
+        use crate::db::epic::update_epic;
+        use crate::models::UpdateEpicInput;
+
+        let (db, _dir, epic, t1, t2) = sync_fixture_with_two_tasks();
+        delete_task(&db, &t1.id).unwrap();
+        update_epic(
+            &db,
+            &epic.id,
+            UpdateEpicInput {
+                status: Some(ItemStatus::Done),
+                ..Default::default()
+            },
+            None,
+        )
+        .unwrap();
+
+        // With no tasks left there is nothing to derive a status from.
+        delete_task(&db, &t2.id).unwrap();
+        assert_eq!(get_epic(&db, &epic.id).unwrap().unwrap().status, ItemStatus::Done);
+    }
+
     #[test]
     fn test_create_task_syncs_epic_status() {
         use crate::db::epic::update_epic;
         use crate::models::UpdateEpicInput;
 
         let (db, _dir) = open_temp_db();
+        db.set_auto_epic_status(true);
         let project = create_test_project(&db);
         let epic = create_test_epic(&db, &project.id);
 
@@ -801,6 +1551,7 @@ mod tests {
                 status: Some(ItemStatus::Done),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
 
@@ -812,6 +1563,7 @@ mod tests {
                 title: "New Task".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -835,6 +1587,7 @@ mod tests {
                     title: title.to_string(),
                     description: String::new(),
                     session_id: None,
+                    assignee: None,
                 },
             )
             .unwrap()
@@ -846,4 +1599,262 @@ mod tests {
         assert_eq!(e1_t1.short_id, Some("E1-T1".to_string()));
         assert_eq!(e2_t1.short_id, Some("E2-T1".to_string()));
     }
+
+    // --- next_task tests ---
+
+    fn make_task(db: &Database, epic_id: &str, title: &str) -> BlueTask {
+        create_task(
+            db,
+            CreateTaskInput {
+                epic_id: epic_id.to_string(),
+                title: title.to_string(),
+                description: String::new(),
+                session_id: None,
+                assignee: None,
+            },
+        )
+        .unwrap()
+    }
+
+    fn block(db: &Database, blocker: (DependencyType, &str), blocked: (DependencyType, &str)) {
+        crate::db::dependency::add_dependency(
+            db,
+            crate::models::AddDependencyInput {
+                blocker_type: blocker.0,
+                blocker_id: blocker.1.to_string(),
+                blocked_type: blocked.0,
+                blocked_id: blocked.1.to_string(),
+            },
+            None,
+        )
+        .unwrap();
+    }
+
+    fn set_created_at(db: &Database, task: &BlueTask, created_at: &str) {
+        db.conn()
+            .execute("UPDATE tasks SET created_at = ?1 WHERE id = ?2", [created_at, &task.id])
+            .unwrap();
+    }
+
+    fn picked(db: &Database, project: &Project) -> BlueTask {
+        match next_task(db, &project.id).unwrap() {
+            NextTask::Ready { task, .. } => *task,
+            other => panic!("expected a ready task, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_next_task_without_open_tasks() {
+        let (db, _dir) = open_temp_db();
+        let project = create_test_project(&db);
+        let epic = create_test_epic(&db, &project.id);
+        assert!(matches!(next_task(&db, &project.id).unwrap(), NextTask::NoOpenTasks));
+
+        let done = make_task(&db, &epic.id, "Done");
+        update_task(
+            &db,
+            &done.id,
+            UpdateTaskInput {
+                status: Some(ItemStatus::Done),
+                ..Default::default()
+            },
+            None,
+        )
+        .unwrap();
+        assert!(matches!(next_task(&db, &project.id).unwrap(), NextTask::NoOpenTasks));
+    }
+
+    #[test]
+    fn test_next_task_all_blocked() {
+        let (db, _dir) = open_temp_db();
+        let project = create_test_project(&db);
+        let epic = create_test_epic(&db, &project.id);
+        let blocked_epic = create_test_epic(&db, &project.id);
+        let a = make_task(&db, &epic.id, "A");
+        let b = make_task(&db, &epic.id, "B");
+        make_task(&db, &blocked_epic.id, "C");
+        // A and B block each other; C's epic waits on the first epic.
+        block(&db, (DependencyType::Task, &a.id), (DependencyType::Task, &b.id));
+        block(&db, (DependencyType::Task, &b.id), (DependencyType::Task, &a.id));
+        block(&db, (DependencyType::Epic, &epic.id), (DependencyType::Epic, &blocked_epic.id));
+
+        assert!(matches!(
+            next_task(&db, &project.id).unwrap(),
+            NextTask::AllBlocked { open: 3 }
+        ));
+    }
+
+    #[test]
+    fn test_next_task_skips_blocked_and_prefers_in_progress_epic() {
+        let (db, _dir) = open_temp_db();
+        let project = create_test_project(&db);
+        let idle = create_test_epic(&db, &project.id);
+        let active = create_test_epic(&db, &project.id);
+        let oldest = make_task(&db, &idle.id, "Oldest");
+        let blocked = make_task(&db, &active.id, "Blocked");
+        let ready = make_task(&db, &active.id, "Ready");
+        let started = make_task(&db, &active.id, "Started");
+        set_created_at(&db, &oldest, "2024-01-01 00:00:00");
+        block(&db, (DependencyType::Task, &started.id), (DependencyType::Task, &blocked.id));
+        update_task(
+            &db,
+            &started.id,
+            UpdateTaskInput {
+                status: Some(ItemStatus::InProgress),
+                ..Default::default()
+            },
+            None,
+        )
+        .unwrap();
+        crate::db::epic::update_epic(
+            &db,
+            &active.id,
+            crate::models::UpdateEpicInput {
+                status: Some(ItemStatus::InProgress),
+                ..Default::default()
+            },
+            None,
+        )
+        .unwrap();
+
+        match next_task(&db, &project.id).unwrap() {
+            NextTask::Ready { task, epic_in_progress } => {
+                assert_eq!(task.id, ready.id);
+                assert!(epic_in_progress);
+            }
+            other => panic!("expected a ready task, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn test_next_task_breaks_ties_by_age() {
+        let (db, _dir) = open_temp_db();
+        let project = create_test_project(&db);
+        let epic = create_test_epic(&db, &project.id);
+        let newer = make_task(&db, &epic.id, "Newer");
+        let older = make_task(&db, &epic.id, "Older");
+        set_created_at(&db, &newer, "2024-02-01 00:00:00");
+        set_created_at(&db, &older, "2024-01-01 00:00:00");
+        assert_eq!(picked(&db, &project).id, older.id);
+
+        // Same timestamp: the task created first wins.
+        set_created_at(&db, &older, "2024-02-01 00:00:00");
+        assert_eq!(picked(&db, &project).id, newer.id);
+    }
+
+    #[test]
+    fn test_complete_chain_marks_blockers_done_first() {
+        let (db, _dir) = open_temp_db();
+        let project = create_test_project(&db);
+        let epic = create_test_epic(&db, &project.id);
+        let [a, b, c, d, other] =
+            ["A", "B", "C", "D", "Other"].map(|title| make_task(&db, &epic.id, title));
+        // a -> b -> d and a -> c -> d, with c already done.
+        for (blocker, blocked) in [(&a, &b), (&a, &c), (&b, &d), (&c, &d)] {
+            block(&db, (DependencyType::Task, &blocker.id), (DependencyType::Task, &blocked.id));
+        }
+        update_task(
+            &db,
+            &c.id,
+            UpdateTaskInput {
+                status: Some(ItemStatus::Done),
+                ..Default::default()
+            },
+            None,
+        )
+        .unwrap();
+
+        let completed = complete_chain(&db, &d.id, None).unwrap();
+        let ids: Vec<&str> = completed.iter().map(|t| t.id.as_str()).collect();
+        assert_eq!(ids, [a.id.as_str(), b.id.as_str(), d.id.as_str()]);
+        for task in [&a, &b, &c, &d] {
+            assert_eq!(get_task(&db, &task.id).unwrap().unwrap().status, ItemStatus::Done);
+        }
+        assert_eq!(get_task(&db, &other.id).unwrap().unwrap().status, ItemStatus::Todo);
+
+        // The history logs every blocker's completion before the blocked task's.
+        let logged: Vec<String> = db
+            .conn()
+            .prepare("SELECT entity_id FROM status_history WHERE to_status = 'done' ORDER BY id")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<rusqlite::Result<_>>()
+            .unwrap();
+        assert_eq!(logged, [&c.id, &a.id, &b.id, &d.id].map(String::clone));
+    }
+
+    #[test]
+    fn test_complete_chain_survives_cycles_and_missing_tasks() {
+        let (db, _dir) = open_temp_db();
+        db.set_auto_epic_status(true);
+        let project = create_test_project(&db);
+        let epic = create_test_epic(&db, &project.id);
+        let a = make_task(&db, &epic.id, "A");
+        let b = make_task(&db, &epic.id, "B");
+        block(&db, (DependencyType::Task, &a.id), (DependencyType::Task, &b.id));
+        block(&db, (DependencyType::Task, &b.id), (DependencyType::Task, &a.id));
+
+        let completed = complete_chain(&db, &b.id, None).unwrap();
+        assert_eq!(completed.len(), 2);
+        assert_eq!(completed[1].id, b.id);
+        assert_eq!(get_epic(&db, &epic.id).unwrap().unwrap().status, ItemStatus::Done);
+
+        let err = complete_chain(&db, "missing", None).unwrap_err();
+        assert!(err.to_string().contains("task not found"));
+    }
+
+    fn task_input(epic_id: &str, title: &str) -> CreateTaskInput {
+        CreateTaskInput {
+            epic_id: epic_id.to_string(),
+            title: title.to_string(),
+            description: String::new(),
+            session_id: None,
+            assignee: None,
+        }
+    }
+
+    #[test]
+    fn test_create_task_with_blockers_adds_each_dependency_once() {
+        let (db, _dir) = open_temp_db();
+        let project = create_test_project(&db);
+        let epic = create_test_epic(&db, &project.id);
+        let a = make_task(&db, &epic.id, "A");
+        let b = make_task(&db, &epic.id, "B");
+
+        let blockers = [a.id.clone(), b.id.clone(), a.id.clone()];
+        let task =
+            create_task_with_blockers(&db, task_input(&epic.id, "C"), &blockers, None).unwrap();
+        assert_eq!(task.title, "C");
+        let deps = crate::db::dependency::get_blockers(&db, &DependencyType::Task, &task.id)
+            .unwrap();
+        let mut blocker_ids: Vec<&str> = deps.iter().map(|d| d.blocker_id.as_str()).collect();
+        blocker_ids.sort();
+        let mut expected = [a.id.as_str(), b.id.as_str()];
+        expected.sort();
+        assert_eq!(blocker_ids, expected);
+    }
+
+    #[test]
+    fn test_create_task_with_missing_blocker_creates_nothing() {
+        let (db, _dir) = open_temp_db();
+        let project = create_test_project(&db);
+        let epic = create_test_epic(&db, &project.id);
+        let a = make_task(&db, &epic.id, "A");
+
+        let blockers = [a.id.clone(), "01J00000000000000000000000".to_string()];
+        let err = create_task_with_blockers(&db, task_input(&epic.id, "C"), &blockers, None)
+            .unwrap_err();
+        assert!(err.to_string().contains("task not found"));
+        let tasks = list_tasks(
+            &db,
+            &TaskListFilter {
+                epic_id: Some(&epic.id),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert!(crate::db::dependency::get_all_dependencies(&db).unwrap().is_empty());
+    }
 }
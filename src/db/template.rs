@@ -0,0 +1,309 @@
+use std::collections::HashSet;
+
+use anyhow::{Context, Result};
+use rusqlite::{OptionalExtension, Row};
+
+use crate::db::dependency::insert_dependency;
+use crate::db::task::{get_task, insert_task};
+use crate::db::Database;
+use crate::models::{
+    AddDependencyInput, BlueTask, CreateTaskInput, CreateTemplateInput, DependencyType,
+    TaskTemplate,
+};
+
+const SELECT_COLUMNS: &str = "id, project_id, name, description, tasks, created_at";
+
+fn row_to_template(row: &Row) -> rusqlite::Result<TaskTemplate> {
+    let tasks: String = row.get("tasks")?;
+    Ok(TaskTemplate {
+        id: row.get("id")?,
+        project_id: row.get("project_id")?,
+        name: row.get("name")?,
+        description: row.get("description")?,
+        tasks: serde_json::from_str(&tasks).map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(4, rusqlite::types::Type::Text, Box::new(e))
+        })?,
+        created_at: row.get("created_at")?,
+    })
+}
+
+/// Store a template. Its tasks may only depend on tasks listed before them,
+/// which keeps every applied template free of cycles. A position repeated
+/// in a task's `depends_on` is kept once.
+pub fn create_template(db: &Database, mut input: CreateTemplateInput) -> Result<TaskTemplate> {
+    anyhow::ensure!(!input.tasks.is_empty(), "a template needs at least one task");
+    for (position, task) in input.tasks.iter_mut().enumerate() {
+        anyhow::ensure!(!task.title.trim().is_empty(), "template task {position} has no title");
+        if let Some(blocker) = task.depends_on.iter().find(|&&b| b >= position) {
+            anyhow::bail!(
+                "template task {position} can only depend on earlier tasks, not task {blocker}"
+            );
+        }
+        let mut seen = HashSet::new();
+        task.depends_on.retain(|&blocker| seen.insert(blocker));
+    }
+
+    let id = ulid::Ulid::new().to_string();
+    let tasks = serde_json::to_string(&input.tasks).context("failed to encode template tasks")?;
+    db.write_transaction("template creation", |tx| {
+        if let Err(e) = tx.execute(
+            "INSERT INTO task_templates (id, project_id, name, description, tasks) \
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            [&id, &input.project_id, &input.name, &input.description, &tasks],
+        ) {
+            return match e {
+                rusqlite::Error::SqliteFailure(ref err, _)
+                    if err.extended_code == rusqlite::ffi::SQLITE_CONSTRAINT_UNIQUE =>
+                {
+                    anyhow::bail!("template already exists: {}", input.name)
+                }
+                _ => Err(e).context("failed to insert template"),
+            };
+        }
+        Ok(())
+    })?;
+
+    get_template(db, &id)?.context("template not found after insert")
+}
+
+pub fn get_template(db: &Database, id: &str) -> Result<Option<TaskTemplate>> {
+    let sql = format!("SELECT {SELECT_COLUMNS} FROM task_templates WHERE id = ?1");
+    db.conn()
+        .prepare(&sql)?
+        .query_row([id], row_to_template)
+        .optional()
+        .context("failed to query template")
+}
+
+/// Find a template of `project_id` by its ID or exact name.
+pub fn resolve_template(db: &Database, project_id: &str, id_or_name: &str) -> Result<TaskTemplate> {
+    let sql = format!(
+        "SELECT {SELECT_COLUMNS} FROM task_templates \
+         WHERE project_id = ?1 AND (id = ?2 OR name = ?2)"
+    );
+    db.conn()
+        .prepare(&sql)?
+        .query_row([project_id, id_or_name], row_to_template)
+        .optional()
+        .context("failed to query template")?
+        .ok_or_else(|| anyhow::anyhow!("template not found: {id_or_name}"))
+}
+
+/// Create the template's tasks at the end of epic `epic_id`, with the
+/// dependencies among them, in one transaction. Returns the tasks in
+/// template order.
+pub fn apply_template(
+    db: &Database,
+    template: &TaskTemplate,
+    epic_id: &str,
+    actor: Option<&str>,
+) -> Result<Vec<BlueTask>> {
+    let ids: Vec<String> = template.tasks.iter().map(|_| ulid::Ulid::new().to_string()).collect();
+    db.write_transaction("applying template", |tx| {
+        let epic_project: Option<String> = tx
+            .query_row("SELECT project_id FROM epics WHERE id = ?1", [epic_id], |row| row.get(0))
+            .optional()
+            .context("failed to look up epic")?;
+        match epic_project {
+            None => anyhow::bail!("epic not found: {epic_id}"),
+            Some(project_id) if project_id != template.project_id => {
+                anyhow::bail!("template {} belongs to another project", template.name)
+            }
+            Some(_) => {}
+        }
+
+        for (id, task) in ids.iter().zip(&template.tasks) {
+            let input = CreateTaskInput {
+                epic_id: epic_id.to_string(),
+                title: task.title.clone(),
+                description: task.description.clone(),
+                session_id: None,
+                assignee: None,
+            };
+            insert_task(tx, db, id, &input, actor)?;
+        }
+        for (id, task) in ids.iter().zip(&template.tasks) {
+            for &blocker in &task.depends_on {
+                let dependency = AddDependencyInput {
+                    blocker_type: DependencyType::Task,
+                    blocker_id: ids[blocker].clone(),
+                    blocked_type: DependencyType::Task,
+                    blocked_id: id.clone(),
+                };
+                insert_dependency(tx, &dependency, actor)?;
+            }
+        }
+        Ok(())
+    })?;
+
+    ids.iter()
+        .map(|id| get_task(db, id)?.context("task not found after insert"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::dependency::get_blockers;
+    use crate::db::epic::create_epic;
+    use crate::db::project::create_project;
+    use crate::models::{CreateEpicInput, CreateProjectInput, TemplateTask};
+    use tempfile::TempDir;
+
+    fn open_temp_db() -> (Database, TempDir) {
+        let dir = TempDir::new().unwrap();
+        let db = Database::open(&dir.path().join("test.db")).unwrap();
+        db.migrate().unwrap();
+        (db, dir)
+    }
+
+    fn project_and_epic(db: &Database, name: &str) -> (String, String) {
+        let project = create_project(
+            db,
+            CreateProjectInput { name: name.to_string(), description: String::new() },
+        )
+        .unwrap();
+        let epic = create_epic(
+            db,
+            CreateEpicInput {
+                project_id: project.id.clone(),
+                title: "Feature".to_string(),
+                description: String::new(),
+                prd_id: None,
+            },
+            None,
+        )
+        .unwrap();
+        (project.id, epic.id)
+    }
+
+    fn template_task(title: &str, depends_on: &[usize]) -> TemplateTask {
+        TemplateTask {
+            title: title.to_string(),
+            description: String::new(),
+            depends_on: depends_on.to_vec(),
+        }
+    }
+
+    fn feature_template(db: &Database, project_id: &str) -> TaskTemplate {
+        create_template(
+            db,
+            CreateTemplateInput {
+                project_id: project_id.to_string(),
+                name: "feature".to_string(),
+                description: "Every feature ships with these".to_string(),
+                tasks: vec![
+                    template_task("Implement", &[]),
+                    template_task("Write tests", &[0]),
+                    template_task("Update docs", &[0, 1]),
+                ],
+            },
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_create_template_round_trips_and_resolves_by_name() {
+        let (db, _dir) = open_temp_db();
+        let (project_id, _) = project_and_epic(&db, "P");
+        let template = feature_template(&db, &project_id);
+        assert_eq!(template.tasks[2].depends_on, [0, 1]);
+
+        let by_name = resolve_template(&db, &project_id, "feature").unwrap();
+        assert_eq!(by_name.id, template.id);
+        assert_eq!(by_name.tasks, template.tasks);
+        let by_id = resolve_template(&db, &project_id, &template.id).unwrap();
+        assert_eq!(by_id.name, "feature");
+        let err = resolve_template(&db, &project_id, "missing").unwrap_err();
+        assert!(err.to_string().contains("template not found: missing"));
+    }
+
+    #[test]
+    fn test_create_template_validates_tasks_and_name() {
+        let (db, _dir) = open_temp_db();
+        let (project_id, _) = project_and_epic(&db, "P");
+        feature_template(&db, &project_id);
+
+        let input = |name: &str, tasks: Vec<TemplateTask>| CreateTemplateInput {
+            project_id: project_id.clone(),
+            name: name.to_string(),
+            description: String::new(),
+            tasks,
+        };
+        let err = create_template(&db, input("empty", vec![])).unwrap_err();
+        assert!(err.to_string().contains("at least one task"));
+        let forward = vec![template_task("A", &[1]), template_task("B", &[])];
+        let err = create_template(&db, input("forward", forward)).unwrap_err();
+        assert!(err.to_string().contains("only depend on earlier tasks"));
+        let err = create_template(&db, input("feature", vec![template_task("A", &[])]))
+            .unwrap_err();
+        assert!(err.to_string().contains("template already exists: feature"));
+    }
+
+    #[test]
+    fn test_create_template_keeps_a_repeated_dependency_once() {
+        let (db, _dir) = open_temp_db();
+        let (project_id, epic_id) = project_and_epic(&db, "P");
+        let template = create_template(
+            &db,
+            CreateTemplateInput {
+                project_id,
+                name: "repeat".to_string(),
+                description: String::new(),
+                tasks: vec![template_task("A", &[]), template_task("B", &[0, 0])],
+            },
+        )
+        .unwrap();
+        assert_eq!(template.tasks[1].depends_on, [0]);
+
+        let tasks = apply_template(&db, &template, &epic_id, None).unwrap();
+        let blockers = get_blockers(&db, &DependencyType::Task, &tasks[1].id).unwrap();
+        assert_eq!(blockers.len(), 1);
+        assert_eq!(blockers[0].blocker_id, tasks[0].id);
+    }
+
+    #[test]
+    fn test_apply_template_creates_tasks_and_dependencies() {
+        let (db, _dir) = open_temp_db();
+        let (project_id, epic_id) = project_and_epic(&db, "P");
+        let template = feature_template(&db, &project_id);
+
+        let tasks = apply_template(&db, &template, &epic_id, None).unwrap();
+        let titles: Vec<&str> = tasks.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, ["Implement", "Write tests", "Update docs"]);
+        assert!(tasks.iter().all(|t| t.epic_id == epic_id));
+
+        let blockers_of = |i: usize| -> Vec<String> {
+            let mut ids: Vec<String> = get_blockers(&db, &DependencyType::Task, &tasks[i].id)
+                .unwrap()
+                .into_iter()
+                .map(|d| d.blocker_id)
+                .collect();
+            ids.sort();
+            ids
+        };
+        assert!(blockers_of(0).is_empty());
+        assert_eq!(blockers_of(1), [tasks[0].id.clone()]);
+        let mut expected = vec![tasks[0].id.clone(), tasks[1].id.clone()];
+        expected.sort();
+        assert_eq!(blockers_of(2), expected);
+
+        // Applying again adds a second, independent set.
+        let again = apply_template(&db, &template, &epic_id, None).unwrap();
+        assert_eq!(again.len(), 3);
+        assert!(again.iter().all(|t| !tasks.iter().any(|o| o.id == t.id)));
+    }
+
+    #[test]
+    fn test_apply_template_refuses_an_epic_of_another_project() {
+        let (db, _dir) = open_temp_db();
+        let (project_id, _) = project_and_epic(&db, "P");
+        let (_, other_epic) = project_and_epic(&db, "Q");
+        let template = feature_template(&db, &project_id);
+
+        let err = apply_template(&db, &template, &other_epic, None).unwrap_err();
+        assert!(err.to_string().contains("belongs to another project"));
+        let err = apply_template(&db, &template, "missing", None).unwrap_err();
+        assert!(err.to_string().contains("epic not found"));
+    }
+}
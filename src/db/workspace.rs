@@ -0,0 +1,196 @@
+//! Named databases ("workspaces") kept side by side in `~/.blueprint`.
+//!
+//! Workspace `name` lives at `~/.blueprint/<name>.db`. The database opened
+//! when no workspace is given is `blueprint.db`, so it shows up in the list
+//! like any other.
+//!
+//! A base directory given with `--data-dir` or `BLUEPRINT_DATA_DIR` takes
+//! the place of home: databases go to `<base>/.blueprint`, and settings are
+//! read from `<base>/.blueprint/setting.json` instead of the working
+//! directory. `--data-dir .` keeps everything in the current project.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+
+/// Name of the workspace backing `~/.blueprint/blueprint.db`.
+pub const DEFAULT_WORKSPACE: &str = "blueprint";
+
+const DB_EXTENSION: &str = "db";
+
+/// Base directory for the databases and settings, below `--data-dir`.
+pub const DATA_DIR_ENV: &str = "BLUEPRINT_DATA_DIR";
+/// Path of the default database file, overriding `BLUEPRINT_DATA_DIR`.
+pub const DB_ENV: &str = "BLUEPRINT_DB";
+
+const BLUEPRINT_DIR: &str = ".blueprint";
+
+fn env_var(key: &str) -> Option<String> {
+    std::env::var(key).ok().filter(|v| !v.is_empty())
+}
+
+/// The base directory from `--data-dir`, else `BLUEPRINT_DATA_DIR`. `None`
+/// leaves the defaults: home for databases, the working directory for
+/// settings.
+pub fn configured_base(flag: Option<&Path>) -> Option<PathBuf> {
+    configured_base_with(flag, env_var)
+}
+
+fn configured_base_with(
+    flag: Option<&Path>,
+    env: impl Fn(&str) -> Option<String>,
+) -> Option<PathBuf> {
+    flag.map(Path::to_path_buf).or_else(|| env(DATA_DIR_ENV).map(PathBuf::from))
+}
+
+/// `<base>/.blueprint`, where workspace databases are kept; `~/.blueprint`
+/// without a base.
+pub fn data_dir(base: Option<&Path>) -> Result<PathBuf> {
+    data_dir_with(base, env_var)
+}
+
+fn data_dir_with(base: Option<&Path>, env: impl Fn(&str) -> Option<String>) -> Result<PathBuf> {
+    let base = match base {
+        Some(base) => base.to_path_buf(),
+        None => PathBuf::from(env("HOME").context("HOME environment variable not set")?),
+    };
+    Ok(base.join(BLUEPRINT_DIR))
+}
+
+/// The database opened when no workspace is named. In order of precedence:
+/// `--data-dir`, `BLUEPRINT_DB`, `BLUEPRINT_DATA_DIR`, then
+/// `~/.blueprint/blueprint.db`.
+pub fn default_db_path(flag: Option<&Path>) -> Result<PathBuf> {
+    default_db_path_with(flag, env_var)
+}
+
+fn default_db_path_with(
+    flag: Option<&Path>,
+    env: impl Fn(&str) -> Option<String>,
+) -> Result<PathBuf> {
+    if flag.is_none()
+        && let Some(path) = env(DB_ENV)
+    {
+        return Ok(PathBuf::from(path));
+    }
+    let base = configured_base_with(flag, &env);
+    workspace_path(&data_dir_with(base.as_deref(), &env)?, DEFAULT_WORKSPACE)
+}
+
+/// Path of workspace `name` inside `dir`. Names are plain file stems, so
+/// anything that could escape `dir` is rejected.
+pub fn workspace_path(dir: &Path, name: &str) -> Result<PathBuf> {
+    let valid = !name.is_empty()
+        && !name.starts_with('.')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'));
+    if !valid {
+        anyhow::bail!(
+            "invalid workspace name '{name}': use letters, digits, '-', '_' or '.'"
+        );
+    }
+    Ok(dir.join(format!("{name}.{DB_EXTENSION}")))
+}
+
+/// Names of the workspaces (`*.db` files) in `dir`, sorted. A missing
+/// directory has no workspaces.
+pub fn list_workspaces(dir: &Path) -> Result<Vec<String>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => {
+            return Err(e).with_context(|| format!("failed to read {}", dir.display()));
+        }
+    };
+
+    let mut names = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        if !path.is_file() || path.extension().and_then(|e| e.to_str()) != Some(DB_EXTENSION) {
+            continue;
+        }
+        if let Some(stem) = path.file_stem().and_then(|s| s.to_str()) {
+            names.push(stem.to_string());
+        }
+    }
+    names.sort();
+    Ok(names)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn workspace_path_maps_name_to_db_file() {
+        let dir = Path::new("/home/me/.blueprint");
+        assert_eq!(
+            workspace_path(dir, "client-a").unwrap(),
+            dir.join("client-a.db")
+        );
+        assert_eq!(
+            workspace_path(dir, DEFAULT_WORKSPACE).unwrap(),
+            dir.join("blueprint.db")
+        );
+    }
+
+    #[test]
+    fn workspace_path_rejects_names_leaving_the_directory() {
+        let dir = Path::new("/home/me/.blueprint");
+        for name in ["", "../other", "a/b", ".hidden", "a b"] {
+            assert!(workspace_path(dir, name).is_err(), "{name:?} should be rejected");
+        }
+    }
+
+    fn env<'a>(vars: &'a [(&str, &str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |key| vars.iter().find(|(k, _)| *k == key).map(|(_, v)| v.to_string())
+    }
+
+    #[test]
+    fn default_db_path_precedence() {
+        let home = ("HOME", "/home/me");
+        let db = (DB_ENV, "/tmp/explicit.db");
+        let data = (DATA_DIR_ENV, "/srv/data");
+        let flag = Some(Path::new("/work/proj"));
+        let path = |flag, vars: &[(&str, &str)]| default_db_path_with(flag, env(vars)).unwrap();
+
+        assert_eq!(
+            path(flag, &[home, db, data]),
+            Path::new("/work/proj/.blueprint/blueprint.db")
+        );
+        assert_eq!(path(None, &[home, db, data]), Path::new("/tmp/explicit.db"));
+        assert_eq!(path(None, &[home, data]), Path::new("/srv/data/.blueprint/blueprint.db"));
+        assert_eq!(path(None, &[home]), Path::new("/home/me/.blueprint/blueprint.db"));
+        assert!(default_db_path_with(None, env(&[])).is_err());
+    }
+
+    #[test]
+    fn named_workspaces_follow_the_base_but_not_blueprint_db() {
+        let db = (DB_ENV, "/tmp/explicit.db");
+        let data = (DATA_DIR_ENV, "/srv/data");
+        let flag = Some(Path::new("/work/proj"));
+
+        assert_eq!(configured_base_with(flag, env(&[data])), Some(PathBuf::from("/work/proj")));
+        assert_eq!(configured_base_with(None, env(&[db, data])), Some(PathBuf::from("/srv/data")));
+        assert_eq!(configured_base_with(None, env(&[db])), None);
+        assert_eq!(
+            data_dir_with(None, env(&[("HOME", "/home/me")])).unwrap(),
+            Path::new("/home/me/.blueprint")
+        );
+    }
+
+    #[test]
+    fn list_workspaces_finds_db_files_only() {
+        let home = TempDir::new().unwrap();
+        let dir = home.path().join(".blueprint");
+        assert!(list_workspaces(&dir).unwrap().is_empty());
+
+        std::fs::create_dir_all(dir.join("nested.db")).unwrap();
+        for file in ["work.db", "blueprint.db", "blueprint.db-wal", "setting.json", "alpha.db"] {
+            std::fs::write(dir.join(file), b"").unwrap();
+        }
+        assert_eq!(list_workspaces(&dir).unwrap(), ["alpha", "blueprint", "work"]);
+    }
+}
@@ -1,6 +1,13 @@
-use std::collections::{BTreeSet, HashMap, VecDeque};
+//! Layout of the dependency graph, shared by the TUI graph view, `export`
+//! and the `get_graph` MCP tool: layering ([`DagLayout`]), node sizes and
+//! canvas positions.
 
-use crate::models::ItemStatus;
+use std::collections::{BTreeSet, HashMap, HashSet, VecDeque};
+use std::fmt;
+
+use crate::db::Database;
+use crate::db::dependency::{get_blocked_by, get_blocker_items};
+use crate::models::{BlueTask, DependencyType, Epic, ItemStatus};
 
 /// A node in the dependency DAG.
 #[derive(Debug, Clone)]
@@ -19,6 +26,21 @@ pub struct Edge {
     pub to: String,
 }
 
+/// Returned by [`DagLayout::topological_order`] when the graph is not acyclic.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CycleError {
+    /// A node that lies on a dependency cycle.
+    pub node: String,
+}
+
+impl fmt::Display for CycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "dependency cycle detected at node {}", self.node)
+    }
+}
+
+impl std::error::Error for CycleError {}
+
 /// DAG layout computed via Kahn's topological sort and longest-path layer assignment.
 #[derive(Debug)]
 pub struct DagLayout {
@@ -34,11 +56,15 @@ impl DagLayout {
     ///
     /// Edges referencing unknown node IDs are silently filtered out.
     /// Orphan nodes (not referenced by any valid edge) are placed in `orphans`
-    /// with `layer = None`. Cycle participants are detected and placed in a
+    /// with `layer = None`, keeping the order they were given in. Cycle participants are detected and placed in a
     /// fallback layer at the end.
     pub fn new(nodes: Vec<Node>, edges: Vec<Edge>) -> Self {
         let mut node_map: HashMap<String, Node> = HashMap::new();
+        let mut input_order: Vec<String> = Vec::new();
         for node in nodes {
+            if !node_map.contains_key(&node.id) {
+                input_order.push(node.id.clone());
+            }
             node_map.insert(node.id.clone(), node);
         }
 
@@ -67,12 +93,10 @@ impl DagLayout {
         }
 
         // Identify orphans: nodes not in any valid edge.
-        let mut orphans: Vec<String> = node_map
-            .keys()
-            .filter(|id| !connected.contains(*id))
-            .cloned()
+        let orphans: Vec<String> = input_order
+            .into_iter()
+            .filter(|id| !connected.contains(id))
             .collect();
-        orphans.sort();
 
         // Mark orphan nodes with layer = None.
         for id in &orphans {
@@ -94,8 +118,8 @@ impl DagLayout {
         cycle_nodes.sort();
 
         if !cycle_nodes.is_empty() {
-            eprintln!(
-                "Warning: dependency cycle detected among nodes: {}",
+            log::warn!(
+                "dependency cycle detected among nodes: {}",
                 cycle_nodes.join(", ")
             );
         }
@@ -231,7 +255,9 @@ impl DagLayout {
     /// passes. In each pass, nodes within a layer are sorted by the average
     /// x-position (barycenter) of their connected nodes in the adjacent layer.
     /// Nodes with no connections to the adjacent layer retain their current
-    /// position. After reordering, each node's `x_position` is updated.
+    /// position. A sweep can undo an earlier improvement, so the ordering
+    /// with the fewest crossings seen, starting from the sorted one, is kept.
+    /// After reordering, each node's `x_position` is updated.
     fn minimize_crossings(&mut self) {
         if self.layers.len() < 2 {
             self.assign_x_positions();
@@ -252,8 +278,13 @@ impl DagLayout {
                 .push(edge.from.clone());
         }
 
-        // Run 3 iterations of forward + backward passes.
+        let mut best = (self.crossing_count(), self.layers.clone());
+
+        // Run up to 3 iterations of forward + backward passes.
         for _ in 0..3 {
+            if best.0 == 0 {
+                break;
+            }
             // Forward pass: top-to-bottom (layer 1, 2, ..., n-1).
             for layer_idx in 1..self.layers.len() {
                 self.sort_layer_by_barycenter(layer_idx, layer_idx - 1, &parents_map);
@@ -263,8 +294,14 @@ impl DagLayout {
             for layer_idx in (0..self.layers.len() - 1).rev() {
                 self.sort_layer_by_barycenter(layer_idx, layer_idx + 1, &children_map);
             }
+
+            let crossings = self.crossing_count();
+            if crossings < best.0 {
+                best = (crossings, self.layers.clone());
+            }
         }
 
+        self.layers = best.1;
         self.assign_x_positions();
     }
 
@@ -323,6 +360,45 @@ impl DagLayout {
         Some(positions.iter().sum::<f64>() / positions.len() as f64)
     }
 
+    /// Number of pairs of edges that cross, given the current order within
+    /// each layer. Only edges between adjacent layers are counted.
+    pub fn crossing_count(&self) -> usize {
+        let positions: HashMap<&str, (usize, usize)> = self
+            .layers
+            .iter()
+            .enumerate()
+            .flat_map(|(l, layer)| {
+                layer.iter().enumerate().map(move |(x, id)| (id.as_str(), (l, x)))
+            })
+            .collect();
+
+        // (upper x, lower x) of each edge, grouped by its upper layer.
+        let mut pairs: Vec<Vec<(usize, usize)>> = vec![Vec::new(); self.layers.len()];
+        for edge in &self.edges {
+            if let (Some(&(fl, fx)), Some(&(tl, tx))) =
+                (positions.get(edge.from.as_str()), positions.get(edge.to.as_str()))
+                && tl == fl + 1
+            {
+                pairs[fl].push((fx, tx));
+            }
+        }
+
+        pairs
+            .iter()
+            .map(|layer| {
+                let mut count = 0;
+                for (i, &(a, b)) in layer.iter().enumerate() {
+                    for &(c, d) in &layer[i + 1..] {
+                        if (a < c && b > d) || (a > c && b < d) {
+                            count += 1;
+                        }
+                    }
+                }
+                count
+            })
+            .sum()
+    }
+
     /// Assign `x_position` to each node based on its index within its layer.
     fn assign_x_positions(&mut self) {
         for layer in &self.layers {
@@ -338,17 +414,247 @@ impl DagLayout {
         self.layers.len()
     }
 
-    pub fn node_count(&self) -> usize {
-        self.nodes.len()
+    /// A linear order in which every blocker precedes the items it blocks:
+    /// connected nodes layer by layer, then orphans. Fails with a node on a
+    /// cycle when the graph is not a DAG.
+    pub fn topological_order(&self) -> Result<Vec<String>, CycleError> {
+        if let Some(node) = self.node_on_cycle() {
+            return Err(CycleError { node });
+        }
+        Ok(self
+            .layers
+            .iter()
+            .flatten()
+            .chain(&self.orphans)
+            .cloned()
+            .collect())
+    }
+
+    /// Nodes that `id` transitively depends on.
+    pub fn ancestors(&self, id: &str) -> HashSet<String> {
+        self.reachable(id, |e| (&e.to, &e.from))
+    }
+
+    /// Nodes that transitively depend on `id`.
+    pub fn descendants(&self, id: &str) -> HashSet<String> {
+        self.reachable(id, |e| (&e.from, &e.to))
+    }
+
+    /// `id` together with its whole blocking chain in both directions.
+    pub fn related_nodes(&self, id: &str) -> HashSet<String> {
+        let mut related = self.ancestors(id);
+        related.extend(self.descendants(id));
+        related.insert(id.to_string());
+        related
+    }
+
+    /// Returns `true` if the node has any incoming edge from a non-done node.
+    pub fn has_incomplete_blocker(&self, id: &str) -> bool {
+        self.edges.iter().any(|e| e.to == id && self.is_open(&e.from))
+    }
+
+    /// Returns `true` if the node is either end of an edge whose blocker is
+    /// not done, i.e. it is blocked or is still blocking something.
+    pub fn on_incomplete_edge(&self, id: &str) -> bool {
+        self.has_incomplete_blocker(id)
+            || (self.is_open(id) && self.edges.iter().any(|e| e.from == id))
+    }
+
+    fn is_open(&self, id: &str) -> bool {
+        self.nodes.get(id).is_some_and(|n| n.status != ItemStatus::Done)
+    }
+
+    /// Breadth-first walk from `id`, following each edge from the first to
+    /// the second endpoint returned by `step`. The start node is excluded
+    /// unless a cycle leads back to it.
+    fn reachable<'a>(&'a self, id: &str, step: impl Fn(&'a Edge) -> (&'a String, &'a String)) -> HashSet<String> {
+        let mut seen: HashSet<String> = HashSet::new();
+        let mut queue: VecDeque<&str> = VecDeque::from([id]);
+        while let Some(current) = queue.pop_front() {
+            for (near, far) in self.edges.iter().map(&step) {
+                if near == current && seen.insert(far.clone()) {
+                    queue.push_back(far);
+                }
+            }
+        }
+        seen
+    }
+
+    /// `cycle_nodes` also holds nodes merely downstream of a cycle. Every one
+    /// of them has a parent that is also in `cycle_nodes`, so walking parents
+    /// from any of them must revisit a node, and that node is on a cycle.
+    fn node_on_cycle(&self) -> Option<String> {
+        let unsorted: HashSet<&str> = self.cycle_nodes.iter().map(String::as_str).collect();
+        let mut current = self.cycle_nodes.first()?.as_str();
+        let mut seen: HashSet<&str> = HashSet::new();
+        while seen.insert(current) {
+            current = self
+                .edges
+                .iter()
+                .filter(|e| e.to == current && unsorted.contains(e.from.as_str()))
+                .map(|e| e.from.as_str())
+                .min()?;
+        }
+        Some(current.to_string())
+    }
+}
+
+// ── Building and placing graphs ──────────────────────────────────────
+
+/// Default total width of a rendered node box (including border characters).
+pub const NODE_WIDTH: usize = 30;
+
+/// Height of a task node (top border + title + bottom border).
+pub const NODE_HEIGHT_TASK: usize = 3;
+
+/// Height of a task node with a 2-line title.
+pub const NODE_HEIGHT_TASK_2LINE: usize = 4;
+
+/// Height of an epic node (top border + title + progress + bottom border).
+pub const NODE_HEIGHT_EPIC: usize = 4;
+
+/// Height of an epic node with a 2-line title.
+pub const NODE_HEIGHT_EPIC_2LINE: usize = 5;
+
+/// Horizontal gap between nodes when the pane width is unknown.
+pub const DEFAULT_NODE_GAP: usize = 4;
+/// Narrowest gap allowed when packing nodes into a pane.
+const MIN_NODE_GAP: usize = 2;
+/// Widest gap used when spreading a few nodes across a wide pane.
+const MAX_NODE_GAP: usize = 8;
+
+/// Node text: the title, prefixed with the short ID when there is one.
+pub fn node_label(short_id: &Option<String>, title: &str) -> String {
+    match short_id {
+        Some(sid) => format!("[{sid}] {title}"),
+        None => title.to_string(),
+    }
+}
+
+/// Graph nodes for `epics`, in order, and the dependency edges between them.
+pub fn epic_nodes_and_edges(db: &Database, epics: &[Epic]) -> (Vec<Node>, Vec<Edge>) {
+    let nodes = epics
+        .iter()
+        .map(|e| unplaced_node(&e.id, &e.short_id, &e.title, &e.status))
+        .collect();
+    let edges = dependency_edges(db, epics.iter().map(|e| &e.id), &DependencyType::Epic);
+    (nodes, edges)
+}
+
+/// Graph nodes for `tasks`, in order, and the dependency edges between them.
+pub fn task_nodes_and_edges(db: &Database, tasks: &[BlueTask]) -> (Vec<Node>, Vec<Edge>) {
+    let nodes = tasks
+        .iter()
+        .map(|t| unplaced_node(&t.id, &t.short_id, &t.title, &t.status))
+        .collect();
+    let edges = dependency_edges(db, tasks.iter().map(|t| &t.id), &DependencyType::Task);
+    (nodes, edges)
+}
+
+fn unplaced_node(id: &str, short_id: &Option<String>, title: &str, status: &ItemStatus) -> Node {
+    Node {
+        id: id.to_string(),
+        label: node_label(short_id, title),
+        status: status.clone(),
+        layer: None,
+        x_position: 0,
     }
+}
 
-    pub fn edge_count(&self) -> usize {
-        self.edges.len()
+/// Blocker → blocked edges leaving `item_ids` towards items of the same type.
+/// Edges to items outside the graph are dropped by [`DagLayout::new`].
+fn dependency_edges<'a>(
+    db: &Database,
+    item_ids: impl Iterator<Item = &'a String>,
+    dep_type: &DependencyType,
+) -> Vec<Edge> {
+    let mut edges = Vec::new();
+    for id in item_ids {
+        if let Ok(deps) = get_blocked_by(db, dep_type, id) {
+            for dep in deps {
+                if &dep.blocked_type == dep_type {
+                    edges.push(Edge {
+                        from: id.clone(),
+                        to: dep.blocked_id,
+                    });
+                }
+            }
+        }
     }
+    edges
+}
+
+/// Nodes of `layout` with an unfinished blocker that is not in the graph:
+/// an item of another type, or of the same type but out of scope, such as
+/// a task in another epic. Their blocked state has no incoming edge to show.
+pub fn external_blockers(
+    db: &Database,
+    layout: &DagLayout,
+    dep_type: &DependencyType,
+) -> HashSet<String> {
+    layout
+        .nodes
+        .keys()
+        .filter(|id| {
+            get_blocker_items(db, dep_type, id).is_ok_and(|blockers| {
+                blockers.iter().any(|b| {
+                    b.status != ItemStatus::Done
+                        && (&b.item_type != dep_type || !layout.nodes.contains_key(&b.id))
+                })
+            })
+        })
+        .cloned()
+        .collect()
+}
 
-    pub fn has_cycles(&self) -> bool {
-        !self.cycle_nodes.is_empty()
+/// Canvas positions for every node: one band per layer with orphans last.
+///
+/// Bands wrap onto extra rows when they hold more nodes than fit in
+/// `viewport_width`, and the gap between columns grows to use spare width.
+/// A width of 0 means unknown: bands never wrap and use the default gap.
+pub fn position_nodes(
+    layout: &DagLayout,
+    node_height: usize,
+    viewport_width: usize,
+    node_width: usize,
+) -> HashMap<String, (usize, usize)> {
+    // Use the max possible height (2-line title) for spacing so all nodes fit.
+    let max_height = node_height + 1; // +1 for potential 2-line title
+    let v_spacing = max_height + 2;
+
+    let widest = layout
+        .layers
+        .iter()
+        .chain([&layout.orphans])
+        .map(Vec::len)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    let (columns, gap) = if viewport_width == 0 {
+        (widest, DEFAULT_NODE_GAP)
+    } else {
+        // One column of left margin, as with the unwrapped layout.
+        let usable = viewport_width.saturating_sub(1);
+        let fit = ((usable + MIN_NODE_GAP) / (node_width + MIN_NODE_GAP)).max(1);
+        let columns = fit.min(widest);
+        let slack = usable.saturating_sub(columns * node_width);
+        let gap = (slack / (columns - 1).max(1)).clamp(MIN_NODE_GAP, MAX_NODE_GAP);
+        (columns, gap)
+    };
+    let h_spacing = node_width + gap;
+
+    let mut node_positions = HashMap::new();
+    let mut row = 0;
+    for band in layout.layers.iter().chain([&layout.orphans]) {
+        for (i, node_id) in band.iter().enumerate() {
+            let x = 1 + (i % columns) * h_spacing;
+            let y = 1 + (row + i / columns) * v_spacing;
+            node_positions.insert(node_id.clone(), (x, y));
+        }
+        row += band.len().div_ceil(columns);
     }
+    node_positions
 }
 
 #[cfg(test)]
@@ -385,7 +691,7 @@ mod tests {
         assert_eq!(layout.layers[1], vec!["B"]);
         assert_eq!(layout.layers[2], vec!["C"]);
         assert!(layout.orphans.is_empty());
-        assert!(!layout.has_cycles());
+        assert!(layout.cycle_nodes.is_empty());
     }
 
     #[test]
@@ -406,7 +712,7 @@ mod tests {
         assert_eq!(layout.layers[0], vec!["A"]);
         assert_eq!(layout.layers[1], vec!["B", "C"]);
         assert_eq!(layout.layers[2], vec!["D"]);
-        assert!(!layout.has_cycles());
+        assert!(layout.cycle_nodes.is_empty());
     }
 
     #[test]
@@ -443,7 +749,37 @@ mod tests {
 
         assert_eq!(layout.layer_count(), 0);
         assert_eq!(layout.orphans, vec!["A", "B", "C"]);
-        assert_eq!(layout.edge_count(), 0);
+        assert_eq!(layout.edges.len(), 0);
+    }
+
+    #[test]
+    fn orphans_keep_input_order() {
+        let layout = DagLayout::new(
+            vec![node("C"), node("A"), node("X"), node("B"), node("Y")],
+            vec![edge("X", "Y")],
+        );
+
+        assert_eq!(layout.orphans, vec!["C", "A", "B"]);
+    }
+
+    #[test]
+    fn incomplete_edges_ignore_done_blockers() {
+        let done = Node {
+            status: ItemStatus::Done,
+            ..node("D")
+        };
+        let layout = DagLayout::new(
+            vec![node("A"), node("B"), done, node("E"), node("O")],
+            vec![edge("A", "B"), edge("D", "E")],
+        );
+
+        assert!(layout.has_incomplete_blocker("B"));
+        assert!(!layout.has_incomplete_blocker("E"));
+        assert!(layout.on_incomplete_edge("A"));
+        assert!(layout.on_incomplete_edge("B"));
+        assert!(!layout.on_incomplete_edge("D"));
+        assert!(!layout.on_incomplete_edge("E"));
+        assert!(!layout.on_incomplete_edge("O"));
     }
 
     #[test]
@@ -451,17 +787,17 @@ mod tests {
         let layout = DagLayout::new(vec![], vec![]);
 
         assert_eq!(layout.layer_count(), 0);
-        assert_eq!(layout.node_count(), 0);
-        assert_eq!(layout.edge_count(), 0);
+        assert_eq!(layout.nodes.len(), 0);
+        assert_eq!(layout.edges.len(), 0);
         assert!(layout.orphans.is_empty());
-        assert!(!layout.has_cycles());
+        assert!(layout.cycle_nodes.is_empty());
     }
 
     #[test]
     fn single_node() {
         let layout = DagLayout::new(vec![node("A")], vec![]);
 
-        assert_eq!(layout.node_count(), 1);
+        assert_eq!(layout.nodes.len(), 1);
         assert_eq!(layout.orphans, vec!["A"]);
         assert_eq!(layout.layer_count(), 0);
     }
@@ -474,7 +810,7 @@ mod tests {
             vec![edge("A", "B"), edge("B", "C"), edge("C", "A")],
         );
 
-        assert!(layout.has_cycles());
+        assert!(!layout.cycle_nodes.is_empty());
         assert_eq!(layout.cycle_nodes, vec!["A", "B", "C"]);
         // Cycle nodes placed in a fallback layer.
         assert_eq!(layout.layer_count(), 1);
@@ -489,7 +825,7 @@ mod tests {
             vec![edge("X", "A"), edge("B", "C"), edge("C", "B")],
         );
 
-        assert!(layout.has_cycles());
+        assert!(!layout.cycle_nodes.is_empty());
         assert_eq!(layout.cycle_nodes, vec!["B", "C"]);
         // X(0) → A(1) are normal layers; B,C in fallback layer 2.
         assert_eq!(layout.layer_count(), 3);
@@ -531,7 +867,7 @@ mod tests {
             ],
         );
 
-        assert_eq!(layout.edge_count(), 1);
+        assert_eq!(layout.edges.len(), 1);
         assert_eq!(layout.layer_count(), 2);
         assert_eq!(layout.layers[0], vec!["A"]);
         assert_eq!(layout.layers[1], vec!["B"]);
@@ -558,48 +894,6 @@ mod tests {
 
     // ==================== Edge-crossing minimization tests ====================
 
-    /// Count the number of edge crossings between two adjacent layers.
-    fn count_crossings(layout: &DagLayout) -> usize {
-        let mut total = 0;
-
-        for layer_idx in 0..layout.layers.len().saturating_sub(1) {
-            let upper = &layout.layers[layer_idx];
-            let lower = &layout.layers[layer_idx + 1];
-
-            let upper_pos: HashMap<&str, usize> = upper
-                .iter()
-                .enumerate()
-                .map(|(i, id)| (id.as_str(), i))
-                .collect();
-            let lower_pos: HashMap<&str, usize> = lower
-                .iter()
-                .enumerate()
-                .map(|(i, id)| (id.as_str(), i))
-                .collect();
-
-            let mut edge_pairs: Vec<(usize, usize)> = Vec::new();
-            for e in &layout.edges {
-                if let (Some(&up), Some(&lp)) =
-                    (upper_pos.get(e.from.as_str()), lower_pos.get(e.to.as_str()))
-                {
-                    edge_pairs.push((up, lp));
-                }
-            }
-
-            for i in 0..edge_pairs.len() {
-                for j in (i + 1)..edge_pairs.len() {
-                    let (a, b) = edge_pairs[i];
-                    let (c, d) = edge_pairs[j];
-                    if (a < c && b > d) || (a > c && b < d) {
-                        total += 1;
-                    }
-                }
-            }
-        }
-
-        total
-    }
-
     #[test]
     fn barycenter_reduces_crossings() {
         // Layer 0: [A, B], Layer 1: [C, D]
@@ -615,7 +909,73 @@ mod tests {
         assert_eq!(layout.layer_count(), 2);
         assert_eq!(layout.layers[0], vec!["A", "B"]);
         assert_eq!(layout.layers[1], vec!["D", "C"]);
-        assert_eq!(count_crossings(&layout), 0);
+        assert_eq!(layout.crossing_count(), 0);
+    }
+
+    #[test]
+    fn crossing_heavy_graph_beats_the_sorted_order() {
+        // Two interleaved fans whose alphabetical layer order crosses
+        // heavily, plus a third layer that crosses back.
+        let ids = ["A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L"];
+        let edges = [
+            ("A", "H"), ("A", "J"), ("B", "E"), ("B", "G"), ("C", "I"), ("C", "F"),
+            ("D", "E"), ("D", "J"), ("E", "L"), ("F", "K"), ("G", "L"), ("H", "K"),
+            ("I", "L"), ("J", "K"),
+        ];
+        let build = || {
+            DagLayout::new(
+                ids.iter().map(|id| node(id)).collect(),
+                edges.iter().map(|(f, t)| edge(f, t)).collect(),
+            )
+        };
+        let layout = build();
+        let mut naive = build();
+        for layer in &mut naive.layers {
+            layer.sort();
+        }
+
+        assert_eq!(layout.layer_count(), 3);
+        assert!(naive.crossing_count() >= 10, "fixture should cross a lot");
+        assert!(
+            layout.crossing_count() < naive.crossing_count(),
+            "{} crossings, sorted order has {}",
+            layout.crossing_count(),
+            naive.crossing_count()
+        );
+        for layer in &layout.layers {
+            for (pos, id) in layer.iter().enumerate() {
+                assert_eq!(layout.nodes[id].x_position, pos);
+            }
+        }
+    }
+
+    #[test]
+    fn crossing_minimization_never_worsens_the_sorted_order() {
+        // Pseudo-random two- and three-layer graphs from a fixed seed.
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = |n: u64| {
+            seed = seed.wrapping_mul(6_364_136_223_846_793_005).wrapping_add(1);
+            (seed >> 33) % n
+        };
+        for _ in 0..50 {
+            let names: Vec<String> = (0..9).map(|i| format!("N{i}")).collect();
+            let mut edges = Vec::new();
+            for _ in 0..10 {
+                let (a, b) = (next(9) as usize, next(9) as usize);
+                if a < b {
+                    edges.push(edge(&names[a], &names[b]));
+                }
+            }
+            let build = || {
+                DagLayout::new(names.iter().map(|n| node(n)).collect(), edges.clone())
+            };
+            let layout = build();
+            let mut naive = build();
+            for layer in &mut naive.layers {
+                layer.sort();
+            }
+            assert!(layout.crossing_count() <= naive.crossing_count());
+        }
     }
 
     #[test]
@@ -630,7 +990,7 @@ mod tests {
             ],
         );
 
-        assert_eq!(count_crossings(&layout), 0);
+        assert_eq!(layout.crossing_count(), 0);
     }
 
     #[test]
@@ -640,7 +1000,7 @@ mod tests {
             vec![edge("A", "B"), edge("A", "C"), edge("A", "D")],
         );
 
-        assert_eq!(count_crossings(&layout), 0);
+        assert_eq!(layout.crossing_count(), 0);
     }
 
     #[test]
@@ -659,7 +1019,7 @@ mod tests {
         assert_eq!(layout.layer_count(), 2);
         assert_eq!(layout.layers[0], vec!["A", "B", "C"]);
         assert_eq!(layout.layers[1], vec!["F", "E", "D"]);
-        assert_eq!(count_crossings(&layout), 0);
+        assert_eq!(layout.crossing_count(), 0);
     }
 
     #[test]
@@ -687,7 +1047,7 @@ mod tests {
             vec![edge("P", "U"), edge("Q", "T"), edge("R", "S")],
         );
 
-        assert_eq!(count_crossings(&layout), 0);
+        assert_eq!(layout.crossing_count(), 0);
         assert_eq!(layout.layers[1], vec!["U", "T", "S"]);
     }
 
@@ -708,7 +1068,7 @@ mod tests {
         );
 
         assert_eq!(layout.layer_count(), 3);
-        assert_eq!(count_crossings(&layout), 0);
+        assert_eq!(layout.crossing_count(), 0);
     }
 
     #[test]
@@ -752,6 +1112,109 @@ mod tests {
         );
 
         assert_eq!(layout.layer_count(), 3);
-        assert_eq!(count_crossings(&layout), 0);
+        assert_eq!(layout.crossing_count(), 0);
+    }
+
+    fn assert_respects_edges(order: &[String], edges: &[(&str, &str)]) {
+        let pos = |id: &str| order.iter().position(|n| n == id).unwrap();
+        for (from, to) in edges {
+            assert!(pos(from) < pos(to), "{from} must come before {to} in {order:?}");
+        }
+    }
+
+    #[test]
+    fn topological_order_respects_edges() {
+        let edges = [("A", "B"), ("A", "C"), ("B", "D"), ("C", "D"), ("D", "E")];
+        let layout = DagLayout::new(
+            vec![node("E"), node("D"), node("C"), node("B"), node("A")],
+            edges.iter().map(|(f, t)| edge(f, t)).collect(),
+        );
+
+        let order = layout.topological_order().unwrap();
+        assert_eq!(order.len(), 5);
+        assert_respects_edges(&order, &edges);
+    }
+
+    #[test]
+    fn topological_order_puts_orphans_last() {
+        let layout = DagLayout::new(
+            vec![node("Z"), node("A"), node("B"), node("M")],
+            vec![edge("A", "B")],
+        );
+
+        let order = layout.topological_order().unwrap();
+        assert_eq!(order, vec!["A", "B", "Z", "M"]);
+    }
+
+    #[test]
+    fn topological_order_reports_node_on_cycle() {
+        // R feeds a B → C → D → B cycle. A hangs off the cycle without being
+        // on it, and sorts first, so the search has to walk back from it.
+        let layout = DagLayout::new(
+            vec![node("R"), node("B"), node("C"), node("D"), node("A")],
+            vec![
+                edge("R", "B"),
+                edge("B", "C"),
+                edge("C", "D"),
+                edge("D", "B"),
+                edge("D", "A"),
+            ],
+        );
+
+        let err = layout.topological_order().unwrap_err();
+        assert!(
+            ["B", "C", "D"].contains(&err.node.as_str()),
+            "expected a cycle member, got {}",
+            err.node
+        );
+        assert!(err.to_string().contains(&err.node));
+    }
+
+    fn branching_dag() -> DagLayout {
+        // A → C, B → C, C → D, C → E, E → F, X → Y (unrelated)
+        DagLayout::new(
+            ["A", "B", "C", "D", "E", "F", "X", "Y"].into_iter().map(node).collect(),
+            vec![
+                edge("A", "C"),
+                edge("B", "C"),
+                edge("C", "D"),
+                edge("C", "E"),
+                edge("E", "F"),
+                edge("X", "Y"),
+            ],
+        )
+    }
+
+    fn ids(set: HashSet<String>) -> Vec<String> {
+        let mut ids: Vec<String> = set.into_iter().collect();
+        ids.sort();
+        ids
+    }
+
+    #[test]
+    fn ancestors_and_descendants_on_branching_dag() {
+        let layout = branching_dag();
+        assert_eq!(ids(layout.ancestors("E")), vec!["A", "B", "C"]);
+        assert_eq!(ids(layout.descendants("C")), vec!["D", "E", "F"]);
+        assert!(layout.ancestors("A").is_empty());
+        assert!(layout.descendants("F").is_empty());
+    }
+
+    #[test]
+    fn related_nodes_excludes_siblings_and_unrelated_components() {
+        let layout = branching_dag();
+        // D is C's other child, not part of E's chain.
+        assert_eq!(ids(layout.related_nodes("E")), vec!["A", "B", "C", "E", "F"]);
+        assert_eq!(ids(layout.related_nodes("X")), vec!["X", "Y"]);
+        assert_eq!(ids(layout.related_nodes("unknown")), vec!["unknown"]);
+    }
+
+    #[test]
+    fn reachable_terminates_on_cycles() {
+        let layout = DagLayout::new(
+            vec![node("A"), node("B"), node("C")],
+            vec![edge("A", "B"), edge("B", "C"), edge("C", "A")],
+        );
+        assert_eq!(ids(layout.descendants("A")), vec!["A", "B", "C"]);
     }
 }
@@ -0,0 +1,106 @@
+//! Leveled diagnostics on stderr.
+//!
+//! stdout belongs to the MCP server's JSON-RPC stream, so every log line goes
+//! to stderr. `--log-level` picks how much is written; the default, `warn`,
+//! keeps routine server traffic quiet.
+
+use clap::ValueEnum;
+use log::{LevelFilter, Log, Metadata, Record};
+
+/// Values accepted by `--log-level`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LogLevel {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl From<LogLevel> for LevelFilter {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Off => LevelFilter::Off,
+            LogLevel::Error => LevelFilter::Error,
+            LogLevel::Warn => LevelFilter::Warn,
+            LogLevel::Info => LevelFilter::Info,
+            LogLevel::Debug => LevelFilter::Debug,
+            LogLevel::Trace => LevelFilter::Trace,
+        }
+    }
+}
+
+struct StderrLogger;
+
+impl Log for StderrLogger {
+    fn enabled(&self, metadata: &Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &Record) {
+        if self.enabled(record.metadata()) {
+            eprintln!("[{}] {}", record.level(), record.args());
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: StderrLogger = StderrLogger;
+
+/// Install the stderr logger, writing records at `level` and above.
+pub fn init(level: LevelFilter) {
+    // Only fails when a logger is already installed, which keeps that one.
+    let _ = log::set_logger(&LOGGER);
+    log::set_max_level(level);
+}
+
+/// Records logged on the current thread, collected by tests instead of
+/// being written to stderr.
+#[cfg(test)]
+pub mod capture {
+    use std::cell::RefCell;
+    use std::sync::Once;
+
+    use log::{Level, LevelFilter, Log, Metadata, Record};
+
+    thread_local! {
+        static RECORDS: RefCell<Option<Vec<(Level, String)>>> = const { RefCell::new(None) };
+    }
+
+    struct CaptureLogger;
+
+    impl Log for CaptureLogger {
+        fn enabled(&self, _: &Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &Record) {
+            RECORDS.with(|r| {
+                if let Some(records) = r.borrow_mut().as_mut() {
+                    records.push((record.level(), record.args().to_string()));
+                }
+            });
+        }
+
+        fn flush(&self) {}
+    }
+
+    static LOGGER: CaptureLogger = CaptureLogger;
+    static INSTALL: Once = Once::new();
+
+    /// Start collecting this thread's records, dropping any collected so far.
+    pub fn start() {
+        INSTALL.call_once(|| {
+            log::set_logger(&LOGGER).expect("no other logger in tests");
+            log::set_max_level(LevelFilter::Trace);
+        });
+        RECORDS.with(|r| *r.borrow_mut() = Some(Vec::new()));
+    }
+
+    /// Stop collecting and return what was logged since [`start`].
+    pub fn finish() -> Vec<(Level, String)> {
+        RECORDS.with(|r| r.borrow_mut().take().unwrap_or_default())
+    }
+}
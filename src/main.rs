@@ -1,5 +1,7 @@
 mod cli;
 mod db;
+mod graph;
+mod logging;
 mod mcp;
 mod models;
 mod settings;
@@ -10,6 +12,20 @@ use clap::{Parser, Subcommand};
 #[derive(Parser)]
 #[command(name = "blueprint", about = "AI-native project management system")]
 struct Cli {
+    /// Use the named database `~/.blueprint/<NAME>.db` instead of the default
+    #[arg(long, global = true, value_name = "NAME")]
+    workspace: Option<String>,
+    /// Keep databases in `<DIR>/.blueprint` and read settings from there
+    /// instead of `~/.blueprint` and the working directory. Falls back to
+    /// `BLUEPRINT_DATA_DIR`; beats `BLUEPRINT_DB`
+    #[arg(long, global = true, value_name = "DIR")]
+    data_dir: Option<std::path::PathBuf>,
+    /// Diagnostics written to stderr
+    #[arg(long, global = true, value_enum, default_value = "warn")]
+    log_level: crate::logging::LogLevel,
+    /// Shorthand for `--log-level debug`
+    #[arg(long, short, global = true, conflicts_with = "log_level")]
+    verbose: bool,
     #[command(subcommand)]
     command: Commands,
 }
@@ -17,12 +33,50 @@ struct Cli {
 #[derive(Subcommand)]
 enum Commands {
     /// Start the MCP server
-    Serve,
+    Serve {
+        /// Use a throwaway in-memory database instead of the on-disk one
+        #[arg(long)]
+        in_memory: bool,
+        /// Refuse every tool that creates, updates or deletes data
+        #[arg(long)]
+        read_only: bool,
+        /// Shut down after this many seconds without a message from the client
+        #[arg(long, value_name = "SECS")]
+        idle_timeout: Option<u64>,
+        /// Wrap tool results as `{"schema": 1, "data": ...}`; also enabled by
+        /// `BLUEPRINT_ENVELOPE=1`
+        #[arg(long)]
+        envelope: bool,
+    },
     /// Launch the terminal UI
-    Tui,
+    Tui {
+        /// Open on this project: its exact name, the name in any case, or a
+        /// part of the name that only one project has
+        #[arg(long)]
+        project: Option<String>,
+    },
+    /// Create a sample project to explore
+    Seed,
+    /// Checkpoint the WAL and vacuum the database
+    Maintain,
+    /// Export a project as JSON, Graphviz DOT or Mermaid, or its tasks as CSV
+    Export {
+        /// Project ID or name, matched as for `tui --project`
+        #[arg(long)]
+        project: String,
+        #[arg(long, value_enum, default_value = "json")]
+        format: crate::cli::export::ExportFormat,
+        /// File to write, or `-` for stdout
+        #[arg(long, short, default_value = crate::cli::export::STDOUT)]
+        output: String,
+        /// Include the TUI's graph layers: per-layer node groups in JSON,
+        /// `rank=same` groups in DOT
+        #[arg(long)]
+        layout: bool,
+    },
     /// Show project status
     Status {
-        /// Filter by project name
+        /// Filter by project name, matched as for `tui --project`
         #[arg(long)]
         project: Option<String>,
     },
@@ -31,20 +85,72 @@ enum Commands {
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
+    let log_level = if cli.verbose { log::LevelFilter::Debug } else { cli.log_level.into() };
+    crate::logging::init(log_level);
+    let workspace = cli.workspace.as_deref();
+    let data_dir = cli.data_dir.as_deref();
 
     match cli.command {
-        Commands::Serve => {
-            let db = crate::db::Database::open_default()?;
+        Commands::Serve { in_memory, read_only, idle_timeout, envelope } => {
+            let db = if in_memory {
+                crate::db::Database::open_in_memory()?
+            } else {
+                crate::db::Database::open_workspace(workspace, data_dir)?
+            };
             db.migrate()?;
-            let server = crate::mcp::McpServer::new(db);
+            let mut server = crate::mcp::McpServer::new(db)
+                .with_read_only(read_only)
+                .with_envelope(
+                    envelope
+                        || crate::mcp::envelope_enabled(
+                            std::env::var(crate::mcp::ENVELOPE_ENV).ok().as_deref(),
+                        ),
+                )
+                .with_idle_timeout(idle_timeout.map(std::time::Duration::from_secs));
+            if let Some(dir) = crate::db::workspace::configured_base(data_dir) {
+                server = server.with_settings_dir(dir);
+            }
             server.run().await?;
         }
-        Commands::Tui => {
-            crate::tui::run()?;
+        Commands::Tui { project } => {
+            crate::tui::run(workspace, data_dir, project.as_deref())?;
+        }
+        Commands::Seed => {
+            let db = crate::db::Database::open_workspace(workspace, data_dir)?;
+            db.migrate()?;
+            let summary = crate::db::seed::seed_demo_data(&db)?;
+            println!(
+                "Seeded \"{}\": {} epics, {} tasks, {} dependencies",
+                crate::db::seed::DEMO_PROJECT_NAME,
+                summary.epics,
+                summary.tasks,
+                summary.dependencies
+            );
+        }
+        Commands::Maintain => {
+            let db = crate::db::Database::open_workspace(workspace, data_dir)?;
+            db.migrate()?;
+            let report = crate::db::maintenance::maintain(&db)?;
+            println!(
+                "Database: {} -> {} bytes, WAL: {} -> {} bytes{}",
+                report.db_bytes_before,
+                report.db_bytes_after,
+                report.wal_bytes_before,
+                report.wal_bytes_after,
+                if report.checkpoint_busy { " (checkpoint incomplete: readers active)" } else { "" }
+            );
+        }
+        Commands::Export { project, format, output, layout } => {
+            let db = crate::db::Database::open_workspace(workspace, data_dir)?;
+            db.migrate()?;
+            crate::cli::export::run(&db, &project, format, &output, layout)?;
         }
         Commands::Status { project } => {
-            if let Some(name) = project {
-                println!("Status for project: {name} (not yet implemented)");
+            if let Some(query) = project {
+                let db = crate::db::Database::open_workspace(workspace, data_dir)?;
+                db.migrate()?;
+                let project = crate::db::project::resolve_project_fuzzy(&db, &query)?;
+                println!("Status for project: {} (not yet implemented)", project.name);
             } else {
                 println!("Overall status (not yet implemented)");
             }
@@ -1,52 +1,156 @@
 pub mod tools;
 pub mod types;
 
+use std::cell::Cell;
+use std::path::PathBuf;
+use std::time::Duration;
+
 use anyhow::Result;
+use serde::Serialize;
 use serde_json::{json, Value};
-use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt, BufReader};
 
 use crate::db::Database;
 use crate::settings::Settings;
-use types::{JsonRpcRequest, JsonRpcResponse, INVALID_PARAMS, JSONRPC_VERSION};
+use types::{JsonRpcNotification, JsonRpcRequest, JsonRpcResponse, INVALID_PARAMS, JSONRPC_VERSION};
 
 pub struct McpServer {
     db: Database,
+    /// Directory holding `.blueprint/setting.json`; the working directory when `None`.
+    settings_dir: Option<PathBuf>,
+    /// Whether the admin tools were part of the last advertised tool list.
+    admin_tools: Cell<bool>,
+    /// When set, calls that [`tools::is_mutating`] are refused.
+    read_only: bool,
+    /// Shut down when no message arrives for this long; never when `None`.
+    idle_timeout: Option<Duration>,
+    /// Wrap tool results as `{schema, data}`; see [`tools::RESULT_SCHEMA_VERSION`].
+    envelope: bool,
+}
+
+/// Environment variable that turns on the result envelope, like `--envelope`.
+pub const ENVELOPE_ENV: &str = "BLUEPRINT_ENVELOPE";
+
+/// Whether a [`ENVELOPE_ENV`] value turns the envelope on: `1` or `true`.
+pub fn envelope_enabled(value: Option<&str>) -> bool {
+    value.is_some_and(|v| v == "1" || v.eq_ignore_ascii_case("true"))
 }
 
 impl McpServer {
     pub fn new(db: Database) -> Self {
-        Self { db }
+        let server = Self {
+            db,
+            settings_dir: None,
+            admin_tools: Cell::new(true),
+            read_only: false,
+            idle_timeout: None,
+            envelope: false,
+        };
+        server.admin_tools.set(server.settings().admin_tools_enabled());
+        server
+    }
+
+    /// Refuse every tool that would modify the database.
+    pub fn with_read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+
+    /// Stop serving once the client has been silent for `timeout`.
+    /// Clients that want to stay connected can send `ping`.
+    pub fn with_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.idle_timeout = timeout;
+        self
+    }
+
+    /// Wrap every tool result as `{"schema": 1, "data": ...}` so clients can
+    /// detect shape changes. Off by default to keep existing clients working.
+    pub fn with_envelope(mut self, envelope: bool) -> Self {
+        self.envelope = envelope;
+        self
+    }
+
+    /// Read settings from `dir` instead of the working directory.
+    pub fn with_settings_dir(mut self, dir: PathBuf) -> Self {
+        self.settings_dir = Some(dir);
+        self.admin_tools.set(self.settings().admin_tools_enabled());
+        self
+    }
+
+    fn settings(&self) -> Settings {
+        match &self.settings_dir {
+            Some(dir) => Settings::load_from(Some(dir.clone())),
+            None => Settings::load(),
+        }
     }
 
     pub async fn run(&self) -> Result<()> {
-        eprintln!("MCP server starting on stdio");
+        log::info!("MCP server starting on stdio");
+        self.serve(BufReader::new(tokio::io::stdin()), tokio::io::stdout())
+            .await?;
+        log::info!("MCP server shutting down");
+        Ok(())
+    }
 
-        let stdin = tokio::io::stdin();
-        let mut stdout = tokio::io::stdout();
-        let mut lines = BufReader::new(stdin).lines();
+    /// Serve newline-delimited JSON-RPC from `reader` until it closes or the
+    /// idle timeout passes, writing responses and server-initiated
+    /// notifications to `writer`.
+    pub async fn serve<R, W>(&self, reader: R, mut writer: W) -> Result<()>
+    where
+        R: AsyncBufRead + Unpin,
+        W: AsyncWrite + Unpin,
+    {
+        let mut lines = reader.lines();
+
+        loop {
+            let next = match self.idle_timeout {
+                Some(idle) => match tokio::time::timeout(idle, lines.next_line()).await {
+                    Ok(next) => next?,
+                    Err(_) => {
+                        log::info!(
+                            "No message received for {}s, closing idle connection",
+                            idle.as_secs_f64()
+                        );
+                        break;
+                    }
+                },
+                None => lines.next_line().await?,
+            };
+            let Some(line) = next else {
+                log::info!("Input closed");
+                break;
+            };
 
-        while let Some(line) = lines.next_line().await? {
             if line.is_empty() {
                 continue;
             }
 
             if let Some(response) = self.process_message(&line) {
-                let mut out = serde_json::to_string(&response)?;
-                out.push('\n');
-                stdout.write_all(out.as_bytes()).await?;
-                stdout.flush().await?;
+                write_message(&mut writer, &response).await?;
+            }
+            if let Some(notification) = self.check_tool_list_changed() {
+                write_message(&mut writer, &notification).await?;
             }
         }
 
-        eprintln!("MCP server shutting down (stdin closed)");
         Ok(())
     }
 
+    /// Re-read the settings and, if the set of offered tools differs from
+    /// what was last advertised, return a `tools/list_changed` notification.
+    fn check_tool_list_changed(&self) -> Option<JsonRpcNotification> {
+        let enabled = self.settings().admin_tools_enabled();
+        if self.admin_tools.replace(enabled) == enabled {
+            return None;
+        }
+        Some(JsonRpcNotification::new("notifications/tools/list_changed"))
+    }
+
     fn process_message(&self, line: &str) -> Option<JsonRpcResponse> {
         let request: JsonRpcRequest = match serde_json::from_str(line) {
             Ok(req) => req,
             Err(e) => {
-                eprintln!("Parse error: {e}");
+                log::warn!("Parse error: {e}");
                 return Some(JsonRpcResponse::parse_error(format!("Parse error: {e}")));
             }
         };
@@ -84,8 +188,8 @@ impl McpServer {
 
     fn handle_notification(&self, request: &JsonRpcRequest) {
         match request.method.as_str() {
-            "notifications/initialized" => eprintln!("Client initialized"),
-            _ => eprintln!("Received notification: {}", request.method),
+            "notifications/initialized" => log::debug!("Client initialized"),
+            _ => log::debug!("Received notification: {}", request.method),
         }
     }
 
@@ -100,23 +204,43 @@ impl McpServer {
     }
 
     fn handle_initialize(&self, id: Value) -> JsonRpcResponse {
+        let mut capabilities = json!({ "tools": { "listChanged": true } });
+        if self.envelope {
+            capabilities["experimental"] =
+                json!({ "resultEnvelope": { "schema": tools::RESULT_SCHEMA_VERSION } });
+        }
         JsonRpcResponse::success(
             id,
             json!({
                 "protocolVersion": "2025-06-18",
-                "capabilities": {
-                    "tools": { "listChanged": false }
-                },
+                "capabilities": capabilities,
                 "serverInfo": {
                     "name": "blueprint",
-                    "version": "0.1.0"
+                    "version": "0.1.0",
+                    "readOnly": self.read_only,
+                    "features": self.features()
                 }
             }),
         )
     }
 
+    /// The optional behaviours this server runs with, so a client can adapt
+    /// to them; settings are read afresh as for each tool call.
+    fn features(&self) -> Value {
+        let settings = self.settings();
+        json!({
+            "readOnly": self.read_only,
+            "resultEnvelope": self.envelope,
+            "adminTools": settings.admin_tools_enabled(),
+            "autoEpicStatus": settings.auto_epic_status_enabled(),
+            "idleTimeoutSecs": self.idle_timeout.map(|t| t.as_secs()),
+            "defaultProject": settings.project_id.is_some(),
+        })
+    }
+
     fn handle_tools_list(&self, id: Value) -> JsonRpcResponse {
-        JsonRpcResponse::success(id, json!({ "tools": tools::tool_definitions() }))
+        let tools = tools::enabled_tool_definitions(self.admin_tools.get());
+        JsonRpcResponse::success(id, json!({ "tools": tools }))
     }
 
     fn handle_tools_call(&self, request: &JsonRpcRequest, id: Value) -> JsonRpcResponse {
@@ -129,10 +253,29 @@ impl McpServer {
         };
 
         let args = params.get("arguments").cloned().unwrap_or(json!({}));
-        let settings = Settings::load();
-        let default_project_id = settings.project_id;
+        let settings = self.settings();
+        let default_project_id = settings.project_id.as_deref();
+        self.db.set_auto_epic_status(settings.auto_epic_status_enabled());
 
-        match tools::dispatch_tool(name, &args, &self.db, default_project_id.as_deref()) {
+        if !settings.admin_tools_enabled() && tools::ADMIN_TOOLS.contains(&name) {
+            return JsonRpcResponse::error(id, INVALID_PARAMS, format!("Unknown tool: {name}"));
+        }
+
+        if self.read_only && tools::is_mutating(name, &args) {
+            return JsonRpcResponse::success(
+                id,
+                tools::tool_error(&format!(
+                    "Tool '{name}' modifies data and is disabled: the server is running in read-only mode"
+                )),
+            );
+        }
+
+        let ctx = tools::ToolContext {
+            envelope: self.envelope,
+            max_list_items: settings.max_list_items(),
+            text_limits: settings.text_limits(),
+        };
+        match tools::dispatch_tool(name, &args, &self.db, &ctx, default_project_id) {
             Some(result) => JsonRpcResponse::success(id, result),
             None => JsonRpcResponse::error(
                 id,
@@ -143,6 +286,15 @@ impl McpServer {
     }
 }
 
+/// Write one JSON-RPC message as a line and flush it.
+async fn write_message<W: AsyncWrite + Unpin>(writer: &mut W, message: &impl Serialize) -> Result<()> {
+    let mut out = serde_json::to_string(message)?;
+    out.push('\n');
+    writer.write_all(out.as_bytes()).await?;
+    writer.flush().await?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -154,7 +306,8 @@ mod tests {
         let path = dir.path().join("test.db");
         let db = Database::open(&path).unwrap();
         db.migrate().unwrap();
-        (McpServer::new(db), dir)
+        let server = McpServer::new(db).with_settings_dir(dir.path().to_path_buf());
+        (server, dir)
     }
 
     #[test]
@@ -217,9 +370,161 @@ mod tests {
         let resp = server.process_message(line).unwrap();
         let result = resp.result.unwrap();
         assert_eq!(result["protocolVersion"], "2025-06-18");
-        assert_eq!(result["capabilities"]["tools"]["listChanged"], false);
+        assert_eq!(result["capabilities"]["tools"]["listChanged"], true);
         assert_eq!(result["serverInfo"]["name"], "blueprint");
         assert_eq!(result["serverInfo"]["version"], "0.1.0");
+        assert_eq!(result["serverInfo"]["readOnly"], false);
+    }
+
+    #[test]
+    fn test_initialize_advertises_configured_features() {
+        let init = r#"{"jsonrpc":"2.0","method":"initialize","id":1}"#;
+        let (server, dir) = test_server();
+        let result = server.process_message(init).unwrap().result.unwrap();
+        assert_eq!(
+            result["serverInfo"]["features"],
+            json!({
+                "readOnly": false,
+                "resultEnvelope": false,
+                "adminTools": true,
+                "autoEpicStatus": false,
+                "idleTimeoutSecs": null,
+                "defaultProject": false,
+            })
+        );
+
+        let bp_dir = dir.path().join(".blueprint");
+        std::fs::create_dir_all(&bp_dir).unwrap();
+        std::fs::write(
+            bp_dir.join("setting.json"),
+            r#"{"project_id": "p1", "admin_tools": false, "auto_epic_status": true}"#,
+        )
+        .unwrap();
+        let server = server
+            .with_read_only(true)
+            .with_envelope(true)
+            .with_idle_timeout(Some(Duration::from_secs(90)));
+        let result = server.process_message(init).unwrap().result.unwrap();
+        assert_eq!(
+            result["serverInfo"]["features"],
+            json!({
+                "readOnly": true,
+                "resultEnvelope": true,
+                "adminTools": false,
+                "autoEpicStatus": true,
+                "idleTimeoutSecs": 90,
+                "defaultProject": true,
+            })
+        );
+    }
+
+    #[test]
+    fn test_read_only_initialize_advertises_mode() {
+        let (server, _dir) = test_server();
+        let server = server.with_read_only(true);
+        let line = r#"{"jsonrpc":"2.0","method":"initialize","id":1}"#;
+        let result = server.process_message(line).unwrap().result.unwrap();
+        assert_eq!(result["serverInfo"]["readOnly"], true);
+    }
+
+    #[test]
+    fn test_read_only_refuses_mutation_and_allows_reads() {
+        let (server, _dir) = test_server();
+        let server = server.with_read_only(true);
+
+        let line = r#"{"jsonrpc":"2.0","method":"tools/call","id":1,"params":{"name":"create_project","arguments":{"name":"P","description":"d"}}}"#;
+        let result = server.process_message(line).unwrap().result.unwrap();
+        assert_eq!(result["isError"], true);
+        assert!(result["content"][0]["text"].as_str().unwrap().contains("read-only"));
+
+        let line = r#"{"jsonrpc":"2.0","method":"tools/call","id":2,"params":{"name":"list_projects","arguments":{}}}"#;
+        let result = server.process_message(line).unwrap().result.unwrap();
+        assert!(result.get("isError").is_none());
+        let projects: Value =
+            serde_json::from_str(result["content"][0]["text"].as_str().unwrap()).unwrap();
+        assert_eq!(projects, json!([]), "refused create must not have written");
+    }
+
+    #[test]
+    fn test_read_only_allows_prd_previews() {
+        let call = |server: &McpServer, name: &str, args: Value| {
+            let line = json!({
+                "jsonrpc": "2.0",
+                "method": "tools/call",
+                "id": 1,
+                "params": { "name": name, "arguments": args }
+            });
+            server.process_message(&line.to_string()).unwrap().result.unwrap()
+        };
+        let parse = |result: &Value| -> Value {
+            serde_json::from_str(result["content"][0]["text"].as_str().unwrap()).unwrap()
+        };
+
+        let (server, _dir) = test_server();
+        let project = parse(&call(&server, "create_project", json!({"name": "P", "description": "d"})));
+        let prd = json!({"project_id": project["id"], "title": "PRD", "content": "## A\n- t\n"});
+        let prd_id = parse(&call(&server, "feed_prd", prd.clone()))["prd_id"].clone();
+        let server = server.with_read_only(true);
+
+        let mut dry = prd.clone();
+        dry["dry_run"] = json!(true);
+        assert!(call(&server, "feed_prd", dry).get("isError").is_none());
+        assert_eq!(call(&server, "feed_prd", prd)["isError"], true);
+
+        let diff = json!({"id": prd_id, "content": "## B\n"});
+        assert!(call(&server, "diff_prd", diff.clone()).get("isError").is_none());
+        let mut apply = diff;
+        apply["apply"] = json!(true);
+        let result = call(&server, "diff_prd", apply);
+        assert!(result["content"][0]["text"].as_str().unwrap().contains("read-only"));
+    }
+
+    #[test]
+    fn test_envelope_wraps_results_only_when_enabled() {
+        let call = |server: &McpServer, name: &str, args: Value| {
+            let line = json!({
+                "jsonrpc": "2.0",
+                "method": "tools/call",
+                "id": 1,
+                "params": { "name": name, "arguments": args }
+            });
+            let result = server.process_message(&line.to_string()).unwrap().result.unwrap();
+            serde_json::from_str::<Value>(result["content"][0]["text"].as_str().unwrap()).unwrap()
+        };
+
+        let (server, _dir) = test_server();
+        let project = call(&server, "create_project", json!({"name": "P", "description": "d"}));
+        assert_eq!(project["name"], "P", "bare shape by default");
+        assert_eq!(call(&server, "list_projects", json!({}))[0]["id"], project["id"]);
+        let init = r#"{"jsonrpc":"2.0","method":"initialize","id":1}"#;
+        let result = server.process_message(init).unwrap().result.unwrap();
+        assert!(result["capabilities"].get("experimental").is_none());
+
+        let server = server.with_envelope(true);
+        let result = server.process_message(init).unwrap().result.unwrap();
+        assert_eq!(result["capabilities"]["experimental"]["resultEnvelope"]["schema"], 1);
+        let got = call(&server, "get_project", json!({"id": project["id"]}));
+        assert_eq!(got["schema"], tools::RESULT_SCHEMA_VERSION);
+        assert_eq!(got["data"]["project"]["id"], project["id"]);
+        let listed = call(&server, "list_projects", json!({}));
+        assert_eq!(listed["schema"], 1);
+        assert_eq!(listed["data"][0]["name"], "P");
+
+        // The flag only applies to calls the server dispatches.
+        let ctx = tools::ToolContext::default();
+        let direct =
+            tools::dispatch_tool("list_projects", &json!({}), &server.db, &ctx, None).unwrap();
+        let text = direct["content"][0]["text"].as_str().unwrap();
+        assert!(serde_json::from_str::<Value>(text).unwrap().is_array());
+    }
+
+    #[test]
+    fn test_envelope_env_values() {
+        assert!(envelope_enabled(Some("1")));
+        assert!(envelope_enabled(Some("TRUE")));
+        assert!(!envelope_enabled(Some("0")));
+        assert!(!envelope_enabled(Some("")));
+        assert!(!envelope_enabled(None));
     }
 
     #[test]
@@ -231,13 +536,13 @@ mod tests {
     }
 
     #[test]
-    fn test_tools_list_returns_19_tools() {
+    fn test_tools_list_returns_52_tools() {
         let (server, _dir) = test_server();
         let line = r#"{"jsonrpc":"2.0","method":"tools/list","id":1}"#;
         let resp = server.process_message(line).unwrap();
         let result = resp.result.unwrap();
         let tools = result["tools"].as_array().unwrap();
-        assert_eq!(tools.len(), 19);
+        assert_eq!(tools.len(), 52);
     }
 
     #[test]
@@ -279,4 +584,152 @@ mod tests {
         let line = r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#;
         assert!(server.process_message(line).is_none());
     }
+
+    #[tokio::test]
+    async fn test_serve_emits_list_changed_when_admin_tools_toggle() {
+        let (server, dir) = test_server();
+        let (client, server_end) = tokio::io::duplex(64 * 1024);
+        let (server_read, server_write) = tokio::io::split(server_end);
+        let (client_read, mut client_write) = tokio::io::split(client);
+        let mut replies = BufReader::new(client_read).lines();
+
+        let set_admin_tools = |enabled: bool| {
+            let path = Settings::path_in(dir.path());
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, json!({ "admin_tools": enabled }).to_string()).unwrap();
+        };
+
+        let client_script = async {
+            let mut send = async |line: &str| {
+                client_write.write_all(format!("{line}\n").as_bytes()).await.unwrap();
+            };
+            let list = r#"{"jsonrpc":"2.0","method":"tools/list","id":1}"#;
+            let ping = r#"{"jsonrpc":"2.0","method":"ping","id":2}"#;
+
+            send(list).await;
+            let resp: Value = serde_json::from_str(&replies.next_line().await.unwrap().unwrap()).unwrap();
+            assert_eq!(resp["result"]["tools"].as_array().unwrap().len(), 52);
+
+            set_admin_tools(false);
+            send(ping).await;
+            let resp: Value = serde_json::from_str(&replies.next_line().await.unwrap().unwrap()).unwrap();
+            assert_eq!(resp["id"], 2);
+            let note: Value = serde_json::from_str(&replies.next_line().await.unwrap().unwrap()).unwrap();
+            assert_eq!(note["method"], "notifications/tools/list_changed");
+            assert!(note.get("id").is_none());
+
+            send(list).await;
+            let resp: Value = serde_json::from_str(&replies.next_line().await.unwrap().unwrap()).unwrap();
+            let tools = resp["result"]["tools"].as_array().unwrap();
+            assert_eq!(tools.len(), 52 - tools::ADMIN_TOOLS.len());
+            assert!(tools.iter().all(|t| t["name"] != "seed_demo_data"));
+
+            // No change, no notification: the next line is the ping reply.
+            send(ping).await;
+            let resp: Value = serde_json::from_str(&replies.next_line().await.unwrap().unwrap()).unwrap();
+            assert_eq!(resp["id"], 2);
+
+            client_write.shutdown().await.unwrap();
+            drop(client_write);
+        };
+
+        let (served, ()) = tokio::join!(
+            server.serve(BufReader::new(server_read), server_write),
+            client_script
+        );
+        served.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_tool_failure_logs_error_and_keeps_output_json_rpc() {
+        let (server, _dir) = test_server();
+        server.db.conn().execute_batch("DROP TABLE projects").unwrap();
+        let input = concat!(
+            r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#,
+            "\n",
+            r#"{"jsonrpc":"2.0","method":"tools/call","id":1,"#,
+            r#""params":{"name":"create_project","arguments":{"name":"P","description":"D"}}}"#,
+            "\n",
+        );
+        let mut output = Vec::new();
+
+        crate::logging::capture::start();
+        server.serve(BufReader::new(input.as_bytes()), &mut output).await.unwrap();
+        let records = crate::logging::capture::finish();
+
+        assert!(
+            records
+                .iter()
+                .any(|(level, msg)| *level == log::Level::Error
+                    && msg.starts_with("create_project error:")),
+            "{records:?}"
+        );
+        let output = String::from_utf8(output).unwrap();
+        let lines: Vec<&str> = output.lines().collect();
+        assert_eq!(lines.len(), 1, "{output}");
+        let resp: Value = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(resp["id"], 1);
+        assert_eq!(resp["result"]["isError"], true);
+    }
+
+    #[tokio::test]
+    async fn test_serve_exits_after_idle_timeout() {
+        let (server, _dir) = test_server();
+        let server = server.with_idle_timeout(Some(Duration::from_millis(50)));
+        let (client, server_end) = tokio::io::duplex(64 * 1024);
+        let (server_read, server_write) = tokio::io::split(server_end);
+
+        // The client stays connected but never writes.
+        let served = tokio::time::timeout(
+            Duration::from_secs(5),
+            server.serve(BufReader::new(server_read), server_write),
+        )
+        .await;
+        assert!(matches!(served, Ok(Ok(()))), "serve should stop on its own");
+        drop(client);
+    }
+
+    #[tokio::test]
+    async fn test_serve_stays_alive_while_pinged() {
+        let (server, _dir) = test_server();
+        let idle = Duration::from_millis(300);
+        let server = server.with_idle_timeout(Some(idle));
+        let (client, server_end) = tokio::io::duplex(64 * 1024);
+        let (server_read, server_write) = tokio::io::split(server_end);
+        let (client_read, mut client_write) = tokio::io::split(client);
+        let mut replies = BufReader::new(client_read).lines();
+
+        let client_script = async {
+            let ping = r#"{"jsonrpc":"2.0","method":"ping","id":1}"#;
+            // Pinging for well past the idle window keeps the server up.
+            for _ in 0..6 {
+                tokio::time::sleep(idle / 3).await;
+                client_write.write_all(format!("{ping}\n").as_bytes()).await.unwrap();
+                let resp: Value =
+                    serde_json::from_str(&replies.next_line().await.unwrap().unwrap()).unwrap();
+                assert_eq!(resp["result"], json!({}));
+            }
+            // Then silence: the server gives up and the reply stream ends.
+            assert!(replies.next_line().await.unwrap().is_none());
+        };
+
+        let (served, ()) = tokio::join!(
+            server.serve(BufReader::new(server_read), server_write),
+            client_script
+        );
+        served.unwrap();
+    }
+
+    #[test]
+    fn test_disabled_admin_tool_is_rejected() {
+        let (server, dir) = test_server();
+        let path = Settings::path_in(dir.path());
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        std::fs::write(path, r#"{"admin_tools": false}"#).unwrap();
+
+        let line = r#"{"jsonrpc":"2.0","method":"tools/call","id":1,"params":{"name":"seed_demo_data"}}"#;
+        let err = server.process_message(line).unwrap().error.unwrap();
+        assert_eq!(err.code, INVALID_PARAMS);
+        assert!(err.message.contains("seed_demo_data"));
+    }
 }
@@ -0,0 +1,261 @@
+use serde_json::{json, Value};
+
+use crate::db::checklist as checklist_db;
+use crate::db::task as task_db;
+use crate::db::Database;
+use crate::models::checklist_progress;
+
+use super::{
+    actor, check_text_limits, optional_str, require_str, resolve_optional_project_id, tool_error,
+    tool_result, ToolContext,
+};
+
+/// Resolve the `task_id` argument to the ID of an existing task.
+fn resolve_task(args: &Value, db: &Database, default_project_id: Option<&str>) -> Result<String, Value> {
+    let raw_id = require_str(args, "task_id")?;
+    let project_id = resolve_optional_project_id(args, default_project_id);
+    let id = task_db::resolve_task_id(db, &raw_id, project_id.as_deref())
+        .map_err(|e| tool_error(&e.to_string()))?;
+    match task_db::get_task(db, &id) {
+        Ok(Some(_)) => Ok(id),
+        Ok(None) => Err(tool_error(&format!("Task not found: {raw_id}"))),
+        Err(e) => {
+            log::error!("get_task error: {e:#}");
+            Err(tool_error("Failed to get task"))
+        }
+    }
+}
+
+pub(super) fn handle_add_checklist_item(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    let task_id = match resolve_task(args, db, default_project_id) {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let text = match require_str(args, "text") {
+        Ok(v) if v.trim().is_empty() => return tool_error("text must not be empty"),
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+
+    match checklist_db::add_checklist_item(db, &task_id, text.trim(), actor(args)) {
+        Ok(item) => tool_result(ctx, &item),
+        Err(e) => {
+            log::error!("add_checklist_item error: {e:#}");
+            tool_error("Failed to add checklist item")
+        }
+    }
+}
+
+pub(super) fn handle_toggle_checklist_item(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+) -> Value {
+    let id = match require_str(args, "id") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let done = args.get("done").and_then(Value::as_bool);
+
+    match checklist_db::toggle_checklist_item(db, &id, done, actor(args)) {
+        Ok(Some(item)) => tool_result(ctx, &item),
+        Ok(None) => tool_error(&format!("Checklist item not found: {id}")),
+        Err(e) => {
+            log::error!("toggle_checklist_item error: {e:#}");
+            tool_error("Failed to update checklist item")
+        }
+    }
+}
+
+pub(super) fn handle_promote_checklist_item(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+) -> Value {
+    let id = match require_str(args, "id") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let after = match optional_str(args, "item").as_deref() {
+        None | Some("keep") => checklist_db::AfterPromotion::Keep,
+        Some("done") => checklist_db::AfterPromotion::Check,
+        Some("delete") => checklist_db::AfterPromotion::Delete,
+        Some(other) => {
+            return tool_error(&format!("Invalid item: {other} (expected keep, done or delete)"));
+        }
+    };
+    let description = optional_str(args, "description");
+
+    // The item's text becomes the title, so it has to fit a title.
+    match checklist_db::get_checklist_item(db, &id) {
+        Ok(Some(item)) => {
+            let fields = json!({ "title": item.text, "description": description });
+            if let Err(e) = check_text_limits(ctx, &fields) {
+                return e;
+            }
+        }
+        Ok(None) => return tool_error(&format!("Checklist item not found: {id}")),
+        Err(e) => {
+            log::error!("get_checklist_item error: {e:#}");
+            return tool_error("Failed to get checklist item");
+        }
+    }
+
+    let promoted =
+        checklist_db::promote_checklist_item(db, &id, description.as_deref(), after, actor(args));
+    match promoted {
+        Ok(Some(promotion)) => tool_result(ctx, &promotion),
+        Ok(None) => tool_error(&format!("Checklist item not found: {id}")),
+        Err(e) => {
+            log::error!("promote_checklist_item error: {e:#}");
+            tool_error("Failed to promote checklist item")
+        }
+    }
+}
+
+pub(super) fn handle_list_checklist_items(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    let task_id = match resolve_task(args, db, default_project_id) {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+
+    match checklist_db::list_checklist_items(db, &task_id) {
+        Ok(items) => {
+            let (done, total) = checklist_progress(&items);
+            tool_result(ctx, &json!({
+                "task_id": task_id,
+                "items": items,
+                "done": done,
+                "total": total,
+            }))
+        }
+        Err(e) => {
+            log::error!("list_checklist_items error: {e:#}");
+            tool_error("Failed to list checklist items")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{dispatch_tool, ToolContext};
+    use crate::db::seed::seed_demo_data;
+    use crate::db::Database;
+    use serde_json::{json, Value};
+    use tempfile::TempDir;
+
+    fn seeded_db() -> (Database, TempDir, String) {
+        let dir = TempDir::new().unwrap();
+        let db = Database::open(&dir.path().join("test.db")).unwrap();
+        db.migrate().unwrap();
+        let project_id = seed_demo_data(&db).unwrap().project_id;
+        (db, dir, project_id)
+    }
+
+    fn call(db: &Database, project_id: &str, name: &str, args: Value) -> Value {
+        let result =
+            dispatch_tool(name, &args, db, &ToolContext::default(), Some(project_id)).unwrap();
+        assert!(result.get("isError").is_none(), "{result}");
+        serde_json::from_str(result["content"][0]["text"].as_str().unwrap()).unwrap()
+    }
+
+    #[test]
+    fn test_add_toggle_and_list_checklist() {
+        let (db, _dir, project_id) = seeded_db();
+        let mut ids = Vec::new();
+        for text in ["Schema", "Migration", "Docs"] {
+            let args = json!({ "task_id": "E1-T1", "text": text });
+            ids.push(call(&db, &project_id, "add_checklist_item", args)["id"].clone());
+        }
+
+        let toggled = call(&db, &project_id, "toggle_checklist_item", json!({ "id": ids[1] }));
+        assert_eq!(toggled["done"], true);
+        let args = json!({ "id": ids[2], "done": false });
+        assert_eq!(call(&db, &project_id, "toggle_checklist_item", args)["done"], false);
+
+        let list = call(&db, &project_id, "list_checklist_items", json!({ "task_id": "e1-t1" }));
+        let items = list["items"].as_array().unwrap();
+        let texts: Vec<&str> = items.iter().map(|i| i["text"].as_str().unwrap()).collect();
+        assert_eq!(texts, ["Schema", "Migration", "Docs"]);
+        assert_eq!((list["done"].as_u64(), list["total"].as_u64()), (Some(1), Some(3)));
+    }
+
+    #[test]
+    fn test_promote_checklist_item() {
+        let (db, _dir, project_id) = seeded_db();
+        let mut ids = Vec::new();
+        for text in ["Rate limiting", "Audit log", "Metrics"] {
+            let args = json!({ "task_id": "E1-T1", "text": text });
+            ids.push(call(&db, &project_id, "add_checklist_item", args)["id"].clone());
+        }
+        let source = call(&db, &project_id, "get_task", json!({ "id": "E1-T1" }))["task"].clone();
+
+        let kept = call(&db, &project_id, "promote_checklist_item", json!({ "id": ids[0] }));
+        assert_eq!(kept["task"]["title"], "Rate limiting");
+        assert_eq!(kept["task"]["epic_id"], source["epic_id"]);
+        assert_eq!(kept["task"]["status"], "todo");
+        assert!(kept["task"]["description"].as_str().unwrap().contains("E1-T1"));
+        assert_eq!(kept["source_task_id"], source["id"]);
+        assert_eq!(kept["item"]["done"], false);
+
+        let args = json!({ "id": ids[1], "item": "done", "description": "Own task now" });
+        let checked = call(&db, &project_id, "promote_checklist_item", args);
+        assert_eq!(checked["task"]["title"], "Audit log");
+        assert_eq!(checked["task"]["description"], "Own task now");
+        assert_eq!(checked["item"]["done"], true);
+
+        let args = json!({ "id": ids[2], "item": "delete" });
+        let deleted = call(&db, &project_id, "promote_checklist_item", args);
+        assert_eq!(deleted["task"]["title"], "Metrics");
+        assert!(deleted["item"].is_null());
+
+        let list = call(&db, &project_id, "list_checklist_items", json!({ "task_id": "E1-T1" }));
+        let items = list["items"].as_array().unwrap();
+        let left: Vec<(&str, bool)> = items
+            .iter()
+            .map(|i| (i["text"].as_str().unwrap(), i["done"].as_bool().unwrap()))
+            .collect();
+        assert_eq!(left, [("Rate limiting", false), ("Audit log", true)]);
+
+        let args = json!({ "id": ids[0], "item": "archive" });
+        let result = dispatch_tool(
+            "promote_checklist_item",
+            &args,
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+        let text = result["content"][0]["text"].as_str().unwrap();
+        assert!(text.contains("Invalid item: archive"), "{text}");
+    }
+
+    #[test]
+    fn test_checklist_errors() {
+        let (db, _dir, project_id) = seeded_db();
+        for (name, args, expected) in [
+            ("add_checklist_item", json!({ "task_id": "E9-T9", "text": "x" }), "not found"),
+            ("add_checklist_item", json!({ "task_id": "E1-T1", "text": "  " }), "empty"),
+            ("toggle_checklist_item", json!({ "id": "missing" }), "not found"),
+            ("promote_checklist_item", json!({ "id": "missing" }), "not found"),
+        ] {
+            let result =
+                dispatch_tool(name, &args, &db, &ToolContext::default(), Some(&project_id))
+                    .unwrap();
+            assert_eq!(result["isError"], true, "{name}");
+            let text = result["content"][0]["text"].as_str().unwrap();
+            assert!(text.contains(expected), "{text}");
+        }
+    }
+}
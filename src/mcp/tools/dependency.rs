@@ -3,9 +3,26 @@ use serde_json::{json, Value};
 use crate::db::epic as epic_db;
 use crate::db::task as task_db;
 use crate::db::{dependency as dep_db, Database};
-use crate::models::dependency::{AddDependencyInput, DependencyType};
-
-use super::{require_str, tool_error, tool_result};
+use crate::models::dependency::{
+    AddDependencyInput, DependencyDirection, DependencyItem, DependencyQuery, DependencyType,
+};
+
+use super::{
+    actor, optional_bool, optional_str, require_str, resolve_optional_project_id, tool_error,
+    tool_result, ToolContext,
+};
+
+/// Most dependencies `get_dependencies` returns per list in one call.
+const MAX_DEPENDENCY_LIMIT: u64 = 1000;
+
+fn parse_direction(args: &Value) -> Result<DependencyDirection, Value> {
+    match optional_str(args, "direction") {
+        Some(s) => s
+            .parse::<DependencyDirection>()
+            .map_err(|_| tool_error(&format!("Invalid direction: {s}"))),
+        None => Ok(DependencyDirection::Both),
+    }
+}
 
 fn parse_dependency_type(args: &Value, field: &str) -> Result<DependencyType, Value> {
     let s = require_str(args, field)?;
@@ -18,11 +35,11 @@ fn resolve_id(
     dep_type: &DependencyType,
     raw_id: &str,
     db: &Database,
-    default_project_id: Option<&str>,
+    project_id: Option<&str>,
 ) -> Result<String, Value> {
     let result = match dep_type {
-        DependencyType::Epic => epic_db::resolve_epic_id(db, raw_id, default_project_id),
-        DependencyType::Task => task_db::resolve_task_id(db, raw_id, default_project_id),
+        DependencyType::Epic => epic_db::resolve_epic_id(db, raw_id, project_id),
+        DependencyType::Task => task_db::resolve_task_id(db, raw_id, project_id),
     };
     result.map_err(|e| tool_error(&e.to_string()))
 }
@@ -37,8 +54,9 @@ fn parse_and_resolve_input(
     let blocked_type = parse_dependency_type(args, "blocked_type")?;
     let raw_blocked_id = require_str(args, "blocked_id")?;
 
-    let blocker_id = resolve_id(&blocker_type, &raw_blocker_id, db, default_project_id)?;
-    let blocked_id = resolve_id(&blocked_type, &raw_blocked_id, db, default_project_id)?;
+    let project_id = resolve_optional_project_id(args, default_project_id);
+    let blocker_id = resolve_id(&blocker_type, &raw_blocker_id, db, project_id.as_deref())?;
+    let blocked_id = resolve_id(&blocked_type, &raw_blocked_id, db, project_id.as_deref())?;
 
     Ok(AddDependencyInput {
         blocker_type,
@@ -48,14 +66,19 @@ fn parse_and_resolve_input(
     })
 }
 
-pub(super) fn handle_add_dependency(args: &Value, db: &Database, default_project_id: Option<&str>) -> Value {
+pub(super) fn handle_add_dependency(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
     let input = match parse_and_resolve_input(args, db, default_project_id) {
         Ok(v) => v,
         Err(e) => return e,
     };
 
-    match dep_db::add_dependency(db, input) {
-        Ok(dep) => tool_result(&dep),
+    match dep_db::add_dependency(db, input, actor(args)) {
+        Ok(dep) => tool_result(ctx, &dep),
         Err(e) => {
             let msg = e.to_string();
             if msg.contains("self-referencing")
@@ -64,14 +87,19 @@ pub(super) fn handle_add_dependency(args: &Value, db: &Database, default_project
             {
                 tool_error(&msg)
             } else {
-                eprintln!("add_dependency error: {e:#}");
+                log::error!("add_dependency error: {e:#}");
                 tool_error("Failed to add dependency")
             }
         }
     }
 }
 
-pub(super) fn handle_remove_dependency(args: &Value, db: &Database, default_project_id: Option<&str>) -> Value {
+pub(super) fn handle_remove_dependency(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
     let input = match parse_and_resolve_input(args, db, default_project_id) {
         Ok(v) => v,
         Err(e) => return e,
@@ -83,19 +111,185 @@ pub(super) fn handle_remove_dependency(args: &Value, db: &Database, default_proj
         &input.blocker_id,
         &input.blocked_type,
         &input.blocked_id,
+        actor(args),
     ) {
-        Ok(true) => tool_result(&json!({ "removed": true })),
-        Ok(false) => tool_result(&json!({ "removed": false, "message": "Dependency not found" })),
+        Ok(true) => tool_result(ctx, &json!({ "removed": true })),
+        Ok(false) => {
+            tool_result(ctx, &json!({ "removed": false, "message": "Dependency not found" }))
+        }
         Err(e) => {
-            eprintln!("remove_dependency error: {e:#}");
+            log::error!("remove_dependency error: {e:#}");
             tool_error("Failed to remove dependency")
         }
     }
 }
 
+pub(super) fn handle_clear_dependencies(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    let item_type = match parse_dependency_type(args, "type") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let raw_id = match require_str(args, "id") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let direction = match parse_direction(args) {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let project_id = resolve_optional_project_id(args, default_project_id);
+    let id = match resolve_id(&item_type, &raw_id, db, project_id.as_deref()) {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+
+    match dep_db::clear_dependencies(db, &item_type, &id, direction, actor(args)) {
+        Ok(removed) => tool_result(ctx, &json!({ "id": id, "removed": removed })),
+        Err(e) => {
+            log::error!("clear_dependencies error: {e:#}");
+            tool_error("Failed to clear dependencies")
+        }
+    }
+}
+
+/// An item's blockers and the items it blocks, as dependency rows or, when
+/// `expand` is set, as the items themselves with short ID, title and status.
+pub(super) fn dependency_lists(
+    db: &Database,
+    item_type: &DependencyType,
+    id: &str,
+    expand: bool,
+) -> anyhow::Result<(Value, Value)> {
+    if expand {
+        Ok((
+            json!(dep_db::get_blocker_items(db, item_type, id)?),
+            json!(dep_db::get_blocked_by_items(db, item_type, id)?),
+        ))
+    } else {
+        Ok((
+            json!(dep_db::get_blockers(db, item_type, id)?),
+            json!(dep_db::get_blocked_by(db, item_type, id)?),
+        ))
+    }
+}
+
+/// `{blockers, blocks}` for the `neighbors` option of `get_epic` and
+/// `get_task`: the item's direct dependencies, each reduced to its short ID,
+/// title and status.
+pub(super) fn neighbors(
+    db: &Database,
+    item_type: &DependencyType,
+    id: &str,
+) -> anyhow::Result<Value> {
+    let compact = |items: Vec<DependencyItem>| -> Vec<Value> {
+        items
+            .into_iter()
+            .map(|item| {
+                json!({ "short_id": item.short_id, "title": item.title, "status": item.status })
+            })
+            .collect()
+    };
+    Ok(json!({
+        "blockers": compact(dep_db::get_blocker_items(db, item_type, id)?),
+        "blocks": compact(dep_db::get_blocked_by_items(db, item_type, id)?),
+    }))
+}
+
+/// One side of an item's dependencies, like [`dependency_lists`] but
+/// following `query`.
+fn dependency_list(
+    db: &Database,
+    item_type: &DependencyType,
+    id: &str,
+    expand: bool,
+    query: &DependencyQuery,
+) -> anyhow::Result<Value> {
+    Ok(if expand {
+        json!(dep_db::get_dependency_items(db, item_type, id, query)?)
+    } else {
+        json!(dep_db::get_dependencies(db, item_type, id, query)?)
+    })
+}
+
+pub(super) fn handle_get_dependencies(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    let item_type = match parse_dependency_type(args, "type") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let raw_id = match require_str(args, "id") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let direction = match parse_direction(args) {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let limit = match args.get("limit") {
+        None | Some(Value::Null) => None,
+        Some(v) => match v.as_u64() {
+            Some(n @ 1..=MAX_DEPENDENCY_LIMIT) => Some(n as usize),
+            _ => {
+                return tool_error(&format!(
+                    "Invalid limit: {v} (expected 1 to {MAX_DEPENDENCY_LIMIT})"
+                ));
+            }
+        },
+    };
+    let offset = match args.get("offset") {
+        None | Some(Value::Null) => 0,
+        Some(v) => match v.as_u64() {
+            Some(n) => n as usize,
+            None => {
+                return tool_error(&format!("Invalid offset: {v} (expected a whole number)"));
+            }
+        },
+    };
+    let project_id = resolve_optional_project_id(args, default_project_id);
+    let id = match resolve_id(&item_type, &raw_id, db, project_id.as_deref()) {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+
+    let expand = optional_bool(args, "expand");
+    let query = |direction| DependencyQuery {
+        direction,
+        transitive: optional_bool(args, "transitive"),
+        limit,
+        offset,
+    };
+    let mut response = json!({ "type": item_type, "id": id });
+    let lists = [
+        ("blockers", DependencyDirection::Incoming),
+        ("blocks", DependencyDirection::Outgoing),
+    ];
+    for (key, side) in lists {
+        if direction != DependencyDirection::Both && direction != side {
+            continue;
+        }
+        match dependency_list(db, &item_type, &id, expand, &query(side)) {
+            Ok(list) => response[key] = list,
+            Err(e) => {
+                log::error!("get_dependencies error: {e:#}");
+                return tool_error("Failed to get dependencies");
+            }
+        }
+    }
+    tool_result(ctx, &response)
+}
+
 #[cfg(test)]
 mod tests {
-    use super::super::dispatch_tool;
+    use super::super::{dispatch_tool, ToolContext};
     use serde_json::{json, Value};
     use tempfile::TempDir;
 
@@ -118,6 +312,7 @@ mod tests {
             "create_project",
             &json!({"name": "Test Project", "description": "for dep tests"}),
             db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -132,6 +327,7 @@ mod tests {
             "create_epic",
             &json!({"project_id": project_id, "title": "Test Epic", "description": "for dep tests"}),
             db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -146,6 +342,7 @@ mod tests {
             "create_task",
             &json!({"epic_id": epic_id, "title": "Test Task", "description": "for dep tests"}),
             db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -171,6 +368,7 @@ mod tests {
                 "blocked_type": "epic", "blocked_id": e2,
             }),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -198,6 +396,7 @@ mod tests {
                 "blocked_type": "task", "blocked_id": t2,
             }),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -222,6 +421,7 @@ mod tests {
                 "blocked_type": "task", "blocked_id": tid,
             }),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -245,6 +445,7 @@ mod tests {
                 "blocked_type": "epic", "blocked_id": eid,
             }),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -268,8 +469,9 @@ mod tests {
             "blocked_type": "epic", "blocked_id": e2,
         });
 
-        dispatch_tool("add_dependency", &args, &db, None).unwrap();
-        let result = dispatch_tool("add_dependency", &args, &db, None).unwrap();
+        dispatch_tool("add_dependency", &args, &db, &ToolContext::default(), None).unwrap();
+        let result =
+            dispatch_tool("add_dependency", &args, &db, &ToolContext::default(), None).unwrap();
 
         assert_eq!(result["isError"], true);
         assert!(result["content"][0]["text"]
@@ -289,6 +491,7 @@ mod tests {
                 "blocked_type": "epic", "blocked_id": "y",
             }),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -313,6 +516,7 @@ mod tests {
                 "blocked_type": "epic", "blocked_id": eid,
             }),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -332,6 +536,7 @@ mod tests {
             "add_dependency",
             &json!({"blocker_type": "epic"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -359,6 +564,7 @@ mod tests {
                 "blocked_type": "epic", "blocked_id": e2,
             }),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -370,6 +576,7 @@ mod tests {
                 "blocked_type": "epic", "blocked_id": e2,
             }),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -390,6 +597,7 @@ mod tests {
                 "blocked_type": "epic", "blocked_id": "b",
             }),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -416,6 +624,7 @@ mod tests {
                 "blocked_type": "epic", "blocked_id": "E2",
             }),
             &db,
+            &ToolContext::default(),
             Some(&pid),
         )
         .unwrap();
@@ -441,6 +650,7 @@ mod tests {
                 "blocked_type": "task", "blocked_id": "E1-T2",
             }),
             &db,
+            &ToolContext::default(),
             Some(&pid),
         )
         .unwrap();
@@ -466,6 +676,7 @@ mod tests {
                 "blocked_type": "epic", "blocked_id": e2,
             }),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -478,6 +689,7 @@ mod tests {
                 "blocked_type": "epic", "blocked_id": "E2",
             }),
             &db,
+            &ToolContext::default(),
             Some(&pid),
         )
         .unwrap();
@@ -502,6 +714,7 @@ mod tests {
                 "blocked_type": "task", "blocked_id": tid,
             }),
             &db,
+            &ToolContext::default(),
             Some(&pid),
         )
         .unwrap();
@@ -530,20 +743,35 @@ mod tests {
                 "blocked_type": "task", "blocked_id": t2,
             }),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
         assert!(add_result.get("isError").is_none());
 
         // Verify via get_task: t2 should show t1 as a blocker
-        let get_result = dispatch_tool("get_task", &json!({"id": t2}), &db, None).unwrap();
+        let get_result = dispatch_tool(
+            "get_task",
+            &json!({"id": t2}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         let data = parse_response(&get_result);
         let blockers = data["blockers"].as_array().unwrap();
         assert_eq!(blockers.len(), 1);
         assert_eq!(blockers[0]["blocker_id"], t1);
 
         // Verify via get_task: t1 should show t2 in blocks
-        let get_result = dispatch_tool("get_task", &json!({"id": t1}), &db, None).unwrap();
+        let get_result = dispatch_tool(
+            "get_task",
+            &json!({"id": t1}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         let data = parse_response(&get_result);
         let blocks = data["blocks"].as_array().unwrap();
         assert_eq!(blocks.len(), 1);
@@ -557,6 +785,7 @@ mod tests {
                 "blocked_type": "task", "blocked_id": t2,
             }),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -564,8 +793,333 @@ mod tests {
         assert_eq!(removed["removed"], true);
 
         // Verify dependency is gone
-        let get_result = dispatch_tool("get_task", &json!({"id": t2}), &db, None).unwrap();
+        let get_result = dispatch_tool(
+            "get_task",
+            &json!({"id": t2}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         let data = parse_response(&get_result);
         assert!(data["blockers"].as_array().unwrap().is_empty());
     }
+
+    #[test]
+    fn test_get_task_neighbors_lists_direct_edges() {
+        let (db, _dir) = test_db();
+        let (hub, blocker, blocked) = seed_hub(&db);
+        dispatch_tool(
+            "update_task",
+            &json!({"id": blocker, "status": "done"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        dispatch_tool(
+            "rename_task",
+            &json!({"id": blocked, "title": "Downstream"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+
+        let result = dispatch_tool(
+            "get_task",
+            &json!({"id": hub}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert!(parse_response(&result).get("neighbors").is_none());
+
+        let result = dispatch_tool(
+            "get_task",
+            &json!({"id": hub, "neighbors": true}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        let neighbors = &parse_response(&result)["neighbors"];
+        let blockers = neighbors["blockers"].as_array().unwrap();
+        let blocks = neighbors["blocks"].as_array().unwrap();
+        assert_eq!((blockers.len(), blocks.len()), (2, 2));
+
+        let short_id = |id: &str| crate::db::task::get_task(&db, id).unwrap().unwrap().short_id;
+        let done = blockers.iter().find(|n| n["short_id"] == json!(short_id(&blocker))).unwrap();
+        assert_eq!(done["status"], "done");
+        assert_eq!(done["title"], "Test Task");
+        let downstream =
+            blocks.iter().find(|n| n["short_id"] == json!(short_id(&blocked))).unwrap();
+        assert_eq!(downstream["title"], "Downstream");
+        assert_eq!(downstream["status"], "todo");
+        assert_eq!(downstream.as_object().unwrap().len(), 3);
+    }
+
+    // --- clear_dependencies tests ---
+
+    /// Seeds `hub` with two incoming edges (a, b -> hub) and two outgoing
+    /// edges (hub -> c, d). Returns (hub, a, c).
+    fn seed_hub(db: &Database) -> (String, String, String) {
+        let pid = create_test_project(db);
+        let eid = create_test_epic(db, &pid);
+        let hub = create_test_task(db, &eid);
+        let others: Vec<String> = (0..4).map(|_| create_test_task(db, &eid)).collect();
+        for (i, other) in others.iter().enumerate() {
+            let (blocker, blocked) = if i < 2 { (other, &hub) } else { (&hub, other) };
+            dispatch_tool(
+                "add_dependency",
+                &json!({
+                    "blocker_type": "task", "blocker_id": blocker,
+                    "blocked_type": "task", "blocked_id": blocked,
+                }),
+                db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap();
+        }
+        (hub, others[0].clone(), others[2].clone())
+    }
+
+    fn edge_counts(db: &Database, id: &str) -> (usize, usize) {
+        let result = dispatch_tool(
+            "get_task",
+            &json!({"id": id}),
+            db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        let data = parse_response(&result);
+        (
+            data["blockers"].as_array().unwrap().len(),
+            data["blocks"].as_array().unwrap().len(),
+        )
+    }
+
+    #[test]
+    fn test_clear_dependencies_incoming_only() {
+        let (db, _dir) = test_db();
+        let (hub, a, _) = seed_hub(&db);
+
+        let result = dispatch_tool(
+            "clear_dependencies",
+            &json!({"type": "task", "id": hub, "direction": "incoming"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(parse_response(&result)["removed"], 2);
+        assert_eq!(edge_counts(&db, &hub), (0, 2));
+        assert_eq!(edge_counts(&db, &a), (0, 0));
+    }
+
+    #[test]
+    fn test_clear_dependencies_outgoing_only() {
+        let (db, _dir) = test_db();
+        let (hub, _, c) = seed_hub(&db);
+
+        let result = dispatch_tool(
+            "clear_dependencies",
+            &json!({"type": "task", "id": hub, "direction": "outgoing"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(parse_response(&result)["removed"], 2);
+        assert_eq!(edge_counts(&db, &hub), (2, 0));
+        assert_eq!(edge_counts(&db, &c), (0, 0));
+    }
+
+    #[test]
+    fn test_clear_dependencies_defaults_to_both() {
+        let (db, _dir) = test_db();
+        let (hub, _, _) = seed_hub(&db);
+
+        let result = dispatch_tool(
+            "clear_dependencies",
+            &json!({"type": "task", "id": hub}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(parse_response(&result)["removed"], 4);
+        assert_eq!(edge_counts(&db, &hub), (0, 0));
+
+        let result = dispatch_tool(
+            "clear_dependencies",
+            &json!({"type": "task", "id": hub}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(parse_response(&result)["removed"], 0);
+    }
+
+    #[test]
+    fn test_clear_dependencies_invalid_direction() {
+        let (db, _dir) = test_db();
+        let (hub, _, _) = seed_hub(&db);
+
+        let result = dispatch_tool(
+            "clear_dependencies",
+            &json!({"type": "task", "id": hub, "direction": "sideways"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+        assert!(result["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("Invalid direction"));
+        assert_eq!(edge_counts(&db, &hub), (2, 2));
+    }
+
+    #[test]
+    fn test_get_dependencies_direction_transitive_and_paging() {
+        let (db, _dir) = test_db();
+        let (hub, a, _) = seed_hub(&db);
+        let get = |args: Value| {
+            let result = dispatch_tool(
+                "get_dependencies",
+                &args,
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap();
+            assert!(result.get("isError").is_none(), "{result}");
+            parse_response(&result)
+        };
+        let len = |v: &Value| v.as_array().unwrap().len();
+
+        let data = get(json!({"type": "task", "id": hub, "direction": "incoming"}));
+        assert_eq!(len(&data["blockers"]), 2);
+        assert!(data.get("blocks").is_none());
+
+        // A blocker of a blocker only shows up transitively.
+        let epic_id = crate::db::task::get_task(&db, &a).unwrap().unwrap().epic_id;
+        let upstream = create_test_task(&db, &epic_id);
+        dispatch_tool(
+            "add_dependency",
+            &json!({
+                "blocker_type": "task", "blocker_id": upstream,
+                "blocked_type": "task", "blocked_id": a,
+            }),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        let data = get(json!({"type": "task", "id": hub}));
+        assert_eq!((len(&data["blockers"]), len(&data["blocks"])), (2, 2));
+        let data = get(json!({"type": "task", "id": hub, "transitive": true, "expand": true}));
+        assert_eq!((len(&data["blockers"]), len(&data["blocks"])), (3, 2));
+        assert!(data["blockers"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|item| item["id"] == upstream));
+
+        // Each list is paged on its own.
+        let page = json!({"type": "task", "id": hub, "transitive": true, "limit": 2, "offset": 1});
+        let data = get(page);
+        assert_eq!((len(&data["blockers"]), len(&data["blocks"])), (2, 1));
+
+        for bad in [json!({"limit": 0}), json!({"limit": "5"}), json!({"offset": -1})] {
+            let mut args = json!({"type": "task", "id": hub});
+            args.as_object_mut().unwrap().extend(bad.as_object().unwrap().clone());
+            let result = dispatch_tool(
+                "get_dependencies",
+                &args,
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap();
+            assert_eq!(result["isError"], true, "{args}");
+        }
+    }
+
+    #[test]
+    fn test_expanded_dependencies_carry_titles_and_statuses() {
+        use crate::db::dependency::get_all_dependencies;
+        use crate::db::seed::seed_demo_data;
+        use crate::db::{epic::get_epic, task::get_task};
+        use crate::models::dependency::DependencyType;
+
+        let (db, _dir) = test_db();
+        seed_demo_data(&db).unwrap();
+        let describe = |item_type: &DependencyType, id: &str| match item_type {
+            DependencyType::Epic => {
+                let e = get_epic(&db, id).unwrap().unwrap();
+                json!({"short_id": e.short_id, "title": e.title, "status": e.status})
+            }
+            DependencyType::Task => {
+                let t = get_task(&db, id).unwrap().unwrap();
+                json!({"short_id": t.short_id, "title": t.title, "status": t.status})
+            }
+        };
+
+        let deps = get_all_dependencies(&db).unwrap();
+        assert!(!deps.is_empty());
+        for dep in &deps {
+            let result = dispatch_tool(
+                "get_dependencies",
+                &json!({"type": dep.blocked_type, "id": dep.blocked_id, "expand": true}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap();
+            assert!(result.get("isError").is_none(), "{result}");
+            let data = parse_response(&result);
+            let blocker = data["blockers"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|b| b["dependency_id"] == dep.id)
+                .expect("blocker listed");
+            assert_eq!(blocker["type"], json!(dep.blocker_type));
+            assert_eq!(blocker["id"], dep.blocker_id);
+            let expected = describe(&dep.blocker_type, &dep.blocker_id);
+            for field in ["short_id", "title", "status"] {
+                assert_eq!(blocker[field], expected[field], "{field} of {}", dep.blocker_id);
+            }
+        }
+
+        // get_task expands the same way; without expand it keeps dependency rows.
+        let dep = deps.iter().find(|d| d.blocked_type == DependencyType::Task).unwrap();
+        let task = |expand: bool| {
+            parse_response(
+                &dispatch_tool(
+                    "get_task",
+                    &json!({"id": dep.blocked_id, "expand": expand}),
+                    &db,
+                    &ToolContext::default(),
+                    None,
+                )
+                .unwrap(),
+            )
+        };
+        let expanded = task(true);
+        let blocker = &expanded["blockers"].as_array().unwrap()[0];
+        assert!(blocker["title"].is_string() && blocker["status"].is_string());
+        let blocker_task = get_task(&db, blocker["id"].as_str().unwrap()).unwrap().unwrap();
+        assert_eq!(blocker["title"], blocker_task.title);
+        let plain = task(false);
+        assert!(plain["blockers"][0]["blocker_id"].is_string());
+        assert!(plain["blockers"][0].get("title").is_none());
+    }
 }
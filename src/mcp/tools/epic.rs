@@ -2,21 +2,31 @@ use serde_json::{json, Value};
 
 use crate::db::dependency as dep_db;
 use crate::db::epic as epic_db;
+use crate::db::prd as prd_db;
+use crate::db::status as status_db;
 use crate::db::task as task_db;
 use crate::db::Database;
 use crate::models::dependency::DependencyType;
-use crate::models::epic::{CreateEpicInput, ItemStatus, UpdateEpicInput};
+use crate::models::epic::{CreateEpicInput, EpicListFilter, ItemStatus, UpdateEpicInput};
+use crate::models::task::TaskListFilter;
 
+use super::dependency::neighbors;
 use super::{
-    optional_str, parse_optional_status, require_str, resolve_optional_project_id,
-    resolve_project_id, tool_error, tool_result, validate_project_exists,
+    actor, check_text_limits, listed_page, optional_bool, optional_date_range, optional_str,
+    parse_optional_status, parse_status_filter, rename_args, require_str,
+    resolve_optional_project_id, resolve_project_id, tool_error, tool_result,
+    validate_project_exists, ListPage, ToolContext,
 };
 
 pub(super) fn handle_create_epic(
     args: &Value,
     db: &Database,
+    ctx: &ToolContext,
     default_project_id: Option<&str>,
 ) -> Value {
+    if let Err(e) = check_text_limits(ctx, args) {
+        return e;
+    }
     let project_id = match resolve_project_id(args, default_project_id) {
         Ok(v) => v,
         Err(e) => return e,
@@ -30,14 +40,29 @@ pub(super) fn handle_create_epic(
         Err(e) => return e,
     };
 
+    let prd_id = optional_str(args, "prd_id");
+
     if let Err(e) = validate_project_exists(db, &project_id) {
         return e;
     }
 
-    match epic_db::create_epic(db, CreateEpicInput { project_id, title, description }) {
-        Ok(epic) => tool_result(&epic),
+    if let Some(prd_id) = &prd_id {
+        match prd_db::get_prd(db, prd_id) {
+            Ok(Some(prd)) if prd.project_id == project_id => {}
+            Ok(Some(_)) => return tool_error(&format!("PRD {prd_id} belongs to another project")),
+            Ok(None) => return tool_error(&format!("PRD not found: {prd_id}")),
+            Err(e) => {
+                log::error!("create_epic error: {e:#}");
+                return tool_error("Failed to look up PRD");
+            }
+        }
+    }
+
+    let input = CreateEpicInput { project_id, title, description, prd_id };
+    match epic_db::create_epic(db, input, actor(args)) {
+        Ok(epic) => tool_result(ctx, &epic),
         Err(e) => {
-            eprintln!("create_epic error: {e:#}");
+            log::error!("create_epic error: {e:#}");
             tool_error("Failed to create epic")
         }
     }
@@ -46,29 +71,84 @@ pub(super) fn handle_create_epic(
 pub(super) fn handle_list_epics(
     args: &Value,
     db: &Database,
+    ctx: &ToolContext,
     default_project_id: Option<&str>,
 ) -> Value {
     let project_id = resolve_optional_project_id(args, default_project_id);
-    let status = match parse_optional_status::<ItemStatus>(args) {
+    let status = match parse_status_filter(args) {
         Ok(s) => s,
         Err(e) => return e,
     };
 
-    match epic_db::list_epics(db, project_id.as_deref(), status) {
-        Ok(epics) => tool_result(&epics),
+    let created = match optional_date_range(args, "created_between") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let updated = match optional_date_range(args, "updated_between") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+
+    let page = match ListPage::from_args(ctx, args) {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+
+    let epics = match epic_db::list_epics(
+        db,
+        &EpicListFilter {
+            project_id: project_id.as_deref(),
+            status,
+            include_archived: optional_bool(args, "include_archived"),
+            created,
+            updated,
+        },
+    ) {
+        Ok(epics) => epics,
         Err(e) => {
-            eprintln!("list_epics error: {e:#}");
-            tool_error("Failed to list epics")
+            log::error!("list_epics error: {e:#}");
+            return tool_error("Failed to list epics");
         }
+    };
+
+    let (epics, marker) = page.apply(epics);
+    if !optional_bool(args, "include_counts") {
+        return tool_result(ctx, &listed_page(epics, marker));
     }
+
+    let mut counts = match status_db::count_tasks_by_status_per_epic(db, project_id.as_deref()) {
+        Ok(c) => c,
+        Err(e) => {
+            log::error!("list_epics error: {e:#}");
+            return tool_error("Failed to count tasks");
+        }
+    };
+
+    let epics: Vec<Value> = epics
+        .into_iter()
+        .map(|epic| {
+            let task_counts = counts.remove(&epic.id).unwrap_or_default();
+            let mut value = json!(epic);
+            value["task_counts"] = json!(task_counts);
+            value
+        })
+        .collect();
+
+    tool_result(ctx, &listed_page(epics, marker))
 }
 
-pub(super) fn handle_get_epic(args: &Value, db: &Database, default_project_id: Option<&str>) -> Value {
+pub(super) fn handle_get_epic(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
     let id = match require_str(args, "id") {
         Ok(v) => v,
         Err(e) => return e,
     };
-    let id = match epic_db::resolve_epic_id(db, &id, default_project_id) {
+    let project_id = resolve_optional_project_id(args, default_project_id);
+    let id = match epic_db::resolve_epic_id(db, &id, project_id.as_deref()) {
         Ok(v) => v,
         Err(e) => return tool_error(&e.to_string()),
     };
@@ -77,15 +157,21 @@ pub(super) fn handle_get_epic(args: &Value, db: &Database, default_project_id: O
         Ok(Some(e)) => e,
         Ok(None) => return tool_error(&format!("Epic not found: {id}")),
         Err(e) => {
-            eprintln!("get_epic error: {e:#}");
+            log::error!("get_epic error: {e:#}");
             return tool_error("Failed to get epic");
         }
     };
 
-    let tasks = match task_db::list_tasks(db, Some(&id), None, None) {
+    let tasks = match task_db::list_tasks(
+        db,
+        &TaskListFilter {
+            epic_id: Some(&id),
+            ..Default::default()
+        },
+    ) {
         Ok(t) => t,
         Err(e) => {
-            eprintln!("list_tasks error: {e:#}");
+            log::error!("list_tasks error: {e:#}");
             return tool_error("Failed to get epic");
         }
     };
@@ -93,7 +179,7 @@ pub(super) fn handle_get_epic(args: &Value, db: &Database, default_project_id: O
     let blockers = match dep_db::get_blockers(db, &DependencyType::Epic, &id) {
         Ok(b) => b,
         Err(e) => {
-            eprintln!("get_blockers error: {e:#}");
+            log::error!("get_blockers error: {e:#}");
             return tool_error("Failed to get epic");
         }
     };
@@ -101,20 +187,41 @@ pub(super) fn handle_get_epic(args: &Value, db: &Database, default_project_id: O
     let blocks = match dep_db::get_blocked_by(db, &DependencyType::Epic, &id) {
         Ok(b) => b,
         Err(e) => {
-            eprintln!("get_blocked_by error: {e:#}");
+            log::error!("get_blocked_by error: {e:#}");
             return tool_error("Failed to get epic");
         }
     };
 
-    tool_result(&json!({ "epic": epic, "tasks": tasks, "blockers": blockers, "blocks": blocks }))
+    let mut response =
+        json!({ "epic": epic, "tasks": tasks, "blockers": blockers, "blocks": blocks });
+    if optional_bool(args, "neighbors") {
+        match neighbors(db, &DependencyType::Epic, &id) {
+            Ok(n) => response["neighbors"] = n,
+            Err(e) => {
+                log::error!("get_epic error: {e:#}");
+                return tool_error("Failed to get epic");
+            }
+        }
+    }
+
+    tool_result(ctx, &response)
 }
 
-pub(super) fn handle_update_epic(args: &Value, db: &Database, default_project_id: Option<&str>) -> Value {
+pub(super) fn handle_update_epic(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    if let Err(e) = check_text_limits(ctx, args) {
+        return e;
+    }
     let id = match require_str(args, "id") {
         Ok(v) => v,
         Err(e) => return e,
     };
-    let id = match epic_db::resolve_epic_id(db, &id, default_project_id) {
+    let project_id = resolve_optional_project_id(args, default_project_id);
+    let id = match epic_db::resolve_epic_id(db, &id, project_id.as_deref()) {
         Ok(v) => v,
         Err(e) => return tool_error(&e.to_string()),
     };
@@ -130,26 +237,44 @@ pub(super) fn handle_update_epic(args: &Value, db: &Database, default_project_id
         status,
     };
 
-    match epic_db::update_epic(db, &id, input) {
-        Ok(epic) => tool_result(&epic),
+    match epic_db::update_epic(db, &id, input, actor(args)) {
+        Ok(epic) => tool_result(ctx, &epic),
         Err(e) => {
             let msg = e.to_string();
             if msg.contains("not found") {
                 tool_error(&format!("Epic not found: {id}"))
             } else {
-                eprintln!("update_epic error: {e:#}");
+                log::error!("update_epic error: {e:#}");
                 tool_error("Failed to update epic")
             }
         }
     }
 }
 
-pub(super) fn handle_delete_epic(args: &Value, db: &Database, default_project_id: Option<&str>) -> Value {
+pub(super) fn handle_rename_epic(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    match rename_args(args, "title") {
+        Ok(update) => handle_update_epic(&update, db, ctx, default_project_id),
+        Err(e) => e,
+    }
+}
+
+pub(super) fn handle_delete_epic(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
     let id = match require_str(args, "id") {
         Ok(v) => v,
         Err(e) => return e,
     };
-    let id = match epic_db::resolve_epic_id(db, &id, default_project_id) {
+    let project_id = resolve_optional_project_id(args, default_project_id);
+    let id = match epic_db::resolve_epic_id(db, &id, project_id.as_deref()) {
         Ok(v) => v,
         Err(e) => return tool_error(&e.to_string()),
     };
@@ -160,20 +285,89 @@ pub(super) fn handle_delete_epic(args: &Value, db: &Database, default_project_id
         .and_then(|e| e.short_id);
 
     match epic_db::delete_epic(db, &id) {
-        Ok(true) => tool_result(&json!({ "deleted": true, "id": id, "short_id": short_id })),
+        Ok(true) => tool_result(ctx, &json!({ "deleted": true, "id": id, "short_id": short_id })),
         Ok(false) => tool_error(&format!("Epic not found: {id}")),
         Err(e) => {
-            eprintln!("delete_epic error: {e:#}");
+            log::error!("delete_epic error: {e:#}");
             tool_error("Failed to delete epic")
         }
     }
 }
 
+/// `archive_epic` / `unarchive_epic`: set the epic's archived flag.
+pub(super) fn handle_set_epic_archived(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+    archived: bool,
+) -> Value {
+    let id = match require_str(args, "id") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let project_id = resolve_optional_project_id(args, default_project_id);
+    let id = match epic_db::resolve_epic_id(db, &id, project_id.as_deref()) {
+        Ok(v) => v,
+        Err(e) => return tool_error(&e.to_string()),
+    };
+
+    match epic_db::set_epic_archived(db, &id, archived, actor(args)) {
+        Ok(epic) => tool_result(ctx, &epic),
+        Err(e) => {
+            log::error!("set_epic_archived error: {e:#}");
+            tool_error("Failed to archive epic")
+        }
+    }
+}
+
+pub(super) fn handle_reorder_epics(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    let project_id = match resolve_project_id(args, default_project_id) {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let Some(requested) = args.get("epic_ids").and_then(Value::as_array) else {
+        return tool_error("Missing required parameter: epic_ids");
+    };
+    if requested.is_empty() {
+        return tool_error("epic_ids must list at least one epic");
+    }
+
+    if let Err(e) = validate_project_exists(db, &project_id) {
+        return e;
+    }
+
+    let mut ordered_ids = Vec::with_capacity(requested.len());
+    for value in requested {
+        let Some(id) = value.as_str() else {
+            return tool_error("epic_ids must be a list of epic IDs");
+        };
+        match epic_db::resolve_epic_id(db, id, Some(&project_id)) {
+            Ok(id) => ordered_ids.push(id),
+            Err(e) => return tool_error(&e.to_string()),
+        }
+    }
+
+    match epic_db::reorder_epics(db, &project_id, &ordered_ids) {
+        Ok(epics) => tool_result(ctx, &epics),
+        Err(e) => {
+            log::error!("reorder_epics error: {e:#}");
+            tool_error(&format!("Failed to reorder epics: {e}"))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::super::dispatch_tool;
+    use super::super::{dispatch_tool, ToolContext};
     use crate::db::task as task_db;
     use crate::db::Database;
+    use crate::models::TaskListFilter;
     use serde_json::{json, Value};
     use tempfile::TempDir;
 
@@ -194,6 +388,7 @@ mod tests {
             "create_project",
             &json!({"name": "Test Project", "description": "for epic tests"}),
             db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -212,6 +407,7 @@ mod tests {
             "create_epic",
             &json!({"project_id": project_id, "title": "My Epic", "description": "desc"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -232,6 +428,7 @@ mod tests {
             "create_epic",
             &json!({"title": "E", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -250,6 +447,7 @@ mod tests {
             "create_epic",
             &json!({"project_id": project_id, "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -267,6 +465,7 @@ mod tests {
             "create_epic",
             &json!({"project_id": "nonexistent", "title": "E", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -282,7 +481,8 @@ mod tests {
     #[test]
     fn test_list_epics_empty() {
         let (db, _dir) = test_db();
-        let result = dispatch_tool("list_epics", &json!({}), &db, None).unwrap();
+        let result =
+            dispatch_tool("list_epics", &json!({}), &db, &ToolContext::default(), None).unwrap();
         assert!(result.get("isError").is_none());
         let epics = parse_response(&result);
         assert!(epics.as_array().unwrap().is_empty());
@@ -296,20 +496,124 @@ mod tests {
             "create_epic",
             &json!({"project_id": project_id, "title": "E1", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         );
         dispatch_tool(
             "create_epic",
             &json!({"project_id": project_id, "title": "E2", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         );
 
-        let result = dispatch_tool("list_epics", &json!({}), &db, None).unwrap();
+        let result =
+            dispatch_tool("list_epics", &json!({}), &db, &ToolContext::default(), None).unwrap();
         let epics = parse_response(&result);
         assert_eq!(epics.as_array().unwrap().len(), 2);
     }
 
+    #[test]
+    fn test_list_epics_include_counts() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let busy = parse_response(
+            &dispatch_tool(
+                "create_epic",
+                &json!({"project_id": project_id, "title": "Busy", "description": "d"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        )["id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+        let empty = parse_response(
+            &dispatch_tool(
+                "create_epic",
+                &json!({"project_id": project_id, "title": "Empty", "description": "d"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        )["id"]
+            .as_str()
+            .unwrap()
+            .to_string();
+
+        let mut task_ids = Vec::new();
+        for title in ["T1", "T2", "T3"] {
+            let created = dispatch_tool(
+                "create_task",
+                &json!({"epic_id": busy, "title": title, "description": "d"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap();
+            task_ids.push(parse_response(&created)["id"].as_str().unwrap().to_string());
+        }
+        dispatch_tool(
+            "update_task",
+            &json!({"id": task_ids[0], "status": "done"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        );
+        dispatch_tool(
+            "update_task",
+            &json!({"id": task_ids[1], "status": "in_progress"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        );
+
+        let result = dispatch_tool(
+            "list_epics",
+            &json!({"project_id": project_id, "include_counts": true}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        let epics = parse_response(&result);
+        let by_id = |id: &str| {
+            epics
+                .as_array()
+                .unwrap()
+                .iter()
+                .find(|e| e["id"] == id)
+                .unwrap()
+                .clone()
+        };
+
+        let busy = by_id(&busy);
+        assert_eq!(busy["task_counts"]["todo"], 1);
+        assert_eq!(busy["task_counts"]["in_progress"], 1);
+        assert_eq!(busy["task_counts"]["done"], 1);
+        assert_eq!(busy["task_count"], 3);
+
+        let empty = by_id(&empty);
+        assert_eq!(empty["task_counts"]["todo"], 0);
+        assert_eq!(empty["task_counts"]["in_progress"], 0);
+        assert_eq!(empty["task_counts"]["done"], 0);
+
+        // Without the flag the breakdown is omitted.
+        let result = dispatch_tool(
+            "list_epics",
+            &json!({"project_id": project_id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        let epics = parse_response(&result);
+        assert!(epics[0].get("task_counts").is_none());
+    }
+
     #[test]
     fn test_list_epics_project_id_filter() {
         let (db, _dir) = test_db();
@@ -319,16 +623,25 @@ mod tests {
             "create_epic",
             &json!({"project_id": p1, "title": "E1", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         );
         dispatch_tool(
             "create_epic",
             &json!({"project_id": p2, "title": "E2", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         );
 
-        let result = dispatch_tool("list_epics", &json!({"project_id": p1}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "list_epics",
+            &json!({"project_id": p1}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         let epics = parse_response(&result);
         assert_eq!(epics.as_array().unwrap().len(), 1);
         assert_eq!(epics[0]["title"], "E1");
@@ -343,6 +656,7 @@ mod tests {
             "create_epic",
             &json!({"project_id": project_id, "title": "E1", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -353,6 +667,7 @@ mod tests {
             "update_epic",
             &json!({"id": epic_id, "status": "in_progress"}),
             &db,
+            &ToolContext::default(),
             None,
         );
 
@@ -360,10 +675,18 @@ mod tests {
             "create_epic",
             &json!({"project_id": project_id, "title": "E2", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         );
 
-        let result = dispatch_tool("list_epics", &json!({"status": "in_progress"}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "list_epics",
+            &json!({"status": "in_progress"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         let epics = parse_response(&result);
         assert_eq!(epics.as_array().unwrap().len(), 1);
         assert_eq!(epics[0]["title"], "E1");
@@ -372,7 +695,14 @@ mod tests {
     #[test]
     fn test_list_epics_invalid_status() {
         let (db, _dir) = test_db();
-        let result = dispatch_tool("list_epics", &json!({"status": "bogus"}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "list_epics",
+            &json!({"status": "bogus"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         assert_eq!(result["isError"], true);
         assert!(result["content"][0]["text"]
             .as_str()
@@ -382,6 +712,58 @@ mod tests {
 
     // --- get_epic tests ---
 
+    #[test]
+    fn test_get_epic_neighbors_lists_direct_edges() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let create = |title: &str| {
+            let result = dispatch_tool(
+                "create_epic",
+                &json!({"project_id": project_id, "title": title, "description": ""}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap();
+            parse_response(&result)["id"].as_str().unwrap().to_string()
+        };
+        let (first, middle, last) = (create("First"), create("Middle"), create("Last"));
+        for (blocker, blocked) in [(&first, &middle), (&middle, &last)] {
+            let args = json!({
+                "blocker_type": "epic", "blocker_id": blocker,
+                "blocked_type": "epic", "blocked_id": blocked,
+            });
+            dispatch_tool("add_dependency", &args, &db, &ToolContext::default(), None).unwrap();
+        }
+        dispatch_tool(
+            "update_epic",
+            &json!({"id": first, "status": "in_progress"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+
+        let result = dispatch_tool(
+            "get_epic",
+            &json!({"id": middle, "neighbors": true}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        let neighbors = &parse_response(&result)["neighbors"];
+        let short_id = |id: &str| crate::db::epic::get_epic(&db, id).unwrap().unwrap().short_id;
+        assert_eq!(
+            neighbors["blockers"],
+            json!([{ "short_id": short_id(&first), "title": "First", "status": "in_progress" }])
+        );
+        assert_eq!(
+            neighbors["blocks"],
+            json!([{ "short_id": short_id(&last), "title": "Last", "status": "todo" }])
+        );
+    }
+
     #[test]
     fn test_get_epic_success_with_nested_data() {
         let (db, _dir) = test_db();
@@ -391,6 +773,7 @@ mod tests {
             "create_epic",
             &json!({"project_id": project_id, "title": "Get Me", "description": "desc"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -405,11 +788,19 @@ mod tests {
                 title: "Child Task".to_string(),
                 description: "task desc".to_string(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
 
-        let result = dispatch_tool("get_epic", &json!({"id": epic_id}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "get_epic",
+            &json!({"id": epic_id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         assert!(result.get("isError").is_none());
         let data = parse_response(&result);
         assert_eq!(data["epic"]["title"], "Get Me");
@@ -419,10 +810,88 @@ mod tests {
         assert!(data["blocks"].as_array().unwrap().is_empty());
     }
 
+    #[test]
+    fn test_epic_title_and_description_limits() {
+        use crate::settings::TextLimits;
+
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let limits = TextLimits { title: 8, description: 12 };
+        let ctx = ToolContext { text_limits: limits, ..Default::default() };
+        let create = |title: &str, description: &str| {
+            let args = json!({"project_id": project_id, "title": title, "description": description});
+            dispatch_tool("create_epic", &args, &db, &ctx, None).unwrap()
+        };
+        let created = create("Exactly8", "twelve chars");
+        assert!(created.get("isError").is_none());
+        let over = create("Nine char", "");
+        assert_eq!(
+            over["content"][0]["text"],
+            "VALIDATION: title is 9 characters, over the limit of 8"
+        );
+
+        let id = parse_response(&created)["id"].clone();
+        let args = json!({"id": id, "description": "thirteen char"});
+        let result = dispatch_tool("update_epic", &args, &db, &ctx, None).unwrap();
+        assert_eq!(result["isError"], true);
+        let args = json!({"id": id, "title": "Too long!"});
+        let result = dispatch_tool("rename_epic", &args, &db, &ctx, None).unwrap();
+        assert_eq!(result["isError"], true);
+    }
+
+    #[test]
+    fn test_get_epic_returns_updated_by() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let create = |title: &str| {
+            let args = json!({"project_id": project_id, "title": title, "description": ""});
+            parse_response(
+                &dispatch_tool("create_epic", &args, &db, &ToolContext::default(), None).unwrap(),
+            )
+        };
+        let first = create("First");
+        let second = create("Second");
+        assert!(first["updated_by"].is_null());
+
+        let args = json!({"id": first["id"], "description": "scoped", "actor": "reviewer"});
+        dispatch_tool("update_epic", &args, &db, &ToolContext::default(), None).unwrap();
+        let fetched = |id: &Value| {
+            let result = dispatch_tool(
+                "get_epic",
+                &json!({"id": id}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap();
+            parse_response(&result)["epic"]["updated_by"].clone()
+        };
+        assert_eq!(fetched(&first["id"]), "reviewer");
+
+        // Adding a dependency touches both ends.
+        let args = json!({
+            "blocker_type": "epic",
+            "blocker_id": first["id"],
+            "blocked_type": "epic",
+            "blocked_id": second["id"],
+            "actor": "planner",
+        });
+        dispatch_tool("add_dependency", &args, &db, &ToolContext::default(), None).unwrap();
+        assert_eq!(fetched(&first["id"]), "planner");
+        assert_eq!(fetched(&second["id"]), "planner");
+    }
+
     #[test]
     fn test_get_epic_not_found() {
         let (db, _dir) = test_db();
-        let result = dispatch_tool("get_epic", &json!({"id": "nonexistent"}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "get_epic",
+            &json!({"id": "nonexistent"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         assert_eq!(result["isError"], true);
         assert!(result["content"][0]["text"]
             .as_str()
@@ -433,7 +902,8 @@ mod tests {
     #[test]
     fn test_get_epic_missing_id() {
         let (db, _dir) = test_db();
-        let result = dispatch_tool("get_epic", &json!({}), &db, None).unwrap();
+        let result =
+            dispatch_tool("get_epic", &json!({}), &db, &ToolContext::default(), None).unwrap();
         assert_eq!(result["isError"], true);
         assert!(result["content"][0]["text"]
             .as_str()
@@ -452,6 +922,7 @@ mod tests {
             "create_epic",
             &json!({"project_id": project_id, "title": "Original", "description": "old desc"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -462,6 +933,7 @@ mod tests {
             "update_epic",
             &json!({"id": id, "title": "Renamed", "status": "done"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -473,6 +945,56 @@ mod tests {
         assert_eq!(updated["status"], "done");
     }
 
+    #[test]
+    fn test_rename_epic_changes_only_title() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let created = parse_response(
+            &dispatch_tool(
+                "create_epic",
+                &json!({"project_id": project_id, "title": "Old", "description": "keep me"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        dispatch_tool(
+            "update_epic",
+            &json!({"id": "E1", "status": "in_progress"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        );
+
+        let result = dispatch_tool(
+            "rename_epic",
+            &json!({"id": "E1", "title": "New", "status": "done"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert!(result.get("isError").is_none());
+        let renamed = parse_response(&result);
+        assert_eq!(renamed["id"], created["id"]);
+        assert_eq!(renamed["title"], "New");
+        assert_eq!(renamed["description"], "keep me");
+        assert_eq!(renamed["status"], "in_progress");
+        assert_eq!(renamed["short_id"], "E1");
+        assert_eq!(renamed["created_at"], created["created_at"]);
+
+        let result = dispatch_tool(
+            "rename_epic",
+            &json!({"id": "E9", "title": "X"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+    }
+
     #[test]
     fn test_update_epic_not_found() {
         let (db, _dir) = test_db();
@@ -480,6 +1002,7 @@ mod tests {
             "update_epic",
             &json!({"id": "nonexistent", "title": "X"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -501,27 +1024,129 @@ mod tests {
             "create_epic",
             &json!({"project_id": project_id, "title": "To Delete", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
         let created = parse_response(&create_result);
         let id = created["id"].as_str().unwrap();
 
-        let result = dispatch_tool("delete_epic", &json!({"id": id}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "delete_epic",
+            &json!({"id": id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         assert!(result.get("isError").is_none());
         let data = parse_response(&result);
         assert_eq!(data["deleted"], true);
 
         // Verify it's gone
-        let get_result = dispatch_tool("get_epic", &json!({"id": id}), &db, None).unwrap();
+        let get_result = dispatch_tool(
+            "get_epic",
+            &json!({"id": id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         assert_eq!(get_result["isError"], true);
     }
 
+    #[test]
+    fn test_list_epics_appends_a_truncation_marker() {
+        let (db, _dir) = test_db();
+        let pid = create_test_project(&db);
+        for i in 0..3 {
+            dispatch_tool(
+                "create_epic",
+                &json!({"project_id": pid, "title": format!("E{i}"), "description": ""}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap();
+        }
+        let list = |args: Value| -> Vec<Value> {
+            let result =
+                dispatch_tool("list_epics", &args, &db, &ToolContext::default(), None).unwrap();
+            parse_response(&result).as_array().unwrap().clone()
+        };
+
+        assert_eq!(list(json!({"project_id": pid})).len(), 3);
+        for counts in [false, true] {
+            let page = list(json!({"project_id": pid, "max_items": 2, "include_counts": counts}));
+            assert_eq!(page.len(), 3);
+            assert_eq!(page[1]["title"], "E1");
+            assert_eq!(page[1].get("task_counts").is_some(), counts);
+            assert_eq!(page[2]["truncated"], true);
+            assert_eq!(page[2]["returned"], 2);
+            assert_eq!(page[2]["total"], 3);
+        }
+    }
+
+    #[test]
+    fn test_archive_epic_hides_it_from_list_epics() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        for title in ["Keep", "Shelve"] {
+            dispatch_tool(
+                "create_epic",
+                &json!({"project_id": project_id, "title": title, "description": ""}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap();
+        }
+        let titles = |args: Value| -> Vec<String> {
+            let parsed = parse_response(
+                &dispatch_tool("list_epics", &args, &db, &ToolContext::default(), None).unwrap(),
+            );
+            let mut titles: Vec<String> = parsed
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|e| e["title"].as_str().unwrap().to_string())
+                .collect();
+            titles.sort();
+            titles
+        };
+
+        let result = dispatch_tool(
+            "archive_epic",
+            &json!({"id": "E2", "project_id": project_id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        let archived = parse_response(&result);
+        assert_eq!(archived["title"], "Shelve");
+        assert_eq!(archived["archived"], true);
+
+        assert_eq!(titles(json!({"project_id": project_id})), vec!["Keep"]);
+        let args = json!({"project_id": project_id, "include_archived": true});
+        assert_eq!(titles(args), vec!["Keep", "Shelve"]);
+
+        let args = json!({"id": archived["id"]});
+        dispatch_tool("unarchive_epic", &args, &db, &ToolContext::default(), None).unwrap();
+        assert_eq!(titles(json!({"project_id": project_id})), vec!["Keep", "Shelve"]);
+    }
+
     #[test]
     fn test_delete_epic_not_found() {
         let (db, _dir) = test_db();
-        let result =
-            dispatch_tool("delete_epic", &json!({"id": "nonexistent"}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "delete_epic",
+            &json!({"id": "nonexistent"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         assert_eq!(result["isError"], true);
         assert!(result["content"][0]["text"]
             .as_str()
@@ -538,6 +1163,7 @@ mod tests {
             "create_epic",
             &json!({"project_id": project_id, "title": "Parent", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -552,15 +1178,30 @@ mod tests {
                 title: "Child Task".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
 
         // Delete the epic
-        dispatch_tool("delete_epic", &json!({"id": epic_id}), &db, None).unwrap();
+        dispatch_tool(
+            "delete_epic",
+            &json!({"id": epic_id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
 
         // Verify tasks are gone
-        let tasks = task_db::list_tasks(&db, Some(epic_id), None, None).unwrap();
+        let tasks = task_db::list_tasks(
+            &db,
+            &TaskListFilter {
+                epic_id: Some(epic_id),
+                ..Default::default()
+            },
+        )
+        .unwrap();
         assert!(tasks.is_empty(), "tasks should be cascade-deleted");
     }
 
@@ -575,6 +1216,7 @@ mod tests {
             "create_epic",
             &json!({"project_id": project_id, "title": "Short ID Test", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -585,6 +1227,7 @@ mod tests {
             "get_epic",
             &json!({"id": "E1"}),
             &db,
+            &ToolContext::default(),
             Some(&project_id),
         )
         .unwrap();
@@ -604,6 +1247,7 @@ mod tests {
             "create_epic",
             &json!({"project_id": project_id, "title": "Original", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -612,6 +1256,7 @@ mod tests {
             "update_epic",
             &json!({"id": "E1", "title": "Updated via short ID"}),
             &db,
+            &ToolContext::default(),
             Some(&project_id),
         )
         .unwrap();
@@ -630,6 +1275,7 @@ mod tests {
             "create_epic",
             &json!({"project_id": project_id, "title": "To Delete", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -638,6 +1284,7 @@ mod tests {
             "delete_epic",
             &json!({"id": "E1"}),
             &db,
+            &ToolContext::default(),
             Some(&project_id),
         )
         .unwrap();
@@ -656,6 +1303,7 @@ mod tests {
             "create_epic",
             &json!({"project_id": project_id, "title": "Case Test", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -666,6 +1314,7 @@ mod tests {
             "get_epic",
             &json!({"id": "e1"}),
             &db,
+            &ToolContext::default(),
             Some(&project_id),
         )
         .unwrap();
@@ -675,6 +1324,79 @@ mod tests {
         assert_eq!(data["epic"]["id"], ulid);
     }
 
+    // --- reorder_epics tests ---
+
+    #[test]
+    fn test_reorder_epics_by_short_id() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        for title in ["A", "B", "C"] {
+            dispatch_tool(
+                "create_epic",
+                &json!({"project_id": project_id, "title": title, "description": "d"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            );
+        }
+
+        let result = dispatch_tool(
+            "reorder_epics",
+            &json!({"project_id": project_id, "epic_ids": ["E1", "E3"]}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert!(result.get("isError").is_none());
+        let epics = parse_response(&result);
+        let titles: Vec<&str> = epics
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| e["title"].as_str().unwrap())
+            .collect();
+        assert_eq!(titles, ["A", "C", "B"]);
+
+        let listed = parse_response(
+            &dispatch_tool(
+                "list_epics",
+                &json!({"project_id": project_id}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        assert_eq!(listed[0]["title"], "A");
+        assert_eq!(listed[2]["position"], 2);
+    }
+
+    #[test]
+    fn test_reorder_epics_rejects_bad_input() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        dispatch_tool(
+            "create_epic",
+            &json!({"project_id": project_id, "title": "A", "description": "d"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        );
+
+        for args in [
+            json!({"project_id": project_id}),
+            json!({"project_id": project_id, "epic_ids": []}),
+            json!({"project_id": project_id, "epic_ids": [1]}),
+            json!({"project_id": project_id, "epic_ids": ["E9"]}),
+            json!({"project_id": project_id, "epic_ids": ["E1", "E1"]}),
+        ] {
+            let result =
+                dispatch_tool("reorder_epics", &args, &db, &ToolContext::default(), None).unwrap();
+            assert_eq!(result["isError"], true, "{args}");
+        }
+    }
+
     // --- Full CRUD lifecycle test ---
 
     #[test]
@@ -687,6 +1409,7 @@ mod tests {
             "create_epic",
             &json!({"project_id": project_id, "title": "Lifecycle", "description": "testing"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -696,7 +1419,14 @@ mod tests {
         assert_eq!(created["status"], "todo");
 
         // Read
-        let get_result = dispatch_tool("get_epic", &json!({"id": id}), &db, None).unwrap();
+        let get_result = dispatch_tool(
+            "get_epic",
+            &json!({"id": id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         let data = parse_response(&get_result);
         assert_eq!(data["epic"]["title"], "Lifecycle");
 
@@ -705,6 +1435,7 @@ mod tests {
             "list_epics",
             &json!({"project_id": project_id}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -716,6 +1447,7 @@ mod tests {
             "update_epic",
             &json!({"id": id, "title": "Updated", "status": "done"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -724,12 +1456,26 @@ mod tests {
         assert_eq!(updated["status"], "done");
 
         // Delete
-        let delete_result = dispatch_tool("delete_epic", &json!({"id": id}), &db, None).unwrap();
+        let delete_result = dispatch_tool(
+            "delete_epic",
+            &json!({"id": id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         let deleted = parse_response(&delete_result);
         assert_eq!(deleted["deleted"], true);
 
         // Verify gone
-        let get_result = dispatch_tool("get_epic", &json!({"id": id}), &db, None).unwrap();
+        let get_result = dispatch_tool(
+            "get_epic",
+            &json!({"id": id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         assert_eq!(get_result["isError"], true);
     }
 }
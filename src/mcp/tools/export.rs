@@ -0,0 +1,117 @@
+use serde_json::{json, Value};
+
+use crate::cli::export::{load_project_export, render, ExportFormat};
+use crate::db::project as project_db;
+use crate::db::Database;
+
+use super::{resolve_project_id, tool_error};
+
+pub(super) fn handle_export_tasks_csv(
+    args: &Value,
+    db: &Database,
+    default_project_id: Option<&str>,
+) -> Value {
+    let project_id = match resolve_project_id(args, default_project_id) {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let project = match project_db::get_project(db, &project_id) {
+        Ok(Some(p)) => p,
+        Ok(None) => return tool_error(&format!("Project not found: {project_id}")),
+        Err(e) => {
+            log::error!("export_tasks_csv error: {e:#}");
+            return tool_error("Failed to export tasks");
+        }
+    };
+
+    match load_project_export(db, project).and_then(|export| render(&export, ExportFormat::Csv)) {
+        // The CSV goes out as-is rather than wrapped in JSON, ready to save.
+        Ok(csv) => json!({ "content": [{ "type": "text", "text": csv }] }),
+        Err(e) => {
+            log::error!("export_tasks_csv error: {e:#}");
+            tool_error("Failed to export tasks")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{dispatch_tool, ToolContext};
+    use crate::db::seed::seed_demo_data;
+    use crate::db::task::{list_tasks, update_task};
+    use crate::db::Database;
+    use crate::models::{TaskListFilter, UpdateTaskInput};
+    use serde_json::json;
+    use tempfile::TempDir;
+
+    fn seeded_db() -> (Database, TempDir, String) {
+        let dir = TempDir::new().unwrap();
+        let db = Database::open(&dir.path().join("test.db")).unwrap();
+        db.migrate().unwrap();
+        let project_id = seed_demo_data(&db).unwrap().project_id;
+        (db, dir, project_id)
+    }
+
+    #[test]
+    fn test_export_tasks_csv() {
+        let (db, _dir, project_id) = seeded_db();
+        let tasks = list_tasks(
+            &db,
+            &TaskListFilter {
+                project_id: Some(&project_id),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        update_task(
+            &db,
+            &tasks[0].id,
+            UpdateTaskInput {
+                title: Some("Fix \"login\", then logout".to_string()),
+                ..Default::default()
+            },
+            None,
+        )
+        .unwrap();
+
+        let result = dispatch_tool(
+            "export_tasks_csv",
+            &json!({}),
+            &db,
+            &ToolContext::default(),
+            Some(&project_id),
+        )
+        .unwrap();
+        assert!(result.get("isError").is_none());
+        let csv = result["content"][0]["text"].as_str().unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], "short_id,title,status,epic,assignee");
+        assert_eq!(lines.len(), tasks.len() + 1);
+        assert!(csv.contains(",\"Fix \"\"login\"\", then logout\","), "{csv}");
+    }
+
+    #[test]
+    fn test_export_tasks_csv_requires_known_project() {
+        let (db, _dir, _) = seeded_db();
+        let result = dispatch_tool(
+            "export_tasks_csv",
+            &json!({}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+
+        let result = dispatch_tool(
+            "export_tasks_csv",
+            &json!({"project_id": "nope"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+        assert!(result["content"][0]["text"].as_str().unwrap().contains("Project not found"));
+    }
+}
@@ -0,0 +1,295 @@
+use std::collections::HashMap;
+
+use serde_json::{json, Value};
+
+use crate::db::epic as epic_db;
+use crate::db::task as task_db;
+use crate::db::Database;
+use crate::graph::{
+    epic_nodes_and_edges, position_nodes, task_nodes_and_edges, DagLayout, NODE_HEIGHT_EPIC,
+    NODE_HEIGHT_TASK, NODE_WIDTH,
+};
+use crate::models::{EpicListFilter, ItemStatus, TaskListFilter};
+
+use super::{
+    optional_str, resolve_project_id, tool_error, tool_result, validate_project_exists, ToolContext,
+};
+
+/// The dependency graph of a project's epics, or of one epic's tasks, laid
+/// out as the TUI's graph view lays it out at the default node width.
+pub(super) fn handle_get_graph(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    let project_id = match resolve_project_id(args, default_project_id) {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    if let Err(e) = validate_project_exists(db, &project_id) {
+        return e;
+    }
+
+    let level = optional_str(args, "level").unwrap_or_else(|| "epic".to_string());
+    let mut response = json!({ "project_id": project_id, "level": level });
+    let (items, (nodes, edges), node_height) = match level.as_str() {
+        "epic" => {
+            let epics = match epic_db::list_epics(
+                db,
+                &EpicListFilter {
+                    project_id: Some(&project_id),
+                    ..Default::default()
+                },
+            ) {
+                Ok(epics) => epics,
+                Err(e) => {
+                    log::error!("get_graph error: {e:#}");
+                    return tool_error("Failed to build graph");
+                }
+            };
+            let items: Vec<(String, Option<String>, String)> = epics
+                .iter()
+                .map(|e| (e.id.clone(), e.short_id.clone(), e.title.clone()))
+                .collect();
+            (items, epic_nodes_and_edges(db, &epics), NODE_HEIGHT_EPIC)
+        }
+        "task" => {
+            let Some(epic_id) = optional_str(args, "epic_id") else {
+                return tool_error("Missing required parameter: epic_id (needed for level task)");
+            };
+            let epic_id = match epic_db::resolve_epic_id(db, &epic_id, Some(&project_id)) {
+                Ok(v) => v,
+                Err(e) => return tool_error(&e.to_string()),
+            };
+            let tasks =
+                match task_db::list_tasks(
+                    db,
+                    &TaskListFilter {
+                        epic_id: Some(&epic_id),
+                        ..Default::default()
+                    },
+                ) {
+                    Ok(tasks) => tasks,
+                    Err(e) => {
+                        log::error!("get_graph error: {e:#}");
+                        return tool_error("Failed to build graph");
+                    }
+                };
+            response["epic_id"] = json!(epic_id);
+            let items: Vec<(String, Option<String>, String)> = tasks
+                .iter()
+                .map(|t| (t.id.clone(), t.short_id.clone(), t.title.clone()))
+                .collect();
+            (items, task_nodes_and_edges(db, &tasks), NODE_HEIGHT_TASK)
+        }
+        other => return tool_error(&format!("Invalid level: {other} (expected epic or task)")),
+    };
+
+    let layout = DagLayout::new(nodes, edges);
+    let positions = position_nodes(&layout, node_height, 0, NODE_WIDTH);
+    let details: HashMap<&str, (&Option<String>, &str)> = items
+        .iter()
+        .map(|(id, short_id, title)| (id.as_str(), (short_id, title.as_str())))
+        .collect();
+
+    // Bands top to bottom, with unconnected nodes last as on screen.
+    let mut graph_nodes = Vec::new();
+    for band in layout.layers.iter().chain([&layout.orphans]) {
+        for (position, id) in band.iter().enumerate() {
+            let (short_id, title) = details[id.as_str()];
+            let status: &ItemStatus = &layout.nodes[id].status;
+            let (x, y) = positions[id];
+            graph_nodes.push(json!({
+                "id": id,
+                "short_id": short_id,
+                "title": title,
+                "status": status,
+                "layer": layout.nodes[id].layer,
+                "position": position,
+                "x": x,
+                "y": y,
+            }));
+        }
+    }
+    let graph_edges: Vec<Value> = layout
+        .edges
+        .iter()
+        .map(|e| json!({ "from": e.from, "to": e.to }))
+        .collect();
+
+    response["nodes"] = json!(graph_nodes);
+    response["edges"] = json!(graph_edges);
+    response["layer_count"] = json!(layout.layer_count());
+    match layout.topological_order() {
+        Ok(order) => response["topological_order"] = json!(order),
+        Err(e) => response["cycle_node"] = json!(e.node),
+    }
+    response["node_width"] = json!(NODE_WIDTH);
+    response["node_height"] = json!(node_height);
+    tool_result(ctx, &response)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{dispatch_tool, ToolContext};
+    use serde_json::{json, Value};
+    use tempfile::TempDir;
+
+    use crate::db::dependency as dep_db;
+    use crate::db::seed::seed_demo_data;
+    use crate::db::Database;
+    use crate::models::{AddDependencyInput, DependencyType};
+    use crate::tui::App;
+
+    fn test_db() -> (Database, TempDir) {
+        let dir = TempDir::new().unwrap();
+        let db = Database::open(&dir.path().join("test.db")).unwrap();
+        db.migrate().unwrap();
+        (db, dir)
+    }
+
+    fn parse_response(result: &Value) -> Value {
+        let text = result["content"][0]["text"].as_str().unwrap();
+        serde_json::from_str(text).unwrap()
+    }
+
+    /// Compare the tool's nodes with the layout the TUI cached.
+    fn assert_matches_tui(graph: &Value, app: &App) {
+        let cache = app.graph_cache.as_ref().unwrap();
+        let nodes = graph["nodes"].as_array().unwrap();
+        assert_eq!(nodes.len(), cache.layout.nodes.len());
+        assert_eq!(graph["layer_count"], json!(cache.layout.layers.len()));
+        for node in nodes {
+            let id = node["id"].as_str().unwrap();
+            let layer = cache.layout.nodes[id].layer;
+            assert_eq!(node["layer"], json!(layer), "layer of {id}");
+            let band = layer.map_or(&cache.layout.orphans, |l| &cache.layout.layers[l]);
+            let position = band.iter().position(|n| n == id).unwrap();
+            assert_eq!(node["position"], json!(position), "position of {id}");
+            let (x, y) = cache.node_positions[id];
+            assert_eq!((node["x"].clone(), node["y"].clone()), (json!(x), json!(y)));
+        }
+        assert_eq!(
+            graph["edges"].as_array().unwrap().len(),
+            cache.layout.edges.len()
+        );
+    }
+
+    #[test]
+    fn test_get_graph_epic_level_matches_tui_layout() {
+        let (db, _dir) = test_db();
+        let project_id = seed_demo_data(&db).unwrap().project_id;
+
+        let result = dispatch_tool(
+            "get_graph",
+            &json!({"project_id": project_id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        let graph = parse_response(&result);
+        assert_eq!(graph["level"], "epic");
+        assert!(graph["nodes"][0]["short_id"].as_str().unwrap().starts_with('E'));
+
+        let mut app = App::new(db).unwrap();
+        app.build_epic_graph();
+        assert_matches_tui(&graph, &app);
+    }
+
+    #[test]
+    fn test_get_graph_task_level_matches_tui_layout() {
+        let (db, _dir) = test_db();
+        let project_id = seed_demo_data(&db).unwrap().project_id;
+
+        let result = dispatch_tool(
+            "get_graph",
+            &json!({"project_id": project_id, "level": "task", "epic_id": "E2"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        let graph = parse_response(&result);
+        assert_eq!(graph["level"], "task");
+        assert!(!graph["edges"].as_array().unwrap().is_empty());
+
+        let mut app = App::new(db).unwrap();
+        let epic_id = graph["epic_id"].as_str().unwrap();
+        app.selected_epic_idx = app.epics.iter().position(|e| e.id == epic_id).unwrap();
+        app.refresh_data();
+        app.build_task_graph();
+        assert_matches_tui(&graph, &app);
+    }
+
+    #[test]
+    fn test_get_graph_orders_blockers_first_and_reports_cycles() {
+        let (db, _dir) = test_db();
+        let project_id = seed_demo_data(&db).unwrap().project_id;
+        let args = json!({"project_id": project_id});
+
+        let graph = parse_response(
+            &dispatch_tool("get_graph", &args, &db, &ToolContext::default(), None).unwrap(),
+        );
+        let order: Vec<&str> = graph["topological_order"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|id| id.as_str().unwrap())
+            .collect();
+        assert_eq!(order.len(), graph["nodes"].as_array().unwrap().len());
+        for edge in graph["edges"].as_array().unwrap() {
+            let at = |key: &str| order.iter().position(|id| *id == edge[key]).unwrap();
+            assert!(at("from") < at("to"), "{edge}");
+        }
+        assert!(graph.get("cycle_node").is_none());
+
+        // Close a cycle by reversing one of the edges.
+        let edge = &graph["edges"][0];
+        dep_db::add_dependency(
+            &db,
+            AddDependencyInput {
+                blocker_type: DependencyType::Epic,
+                blocker_id: edge["to"].as_str().unwrap().to_string(),
+                blocked_type: DependencyType::Epic,
+                blocked_id: edge["from"].as_str().unwrap().to_string(),
+            },
+            None,
+        )
+        .unwrap();
+        let graph = parse_response(
+            &dispatch_tool("get_graph", &args, &db, &ToolContext::default(), None).unwrap(),
+        );
+        assert!(graph.get("topological_order").is_none());
+        assert!(graph["cycle_node"].is_string());
+    }
+
+    #[test]
+    fn test_get_graph_rejects_bad_level_and_missing_epic() {
+        let (db, _dir) = test_db();
+        let project_id = seed_demo_data(&db).unwrap().project_id;
+
+        let result = dispatch_tool(
+            "get_graph",
+            &json!({"project_id": project_id, "level": "prd"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+        let text = result["content"][0]["text"].as_str().unwrap();
+        assert!(text.contains("Invalid level: prd"));
+
+        let result = dispatch_tool(
+            "get_graph",
+            &json!({"project_id": project_id, "level": "task"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+    }
+}
@@ -0,0 +1,69 @@
+use serde_json::Value;
+
+use crate::db::maintenance as maintenance_db;
+use crate::db::Database;
+
+use super::{tool_error, tool_result, ToolContext};
+
+pub(super) fn handle_maintain_db(db: &Database, ctx: &ToolContext) -> Value {
+    match maintenance_db::maintain(db) {
+        Ok(report) => tool_result(ctx, &report),
+        Err(e) => {
+            log::error!("maintain_db error: {e:#}");
+            if format!("{e:#}").contains("locked") {
+                tool_error("Database is busy; retry maintain_db once other writers finish")
+            } else {
+                tool_error("Failed to maintain database")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{dispatch_tool, ToolContext};
+    use crate::db::Database;
+    use serde_json::{json, Value};
+    use tempfile::TempDir;
+
+    fn test_db() -> (Database, TempDir) {
+        let dir = TempDir::new().unwrap();
+        let db = Database::open(&dir.path().join("test.db")).unwrap();
+        db.migrate().unwrap();
+        (db, dir)
+    }
+
+    fn parse_response(result: &Value) -> Value {
+        let text = result["content"][0]["text"].as_str().unwrap();
+        serde_json::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn test_maintain_db_reports_sizes() {
+        let (db, _dir) = test_db();
+        dispatch_tool("seed_demo_data", &json!({}), &db, &ToolContext::default(), None).unwrap();
+
+        let result = dispatch_tool(
+            "maintain_db",
+            &json!({}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert!(result.get("isError").is_none());
+        let data = parse_response(&result);
+        assert!(data["db_bytes_after"].as_u64().unwrap() > 0);
+        assert_eq!(data["wal_bytes_after"], 0);
+
+        let projects = dispatch_tool(
+            "list_projects",
+            &json!({}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(parse_response(&projects).as_array().unwrap().len(), 1);
+    }
+}
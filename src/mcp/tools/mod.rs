@@ -1,16 +1,32 @@
+mod checklist;
 mod dependency;
 mod epic;
+mod export;
+mod graph;
+mod maintenance;
 mod prd;
 mod project;
+mod search;
+mod seed;
 mod status;
 mod task;
+mod template;
 
 use serde::Serialize;
 use serde_json::{json, Value};
 
 use crate::db::Database;
+use crate::models::{DateRange, ItemStatus, StatusFilter};
+use crate::settings::{TextLimits, DEFAULT_MAX_LIST_ITEMS};
 
-fn tool(name: &str, description: &str, properties: Value, required: &[&str]) -> Value {
+/// Builds a tool definition. [`MUTATING_TOOLS`] also accept an `actor`.
+fn tool(name: &str, description: &str, mut properties: Value, required: &[&str]) -> Value {
+    if MUTATING_TOOLS.contains(&name) {
+        properties["actor"] = json!({
+            "type": "string",
+            "description": "Who is making the change (agent or person), recorded as updated_by on the tasks and epics it touches"
+        });
+    }
     json!({
         "name": name,
         "description": description,
@@ -38,10 +54,70 @@ fn dependency_properties() -> Value {
             "enum": ["epic", "task"],
             "description": "Type of the blocked item"
         },
-        "blocked_id": { "type": "string", "description": "ID of the blocked item (ULID or short ID like E1 / E1-T3)" }
+        "blocked_id": { "type": "string", "description": "ID of the blocked item (ULID or short ID like E1 / E1-T3)" },
+        "project_id": { "type": "string", "description": "Project to resolve short IDs in (optional, defaults to .blueprint/setting.json when configured)" }
     })
 }
 
+/// Housekeeping tools that can be hidden with the `admin_tools` setting.
+pub const ADMIN_TOOLS: &[&str] = &["maintain_db", "seed_demo_data"];
+
+/// Tools that write to the database, refused when the server is read-only.
+pub const MUTATING_TOOLS: &[&str] = &[
+    "create_project",
+    "update_project",
+    "rename_project",
+    "delete_project",
+    "reset_project_tasks",
+    "create_epic",
+    "update_epic",
+    "rename_epic",
+    "delete_epic",
+    "archive_epic",
+    "unarchive_epic",
+    "reorder_epics",
+    "create_task",
+    "update_task",
+    "rename_task",
+    "delete_task",
+    "archive_task",
+    "unarchive_task",
+    "claim_task",
+    "release_task",
+    "reopen_task",
+    "complete_chain",
+    "add_checklist_item",
+    "toggle_checklist_item",
+    "promote_checklist_item",
+    "create_template",
+    "apply_template",
+    "add_dependency",
+    "remove_dependency",
+    "clear_dependencies",
+    "feed_prd",
+    "diff_prd",
+    "maintain_db",
+    "seed_demo_data",
+];
+
+/// Whether this call of `name` writes. A dry-run `feed_prd` and a `diff_prd`
+/// that does not `apply` only read, so read-only mode lets them through.
+pub fn is_mutating(name: &str, args: &Value) -> bool {
+    match name {
+        "feed_prd" => !optional_bool(args, "dry_run"),
+        "diff_prd" => optional_bool(args, "apply"),
+        _ => MUTATING_TOOLS.contains(&name),
+    }
+}
+
+/// Tools offered to clients, leaving out [`ADMIN_TOOLS`] unless enabled.
+pub fn enabled_tool_definitions(admin_tools: bool) -> Vec<Value> {
+    tool_definitions()
+        .into_iter()
+        .filter(|t| admin_tools || !ADMIN_TOOLS.iter().any(|name| t["name"] == *name))
+        .collect()
+}
+
 pub fn tool_definitions() -> Vec<Value> {
     vec![
         // Project tools
@@ -60,17 +136,23 @@ pub fn tool_definitions() -> Vec<Value> {
             json!({
                 "status": {
                     "type": "string",
-                    "enum": ["active", "archived"],
+                    "enum": ["active", "on_hold", "archived"],
                     "description": "Filter by status"
+                },
+                "sort": {
+                    "type": "string",
+                    "enum": ["name", "created", "updated", "activity"],
+                    "description": "Order: name (A-Z), created (newest first, default), updated (project last edited first), activity (latest change to the project, its epics or tasks first)"
                 }
             }),
             &[],
         ),
         tool(
             "get_project",
-            "Get a project by ID",
+            "Get a project by ID along with its epics. Set include_summary for overall task/epic counts and progress.",
             json!({
-                "id": { "type": "string", "description": "Project ID" }
+                "id": { "type": "string", "description": "Project ID" },
+                "include_summary": { "type": "boolean", "description": "Include status counts and overall progress (default false)" }
             }),
             &["id"],
         ),
@@ -83,20 +165,42 @@ pub fn tool_definitions() -> Vec<Value> {
                 "description": { "type": "string", "description": "New description" },
                 "status": {
                     "type": "string",
-                    "enum": ["active", "archived"],
+                    "enum": ["active", "on_hold", "archived"],
                     "description": "New status"
-                }
+                },
+                "short_id_prefix": { "type": "string", "description": "Prefix for new epic/task short IDs, e.g. PROJ gives PROJ-E1-T2 (1-10 letters/digits; empty string clears). Existing short IDs are unchanged." }
             }),
             &["id"],
         ),
+        tool(
+            "rename_project",
+            "Rename a project. Only the name changes; use update_project for other fields.",
+            json!({
+                "id": { "type": "string", "description": "Project ID" },
+                "name": { "type": "string", "description": "New name" }
+            }),
+            &["id", "name"],
+        ),
         tool(
             "delete_project",
-            "Delete a project",
+            "Delete a project together with all of its epics, tasks and PRDs. Refused unless confirm is true or name repeats the project's name; the refusal reports what would be deleted.",
             json!({
-                "id": { "type": "string", "description": "Project ID" }
+                "id": { "type": "string", "description": "Project ID" },
+                "confirm": { "type": "boolean", "description": "Set to true to confirm the cascading delete" },
+                "name": { "type": "string", "description": "The project's exact name, as an alternative to confirm" }
             }),
             &["id"],
         ),
+        tool(
+            "reset_project_tasks",
+            "Move every task in a project back to todo in one step, e.g. to re-run a recurring process. Epics, tasks and dependencies are kept. Refused unless confirm is true; the refusal reports how many tasks would be reset. Returns tasks_reset and epics_reset. If project_id is omitted, the default from .blueprint/setting.json is used.",
+            json!({
+                "project_id": { "type": "string", "description": "Project ID (optional if .blueprint/setting.json is configured)" },
+                "include_epics": { "type": "boolean", "description": "Reset every epic to todo as well (default false)" },
+                "confirm": { "type": "boolean", "description": "Set to true to confirm the reset" }
+            }),
+            &[],
+        ),
         // Epic tools
         tool(
             "create_epic",
@@ -104,20 +208,29 @@ pub fn tool_definitions() -> Vec<Value> {
             json!({
                 "project_id": { "type": "string", "description": "Parent project ID (optional if .blueprint/setting.json is configured)" },
                 "title": { "type": "string", "description": "Epic title" },
-                "description": { "type": "string", "description": "Epic description" }
+                "description": { "type": "string", "description": "Epic description" },
+                "prd_id": { "type": "string", "description": "PRD this epic was broken down from (optional, must belong to the same project)" }
             }),
             &["title", "description"],
         ),
         tool(
             "list_epics",
-            "List epics, optionally filtered by project or status. If project_id is omitted, the default from .blueprint/setting.json is used when configured.",
+            "List epics, optionally filtered by project, status or created/updated date range. Long results are cut at max_items, with a final {truncated: true, returned, total, next_offset} entry; pass offset to page. If project_id is omitted, the default from .blueprint/setting.json is used when configured.",
             json!({
                 "project_id": { "type": "string", "description": "Filter by project ID (optional, defaults to .blueprint/setting.json when configured)" },
                 "status": {
                     "type": "string",
                     "enum": ["todo", "in_progress", "done"],
                     "description": "Filter by status"
-                }
+                },
+                "statuses": { "type": "array", "items": { "type": "string", "enum": ["todo", "in_progress", "done"] }, "description": "Only items in any of these statuses (use instead of status)" },
+                "exclude_statuses": { "type": "array", "items": { "type": "string", "enum": ["todo", "in_progress", "done"] }, "description": "Leave out items in any of these statuses" },
+                "created_between": { "type": "array", "items": { "type": "string" }, "description": "Only items created in this inclusive [start, end] window (ISO 8601 dates or datetimes, UTC; a bare date covers the whole day)" },
+                "updated_between": { "type": "array", "items": { "type": "string" }, "description": "Only items last updated in this inclusive [start, end] window (same format as created_between)" },
+                "include_counts": { "type": "boolean", "description": "Include per-epic task counts by status as task_counts (default false)" },
+                "include_archived": { "type": "boolean", "description": "Also list archived epics (default false)" },
+                "offset": { "type": "integer", "description": "Matching epics to skip, for paging (default 0)" },
+                "max_items": { "type": "integer", "description": "Most epics to return (default: the server's max_list_items, 500 unless configured)" }
             }),
             &[],
         ),
@@ -125,7 +238,9 @@ pub fn tool_definitions() -> Vec<Value> {
             "get_epic",
             "Get an epic by ID",
             json!({
-                "id": { "type": "string", "description": "Epic ID (ULID or short ID like E1)" }
+                "id": { "type": "string", "description": "Epic ID (ULID or short ID like E1)" },
+                "project_id": { "type": "string", "description": "Project to resolve a short ID in (optional, defaults to .blueprint/setting.json when configured)" },
+                "neighbors": { "type": "boolean", "description": "Add neighbors: {blockers, blocks} with the direct dependencies as {short_id, title, status} (default false)" }
             }),
             &["id"],
         ),
@@ -134,6 +249,7 @@ pub fn tool_definitions() -> Vec<Value> {
             "Update an epic",
             json!({
                 "id": { "type": "string", "description": "Epic ID (ULID or short ID like E1)" },
+                "project_id": { "type": "string", "description": "Project to resolve a short ID in (optional, defaults to .blueprint/setting.json when configured)" },
                 "title": { "type": "string", "description": "New title" },
                 "description": { "type": "string", "description": "New description" },
                 "status": {
@@ -144,29 +260,78 @@ pub fn tool_definitions() -> Vec<Value> {
             }),
             &["id"],
         ),
+        tool(
+            "rename_epic",
+            "Retitle an epic. Only the title changes; use update_epic for other fields.",
+            json!({
+                "id": { "type": "string", "description": "Epic ID (ULID or short ID like E1)" },
+                "project_id": { "type": "string", "description": "Project to resolve a short ID in (optional, defaults to .blueprint/setting.json when configured)" },
+                "title": { "type": "string", "description": "New title" }
+            }),
+            &["id", "title"],
+        ),
         tool(
             "delete_epic",
             "Delete an epic",
             json!({
-                "id": { "type": "string", "description": "Epic ID (ULID or short ID like E1)" }
+                "id": { "type": "string", "description": "Epic ID (ULID or short ID like E1)" },
+                "project_id": { "type": "string", "description": "Project to resolve a short ID in (optional, defaults to .blueprint/setting.json when configured)" }
+            }),
+            &["id"],
+        ),
+        tool(
+            "archive_epic",
+            "Archive an epic: it keeps its status, tasks and dependencies but is hidden from list_epics and the TUI unless include_archived is set. Its tasks are not archived.",
+            json!({
+                "id": { "type": "string", "description": "Epic ID (ULID or short ID like E1)" },
+                "project_id": { "type": "string", "description": "Project to resolve a short ID in (optional, defaults to .blueprint/setting.json when configured)" }
+            }),
+            &["id"],
+        ),
+        tool(
+            "unarchive_epic",
+            "Return an archived epic to the default listings",
+            json!({
+                "id": { "type": "string", "description": "Epic ID (ULID or short ID like E1)" },
+                "project_id": { "type": "string", "description": "Project to resolve a short ID in (optional, defaults to .blueprint/setting.json when configured)" }
             }),
             &["id"],
         ),
+        tool(
+            "reorder_epics",
+            "Change the order epics are listed in (list_epics, the TUI and the graph's unconnected row). The given epics move to the front in the given order; the others keep their relative order after them. If project_id is omitted, the default from .blueprint/setting.json is used.",
+            json!({
+                "project_id": { "type": "string", "description": "Project ID (optional if .blueprint/setting.json is configured)" },
+                "epic_ids": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Epic IDs (ULID or short ID like E1) in their new order"
+                }
+            }),
+            &["epic_ids"],
+        ),
         // Task tools
         tool(
             "create_task",
-            "Create a new task within an epic",
+            "Create a new task within an epic, optionally blocked by existing tasks (depends_on). The task and its dependencies are created together: if a blocker does not exist, nothing is created.",
             json!({
                 "epic_id": { "type": "string", "description": "Parent epic ID (ULID or short ID like E1)" },
+                "project_id": { "type": "string", "description": "Project to resolve a short ID in (optional, defaults to .blueprint/setting.json when configured)" },
                 "title": { "type": "string", "description": "Task title" },
                 "description": { "type": "string", "description": "Task description" },
-                "session_id": { "type": "string", "description": "Optional session ID to track which session is working on this task" }
+                "session_id": { "type": "string", "description": "Optional session ID to track which session is working on this task" },
+                "assignee": { "type": "string", "description": "Optional person or agent responsible for this task" },
+                "depends_on": {
+                    "type": "array",
+                    "items": { "type": "string" },
+                    "description": "Optional task IDs (ULID or short ID like E1-T2) that block the new task"
+                }
             }),
             &["epic_id", "title", "description"],
         ),
         tool(
             "list_tasks",
-            "List tasks, optionally filtered by epic or status. Returns summaries only — use get_task for full details. If project_id is omitted, the default from .blueprint/setting.json is used when configured.",
+            "List tasks, optionally filtered by epic, status, assignee, blocked or ready state, or created/updated date range. Returns summaries only — use get_task for full details. Long results are cut at max_items, with a final {truncated: true, returned, total, next_offset} entry in tasks; pass offset to page. If project_id is omitted, the default from .blueprint/setting.json is used when configured.",
             json!({
                 "epic_id": { "type": "string", "description": "Filter by epic ID (ULID or short ID like E1)" },
                 "project_id": { "type": "string", "description": "Filter by project ID (optional, defaults to .blueprint/setting.json when configured)" },
@@ -174,7 +339,19 @@ pub fn tool_definitions() -> Vec<Value> {
                     "type": "string",
                     "enum": ["todo", "in_progress", "done"],
                     "description": "Filter by status"
-                }
+                },
+                "statuses": { "type": "array", "items": { "type": "string", "enum": ["todo", "in_progress", "done"] }, "description": "Only tasks in any of these statuses (use instead of status)" },
+                "exclude_statuses": { "type": "array", "items": { "type": "string", "enum": ["todo", "in_progress", "done"] }, "description": "Leave out tasks in any of these statuses" },
+                "assignee": { "type": "string", "description": "Only tasks assigned to this person or agent" },
+                "unassigned": { "type": "boolean", "description": "Only tasks with no assignee (default false)" },
+                "created_between": { "type": "array", "items": { "type": "string" }, "description": "Only items created in this inclusive [start, end] window (ISO 8601 dates or datetimes, UTC; a bare date covers the whole day)" },
+                "updated_between": { "type": "array", "items": { "type": "string" }, "description": "Only items last updated in this inclusive [start, end] window (same format as created_between)" },
+                "include_blocked": { "type": "boolean", "description": "Add a blocked flag to each task: true if any upstream blocker, at any depth, is not done (default false)" },
+                "blocked_only": { "type": "boolean", "description": "Only unfinished tasks with an unfinished blocker at any depth (default false; not with ready_only)" },
+                "ready_only": { "type": "boolean", "description": "Only todo tasks with every blocker, at any depth, done: the ones that can be started now (default false; not with blocked_only)" },
+                "include_archived": { "type": "boolean", "description": "Also list archived tasks, marked archived: true (default false)" },
+                "offset": { "type": "integer", "description": "Matching tasks to skip, for paging (default 0)" },
+                "max_items": { "type": "integer", "description": "Most tasks to return (default: the server's max_list_items, 500 unless configured)" }
             }),
             &[],
         ),
@@ -182,7 +359,11 @@ pub fn tool_definitions() -> Vec<Value> {
             "get_task",
             "Get a task by ID",
             json!({
-                "id": { "type": "string", "description": "Task ID (ULID or short ID like E1-T3)" }
+                "id": { "type": "string", "description": "Task ID (ULID or short ID like E1-T3)" },
+                "project_id": { "type": "string", "description": "Project to resolve a short ID in (optional, defaults to .blueprint/setting.json when configured)" },
+                "include_blocked": { "type": "boolean", "description": "Add a blocked flag: true if any upstream blocker, at any depth, is not done (default false)" },
+                "expand": { "type": "boolean", "description": "Return blockers and blocks as items with type, id, short_id, title and status instead of dependency rows (default false)" },
+                "neighbors": { "type": "boolean", "description": "Add neighbors: {blockers, blocks} with the direct dependencies as {short_id, title, status} (default false)" }
             }),
             &["id"],
         ),
@@ -191,6 +372,7 @@ pub fn tool_definitions() -> Vec<Value> {
             "Update a task",
             json!({
                 "id": { "type": "string", "description": "Task ID (ULID or short ID like E1-T3)" },
+                "project_id": { "type": "string", "description": "Project to resolve a short ID in (optional, defaults to .blueprint/setting.json when configured)" },
                 "title": { "type": "string", "description": "New title" },
                 "description": { "type": "string", "description": "New description" },
                 "status": {
@@ -198,18 +380,177 @@ pub fn tool_definitions() -> Vec<Value> {
                     "enum": ["todo", "in_progress", "done"],
                     "description": "New status"
                 },
-                "session_id": { "type": "string", "description": "Session ID to track which session is working on this task. Pass empty string to clear." }
+                "session_id": { "type": "string", "description": "Session ID to track which session is working on this task. Pass empty string to clear." },
+                "assignee": { "type": "string", "description": "Person or agent responsible for this task. Pass empty string to clear." }
             }),
             &["id"],
         ),
+        tool(
+            "rename_task",
+            "Retitle a task. Only the title changes; use update_task for other fields.",
+            json!({
+                "id": { "type": "string", "description": "Task ID (ULID or short ID like E1-T3)" },
+                "project_id": { "type": "string", "description": "Project to resolve a short ID in (optional, defaults to .blueprint/setting.json when configured)" },
+                "title": { "type": "string", "description": "New title" }
+            }),
+            &["id", "title"],
+        ),
         tool(
             "delete_task",
             "Delete a task",
             json!({
-                "id": { "type": "string", "description": "Task ID (ULID or short ID like E1-T3)" }
+                "id": { "type": "string", "description": "Task ID (ULID or short ID like E1-T3)" },
+                "project_id": { "type": "string", "description": "Project to resolve a short ID in (optional, defaults to .blueprint/setting.json when configured)" }
             }),
             &["id"],
         ),
+        tool(
+            "archive_task",
+            "Archive a task: it keeps its status and dependencies but is hidden from list_tasks and the TUI unless include_archived is set",
+            json!({
+                "id": { "type": "string", "description": "Task ID (ULID or short ID like E1-T3)" },
+                "project_id": { "type": "string", "description": "Project to resolve a short ID in (optional, defaults to .blueprint/setting.json when configured)" }
+            }),
+            &["id"],
+        ),
+        tool(
+            "unarchive_task",
+            "Return an archived task to the default listings",
+            json!({
+                "id": { "type": "string", "description": "Task ID (ULID or short ID like E1-T3)" },
+                "project_id": { "type": "string", "description": "Project to resolve a short ID in (optional, defaults to .blueprint/setting.json when configured)" }
+            }),
+            &["id"],
+        ),
+        tool(
+            "claim_task",
+            "Mark a task as being worked on by a session, so other agents can see it is taken. Claiming a task the session already holds succeeds; if another session holds it, the call fails with a CONFLICT error naming that session.",
+            json!({
+                "id": { "type": "string", "description": "Task ID (ULID or short ID like E1-T3)" },
+                "project_id": { "type": "string", "description": "Project to resolve a short ID in (optional, defaults to .blueprint/setting.json when configured)" },
+                "session_id": { "type": "string", "description": "Session taking the task" }
+            }),
+            &["id", "session_id"],
+        ),
+        tool(
+            "release_task",
+            "Clear a task's session claim. With session_id, only that session's claim is released and a claim by another session fails with a CONFLICT error naming it; without it, any claim is cleared.",
+            json!({
+                "id": { "type": "string", "description": "Task ID (ULID or short ID like E1-T3)" },
+                "project_id": { "type": "string", "description": "Project to resolve a short ID in (optional, defaults to .blueprint/setting.json when configured)" },
+                "session_id": { "type": "string", "description": "Session giving up the task (optional)" }
+            }),
+            &["id"],
+        ),
+        tool(
+            "reopen_task",
+            "Move a done task back to todo or in_progress, recording the reason in its status history (shown by get_timeline). A task that is not done is left unchanged and reported as an error.",
+            json!({
+                "id": { "type": "string", "description": "Task ID (ULID or short ID like E1-T3)" },
+                "project_id": { "type": "string", "description": "Project to resolve a short ID in (optional, defaults to .blueprint/setting.json when configured)" },
+                "reason": { "type": "string", "description": "Why the task is being reopened (optional)" },
+                "status": { "type": "string", "enum": ["todo", "in_progress"], "description": "Status to reopen to (default todo)" }
+            }),
+            &["id"],
+        ),
+        tool(
+            "complete_chain",
+            "Mark a task and all of its transitive task blockers done in one transaction, blockers before the tasks they block, so no done task is ever shown blocked by an unfinished one. Tasks already done are skipped and epic blockers are left as they are. Returns the tasks that changed, in the order they were marked.",
+            json!({
+                "id": { "type": "string", "description": "Task ID (ULID or short ID like E1-T3)" },
+                "project_id": { "type": "string", "description": "Project to resolve a short ID in (optional, defaults to .blueprint/setting.json when configured)" }
+            }),
+            &["id"],
+        ),
+        // Checklist tools
+        tool(
+            "add_checklist_item",
+            "Append an unchecked item to a task's checklist. Checklists are lightweight steps inside a task, such as the checkboxes of a PRD, short of separate tasks.",
+            json!({
+                "task_id": { "type": "string", "description": "Task ID (ULID or short ID like E1-T3)" },
+                "project_id": { "type": "string", "description": "Project to resolve a short ID in (optional, defaults to .blueprint/setting.json when configured)" },
+                "text": { "type": "string", "description": "Item text" }
+            }),
+            &["task_id", "text"],
+        ),
+        tool(
+            "toggle_checklist_item",
+            "Check or uncheck a checklist item. Without done, the item flips.",
+            json!({
+                "id": { "type": "string", "description": "Checklist item ID, as returned by add_checklist_item or list_checklist_items" },
+                "done": { "type": "boolean", "description": "New state (optional, flips the item when omitted)" }
+            }),
+            &["id"],
+        ),
+        tool(
+            "promote_checklist_item",
+            "Turn a checklist item that grew too big into a task of its own, in the same epic as the task whose checklist holds it, with the item's text as title. The item is kept by default; pass item done to check it off or item delete to remove it. Returns the new task, the source task's ID and the item as left (null once deleted).",
+            json!({
+                "id": { "type": "string", "description": "Checklist item ID, as returned by add_checklist_item or list_checklist_items" },
+                "description": { "type": "string", "description": "Description of the new task (optional, defaults to a note naming the source task)" },
+                "item": {
+                    "type": "string",
+                    "enum": ["keep", "done", "delete"],
+                    "description": "What to do with the checklist item afterwards (default keep)"
+                }
+            }),
+            &["id"],
+        ),
+        tool(
+            "list_checklist_items",
+            "List a task's checklist in order, with the number of items done and in total",
+            json!({
+                "task_id": { "type": "string", "description": "Task ID (ULID or short ID like E1-T3)" },
+                "project_id": { "type": "string", "description": "Project to resolve a short ID in (optional, defaults to .blueprint/setting.json when configured)" }
+            }),
+            &["task_id"],
+        ),
+        tool(
+            "get_next_task",
+            "Pick the single todo task to work on next. Only ready tasks qualify (no unfinished blocker on the task or its epic); tasks in an in-progress epic come first, then the oldest. Returns task: null with a reason when nothing is actionable. If project_id is omitted, the default from .blueprint/setting.json is used.",
+            json!({
+                "project_id": { "type": "string", "description": "Project ID (optional if .blueprint/setting.json is configured)" }
+            }),
+            &[],
+        ),
+        // Template tools
+        tool(
+            "create_template",
+            "Save a reusable set of tasks (e.g. implement, write tests, update docs) under a name, for apply_template to add to any epic of the project. A task may depend on tasks listed before it, by their position in the list (0 first). If project_id is omitted, the default from .blueprint/setting.json is used.",
+            json!({
+                "project_id": { "type": "string", "description": "Project ID (optional if .blueprint/setting.json is configured)" },
+                "name": { "type": "string", "description": "Template name, unique within the project" },
+                "description": { "type": "string", "description": "What the template is for (optional)" },
+                "tasks": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "properties": {
+                            "title": { "type": "string", "description": "Task title" },
+                            "description": { "type": "string", "description": "Task description (optional)" },
+                            "depends_on": {
+                                "type": "array",
+                                "items": { "type": "integer", "minimum": 0 },
+                                "description": "Positions of earlier tasks in this list that block this one"
+                            }
+                        },
+                        "required": ["title"]
+                    },
+                    "description": "Tasks the template creates, in order"
+                }
+            }),
+            &["name", "tasks"],
+        ),
+        tool(
+            "apply_template",
+            "Create a template's tasks at the end of an epic, with the dependencies among them, in one step. The template is looked up by ID or name in the epic's project. Returns the created tasks in template order.",
+            json!({
+                "epic_id": { "type": "string", "description": "Epic ID (ULID or short ID like E1)" },
+                "template": { "type": "string", "description": "Template ID or name" },
+                "project_id": { "type": "string", "description": "Project to resolve a short ID in (optional, defaults to .blueprint/setting.json when configured)" }
+            }),
+            &["epic_id", "template"],
+        ),
         // Dependency tools
         tool(
             "add_dependency",
@@ -223,26 +564,174 @@ pub fn tool_definitions() -> Vec<Value> {
             dependency_properties(),
             &DEPENDENCY_REQUIRED,
         ),
+        tool(
+            "clear_dependencies",
+            "Remove all dependencies of an epic or task in one step. Returns the number removed.",
+            json!({
+                "type": {
+                    "type": "string",
+                    "enum": ["epic", "task"],
+                    "description": "Type of the item"
+                },
+                "id": { "type": "string", "description": "ID of the item (ULID or short ID like E1 / E1-T3)" },
+                "project_id": { "type": "string", "description": "Project to resolve a short ID in (optional, defaults to .blueprint/setting.json when configured)" },
+                "direction": {
+                    "type": "string",
+                    "enum": ["incoming", "outgoing", "both"],
+                    "description": "incoming = edges blocking this item, outgoing = edges where it blocks others (default both)"
+                }
+            }),
+            &["type", "id"],
+        ),
+        tool(
+            "get_dependencies",
+            "Get the blockers of an epic or task (\"blockers\") and the items it blocks (\"blocks\"). Set direction to list only one side, and transitive to follow blockers of blockers (or blocked items of blocked items) at any depth. limit and offset page each list, ordered by dependency ID. Set expand to get each as {type, id, short_id, title, status} of the far end rather than a dependency row of IDs.",
+            json!({
+                "type": {
+                    "type": "string",
+                    "enum": ["epic", "task"],
+                    "description": "Type of the item"
+                },
+                "id": { "type": "string", "description": "ID of the item (ULID or short ID like E1 / E1-T3)" },
+                "project_id": { "type": "string", "description": "Project to resolve a short ID in (optional, defaults to .blueprint/setting.json when configured)" },
+                "expand": { "type": "boolean", "description": "Resolve each blocker/blocked item to its short_id, title and status (default false)" },
+                "direction": {
+                    "type": "string",
+                    "enum": ["incoming", "outgoing", "both"],
+                    "description": "incoming lists only blockers, outgoing only blocked items (default both)"
+                },
+                "transitive": { "type": "boolean", "description": "Include indirect dependencies at any depth (default false)" },
+                "limit": { "type": "integer", "description": "Most dependencies per list, 1-1000 (default: all)" },
+                "offset": { "type": "integer", "description": "Dependencies to skip per list before limit applies (default 0)" }
+            }),
+            &["type", "id"],
+        ),
+        tool(
+            "get_graph",
+            "Get the dependency graph of a project's epics (level epic) or of one epic's tasks (level task) as laid out by the TUI graph view: nodes in layer order with {id, short_id, title, status, layer, position, x, y} and edges as {from, to}. layer is null for nodes with no dependencies, which are placed after the last layer. x and y are the node's top-left cell at the default node width. topological_order lists the node IDs so that every blocker comes before the items it blocks; when the dependencies form a cycle it is left out and cycle_node names a node on the cycle.",
+            json!({
+                "project_id": { "type": "string", "description": "Project ID (optional, defaults to .blueprint/setting.json when configured)" },
+                "level": {
+                    "type": "string",
+                    "enum": ["epic", "task"],
+                    "description": "Graph of epics or of one epic's tasks (default epic)"
+                },
+                "epic_id": { "type": "string", "description": "Epic whose tasks to lay out (ULID or short ID like E1), required for level task" }
+            }),
+            &[],
+        ),
         // Status tool
         tool(
             "get_status",
-            "Get project status overview with progress summaries. If project_id is omitted, the default from .blueprint/setting.json is used when configured.",
+            "Get project status overview with progress summaries. Set group_by to \"epic\" to add a per-epic breakdown (task counts, progress, blocked tasks) under \"epics\". Set stale_days to list in-progress tasks not updated for that many days under \"stale_tasks\". \"blocked_count\" and \"blocked_items\" cover items with unfinished blockers; set include_blocked_details to add each one's short_id and status and its unfinished blockers as {type, id, short_id, title, status} under \"blockers\". \"forecast\" estimates when the remaining tasks will be done from the tasks completed over the last 14 days, with a caveat when that history is sparse; archived tasks are left out. If project_id is omitted, the default from .blueprint/setting.json is used when configured.",
             json!({
-                "project_id": { "type": "string", "description": "Filter by project ID (optional, defaults to .blueprint/setting.json when configured)" }
+                "project_id": { "type": "string", "description": "Filter by project ID (optional, defaults to .blueprint/setting.json when configured)" },
+                "group_by": { "type": "string", "enum": ["project", "epic"], "description": "Aggregation level (default project)" },
+                "stale_days": { "type": "integer", "minimum": 1, "maximum": 3650, "description": "Report in-progress tasks whose last update is older than this many days" },
+                "include_blocked_details": { "type": "boolean", "description": "Expand each blocked item's blockers, for listing them in a summary (default false)" }
             }),
             &[],
         ),
+        tool(
+            "get_burndown",
+            "Daily task counts for a project, replayed from the status history: for each day, total tasks created so far and how many of them were not yet done. Archived tasks are left out. If project_id is omitted, the default from .blueprint/setting.json is used.",
+            json!({
+                "project_id": { "type": "string", "description": "Project ID (optional if .blueprint/setting.json is configured)" },
+                "from": { "type": "string", "description": "First day, YYYY-MM-DD (default: 13 days before to)" },
+                "to": { "type": "string", "description": "Last day, YYYY-MM-DD (default: today, UTC)" }
+            }),
+            &[],
+        ),
+        tool(
+            "get_timeline",
+            "Recent activity in a project, oldest first: when each epic and task was created and every status change. Each event has timestamp, entity_type, entity_id, short_id, title and verb (\"created\", \"moved to <status>\" or \"completed\"). If project_id is omitted, the default from .blueprint/setting.json is used.",
+            json!({
+                "project_id": { "type": "string", "description": "Project ID (optional if .blueprint/setting.json is configured)" },
+                "limit": { "type": "integer", "description": "Most recent events to return, 1-1000 (default: 50)" }
+            }),
+            &[],
+        ),
+        // Export tool
+        tool(
+            "export_tasks_csv",
+            "Export a project's tasks as CSV for spreadsheets. The text is the CSV itself, one row per task with the columns short_id,title,status,epic,assignee (epic is the parent epic's title); fields containing commas, quotes or line breaks are quoted. If project_id is omitted, the default from .blueprint/setting.json is used.",
+            json!({
+                "project_id": { "type": "string", "description": "Project ID (optional if .blueprint/setting.json is configured)" }
+            }),
+            &[],
+        ),
+        // Search tool
+        tool(
+            "search",
+            "Search epics and tasks. Whitespace-separated terms are ANDed together; use field:value to scope a term to title, description, status (todo|in_progress|done) or type (epic|task). Other terms match title or description. If project_id is omitted, the default from .blueprint/setting.json is used when configured.",
+            json!({
+                "query": { "type": "string", "description": "Search query, e.g. `title:auth status:todo backend`" },
+                "project_id": { "type": "string", "description": "Filter by project ID (optional, defaults to .blueprint/setting.json when configured)" },
+                "with_snippets": { "type": "boolean", "description": "Add a snippet to each result whose description matched: an excerpt around the first matching term, with the match wrapped in ** (default false)" }
+            }),
+            &["query"],
+        ),
+        // Maintenance tool
+        tool(
+            "maintain_db",
+            "Checkpoint the WAL and VACUUM the database to reclaim space. Safe while the TUI is open; returns file sizes before and after.",
+            json!({}),
+            &[],
+        ),
+        // Seed tool
+        tool(
+            "seed_demo_data",
+            "Create a sample project with epics, tasks in mixed statuses and dependencies for exploring Blueprint. Refuses to run if the demo project already exists.",
+            json!({}),
+            &[],
+        ),
         // PRD tool
         tool(
             "feed_prd",
-            "Feed a PRD document to break down into epics and tasks. If project_id is omitted, the default from .blueprint/setting.json is used.",
+            "Feed a PRD document to break down into epics and tasks. The response includes proposed_epics, an epic/task tree outlined from the markdown headings and lists. Set dry_run to get that outline without storing anything. If project_id is omitted, the default from .blueprint/setting.json is used.",
             json!({
                 "project_id": { "type": "string", "description": "Target project ID (optional if .blueprint/setting.json is configured)" },
                 "title": { "type": "string", "description": "PRD title" },
-                "content": { "type": "string", "description": "PRD content as text or markdown" }
+                "content": { "type": "string", "description": "PRD content as text or markdown" },
+                "dry_run": { "type": "boolean", "description": "Only return the proposed epic/task tree; store nothing (default false)" }
             }),
             &["title", "content"],
         ),
+        tool(
+            "list_prds",
+            "List PRDs stored for a project, newest first. Content is omitted unless include_content is set; use get_prd to read one document. If project_id is omitted, the default from .blueprint/setting.json is used.",
+            json!({
+                "project_id": { "type": "string", "description": "Project ID (optional if .blueprint/setting.json is configured)" },
+                "include_content": { "type": "boolean", "description": "Include the full PRD content (default false)" }
+            }),
+            &[],
+        ),
+        tool(
+            "get_prd",
+            "Get a stored PRD by ID, including its full content",
+            json!({
+                "id": { "type": "string", "description": "PRD ID" }
+            }),
+            &["id"],
+        ),
+        tool(
+            "get_prd_breakdown",
+            "Get the epics (with their tasks) that were created from a PRD via create_epic's prd_id",
+            json!({
+                "id": { "type": "string", "description": "PRD ID" }
+            }),
+            &["id"],
+        ),
+        tool(
+            "diff_prd",
+            "Compare an updated version of a PRD with its breakdown. The new content is outlined like feed_prd and matched by title against the epics linked to the PRD and their tasks, reporting what would be added, unchanged or removed. Nothing changes unless apply is set, which deletes removed epics/tasks, creates added ones linked to the PRD and stores the new content.",
+            json!({
+                "id": { "type": "string", "description": "PRD ID" },
+                "content": { "type": "string", "description": "Updated PRD content as text or markdown" },
+                "apply": { "type": "boolean", "description": "Perform the additions and removals (default false)" }
+            }),
+            &["id", "content"],
+        ),
     ]
 }
 
@@ -250,9 +739,113 @@ pub fn tool_definitions() -> Vec<Value> {
 // Helpers
 // ---------------------------------------------------------------------------
 
-pub(crate) fn tool_result(data: &impl Serialize) -> Value {
-    let text = serde_json::to_string_pretty(data)
-        .unwrap_or_else(|e| format!("{{\"error\": \"{e}\"}}"));
+/// Version of the result shapes, sent as `schema` in the result envelope.
+/// Bumped when a tool's result changes incompatibly.
+pub const RESULT_SCHEMA_VERSION: u32 = 1;
+
+/// Server settings a tool call runs with, handed from [`dispatch_tool`] to
+/// the handlers. The default is a server without a settings file.
+#[derive(Debug, Clone, Copy)]
+pub struct ToolContext {
+    /// Wrap results as `{schema, data}` in [`tool_result`].
+    pub envelope: bool,
+    /// Items a list tool returns per call when the caller passes no `max_items`.
+    pub max_list_items: usize,
+    /// Longest epic and task title and description accepted.
+    pub text_limits: TextLimits,
+}
+
+impl Default for ToolContext {
+    fn default() -> Self {
+        Self {
+            envelope: false,
+            max_list_items: DEFAULT_MAX_LIST_ITEMS,
+            text_limits: TextLimits::default(),
+        }
+    }
+}
+
+/// Refuse a `title` or `description` argument longer than the call's
+/// [`TextLimits`], naming the limit, rather than storing or truncating it.
+pub(crate) fn check_text_limits(ctx: &ToolContext, args: &Value) -> Result<(), Value> {
+    let limits = ctx.text_limits;
+    for (field, limit) in [("title", limits.title), ("description", limits.description)] {
+        let Some(value) = args.get(field).and_then(|v| v.as_str()) else {
+            continue;
+        };
+        let length = value.chars().count();
+        if length > limit {
+            return Err(tool_error(&format!(
+                "VALIDATION: {field} is {length} characters, over the limit of {limit}"
+            )));
+        }
+    }
+    Ok(())
+}
+
+/// The `offset` / `max_items` window a list tool returns.
+pub(crate) struct ListPage {
+    offset: usize,
+    max_items: usize,
+}
+
+impl ListPage {
+    /// Reads `offset` (default 0) and `max_items` (default the server cap).
+    pub(crate) fn from_args(ctx: &ToolContext, args: &Value) -> Result<Self, Value> {
+        let count = |field: &str, min: u64| match args.get(field) {
+            None | Some(Value::Null) => Ok(None),
+            Some(v) => match v.as_u64().filter(|n| *n >= min) {
+                Some(n) => Ok(Some(n as usize)),
+                None => Err(tool_error(&format!(
+                    "Invalid {field}: {v} (expected a whole number of at least {min})"
+                ))),
+            },
+        };
+        Ok(Self {
+            offset: count("offset", 0)?.unwrap_or(0),
+            max_items: count("max_items", 1)?.unwrap_or(ctx.max_list_items),
+        })
+    }
+
+    /// The page of `items`, plus the marker to append to the listed page
+    /// when items beyond it were cut off.
+    pub(crate) fn apply<T>(&self, items: Vec<T>) -> (Vec<T>, Option<Value>) {
+        let total = items.len();
+        let page: Vec<T> = items.into_iter().skip(self.offset).take(self.max_items).collect();
+        let next_offset = self.offset + page.len();
+        let marker = (next_offset < total).then(|| {
+            json!({
+                "truncated": true,
+                "returned": page.len(),
+                "total": total,
+                "next_offset": next_offset,
+                "_hint": format!(
+                    "Results were truncated; call again with offset {next_offset} for the next \
+                     page, or narrow the filters."
+                ),
+            })
+        });
+        (page, marker)
+    }
+}
+
+/// `items` as a JSON array, with a truncation marker from
+/// [`ListPage::apply`] as its last element.
+pub(crate) fn listed_page(items: impl Serialize, marker: Option<Value>) -> Value {
+    let mut list = json!(items);
+    if let (Some(marker), Some(array)) = (marker, list.as_array_mut()) {
+        array.push(marker);
+    }
+    list
+}
+
+pub(crate) fn tool_result(ctx: &ToolContext, data: &impl Serialize) -> Value {
+    let text = if ctx.envelope {
+        serde_json::to_string_pretty(&json!({ "schema": RESULT_SCHEMA_VERSION, "data": data }))
+    } else {
+        serde_json::to_string_pretty(data)
+    }
+    .unwrap_or_else(|e| format!("{{\"error\": \"{e}\"}}"));
     json!({ "content": [{ "type": "text", "text": text }] })
 }
 
@@ -267,10 +860,55 @@ pub(crate) fn require_str(args: &Value, field: &str) -> Result<String, Value> {
         .ok_or_else(|| tool_error(&format!("Missing required parameter: {field}")))
 }
 
+/// Arguments for an update tool carrying only `id` and `field` (plus the
+/// `project_id` used to resolve a short ID), so the rename tools can never
+/// touch any other column.
+pub(crate) fn rename_args(args: &Value, field: &str) -> Result<Value, Value> {
+    let id = require_str(args, "id")?;
+    let value = require_str(args, field)?;
+    if value.trim().is_empty() {
+        return Err(tool_error(&format!("{field} must not be empty")));
+    }
+    let mut update = json!({ "id": id, field: value });
+    if let Some(project_id) = optional_str(args, "project_id") {
+        update["project_id"] = json!(project_id);
+    }
+    Ok(update)
+}
+
 pub(crate) fn optional_str(args: &Value, field: &str) -> Option<String> {
     args.get(field).and_then(|v| v.as_str()).map(String::from)
 }
 
+/// The `actor` argument of a mutating tool, recorded as `updated_by` on the
+/// tasks and epics the call writes.
+pub(crate) fn actor(args: &Value) -> Option<&str> {
+    args.get("actor").and_then(|v| v.as_str())
+}
+
+pub(crate) fn optional_bool(args: &Value, field: &str) -> bool {
+    args.get(field).and_then(|v| v.as_bool()).unwrap_or(false)
+}
+
+/// Parse an inclusive `[start, end]` window, also accepted as a
+/// `"start,end"` string.
+pub(crate) fn optional_date_range(args: &Value, field: &str) -> Result<Option<DateRange>, Value> {
+    let Some(value) = args.get(field) else {
+        return Ok(None);
+    };
+    let bounds = match value {
+        Value::Array(pair) if pair.len() == 2 => pair[0].as_str().zip(pair[1].as_str()),
+        Value::String(s) => s.split_once(','),
+        _ => None,
+    };
+    let Some((start, end)) = bounds else {
+        return Err(tool_error(&format!("Invalid {field}: expected [start, end]")));
+    };
+    DateRange::parse(start, end)
+        .map(Some)
+        .map_err(|e| tool_error(&format!("Invalid {field}: {e}")))
+}
+
 pub(crate) fn parse_optional_status<T: std::str::FromStr>(args: &Value) -> Result<Option<T>, Value> {
     match optional_str(args, "status") {
         Some(s) => s
@@ -281,6 +919,41 @@ pub(crate) fn parse_optional_status<T: std::str::FromStr>(args: &Value) -> Resul
     }
 }
 
+/// Parse an optional array of statuses; an absent or empty array means
+/// no constraint.
+fn optional_status_list(args: &Value, field: &str) -> Result<Vec<ItemStatus>, Value> {
+    let Some(value) = args.get(field) else {
+        return Ok(Vec::new());
+    };
+    let Some(items) = value.as_array() else {
+        return Err(tool_error(&format!("Invalid {field}: expected an array of statuses")));
+    };
+    items
+        .iter()
+        .map(|item| {
+            let s = item.as_str().unwrap_or_default();
+            s.parse::<ItemStatus>()
+                .map_err(|_| tool_error(&format!("Invalid status: {item}")))
+        })
+        .collect()
+}
+
+/// Build the status filter for list tools from the legacy single `status`,
+/// the `statuses` array (match any) and the `exclude_statuses` array.
+pub(crate) fn parse_status_filter(args: &Value) -> Result<StatusFilter, Value> {
+    let status = parse_optional_status::<ItemStatus>(args)?;
+    let statuses = optional_status_list(args, "statuses")?;
+    let mut filter = match status {
+        Some(_) if !statuses.is_empty() => {
+            return Err(tool_error("Pass either status or statuses, not both"));
+        }
+        Some(s) => StatusFilter::only(s),
+        None => StatusFilter { include: statuses, ..StatusFilter::default() },
+    };
+    filter.exclude = optional_status_list(args, "exclude_statuses")?;
+    Ok(filter)
+}
+
 /// Resolve `project_id` from args, falling back to the server default.
 /// Returns `None` when neither source provides a value.
 pub(crate) fn resolve_optional_project_id(
@@ -311,7 +984,7 @@ pub(crate) fn validate_project_exists(
         Ok(Some(_)) => Ok(()),
         Ok(None) => Err(tool_error(&format!("Project not found: {project_id}"))),
         Err(e) => {
-            eprintln!("validate_project error: {e:#}");
+            log::error!("validate_project error: {e:#}");
             Err(tool_error("Failed to validate project"))
         }
     }
@@ -321,32 +994,67 @@ pub(crate) fn validate_project_exists(
 // Dispatch
 // ---------------------------------------------------------------------------
 
+/// Run the named tool, or `None` for an unknown tool.
 pub fn dispatch_tool(
     name: &str,
     args: &Value,
     db: &Database,
+    ctx: &ToolContext,
     default_project_id: Option<&str>,
 ) -> Option<Value> {
     let result = match name {
-        "create_project" => project::handle_create_project(args, db),
-        "list_projects" => project::handle_list_projects(args, db),
-        "get_project" => project::handle_get_project(args, db),
-        "update_project" => project::handle_update_project(args, db),
-        "delete_project" => project::handle_delete_project(args, db),
-        "create_epic" => epic::handle_create_epic(args, db, default_project_id),
-        "list_epics" => epic::handle_list_epics(args, db, default_project_id),
-        "get_epic" => epic::handle_get_epic(args, db, default_project_id),
-        "update_epic" => epic::handle_update_epic(args, db, default_project_id),
-        "delete_epic" => epic::handle_delete_epic(args, db, default_project_id),
-        "create_task" => task::handle_create_task(args, db, default_project_id),
-        "list_tasks" => task::handle_list_tasks(args, db, default_project_id),
-        "get_task" => task::handle_get_task(args, db, default_project_id),
-        "update_task" => task::handle_update_task(args, db, default_project_id),
-        "delete_task" => task::handle_delete_task(args, db, default_project_id),
-        "add_dependency" => dependency::handle_add_dependency(args, db, default_project_id),
-        "remove_dependency" => dependency::handle_remove_dependency(args, db, default_project_id),
-        "get_status" => status::handle_get_status(args, db, default_project_id),
-        "feed_prd" => prd::handle_feed_prd(args, db, default_project_id),
+        "create_project" => project::handle_create_project(args, db, ctx),
+        "list_projects" => project::handle_list_projects(args, db, ctx),
+        "get_project" => project::handle_get_project(args, db, ctx),
+        "update_project" => project::handle_update_project(args, db, ctx),
+        "rename_project" => project::handle_rename_project(args, db, ctx),
+        "delete_project" => project::handle_delete_project(args, db, ctx),
+        "reset_project_tasks" => project::handle_reset_project_tasks(args, db, ctx, default_project_id),
+        "create_epic" => epic::handle_create_epic(args, db, ctx, default_project_id),
+        "list_epics" => epic::handle_list_epics(args, db, ctx, default_project_id),
+        "get_epic" => epic::handle_get_epic(args, db, ctx, default_project_id),
+        "update_epic" => epic::handle_update_epic(args, db, ctx, default_project_id),
+        "rename_epic" => epic::handle_rename_epic(args, db, ctx, default_project_id),
+        "delete_epic" => epic::handle_delete_epic(args, db, ctx, default_project_id),
+        "archive_epic" => epic::handle_set_epic_archived(args, db, ctx, default_project_id, true),
+        "unarchive_epic" => epic::handle_set_epic_archived(args, db, ctx, default_project_id, false),
+        "reorder_epics" => epic::handle_reorder_epics(args, db, ctx, default_project_id),
+        "create_task" => task::handle_create_task(args, db, ctx, default_project_id),
+        "list_tasks" => task::handle_list_tasks(args, db, ctx, default_project_id),
+        "get_task" => task::handle_get_task(args, db, ctx, default_project_id),
+        "get_next_task" => task::handle_get_next_task(args, db, ctx, default_project_id),
+        "claim_task" => task::handle_claim_task(args, db, ctx, default_project_id),
+        "release_task" => task::handle_release_task(args, db, ctx, default_project_id),
+        "reopen_task" => task::handle_reopen_task(args, db, ctx, default_project_id),
+        "complete_chain" => task::handle_complete_chain(args, db, ctx, default_project_id),
+        "update_task" => task::handle_update_task(args, db, ctx, default_project_id),
+        "rename_task" => task::handle_rename_task(args, db, ctx, default_project_id),
+        "delete_task" => task::handle_delete_task(args, db, ctx, default_project_id),
+        "archive_task" => task::handle_set_task_archived(args, db, ctx, default_project_id, true),
+        "unarchive_task" => task::handle_set_task_archived(args, db, ctx, default_project_id, false),
+        "add_checklist_item" => checklist::handle_add_checklist_item(args, db, ctx, default_project_id),
+        "toggle_checklist_item" => checklist::handle_toggle_checklist_item(args, db, ctx),
+        "promote_checklist_item" => checklist::handle_promote_checklist_item(args, db, ctx),
+        "list_checklist_items" => checklist::handle_list_checklist_items(args, db, ctx, default_project_id),
+        "create_template" => template::handle_create_template(args, db, ctx, default_project_id),
+        "apply_template" => template::handle_apply_template(args, db, ctx, default_project_id),
+        "add_dependency" => dependency::handle_add_dependency(args, db, ctx, default_project_id),
+        "remove_dependency" => dependency::handle_remove_dependency(args, db, ctx, default_project_id),
+        "clear_dependencies" => dependency::handle_clear_dependencies(args, db, ctx, default_project_id),
+        "get_dependencies" => dependency::handle_get_dependencies(args, db, ctx, default_project_id),
+        "get_graph" => graph::handle_get_graph(args, db, ctx, default_project_id),
+        "get_status" => status::handle_get_status(args, db, ctx, default_project_id),
+        "get_burndown" => status::handle_get_burndown(args, db, ctx, default_project_id),
+        "get_timeline" => status::handle_get_timeline(args, db, ctx, default_project_id),
+        "export_tasks_csv" => export::handle_export_tasks_csv(args, db, default_project_id),
+        "search" => search::handle_search(args, db, ctx, default_project_id),
+        "maintain_db" => maintenance::handle_maintain_db(db, ctx),
+        "seed_demo_data" => seed::handle_seed_demo_data(db, ctx),
+        "feed_prd" => prd::handle_feed_prd(args, db, ctx, default_project_id),
+        "list_prds" => prd::handle_list_prds(args, db, ctx, default_project_id),
+        "get_prd" => prd::handle_get_prd(args, db, ctx),
+        "get_prd_breakdown" => prd::handle_get_prd_breakdown(args, db, ctx),
+        "diff_prd" => prd::handle_diff_prd(args, db, ctx),
         _ => return None,
     };
     Some(result)
@@ -369,7 +1077,28 @@ mod tests {
 
     #[test]
     fn test_tool_definitions_count() {
-        assert_eq!(tool_definitions().len(), 19);
+        assert_eq!(tool_definitions().len(), 52);
+    }
+
+    #[test]
+    fn test_mutating_tools_are_defined() {
+        let names: HashSet<String> = tool_definitions()
+            .iter()
+            .map(|t| t["name"].as_str().unwrap().to_string())
+            .collect();
+        for name in MUTATING_TOOLS {
+            assert!(names.contains(*name), "{name} is not a tool");
+        }
+    }
+
+    #[test]
+    fn test_is_mutating_looks_at_prd_flags() {
+        assert!(is_mutating("create_task", &json!({})));
+        assert!(!is_mutating("list_tasks", &json!({})));
+        assert!(is_mutating("feed_prd", &json!({})));
+        assert!(!is_mutating("feed_prd", &json!({"dry_run": true})));
+        assert!(!is_mutating("diff_prd", &json!({})));
+        assert!(is_mutating("diff_prd", &json!({"apply": true})));
     }
 
     #[test]
@@ -394,6 +1123,15 @@ mod tests {
     #[test]
     fn test_dispatch_unknown_tool_returns_none() {
         let (db, _dir) = test_db();
-        assert!(dispatch_tool("nonexistent_tool", &json!({}), &db, None).is_none());
+        assert!(
+            dispatch_tool(
+                "nonexistent_tool",
+                &json!({}),
+                &db,
+                &ToolContext::default(),
+                None
+            )
+            .is_none()
+        );
     }
 }
@@ -1,14 +1,21 @@
 use serde_json::{json, Value};
 
+use crate::db::epic as epic_db;
 use crate::db::prd as prd_db;
+use crate::db::task as task_db;
 use crate::db::Database;
-use crate::models::prd::CreatePrdInput;
+use crate::models::prd::{outline_prd, CreatePrdInput, PrdSummary};
+use crate::models::TaskListFilter;
 
-use super::{require_str, resolve_project_id, tool_error, tool_result, validate_project_exists};
+use super::{
+    actor, check_text_limits, optional_bool, require_str, resolve_project_id, tool_error,
+    tool_result, validate_project_exists, ToolContext,
+};
 
 pub(super) fn handle_feed_prd(
     args: &Value,
     db: &Database,
+    ctx: &ToolContext,
     default_project_id: Option<&str>,
 ) -> Value {
     let project_id = match resolve_project_id(args, default_project_id) {
@@ -28,6 +35,16 @@ pub(super) fn handle_feed_prd(
         return e;
     }
 
+    let proposed_epics = outline_prd(&content);
+    if optional_bool(args, "dry_run") {
+        return tool_result(ctx, &json!({
+            "message": "Dry run: nothing was stored",
+            "dry_run": true,
+            "title": title,
+            "proposed_epics": proposed_epics,
+        }));
+    }
+
     let prd = match prd_db::create_prd(
         db,
         CreatePrdInput {
@@ -38,30 +55,194 @@ pub(super) fn handle_feed_prd(
     ) {
         Ok(prd) => prd,
         Err(e) => {
-            eprintln!("feed_prd error: {e:#}");
+            log::error!("feed_prd error: {e:#}");
             return tool_error("Failed to store PRD");
         }
     };
 
     let guide = format!(
         "PRD stored successfully. Now break it down:\n\
-         1. Analyze the PRD content above\n\
-         2. Create epics using `create_epic` with project_id=\"{project_id}\"\n\
+         1. Analyze the PRD content above (proposed_epics outlines its headings and lists)\n\
+         2. Create epics using `create_epic` with project_id=\"{project_id}\" and prd_id=\"{prd_id}\"\n\
          3. Create tasks under each epic using `create_task`\n\
          4. Set up dependencies between tasks/epics using `add_dependency`\n\
-         5. Use `get_status` with project_id=\"{project_id}\" to verify the breakdown"
+         5. Use `get_prd_breakdown` with id=\"{prd_id}\" to verify the breakdown",
+        prd_id = prd.id
     );
 
-    tool_result(&json!({
+    tool_result(ctx, &json!({
         "message": "PRD stored successfully",
         "prd_id": prd.id,
         "guide": guide,
+        "proposed_epics": proposed_epics,
+    }))
+}
+
+pub(super) fn handle_list_prds(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    let project_id = match resolve_project_id(args, default_project_id) {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+
+    if let Err(e) = validate_project_exists(db, &project_id) {
+        return e;
+    }
+
+    let prds = match prd_db::list_prds(db, &project_id) {
+        Ok(prds) => prds,
+        Err(e) => {
+            log::error!("list_prds error: {e:#}");
+            return tool_error("Failed to list PRDs");
+        }
+    };
+
+    if optional_bool(args, "include_content") {
+        return tool_result(ctx, &json!(prds));
+    }
+    let summaries: Vec<PrdSummary> = prds.iter().map(PrdSummary::from).collect();
+    tool_result(ctx, &json!(summaries))
+}
+
+pub(super) fn handle_get_prd(args: &Value, db: &Database, ctx: &ToolContext) -> Value {
+    let id = match require_str(args, "id") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+
+    match prd_db::get_prd(db, &id) {
+        Ok(Some(prd)) => tool_result(ctx, &json!(prd)),
+        Ok(None) => tool_error(&format!("PRD not found: {id}")),
+        Err(e) => {
+            log::error!("get_prd error: {e:#}");
+            tool_error("Failed to get PRD")
+        }
+    }
+}
+
+pub(super) fn handle_get_prd_breakdown(args: &Value, db: &Database, ctx: &ToolContext) -> Value {
+    let id = match require_str(args, "id") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+
+    let prd = match prd_db::get_prd(db, &id) {
+        Ok(Some(prd)) => prd,
+        Ok(None) => return tool_error(&format!("PRD not found: {id}")),
+        Err(e) => {
+            log::error!("get_prd_breakdown error: {e:#}");
+            return tool_error("Failed to get PRD");
+        }
+    };
+
+    let epics = match epic_db::list_epics_for_prd(db, &id) {
+        Ok(epics) => epics,
+        Err(e) => {
+            log::error!("get_prd_breakdown error: {e:#}");
+            return tool_error("Failed to get PRD breakdown");
+        }
+    };
+
+    let mut tree = Vec::with_capacity(epics.len());
+    for epic in epics {
+        let tasks = match task_db::list_tasks(
+            db,
+            &TaskListFilter {
+                epic_id: Some(&epic.id),
+                include_archived: true,
+                ..Default::default()
+            },
+        ) {
+            Ok(tasks) => tasks,
+            Err(e) => {
+                log::error!("get_prd_breakdown error: {e:#}");
+                return tool_error("Failed to get PRD breakdown");
+            }
+        };
+        let mut node = json!(epic);
+        node["tasks"] = json!(tasks);
+        tree.push(node);
+    }
+
+    tool_result(ctx, &json!({
+        "prd": PrdSummary::from(&prd),
+        "epics": tree,
+    }))
+}
+
+pub(super) fn handle_diff_prd(args: &Value, db: &Database, ctx: &ToolContext) -> Value {
+    let id = match require_str(args, "id") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let content = match require_str(args, "content") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+
+    let prd = match prd_db::get_prd(db, &id) {
+        Ok(Some(prd)) => prd,
+        Ok(None) => return tool_error(&format!("PRD not found: {id}")),
+        Err(e) => {
+            log::error!("diff_prd error: {e:#}");
+            return tool_error("Failed to get PRD");
+        }
+    };
+
+    let diff = match prd_db::diff_prd(db, &id, &content) {
+        Ok(diff) => diff,
+        Err(e) => {
+            log::error!("diff_prd error: {e:#}");
+            return tool_error("Failed to diff PRD");
+        }
+    };
+
+    let apply = optional_bool(args, "apply");
+    if apply {
+        // Added epics and tasks are held to the same limits as create_epic and create_task.
+        let epics = diff.added_epics.iter().map(|e| json!(e));
+        let tasks = diff
+            .added_epics
+            .iter()
+            .flat_map(|e| &e.tasks)
+            .chain(diff.unchanged_epics.iter().flat_map(|e| &e.added_tasks))
+            .map(|t| json!(t));
+        for item in epics.chain(tasks) {
+            if let Err(e) = check_text_limits(ctx, &item) {
+                return e;
+            }
+        }
+    }
+    if apply && let Err(e) = prd_db::apply_prd_diff(db, &prd, &diff, &content, actor(args)) {
+        log::error!("diff_prd error: {e:#}");
+        return tool_error("Failed to apply PRD diff");
+    }
+
+    let (added, unchanged, removed) = diff.task_counts();
+    tool_result(ctx, &json!({
+        "prd_id": prd.id,
+        "applied": apply,
+        "summary": {
+            "epics": {
+                "added": diff.added_epics.len(),
+                "unchanged": diff.unchanged_epics.len(),
+                "removed": diff.removed_epics.len(),
+            },
+            "tasks": { "added": added, "unchanged": unchanged, "removed": removed },
+        },
+        "added_epics": diff.added_epics,
+        "unchanged_epics": diff.unchanged_epics,
+        "removed_epics": diff.removed_epics,
     }))
 }
 
 #[cfg(test)]
 mod tests {
-    use super::super::dispatch_tool;
+    use super::super::{dispatch_tool, ToolContext};
     use crate::db::prd::get_prd;
     use crate::db::project::create_project;
     use crate::db::Database;
@@ -101,6 +282,7 @@ mod tests {
                 "content": "# Requirements\n\nBuild a widget"
             }),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -116,6 +298,65 @@ mod tests {
             .contains(&project.id));
     }
 
+    #[test]
+    fn test_feed_prd_dry_run_matches_real_run_and_stores_nothing() {
+        let (db, _dir) = test_db();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "Dry Run".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let content =
+            "# Widget\n## Accounts\n- Sign-up form\n- Password reset\n## Billing\nInvoices.\n";
+
+        let dry = parse_response(
+        &dispatch_tool(
+            "feed_prd",
+            &json!({"project_id": project.id, "title": "PRD", "content": content, "dry_run": true}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap(),
+    );
+        assert_eq!(dry["dry_run"], true);
+        assert!(dry.get("prd_id").is_none());
+        assert_eq!(dry["proposed_epics"][0]["title"], "Accounts");
+        assert_eq!(dry["proposed_epics"][0]["tasks"][1]["title"], "Password reset");
+        assert_eq!(dry["proposed_epics"][1]["description"], "Invoices.");
+
+        let list = |tool: &str| {
+            parse_response(
+                &dispatch_tool(
+                    tool,
+                    &json!({"project_id": project.id}),
+                    &db,
+                    &ToolContext::default(),
+                    None,
+                )
+                .unwrap(),
+            )
+        };
+        assert!(list("list_prds").as_array().unwrap().is_empty());
+        assert!(list("list_epics").as_array().unwrap().is_empty());
+
+        let real = parse_response(
+            &dispatch_tool(
+                "feed_prd",
+                &json!({"project_id": project.id, "title": "PRD", "content": content}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        assert_eq!(real["proposed_epics"], dry["proposed_epics"]);
+        assert_eq!(list("list_prds").as_array().unwrap().len(), 1);
+    }
+
     #[test]
     fn test_feed_prd_invalid_project() {
         let (db, _dir) = test_db();
@@ -127,6 +368,7 @@ mod tests {
                 "content": "content"
             }),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -147,6 +389,7 @@ mod tests {
             "feed_prd",
             &json!({"title": "T", "content": "C"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -161,6 +404,7 @@ mod tests {
             "feed_prd",
             &json!({"project_id": "p1", "content": "C"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -171,6 +415,7 @@ mod tests {
             "feed_prd",
             &json!({"project_id": "p1", "title": "T"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -197,6 +442,7 @@ mod tests {
                 "content": "This should be in the DB"
             }),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -210,4 +456,340 @@ mod tests {
         assert_eq!(prd.content, "This should be in the DB");
         assert_eq!(prd.project_id, project.id);
     }
+
+    #[test]
+    fn test_feed_list_and_get_prd_round_trip() {
+        let (db, _dir) = test_db();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "Round Trip".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let content =
+            "# Goals\n\n- **Fast** startup\n- Unicode: caf\u{e9} \u{2713}\n\n```\ncode\n```\n";
+
+        let fed = parse_response(
+            &dispatch_tool(
+                "feed_prd",
+                &json!({"project_id": project.id, "title": "Spec", "content": content}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        let prd_id = fed["prd_id"].as_str().unwrap();
+
+        // The default project lets project_id be omitted.
+        let list_result = dispatch_tool(
+            "list_prds",
+            &json!({}),
+            &db,
+            &ToolContext::default(),
+            Some(&project.id),
+        )
+        .unwrap();
+        assert!(list_result.get("isError").is_none());
+        let listed = parse_response(&list_result);
+        let listed = listed.as_array().unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0]["id"], prd_id);
+        assert_eq!(listed[0]["title"], "Spec");
+        assert_eq!(listed[0]["content_length"], content.len());
+        assert!(listed[0].get("content").is_none());
+        assert!(listed[0]["created_at"].is_string());
+
+        let with_content = parse_response(
+            &dispatch_tool(
+                "list_prds",
+                &json!({"project_id": project.id, "include_content": true}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        assert_eq!(with_content[0]["content"], content);
+
+        let fetched = parse_response(
+            &dispatch_tool(
+                "get_prd",
+                &json!({"id": prd_id}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        assert_eq!(fetched["title"], "Spec");
+        assert_eq!(fetched["content"], content);
+        assert_eq!(fetched["project_id"], project.id);
+    }
+
+    #[test]
+    fn test_get_prd_not_found_and_list_unknown_project() {
+        let (db, _dir) = test_db();
+
+        let result = dispatch_tool(
+            "get_prd",
+            &json!({"id": "nope"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+        assert!(result["content"][0]["text"].as_str().unwrap().contains("not found"));
+
+        let result = dispatch_tool(
+            "list_prds",
+            &json!({"project_id": "nope"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+    }
+
+    #[test]
+    fn test_prd_breakdown_tracks_created_epics() {
+        let (db, _dir) = test_db();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "Traceable".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+
+        let fed = parse_response(
+            &dispatch_tool(
+                "feed_prd",
+                &json!({"project_id": project.id, "title": "Spec", "content": "Build it"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        let prd_id = fed["prd_id"].as_str().unwrap();
+        assert!(fed["guide"].as_str().unwrap().contains(prd_id));
+
+        let epic = parse_response(
+            &dispatch_tool(
+                "create_epic",
+                &json!({
+                    "project_id": project.id,
+                    "title": "Derived",
+                    "description": "",
+                    "prd_id": prd_id
+                }),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        assert_eq!(epic["prd_id"], prd_id);
+        dispatch_tool(
+            "create_task",
+            &json!({"epic_id": epic["id"], "title": "Step 1", "description": ""}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        dispatch_tool(
+            "create_epic",
+            &json!({"project_id": project.id, "title": "Unrelated", "description": ""}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+
+        let result = dispatch_tool(
+            "get_prd_breakdown",
+            &json!({"id": prd_id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert!(result.get("isError").is_none());
+        let data = parse_response(&result);
+        assert_eq!(data["prd"]["id"], prd_id);
+        let epics = data["epics"].as_array().unwrap();
+        assert_eq!(epics.len(), 1);
+        assert_eq!(epics[0]["title"], "Derived");
+        assert_eq!(epics[0]["tasks"][0]["title"], "Step 1");
+    }
+
+    #[test]
+    fn test_create_epic_rejects_foreign_prd() {
+        let (db, _dir) = test_db();
+        let mut project_ids = Vec::new();
+        for name in ["A", "B"] {
+            let project = create_project(
+                &db,
+                CreateProjectInput {
+                    name: name.to_string(),
+                    description: String::new(),
+                },
+            )
+            .unwrap();
+            project_ids.push(project.id);
+        }
+        let fed = parse_response(
+            &dispatch_tool(
+                "feed_prd",
+                &json!({"project_id": project_ids[0], "title": "Spec", "content": "x"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+
+        for prd_id in [fed["prd_id"].as_str().unwrap(), "missing"] {
+            let result = dispatch_tool(
+                "create_epic",
+                &json!({
+                    "project_id": project_ids[1],
+                    "title": "Wrong",
+                    "description": "",
+                    "prd_id": prd_id
+                }),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap();
+            assert_eq!(result["isError"], true, "prd_id {prd_id} should be rejected");
+        }
+    }
+
+    #[test]
+    fn test_diff_prd_classifies_an_edit_and_applies_it() {
+        let (db, _dir) = test_db();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "Evolving".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let call = |name: &str, args: Value| {
+            let result = dispatch_tool(name, &args, &db, &ToolContext::default(), None).unwrap();
+            assert!(result.get("isError").is_none(), "{name} failed: {result}");
+            parse_response(&result)
+        };
+
+        let original = "## Accounts\n- Sign-up form\n- Audit log\n";
+        let fed = call(
+            "feed_prd",
+            json!({"project_id": project.id, "title": "Spec", "content": original}),
+        );
+        let prd_id = fed["prd_id"].as_str().unwrap();
+        let epic = call(
+            "create_epic",
+            json!({
+                "project_id": project.id,
+                "title": "Accounts",
+                "description": "",
+                "prd_id": prd_id
+            }),
+        );
+        for title in ["Sign-up form", "Audit log"] {
+            call(
+                "create_task",
+                json!({"epic_id": epic["id"], "title": title, "description": ""}),
+            );
+        }
+
+        let edited = "## Accounts\n- Sign-up form\n- Password reset\n";
+        let diff = call("diff_prd", json!({"id": prd_id, "content": edited}));
+        assert_eq!(diff["applied"], false);
+        assert_eq!(diff["summary"]["epics"], json!({"added": 0, "unchanged": 1, "removed": 0}));
+        assert_eq!(diff["summary"]["tasks"], json!({"added": 1, "unchanged": 1, "removed": 1}));
+        let accounts = &diff["unchanged_epics"][0];
+        assert_eq!(accounts["id"], epic["id"]);
+        assert_eq!(accounts["added_tasks"][0]["title"], "Password reset");
+        assert_eq!(accounts["unchanged_tasks"][0]["title"], "Sign-up form");
+        assert_eq!(accounts["removed_tasks"][0]["title"], "Audit log");
+        // A plain diff changes nothing.
+        let breakdown = call("get_prd_breakdown", json!({"id": prd_id}));
+        assert_eq!(breakdown["epics"][0]["tasks"].as_array().unwrap().len(), 2);
+        assert_eq!(get_prd(&db, prd_id).unwrap().unwrap().content, original);
+
+        let applied = call("diff_prd", json!({"id": prd_id, "content": edited, "apply": true}));
+        assert_eq!(applied["applied"], true);
+        let breakdown = call("get_prd_breakdown", json!({"id": prd_id}));
+        let mut titles: Vec<&str> = breakdown["epics"][0]["tasks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| t["title"].as_str().unwrap())
+            .collect();
+        titles.sort();
+        assert_eq!(titles, ["Password reset", "Sign-up form"]);
+        assert_eq!(get_prd(&db, prd_id).unwrap().unwrap().content, edited);
+
+        let again = call("diff_prd", json!({"id": prd_id, "content": edited}));
+        assert_eq!(again["summary"]["tasks"], json!({"added": 0, "unchanged": 2, "removed": 0}));
+
+        let missing = dispatch_tool(
+            "diff_prd",
+            &json!({"id": "missing", "content": "x"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(missing["isError"], true);
+    }
+
+    #[test]
+    fn test_diff_prd_apply_checks_text_limits() {
+        use crate::settings::TextLimits;
+
+        let (db, _dir) = test_db();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "Limited".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let args = json!({"project_id": project.id, "title": "Spec", "content": "## Old\n"});
+        let fed = parse_response(
+            &dispatch_tool("feed_prd", &args, &db, &ToolContext::default(), None).unwrap(),
+        );
+        let prd_id = fed["prd_id"].as_str().unwrap();
+
+        let limits = TextLimits { title: 8, description: 100 };
+        let ctx = ToolContext { text_limits: limits, ..Default::default() };
+        let apply = |content: &str| {
+            let args = json!({"id": prd_id, "content": content, "apply": true});
+            dispatch_tool("diff_prd", &args, &db, &ctx, None).unwrap()
+        };
+        let result = apply("## Search\n- Rebuild the index\n");
+        assert_eq!(
+            result["content"][0]["text"],
+            "VALIDATION: title is 17 characters, over the limit of 8"
+        );
+        let result = apply("## Long epic title\n");
+        assert_eq!(result["isError"], true);
+        assert_eq!(get_prd(&db, prd_id).unwrap().unwrap().content, "## Old\n");
+
+        let result = apply("## Search\n- Index\n");
+        assert!(result.get("isError").is_none(), "{result}");
+    }
 }
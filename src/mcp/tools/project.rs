@@ -2,12 +2,17 @@ use serde_json::{json, Value};
 
 use crate::db::epic as epic_db;
 use crate::db::project as project_db;
+use crate::db::status as status_db;
 use crate::db::Database;
-use crate::models::project::{CreateProjectInput, ProjectStatus, UpdateProjectInput};
+use crate::models::project::{CreateProjectInput, ProjectSort, ProjectStatus, UpdateProjectInput};
+use crate::models::EpicListFilter;
 
-use super::{optional_str, parse_optional_status, require_str, tool_error, tool_result};
+use super::{
+    actor, optional_bool, optional_str, parse_optional_status, rename_args, require_str,
+    resolve_project_id, tool_error, tool_result, validate_project_exists, ToolContext,
+};
 
-pub(super) fn handle_create_project(args: &Value, db: &Database) -> Value {
+pub(super) fn handle_create_project(args: &Value, db: &Database, ctx: &ToolContext) -> Value {
     let name = match require_str(args, "name") {
         Ok(v) => v,
         Err(e) => return e,
@@ -18,30 +23,42 @@ pub(super) fn handle_create_project(args: &Value, db: &Database) -> Value {
     };
 
     match project_db::create_project(db, CreateProjectInput { name, description }) {
-        Ok(project) => tool_result(&project),
+        Ok(project) => tool_result(ctx, &project),
         Err(e) => {
-            eprintln!("create_project error: {e:#}");
+            log::error!("create_project error: {e:#}");
             tool_error("Failed to create project")
         }
     }
 }
 
-pub(super) fn handle_list_projects(args: &Value, db: &Database) -> Value {
+pub(super) fn handle_list_projects(args: &Value, db: &Database, ctx: &ToolContext) -> Value {
     let status = match parse_optional_status::<ProjectStatus>(args) {
         Ok(s) => s,
         Err(e) => return e,
     };
 
-    match project_db::list_projects(db, status) {
-        Ok(projects) => tool_result(&projects),
+    let sort = match optional_str(args, "sort") {
+        Some(s) => match s.parse::<ProjectSort>() {
+            Ok(sort) => sort,
+            Err(_) => {
+                return tool_error(&format!(
+                    "Invalid sort: {s} (expected name, created, updated or activity)"
+                ));
+            }
+        },
+        None => ProjectSort::default(),
+    };
+
+    match project_db::list_projects(db, status, sort) {
+        Ok(projects) => tool_result(ctx, &projects),
         Err(e) => {
-            eprintln!("list_projects error: {e:#}");
+            log::error!("list_projects error: {e:#}");
             tool_error("Failed to list projects")
         }
     }
 }
 
-pub(super) fn handle_get_project(args: &Value, db: &Database) -> Value {
+pub(super) fn handle_get_project(args: &Value, db: &Database, ctx: &ToolContext) -> Value {
     let id = match require_str(args, "id") {
         Ok(v) => v,
         Err(e) => return e,
@@ -51,23 +68,64 @@ pub(super) fn handle_get_project(args: &Value, db: &Database) -> Value {
         Ok(Some(p)) => p,
         Ok(None) => return tool_error(&format!("Project not found: {id}")),
         Err(e) => {
-            eprintln!("get_project error: {e:#}");
+            log::error!("get_project error: {e:#}");
             return tool_error("Failed to get project");
         }
     };
 
-    let epics = match epic_db::list_epics(db, Some(&id), None) {
+    let epics = match epic_db::list_epics(
+        db,
+        &EpicListFilter {
+            project_id: Some(&id),
+            ..Default::default()
+        },
+    ) {
         Ok(e) => e,
         Err(e) => {
-            eprintln!("list_epics error: {e:#}");
+            log::error!("list_epics error: {e:#}");
             return tool_error("Failed to get project");
         }
     };
 
-    tool_result(&json!({ "project": project, "epics": epics }))
+    if !optional_bool(args, "include_summary") {
+        return tool_result(ctx, &json!({ "project": project, "epics": epics }));
+    }
+
+    let epics_by_status = match status_db::count_epics_by_status(db, Some(&id)) {
+        Ok(m) => m,
+        Err(e) => {
+            log::error!("get_project error: {e:#}");
+            return tool_error("Failed to count epics");
+        }
+    };
+    let tasks_by_status = match status_db::count_tasks_by_status(db, Some(&id)) {
+        Ok(m) => m,
+        Err(e) => {
+            log::error!("get_project error: {e:#}");
+            return tool_error("Failed to count tasks");
+        }
+    };
+
+    let total_epics: i64 = epics_by_status.values().sum();
+    let total_tasks: i64 = tasks_by_status.values().sum();
+    let done_tasks = tasks_by_status.get("done").copied().unwrap_or(0);
+    let progress_percent = (done_tasks * 100).checked_div(total_tasks).unwrap_or(0);
+
+    tool_result(ctx, &json!({
+        "project": project,
+        "epics": epics,
+        "summary": {
+            "total_epics": total_epics,
+            "epics_by_status": epics_by_status,
+            "total_tasks": total_tasks,
+            "tasks_by_status": tasks_by_status,
+            "done_tasks": done_tasks,
+            "progress_percent": progress_percent,
+        },
+    }))
 }
 
-pub(super) fn handle_update_project(args: &Value, db: &Database) -> Value {
+pub(super) fn handle_update_project(args: &Value, db: &Database, ctx: &ToolContext) -> Value {
     let id = match require_str(args, "id") {
         Ok(v) => v,
         Err(e) => return e,
@@ -82,43 +140,137 @@ pub(super) fn handle_update_project(args: &Value, db: &Database) -> Value {
         name: optional_str(args, "name"),
         description: optional_str(args, "description"),
         status,
+        // An empty string clears the prefix (handled by the DB layer).
+        short_id_prefix: optional_str(args, "short_id_prefix").map(Some),
     };
 
     match project_db::update_project(db, &id, input) {
-        Ok(project) => tool_result(&project),
+        Ok(project) => tool_result(ctx, &project),
         Err(e) => {
             let msg = e.to_string();
             if msg.contains("not found") {
                 tool_error(&format!("Project not found: {id}"))
+            } else if msg.contains("invalid short_id_prefix") {
+                tool_error(&msg)
             } else {
-                eprintln!("update_project error: {e:#}");
+                log::error!("update_project error: {e:#}");
                 tool_error("Failed to update project")
             }
         }
     }
 }
 
-pub(super) fn handle_delete_project(args: &Value, db: &Database) -> Value {
+pub(super) fn handle_rename_project(args: &Value, db: &Database, ctx: &ToolContext) -> Value {
+    match rename_args(args, "name") {
+        Ok(update) => handle_update_project(&update, db, ctx),
+        Err(e) => e,
+    }
+}
+
+pub(super) fn handle_delete_project(args: &Value, db: &Database, ctx: &ToolContext) -> Value {
     let id = match require_str(args, "id") {
         Ok(v) => v,
         Err(e) => return e,
     };
 
+    let project = match project_db::get_project(db, &id) {
+        Ok(Some(p)) => p,
+        Ok(None) => return tool_error(&format!("Project not found: {id}")),
+        Err(e) => {
+            log::error!("delete_project error: {e:#}");
+            return tool_error("Failed to delete project");
+        }
+    };
+
+    let confirmed = optional_bool(args, "confirm")
+        || optional_str(args, "name").is_some_and(|name| name == project.name);
+    if !confirmed {
+        return refuse_unconfirmed_delete(db, &project.id, &project.name);
+    }
+
     match project_db::delete_project(db, &id) {
-        Ok(true) => tool_result(&json!({ "deleted": true, "id": id })),
+        Ok(true) => tool_result(ctx, &json!({ "deleted": true, "id": id })),
         Ok(false) => tool_error(&format!("Project not found: {id}")),
         Err(e) => {
-            eprintln!("delete_project error: {e:#}");
+            log::error!("delete_project error: {e:#}");
             tool_error("Failed to delete project")
         }
     }
 }
 
+pub(super) fn handle_reset_project_tasks(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    let project_id = match resolve_project_id(args, default_project_id) {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    if let Err(e) = validate_project_exists(db, &project_id) {
+        return e;
+    }
+
+    if !optional_bool(args, "confirm") {
+        let started = match status_db::count_tasks_by_status(db, Some(&project_id)) {
+            Ok(counts) => counts
+                .iter()
+                .filter(|(status, _)| status.as_str() != "todo")
+                .map(|(_, n)| n)
+                .sum::<i64>(),
+            Err(e) => {
+                log::error!("reset_project_tasks error: {e:#}");
+                return tool_error("Failed to reset project tasks");
+            }
+        };
+        return tool_error(&format!(
+            "Refusing to reset project tasks without confirmation: this would move {started} \
+             task(s) back to todo. Call reset_project_tasks again with confirm: true to proceed."
+        ));
+    }
+
+    let include_epics = optional_bool(args, "include_epics");
+    match project_db::reset_project_tasks(db, &project_id, include_epics, actor(args)) {
+        Ok(counts) => tool_result(ctx, &json!({
+            "project_id": project_id,
+            "tasks_reset": counts.tasks,
+            "epics_reset": counts.epics,
+        })),
+        Err(e) => {
+            log::error!("reset_project_tasks error: {e:#}");
+            tool_error("Failed to reset project tasks")
+        }
+    }
+}
+
+/// Explain what an unconfirmed `delete_project` call would have removed.
+fn refuse_unconfirmed_delete(db: &Database, id: &str, name: &str) -> Value {
+    let counts = status_db::count_epics_by_status(db, Some(id))
+        .and_then(|epics| Ok((epics, status_db::count_tasks_by_status(db, Some(id))?)));
+    let (epics, tasks) = match counts {
+        Ok((epics, tasks)) => (epics.values().sum::<i64>(), tasks.values().sum::<i64>()),
+        Err(e) => {
+            log::error!("delete_project error: {e:#}");
+            return tool_error("Failed to delete project");
+        }
+    };
+    tool_error(&format!(
+        "Refusing to delete project \"{name}\" without confirmation: this would permanently delete \
+         {epics} epic(s) and {tasks} task(s). Call delete_project again with confirm: true \
+         (or name: \"{name}\") to proceed."
+    ))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::super::dispatch_tool;
+    use super::super::{dispatch_tool, ToolContext};
     use crate::db::epic as epic_db;
+    use crate::db::task as task_db;
     use crate::db::Database;
+    use crate::models::{
+        CreateTaskInput, EpicListFilter, ItemStatus, TaskListFilter, UpdateTaskInput,
+    };
     use serde_json::{json, Value};
     use tempfile::TempDir;
 
@@ -143,6 +295,7 @@ mod tests {
             "create_project",
             &json!({"name": "Test", "description": "A project"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -162,6 +315,7 @@ mod tests {
             "create_project",
             &json!({"description": "desc"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -180,6 +334,7 @@ mod tests {
             "create_project",
             &json!({"name": "Test"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -196,7 +351,14 @@ mod tests {
     #[test]
     fn test_list_projects_empty() {
         let (db, _dir) = test_db();
-        let result = dispatch_tool("list_projects", &json!({}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "list_projects",
+            &json!({}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
 
         assert!(result.get("isError").is_none());
         let projects = parse_response(&result);
@@ -210,16 +372,25 @@ mod tests {
             "create_project",
             &json!({"name": "P1", "description": "d1"}),
             &db,
+            &ToolContext::default(),
             None,
         );
         dispatch_tool(
             "create_project",
             &json!({"name": "P2", "description": "d2"}),
             &db,
+            &ToolContext::default(),
             None,
         );
 
-        let result = dispatch_tool("list_projects", &json!({}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "list_projects",
+            &json!({}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         let projects = parse_response(&result);
         assert_eq!(projects.as_array().unwrap().len(), 2);
     }
@@ -231,6 +402,7 @@ mod tests {
             "create_project",
             &json!({"name": "Active", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -242,6 +414,7 @@ mod tests {
             "update_project",
             &json!({"id": id, "status": "archived"}),
             &db,
+            &ToolContext::default(),
             None,
         );
 
@@ -250,25 +423,150 @@ mod tests {
             "create_project",
             &json!({"name": "Still Active", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         );
 
-        let active = dispatch_tool("list_projects", &json!({"status": "active"}), &db, None).unwrap();
+        let active = dispatch_tool(
+            "list_projects",
+            &json!({"status": "active"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         let projects = parse_response(&active);
         assert_eq!(projects.as_array().unwrap().len(), 1);
         assert_eq!(projects[0]["name"], "Still Active");
 
-        let archived =
-            dispatch_tool("list_projects", &json!({"status": "archived"}), &db, None).unwrap();
+        let archived = dispatch_tool(
+            "list_projects",
+            &json!({"status": "archived"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         let projects = parse_response(&archived);
         assert_eq!(projects.as_array().unwrap().len(), 1);
         assert_eq!(projects[0]["name"], "Active");
     }
 
+    #[test]
+    fn test_list_projects_on_hold_filter() {
+        let (db, _dir) = test_db();
+        for name in ["Paused", "Running", "Shelved"] {
+            dispatch_tool(
+                "create_project",
+                &json!({"name": name, "description": "d"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            );
+        }
+        let all = parse_response(
+            &dispatch_tool(
+                "list_projects",
+                &json!({}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        let id_of = |name: &str| {
+            all.as_array()
+                .unwrap()
+                .iter()
+                .find(|p| p["name"] == name)
+                .unwrap()["id"]
+                .clone()
+        };
+
+        let paused = dispatch_tool(
+            "update_project",
+            &json!({"id": id_of("Paused"), "status": "on_hold"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(parse_response(&paused)["status"], "on_hold");
+        dispatch_tool(
+            "update_project",
+            &json!({"id": id_of("Shelved"), "status": "archived"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        );
+
+        for (status, expected) in [
+            ("on_hold", "Paused"),
+            ("active", "Running"),
+            ("archived", "Shelved"),
+        ] {
+            let result = dispatch_tool(
+                "list_projects",
+                &json!({"status": status}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap();
+            let projects = parse_response(&result);
+            assert_eq!(projects.as_array().unwrap().len(), 1, "{status}");
+            assert_eq!(projects[0]["name"], expected);
+        }
+    }
+
+    #[test]
+    fn test_list_projects_sort_param() {
+        let (db, _dir) = test_db();
+        for name in ["beta", "Alpha"] {
+            dispatch_tool(
+                "create_project",
+                &json!({"name": name, "description": "d"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            );
+        }
+
+        let result = dispatch_tool(
+            "list_projects",
+            &json!({"sort": "name"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        let projects = parse_response(&result);
+        assert_eq!(projects[0]["name"], "Alpha");
+        assert_eq!(projects[1]["name"], "beta");
+
+        let result = dispatch_tool(
+            "list_projects",
+            &json!({"sort": "size"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+        assert!(result["content"][0]["text"].as_str().unwrap().contains("Invalid sort"));
+    }
+
     #[test]
     fn test_list_projects_invalid_status() {
         let (db, _dir) = test_db();
-        let result = dispatch_tool("list_projects", &json!({"status": "bogus"}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "list_projects",
+            &json!({"status": "bogus"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         assert_eq!(result["isError"], true);
         assert!(result["content"][0]["text"]
             .as_str()
@@ -285,13 +583,21 @@ mod tests {
             "create_project",
             &json!({"name": "Get Me", "description": "desc"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
         let created = parse_response(&create_result);
         let id = created["id"].as_str().unwrap();
 
-        let result = dispatch_tool("get_project", &json!({"id": id}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "get_project",
+            &json!({"id": id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         assert!(result.get("isError").is_none());
         let data = parse_response(&result);
         assert_eq!(data["project"]["name"], "Get Me");
@@ -305,6 +611,7 @@ mod tests {
             "create_project",
             &json!({"name": "Parent", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -318,20 +625,180 @@ mod tests {
                 project_id: project_id.to_string(),
                 title: "Child Epic".to_string(),
                 description: "desc".to_string(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
 
-        let result = dispatch_tool("get_project", &json!({"id": project_id}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "get_project",
+            &json!({"id": project_id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         let data = parse_response(&result);
         assert_eq!(data["epics"].as_array().unwrap().len(), 1);
         assert_eq!(data["epics"][0]["title"], "Child Epic");
     }
 
+    #[test]
+    fn test_get_project_without_summary_omits_it() {
+        let (db, _dir) = test_db();
+        let created = parse_response(
+            &dispatch_tool(
+                "create_project",
+                &json!({"name": "Plain", "description": "d"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        let id = created["id"].as_str().unwrap();
+
+        let result = dispatch_tool(
+            "get_project",
+            &json!({"id": id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        let data = parse_response(&result);
+        assert!(data.get("summary").is_none());
+        assert_eq!(data["project"]["name"], "Plain");
+    }
+
+    #[test]
+    fn test_get_project_include_summary() {
+        let (db, _dir) = test_db();
+        db.set_auto_epic_status(true);
+        let created = parse_response(
+            &dispatch_tool(
+                "create_project",
+                &json!({"name": "Summary", "description": "d"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        let project_id = created["id"].as_str().unwrap();
+
+        let first = epic_db::create_epic(
+            &db,
+            crate::models::epic::CreateEpicInput {
+                project_id: project_id.to_string(),
+                title: "First".to_string(),
+                description: String::new(),
+                prd_id: None,
+            },
+            None,
+        )
+        .unwrap();
+        let second = epic_db::create_epic(
+            &db,
+            crate::models::epic::CreateEpicInput {
+                project_id: project_id.to_string(),
+                title: "Second".to_string(),
+                description: String::new(),
+                prd_id: None,
+            },
+            None,
+        )
+        .unwrap();
+        // created_at has second resolution; pin it so ordering is deterministic.
+        db.conn()
+            .execute(
+                "UPDATE epics SET created_at = '2025-01-01 00:00:00' WHERE id = ?1",
+                [&first.id],
+            )
+            .unwrap();
+
+        let seeded = [
+            (&first.id, "A"),
+            (&first.id, "B"),
+            (&second.id, "C"),
+            (&second.id, "D"),
+        ];
+        for (epic_id, title) in seeded {
+            task_db::create_task(
+                &db,
+                CreateTaskInput {
+                    epic_id: epic_id.clone(),
+                    title: title.to_string(),
+                    description: String::new(),
+                    session_id: None,
+                    assignee: None,
+                },
+            )
+            .unwrap();
+        }
+        let tasks = task_db::list_tasks(
+            &db,
+            &TaskListFilter {
+                epic_id: Some(&first.id),
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        for task in &tasks {
+            task_db::update_task(
+                &db,
+                &task.id,
+                UpdateTaskInput {
+                    status: Some(ItemStatus::Done),
+                    ..Default::default()
+                },
+                None,
+            )
+            .unwrap();
+        }
+
+        let result = dispatch_tool(
+            "get_project",
+            &json!({"id": project_id, "include_summary": true}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert!(result.get("isError").is_none());
+        let data = parse_response(&result);
+
+        // Epics keep list_epics ordering (newest first) with per-epic rollups.
+        let epics = data["epics"].as_array().unwrap();
+        assert_eq!(epics.len(), 2);
+        assert_eq!(epics[0]["title"], "Second");
+        assert_eq!(epics[0]["task_count"], 2);
+        assert_eq!(epics[0]["done_count"], 0);
+        assert_eq!(epics[1]["title"], "First");
+        assert_eq!(epics[1]["done_count"], 2);
+
+        let summary = &data["summary"];
+        assert_eq!(summary["total_epics"], 2);
+        assert_eq!(summary["epics_by_status"]["done"], 1);
+        assert_eq!(summary["epics_by_status"]["todo"], 1);
+        assert_eq!(summary["total_tasks"], 4);
+        assert_eq!(summary["tasks_by_status"]["done"], 2);
+        assert_eq!(summary["done_tasks"], 2);
+        assert_eq!(summary["progress_percent"], 50);
+    }
+
     #[test]
     fn test_get_project_not_found() {
         let (db, _dir) = test_db();
-        let result = dispatch_tool("get_project", &json!({"id": "nonexistent"}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "get_project",
+            &json!({"id": "nonexistent"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         assert_eq!(result["isError"], true);
         assert!(result["content"][0]["text"]
             .as_str()
@@ -342,7 +809,14 @@ mod tests {
     #[test]
     fn test_get_project_missing_id() {
         let (db, _dir) = test_db();
-        let result = dispatch_tool("get_project", &json!({}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "get_project",
+            &json!({}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         assert_eq!(result["isError"], true);
         assert!(result["content"][0]["text"]
             .as_str()
@@ -359,6 +833,7 @@ mod tests {
             "create_project",
             &json!({"name": "Original", "description": "old desc"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -369,6 +844,7 @@ mod tests {
             "update_project",
             &json!({"id": id, "name": "Renamed", "status": "archived"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -380,6 +856,112 @@ mod tests {
         assert_eq!(updated["status"], "archived");
     }
 
+    #[test]
+    fn test_rename_project_changes_only_name() {
+        let (db, _dir) = test_db();
+        let created = parse_response(
+            &dispatch_tool(
+                "create_project",
+                &json!({"name": "Original", "description": "old desc"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        let id = created["id"].as_str().unwrap();
+        dispatch_tool(
+            "update_project",
+            &json!({"id": id, "status": "archived", "short_id_prefix": "OLD"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        );
+
+        // Fields other than the name are ignored even when passed.
+        let result = dispatch_tool(
+            "rename_project",
+            &json!({"id": id, "name": "Renamed", "description": "", "status": "active"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert!(result.get("isError").is_none());
+        let renamed = parse_response(&result);
+        assert_eq!(renamed["name"], "Renamed");
+        assert_eq!(renamed["description"], "old desc");
+        assert_eq!(renamed["status"], "archived");
+        assert_eq!(renamed["short_id_prefix"], "OLD");
+        assert_eq!(renamed["created_at"], created["created_at"]);
+
+        for args in [json!({"id": id}), json!({"id": id, "name": "  "})] {
+            let result =
+                dispatch_tool("rename_project", &args, &db, &ToolContext::default(), None).unwrap();
+            assert_eq!(result["isError"], true, "{args}");
+        }
+    }
+
+    #[test]
+    fn test_update_project_short_id_prefix() {
+        let (db, _dir) = test_db();
+        let created = parse_response(
+            &dispatch_tool(
+                "create_project",
+                &json!({"name": "Prefixed", "description": "d"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        let id = created["id"].as_str().unwrap();
+
+        let result = dispatch_tool(
+            "update_project",
+            &json!({"id": id, "short_id_prefix": "web"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(parse_response(&result)["short_id_prefix"], "WEB");
+
+        let epic = dispatch_tool(
+            "create_epic",
+            &json!({"project_id": id, "title": "E", "description": "d"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(parse_response(&epic)["short_id"], "WEB-E1");
+
+        let result = dispatch_tool(
+            "update_project",
+            &json!({"id": id, "short_id_prefix": "no-dashes"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+        assert!(result["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("invalid short_id_prefix"));
+
+        let result = dispatch_tool(
+            "update_project",
+            &json!({"id": id, "short_id_prefix": ""}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert!(parse_response(&result)["short_id_prefix"].is_null());
+    }
+
     #[test]
     fn test_update_project_not_found() {
         let (db, _dir) = test_db();
@@ -387,6 +969,7 @@ mod tests {
             "update_project",
             &json!({"id": "nonexistent", "name": "X"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -406,27 +989,48 @@ mod tests {
             "create_project",
             &json!({"name": "To Delete", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
         let created = parse_response(&create_result);
         let id = created["id"].as_str().unwrap();
 
-        let result = dispatch_tool("delete_project", &json!({"id": id}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "delete_project",
+            &json!({"id": id, "confirm": true}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         assert!(result.get("isError").is_none());
         let data = parse_response(&result);
         assert_eq!(data["deleted"], true);
 
         // Verify it's gone
-        let get_result = dispatch_tool("get_project", &json!({"id": id}), &db, None).unwrap();
+        let get_result = dispatch_tool(
+            "get_project",
+            &json!({"id": id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         assert_eq!(get_result["isError"], true);
     }
 
     #[test]
     fn test_delete_project_not_found() {
         let (db, _dir) = test_db();
-        let result =
-            dispatch_tool("delete_project", &json!({"id": "nonexistent"}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "delete_project",
+            &json!({"id": "nonexistent"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         assert_eq!(result["isError"], true);
         assert!(result["content"][0]["text"]
             .as_str()
@@ -441,6 +1045,7 @@ mod tests {
             "create_project",
             &json!({"name": "Parent", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -454,15 +1059,197 @@ mod tests {
                 project_id: project_id.to_string(),
                 title: "Child Epic".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
 
         // Delete the project
-        dispatch_tool("delete_project", &json!({"id": project_id}), &db, None).unwrap();
+        dispatch_tool(
+            "delete_project",
+            &json!({"id": project_id, "confirm": true}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
 
         // Verify epics are gone
-        let epics = epic_db::list_epics(&db, Some(project_id), None).unwrap();
+        let epics = epic_db::list_epics(
+            &db,
+            &EpicListFilter {
+                project_id: Some(project_id),
+                ..Default::default()
+            },
+        )
+        .unwrap();
         assert!(epics.is_empty(), "epics should be cascade-deleted");
     }
+
+    fn project_with_task(db: &Database) -> String {
+        let created = parse_response(
+            &dispatch_tool(
+                "create_project",
+                &json!({"name": "Keep Me", "description": "d"}),
+                db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        let project_id = created["id"].as_str().unwrap().to_string();
+        let epic = epic_db::create_epic(
+            db,
+            crate::models::epic::CreateEpicInput {
+                project_id: project_id.clone(),
+                title: "Child Epic".to_string(),
+                description: String::new(),
+                prd_id: None,
+            },
+            None,
+        )
+        .unwrap();
+        task_db::create_task(
+            db,
+            CreateTaskInput {
+                epic_id: epic.id,
+                title: "Child Task".to_string(),
+                description: String::new(),
+                session_id: None,
+                assignee: None,
+            },
+        )
+        .unwrap();
+        project_id
+    }
+
+    #[test]
+    fn test_delete_project_without_confirmation_is_refused() {
+        let (db, _dir) = test_db();
+        let project_id = project_with_task(&db);
+
+        for args in [
+            json!({"id": project_id}),
+            json!({"id": project_id, "confirm": false}),
+            json!({"id": project_id, "name": "keep me"}),
+        ] {
+            let result =
+                dispatch_tool("delete_project", &args, &db, &ToolContext::default(), None).unwrap();
+            assert_eq!(result["isError"], true);
+            let text = result["content"][0]["text"].as_str().unwrap();
+            assert!(text.contains("1 epic(s) and 1 task(s)"), "{text}");
+            assert!(text.contains("confirm: true"), "{text}");
+        }
+
+        let pid = Some(project_id.as_str());
+        let epics = epic_db::list_epics(
+            &db,
+            &EpicListFilter {
+                project_id: pid,
+                ..Default::default()
+            },
+        );
+        assert_eq!(epics.unwrap().len(), 1);
+        let tasks = task_db::list_tasks(
+            &db,
+            &TaskListFilter {
+                project_id: pid,
+                ..Default::default()
+            },
+        );
+        assert_eq!(tasks.unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_delete_project_confirmed_by_name_cascades() {
+        let (db, _dir) = test_db();
+        let project_id = project_with_task(&db);
+
+        let result = dispatch_tool(
+            "delete_project",
+            &json!({"id": project_id, "name": "Keep Me"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert!(result.get("isError").is_none());
+
+        let pid = Some(project_id.as_str());
+        let epics = epic_db::list_epics(
+            &db,
+            &EpicListFilter {
+                project_id: pid,
+                ..Default::default()
+            },
+        );
+        assert!(epics.unwrap().is_empty());
+        let tasks = task_db::list_tasks(
+            &db,
+            &TaskListFilter {
+                project_id: pid,
+                ..Default::default()
+            },
+        );
+        assert!(tasks.unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_reset_project_tasks_requires_confirm() {
+        let (db, _dir) = test_db();
+        let project_id = crate::db::seed::seed_demo_data(&db).unwrap().project_id;
+        let started = |db: &Database| {
+            task_db::list_tasks(
+                db,
+                &TaskListFilter {
+                    project_id: Some(&project_id),
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+            .iter()
+            .filter(|t| t.status != ItemStatus::Todo)
+            .count()
+        };
+        let before = started(&db);
+        assert!(before > 0);
+
+        let result = dispatch_tool(
+            "reset_project_tasks",
+            &json!({}),
+            &db,
+            &ToolContext::default(),
+            Some(&project_id),
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+        let text = result["content"][0]["text"].as_str().unwrap();
+        assert!(text.contains(&format!("move {before} task(s)")), "{text}");
+        assert_eq!(started(&db), before);
+
+        let deps = crate::db::dependency::get_all_dependencies(&db).unwrap().len();
+        let args = json!({"confirm": true, "include_epics": true});
+        let result = dispatch_tool(
+            "reset_project_tasks",
+            &args,
+            &db,
+            &ToolContext::default(),
+            Some(&project_id),
+        )
+        .unwrap();
+        let data = parse_response(&result);
+        assert_eq!(data["tasks_reset"], before);
+        assert!(data["epics_reset"].as_u64().unwrap() > 0);
+        assert_eq!(started(&db), 0);
+        let epics = epic_db::list_epics(
+            &db,
+            &EpicListFilter {
+                project_id: Some(&project_id),
+                ..Default::default()
+            },
+        );
+        assert!(epics.unwrap().iter().all(|e| e.status == ItemStatus::Todo));
+        assert_eq!(crate::db::dependency::get_all_dependencies(&db).unwrap().len(), deps);
+    }
 }
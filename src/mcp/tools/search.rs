@@ -0,0 +1,184 @@
+use serde_json::{json, Value};
+
+use crate::db::search as search_db;
+use crate::db::Database;
+
+use super::{
+    optional_bool, require_str, resolve_optional_project_id, tool_error, tool_result, ToolContext,
+};
+
+pub(super) fn handle_search(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    let raw_query = match require_str(args, "query") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let project_id = resolve_optional_project_id(args, default_project_id);
+
+    let query = search_db::parse_query(&raw_query);
+    if query.is_empty() {
+        return tool_error("Search query must not be empty");
+    }
+
+    match search_db::search(db, project_id.as_deref(), &query) {
+        Ok(mut results) => {
+            if optional_bool(args, "with_snippets") {
+                for hit in &mut results {
+                    hit.snippet = search_db::description_snippet(&hit.description, &query);
+                }
+            }
+            tool_result(ctx, &json!({
+                "query": raw_query,
+                "count": results.len(),
+                "results": results,
+            }))
+        }
+        Err(e) => {
+            log::error!("search error: {e:#}");
+            tool_error("Failed to search")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{dispatch_tool, ToolContext};
+    use crate::db::epic::create_epic;
+    use crate::db::project::create_project;
+    use crate::db::task::create_task;
+    use crate::db::Database;
+    use crate::models::{CreateEpicInput, CreateProjectInput, CreateTaskInput};
+    use serde_json::{json, Value};
+    use tempfile::TempDir;
+
+    fn test_db() -> (Database, TempDir) {
+        let dir = TempDir::new().unwrap();
+        let db = Database::open(&dir.path().join("test.db")).unwrap();
+        db.migrate().unwrap();
+        (db, dir)
+    }
+
+    fn parse_response(result: &Value) -> Value {
+        let text = result["content"][0]["text"].as_str().unwrap();
+        serde_json::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn test_search_mixed_query() {
+        let (db, _dir) = test_db();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "Search".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let epic = create_epic(
+            &db,
+            CreateEpicInput {
+                project_id: project.id.clone(),
+                title: "Platform".to_string(),
+                description: String::new(),
+                prd_id: None,
+            },
+            None,
+        )
+        .unwrap();
+        for (title, description) in [("Auth API", "backend"), ("Auth UI", "frontend")] {
+            create_task(
+                &db,
+                CreateTaskInput {
+                    epic_id: epic.id.clone(),
+                    title: title.to_string(),
+                    description: description.to_string(),
+                    session_id: None,
+                    assignee: None,
+                },
+            )
+            .unwrap();
+        }
+
+        let result = dispatch_tool(
+            "search",
+            &json!({"query": "title:auth status:todo backend"}),
+            &db,
+            &ToolContext::default(),
+            Some(&project.id),
+        )
+        .unwrap();
+        assert!(result.get("isError").is_none());
+
+        let data = parse_response(&result);
+        assert_eq!(data["count"], 1);
+        assert_eq!(data["results"][0]["title"], "Auth API");
+        assert_eq!(data["results"][0]["type"], "task");
+        assert_eq!(data["results"][0]["epic_id"], epic.id);
+    }
+
+    #[test]
+    fn test_search_empty_query() {
+        let (db, _dir) = test_db();
+
+        let result = dispatch_tool(
+            "search",
+            &json!({"query": "  "}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+
+        let result =
+            dispatch_tool("search", &json!({}), &db, &ToolContext::default(), None).unwrap();
+        assert_eq!(result["isError"], true);
+        assert!(result["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("Missing required parameter"));
+    }
+
+    #[test]
+    fn test_search_snippets_are_opt_in() {
+        let (db, _dir) = test_db();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "Snippets".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        create_epic(
+            &db,
+            CreateEpicInput {
+                project_id: project.id.clone(),
+                title: "Sessions".to_string(),
+                description: "Rotate the refresh token on every login".to_string(),
+                prd_id: None,
+            },
+            None,
+        )
+        .unwrap();
+
+        let search = |args: Value| {
+            parse_response(
+                &dispatch_tool("search", &args, &db, &ToolContext::default(), None).unwrap(),
+            )["results"][0]
+                .clone()
+        };
+        let plain = search(json!({"query": "token"}));
+        assert!(plain.get("snippet").is_none());
+        assert!(plain.get("description").is_none());
+
+        let hit = search(json!({"query": "token", "with_snippets": true}));
+        assert_eq!(hit["snippet"], "Rotate the refresh **token** on every login");
+        let by_title = search(json!({"query": "sessions", "with_snippets": true}));
+        assert!(by_title.get("snippet").is_none(), "title-only match has no snippet");
+    }
+}
@@ -0,0 +1,90 @@
+use serde_json::{json, Value};
+
+use crate::db::seed as seed_db;
+use crate::db::Database;
+
+use super::{tool_error, tool_result, ToolContext};
+
+pub(super) fn handle_seed_demo_data(db: &Database, ctx: &ToolContext) -> Value {
+    match seed_db::seed_demo_data(db) {
+        Ok(summary) => tool_result(ctx, &json!({
+            "message": format!("Seeded demo project \"{}\"", seed_db::DEMO_PROJECT_NAME),
+            "project_id": summary.project_id,
+            "epics": summary.epics,
+            "tasks": summary.tasks,
+            "dependencies": summary.dependencies,
+        })),
+        Err(e) => {
+            let msg = e.to_string();
+            if msg.contains("already seeded") {
+                tool_error(&msg)
+            } else {
+                log::error!("seed_demo_data error: {e:#}");
+                tool_error("Failed to seed demo data")
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{dispatch_tool, ToolContext};
+    use crate::db::Database;
+    use serde_json::{json, Value};
+    use tempfile::TempDir;
+
+    fn test_db() -> (Database, TempDir) {
+        let dir = TempDir::new().unwrap();
+        let db = Database::open(&dir.path().join("test.db")).unwrap();
+        db.migrate().unwrap();
+        (db, dir)
+    }
+
+    fn parse_response(result: &Value) -> Value {
+        let text = result["content"][0]["text"].as_str().unwrap();
+        serde_json::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn test_seed_demo_data_then_refuse() {
+        let (db, _dir) = test_db();
+
+        let result = dispatch_tool(
+            "seed_demo_data",
+            &json!({}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert!(result.get("isError").is_none());
+        let data = parse_response(&result);
+        assert_eq!(data["epics"], 4);
+        assert_eq!(data["tasks"], 9);
+
+        let status = dispatch_tool(
+            "get_status",
+            &json!({"project_id": data["project_id"]}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        let status = parse_response(&status);
+        assert!(!status["blocked_items"].as_array().unwrap().is_empty());
+
+        let result = dispatch_tool(
+            "seed_demo_data",
+            &json!({}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+        assert!(result["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("already seeded"));
+    }
+}
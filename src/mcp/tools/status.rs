@@ -1,26 +1,67 @@
 use std::collections::BTreeMap;
 
+use chrono::{Days, NaiveDate, Utc};
 use serde_json::{json, Value};
 
+use crate::db::dependency as dependency_db;
+use crate::db::history as history_db;
 use crate::db::project as project_db;
 use crate::db::status as status_db;
 use crate::db::Database;
+use crate::models::{DependencyItem, DependencyType, ItemStatus};
 
-use super::{resolve_optional_project_id, tool_error, tool_result};
+use super::{
+    optional_bool, optional_str, resolve_optional_project_id, resolve_project_id, tool_error,
+    tool_result, validate_project_exists, ToolContext,
+};
+
+/// Default burndown window when `from` is omitted: two weeks ending at `to`.
+const DEFAULT_BURNDOWN_DAYS: u64 = 14;
+/// Largest `stale_days` threshold `get_status` accepts (about ten years).
+const MAX_STALE_DAYS: u64 = 3650;
+/// Longest burndown series a single call may request.
+const MAX_BURNDOWN_DAYS: i64 = 366;
+/// Events `get_timeline` returns when `limit` is omitted.
+const DEFAULT_TIMELINE_LIMIT: u64 = 50;
+/// Most events a single `get_timeline` call may request.
+const MAX_TIMELINE_LIMIT: u64 = 1000;
 
 pub(super) fn handle_get_status(
     args: &Value,
     db: &Database,
+    ctx: &ToolContext,
     default_project_id: Option<&str>,
 ) -> Value {
     let project_id = resolve_optional_project_id(args, default_project_id);
 
+    let by_epic = match optional_str(args, "group_by").as_deref() {
+        None | Some("project") => false,
+        Some("epic") => true,
+        Some(other) => {
+            return tool_error(&format!(
+                "Invalid group_by: {other} (expected 'project' or 'epic')"
+            ));
+        }
+    };
+
+    let stale_days = match args.get("stale_days") {
+        None | Some(Value::Null) => None,
+        Some(v) => match v.as_u64().filter(|d| (1..=MAX_STALE_DAYS).contains(d)) {
+            Some(days) => Some(days as u32),
+            None => {
+                return tool_error(&format!(
+                    "Invalid stale_days: {v} (expected a whole number of days from 1 to {MAX_STALE_DAYS})"
+                ));
+            }
+        },
+    };
+
     let project_label = match &project_id {
         Some(pid) => match project_db::get_project(db, pid) {
             Ok(Some(p)) => p.name,
             Ok(None) => return tool_error(&format!("Project not found: {pid}")),
             Err(e) => {
-                eprintln!("get_status error: {e:#}");
+                log::error!("get_status error: {e:#}");
                 return tool_error("Failed to get project");
             }
         },
@@ -30,7 +71,7 @@ pub(super) fn handle_get_status(
     let epics_by_status = match status_db::count_epics_by_status(db, project_id.as_deref()) {
         Ok(m) => m,
         Err(e) => {
-            eprintln!("get_status error: {e:#}");
+            log::error!("get_status error: {e:#}");
             return tool_error("Failed to count epics");
         }
     };
@@ -38,7 +79,7 @@ pub(super) fn handle_get_status(
     let tasks_by_status = match status_db::count_tasks_by_status(db, project_id.as_deref()) {
         Ok(m) => m,
         Err(e) => {
-            eprintln!("get_status error: {e:#}");
+            log::error!("get_status error: {e:#}");
             return tool_error("Failed to count tasks");
         }
     };
@@ -46,50 +87,231 @@ pub(super) fn handle_get_status(
     let blocked_rows = match status_db::get_blocked_items(db, project_id.as_deref()) {
         Ok(rows) => rows,
         Err(e) => {
-            eprintln!("get_status error: {e:#}");
+            log::error!("get_status error: {e:#}");
             return tool_error("Failed to get blocked items");
         }
     };
 
     // Group blocked rows by (item_type, item_id)
-    let mut grouped: BTreeMap<(String, String), (String, Vec<String>)> = BTreeMap::new();
+    let mut grouped: BTreeMap<(String, String), (status_db::BlockedItemRow, Vec<String>)> =
+        BTreeMap::new();
     for row in blocked_rows {
-        let key = (row.item_type, row.item_id);
-        grouped
-            .entry(key)
-            .or_insert_with(|| (row.title, Vec::new()))
-            .1
-            .push(row.blocker_id);
+        let key = (row.item_type.clone(), row.item_id.clone());
+        let blocker_id = row.blocker_id.clone();
+        grouped.entry(key).or_insert_with(|| (row, Vec::new())).1.push(blocker_id);
     }
 
-    let blocked_items: Vec<Value> = grouped
-        .into_iter()
-        .map(|((item_type, item_id), (title, blocked_by))| {
-            json!({
-                "type": item_type,
-                "id": item_id,
-                "title": title,
-                "blocked_by": blocked_by,
-            })
-        })
-        .collect();
+    let details = optional_bool(args, "include_blocked_details");
+    let mut blocked_items = Vec::new();
+    for (row, blocked_by) in grouped.into_values() {
+        let mut item = json!({
+            "type": row.item_type,
+            "id": row.item_id,
+            "title": row.title,
+            "blocked_by": blocked_by,
+        });
+        if details {
+            match blocker_details(db, &row) {
+                Ok(blockers) => {
+                    item["short_id"] = json!(row.short_id);
+                    item["status"] = json!(row.status);
+                    item["blockers"] = json!(blockers);
+                }
+                Err(e) => {
+                    log::error!("get_status error: {e:#}");
+                    return tool_error("Failed to get blockers");
+                }
+            }
+        }
+        blocked_items.push(item);
+    }
+
+    let forecast =
+        match history_db::get_forecast(db, project_id.as_deref(), Utc::now().date_naive()) {
+            Ok(f) => f,
+            Err(e) => {
+                log::error!("get_status error: {e:#}");
+                return tool_error("Failed to forecast completion");
+            }
+        };
 
     let total_epics: i64 = epics_by_status.values().sum();
     let total_tasks: i64 = tasks_by_status.values().sum();
 
-    tool_result(&json!({
+    let mut response = json!({
         "project": project_label,
         "total_epics": total_epics,
         "epics_by_status": epics_by_status,
         "total_tasks": total_tasks,
         "tasks_by_status": tasks_by_status,
+        "blocked_count": blocked_items.len(),
         "blocked_items": blocked_items,
-    }))
+        "forecast": forecast,
+    });
+
+    if by_epic {
+        let rows = match status_db::get_epic_status_rows(db, project_id.as_deref()) {
+            Ok(rows) => rows,
+            Err(e) => {
+                log::error!("get_status error: {e:#}");
+                return tool_error("Failed to count tasks per epic");
+            }
+        };
+        response["epics"] = rows.into_iter().map(epic_summary).collect();
+    }
+
+    if let Some(days) = stale_days {
+        let rows = match status_db::get_stale_tasks(db, project_id.as_deref(), days) {
+            Ok(rows) => rows,
+            Err(e) => {
+                log::error!("get_status error: {e:#}");
+                return tool_error("Failed to get stale tasks");
+            }
+        };
+        response["stale_tasks"] = rows
+            .into_iter()
+            .map(|row| {
+                json!({
+                    "id": row.task_id,
+                    "short_id": row.short_id,
+                    "title": row.title,
+                    "epic_id": row.epic_id,
+                    "updated_at": row.updated_at,
+                    "days_idle": row.days_idle,
+                })
+            })
+            .collect();
+    }
+
+    tool_result(ctx, &response)
+}
+
+/// The unfinished blockers of a blocked item, each as {type, id, short_id,
+/// title, status}.
+fn blocker_details(
+    db: &Database,
+    row: &status_db::BlockedItemRow,
+) -> anyhow::Result<Vec<DependencyItem>> {
+    let item_type: DependencyType = row.item_type.parse()?;
+    let mut blockers = dependency_db::get_blocker_items(db, &item_type, &row.item_id)?;
+    blockers.retain(|b| b.status != ItemStatus::Done);
+    Ok(blockers)
+}
+
+fn epic_summary(row: status_db::EpicStatusRow) -> Value {
+    let total_tasks = row.todo + row.in_progress + row.done;
+    let progress_percent = (row.done * 100).checked_div(total_tasks).unwrap_or(0);
+    json!({
+        "id": row.epic_id,
+        "short_id": row.short_id,
+        "title": row.title,
+        "status": row.status,
+        "total_tasks": total_tasks,
+        "tasks_by_status": {
+            "todo": row.todo,
+            "in_progress": row.in_progress,
+            "done": row.done,
+        },
+        "progress_percent": progress_percent,
+        "blocked_tasks": row.blocked_tasks,
+    })
+}
+
+pub(super) fn handle_get_burndown(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    let project_id = match resolve_project_id(args, default_project_id) {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    if let Err(e) = validate_project_exists(db, &project_id) {
+        return e;
+    }
+
+    let to = match optional_date(args, "to") {
+        Ok(d) => d.unwrap_or_else(|| Utc::now().date_naive()),
+        Err(e) => return e,
+    };
+    let from = match optional_date(args, "from") {
+        Ok(d) => d.unwrap_or_else(|| to - Days::new(DEFAULT_BURNDOWN_DAYS - 1)),
+        Err(e) => return e,
+    };
+    if from > to {
+        return tool_error(&format!("Invalid range: from ({from}) is after to ({to})"));
+    }
+    if (to - from).num_days() >= MAX_BURNDOWN_DAYS {
+        return tool_error(&format!(
+            "Invalid range: at most {MAX_BURNDOWN_DAYS} days can be requested"
+        ));
+    }
+
+    match history_db::get_burndown(db, &project_id, from, to) {
+        Ok(series) => tool_result(ctx, &json!({
+            "project_id": project_id,
+            "from": from.to_string(),
+            "to": to.to_string(),
+            "series": series,
+        })),
+        Err(e) => {
+            log::error!("get_burndown error: {e:#}");
+            tool_error("Failed to compute burndown")
+        }
+    }
+}
+
+pub(super) fn handle_get_timeline(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    let project_id = match resolve_project_id(args, default_project_id) {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    if let Err(e) = validate_project_exists(db, &project_id) {
+        return e;
+    }
+
+    let limit = match args.get("limit") {
+        None => DEFAULT_TIMELINE_LIMIT,
+        Some(v) => match v.as_u64() {
+            Some(n @ 1..=MAX_TIMELINE_LIMIT) => n,
+            _ => {
+                return tool_error(&format!(
+                    "Invalid limit: {v} (expected 1 to {MAX_TIMELINE_LIMIT})"
+                ));
+            }
+        },
+    };
+
+    match history_db::get_timeline(db, &project_id, limit as usize) {
+        Ok(events) => tool_result(ctx, &json!({
+            "project_id": project_id,
+            "events": events,
+        })),
+        Err(e) => {
+            log::error!("get_timeline error: {e:#}");
+            tool_error("Failed to load timeline")
+        }
+    }
+}
+
+fn optional_date(args: &Value, field: &str) -> Result<Option<NaiveDate>, Value> {
+    optional_str(args, field)
+        .map(|s| {
+            NaiveDate::parse_from_str(&s, "%Y-%m-%d")
+                .map_err(|_| tool_error(&format!("Invalid {field}: {s} (expected YYYY-MM-DD)")))
+        })
+        .transpose()
 }
 
 #[cfg(test)]
 mod tests {
-    use super::super::dispatch_tool;
+    use super::super::{dispatch_tool, ToolContext};
     use crate::db::dependency::add_dependency;
     use crate::db::epic::create_epic;
     use crate::db::project::create_project;
@@ -132,11 +354,20 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "E1".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
 
-        let result = dispatch_tool("get_status", &json!({"project_id": project.id}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "get_status",
+            &json!({"project_id": project.id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         assert!(result.get("isError").is_none());
 
         let data = parse_response(&result);
@@ -150,7 +381,8 @@ mod tests {
     fn test_status_without_project_id() {
         let (db, _dir) = test_db();
 
-        let result = dispatch_tool("get_status", &json!({}), &db, None).unwrap();
+        let result =
+            dispatch_tool("get_status", &json!({}), &db, &ToolContext::default(), None).unwrap();
         assert!(result.get("isError").is_none());
 
         let data = parse_response(&result);
@@ -163,8 +395,14 @@ mod tests {
     fn test_status_project_not_found() {
         let (db, _dir) = test_db();
 
-        let result =
-            dispatch_tool("get_status", &json!({"project_id": "nonexistent"}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "get_status",
+            &json!({"project_id": "nonexistent"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         assert_eq!(result["isError"], true);
         assert!(result["content"][0]["text"]
             .as_str()
@@ -184,7 +422,14 @@ mod tests {
         )
         .unwrap();
 
-        let result = dispatch_tool("get_status", &json!({"project_id": project.id}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "get_status",
+            &json!({"project_id": project.id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         let data = parse_response(&result);
 
         assert_eq!(data["total_epics"], 0);
@@ -198,6 +443,7 @@ mod tests {
     #[test]
     fn test_status_full_lifecycle() {
         let (db, _dir) = test_db();
+        db.set_auto_epic_status(true);
         let project = create_project(
             &db,
             CreateProjectInput {
@@ -213,7 +459,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "Epic 1".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
 
@@ -224,6 +472,7 @@ mod tests {
                 title: "Task A".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -234,6 +483,7 @@ mod tests {
                 title: "Task B".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -244,6 +494,7 @@ mod tests {
                 title: "Task C".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -257,6 +508,7 @@ mod tests {
                 blocked_type: DependencyType::Task,
                 blocked_id: t2.id.clone(),
             },
+            None,
         )
         .unwrap();
         add_dependency(
@@ -267,6 +519,7 @@ mod tests {
                 blocked_type: DependencyType::Task,
                 blocked_id: t3.id.clone(),
             },
+            None,
         )
         .unwrap();
 
@@ -278,10 +531,18 @@ mod tests {
                 status: Some(ItemStatus::InProgress),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
 
-        let result = dispatch_tool("get_status", &json!({"project_id": project.id}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "get_status",
+            &json!({"project_id": project.id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         let data = parse_response(&result);
 
         assert_eq!(data["project"], "Full");
@@ -300,6 +561,566 @@ mod tests {
             assert_eq!(item["type"], "task");
             assert_eq!(item["blocked_by"].as_array().unwrap().len(), 1);
             assert_eq!(item["blocked_by"][0], t1.id);
+            assert!(item.get("blockers").is_none());
+        }
+        assert_eq!(data["blocked_count"], 2);
+    }
+
+    #[test]
+    fn test_status_blocked_details_expand_blockers() {
+        let (db, _dir) = test_db();
+        let summary = crate::db::seed::seed_demo_data(&db).unwrap();
+        let args = json!({"project_id": summary.project_id, "include_blocked_details": true});
+
+        let result =
+            dispatch_tool("get_status", &args, &db, &ToolContext::default(), None).unwrap();
+        assert!(result.get("isError").is_none(), "{result}");
+        let data = parse_response(&result);
+        let blocked = data["blocked_items"].as_array().unwrap();
+        assert!(!blocked.is_empty());
+        assert_eq!(data["blocked_count"], blocked.len());
+
+        for item in blocked {
+            assert!(item["short_id"].is_string(), "{item}");
+            assert_ne!(item["status"], "done");
+            let blockers = item["blockers"].as_array().unwrap();
+            let mut ids: Vec<&str> = blockers.iter().map(|b| b["id"].as_str().unwrap()).collect();
+            let blocked_by = item["blocked_by"].as_array().unwrap();
+            let mut listed: Vec<&str> = blocked_by.iter().map(|b| b.as_str().unwrap()).collect();
+            ids.sort();
+            listed.sort();
+            assert_eq!(ids, listed, "{item}");
+            for blocker in blockers {
+                assert_ne!(blocker["status"], "done");
+                assert!(blocker["short_id"].is_string() && blocker["title"].is_string());
+
+                let id = blocker["id"].as_str().unwrap();
+                let (kind, tool) = match blocker["type"].as_str().unwrap() {
+                    "task" => ("task", "get_task"),
+                    _ => ("epic", "get_epic"),
+                };
+                let fetched =
+                    dispatch_tool(tool, &json!({"id": id}), &db, &ToolContext::default(), None)
+                        .unwrap();
+                let fetched = parse_response(&fetched);
+                let fetched = fetched.get(kind).unwrap_or(&fetched);
+                assert_eq!(fetched["title"], blocker["title"]);
+                assert_eq!(fetched["status"], blocker["status"]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_status_group_by_epic() {
+        let (db, _dir) = test_db();
+        let summary = crate::db::seed::seed_demo_data(&db).unwrap();
+
+        let result = dispatch_tool(
+            "get_status",
+            &json!({"project_id": summary.project_id, "group_by": "epic"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert!(result.get("isError").is_none());
+        let data = parse_response(&result);
+
+        // Project-level fields are still present alongside the breakdown.
+        assert_eq!(data["total_tasks"], 9);
+        let epics = data["epics"].as_array().unwrap();
+        assert_eq!(epics.len(), 4);
+
+        let by_title = |title: &str| epics.iter().find(|e| e["title"] == title).unwrap().clone();
+
+        let foundation = by_title("Foundation");
+        assert_eq!(foundation["total_tasks"], 2);
+        assert_eq!(foundation["tasks_by_status"]["done"], 2);
+        assert_eq!(foundation["progress_percent"], 100);
+        assert_eq!(foundation["blocked_tasks"], 0);
+
+        let auth = by_title("Authentication");
+        assert_eq!(auth["total_tasks"], 4);
+        assert_eq!(auth["tasks_by_status"]["todo"], 2);
+        assert_eq!(auth["tasks_by_status"]["in_progress"], 1);
+        assert_eq!(auth["tasks_by_status"]["done"], 1);
+        assert_eq!(auth["progress_percent"], 25);
+        // The two tasks waiting on the in-progress login API; the login API's
+        // own blocker is done.
+        assert_eq!(auth["blocked_tasks"], 2);
+
+        let dashboard = by_title("Dashboard");
+        assert_eq!(dashboard["blocked_tasks"], 1);
+        assert_eq!(dashboard["progress_percent"], 0);
+    }
+
+    #[test]
+    fn test_status_defaults_to_project_grouping() {
+        let (db, _dir) = test_db();
+        let summary = crate::db::seed::seed_demo_data(&db).unwrap();
+
+        let default = parse_response(
+            &dispatch_tool(
+                "get_status",
+                &json!({"project_id": summary.project_id}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        assert!(default.get("epics").is_none());
+
+        let explicit = parse_response(
+            &dispatch_tool(
+                "get_status",
+                &json!({"project_id": summary.project_id, "group_by": "project"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        assert_eq!(default, explicit);
+
+        let result = dispatch_tool(
+            "get_status",
+            &json!({"group_by": "task"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+    }
+
+    #[test]
+    fn test_status_lists_only_stale_in_progress_tasks() {
+        let (db, _dir) = test_db();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "P".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let epic = create_epic(
+            &db,
+            CreateEpicInput {
+                project_id: project.id.clone(),
+                title: "E".to_string(),
+                description: String::new(),
+                prd_id: None,
+            },
+            None,
+        )
+        .unwrap();
+        let mut ids = Vec::new();
+        for (title, status, age_days) in [
+            ("Old in progress", ItemStatus::InProgress, 10),
+            ("Fresh in progress", ItemStatus::InProgress, 0),
+            ("Old todo", ItemStatus::Todo, 10),
+        ] {
+            let task = create_task(
+                &db,
+                CreateTaskInput {
+                    epic_id: epic.id.clone(),
+                    title: title.to_string(),
+                    description: String::new(),
+                    session_id: None,
+                    assignee: None,
+                },
+            )
+            .unwrap();
+            update_task(
+                &db,
+                &task.id,
+                UpdateTaskInput {
+                    status: Some(status),
+                    ..Default::default()
+                },
+                None,
+            )
+            .unwrap();
+            db.conn()
+                .execute(
+                    "UPDATE tasks SET updated_at = datetime('now', ?1) WHERE id = ?2",
+                    [format!("-{age_days} days"), task.id.clone()],
+                )
+                .unwrap();
+            ids.push(task.id);
+        }
+
+        let data = parse_response(
+            &dispatch_tool(
+                "get_status",
+                &json!({"stale_days": 7}),
+                &db,
+                &ToolContext::default(),
+                Some(&project.id),
+            )
+            .unwrap(),
+        );
+        let stale = data["stale_tasks"].as_array().unwrap();
+        assert_eq!(stale.len(), 1);
+        assert_eq!(stale[0]["id"], ids[0]);
+        assert_eq!(stale[0]["title"], "Old in progress");
+        assert_eq!(stale[0]["days_idle"], 10);
+
+        // A threshold past the oldest task finds nothing; omitting it leaves the field out.
+        let data = parse_response(
+            &dispatch_tool(
+                "get_status",
+                &json!({"stale_days": 30}),
+                &db,
+                &ToolContext::default(),
+                Some(&project.id),
+            )
+            .unwrap(),
+        );
+        assert_eq!(data["stale_tasks"], json!([]));
+        let data = parse_response(
+            &dispatch_tool(
+                "get_status",
+                &json!({}),
+                &db,
+                &ToolContext::default(),
+                Some(&project.id),
+            )
+            .unwrap(),
+        );
+        assert!(data.get("stale_tasks").is_none());
+    }
+
+    #[test]
+    fn test_status_rejects_invalid_stale_days() {
+        let (db, _dir) = test_db();
+        for bad in [json!(0), json!(-3), json!(1.5), json!("7"), json!(100_000)] {
+            let result = dispatch_tool(
+                "get_status",
+                &json!({"stale_days": bad}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap();
+            assert_eq!(result["isError"], true, "{bad} should be rejected");
+            let text = result["content"][0]["text"].as_str().unwrap();
+            assert!(text.contains("stale_days"), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_status_forecasts_from_recent_completions() {
+        let (db, _dir) = test_db();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "Forecast".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let epic = create_epic(
+            &db,
+            CreateEpicInput {
+                project_id: project.id.clone(),
+                title: "E".to_string(),
+                description: String::new(),
+                prd_id: None,
+            },
+            None,
+        )
+        .unwrap();
+        let tasks: Vec<_> = (0..2)
+            .map(|i| {
+                create_task(
+                    &db,
+                    CreateTaskInput {
+                        epic_id: epic.id.clone(),
+                        title: format!("T{i}"),
+                        description: String::new(),
+                        session_id: None,
+                        assignee: None,
+                    },
+                )
+                .unwrap()
+            })
+            .collect();
+
+        let forecast = |db: &Database| {
+            let result = dispatch_tool(
+                "get_status",
+                &json!({"project_id": project.id}),
+                db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap();
+            parse_response(&result)["forecast"].clone()
+        };
+
+        let data = forecast(&db);
+        assert_eq!(data["remaining_tasks"], 2);
+        assert_eq!(data["completed_recently"], 0);
+        assert!(data["estimated_completion"].is_null());
+        assert!(data["caveat"].as_str().unwrap().contains("no recent velocity"));
+
+        update_task(
+            &db,
+            &tasks[0].id,
+            UpdateTaskInput {
+                status: Some(ItemStatus::Done),
+                ..Default::default()
+            },
+            None,
+        )
+        .unwrap();
+        let data = forecast(&db);
+        assert_eq!(data["remaining_tasks"], 1);
+        assert_eq!(data["completed_recently"], 1);
+        assert_eq!(data["window_days"], 14);
+        assert!(data["estimated_completion"].is_string());
+        assert!(data["caveat"].as_str().unwrap().starts_with("Only 1 task(s)"));
+    }
+
+    // --- get_burndown tests ---
+
+    fn backdate(db: &Database, sql: &str, id: &str) {
+        db.conn().execute(sql, [id]).unwrap();
+    }
+
+    #[test]
+    fn test_burndown_tracks_remaining_work() {
+        let (db, _dir) = test_db();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "Burndown".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let epic = create_epic(
+            &db,
+            CreateEpicInput {
+                project_id: project.id.clone(),
+                title: "E".to_string(),
+                description: String::new(),
+                prd_id: None,
+            },
+            None,
+        )
+        .unwrap();
+        let tasks: Vec<_> = (0..3)
+            .map(|i| {
+                create_task(
+                    &db,
+                    CreateTaskInput {
+                        epic_id: epic.id.clone(),
+                        title: format!("T{i}"),
+                        description: String::new(),
+                        session_id: None,
+                        assignee: None,
+                    },
+                )
+                .unwrap()
+            })
+            .collect();
+        for task in &tasks {
+            backdate(&db, "UPDATE tasks SET created_at = '2026-03-01 09:00:00' WHERE id = ?1", &task.id);
+        }
+
+        // T0 done on the 2nd, T1 started on the 2nd and done on the 4th,
+        // T2 done on the 3rd then reopened on the 4th.
+        let transitions = [
+            (0, ItemStatus::Done, "2026-03-02 10:00:00"),
+            (1, ItemStatus::InProgress, "2026-03-02 11:00:00"),
+            (2, ItemStatus::Done, "2026-03-03 10:00:00"),
+            (1, ItemStatus::Done, "2026-03-04 10:00:00"),
+            (2, ItemStatus::Todo, "2026-03-04 12:00:00"),
+        ];
+        for (idx, status, at) in transitions {
+            update_task(
+                &db,
+                &tasks[idx].id,
+                UpdateTaskInput {
+                    status: Some(status),
+                    ..Default::default()
+                },
+                None,
+            )
+            .unwrap();
+            db.conn()
+                .execute(
+                    "UPDATE status_history SET changed_at = ?1 WHERE id = (SELECT MAX(id) FROM status_history WHERE entity_type = 'task')",
+                    [at],
+                )
+                .unwrap();
+        }
+
+        let result = dispatch_tool(
+            "get_burndown",
+            &json!({"project_id": project.id, "from": "2026-02-28", "to": "2026-03-05"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert!(result.get("isError").is_none(), "{result}");
+        let data = parse_response(&result);
+        let series = data["series"].as_array().unwrap();
+        let days: Vec<&str> = series.iter().map(|p| p["date"].as_str().unwrap()).collect();
+        assert_eq!(
+            days,
+            ["2026-02-28", "2026-03-01", "2026-03-02", "2026-03-03", "2026-03-04", "2026-03-05"]
+        );
+        let remaining: Vec<i64> = series.iter().map(|p| p["remaining"].as_i64().unwrap()).collect();
+        assert_eq!(remaining, [0, 3, 2, 1, 1, 1]);
+        let total: Vec<i64> = series.iter().map(|p| p["total"].as_i64().unwrap()).collect();
+        assert_eq!(total, [0, 3, 3, 3, 3, 3]);
+    }
+
+    #[test]
+    fn test_burndown_rejects_bad_ranges() {
+        let (db, _dir) = test_db();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "P".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+
+        for (args, expected) in [
+            (json!({"from": "2026-03-05", "to": "2026-03-01"}), "after"),
+            (json!({"from": "03/01/2026"}), "YYYY-MM-DD"),
+            (json!({"from": "2020-01-01", "to": "2026-01-01"}), "at most"),
+        ] {
+            let result = dispatch_tool(
+                "get_burndown",
+                &args,
+                &db,
+                &ToolContext::default(),
+                Some(&project.id),
+            )
+            .unwrap();
+            assert_eq!(result["isError"], true);
+            let text = result["content"][0]["text"].as_str().unwrap();
+            assert!(text.contains(expected), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_burndown_defaults_to_two_weeks() {
+        let (db, _dir) = test_db();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "P".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let result = dispatch_tool(
+            "get_burndown",
+            &json!({}),
+            &db,
+            &ToolContext::default(),
+            Some(&project.id),
+        )
+        .unwrap();
+        let data = parse_response(&result);
+        assert_eq!(data["series"].as_array().unwrap().len(), 14);
+    }
+
+    #[test]
+    fn test_timeline_reports_verbs_and_validates_limit() {
+        let (db, _dir) = test_db();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "P".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let epic = create_epic(
+            &db,
+            CreateEpicInput {
+                project_id: project.id.clone(),
+                title: "Auth".to_string(),
+                description: String::new(),
+                prd_id: None,
+            },
+            None,
+        )
+        .unwrap();
+        let task = create_task(
+            &db,
+            CreateTaskInput {
+                epic_id: epic.id,
+                title: "Login".to_string(),
+                description: String::new(),
+                session_id: None,
+                assignee: None,
+            },
+        )
+        .unwrap();
+        update_task(
+            &db,
+            &task.id,
+            UpdateTaskInput {
+                status: Some(ItemStatus::Done),
+                ..Default::default()
+            },
+            None,
+        )
+        .unwrap();
+
+        let result = dispatch_tool(
+            "get_timeline",
+            &json!({}),
+            &db,
+            &ToolContext::default(),
+            Some(&project.id),
+        )
+        .unwrap();
+        let data = parse_response(&result);
+        let events: Vec<(&str, &str, &str)> = data["events"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|e| {
+                (
+                    e["short_id"].as_str().unwrap(),
+                    e["title"].as_str().unwrap(),
+                    e["verb"].as_str().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            events,
+            [
+                ("E1", "Auth", "created"),
+                ("E1-T1", "Login", "created"),
+                ("E1-T1", "Login", "completed"),
+            ]
+        );
+
+        for limit in [json!(0), json!("ten"), json!(1001)] {
+            let args = json!({ "limit": limit });
+            let result = dispatch_tool(
+                "get_timeline",
+                &args,
+                &db,
+                &ToolContext::default(),
+                Some(&project.id),
+            )
+            .unwrap();
+            assert_eq!(result["isError"], true, "{limit}");
         }
     }
 }
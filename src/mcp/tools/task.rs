@@ -2,13 +2,15 @@ use serde_json::{json, Value};
 
 use crate::db::dependency as dep_db;
 use crate::db::epic as epic_db;
+use crate::db::project as project_db;
 use crate::db::task as task_db;
 use crate::db::Database;
 use crate::models::dependency::{Dependency, DependencyType};
 use crate::models::epic::ItemStatus;
-use crate::models::task::{CreateTaskInput, TaskSummary, UpdateTaskInput};
+use crate::models::task::{CreateTaskInput, TaskListFilter, TaskSummary, UpdateTaskInput};
 
-use super::{optional_str, parse_optional_status, require_str, resolve_optional_project_id, tool_error, tool_result};
+use super::dependency::{dependency_lists, neighbors};
+use super::{actor, check_text_limits, listed_page, optional_bool, optional_date_range, optional_str, parse_optional_status, parse_status_filter, rename_args, require_str, resolve_optional_project_id, resolve_project_id, tool_error, tool_result, validate_project_exists, ListPage, ToolContext};
 
 /// Return the short ID of a blocker task if available, otherwise its ULID.
 fn blocker_label(db: &Database, dep: Dependency) -> String {
@@ -19,12 +21,21 @@ fn blocker_label(db: &Database, dep: Dependency) -> String {
         .unwrap_or(dep.blocker_id)
 }
 
-pub(super) fn handle_create_task(args: &Value, db: &Database, default_project_id: Option<&str>) -> Value {
+pub(super) fn handle_create_task(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    if let Err(e) = check_text_limits(ctx, args) {
+        return e;
+    }
     let epic_id = match require_str(args, "epic_id") {
         Ok(v) => v,
         Err(e) => return e,
     };
-    let epic_id = match epic_db::resolve_epic_id(db, &epic_id, default_project_id) {
+    let project_id = resolve_optional_project_id(args, default_project_id);
+    let epic_id = match epic_db::resolve_epic_id(db, &epic_id, project_id.as_deref()) {
         Ok(v) => v,
         Err(e) => return tool_error(&e.to_string()),
     };
@@ -42,68 +53,182 @@ pub(super) fn handle_create_task(args: &Value, db: &Database, default_project_id
         Ok(Some(_)) => {}
         Ok(None) => return tool_error(&format!("Epic not found: {epic_id}")),
         Err(e) => {
-            eprintln!("create_task error: {e:#}");
+            log::error!("create_task error: {e:#}");
             return tool_error("Failed to create task");
         }
     }
 
     let session_id = optional_str(args, "session_id");
+    let assignee = optional_str(args, "assignee").filter(|a| !a.is_empty());
+
+    let mut blocker_ids = Vec::new();
+    if let Some(depends_on) = args.get("depends_on") {
+        let Some(requested) = depends_on.as_array() else {
+            return tool_error("depends_on must be a list of task IDs");
+        };
+        for value in requested {
+            let Some(id) = value.as_str() else {
+                return tool_error("depends_on must be a list of task IDs");
+            };
+            match task_db::resolve_task_id(db, id, project_id.as_deref()) {
+                Ok(id) => blocker_ids.push(id),
+                Err(e) => return tool_error(&e.to_string()),
+            }
+        }
+    }
 
-    match task_db::create_task(db, CreateTaskInput { epic_id, title, description, session_id }) {
-        Ok(task) => tool_result(&task),
+    let input = CreateTaskInput { epic_id, title, description, session_id, assignee };
+    match task_db::create_task_with_blockers(db, input, &blocker_ids, actor(args)) {
+        Ok(task) => tool_result(ctx, &task),
         Err(e) => {
-            eprintln!("create_task error: {e:#}");
-            tool_error("Failed to create task")
+            let msg = e.to_string();
+            if msg.contains("not found") {
+                tool_error(&msg)
+            } else {
+                log::error!("create_task error: {e:#}");
+                tool_error("Failed to create task")
+            }
         }
     }
 }
 
-pub(super) fn handle_list_tasks(args: &Value, db: &Database, default_project_id: Option<&str>) -> Value {
+pub(super) fn handle_list_tasks(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    let project_id = resolve_optional_project_id(args, default_project_id);
     let epic_id = match optional_str(args, "epic_id") {
-        Some(eid) => match epic_db::resolve_epic_id(db, &eid, default_project_id) {
+        Some(eid) => match epic_db::resolve_epic_id(db, &eid, project_id.as_deref()) {
             Ok(v) => Some(v),
             Err(e) => return tool_error(&e.to_string()),
         },
         None => None,
     };
-    let project_id = resolve_optional_project_id(args, default_project_id);
-    let status = match parse_optional_status::<ItemStatus>(args) {
+    let status = match parse_status_filter(args) {
         Ok(s) => s,
         Err(e) => return e,
     };
 
-    let tasks = match task_db::list_tasks(db, epic_id.as_deref(), project_id.as_deref(), status) {
+    let assignee = optional_str(args, "assignee");
+    let assignee_filter = match (assignee.as_deref(), optional_bool(args, "unassigned")) {
+        (Some(_), true) => return tool_error("Pass either assignee or unassigned, not both"),
+        (Some(name), false) => Some(Some(name)),
+        (None, true) => Some(None),
+        (None, false) => None,
+    };
+    let created = match optional_date_range(args, "created_between") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let updated = match optional_date_range(args, "updated_between") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let blocked_only = optional_bool(args, "blocked_only");
+    let blocked_filter = match (blocked_only, optional_bool(args, "ready_only")) {
+        (true, true) => return tool_error("Pass either blocked_only or ready_only, not both"),
+        (true, false) => Some(true),
+        (false, true) => Some(false),
+        (false, false) => None,
+    };
+
+    let page = match ListPage::from_args(ctx, args) {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+
+    let tasks = match task_db::list_tasks(
+        db,
+        &TaskListFilter {
+            epic_id: epic_id.as_deref(),
+            project_id: project_id.as_deref(),
+            status,
+            include_archived: optional_bool(args, "include_archived"),
+            assignee: assignee_filter,
+            created,
+            updated,
+        },
+    ) {
         Ok(t) => t,
         Err(e) => {
-            eprintln!("list_tasks error: {e:#}");
+            log::error!("list_tasks error: {e:#}");
             return tool_error("Failed to list tasks");
         }
     };
 
-    let summaries: Vec<TaskSummary> = tasks
-        .into_iter()
-        .map(|task| {
-            let blockers = dep_db::get_blockers(db, &DependencyType::Task, &task.id)
-                .unwrap_or_default()
-                .into_iter()
-                .map(|dep| blocker_label(db, dep))
-                .collect();
-            TaskSummary::from_task(task, blockers)
-        })
-        .collect();
+    // Blocked state decides which tasks match, so with a filter it is
+    // worked out before paging.
+    let mut matching = Vec::with_capacity(tasks.len());
+    for task in tasks {
+        let Some(want_blocked) = blocked_filter else {
+            matching.push((task, None));
+            continue;
+        };
+        let blocked = match transitively_blocked(db, &task.id) {
+            Ok(b) => b,
+            Err(e) => return e,
+        };
+        let keep = if want_blocked {
+            blocked && task.status != ItemStatus::Done
+        } else {
+            !blocked && task.status == ItemStatus::Todo
+        };
+        if keep {
+            matching.push((task, Some(blocked)));
+        }
+    }
+
+    let (matching, marker) = page.apply(matching);
+    let include_blocked = optional_bool(args, "include_blocked");
+    let mut summaries = Vec::with_capacity(matching.len());
+    for (task, known_blocked) in matching {
+        let blocked = match known_blocked {
+            _ if !include_blocked => None,
+            Some(b) => Some(b),
+            None => match transitively_blocked(db, &task.id) {
+                Ok(b) => Some(b),
+                Err(e) => return e,
+            },
+        };
+        let blockers = dep_db::get_blockers(db, &DependencyType::Task, &task.id)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|dep| blocker_label(db, dep))
+            .collect();
+        summaries.push(TaskSummary {
+            blocked,
+            ..TaskSummary::from_task(task, blockers)
+        });
+    }
 
-    tool_result(&json!({
-        "tasks": summaries,
+    tool_result(ctx, &json!({
+        "tasks": listed_page(summaries, marker),
         "_hint": "Use get_task to view full task details including description."
     }))
 }
 
-pub(super) fn handle_get_task(args: &Value, db: &Database, default_project_id: Option<&str>) -> Value {
+/// Whether anything upstream of the task is unfinished, for `list_tasks`.
+fn transitively_blocked(db: &Database, task_id: &str) -> Result<bool, Value> {
+    dep_db::is_transitively_blocked(db, &DependencyType::Task, task_id).map_err(|e| {
+        log::error!("list_tasks error: {e:#}");
+        tool_error("Failed to list tasks")
+    })
+}
+
+pub(super) fn handle_get_task(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
     let id = match require_str(args, "id") {
         Ok(v) => v,
         Err(e) => return e,
     };
-    let id = match task_db::resolve_task_id(db, &id, default_project_id) {
+    let project_id = resolve_optional_project_id(args, default_project_id);
+    let id = match task_db::resolve_task_id(db, &id, project_id.as_deref()) {
         Ok(v) => v,
         Err(e) => return tool_error(&e.to_string()),
     };
@@ -112,36 +237,83 @@ pub(super) fn handle_get_task(args: &Value, db: &Database, default_project_id: O
         Ok(Some(t)) => t,
         Ok(None) => return tool_error(&format!("Task not found: {id}")),
         Err(e) => {
-            eprintln!("get_task error: {e:#}");
+            log::error!("get_task error: {e:#}");
             return tool_error("Failed to get task");
         }
     };
 
-    let blockers = match dep_db::get_blockers(db, &DependencyType::Task, &id) {
-        Ok(b) => b,
+    let expand = optional_bool(args, "expand");
+    let (blockers, blocks) = match dependency_lists(db, &DependencyType::Task, &id, expand) {
+        Ok(lists) => lists,
         Err(e) => {
-            eprintln!("get_blockers error: {e:#}");
+            log::error!("get_task error: {e:#}");
             return tool_error("Failed to get task");
         }
     };
 
-    let blocks = match dep_db::get_blocked_by(db, &DependencyType::Task, &id) {
-        Ok(b) => b,
+    let epic = match epic_db::get_epic(db, &task.epic_id) {
+        Ok(e) => e,
         Err(e) => {
-            eprintln!("get_blocked_by error: {e:#}");
+            log::error!("get_epic error: {e:#}");
+            return tool_error("Failed to get task");
+        }
+    };
+
+    let project = match epic.as_ref().map(|e| project_db::get_project(db, &e.project_id)) {
+        Some(Ok(p)) => p,
+        Some(Err(e)) => {
+            log::error!("get_project error: {e:#}");
             return tool_error("Failed to get task");
         }
+        None => None,
     };
 
-    tool_result(&json!({ "task": task, "blockers": blockers, "blocks": blocks }))
+    let mut response = json!({
+        "task": task,
+        "epic": epic.map(|e| json!({ "id": e.id, "short_id": e.short_id, "title": e.title })),
+        "project": project.map(|p| json!({ "id": p.id, "name": p.name })),
+        "blockers": blockers,
+        "blocks": blocks,
+    });
+
+    if optional_bool(args, "neighbors") {
+        match neighbors(db, &DependencyType::Task, &id) {
+            Ok(n) => response["neighbors"] = n,
+            Err(e) => {
+                log::error!("get_task error: {e:#}");
+                return tool_error("Failed to get task");
+            }
+        }
+    }
+
+    if optional_bool(args, "include_blocked") {
+        match dep_db::is_transitively_blocked(db, &DependencyType::Task, &id) {
+            Ok(b) => response["blocked"] = json!(b),
+            Err(e) => {
+                log::error!("get_task error: {e:#}");
+                return tool_error("Failed to get task");
+            }
+        }
+    }
+
+    tool_result(ctx, &response)
 }
 
-pub(super) fn handle_update_task(args: &Value, db: &Database, default_project_id: Option<&str>) -> Value {
+pub(super) fn handle_update_task(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    if let Err(e) = check_text_limits(ctx, args) {
+        return e;
+    }
     let id = match require_str(args, "id") {
         Ok(v) => v,
         Err(e) => return e,
     };
-    let id = match task_db::resolve_task_id(db, &id, default_project_id) {
+    let project_id = resolve_optional_project_id(args, default_project_id);
+    let id = match task_db::resolve_task_id(db, &id, project_id.as_deref()) {
         Ok(v) => v,
         Err(e) => return tool_error(&e.to_string()),
     };
@@ -157,34 +329,58 @@ pub(super) fn handle_update_task(args: &Value, db: &Database, default_project_id
         Some(s) => Some(Some(s)),
         None => None,
     };
+    let assignee = match optional_str(args, "assignee") {
+        Some(s) if s.is_empty() => Some(None),
+        Some(s) => Some(Some(s)),
+        None => None,
+    };
 
     let input = UpdateTaskInput {
         title: optional_str(args, "title"),
         description: optional_str(args, "description"),
         status,
         session_id,
+        assignee,
     };
 
-    match task_db::update_task(db, &id, input) {
-        Ok(task) => tool_result(&task),
+    match task_db::update_task(db, &id, input, actor(args)) {
+        Ok(task) => tool_result(ctx, &task),
         Err(e) => {
             let msg = e.to_string();
             if msg.contains("not found") {
                 tool_error(&format!("Task not found: {id}"))
             } else {
-                eprintln!("update_task error: {e:#}");
+                log::error!("update_task error: {e:#}");
                 tool_error("Failed to update task")
             }
         }
     }
 }
 
-pub(super) fn handle_delete_task(args: &Value, db: &Database, default_project_id: Option<&str>) -> Value {
+pub(super) fn handle_rename_task(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    match rename_args(args, "title") {
+        Ok(update) => handle_update_task(&update, db, ctx, default_project_id),
+        Err(e) => e,
+    }
+}
+
+pub(super) fn handle_delete_task(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
     let id = match require_str(args, "id") {
         Ok(v) => v,
         Err(e) => return e,
     };
-    let id = match task_db::resolve_task_id(db, &id, default_project_id) {
+    let project_id = resolve_optional_project_id(args, default_project_id);
+    let id = match task_db::resolve_task_id(db, &id, project_id.as_deref()) {
         Ok(v) => v,
         Err(e) => return tool_error(&e.to_string()),
     };
@@ -195,18 +391,217 @@ pub(super) fn handle_delete_task(args: &Value, db: &Database, default_project_id
         .and_then(|t| t.short_id);
 
     match task_db::delete_task(db, &id) {
-        Ok(true) => tool_result(&json!({ "deleted": true, "id": id, "short_id": short_id })),
+        Ok(true) => tool_result(ctx, &json!({ "deleted": true, "id": id, "short_id": short_id })),
         Ok(false) => tool_error(&format!("Task not found: {id}")),
         Err(e) => {
-            eprintln!("delete_task error: {e:#}");
+            log::error!("delete_task error: {e:#}");
             tool_error("Failed to delete task")
         }
     }
 }
 
+/// `archive_task` / `unarchive_task`: set the task's archived flag.
+pub(super) fn handle_set_task_archived(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+    archived: bool,
+) -> Value {
+    let id = match require_str(args, "id") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let project_id = resolve_optional_project_id(args, default_project_id);
+    let id = match task_db::resolve_task_id(db, &id, project_id.as_deref()) {
+        Ok(v) => v,
+        Err(e) => return tool_error(&e.to_string()),
+    };
+
+    match task_db::set_task_archived(db, &id, archived, actor(args)) {
+        Ok(task) => tool_result(ctx, &task),
+        Err(e) => {
+            log::error!("set_task_archived error: {e:#}");
+            tool_error("Failed to archive task")
+        }
+    }
+}
+
+pub(super) fn handle_claim_task(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    let session_id = match require_str(args, "session_id") {
+        Ok(v) if v.is_empty() => return tool_error("session_id must not be empty"),
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    change_task_session(args, db, ctx, default_project_id, "claim_task", |db, id| {
+        task_db::claim_task(db, id, &session_id, actor(args))
+    })
+}
+
+pub(super) fn handle_release_task(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    let session_id = optional_str(args, "session_id").filter(|s| !s.is_empty());
+    change_task_session(args, db, ctx, default_project_id, "release_task", |db, id| {
+        task_db::release_task(db, id, session_id.as_deref(), actor(args))
+    })
+}
+
+/// Shared body of `claim_task` and `release_task`: resolve `id`, apply the
+/// change and report a claim held by another session as a conflict.
+fn change_task_session(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+    tool: &str,
+    change: impl FnOnce(&Database, &str) -> anyhow::Result<task_db::SessionChange>,
+) -> Value {
+    let raw_id = match require_str(args, "id") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let project_id = resolve_optional_project_id(args, default_project_id);
+    let id = match task_db::resolve_task_id(db, &raw_id, project_id.as_deref()) {
+        Ok(v) => v,
+        Err(e) => return tool_error(&e.to_string()),
+    };
+
+    match change(db, &id) {
+        Ok(task_db::SessionChange::Applied(task)) => tool_result(ctx, &task),
+        Ok(task_db::SessionChange::HeldBy(holder)) => tool_error(&format!(
+            "CONFLICT: Task {raw_id} is claimed by session {holder}"
+        )),
+        Err(e) if e.to_string().contains("not found") => {
+            tool_error(&format!("Task not found: {id}"))
+        }
+        Err(e) => {
+            log::error!("{tool} error: {e:#}");
+            tool_error("Failed to update task session")
+        }
+    }
+}
+
+pub(super) fn handle_reopen_task(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    let raw_id = match require_str(args, "id") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let status = match parse_optional_status::<ItemStatus>(args) {
+        Ok(Some(ItemStatus::Done)) => {
+            return tool_error("Invalid status: done (expected todo or in_progress)");
+        }
+        Ok(s) => s.unwrap_or(ItemStatus::Todo),
+        Err(e) => return e,
+    };
+    let reason = optional_str(args, "reason").filter(|r| !r.trim().is_empty());
+    let project_id = resolve_optional_project_id(args, default_project_id);
+    let id = match task_db::resolve_task_id(db, &raw_id, project_id.as_deref()) {
+        Ok(v) => v,
+        Err(e) => return tool_error(&e.to_string()),
+    };
+
+    match task_db::reopen_task(db, &id, &status, reason.as_deref(), actor(args)) {
+        Ok(task_db::Reopen::Reopened(task)) => {
+            tool_result(ctx, &json!({ "task": task, "reopened_from": "done", "reason": reason }))
+        }
+        Ok(task_db::Reopen::NotDone(current)) => tool_error(&format!(
+            "Task {raw_id} is not done (status: {}); only done tasks can be reopened",
+            current.as_str()
+        )),
+        Err(e) if e.to_string().contains("not found") => {
+            tool_error(&format!("Task not found: {id}"))
+        }
+        Err(e) => {
+            log::error!("reopen_task error: {e:#}");
+            tool_error("Failed to reopen task")
+        }
+    }
+}
+
+pub(super) fn handle_complete_chain(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    let raw_id = match require_str(args, "id") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let project_id = resolve_optional_project_id(args, default_project_id);
+    let id = match task_db::resolve_task_id(db, &raw_id, project_id.as_deref()) {
+        Ok(v) => v,
+        Err(e) => return tool_error(&e.to_string()),
+    };
+
+    match task_db::complete_chain(db, &id, actor(args)) {
+        Ok(completed) => {
+            tool_result(ctx, &json!({ "count": completed.len(), "completed": completed }))
+        }
+        Err(e) if e.to_string().contains("not found") => {
+            tool_error(&format!("Task not found: {id}"))
+        }
+        Err(e) => {
+            log::error!("complete_chain error: {e:#}");
+            tool_error("Failed to complete chain")
+        }
+    }
+}
+
+pub(super) fn handle_get_next_task(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    let project_id = match resolve_project_id(args, default_project_id) {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    if let Err(e) = validate_project_exists(db, &project_id) {
+        return e;
+    }
+
+    let (task, reason) = match task_db::next_task(db, &project_id) {
+        Ok(task_db::NextTask::Ready { task, epic_in_progress }) => {
+            let reason = if epic_in_progress {
+                "Oldest ready task in an in-progress epic"
+            } else {
+                "Oldest ready task"
+            };
+            (Some(task), reason.to_string())
+        }
+        Ok(task_db::NextTask::AllBlocked { open }) => (
+            None,
+            format!("All {open} todo tasks are blocked; finish their blockers first"),
+        ),
+        Ok(task_db::NextTask::NoOpenTasks) => (None, "No todo tasks in this project".to_string()),
+        Err(e) => {
+            log::error!("get_next_task error: {e:#}");
+            return tool_error("Failed to pick the next task");
+        }
+    };
+
+    tool_result(ctx, &json!({ "task": task, "reason": reason }))
+}
+
 #[cfg(test)]
 mod tests {
-    use super::super::dispatch_tool;
+    use super::super::{dispatch_tool, ToolContext};
     use crate::db::dependency as dep_db;
     use crate::db::Database;
     use crate::models::dependency::{AddDependencyInput, DependencyType};
@@ -230,6 +625,7 @@ mod tests {
             "create_project",
             &json!({"name": "Test Project", "description": "for task tests"}),
             db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -242,6 +638,7 @@ mod tests {
             "create_epic",
             &json!({"project_id": project_id, "title": "Test Epic", "description": "for task tests"}),
             db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -261,6 +658,7 @@ mod tests {
             "create_task",
             &json!({"epic_id": epic_id, "title": "My Task", "description": "desc"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -281,6 +679,7 @@ mod tests {
             "create_task",
             &json!({"title": "T", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -300,6 +699,7 @@ mod tests {
             "create_task",
             &json!({"epic_id": epic_id, "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -317,6 +717,7 @@ mod tests {
             "create_task",
             &json!({"epic_id": "nonexistent", "title": "T", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -327,12 +728,70 @@ mod tests {
             .contains("not found"));
     }
 
+    #[test]
+    fn test_create_task_with_depends_on() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let epic_id = create_test_epic(&db, &project_id);
+        let create = |title: &str, extra: Value| {
+            let mut args = json!({"epic_id": epic_id, "title": title, "description": "d"});
+            args.as_object_mut().unwrap().extend(extra.as_object().unwrap().clone());
+            dispatch_tool("create_task", &args, &db, &ToolContext::default(), None).unwrap()
+        };
+        let first = parse_response(&create("First", json!({})));
+        let second = parse_response(&create("Second", json!({})));
+
+        let result = create(
+            "Third",
+            json!({"project_id": project_id, "depends_on": [first["short_id"], second["id"]]}),
+        );
+        assert!(result.get("isError").is_none(), "{result}");
+        let third = parse_response(&result);
+        assert_eq!(third["title"], "Third");
+        let third_id = third["id"].as_str().unwrap();
+        let deps = dep_db::get_blockers(&db, &DependencyType::Task, third_id).unwrap();
+        let mut blockers: Vec<&str> = deps.iter().map(|d| d.blocker_id.as_str()).collect();
+        blockers.sort();
+        let mut expected = [first["id"].as_str().unwrap(), second["id"].as_str().unwrap()];
+        expected.sort();
+        assert_eq!(blockers, expected);
+    }
+
+    #[test]
+    fn test_create_task_with_invalid_blocker_rolls_back() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let epic_id = create_test_epic(&db, &project_id);
+
+        for depends_on in [json!(["01J00000000000000000000000"]), json!(["E9-T1"]), json!("E1")] {
+            let result = dispatch_tool(
+                "create_task",
+                &json!({"epic_id": epic_id, "title": "T", "description": "d", "depends_on": depends_on}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap();
+            assert_eq!(result["isError"], true, "{depends_on}");
+        }
+        let result = dispatch_tool(
+            "list_tasks",
+            &json!({"epic_id": epic_id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(parse_response(&result)["tasks"].as_array().unwrap().len(), 0);
+    }
+
     // --- list_tasks tests ---
 
     #[test]
     fn test_list_tasks_empty() {
         let (db, _dir) = test_db();
-        let result = dispatch_tool("list_tasks", &json!({}), &db, None).unwrap();
+        let result =
+            dispatch_tool("list_tasks", &json!({}), &db, &ToolContext::default(), None).unwrap();
         assert!(result.get("isError").is_none());
         let parsed = parse_response(&result);
         assert!(parsed["tasks"].as_array().unwrap().is_empty());
@@ -348,16 +807,19 @@ mod tests {
             "create_task",
             &json!({"epic_id": epic_id, "title": "T1", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         );
         dispatch_tool(
             "create_task",
             &json!({"epic_id": epic_id, "title": "T2", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         );
 
-        let result = dispatch_tool("list_tasks", &json!({}), &db, None).unwrap();
+        let result =
+            dispatch_tool("list_tasks", &json!({}), &db, &ToolContext::default(), None).unwrap();
         let parsed = parse_response(&result);
         let tasks = parsed["tasks"].as_array().unwrap();
         assert_eq!(tasks.len(), 2);
@@ -374,130 +836,1242 @@ mod tests {
             "create_task",
             &json!({"epic_id": e1, "title": "T1", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         );
         dispatch_tool(
             "create_task",
             &json!({"epic_id": e2, "title": "T2", "description": "d"}),
             &db,
+            &ToolContext::default(),
+            None,
+        );
+
+        let result = dispatch_tool(
+            "list_tasks",
+            &json!({"epic_id": e1}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        let parsed = parse_response(&result);
+        let tasks = parsed["tasks"].as_array().unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0]["title"], "T1");
+    }
+
+    #[test]
+    fn test_list_tasks_status_filter() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let epic_id = create_test_epic(&db, &project_id);
+
+        let create_result = dispatch_tool(
+            "create_task",
+            &json!({"epic_id": epic_id, "title": "T1", "description": "d"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        let task = parse_response(&create_result);
+        let task_id = task["id"].as_str().unwrap();
+
+        dispatch_tool(
+            "update_task",
+            &json!({"id": task_id, "status": "in_progress"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        );
+
+        dispatch_tool(
+            "create_task",
+            &json!({"epic_id": epic_id, "title": "T2", "description": "d"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        );
+
+        let result = dispatch_tool(
+            "list_tasks",
+            &json!({"status": "in_progress"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        let parsed = parse_response(&result);
+        let tasks = parsed["tasks"].as_array().unwrap();
+        assert_eq!(tasks.len(), 1);
+        assert_eq!(tasks[0]["title"], "T1");
+    }
+
+    #[test]
+    fn test_set_and_clear_assignee() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let epic_id = create_test_epic(&db, &project_id);
+
+        let created = parse_response(
+            &dispatch_tool(
+                "create_task",
+                &json!({"epic_id": epic_id, "title": "T1", "description": "d", "assignee": "alice"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        assert_eq!(created["assignee"], "alice");
+        let id = created["id"].as_str().unwrap();
+
+        let reassigned = parse_response(
+            &dispatch_tool(
+                "update_task",
+                &json!({"id": id, "assignee": "bob"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        assert_eq!(reassigned["assignee"], "bob");
+
+        // Updating other fields leaves the assignee alone.
+        let renamed = parse_response(
+            &dispatch_tool(
+                "update_task",
+                &json!({"id": id, "title": "T1b"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        assert_eq!(renamed["assignee"], "bob");
+
+        let cleared = parse_response(
+            &dispatch_tool(
+                "update_task",
+                &json!({"id": id, "assignee": ""}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        assert!(cleared["assignee"].is_null());
+    }
+
+    #[test]
+    fn test_list_tasks_assignee_filters() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let epic_id = create_test_epic(&db, &project_id);
+
+        for (title, assignee) in [("A1", Some("alice")), ("A2", Some("alice")), ("B1", Some("bob")), ("U1", None)] {
+            let mut args = json!({"epic_id": epic_id, "title": title, "description": ""});
+            if let Some(a) = assignee {
+                args["assignee"] = json!(a);
+            }
+            dispatch_tool("create_task", &args, &db, &ToolContext::default(), None).unwrap();
+        }
+
+        let titles = |args: Value| -> Vec<String> {
+            let parsed = parse_response(
+                &dispatch_tool("list_tasks", &args, &db, &ToolContext::default(), None).unwrap(),
+            );
+            let mut titles: Vec<String> = parsed["tasks"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|t| t["title"].as_str().unwrap().to_string())
+                .collect();
+            titles.sort();
+            titles
+        };
+
+        assert_eq!(titles(json!({"assignee": "alice"})), vec!["A1", "A2"]);
+        assert_eq!(titles(json!({"assignee": "bob"})), vec!["B1"]);
+        assert_eq!(titles(json!({"unassigned": true})), vec!["U1"]);
+        assert_eq!(titles(json!({})).len(), 4);
+
+        let both = dispatch_tool(
+            "list_tasks",
+            &json!({"assignee": "alice", "unassigned": true}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(both["isError"], true);
+    }
+
+    #[test]
+    fn test_archive_and_unarchive_task() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let epic_id = create_test_epic(&db, &project_id);
+        for title in ["Keep", "Shelve"] {
+            dispatch_tool(
+                "create_task",
+                &json!({"epic_id": epic_id, "title": title, "description": ""}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap();
+        }
+        let list = |args: Value| -> Vec<Value> {
+            let parsed = parse_response(
+                &dispatch_tool("list_tasks", &args, &db, &ToolContext::default(), None).unwrap(),
+            );
+            parsed["tasks"].as_array().unwrap().clone()
+        };
+        let shelve = list(json!({}))
+            .into_iter()
+            .find(|t| t["title"] == "Shelve")
+            .unwrap();
+
+        let archived = dispatch_tool(
+            "archive_task",
+            &json!({"id": shelve["short_id"], "project_id": project_id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(parse_response(&archived)["archived"], true);
+
+        let visible = list(json!({}));
+        assert_eq!(visible.len(), 1);
+        assert_eq!(visible[0]["title"], "Keep");
+        assert!(visible[0].get("archived").is_none());
+        let everything = list(json!({"include_archived": true}));
+        assert_eq!(everything.len(), 2);
+        let marked = everything.iter().find(|t| t["title"] == "Shelve").unwrap();
+        assert_eq!(marked["archived"], true);
+
+        let restored = dispatch_tool(
+            "unarchive_task",
+            &json!({"id": shelve["id"]}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(parse_response(&restored)["archived"], false);
+        assert_eq!(list(json!({})).len(), 2);
+
+        let missing = dispatch_tool(
+            "archive_task",
+            &json!({"id": "nope"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(missing["isError"], true);
+    }
+
+    #[test]
+    fn test_list_tasks_statuses_and_exclude_statuses() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let epic_id = create_test_epic(&db, &project_id);
+        for (title, status) in [("Todo", "todo"), ("Doing", "in_progress"), ("Done", "done")] {
+            let created = parse_response(
+                &dispatch_tool(
+                    "create_task",
+                    &json!({"epic_id": epic_id, "title": title, "description": ""}),
+                    &db,
+                    &ToolContext::default(),
+                    None,
+                )
+                .unwrap(),
+            );
+            dispatch_tool(
+                "update_task",
+                &json!({"id": created["id"], "status": status}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap();
+        }
+
+        let titles = |args: Value| -> Vec<String> {
+            let parsed = parse_response(
+                &dispatch_tool("list_tasks", &args, &db, &ToolContext::default(), None).unwrap(),
+            );
+            let mut titles: Vec<String> = parsed["tasks"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|t| t["title"].as_str().unwrap().to_string())
+                .collect();
+            titles.sort();
+            titles
+        };
+
+        assert_eq!(titles(json!({"statuses": ["todo", "done"]})), vec!["Done", "Todo"]);
+        assert_eq!(titles(json!({"exclude_statuses": ["done"]})), vec!["Doing", "Todo"]);
+        assert_eq!(
+            titles(json!({"statuses": ["todo", "in_progress"], "exclude_statuses": ["todo"]})),
+            vec!["Doing"]
+        );
+        assert_eq!(titles(json!({"status": "done", "exclude_statuses": []})), vec!["Done"]);
+        assert_eq!(titles(json!({"statuses": []})).len(), 3);
+
+        for args in [
+            json!({"status": "done", "statuses": ["todo"]}),
+            json!({"statuses": ["blocked"]}),
+            json!({"exclude_statuses": "done"}),
+        ] {
+            let result =
+                dispatch_tool("list_tasks", &args, &db, &ToolContext::default(), None).unwrap();
+            assert_eq!(result["isError"], true, "{args}");
+        }
+    }
+
+    #[test]
+    fn test_list_tasks_blocked_only_and_ready_only() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let epic_id = create_test_epic(&db, &project_id);
+        let task = |title: &str, status: &str| {
+            let result = dispatch_tool(
+                "create_task",
+                &json!({"epic_id": epic_id, "title": title, "description": "d"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap();
+            let id = parse_response(&result)["id"].as_str().unwrap().to_string();
+            dispatch_tool(
+                "update_task",
+                &json!({"id": id, "status": status}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap();
+            id
+        };
+        let root = task("Root", "todo");
+        let direct = task("Direct", "todo");
+        let indirect = task("Indirect", "todo");
+        let started = task("Started", "in_progress");
+        let finished = task("Finished", "done");
+        let unblocked = task("Unblocked", "todo");
+        let blocks = |blocker: &str, blocked: &str| {
+            dep_db::add_dependency(
+                &db,
+                AddDependencyInput {
+                    blocker_type: DependencyType::Task,
+                    blocker_id: blocker.to_string(),
+                    blocked_type: DependencyType::Task,
+                    blocked_id: blocked.to_string(),
+                },
+                None,
+            )
+            .unwrap();
+        };
+        blocks(&root, &direct);
+        blocks(&direct, &indirect);
+        blocks(&root, &started);
+        blocks(&finished, &unblocked);
+
+        let titles = |args: Value| {
+            let result =
+                dispatch_tool("list_tasks", &args, &db, &ToolContext::default(), None).unwrap();
+            let mut titles: Vec<String> = parse_response(&result)["tasks"]
+                .as_array()
+                .unwrap()
+                .iter()
+                .map(|t| t["title"].as_str().unwrap().to_string())
+                .collect();
+            titles.sort();
+            titles
+        };
+        assert_eq!(titles(json!({"blocked_only": true})), ["Direct", "Indirect", "Started"]);
+        assert_eq!(titles(json!({"ready_only": true})), ["Root", "Unblocked"]);
+
+        let result = dispatch_tool(
+            "list_tasks",
+            &json!({"ready_only": true, "include_blocked": true, "max_items": 1}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        let listed = parse_response(&result)["tasks"].as_array().unwrap().clone();
+        assert_eq!(listed[0]["blocked"], false);
+        assert_eq!(listed[1]["total"], 2, "paging counts only the matching tasks");
+
+        let result = dispatch_tool(
+            "list_tasks",
+            &json!({"blocked_only": true, "ready_only": true}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+    }
+
+    #[test]
+    fn test_list_tasks_truncates_at_max_items_with_a_marker() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let epic_id = create_test_epic(&db, &project_id);
+        for i in 0..5 {
+            dispatch_tool(
+                "create_task",
+                &json!({"epic_id": epic_id, "title": format!("T{i}"), "description": ""}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap();
+        }
+        let list = |args: Value| -> Vec<Value> {
+            let result =
+                dispatch_tool("list_tasks", &args, &db, &ToolContext::default(), None).unwrap();
+            assert!(result.get("isError").is_none(), "{result}");
+            parse_response(&result)["tasks"].as_array().unwrap().clone()
+        };
+
+        // Under the cap nothing changes.
+        let all = list(json!({}));
+        assert_eq!(all.len(), 5);
+        assert!(all.iter().all(|t| t.get("truncated").is_none()));
+
+        let page = list(json!({"max_items": 2}));
+        assert_eq!(page.len(), 3);
+        assert_eq!(page[0]["title"], all[0]["title"]);
+        let marker = &page[2];
+        assert_eq!(marker["truncated"], true);
+        assert_eq!(marker["returned"], 2);
+        assert_eq!(marker["total"], 5);
+        assert_eq!(marker["next_offset"], 2);
+
+        let page = list(json!({"max_items": 2, "offset": 2}));
+        assert_eq!(page[0]["title"], all[2]["title"]);
+        assert_eq!(page[2]["next_offset"], 4);
+        let last = list(json!({"max_items": 2, "offset": 4}));
+        assert_eq!(last.len(), 1);
+        assert_eq!(last[0]["title"], all[4]["title"]);
+
+        // The server-wide cap applies when max_items is omitted.
+        let ctx = ToolContext { max_list_items: 3, ..Default::default() };
+        let result = dispatch_tool("list_tasks", &json!({}), &db, &ctx, None).unwrap();
+        let capped = parse_response(&result)["tasks"].as_array().unwrap().clone();
+        assert_eq!(capped.len(), 4);
+        assert_eq!(capped[3]["total"], 5);
+
+        for args in [json!({"max_items": 0}), json!({"offset": -1}), json!({"max_items": "2"})] {
+            let result =
+                dispatch_tool("list_tasks", &args, &db, &ToolContext::default(), None).unwrap();
+            assert_eq!(result["isError"], true, "{args}");
+        }
+    }
+
+    #[test]
+    fn test_list_tasks_between_filters() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let epic_id = create_test_epic(&db, &project_id);
+        for (title, at) in [("Before", "2026-02-27 10:00:00"), ("During", "2026-03-02 10:00:00")] {
+            let args = json!({"epic_id": epic_id, "title": title, "description": ""});
+            let created = parse_response(
+                &dispatch_tool("create_task", &args, &db, &ToolContext::default(), None).unwrap(),
+            );
+            db.conn()
+                .execute(
+                    "UPDATE tasks SET created_at = ?1, updated_at = ?1 WHERE id = ?2",
+                    [at, created["id"].as_str().unwrap()],
+                )
+                .unwrap();
+        }
+
+        for args in [
+            json!({"created_between": ["2026-03-01", "2026-03-02"]}),
+            json!({"updated_between": "2026-03-01,2026-03-02T23:00:00Z"}),
+        ] {
+            let parsed = parse_response(
+                &dispatch_tool("list_tasks", &args, &db, &ToolContext::default(), None).unwrap(),
+            );
+            let tasks = parsed["tasks"].as_array().unwrap();
+            assert_eq!(tasks.len(), 1, "{args}");
+            assert_eq!(tasks[0]["title"], "During");
+        }
+
+        for (args, expected) in [
+            (json!({"created_between": ["2026-03-05", "2026-03-01"]}), "after"),
+            (json!({"created_between": ["March 1st", "2026-03-02"]}), "invalid date"),
+            (json!({"updated_between": ["2026-03-01"]}), "expected [start, end]"),
+        ] {
+            let result =
+                dispatch_tool("list_tasks", &args, &db, &ToolContext::default(), None).unwrap();
+            assert_eq!(result["isError"], true, "{args}");
+            let text = result["content"][0]["text"].as_str().unwrap();
+            assert!(text.contains(expected), "{text}");
+        }
+    }
+
+    #[test]
+    fn test_list_tasks_invalid_status() {
+        let (db, _dir) = test_db();
+        let result = dispatch_tool(
+            "list_tasks",
+            &json!({"status": "bogus"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+        assert!(result["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("Invalid status"));
+    }
+
+    // --- get_task tests ---
+
+    #[test]
+    fn test_get_task_success_with_dependency_info() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let epic_id = create_test_epic(&db, &project_id);
+
+        let create_result = dispatch_tool(
+            "create_task",
+            &json!({"epic_id": epic_id, "title": "Get Me", "description": "desc"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        let task = parse_response(&create_result);
+        let task_id = task["id"].as_str().unwrap();
+
+        let result = dispatch_tool(
+            "get_task",
+            &json!({"id": task_id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert!(result.get("isError").is_none());
+        let data = parse_response(&result);
+        assert_eq!(data["task"]["title"], "Get Me");
+        assert!(data["blockers"].as_array().unwrap().is_empty());
+        assert!(data["blocks"].as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_get_task_includes_epic_and_project_context() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let epic_id = create_test_epic(&db, &project_id);
+
+        let create_result = dispatch_tool(
+            "create_task",
+            &json!({"epic_id": epic_id, "title": "Breadcrumb", "description": "d"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        let task_id = parse_response(&create_result)["id"].as_str().unwrap().to_string();
+
+        let result = dispatch_tool(
+            "get_task",
+            &json!({"id": task_id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        let data = parse_response(&result);
+        assert_eq!(data["task"]["id"], task_id);
+        assert_eq!(data["epic"]["id"], epic_id);
+        assert_eq!(data["epic"]["short_id"], "E1");
+        assert_eq!(data["epic"]["title"], "Test Epic");
+        assert_eq!(data["project"]["id"], project_id);
+        assert_eq!(data["project"]["name"], "Test Project");
+    }
+
+    #[test]
+    fn test_short_id_ambiguous_without_project_context() {
+        let (db, _dir) = test_db();
+        let mut task_ids = Vec::new();
+        let mut project_ids = Vec::new();
+        for _ in 0..2 {
+            let project_id = create_test_project(&db);
+            let epic_id = create_test_epic(&db, &project_id);
+            let result = dispatch_tool(
+                "create_task",
+                &json!({"epic_id": epic_id, "title": "T", "description": "d"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap();
+            task_ids.push(parse_response(&result)["id"].as_str().unwrap().to_string());
+            project_ids.push(project_id);
+        }
+
+        let result = dispatch_tool(
+            "get_task",
+            &json!({"id": "E1-T1"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+        let text = result["content"][0]["text"].as_str().unwrap();
+        assert!(text.starts_with("CONFLICT"), "{text}");
+        assert!(task_ids.iter().all(|id| text.contains(id.as_str())), "{text}");
+
+        let result = dispatch_tool(
+            "update_task",
+            &json!({"id": "E1-T1", "status": "done"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+
+        // An explicit project_id disambiguates, and overrides the default.
+        let result = dispatch_tool(
+            "get_task",
+            &json!({"id": "E1-T1", "project_id": project_ids[1]}),
+            &db,
+            &ToolContext::default(),
+            Some(&project_ids[0]),
+        )
+        .unwrap();
+        assert!(result.get("isError").is_none());
+        assert_eq!(parse_response(&result)["task"]["id"], task_ids[1].as_str());
+
+        let result = dispatch_tool(
+            "rename_task",
+            &json!({"id": "E1-T1", "title": "Renamed", "project_id": project_ids[0]}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(parse_response(&result)["id"], task_ids[0].as_str());
+        assert_eq!(parse_response(&result)["title"], "Renamed");
+
+        let result = dispatch_tool(
+            "add_dependency",
+            &json!({
+                "blocker_type": "epic", "blocker_id": "E1",
+                "blocked_type": "task", "blocked_id": "E1-T1",
+                "project_id": project_ids[0],
+            }),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert!(result.get("isError").is_none(), "{result}");
+    }
+
+    fn create_claim_test_task(db: &Database) -> String {
+        let project_id = create_test_project(db);
+        let epic_id = create_test_epic(db, &project_id);
+        let result = dispatch_tool(
+            "create_task",
+            &json!({"epic_id": epic_id, "title": "Shared", "description": "d"}),
+            db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        parse_response(&result)["id"].as_str().unwrap().to_string()
+    }
+
+    #[test]
+    fn test_claim_free_task_and_conflict() {
+        let (db, _dir) = test_db();
+        let task_id = create_claim_test_task(&db);
+
+        let result = dispatch_tool(
+            "claim_task",
+            &json!({"id": task_id, "session_id": "agent-a"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert!(result.get("isError").is_none());
+        assert_eq!(parse_response(&result)["session_id"], "agent-a");
+
+        // Claiming again from the same session is fine.
+        let result = dispatch_tool(
+            "claim_task",
+            &json!({"id": "E1-T1", "session_id": "agent-a"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert!(result.get("isError").is_none());
+
+        let result = dispatch_tool(
+            "claim_task",
+            &json!({"id": "E1-T1", "session_id": "agent-b"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+        let text = result["content"][0]["text"].as_str().unwrap();
+        assert!(text.starts_with("CONFLICT"), "{text}");
+        assert!(text.contains("agent-a"), "{text}");
+
+        let task = dispatch_tool(
+            "get_task",
+            &json!({"id": task_id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(parse_response(&task)["task"]["session_id"], "agent-a");
+    }
+
+    #[test]
+    fn test_release_task_returns_it_to_unclaimed() {
+        let (db, _dir) = test_db();
+        let task_id = create_claim_test_task(&db);
+        dispatch_tool(
+            "claim_task",
+            &json!({"id": task_id, "session_id": "agent-a"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+
+        // Another session cannot release it.
+        let result = dispatch_tool(
+            "release_task",
+            &json!({"id": task_id, "session_id": "agent-b"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+        assert!(result["content"][0]["text"].as_str().unwrap().contains("agent-a"));
+
+        let result = dispatch_tool(
+            "release_task",
+            &json!({"id": task_id, "session_id": "agent-a"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert!(result.get("isError").is_none());
+        assert!(parse_response(&result)["session_id"].is_null());
+
+        let result = dispatch_tool(
+            "claim_task",
+            &json!({"id": task_id, "session_id": "agent-b"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(parse_response(&result)["session_id"], "agent-b");
+
+        // Without a session_id any claim is cleared.
+        let result = dispatch_tool(
+            "release_task",
+            &json!({"id": task_id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert!(parse_response(&result)["session_id"].is_null());
+    }
+
+    #[test]
+    fn test_claim_task_unknown_or_missing_session() {
+        let (db, _dir) = test_db();
+        let result = dispatch_tool(
+            "claim_task",
+            &json!({"id": "nonexistent", "session_id": "s"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert!(result["content"][0]["text"].as_str().unwrap().contains("not found"));
+
+        let result = dispatch_tool(
+            "claim_task",
+            &json!({"id": "nonexistent"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert!(result["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("Missing required parameter: session_id"));
+    }
+
+    #[test]
+    fn test_get_task_not_found() {
+        let (db, _dir) = test_db();
+        let result = dispatch_tool(
+            "get_task",
+            &json!({"id": "nonexistent"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+        assert!(result["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("not found"));
+    }
+
+    #[test]
+    fn test_get_task_missing_id() {
+        let (db, _dir) = test_db();
+        let result =
+            dispatch_tool("get_task", &json!({}), &db, &ToolContext::default(), None).unwrap();
+        assert_eq!(result["isError"], true);
+        assert!(result["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("Missing required parameter"));
+    }
+
+    // --- update_task tests ---
+
+    #[test]
+    fn test_update_task_success() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let epic_id = create_test_epic(&db, &project_id);
+
+        let create_result = dispatch_tool(
+            "create_task",
+            &json!({"epic_id": epic_id, "title": "Original", "description": "old desc"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        let created = parse_response(&create_result);
+        let id = created["id"].as_str().unwrap();
+
+        let result = dispatch_tool(
+            "update_task",
+            &json!({"id": id, "title": "Renamed", "status": "done"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+
+        assert!(result.get("isError").is_none());
+        let updated = parse_response(&result);
+        assert_eq!(updated["title"], "Renamed");
+        assert_eq!(updated["description"], "old desc");
+        assert_eq!(updated["status"], "done");
+    }
+
+    #[test]
+    fn test_rename_task_changes_only_title() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let epic_id = create_test_epic(&db, &project_id);
+        let created = parse_response(
+            &dispatch_tool(
+                "create_task",
+                &json!({
+                    "epic_id": epic_id,
+                    "title": "Old",
+                    "description": "keep me",
+                    "assignee": "ada",
+                    "session_id": "s-1"
+                }),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        let id = created["id"].as_str().unwrap();
+        dispatch_tool(
+            "update_task",
+            &json!({"id": id, "status": "in_progress"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        );
+
+        // Empty strings would clear these through update_task; rename ignores them.
+        let result = dispatch_tool(
+            "rename_task",
+            &json!({"id": "E1-T1", "title": "New", "assignee": "", "session_id": ""}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert!(result.get("isError").is_none());
+        let renamed = parse_response(&result);
+        assert_eq!(renamed["id"], id);
+        assert_eq!(renamed["title"], "New");
+        assert_eq!(renamed["description"], "keep me");
+        assert_eq!(renamed["status"], "in_progress");
+        assert_eq!(renamed["assignee"], "ada");
+        assert_eq!(renamed["session_id"], "s-1");
+        assert_eq!(renamed["created_at"], created["created_at"]);
+
+        let result = dispatch_tool(
+            "rename_task",
+            &json!({"id": id, "title": ""}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+    }
+
+    #[test]
+    fn test_task_title_and_description_limits() {
+        use crate::settings::{TextLimits, DEFAULT_MAX_TITLE_LENGTH};
+
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let epic_id = create_test_epic(&db, &project_id);
+        let create = |ctx: &ToolContext, title: String, description: String| {
+            let args = json!({"epic_id": epic_id, "title": title, "description": description});
+            dispatch_tool("create_task", &args, &db, ctx, None).unwrap()
+        };
+
+        // The default title limit applies without any settings.
+        let ctx = ToolContext::default();
+        let at_limit = create(&ctx, "t".repeat(DEFAULT_MAX_TITLE_LENGTH), String::new());
+        assert!(at_limit.get("isError").is_none());
+        let over = create(&ctx, "t".repeat(DEFAULT_MAX_TITLE_LENGTH + 1), String::new());
+        assert_eq!(over["isError"], true);
+        assert_eq!(
+            over["content"][0]["text"],
+            format!(
+                "VALIDATION: title is {} characters, over the limit of {}",
+                DEFAULT_MAX_TITLE_LENGTH + 1,
+                DEFAULT_MAX_TITLE_LENGTH
+            )
+        );
+
+        let limits = TextLimits { title: 5, description: 10 };
+        let ctx = ToolContext { text_limits: limits, ..Default::default() };
+        // Characters, not bytes, are counted.
+        let created = create(&ctx, "héllo".to_string(), "é".repeat(10));
+        assert!(created.get("isError").is_none());
+        let id = parse_response(&created)["id"].as_str().unwrap().to_string();
+
+        let over = create(&ctx, "T".to_string(), "d".repeat(11));
+        assert!(over["content"][0]["text"].as_str().unwrap().contains("description is 11"));
+
+        let args = json!({"id": id, "title": "sixsix"});
+        let result = dispatch_tool("update_task", &args, &db, &ctx, None).unwrap();
+        assert_eq!(result["isError"], true);
+        let result = dispatch_tool("rename_task", &args, &db, &ctx, None).unwrap();
+        assert_eq!(result["isError"], true);
+        let task = crate::db::task::get_task(&db, &id).unwrap().unwrap();
+        assert_eq!(task.title, "héllo");
+
+        let listed = parse_response(
+            &dispatch_tool("list_tasks", &json!({}), &db, &ToolContext::default(), None).unwrap(),
+        );
+        assert_eq!(listed["tasks"].as_array().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_actor_is_recorded_as_updated_by() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let epic_id = create_test_epic(&db, &project_id);
+
+        let created = parse_response(
+            &dispatch_tool(
+                "create_task",
+                &json!({"epic_id": epic_id, "title": "T1", "description": "", "actor": "planner"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        assert_eq!(created["updated_by"], "planner");
+        let id = created["id"].as_str().unwrap();
+
+        let args = json!({"id": id, "status": "in_progress", "actor": "coder"});
+        let updated = parse_response(
+            &dispatch_tool("update_task", &args, &db, &ToolContext::default(), None).unwrap(),
+        );
+        assert_eq!(updated["updated_by"], "coder");
+        let result = dispatch_tool(
+            "get_task",
+            &json!({"id": id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(parse_response(&result)["task"]["updated_by"], "coder");
+
+        // A change without an actor clears the attribution.
+        let updated = parse_response(
+            &dispatch_tool(
+                "update_task",
+                &json!({"id": id, "title": "T1b"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        assert!(updated["updated_by"].is_null());
+    }
+
+    #[test]
+    fn test_update_task_not_found() {
+        let (db, _dir) = test_db();
+        let result = dispatch_tool(
+            "update_task",
+            &json!({"id": "nonexistent", "title": "X"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+        assert!(result["content"][0]["text"]
+            .as_str()
+            .unwrap()
+            .contains("not found"));
+    }
+
+    // --- reopen_task tests ---
+
+    #[test]
+    fn test_reopen_task_moves_done_back_and_records_the_reason() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let epic_id = create_test_epic(&db, &project_id);
+        let result = dispatch_tool(
+            "create_task",
+            &json!({"epic_id": epic_id, "title": "Flaky fix", "description": "d"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        let id = parse_response(&result)["id"].as_str().unwrap().to_string();
+        dispatch_tool(
+            "update_task",
+            &json!({"id": id, "status": "done"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+
+        let result = dispatch_tool(
+            "reopen_task",
+            &json!({"id": id, "status": "in_progress", "reason": "Regressed on CI"}),
+            &db,
+            &ToolContext::default(),
             None,
-        );
+        )
+        .unwrap();
+        assert!(result.get("isError").is_none(), "{result}");
+        let data = parse_response(&result);
+        assert_eq!(data["task"]["status"], "in_progress");
+        assert_eq!(data["reopened_from"], "done");
+        assert_eq!(data["reason"], "Regressed on CI");
 
-        let result = dispatch_tool("list_tasks", &json!({"epic_id": e1}), &db, None).unwrap();
-        let parsed = parse_response(&result);
-        let tasks = parsed["tasks"].as_array().unwrap();
-        assert_eq!(tasks.len(), 1);
-        assert_eq!(tasks[0]["title"], "T1");
+        let result = dispatch_tool(
+            "get_timeline",
+            &json!({"project_id": project_id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        let events = parse_response(&result)["events"].as_array().unwrap().clone();
+        let last = events.last().unwrap();
+        assert_eq!(last["verb"], "moved to in_progress");
+        assert_eq!(last["reason"], "Regressed on CI");
+        assert!(events[events.len() - 2].get("reason").is_none());
     }
 
     #[test]
-    fn test_list_tasks_status_filter() {
+    fn test_reopen_task_refuses_tasks_that_are_not_done() {
         let (db, _dir) = test_db();
         let project_id = create_test_project(&db);
         let epic_id = create_test_epic(&db, &project_id);
-
-        let create_result = dispatch_tool(
+        let result = dispatch_tool(
             "create_task",
-            &json!({"epic_id": epic_id, "title": "T1", "description": "d"}),
+            &json!({"epic_id": epic_id, "title": "Open", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
-        let task = parse_response(&create_result);
-        let task_id = task["id"].as_str().unwrap();
+        let id = parse_response(&result)["id"].as_str().unwrap().to_string();
 
-        dispatch_tool(
-            "update_task",
-            &json!({"id": task_id, "status": "in_progress"}),
+        let result = dispatch_tool(
+            "reopen_task",
+            &json!({"id": id, "reason": "why"}),
             &db,
+            &ToolContext::default(),
             None,
-        );
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+        let text = result["content"][0]["text"].as_str().unwrap();
+        assert!(text.contains("not done (status: todo)"), "{text}");
 
-        dispatch_tool(
-            "create_task",
-            &json!({"epic_id": epic_id, "title": "T2", "description": "d"}),
+        let result = dispatch_tool(
+            "reopen_task",
+            &json!({"id": id, "status": "done"}),
             &db,
+            &ToolContext::default(),
             None,
-        );
-
-        let result = dispatch_tool("list_tasks", &json!({"status": "in_progress"}), &db, None).unwrap();
-        let parsed = parse_response(&result);
-        let tasks = parsed["tasks"].as_array().unwrap();
-        assert_eq!(tasks.len(), 1);
-        assert_eq!(tasks[0]["title"], "T1");
-    }
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
 
-    #[test]
-    fn test_list_tasks_invalid_status() {
-        let (db, _dir) = test_db();
-        let result = dispatch_tool("list_tasks", &json!({"status": "bogus"}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "reopen_task",
+            &json!({"id": "E9-T9"}),
+            &db,
+            &ToolContext::default(),
+            Some(&project_id),
+        )
+        .unwrap();
         assert_eq!(result["isError"], true);
-        assert!(result["content"][0]["text"]
-            .as_str()
-            .unwrap()
-            .contains("Invalid status"));
+
+        let history: i64 = db
+            .conn()
+            .query_row("SELECT COUNT(*) FROM status_history", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(history, 0);
     }
 
-    // --- get_task tests ---
+    // --- complete_chain tests ---
 
     #[test]
-    fn test_get_task_success_with_dependency_info() {
+    fn test_complete_chain_finishes_blockers_first() {
         let (db, _dir) = test_db();
         let project_id = create_test_project(&db);
         let epic_id = create_test_epic(&db, &project_id);
+        let ids: Vec<String> = ["Schema", "API", "UI"]
+            .iter()
+            .map(|title| {
+                let result = dispatch_tool(
+                    "create_task",
+                    &json!({"epic_id": epic_id, "title": title, "description": "d"}),
+                    &db,
+                    &ToolContext::default(),
+                    None,
+                )
+                .unwrap();
+                parse_response(&result)["id"].as_str().unwrap().to_string()
+            })
+            .collect();
+        for pair in ids.windows(2) {
+            let args = json!({
+                "blocker_type": "task", "blocker_id": pair[0],
+                "blocked_type": "task", "blocked_id": pair[1],
+            });
+            dispatch_tool("add_dependency", &args, &db, &ToolContext::default(), None).unwrap();
+        }
 
-        let create_result = dispatch_tool(
-            "create_task",
-            &json!({"epic_id": epic_id, "title": "Get Me", "description": "desc"}),
+        let result = dispatch_tool(
+            "complete_chain",
+            &json!({"id": "E1-T3", "actor": "release-bot"}),
             &db,
-            None,
+            &ToolContext::default(),
+            Some(&project_id),
         )
         .unwrap();
-        let task = parse_response(&create_result);
-        let task_id = task["id"].as_str().unwrap();
-
-        let result = dispatch_tool("get_task", &json!({"id": task_id}), &db, None).unwrap();
-        assert!(result.get("isError").is_none());
+        assert!(result.get("isError").is_none(), "{result}");
         let data = parse_response(&result);
-        assert_eq!(data["task"]["title"], "Get Me");
-        assert!(data["blockers"].as_array().unwrap().is_empty());
-        assert!(data["blocks"].as_array().unwrap().is_empty());
-    }
+        assert_eq!(data["count"], 3);
+        let completed = data["completed"].as_array().unwrap();
+        let order: Vec<&str> = completed.iter().map(|t| t["id"].as_str().unwrap()).collect();
+        assert_eq!(order, ids);
+        for task in completed {
+            assert_eq!(task["status"], "done");
+            assert_eq!(task["updated_by"], "release-bot");
+        }
 
-    #[test]
-    fn test_get_task_not_found() {
-        let (db, _dir) = test_db();
-        let result = dispatch_tool("get_task", &json!({"id": "nonexistent"}), &db, None).unwrap();
-        assert_eq!(result["isError"], true);
-        assert!(result["content"][0]["text"]
-            .as_str()
-            .unwrap()
-            .contains("not found"));
-    }
+        // Nothing is left to do on a second run.
+        let result = dispatch_tool(
+            "complete_chain",
+            &json!({"id": ids[2]}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(parse_response(&result)["count"], 0);
 
-    #[test]
-    fn test_get_task_missing_id() {
-        let (db, _dir) = test_db();
-        let result = dispatch_tool("get_task", &json!({}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "complete_chain",
+            &json!({"id": "nope"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         assert_eq!(result["isError"], true);
-        assert!(result["content"][0]["text"]
-            .as_str()
-            .unwrap()
-            .contains("Missing required parameter"));
     }
 
-    // --- update_task tests ---
+    // --- delete_task tests ---
 
     #[test]
-    fn test_update_task_success() {
+    fn test_delete_task_success() {
         let (db, _dir) = test_db();
         let project_id = create_test_project(&db);
         let epic_id = create_test_epic(&db, &project_id);
 
         let create_result = dispatch_tool(
             "create_task",
-            &json!({"epic_id": epic_id, "title": "Original", "description": "old desc"}),
+            &json!({"epic_id": epic_id, "title": "To Delete", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -505,27 +2079,37 @@ mod tests {
         let id = created["id"].as_str().unwrap();
 
         let result = dispatch_tool(
-            "update_task",
-            &json!({"id": id, "title": "Renamed", "status": "done"}),
+            "delete_task",
+            &json!({"id": id}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
-
         assert!(result.get("isError").is_none());
-        let updated = parse_response(&result);
-        assert_eq!(updated["title"], "Renamed");
-        assert_eq!(updated["description"], "old desc");
-        assert_eq!(updated["status"], "done");
+        let data = parse_response(&result);
+        assert_eq!(data["deleted"], true);
+
+        // Verify it's gone
+        let get_result = dispatch_tool(
+            "get_task",
+            &json!({"id": id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(get_result["isError"], true);
     }
 
     #[test]
-    fn test_update_task_not_found() {
+    fn test_delete_task_not_found() {
         let (db, _dir) = test_db();
         let result = dispatch_tool(
-            "update_task",
-            &json!({"id": "nonexistent", "title": "X"}),
+            "delete_task",
+            &json!({"id": "nonexistent"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -536,44 +2120,113 @@ mod tests {
             .contains("not found"));
     }
 
-    // --- delete_task tests ---
+    // --- get_next_task tests ---
 
     #[test]
-    fn test_delete_task_success() {
+    fn test_get_next_task_picks_ready_task_or_explains() {
         let (db, _dir) = test_db();
         let project_id = create_test_project(&db);
         let epic_id = create_test_epic(&db, &project_id);
 
-        let create_result = dispatch_tool(
-            "create_task",
-            &json!({"epic_id": epic_id, "title": "To Delete", "description": "d"}),
+        let result = dispatch_tool(
+            "get_next_task",
+            &json!({"project_id": project_id}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
-        let created = parse_response(&create_result);
-        let id = created["id"].as_str().unwrap();
-
-        let result = dispatch_tool("delete_task", &json!({"id": id}), &db, None).unwrap();
-        assert!(result.get("isError").is_none());
         let data = parse_response(&result);
-        assert_eq!(data["deleted"], true);
+        assert!(data["task"].is_null());
+        assert!(data["reason"].as_str().unwrap().contains("No todo tasks"));
+
+        let blocker = parse_response(
+            &dispatch_tool(
+                "create_task",
+                &json!({"epic_id": epic_id, "title": "Blocker", "description": "d"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        let blocked = parse_response(
+            &dispatch_tool(
+                "create_task",
+                &json!({"epic_id": epic_id, "title": "Blocked", "description": "d"}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        dep_db::add_dependency(
+            &db,
+            AddDependencyInput {
+                blocker_type: DependencyType::Task,
+                blocker_id: blocker["id"].as_str().unwrap().to_string(),
+                blocked_type: DependencyType::Task,
+                blocked_id: blocked["id"].as_str().unwrap().to_string(),
+            },
+            None,
+        )
+        .unwrap();
 
-        // Verify it's gone
-        let get_result = dispatch_tool("get_task", &json!({"id": id}), &db, None).unwrap();
-        assert_eq!(get_result["isError"], true);
+        let data = parse_response(
+            &dispatch_tool(
+                "get_next_task",
+                &json!({"project_id": project_id}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        assert_eq!(data["task"]["title"], "Blocker");
+
+        // With the blocker underway, the only todo task left is blocked.
+        dispatch_tool(
+            "update_task",
+            &json!({"id": blocker["id"], "status": "in_progress"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        );
+        let data = parse_response(
+            &dispatch_tool(
+                "get_next_task",
+                &json!({"project_id": project_id}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        assert!(data["task"].is_null());
+        assert!(data["reason"].as_str().unwrap().contains("blocked"), "{data}");
     }
 
     #[test]
-    fn test_delete_task_not_found() {
+    fn test_get_next_task_requires_known_project() {
         let (db, _dir) = test_db();
-        let result =
-            dispatch_tool("delete_task", &json!({"id": "nonexistent"}), &db, None).unwrap();
+        let result = dispatch_tool(
+            "get_next_task",
+            &json!({}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+        assert_eq!(result["isError"], true);
+        let result = dispatch_tool(
+            "get_next_task",
+            &json!({"project_id": "nope"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         assert_eq!(result["isError"], true);
-        assert!(result["content"][0]["text"]
-            .as_str()
-            .unwrap()
-            .contains("not found"));
     }
 
     #[test]
@@ -586,6 +2239,7 @@ mod tests {
             "create_task",
             &json!({"epic_id": epic_id, "title": "Blocker", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -596,6 +2250,7 @@ mod tests {
             "create_task",
             &json!({"epic_id": epic_id, "title": "Blocked", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -611,17 +2266,125 @@ mod tests {
                 blocked_type: DependencyType::Task,
                 blocked_id: t2_id.to_string(),
             },
+            None,
         )
         .unwrap();
 
         // Delete t1 — should cascade-remove the dependency
-        dispatch_tool("delete_task", &json!({"id": t1_id}), &db, None).unwrap();
+        dispatch_tool(
+            "delete_task",
+            &json!({"id": t1_id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
 
         // Verify dependency is gone
         let blockers = dep_db::get_blockers(&db, &DependencyType::Task, t2_id).unwrap();
         assert!(blockers.is_empty(), "dependency should be cascade-deleted");
     }
 
+    #[test]
+    fn test_include_blocked_reports_transitive_blocking() {
+        let (db, _dir) = test_db();
+        let project_id = create_test_project(&db);
+        let epic_id = create_test_epic(&db, &project_id);
+        let mut ids = Vec::new();
+        for title in ["Root", "Direct", "Transitive", "Free"] {
+            let created = parse_response(
+                &dispatch_tool(
+                    "create_task",
+                    &json!({"epic_id": epic_id, "title": title, "description": ""}),
+                    &db,
+                    &ToolContext::default(),
+                    None,
+                )
+                .unwrap(),
+            );
+            ids.push(created["id"].as_str().unwrap().to_string());
+        }
+        // Root → Direct → Transitive, with Direct already done.
+        for (blocker, blocked) in [(0, 1), (1, 2)] {
+            dep_db::add_dependency(
+                &db,
+                AddDependencyInput {
+                    blocker_type: DependencyType::Task,
+                    blocker_id: ids[blocker].clone(),
+                    blocked_type: DependencyType::Task,
+                    blocked_id: ids[blocked].clone(),
+                },
+                None,
+            )
+            .unwrap();
+        }
+        dispatch_tool(
+            "update_task",
+            &json!({"id": ids[1], "status": "done"}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
+
+        let listed = parse_response(
+            &dispatch_tool(
+                "list_tasks",
+                &json!({"epic_id": epic_id, "include_blocked": true}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        let blocked: std::collections::HashMap<&str, bool> = listed["tasks"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|t| (t["title"].as_str().unwrap(), t["blocked"].as_bool().unwrap()))
+            .collect();
+        assert_eq!(
+            blocked,
+            [("Root", false), ("Direct", true), ("Transitive", true), ("Free", false)].into()
+        );
+
+        // Opt-in: the flag is absent by default.
+        let listed = parse_response(
+            &dispatch_tool(
+                "list_tasks",
+                &json!({"epic_id": epic_id}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        assert!(listed["tasks"][0].get("blocked").is_none());
+
+        let detail = parse_response(
+            &dispatch_tool(
+                "get_task",
+                &json!({"id": ids[2], "include_blocked": true}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        assert_eq!(detail["blocked"], true);
+        let detail = parse_response(
+            &dispatch_tool(
+                "get_task",
+                &json!({"id": ids[3]}),
+                &db,
+                &ToolContext::default(),
+                None,
+            )
+            .unwrap(),
+        );
+        assert!(detail.get("blocked").is_none());
+    }
+
     // --- Short ID integration tests ---
 
     #[test]
@@ -634,6 +2397,7 @@ mod tests {
             "create_task",
             &json!({"epic_id": epic_id, "title": "Short ID Task", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -644,6 +2408,7 @@ mod tests {
             "get_task",
             &json!({"id": "E1-T1"}),
             &db,
+            &ToolContext::default(),
             Some(&project_id),
         )
         .unwrap();
@@ -664,6 +2429,7 @@ mod tests {
             "create_task",
             &json!({"epic_id": "E1", "title": "Via Short ID", "description": "d"}),
             &db,
+            &ToolContext::default(),
             Some(&project_id),
         )
         .unwrap();
@@ -683,6 +2449,7 @@ mod tests {
             "create_task",
             &json!({"epic_id": epic_id, "title": "T1", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         );
 
@@ -690,6 +2457,7 @@ mod tests {
             "list_tasks",
             &json!({"epic_id": "E1"}),
             &db,
+            &ToolContext::default(),
             Some(&project_id),
         )
         .unwrap();
@@ -711,6 +2479,7 @@ mod tests {
             "create_task",
             &json!({"epic_id": epic_id, "title": "Original", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         );
 
@@ -718,6 +2487,7 @@ mod tests {
             "update_task",
             &json!({"id": "E1-T1", "title": "Updated via short ID"}),
             &db,
+            &ToolContext::default(),
             Some(&project_id),
         )
         .unwrap();
@@ -737,6 +2507,7 @@ mod tests {
             "create_task",
             &json!({"epic_id": epic_id, "title": "To Delete", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         );
 
@@ -744,6 +2515,7 @@ mod tests {
             "delete_task",
             &json!({"id": "E1-T1"}),
             &db,
+            &ToolContext::default(),
             Some(&project_id),
         )
         .unwrap();
@@ -766,6 +2538,7 @@ mod tests {
             "create_task",
             &json!({"epic_id": epic_id, "title": "Lifecycle", "description": "testing"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -775,7 +2548,14 @@ mod tests {
         assert_eq!(created["status"], "todo");
 
         // Read
-        let get_result = dispatch_tool("get_task", &json!({"id": id}), &db, None).unwrap();
+        let get_result = dispatch_tool(
+            "get_task",
+            &json!({"id": id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         let data = parse_response(&get_result);
         assert_eq!(data["task"]["title"], "Lifecycle");
 
@@ -784,6 +2564,7 @@ mod tests {
             "list_tasks",
             &json!({"epic_id": epic_id}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -795,6 +2576,7 @@ mod tests {
             "update_task",
             &json!({"id": id, "title": "Updated", "status": "done"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -803,12 +2585,26 @@ mod tests {
         assert_eq!(updated["status"], "done");
 
         // Delete
-        let delete_result = dispatch_tool("delete_task", &json!({"id": id}), &db, None).unwrap();
+        let delete_result = dispatch_tool(
+            "delete_task",
+            &json!({"id": id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         let deleted = parse_response(&delete_result);
         assert_eq!(deleted["deleted"], true);
 
         // Verify gone
-        let get_result = dispatch_tool("get_task", &json!({"id": id}), &db, None).unwrap();
+        let get_result = dispatch_tool(
+            "get_task",
+            &json!({"id": id}),
+            &db,
+            &ToolContext::default(),
+            None,
+        )
+        .unwrap();
         assert_eq!(get_result["isError"], true);
     }
 
@@ -818,6 +2614,7 @@ mod tests {
                 "create_project",
                 &json!({"name": "Project A", "description": "a"}),
                 db,
+                &ToolContext::default(),
                 None,
             )
             .unwrap();
@@ -828,6 +2625,7 @@ mod tests {
             "create_task",
             &json!({"epic_id": eid_a, "title": "Task A", "description": "d"}),
             db,
+            &ToolContext::default(),
             None,
         );
 
@@ -836,6 +2634,7 @@ mod tests {
                 "create_project",
                 &json!({"name": "Project B", "description": "b"}),
                 db,
+                &ToolContext::default(),
                 None,
             )
             .unwrap();
@@ -846,6 +2645,7 @@ mod tests {
             "create_task",
             &json!({"epic_id": eid_b, "title": "Task B", "description": "d"}),
             db,
+            &ToolContext::default(),
             None,
         );
 
@@ -858,14 +2658,28 @@ mod tests {
         let (pid_a, pid_b) = two_projects_with_tasks(&db);
 
         // With default_project_id set to A, list_tasks (no args) returns only A's tasks
-        let result = dispatch_tool("list_tasks", &json!({}), &db, Some(&pid_a)).unwrap();
+        let result = dispatch_tool(
+            "list_tasks",
+            &json!({}),
+            &db,
+            &ToolContext::default(),
+            Some(&pid_a),
+        )
+        .unwrap();
         let parsed = parse_response(&result);
         let tasks = parsed["tasks"].as_array().unwrap();
         assert_eq!(tasks.len(), 1);
         assert_eq!(tasks[0]["title"], "Task A");
 
         // With default_project_id set to B, returns only B's tasks
-        let result = dispatch_tool("list_tasks", &json!({}), &db, Some(&pid_b)).unwrap();
+        let result = dispatch_tool(
+            "list_tasks",
+            &json!({}),
+            &db,
+            &ToolContext::default(),
+            Some(&pid_b),
+        )
+        .unwrap();
         let parsed = parse_response(&result);
         let tasks = parsed["tasks"].as_array().unwrap();
         assert_eq!(tasks.len(), 1);
@@ -882,6 +2696,7 @@ mod tests {
             "list_tasks",
             &json!({"project_id": pid_b}),
             &db,
+            &ToolContext::default(),
             Some(&pid_a),
         )
         .unwrap();
@@ -897,7 +2712,8 @@ mod tests {
         two_projects_with_tasks(&db);
 
         // No default project, no args - returns all tasks
-        let result = dispatch_tool("list_tasks", &json!({}), &db, None).unwrap();
+        let result =
+            dispatch_tool("list_tasks", &json!({}), &db, &ToolContext::default(), None).unwrap();
         let parsed = parse_response(&result);
         assert_eq!(parsed["tasks"].as_array().unwrap().len(), 2);
     }
@@ -912,6 +2728,7 @@ mod tests {
             "create_task",
             &json!({"epic_id": epic_id, "title": "Blocker Task", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -923,6 +2740,7 @@ mod tests {
             "create_task",
             &json!({"epic_id": epic_id, "title": "Blocked Task", "description": "d"}),
             &db,
+            &ToolContext::default(),
             None,
         )
         .unwrap();
@@ -938,10 +2756,12 @@ mod tests {
                 blocked_type: DependencyType::Task,
                 blocked_id: t2_id.to_string(),
             },
+            None,
         )
         .unwrap();
 
-        let result = dispatch_tool("list_tasks", &json!({}), &db, None).unwrap();
+        let result =
+            dispatch_tool("list_tasks", &json!({}), &db, &ToolContext::default(), None).unwrap();
         let parsed = parse_response(&result);
         let tasks = parsed["tasks"].as_array().unwrap();
 
@@ -0,0 +1,218 @@
+use serde_json::{json, Value};
+
+use crate::db::epic as epic_db;
+use crate::db::template as template_db;
+use crate::db::Database;
+use crate::models::{CreateTemplateInput, TemplateTask};
+
+use super::{
+    actor, check_text_limits, optional_str, require_str, resolve_optional_project_id,
+    resolve_project_id, tool_error, tool_result, validate_project_exists, ToolContext,
+};
+
+pub(super) fn handle_create_template(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    let project_id = match resolve_project_id(args, default_project_id) {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let name = match require_str(args, "name") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let Some(requested) = args.get("tasks").and_then(Value::as_array) else {
+        return tool_error("Missing required parameter: tasks");
+    };
+    // Each task is held to the same limits as create_task.
+    for task in requested {
+        if let Err(e) = check_text_limits(ctx, task) {
+            return e;
+        }
+    }
+    let tasks: Vec<TemplateTask> = match serde_json::from_value(json!(requested)) {
+        Ok(v) => v,
+        Err(e) => return tool_error(&format!("Invalid tasks: {e}")),
+    };
+    let description = optional_str(args, "description").unwrap_or_default();
+
+    if let Err(e) = validate_project_exists(db, &project_id) {
+        return e;
+    }
+
+    let input = CreateTemplateInput { project_id, name, description, tasks };
+    match template_db::create_template(db, input) {
+        Ok(template) => tool_result(ctx, &template),
+        Err(e) => {
+            let msg = e.to_string();
+            if msg.contains("template") {
+                tool_error(&msg)
+            } else {
+                log::error!("create_template error: {e:#}");
+                tool_error("Failed to create template")
+            }
+        }
+    }
+}
+
+pub(super) fn handle_apply_template(
+    args: &Value,
+    db: &Database,
+    ctx: &ToolContext,
+    default_project_id: Option<&str>,
+) -> Value {
+    let raw_epic_id = match require_str(args, "epic_id") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let template = match require_str(args, "template") {
+        Ok(v) => v,
+        Err(e) => return e,
+    };
+    let project_id = resolve_optional_project_id(args, default_project_id);
+    let epic_id = match epic_db::resolve_epic_id(db, &raw_epic_id, project_id.as_deref()) {
+        Ok(v) => v,
+        Err(e) => return tool_error(&e.to_string()),
+    };
+    // The template is looked up in the epic's own project.
+    let epic = match epic_db::get_epic(db, &epic_id) {
+        Ok(Some(epic)) => epic,
+        Ok(None) => return tool_error(&format!("Epic not found: {raw_epic_id}")),
+        Err(e) => {
+            log::error!("apply_template error: {e:#}");
+            return tool_error("Failed to apply template");
+        }
+    };
+    let template = match template_db::resolve_template(db, &epic.project_id, &template) {
+        Ok(v) => v,
+        Err(e) => return tool_error(&e.to_string()),
+    };
+
+    match template_db::apply_template(db, &template, &epic_id, actor(args)) {
+        Ok(tasks) => tool_result(ctx, &json!({
+            "template_id": template.id,
+            "epic_id": epic_id,
+            "tasks": tasks,
+        })),
+        Err(e) => {
+            log::error!("apply_template error: {e:#}");
+            tool_error("Failed to apply template")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::{dispatch_tool, ToolContext};
+    use serde_json::{json, Value};
+    use tempfile::TempDir;
+
+    use crate::db::dependency as dep_db;
+    use crate::db::Database;
+    use crate::models::DependencyType;
+
+    fn test_db() -> (Database, TempDir) {
+        let dir = TempDir::new().unwrap();
+        let db = Database::open(&dir.path().join("test.db")).unwrap();
+        db.migrate().unwrap();
+        (db, dir)
+    }
+
+    fn parse_response(result: &Value) -> Value {
+        let text = result["content"][0]["text"].as_str().unwrap();
+        serde_json::from_str(text).unwrap()
+    }
+
+    fn call(db: &Database, name: &str, args: Value) -> Value {
+        dispatch_tool(name, &args, db, &ToolContext::default(), None).unwrap()
+    }
+
+    fn project_with_epic(db: &Database) -> (String, String) {
+        let project = parse_response(&call(
+            db,
+            "create_project",
+            json!({"name": "P", "description": "d"}),
+        ));
+        let project_id = project["id"].as_str().unwrap().to_string();
+        let epic = parse_response(&call(
+            db,
+            "create_epic",
+            json!({"project_id": project_id, "title": "Search", "description": "d"}),
+        ));
+        (project_id, epic["short_id"].as_str().unwrap().to_string())
+    }
+
+    #[test]
+    fn test_create_and_apply_template() {
+        let (db, _dir) = test_db();
+        let (project_id, epic_short_id) = project_with_epic(&db);
+
+        let result = call(
+            &db,
+            "create_template",
+            json!({
+                "project_id": project_id,
+                "name": "feature",
+                "tasks": [
+                    {"title": "Implement", "description": "Build it"},
+                    {"title": "Write tests", "depends_on": [0]},
+                    {"title": "Update docs", "depends_on": [0, 1]}
+                ]
+            }),
+        );
+        assert!(result.get("isError").is_none(), "{result}");
+        let template = parse_response(&result);
+        assert_eq!(template["tasks"].as_array().unwrap().len(), 3);
+
+        let result = call(
+            &db,
+            "apply_template",
+            json!({"project_id": project_id, "epic_id": epic_short_id, "template": "feature"}),
+        );
+        assert!(result.get("isError").is_none(), "{result}");
+        let applied = parse_response(&result);
+        assert_eq!(applied["template_id"], template["id"]);
+        let tasks = applied["tasks"].as_array().unwrap();
+        let titles: Vec<&str> = tasks.iter().map(|t| t["title"].as_str().unwrap()).collect();
+        assert_eq!(titles, ["Implement", "Write tests", "Update docs"]);
+        assert_eq!(tasks[0]["description"], "Build it");
+        assert_eq!(tasks[0]["short_id"], format!("{epic_short_id}-T1"));
+
+        let blocker_count = |task: &Value| {
+            let id = task["id"].as_str().unwrap();
+            dep_db::get_blockers(&db, &DependencyType::Task, id).unwrap().len()
+        };
+        assert_eq!(tasks.iter().map(blocker_count).collect::<Vec<_>>(), [0, 1, 2]);
+    }
+
+    #[test]
+    fn test_template_errors_are_reported() {
+        let (db, _dir) = test_db();
+        let (project_id, epic_short_id) = project_with_epic(&db);
+
+        let error_text = |name: &str, args: Value| {
+            let result = call(&db, name, args);
+            assert_eq!(result["isError"], true, "{result}");
+            result["content"][0]["text"].as_str().unwrap().to_string()
+        };
+        let forward = json!([{"title": "A", "depends_on": [0]}]);
+        let text = error_text(
+            "create_template",
+            json!({"project_id": project_id, "name": "t", "tasks": forward}),
+        );
+        assert!(text.contains("only depend on earlier tasks"), "{text}");
+        let text = error_text(
+            "create_template",
+            json!({"project_id": project_id, "name": "t", "tasks": [{"description": "no title"}]}),
+        );
+        assert!(text.contains("Invalid tasks"), "{text}");
+        let text = error_text(
+            "apply_template",
+            json!({"project_id": project_id, "epic_id": epic_short_id, "template": "missing"}),
+        );
+        assert!(text.contains("template not found: missing"), "{text}");
+    }
+}
@@ -0,0 +1,17 @@
+use serde::Serialize;
+
+/// A checklist entry inside a task, short of being a task itself.
+#[derive(Debug, Clone, Serialize, PartialEq)]
+pub struct ChecklistItem {
+    pub id: String,
+    pub task_id: String,
+    pub text: String,
+    pub done: bool,
+    pub position: i64,
+    pub created_at: String,
+}
+
+/// `(done, total)` for a task's checklist.
+pub fn checklist_progress(items: &[ChecklistItem]) -> (usize, usize) {
+    (items.iter().filter(|i| i.done).count(), items.len())
+}
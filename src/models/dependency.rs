@@ -3,6 +3,8 @@ use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 
+use crate::models::ItemStatus;
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum DependencyType {
@@ -37,6 +39,52 @@ impl FromStr for DependencyType {
     }
 }
 
+/// Which edges of an item to act on: `Incoming` edges are its blockers,
+/// `Outgoing` edges are the items it blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DependencyDirection {
+    Incoming,
+    Outgoing,
+    Both,
+}
+
+impl FromStr for DependencyDirection {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "incoming" => Ok(Self::Incoming),
+            "outgoing" => Ok(Self::Outgoing),
+            "both" => Ok(Self::Both),
+            other => anyhow::bail!("invalid dependency direction: {other}"),
+        }
+    }
+}
+
+/// Which dependencies of an item to list, and which page of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DependencyQuery {
+    pub direction: DependencyDirection,
+    /// Follow edges beyond the item's own: blockers of its blockers, or
+    /// items blocked by the items it blocks, at any depth.
+    pub transitive: bool,
+    /// Most rows to return; `None` for all of them.
+    pub limit: Option<usize>,
+    pub offset: usize,
+}
+
+impl DependencyQuery {
+    /// All direct edges in `direction`.
+    pub fn direct(direction: DependencyDirection) -> Self {
+        Self {
+            direction,
+            transitive: false,
+            limit: None,
+            offset: 0,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Dependency {
     pub id: i64,
@@ -46,6 +94,20 @@ pub struct Dependency {
     pub blocked_id: String,
 }
 
+/// The item at the other end of a dependency, resolved to what a caller
+/// usually wants to show.
+#[derive(Debug, Clone, Serialize)]
+pub struct DependencyItem {
+    /// ID of the dependency row.
+    pub dependency_id: i64,
+    #[serde(rename = "type")]
+    pub item_type: DependencyType,
+    pub id: String,
+    pub short_id: Option<String>,
+    pub title: String,
+    pub status: ItemStatus,
+}
+
 pub struct AddDependencyInput {
     pub blocker_type: DependencyType,
     pub blocker_id: String,
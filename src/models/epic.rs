@@ -1,6 +1,8 @@
 use std::fmt;
 use std::str::FromStr;
 
+use anyhow::{anyhow, bail};
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -40,6 +42,78 @@ impl FromStr for ItemStatus {
     }
 }
 
+/// Inclusive window over `created_at` or `updated_at`, held in the stored
+/// `YYYY-MM-DD HH:MM:SS` (UTC) form so it compares directly against them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateRange {
+    pub start: String,
+    pub end: String,
+}
+
+impl DateRange {
+    /// Each bound may be `YYYY-MM-DD`, `YYYY-MM-DD HH:MM:SS`,
+    /// `YYYY-MM-DDTHH:MM:SS` or RFC 3339 with an offset. A bare date covers
+    /// its whole day, so `2026-03-01` to `2026-03-01` is that one day.
+    pub fn parse(start: &str, end: &str) -> anyhow::Result<Self> {
+        let start = parse_bound(start, false)?;
+        let end = parse_bound(end, true)?;
+        if start > end {
+            bail!("start ({start}) is after end ({end})");
+        }
+        let stored = |t: NaiveDateTime| t.format("%Y-%m-%d %H:%M:%S").to_string();
+        Ok(Self {
+            start: stored(start),
+            end: stored(end),
+        })
+    }
+}
+
+fn parse_bound(s: &str, end_of_day: bool) -> anyhow::Result<NaiveDateTime> {
+    let s = s.trim();
+    if let Ok(day) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        let time = if end_of_day { day.and_hms_opt(23, 59, 59) } else { day.and_hms_opt(0, 0, 0) };
+        return Ok(time.expect("valid time of day"));
+    }
+    for format in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S"] {
+        if let Ok(time) = NaiveDateTime::parse_from_str(s, format) {
+            return Ok(time);
+        }
+    }
+    DateTime::parse_from_rfc3339(s).map(|t| t.naive_utc()).map_err(|_| {
+        anyhow!("invalid date: {s} (expected ISO 8601, e.g. 2026-03-01 or 2026-03-01T09:00:00Z)")
+    })
+}
+
+/// Which statuses a listing keeps: any of `include`, or every status when
+/// it is empty, minus those in `exclude`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StatusFilter {
+    pub include: Vec<ItemStatus>,
+    pub exclude: Vec<ItemStatus>,
+}
+
+impl StatusFilter {
+    /// Keep only items in `status`.
+    pub fn only(status: ItemStatus) -> Self {
+        Self {
+            include: vec![status],
+            exclude: Vec::new(),
+        }
+    }
+}
+
+/// Which epics `list_epics` returns. Every set field must match; the
+/// default keeps all unarchived epics.
+#[derive(Debug, Clone, Default)]
+pub struct EpicListFilter<'a> {
+    pub project_id: Option<&'a str>,
+    pub status: StatusFilter,
+    /// Also return archived epics.
+    pub include_archived: bool,
+    pub created: Option<DateRange>,
+    pub updated: Option<DateRange>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Epic {
     pub id: String,
@@ -48,8 +122,18 @@ pub struct Epic {
     pub description: String,
     pub status: ItemStatus,
     pub short_id: Option<String>,
+    /// PRD this epic was broken down from, if any.
+    pub prd_id: Option<String>,
+    /// Place in the project's epic list, 0 first. Set with `reorder_epics`.
+    pub position: i64,
+    /// Hidden from default listings; see `archive_epic`.
+    #[serde(default)]
+    pub archived: bool,
     pub created_at: String,
     pub updated_at: String,
+    /// The `actor` of the last tool call that changed the epic, if it gave one.
+    #[serde(default)]
+    pub updated_by: Option<String>,
     pub task_count: i64,
     pub done_count: i64,
 }
@@ -58,6 +142,7 @@ pub struct CreateEpicInput {
     pub project_id: String,
     pub title: String,
     pub description: String,
+    pub prd_id: Option<String>,
 }
 
 #[derive(Default)]
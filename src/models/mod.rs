@@ -1,11 +1,15 @@
+pub mod checklist;
 pub mod dependency;
 pub mod epic;
 pub mod prd;
 pub mod project;
 pub mod task;
+pub mod template;
 
+pub use checklist::*;
 pub use dependency::*;
 pub use epic::*;
 pub use prd::*;
 pub use project::*;
 pub use task::*;
+pub use template::*;
@@ -9,8 +9,348 @@ pub struct Prd {
     pub created_at: String,
 }
 
+/// A PRD without its body, for listings where documents may be large.
+#[derive(Debug, Clone, Serialize)]
+pub struct PrdSummary {
+    pub id: String,
+    pub project_id: String,
+    pub title: String,
+    pub created_at: String,
+    /// Length of the stored content in bytes.
+    pub content_length: usize,
+}
+
+impl From<&Prd> for PrdSummary {
+    fn from(prd: &Prd) -> Self {
+        Self {
+            id: prd.id.clone(),
+            project_id: prd.project_id.clone(),
+            title: prd.title.clone(),
+            created_at: prd.created_at.clone(),
+            content_length: prd.content.len(),
+        }
+    }
+}
+
 pub struct CreatePrdInput {
     pub project_id: String,
     pub title: String,
     pub content: String,
 }
+
+/// A task suggested by [`outline_prd`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProposedTask {
+    pub title: String,
+    pub description: String,
+}
+
+/// An epic suggested by [`outline_prd`], with its tasks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ProposedEpic {
+    pub title: String,
+    pub description: String,
+    pub tasks: Vec<ProposedTask>,
+}
+
+/// Suggest an epic/task tree from a markdown PRD.
+///
+/// Second-level headings (`##`) become epics, or first-level ones when the
+/// document has no `##`. Under an epic, deeper headings and unindented list
+/// items become tasks. List items under a task heading, indented lines and
+/// other text go to the description of the task or epic above them.
+/// Anything before the first epic heading is ignored.
+pub fn outline_prd(content: &str) -> Vec<ProposedEpic> {
+    let epic_level = if content.lines().any(|l| heading(l).is_some_and(|(level, _)| level == 2)) {
+        2
+    } else {
+        1
+    };
+
+    let mut epics: Vec<ProposedEpic> = Vec::new();
+    // Whether text still belongs to the last epic, and whether its last task
+    // came from a heading (so list items below it are details, not tasks).
+    let mut in_epic = false;
+    let mut under_task_heading = false;
+    let mut in_fence = false;
+
+    for line in content.lines() {
+        let fence = line.trim_start().starts_with("```") || line.trim_start().starts_with("~~~");
+        if !in_fence && !fence {
+            if let Some((level, title)) = heading(line) {
+                if level == epic_level {
+                    epics.push(ProposedEpic {
+                        title,
+                        description: String::new(),
+                        tasks: Vec::new(),
+                    });
+                    in_epic = true;
+                    under_task_heading = false;
+                } else if level > epic_level && in_epic {
+                    push_task(&mut epics, title);
+                    under_task_heading = true;
+                } else if level < epic_level {
+                    in_epic = false;
+                }
+                continue;
+            }
+            if in_epic
+                && !under_task_heading
+                && let Some(title) = list_item(line)
+            {
+                push_task(&mut epics, title);
+                continue;
+            }
+        }
+        if fence {
+            in_fence = !in_fence;
+        }
+        if in_epic && !line.trim().is_empty() {
+            append_description(&mut epics, line.trim_end());
+        }
+    }
+    epics
+}
+
+/// An existing epic or task named in a [`PrdDiff`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PrdDiffItem {
+    pub id: String,
+    pub short_id: Option<String>,
+    pub title: String,
+}
+
+/// An epic from the last breakdown and what happens to its tasks.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PrdEpicDiff {
+    #[serde(flatten)]
+    pub epic: PrdDiffItem,
+    pub added_tasks: Vec<ProposedTask>,
+    pub unchanged_tasks: Vec<PrdDiffItem>,
+    pub removed_tasks: Vec<PrdDiffItem>,
+}
+
+/// How a PRD's breakdown compares with the outline of new content.
+///
+/// Epics are matched by title, then tasks by title within a matched epic.
+/// A removed epic lists all of its tasks as removed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct PrdDiff {
+    pub added_epics: Vec<ProposedEpic>,
+    pub unchanged_epics: Vec<PrdEpicDiff>,
+    pub removed_epics: Vec<PrdEpicDiff>,
+}
+
+impl PrdDiff {
+    /// `(added, unchanged, removed)` task counts across all epics.
+    pub fn task_counts(&self) -> (usize, usize, usize) {
+        let added = self.added_epics.iter().map(|e| e.tasks.len()).sum::<usize>()
+            + self.unchanged_epics.iter().map(|e| e.added_tasks.len()).sum::<usize>();
+        let unchanged = self.unchanged_epics.iter().map(|e| e.unchanged_tasks.len()).sum();
+        let removed = self
+            .unchanged_epics
+            .iter()
+            .chain(&self.removed_epics)
+            .map(|e| e.removed_tasks.len())
+            .sum();
+        (added, unchanged, removed)
+    }
+}
+
+/// Compare an existing breakdown, epics with their tasks, against a new
+/// outline. Titles match ignoring case and surrounding whitespace; when a
+/// title repeats, occurrences pair up in order.
+pub fn diff_outline(
+    breakdown: Vec<(PrdDiffItem, Vec<PrdDiffItem>)>,
+    outline: Vec<ProposedEpic>,
+) -> PrdDiff {
+    let mut existing: Vec<Option<(PrdDiffItem, Vec<PrdDiffItem>)>> =
+        breakdown.into_iter().map(Some).collect();
+    let mut diff = PrdDiff::default();
+
+    for proposed in outline {
+        let Some((epic, tasks)) = take_match(&mut existing, &proposed.title, |(e, _)| &e.title)
+        else {
+            diff.added_epics.push(proposed);
+            continue;
+        };
+        let mut tasks: Vec<Option<PrdDiffItem>> = tasks.into_iter().map(Some).collect();
+        let mut epic_diff = PrdEpicDiff {
+            epic,
+            added_tasks: Vec::new(),
+            unchanged_tasks: Vec::new(),
+            removed_tasks: Vec::new(),
+        };
+        for task in proposed.tasks {
+            match take_match(&mut tasks, &task.title, |t| &t.title) {
+                Some(existing) => epic_diff.unchanged_tasks.push(existing),
+                None => epic_diff.added_tasks.push(task),
+            }
+        }
+        epic_diff.removed_tasks = tasks.into_iter().flatten().collect();
+        diff.unchanged_epics.push(epic_diff);
+    }
+
+    diff.removed_epics = existing
+        .into_iter()
+        .flatten()
+        .map(|(epic, tasks)| PrdEpicDiff {
+            epic,
+            added_tasks: Vec::new(),
+            unchanged_tasks: Vec::new(),
+            removed_tasks: tasks,
+        })
+        .collect();
+    diff
+}
+
+/// Remove and return the first not-yet-matched item whose title matches.
+fn take_match<T>(
+    items: &mut [Option<T>],
+    title: &str,
+    title_of: impl Fn(&T) -> &String,
+) -> Option<T> {
+    let wanted = title.trim().to_lowercase();
+    let same = |item: &T| title_of(item).trim().to_lowercase() == wanted;
+    items.iter_mut().find(|slot| slot.as_ref().is_some_and(&same))?.take()
+}
+
+/// `(level, title)` for an ATX heading such as `## Login`.
+fn heading(line: &str) -> Option<(usize, String)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    let rest = line[level..].strip_prefix(' ')?;
+    let title = rest.trim().trim_end_matches('#').trim();
+    ((1..=6).contains(&level) && !title.is_empty()).then(|| (level, title.to_string()))
+}
+
+/// Text of an unindented bullet, numbered or checkbox list item.
+fn list_item(line: &str) -> Option<String> {
+    let rest = ["- ", "* ", "+ "]
+        .iter()
+        .find_map(|bullet| line.strip_prefix(bullet))
+        .or_else(|| {
+            let digits = line.chars().take_while(char::is_ascii_digit).count();
+            (digits > 0)
+                .then(|| line[digits..].strip_prefix(". ").or(line[digits..].strip_prefix(") ")))
+                .flatten()
+        })?;
+    let rest = ["[ ] ", "[x] ", "[X] "]
+        .iter()
+        .find_map(|box_| rest.strip_prefix(box_))
+        .unwrap_or(rest);
+    let title = rest.trim();
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+fn push_task(epics: &mut [ProposedEpic], title: String) {
+    if let Some(epic) = epics.last_mut() {
+        epic.tasks.push(ProposedTask {
+            title,
+            description: String::new(),
+        });
+    }
+}
+
+fn append_description(epics: &mut [ProposedEpic], line: &str) {
+    let Some(epic) = epics.last_mut() else {
+        return;
+    };
+    let description = match epic.tasks.last_mut() {
+        Some(task) => &mut task.description,
+        None => &mut epic.description,
+    };
+    if !description.is_empty() {
+        description.push('\n');
+    }
+    description.push_str(line);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn titles(epic: &ProposedEpic) -> Vec<&str> {
+        epic.tasks.iter().map(|t| t.title.as_str()).collect()
+    }
+
+    #[test]
+    fn second_level_headings_become_epics_with_list_tasks() {
+        let outline = outline_prd(
+            "# Widget PRD\n\
+             Intro text is not part of any epic.\n\
+             \n\
+             ## Accounts\n\
+             Users can sign up.\n\
+             - Sign-up form\n\
+             \x20 with email validation\n\
+             - [ ] Password reset\n\
+             1. Audit log\n\
+             \n\
+             ## Billing\n\
+             ### Invoices\n\
+             - PDF export\n\
+             - Email delivery\n\
+             ### Refunds\n",
+        );
+
+        assert_eq!(outline.len(), 2);
+        assert_eq!(outline[0].title, "Accounts");
+        assert_eq!(outline[0].description, "Users can sign up.");
+        assert_eq!(titles(&outline[0]), ["Sign-up form", "Password reset", "Audit log"]);
+        assert_eq!(outline[0].tasks[0].description, "  with email validation");
+
+        assert_eq!(outline[1].title, "Billing");
+        assert_eq!(titles(&outline[1]), ["Invoices", "Refunds"]);
+        assert_eq!(outline[1].tasks[0].description, "- PDF export\n- Email delivery");
+    }
+
+    #[test]
+    fn first_level_headings_are_epics_without_second_level() {
+        let outline = outline_prd("# Search\n- Index\n# Export\n- CSV\n");
+        let epics: Vec<&str> = outline.iter().map(|e| e.title.as_str()).collect();
+        assert_eq!(epics, ["Search", "Export"]);
+        assert_eq!(titles(&outline[1]), ["CSV"]);
+    }
+
+    #[test]
+    fn fenced_code_is_description_text() {
+        let outline = outline_prd("## API\n```\n## not a heading\n- not a task\n```\n");
+        assert_eq!(outline.len(), 1);
+        assert!(outline[0].tasks.is_empty());
+        assert_eq!(outline[0].description, "```\n## not a heading\n- not a task\n```");
+    }
+
+    #[test]
+    fn diff_matches_titles_and_classifies_the_rest() {
+        let item = |id: &str, title: &str| PrdDiffItem {
+            id: id.to_string(),
+            short_id: None,
+            title: title.to_string(),
+        };
+        let breakdown = vec![
+            (item("e1", "Accounts"), vec![item("t1", "Sign-up form"), item("t2", "Audit log")]),
+            (item("e2", "Legacy"), vec![item("t3", "Old export")]),
+        ];
+        let outline =
+            outline_prd("## accounts\n- Sign-up form \n- Password reset\n## Search\n- Index\n");
+
+        let diff = diff_outline(breakdown, outline);
+        assert_eq!(diff.added_epics.len(), 1);
+        assert_eq!(diff.added_epics[0].title, "Search");
+        assert_eq!(diff.unchanged_epics.len(), 1);
+        let accounts = &diff.unchanged_epics[0];
+        assert_eq!(accounts.epic.id, "e1");
+        assert_eq!(accounts.unchanged_tasks, [item("t1", "Sign-up form")]);
+        assert_eq!(accounts.added_tasks[0].title, "Password reset");
+        assert_eq!(accounts.removed_tasks, [item("t2", "Audit log")]);
+        assert_eq!(diff.removed_epics.len(), 1);
+        assert_eq!(diff.removed_epics[0].removed_tasks, [item("t3", "Old export")]);
+        assert_eq!(diff.task_counts(), (2, 1, 2));
+    }
+
+    #[test]
+    fn plain_text_has_no_outline() {
+        assert!(outline_prd("Just build a widget.").is_empty());
+        assert!(outline_prd("#hashtag\n-dash").is_empty());
+    }
+}
@@ -4,9 +4,11 @@ use std::str::FromStr;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "lowercase")]
+#[serde(rename_all = "snake_case")]
 pub enum ProjectStatus {
     Active,
+    /// Paused: kept out of the way without archiving it.
+    OnHold,
     Archived,
 }
 
@@ -14,6 +16,7 @@ impl ProjectStatus {
     pub fn as_str(&self) -> &'static str {
         match self {
             Self::Active => "active",
+            Self::OnHold => "on_hold",
             Self::Archived => "archived",
         }
     }
@@ -31,18 +34,49 @@ impl FromStr for ProjectStatus {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "active" => Ok(Self::Active),
+            "on_hold" => Ok(Self::OnHold),
             "archived" => Ok(Self::Archived),
             other => anyhow::bail!("invalid project status: {other}"),
         }
     }
 }
 
+/// Orderings offered by `list_projects`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ProjectSort {
+    /// Alphabetical, ignoring case.
+    Name,
+    /// Newest first.
+    #[default]
+    Created,
+    /// Most recently edited project first.
+    Updated,
+    /// Most recent change to the project, its epics or its tasks first.
+    Activity,
+}
+
+impl FromStr for ProjectSort {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "name" => Ok(Self::Name),
+            "created" => Ok(Self::Created),
+            "updated" => Ok(Self::Updated),
+            "activity" => Ok(Self::Activity),
+            other => anyhow::bail!("invalid project sort: {other}"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Project {
     pub id: String,
     pub name: String,
     pub description: String,
     pub status: ProjectStatus,
+    /// Optional prefix for epic/task short IDs, e.g. `PROJ` gives `PROJ-E1-T2`.
+    pub short_id_prefix: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -57,4 +91,26 @@ pub struct UpdateProjectInput {
     pub name: Option<String>,
     pub description: Option<String>,
     pub status: Option<ProjectStatus>,
+    /// None = don't touch, Some(None) = clear, Some(Some(v)) = set
+    pub short_id_prefix: Option<Option<String>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn project_status_round_trips_through_str_and_json() {
+        for (status, text) in [
+            (ProjectStatus::Active, "active"),
+            (ProjectStatus::OnHold, "on_hold"),
+            (ProjectStatus::Archived, "archived"),
+        ] {
+            assert_eq!(status.to_string(), text);
+            assert_eq!(text.parse::<ProjectStatus>().unwrap(), status);
+            assert_eq!(serde_json::to_value(&status).unwrap(), text);
+        }
+        assert!("onhold".parse::<ProjectStatus>().is_err());
+        assert!("paused".parse::<ProjectStatus>().is_err());
+    }
 }
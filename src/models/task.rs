@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use super::ItemStatus;
+use super::{DateRange, ItemStatus, StatusFilter};
 
 #[derive(Debug, Clone, Serialize)]
 pub struct TaskSummary {
@@ -9,7 +9,15 @@ pub struct TaskSummary {
     pub epic_id: String,
     pub title: String,
     pub status: ItemStatus,
+    pub assignee: Option<String>,
     pub blockers: Vec<String>,
+    /// Whether anything upstream, at any depth, is unfinished. Only computed
+    /// when `list_tasks` is called with `include_blocked`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blocked: Option<bool>,
+    /// Only present, as `true`, for archived tasks.
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub archived: bool,
 }
 
 impl TaskSummary {
@@ -20,7 +28,10 @@ impl TaskSummary {
             epic_id: task.epic_id,
             title: task.title,
             status: task.status,
+            assignee: task.assignee,
             blockers,
+            blocked: None,
+            archived: task.archived,
         }
     }
 }
@@ -34,8 +45,15 @@ pub struct BlueTask {
     pub status: ItemStatus,
     pub short_id: Option<String>,
     pub session_id: Option<String>,
+    pub assignee: Option<String>,
+    /// Hidden from default listings; see `archive_task`.
+    #[serde(default)]
+    pub archived: bool,
     pub created_at: String,
     pub updated_at: String,
+    /// The `actor` of the last tool call that changed the task, if it gave one.
+    #[serde(default)]
+    pub updated_by: Option<String>,
 }
 
 pub struct CreateTaskInput {
@@ -43,6 +61,23 @@ pub struct CreateTaskInput {
     pub title: String,
     pub description: String,
     pub session_id: Option<String>,
+    pub assignee: Option<String>,
+}
+
+/// Which tasks `list_tasks` returns. Every set field must match; the
+/// default keeps all unarchived tasks.
+#[derive(Debug, Clone, Default)]
+pub struct TaskListFilter<'a> {
+    pub epic_id: Option<&'a str>,
+    pub project_id: Option<&'a str>,
+    pub status: StatusFilter,
+    /// Also return archived tasks.
+    pub include_archived: bool,
+    /// `None` for any assignee, `Some(None)` for unassigned tasks only and
+    /// `Some(Some(name))` for tasks assigned to `name`.
+    pub assignee: Option<Option<&'a str>>,
+    pub created: Option<DateRange>,
+    pub updated: Option<DateRange>,
 }
 
 #[derive(Default)]
@@ -51,4 +86,5 @@ pub struct UpdateTaskInput {
     pub description: Option<String>,
     pub status: Option<ItemStatus>,
     pub session_id: Option<Option<String>>,
+    pub assignee: Option<Option<String>>,
 }
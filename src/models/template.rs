@@ -0,0 +1,31 @@
+use serde::{Deserialize, Serialize};
+
+/// One task a template creates.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TemplateTask {
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    /// Positions of earlier tasks in the same template that block this one.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<usize>,
+}
+
+/// A named set of tasks, with dependencies among them, that can be applied
+/// to any epic of its project.
+#[derive(Debug, Clone, Serialize)]
+pub struct TaskTemplate {
+    pub id: String,
+    pub project_id: String,
+    pub name: String,
+    pub description: String,
+    pub tasks: Vec<TemplateTask>,
+    pub created_at: String,
+}
+
+pub struct CreateTemplateInput {
+    pub project_id: String,
+    pub name: String,
+    pub description: String,
+    pub tasks: Vec<TemplateTask>,
+}
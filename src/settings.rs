@@ -5,11 +5,101 @@ use serde::{Deserialize, Serialize};
 
 const SETTINGS_DIR: &str = ".blueprint";
 const SETTINGS_FILE: &str = "setting.json";
+/// Items an MCP list tool returns in one call when `max_list_items` is unset.
+pub const DEFAULT_MAX_LIST_ITEMS: usize = 500;
+/// Longest epic or task title, in characters, when `max_title_length` is unset.
+pub const DEFAULT_MAX_TITLE_LENGTH: usize = 200;
+/// Longest epic or task description, in characters, when
+/// `max_description_length` is unset.
+pub const DEFAULT_MAX_DESCRIPTION_LENGTH: usize = 20_000;
+
+/// Longest title and description, in characters, the MCP tools accept for
+/// an epic or task.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextLimits {
+    pub title: usize,
+    pub description: usize,
+}
+
+impl Default for TextLimits {
+    fn default() -> Self {
+        Self { title: DEFAULT_MAX_TITLE_LENGTH, description: DEFAULT_MAX_DESCRIPTION_LENGTH }
+    }
+}
+
+/// How the TUI's main screen arranges its panels.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LayoutMode {
+    /// Epics, tasks, dependencies and status in a 2x2 grid.
+    #[default]
+    Grid,
+    /// Epics stacked over tasks in a single column.
+    List,
+}
+
+impl LayoutMode {
+    /// The mode after this one when cycling with `v`.
+    pub fn next(self) -> Self {
+        match self {
+            LayoutMode::Grid => LayoutMode::List,
+            LayoutMode::List => LayoutMode::Grid,
+        }
+    }
+}
 
 #[derive(Debug, Default, Serialize, Deserialize)]
 pub struct Settings {
     #[serde(default)]
     pub project_id: Option<String>,
+    /// Whether the TUI runs its border/pulse animations. Defaults to on when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub animations_enabled: Option<bool>,
+    /// Whether the MCP server offers the housekeeping tools
+    /// (`seed_demo_data`, `maintain_db`). Defaults to on when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub admin_tools: Option<bool>,
+    /// Width of graph node boxes in the TUI, clamped to the supported range.
+    /// Defaults to the built-in width when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub node_width: Option<usize>,
+    /// Whether task changes move the parent epic to the status its tasks
+    /// imply. Defaults to off when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_epic_status: Option<bool>,
+    /// Whether TUI list navigation wraps around at the ends. Defaults to on
+    /// when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub wrap_navigation: Option<bool>,
+    /// Initial TUI panel layout. Defaults to the grid when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub layout: Option<LayoutMode>,
+    /// Most items `list_tasks` / `list_epics` return before truncating and
+    /// asking the caller to page. Defaults to [`DEFAULT_MAX_LIST_ITEMS`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_list_items: Option<usize>,
+    /// Longest epic or task title the MCP tools accept. Defaults to
+    /// [`DEFAULT_MAX_TITLE_LENGTH`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_title_length: Option<usize>,
+    /// Longest epic or task description the MCP tools accept. Defaults to
+    /// [`DEFAULT_MAX_DESCRIPTION_LENGTH`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_description_length: Option<usize>,
+    /// Whether the TUI marks statuses with ASCII shapes (`[ ]`, `[~]`, `[x]`,
+    /// `!`) instead of glyphs told apart mostly by color. Defaults to off when
+    /// unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub high_contrast_symbols: Option<bool>,
+    /// Whether the TUI draws the graph and status symbols with plain ASCII
+    /// (`+`, `-`, `|`, `o`, `>`) for terminals without box-drawing glyphs.
+    /// `BLUEPRINT_ASCII=1` turns it on as well. Defaults to off when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ascii_glyphs: Option<bool>,
+    /// Whether the TUI graph starts with done nodes and their edges dimmed,
+    /// so remaining work stands out. Defaults to off when unset.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dim_completed: Option<bool>,
 }
 
 impl Settings {
@@ -19,7 +109,7 @@ impl Settings {
         Self::load_from(std::env::current_dir().ok())
     }
 
-    fn load_from(cwd: Option<PathBuf>) -> Self {
+    pub(crate) fn load_from(cwd: Option<PathBuf>) -> Self {
         let Some(cwd) = cwd else {
             return Self::default();
         };
@@ -27,6 +117,55 @@ impl Settings {
         Self::read_file(&path).unwrap_or_default()
     }
 
+    /// Returns whether TUI animations are enabled, defaulting to `true`.
+    pub fn animations_enabled(&self) -> bool {
+        self.animations_enabled.unwrap_or(true)
+    }
+
+    /// Returns whether TUI list navigation wraps, defaulting to `true`.
+    pub fn wrap_navigation_enabled(&self) -> bool {
+        self.wrap_navigation.unwrap_or(true)
+    }
+
+    /// Returns whether the MCP housekeeping tools are offered, defaulting to `true`.
+    pub fn admin_tools_enabled(&self) -> bool {
+        self.admin_tools.unwrap_or(true)
+    }
+
+    /// Returns whether epics follow their tasks' statuses, defaulting to `false`.
+    pub fn auto_epic_status_enabled(&self) -> bool {
+        self.auto_epic_status.unwrap_or(false)
+    }
+
+    /// Returns whether the TUI uses ASCII status markers, defaulting to `false`.
+    pub fn high_contrast_symbols_enabled(&self) -> bool {
+        self.high_contrast_symbols.unwrap_or(false)
+    }
+
+    /// Returns whether the TUI uses the ASCII glyph set, defaulting to `false`.
+    pub fn ascii_glyphs_enabled(&self) -> bool {
+        self.ascii_glyphs.unwrap_or(false)
+    }
+
+    /// Returns whether the TUI graph dims done nodes, defaulting to `false`.
+    pub fn dim_completed_enabled(&self) -> bool {
+        self.dim_completed.unwrap_or(false)
+    }
+
+    /// Returns the list tools' item cap, defaulting to [`DEFAULT_MAX_LIST_ITEMS`].
+    pub fn max_list_items(&self) -> usize {
+        self.max_list_items.unwrap_or(DEFAULT_MAX_LIST_ITEMS).max(1)
+    }
+
+    /// Returns the title and description limits, defaulting to [`TextLimits::default`].
+    pub fn text_limits(&self) -> TextLimits {
+        let defaults = TextLimits::default();
+        TextLimits {
+            title: self.max_title_length.unwrap_or(defaults.title).max(1),
+            description: self.max_description_length.unwrap_or(defaults.description),
+        }
+    }
+
     fn read_file(path: &Path) -> Option<Self> {
         let data = fs::read_to_string(path).ok()?;
         serde_json::from_str(&data).ok()
@@ -48,13 +187,13 @@ impl Settings {
     }
 
     /// Write settings to a specific directory (used by TUI).
+    /// Other keys already present in the file are preserved.
     pub fn save_to(dir: &Path, project_id: &str) -> std::io::Result<()> {
         let settings_dir = dir.join(SETTINGS_DIR);
         fs::create_dir_all(&settings_dir)?;
 
-        let settings = Settings {
-            project_id: Some(project_id.to_string()),
-        };
+        let mut settings = Self::read_file(&Self::path_in(dir)).unwrap_or_default();
+        settings.project_id = Some(project_id.to_string());
         let json = serde_json::to_string_pretty(&settings)
             .map_err(std::io::Error::other)?;
         fs::write(settings_dir.join(SETTINGS_FILE), json.as_bytes())
@@ -108,6 +247,157 @@ mod tests {
         assert_eq!(settings.project_id.as_deref(), Some("proj_001"));
     }
 
+    #[test]
+    fn test_animations_enabled_defaults_to_true() {
+        let dir = TempDir::new().unwrap();
+        let settings = Settings::load_from(Some(dir.path().to_path_buf()));
+        assert!(settings.animations_enabled());
+    }
+
+    #[test]
+    fn test_high_contrast_symbols_default_off_and_read_from_file() {
+        let dir = TempDir::new().unwrap();
+        let settings = Settings::load_from(Some(dir.path().to_path_buf()));
+        assert!(!settings.high_contrast_symbols_enabled());
+
+        let bp_dir = dir.path().join(".blueprint");
+        fs::create_dir_all(&bp_dir).unwrap();
+        fs::write(bp_dir.join("setting.json"), r#"{"high_contrast_symbols": true}"#).unwrap();
+        let settings = Settings::load_from(Some(dir.path().to_path_buf()));
+        assert!(settings.high_contrast_symbols_enabled());
+    }
+
+    #[test]
+    fn test_dim_completed_default_off_and_read_from_file() {
+        let dir = TempDir::new().unwrap();
+        let settings = Settings::load_from(Some(dir.path().to_path_buf()));
+        assert!(!settings.dim_completed_enabled());
+
+        let bp_dir = dir.path().join(".blueprint");
+        fs::create_dir_all(&bp_dir).unwrap();
+        fs::write(bp_dir.join("setting.json"), r#"{"dim_completed": true}"#).unwrap();
+        let settings = Settings::load_from(Some(dir.path().to_path_buf()));
+        assert!(settings.dim_completed_enabled());
+    }
+
+    #[test]
+    fn test_ascii_glyphs_default_off_and_read_from_file() {
+        let dir = TempDir::new().unwrap();
+        let settings = Settings::load_from(Some(dir.path().to_path_buf()));
+        assert!(!settings.ascii_glyphs_enabled());
+
+        let bp_dir = dir.path().join(".blueprint");
+        fs::create_dir_all(&bp_dir).unwrap();
+        fs::write(bp_dir.join("setting.json"), r#"{"ascii_glyphs": true}"#).unwrap();
+        let settings = Settings::load_from(Some(dir.path().to_path_buf()));
+        assert!(settings.ascii_glyphs_enabled());
+    }
+
+    #[test]
+    fn test_animations_disabled_via_file() {
+        let dir = TempDir::new().unwrap();
+        let bp_dir = dir.path().join(".blueprint");
+        fs::create_dir_all(&bp_dir).unwrap();
+        fs::write(
+            bp_dir.join("setting.json"),
+            r#"{"project_id": "abc123", "animations_enabled": false}"#,
+        )
+        .unwrap();
+
+        let settings = Settings::load_from(Some(dir.path().to_path_buf()));
+        assert!(!settings.animations_enabled());
+
+        // Saving a new project must not drop the animation preference
+        Settings::save_to(dir.path(), "proj_002").unwrap();
+        let settings = Settings::load_from(Some(dir.path().to_path_buf()));
+        assert_eq!(settings.project_id.as_deref(), Some("proj_002"));
+        assert!(!settings.animations_enabled());
+    }
+
+    #[test]
+    fn test_node_width_read_from_file() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(Settings::load_from(Some(dir.path().to_path_buf())).node_width, None);
+
+        let bp_dir = dir.path().join(".blueprint");
+        fs::create_dir_all(&bp_dir).unwrap();
+        fs::write(bp_dir.join("setting.json"), r#"{"node_width": 44}"#).unwrap();
+        let settings = Settings::load_from(Some(dir.path().to_path_buf()));
+        assert_eq!(settings.node_width, Some(44));
+    }
+
+    #[test]
+    fn test_wrap_navigation_defaults_to_true() {
+        let dir = TempDir::new().unwrap();
+        let settings = Settings::load_from(Some(dir.path().to_path_buf()));
+        assert!(settings.wrap_navigation_enabled());
+
+        let bp_dir = dir.path().join(".blueprint");
+        fs::create_dir_all(&bp_dir).unwrap();
+        fs::write(bp_dir.join("setting.json"), r#"{"wrap_navigation": false}"#).unwrap();
+        let settings = Settings::load_from(Some(dir.path().to_path_buf()));
+        assert!(!settings.wrap_navigation_enabled());
+    }
+
+    #[test]
+    fn test_layout_read_from_file() {
+        let dir = TempDir::new().unwrap();
+        assert_eq!(Settings::load_from(Some(dir.path().to_path_buf())).layout, None);
+
+        let bp_dir = dir.path().join(".blueprint");
+        fs::create_dir_all(&bp_dir).unwrap();
+        fs::write(bp_dir.join("setting.json"), r#"{"layout": "list"}"#).unwrap();
+        let settings = Settings::load_from(Some(dir.path().to_path_buf()));
+        assert_eq!(settings.layout, Some(LayoutMode::List));
+    }
+
+    #[test]
+    fn test_max_list_items_defaults_and_floor() {
+        let dir = TempDir::new().unwrap();
+        let settings = Settings::load_from(Some(dir.path().to_path_buf()));
+        assert_eq!(settings.max_list_items(), DEFAULT_MAX_LIST_ITEMS);
+
+        let bp_dir = dir.path().join(".blueprint");
+        fs::create_dir_all(&bp_dir).unwrap();
+        fs::write(bp_dir.join("setting.json"), r#"{"max_list_items": 20}"#).unwrap();
+        let settings = Settings::load_from(Some(dir.path().to_path_buf()));
+        assert_eq!(settings.max_list_items(), 20);
+
+        fs::write(bp_dir.join("setting.json"), r#"{"max_list_items": 0}"#).unwrap();
+        let settings = Settings::load_from(Some(dir.path().to_path_buf()));
+        assert_eq!(settings.max_list_items(), 1);
+    }
+
+    #[test]
+    fn test_text_limits_default_and_read_from_file() {
+        let dir = TempDir::new().unwrap();
+        let settings = Settings::load_from(Some(dir.path().to_path_buf()));
+        assert_eq!(settings.text_limits(), TextLimits::default());
+
+        let bp_dir = dir.path().join(".blueprint");
+        fs::create_dir_all(&bp_dir).unwrap();
+        fs::write(
+            bp_dir.join("setting.json"),
+            r#"{"max_title_length": 80, "max_description_length": 4000}"#,
+        )
+        .unwrap();
+        let settings = Settings::load_from(Some(dir.path().to_path_buf()));
+        assert_eq!(settings.text_limits(), TextLimits { title: 80, description: 4000 });
+    }
+
+    #[test]
+    fn test_auto_epic_status_defaults_to_false() {
+        let dir = TempDir::new().unwrap();
+        let settings = Settings::load_from(Some(dir.path().to_path_buf()));
+        assert!(!settings.auto_epic_status_enabled());
+
+        let bp_dir = dir.path().join(".blueprint");
+        fs::create_dir_all(&bp_dir).unwrap();
+        fs::write(bp_dir.join("setting.json"), r#"{"auto_epic_status": true}"#).unwrap();
+        let settings = Settings::load_from(Some(dir.path().to_path_buf()));
+        assert!(settings.auto_epic_status_enabled());
+    }
+
     #[test]
     fn test_exists_in() {
         let dir = TempDir::new().unwrap();
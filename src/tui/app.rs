@@ -1,35 +1,49 @@
 use std::cell::Cell;
 use std::collections::{HashMap, HashSet};
-use std::io::Stdout;
+use std::io::{Stdout, Write};
+use std::path::PathBuf;
 use std::time::{Duration, Instant};
 
 use anyhow::Result;
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use ratatui::Terminal;
 use ratatui::backend::CrosstermBackend;
 
 use crate::db::Database;
-use crate::settings::Settings;
-use crate::db::dependency::{get_blocked_by, get_blockers, is_blocked};
-use crate::db::epic::list_epics;
-use crate::db::project::list_projects;
+use crate::db::checklist::list_checklist_items;
+use crate::settings::{LayoutMode, Settings};
+use crate::db::dependency::{get_blockers, is_blocked};
+use crate::db::epic::{get_epic, list_epics, reorder_epics};
+use crate::db::project::{create_project, list_projects};
+use crate::db::workspace::{self, DEFAULT_WORKSPACE};
 use crate::db::status::{
-    DependencyDisplayRow, count_epics_by_status, count_tasks_by_status, get_blocked_items,
-    get_dependency_display_rows, get_max_updated_at,
+    BlockedItemRow, DependencyDisplayRow, count_epics_by_status, count_tasks_by_status,
+    get_blocked_items, get_dependency_display_rows, get_max_updated_at,
 };
 use crate::db::task::{get_task, list_tasks, update_task};
-use crate::models::{BlueTask, DependencyType, Epic, ItemStatus, Project, UpdateTaskInput};
-use crate::tui::graph::{DagLayout, Edge, Node};
-use crate::tui::graph_render::{self, NODE_HEIGHT_EPIC, NODE_HEIGHT_TASK, NODE_WIDTH};
-use crate::tui::ui;
+use crate::models::{
+    BlueTask, ChecklistItem, CreateProjectInput, DependencyType, Epic, EpicListFilter, ItemStatus,
+    Project, ProjectSort, TaskListFilter, UpdateTaskInput,
+};
+use crate::graph::{
+    epic_nodes_and_edges, external_blockers, position_nodes, task_nodes_and_edges, DagLayout,
+    Edge, Node, NODE_HEIGHT_EPIC, NODE_HEIGHT_TASK, NODE_WIDTH,
+};
+use crate::tui::graph_render::{self, MAX_NODE_WIDTH, MIN_NODE_WIDTH};
+use crate::tui::state::TuiState;
+use crate::tui::{theme, ui};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum InputMode {
     Normal,
     ProjectSelector,
+    WorkspaceSelector,
+    BlockedList,
     TaskDetail,
     HelpOverlay,
     GraphView,
+    CommandPalette,
+    ProjectCreate,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -52,11 +66,64 @@ pub enum GraphDirection {
     Right,
 }
 
+/// Speeds up hjkl panning while a key repeats: presses in the same
+/// direction less than [`PAN_REPEAT_WINDOW`] apart build a streak, and the
+/// step grows from 1 to 2 to 4 cells as it lengthens. A pause or a change of
+/// direction starts over at 1.
+#[derive(Debug, Default)]
+pub struct PanAcceleration {
+    last: Option<(GraphDirection, Instant)>,
+    streak: usize,
+}
+
+/// Longest gap between presses that still counts as a key repeat.
+pub const PAN_REPEAT_WINDOW: Duration = Duration::from_millis(150);
+/// Repeated presses before the pan step doubles, and doubles again.
+const PAN_STEP_2_AFTER: usize = 4;
+const PAN_STEP_4_AFTER: usize = 10;
+
+impl PanAcceleration {
+    /// Record a press in `direction` at `now` and return how many cells to pan.
+    pub fn step(&mut self, direction: GraphDirection, now: Instant) -> usize {
+        let repeat = self.last.is_some_and(|(dir, at)| {
+            dir == direction && now.saturating_duration_since(at) <= PAN_REPEAT_WINDOW
+        });
+        self.streak = if repeat { self.streak + 1 } else { 0 };
+        self.last = Some((direction, now));
+        if self.streak >= PAN_STEP_4_AFTER {
+            4
+        } else if self.streak >= PAN_STEP_2_AFTER {
+            2
+        } else {
+            1
+        }
+    }
+}
+
 /// Cached graph layout data (recomputed only when data changes).
 pub struct GraphCache {
     pub layout: DagLayout,
     pub node_positions: HashMap<String, (usize, usize)>,
     pub level: GraphLevel,
+    /// Pane width `node_positions` were computed for (0 = unknown).
+    pub viewport_width: usize,
+    /// Node box width `node_positions` were computed for.
+    pub node_width: usize,
+    /// Nodes held up by a blocker outside the graph; see [`external_blockers`].
+    pub external_blockers: HashSet<String>,
+}
+
+impl GraphCache {
+    /// Recompute node positions for a new pane or node width, keeping the layout.
+    pub fn relayout(&mut self, viewport_width: usize, node_width: usize) {
+        let node_height = match self.level {
+            GraphLevel::Epic => NODE_HEIGHT_EPIC,
+            GraphLevel::Task => NODE_HEIGHT_TASK,
+        };
+        self.node_positions = position_nodes(&self.layout, node_height, viewport_width, node_width);
+        self.viewport_width = viewport_width;
+        self.node_width = node_width;
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -67,6 +134,50 @@ pub enum FocusedPanel {
     Status,
 }
 
+/// One row of the blocked-items list opened with `b`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockedEntry {
+    pub item_type: DependencyType,
+    pub id: String,
+    /// Short ID and title, e.g. `E1-T2 Login form`.
+    pub label: String,
+    /// Labels of the unfinished items blocking it.
+    pub blockers: Vec<String>,
+}
+
+/// A task blocking another, as listed in the task detail view.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TaskBlocker {
+    pub id: String,
+    pub epic_id: String,
+    pub title: String,
+}
+
+/// Fields of the new-project form opened with `n` in the project selector.
+#[derive(Debug, Default)]
+pub struct ProjectForm {
+    pub name: String,
+    pub description: String,
+    /// Whether typing goes to the description rather than the name.
+    pub on_description: bool,
+    /// Why the last submit was refused, shown under the fields.
+    pub error: Option<String>,
+    /// Whether anything was typed or deleted since the form opened.
+    pub dirty: bool,
+    /// Set while asking whether to throw the typed text away; holds what
+    /// leaving does once confirmed.
+    pub confirm_discard: Option<FormExit>,
+}
+
+/// How the user asked to leave the new-project form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormExit {
+    /// Esc: back to where the form was opened from.
+    Cancel,
+    /// Ctrl-C: quit the TUI. `q` is text inside the form.
+    Quit,
+}
+
 pub struct App {
     pub db: Database,
     pub running: bool,
@@ -75,23 +186,62 @@ pub struct App {
     pub projects: Vec<Project>,
     pub selected_project_idx: usize,
     pub selector_idx: usize,
+    /// Name of the open workspace; `None` for the default database.
+    pub workspace: Option<String>,
+    /// Directory the workspace switcher (`w`) scans for `*.db` files.
+    pub workspace_dir: Option<PathBuf>,
+    /// Directory holding `.blueprint/setting.json`; the working directory
+    /// when `None`. Set from `--data-dir` / `BLUEPRINT_DATA_DIR`.
+    pub settings_dir: Option<PathBuf>,
+    /// Workspaces listed in the switcher and the highlighted entry.
+    pub workspaces: Vec<String>,
+    pub workspace_idx: usize,
+    /// Blocked items listed by the `b` modal and the highlighted entry.
+    pub blocked_items: Vec<BlockedEntry>,
+    pub blocked_idx: usize,
+    /// Whether list and selector navigation wraps from the last item to the
+    /// first (and back) rather than stopping at the ends.
+    pub wrap_navigation: bool,
+    /// Arrangement of the main screen's panels. Cycled with `v`.
+    pub layout_mode: LayoutMode,
     pub epics: Vec<Epic>,
     pub selected_epic_idx: usize,
     pub blocked_epic_ids: HashSet<String>,
     pub tasks: Vec<BlueTask>,
     pub selected_task_idx: usize,
     pub blocked_task_ids: HashSet<String>,
-    /// Cached blocker names per task ID, computed in `refresh_tasks()`.
-    pub task_blocker_names: HashMap<String, Vec<String>>,
+    /// Cached task blockers per task ID, computed in `refresh_tasks()`.
+    pub task_blockers: HashMap<String, Vec<TaskBlocker>>,
+    /// Task whose description is shown inline in the Tasks panel, toggled
+    /// with `o`. Only drawn while that task is selected.
+    pub expanded_task: Option<String>,
+    /// Highlighted entry of the detail view's "Blocked by" list.
+    pub blocker_idx: usize,
+    /// Checklist items per task ID, for tasks that have any.
+    pub task_checklists: HashMap<String, Vec<ChecklistItem>>,
     pub epic_status_counts: HashMap<String, i64>,
     pub task_status_counts: HashMap<String, i64>,
     pub blocked_count: usize,
     pub dep_display_rows: Vec<DependencyDisplayRow>,
     pub last_refresh: Instant,
+    /// Repeat tracking for accelerated hjkl panning in the graph view.
+    pub pan_acceleration: PanAcceleration,
     pub last_db_watermark: String,
     /// Global animation frame counter (0–47) for animation effects.
     /// Advances every tick (~42ms) for ~24 fps refresh.
     pub animation_frame: u8,
+    /// When false, `animation_frame` is frozen at 0 and the screen is only
+    /// redrawn after input or a data change. Toggled with `a`.
+    pub animations_enabled: bool,
+    /// Draw statuses with ASCII markers that do not depend on color
+    /// (the `high_contrast_symbols` setting).
+    pub high_contrast_symbols: bool,
+    /// Draw the graph and symbols with plain ASCII (the `ascii_glyphs`
+    /// setting or `BLUEPRINT_ASCII`).
+    pub ascii_glyphs: bool,
+    /// Set whenever something visible changed and the next loop iteration
+    /// must redraw even with animations disabled.
+    pub needs_redraw: bool,
     pub graph_mode: GraphLevel,
     pub graph_cache: Option<GraphCache>,
     pub scroll_x: usize,
@@ -110,6 +260,34 @@ pub struct App {
     pub epic_focused_node: Option<String>,
     /// Focused node ID in dual-pane right (task) graph.
     pub task_focused_node: Option<String>,
+    /// The focused node plus its transitive ancestors and descendants while
+    /// the chain highlight is on (toggled with `f`). Everything else in the
+    /// graph is drawn dimmed.
+    pub highlight_set: Option<HashSet<String>>,
+    /// Width of graph node boxes, adjusted with `+`/`-` in graph view.
+    pub node_width: usize,
+    /// Draw the overview minimap when the graph is larger than its pane.
+    /// Toggled with `m` in graph view.
+    pub show_minimap: bool,
+    /// Draw dependency edges in graph view; hiding them leaves only the
+    /// nodes, for an overview of dense graphs. Toggled with `e`.
+    pub show_edges: bool,
+    /// Badge each graph node with its incoming and outgoing edge counts,
+    /// to spot bottlenecks. Toggled with `c` in graph view.
+    pub show_edge_counts: bool,
+    /// Dim done nodes and their edges in graph view so remaining work
+    /// stands out. Toggled with `D`; the `dim_completed` setting sets it
+    /// at startup.
+    pub dim_completed: bool,
+    /// Show full ULIDs in place of short IDs in the lists and task detail,
+    /// for copying into other tools. Toggled with `i`.
+    pub show_full_ids: bool,
+    /// Limit the graph to items that are blocked or block something.
+    /// Toggled with `o` in graph view.
+    pub blocked_only: bool,
+    /// Include archived epics and tasks, which are hidden by default.
+    /// Toggled with `A` in graph view.
+    pub show_archived: bool,
     /// Viewport size (width, height) for auto-scroll, updated each frame.
     pub graph_viewport_size: (u16, u16),
     /// Max scroll bounds, updated each frame by the render function.
@@ -117,6 +295,188 @@ pub struct App {
     pub max_scroll: Cell<(usize, usize)>,
     pub epic_max_scroll: Cell<(usize, usize)>,
     pub task_max_scroll: Cell<(usize, usize)>,
+    /// First visible row of the epic, task and project lists. Advanced by the render
+    /// function so the selected item stays in view.
+    pub epic_list_offset: Cell<usize>,
+    pub task_list_offset: Cell<usize>,
+    pub selector_list_offset: Cell<usize>,
+    /// One-off feedback shown in the footer until the next key press.
+    pub status_message: Option<String>,
+    /// Filter text of the command palette and its highlighted match.
+    pub palette_query: String,
+    pub palette_idx: usize,
+    /// Mode the command palette was opened from, restored when it closes.
+    pub palette_origin: InputMode,
+    pub project_form: ProjectForm,
+    /// Mode the new-project form was opened from, restored on cancel.
+    project_form_origin: InputMode,
+    /// Project the last refresh read blocked items for, and the IDs it found
+    /// blocked, so the next refresh can tell which blocks are new. `None`
+    /// before the first refresh.
+    blocked_snapshot: Option<(Option<String>, HashSet<String>)>,
+    /// Ring the terminal bell on the next loop iteration.
+    bell_pending: bool,
+    /// System clipboard, opened on first use. Stays `None` where there is
+    /// no clipboard to talk to (e.g. over SSH).
+    clipboard: Option<arboard::Clipboard>,
+}
+
+/// Clipboard summary of an epic, e.g. `E1 Auth (in_progress, 2/5 tasks done)`.
+pub(crate) fn epic_summary(epic: &Epic) -> String {
+    format!(
+        "{} ({}, {}/{} tasks done)",
+        summary_head(&epic.short_id, &epic.title),
+        epic.status,
+        epic.done_count,
+        epic.task_count
+    )
+}
+
+/// Clipboard summary of a task, e.g. `E1-T2 Login form (todo)`.
+pub(crate) fn task_summary(task: &BlueTask) -> String {
+    format!("{} ({})", summary_head(&task.short_id, &task.title), task.status)
+}
+
+fn summary_head(short_id: &Option<String>, title: &str) -> String {
+    match short_id {
+        Some(sid) => format!("{sid} {title}"),
+        None => title.to_string(),
+    }
+}
+
+/// IDs in `current` that were not in `previous`, sorted.
+fn newly_blocked<'a>(previous: &HashSet<String>, current: &'a HashSet<String>) -> Vec<&'a str> {
+    let mut fresh: Vec<&str> = current
+        .iter()
+        .filter(|id| !previous.contains(*id))
+        .map(String::as_str)
+        .collect();
+    fresh.sort_unstable();
+    fresh
+}
+
+/// A named action offered by the command palette (`:` or `Ctrl-p`).
+pub struct PaletteAction {
+    pub name: &'static str,
+    /// Key that runs the action directly, shown as a hint.
+    pub key: &'static str,
+    run: fn(&mut App),
+}
+
+/// Actions listed by the command palette, in the order shown for an empty
+/// query.
+const PALETTE_ACTIONS: &[PaletteAction] = &[
+    PaletteAction {
+        name: "Open dependency graph",
+        key: "d",
+        run: App::open_graph,
+    },
+    PaletteAction {
+        name: "Switch project",
+        key: "p",
+        run: App::open_project_selector,
+    },
+    PaletteAction {
+        name: "Create project",
+        key: "p n",
+        run: App::open_project_form,
+    },
+    PaletteAction {
+        name: "Switch workspace",
+        key: "w",
+        run: App::open_workspace_selector,
+    },
+    PaletteAction {
+        name: "List blocked items",
+        key: "b",
+        run: App::open_blocked_list,
+    },
+    PaletteAction {
+        name: "Cycle selected task status",
+        key: "s",
+        run: App::cycle_task_status,
+    },
+    PaletteAction {
+        name: "Cycle layout",
+        key: "v",
+        run: App::cycle_layout,
+    },
+    PaletteAction {
+        name: "Toggle animations",
+        key: "a",
+        run: App::toggle_animations,
+    },
+    PaletteAction {
+        name: "Toggle minimap",
+        key: "m",
+        run: |app| app.show_minimap = !app.show_minimap,
+    },
+    PaletteAction {
+        name: "Toggle graph edges",
+        key: "e",
+        run: |app| app.show_edges = !app.show_edges,
+    },
+    PaletteAction {
+        name: "Toggle edge count badges",
+        key: "c",
+        run: |app| app.show_edge_counts = !app.show_edge_counts,
+    },
+    PaletteAction {
+        name: "Dim completed graph nodes",
+        key: "D",
+        run: |app| app.dim_completed = !app.dim_completed,
+    },
+    PaletteAction {
+        name: "Toggle full IDs",
+        key: "i",
+        run: |app| app.show_full_ids = !app.show_full_ids,
+    },
+    PaletteAction {
+        name: "Filter graph to blocked items",
+        key: "o",
+        run: App::toggle_blocked_only,
+    },
+    PaletteAction {
+        name: "Show archived items",
+        key: "A",
+        run: App::toggle_show_archived,
+    },
+    PaletteAction {
+        name: "Show keyboard shortcuts",
+        key: "?",
+        run: |app| app.mode = InputMode::HelpOverlay,
+    },
+    PaletteAction {
+        name: "Quit",
+        key: "q",
+        run: |app| app.running = false,
+    },
+];
+
+/// Scores `candidate` as a case-insensitive subsequence match of `query`
+/// (spaces in the query are ignored), or `None` when it does not match.
+/// Matches at the start of a word and runs of consecutive characters
+/// score higher, so "sp" ranks "Switch project" above "Switch
+/// workspace".
+fn fuzzy_score(query: &str, candidate: &str) -> Option<u32> {
+    let haystack: Vec<char> = candidate.chars().map(|c| c.to_ascii_lowercase()).collect();
+    let mut score = 0;
+    let mut next = 0;
+    let mut last_match: Option<usize> = None;
+    for q in query.chars().filter(|c| !c.is_whitespace()) {
+        let q = q.to_ascii_lowercase();
+        let at = (next..haystack.len()).find(|&i| haystack[i] == q)?;
+        score += 1;
+        if at == 0 || !haystack[at - 1].is_alphanumeric() {
+            score += 3;
+        }
+        if last_match.is_some_and(|last| last + 1 == at) {
+            score += 2;
+        }
+        last_match = Some(at);
+        next = at + 1;
+    }
+    Some(score)
 }
 
 /// Wraps an index by `delta` within `len`, returning `None` when the list is empty.
@@ -127,41 +487,96 @@ fn wrap_index(current: usize, len: usize, delta: isize) -> Option<usize> {
     Some(((current as isize + delta).rem_euclid(len as isize)) as usize)
 }
 
+/// `+1` for the keys that move a list cursor down, `-1` for up.
+fn vertical_step(code: KeyCode) -> Option<isize> {
+    match code {
+        KeyCode::Char('j') | KeyCode::Down => Some(1),
+        KeyCode::Char('k') | KeyCode::Up => Some(-1),
+        _ => None,
+    }
+}
+
+/// Moves an index by `delta`, stopping at either end of a list of `len`.
+/// Returns `None` when the list is empty or the index is already at the end.
+fn clamp_index(current: usize, len: usize, delta: isize) -> Option<usize> {
+    let last = len.checked_sub(1)?;
+    let next = current.saturating_add_signed(delta).min(last);
+    (next != current).then_some(next)
+}
+
+/// First visible row of a list of `len` items shown `height` rows at a time,
+/// scrolling the minimum distance from `offset` to keep `selected` visible.
+pub(crate) fn list_offset(selected: usize, offset: usize, height: usize, len: usize) -> usize {
+    if height == 0 || len <= height {
+        return 0;
+    }
+    let offset = offset.min(len - height);
+    if selected < offset {
+        selected
+    } else if selected >= offset + height {
+        selected + 1 - height
+    } else {
+        offset
+    }
+}
+
+/// Normalize a key event for matching. Terminals disagree on whether an
+/// uppercase letter carries `SHIFT`, so it is folded into the character
+/// (`Shift+j` and `J` both become plain `J`). `BackTab` already means
+/// Shift+Tab, so its `SHIFT` is dropped too. Modifiers other than
+/// `CONTROL`, `ALT` and `SHIFT` are dropped.
+fn normalize_key(key: KeyEvent) -> KeyEvent {
+    let mut modifiers =
+        key.modifiers & (KeyModifiers::CONTROL | KeyModifiers::ALT | KeyModifiers::SHIFT);
+    let code = match key.code {
+        KeyCode::Char(c) if modifiers.contains(KeyModifiers::SHIFT) => {
+            modifiers.remove(KeyModifiers::SHIFT);
+            KeyCode::Char(c.to_ascii_uppercase())
+        }
+        KeyCode::BackTab => {
+            modifiers.remove(KeyModifiers::SHIFT);
+            KeyCode::BackTab
+        }
+        other => other,
+    };
+    KeyEvent::new(code, modifiers)
+}
+
+/// How much `+`/`-` widen or narrow graph nodes.
+const NODE_WIDTH_STEP: usize = 4;
+
 /// Build a [`GraphCache`] from a set of nodes, edges, and the node height used
 /// for vertical spacing. This is the shared logic behind both epic and task
-/// graph construction.
+/// graph construction. With `blocked_only`, nodes that are not on any
+/// incomplete edge are left out of the layout.
 fn build_graph_cache(
     nodes: Vec<Node>,
     edges: Vec<Edge>,
     node_height: usize,
     level: GraphLevel,
+    viewport_width: usize,
+    node_width: usize,
+    blocked_only: bool,
 ) -> GraphCache {
-    let layout = DagLayout::new(nodes, edges);
-
-    let h_spacing = NODE_WIDTH + 4;
-    // Use the max possible height (2-line title) for spacing so all nodes fit.
-    let max_height = node_height + 1; // +1 for potential 2-line title
-    let v_spacing = max_height + 2;
-
-    let mut node_positions = HashMap::new();
-
-    for (layer_idx, layer) in layout.layers.iter().enumerate() {
-        for (x_idx, node_id) in layer.iter().enumerate() {
-            node_positions.insert(node_id.clone(), (1 + x_idx * h_spacing, 1 + layer_idx * v_spacing));
-        }
-    }
-
-    if !layout.orphans.is_empty() {
-        let orphan_y = layout.layers.len() * v_spacing;
-        for (x_idx, node_id) in layout.orphans.iter().enumerate() {
-            node_positions.insert(node_id.clone(), (1 + x_idx * h_spacing, 1 + orphan_y));
-        }
-    }
+    let layout = if blocked_only {
+        let full = DagLayout::new(nodes.clone(), edges.clone());
+        let kept: Vec<Node> = nodes
+            .into_iter()
+            .filter(|n| full.on_incomplete_edge(&n.id))
+            .collect();
+        DagLayout::new(kept, edges)
+    } else {
+        DagLayout::new(nodes, edges)
+    };
+    let node_positions = position_nodes(&layout, node_height, viewport_width, node_width);
 
     GraphCache {
         layout,
         node_positions,
         level,
+        viewport_width,
+        node_width,
+        external_blockers: HashSet::new(),
     }
 }
 
@@ -175,20 +590,37 @@ impl App {
             projects: Vec::new(),
             selected_project_idx: 0,
             selector_idx: 0,
+            workspace: None,
+            workspace_dir: workspace::data_dir(None).ok(),
+            settings_dir: None,
+            workspaces: Vec::new(),
+            workspace_idx: 0,
+            blocked_items: Vec::new(),
+            blocked_idx: 0,
+            wrap_navigation: true,
+            layout_mode: LayoutMode::Grid,
             epics: Vec::new(),
             selected_epic_idx: 0,
             blocked_epic_ids: HashSet::new(),
             tasks: Vec::new(),
             selected_task_idx: 0,
             blocked_task_ids: HashSet::new(),
-            task_blocker_names: HashMap::new(),
+            task_blockers: HashMap::new(),
+            expanded_task: None,
+            blocker_idx: 0,
+            task_checklists: HashMap::new(),
             epic_status_counts: HashMap::new(),
             task_status_counts: HashMap::new(),
             blocked_count: 0,
             dep_display_rows: Vec::new(),
             last_refresh: Instant::now(),
+            pan_acceleration: PanAcceleration::default(),
             last_db_watermark: String::new(),
             animation_frame: 0,
+            animations_enabled: true,
+            high_contrast_symbols: false,
+            ascii_glyphs: false,
+            needs_redraw: true,
             graph_mode: GraphLevel::Epic,
             graph_cache: None,
             scroll_x: 0,
@@ -204,10 +636,31 @@ impl App {
             focused_node: None,
             epic_focused_node: None,
             task_focused_node: None,
+            highlight_set: None,
+            node_width: NODE_WIDTH,
+            show_minimap: true,
+            show_edges: true,
+            show_edge_counts: false,
+            dim_completed: false,
+            show_full_ids: false,
+            blocked_only: false,
+            show_archived: false,
             graph_viewport_size: (0, 0),
             max_scroll: Cell::new((0, 0)),
             epic_max_scroll: Cell::new((0, 0)),
             task_max_scroll: Cell::new((0, 0)),
+            epic_list_offset: Cell::new(0),
+            task_list_offset: Cell::new(0),
+            selector_list_offset: Cell::new(0),
+            status_message: None,
+            palette_query: String::new(),
+            palette_idx: 0,
+            palette_origin: InputMode::Normal,
+            project_form: ProjectForm::default(),
+            project_form_origin: InputMode::Normal,
+            blocked_snapshot: None,
+            bell_pending: false,
+            clipboard: None,
         };
         app.refresh_data();
         Ok(app)
@@ -215,31 +668,67 @@ impl App {
 
     pub fn run(&mut self, terminal: &mut Terminal<CrosstermBackend<Stdout>>) -> Result<()> {
         while self.running {
-            // Store viewport size for auto-scroll calculations.
-            if let Ok(size) = terminal.size() {
-                self.graph_viewport_size = (size.width, size.height);
-            }
+            if self.animations_enabled || self.needs_redraw {
+                // Store viewport size for auto-scroll calculations.
+                if let Ok(size) = terminal.size() {
+                    self.graph_viewport_size = (size.width, size.height);
+                    self.relayout_graphs();
+                }
 
-            terminal.draw(|frame| ui::draw(frame, self))?;
+                terminal.draw(|frame| ui::draw(frame, self))?;
+                self.needs_redraw = false;
+            }
 
-            if event::poll(Duration::from_millis(42))?
-                && let Event::Key(key) = event::read()?
-                && key.kind == KeyEventKind::Press
-            {
-                self.handle_key(key);
+            if event::poll(self.tick_interval())? {
+                match event::read()? {
+                    Event::Key(key) if key.kind == KeyEventKind::Press => {
+                        self.handle_key(key);
+                        self.needs_redraw = true;
+                    }
+                    Event::Resize(..) => self.needs_redraw = true,
+                    _ => {}
+                }
             }
 
-            // Advance animation frame every tick (~42ms ≈ 24 fps).
-            self.animation_frame = (self.animation_frame + 1) % 48;
+            self.tick();
 
             // Auto-refresh: poll DB for changes every ~1 second
             if self.last_refresh.elapsed() >= Duration::from_secs(1) {
                 self.check_for_db_changes();
             }
+
+            if std::mem::take(&mut self.bell_pending) {
+                let out = terminal.backend_mut();
+                out.write_all(b"\x07")?;
+                out.flush()?;
+            }
         }
         Ok(())
     }
 
+    /// How long the event loop waits for input before the next tick.
+    /// Animated mode ticks at ~24 fps; static mode only needs to stay
+    /// responsive to input and the 1-second DB poll.
+    fn tick_interval(&self) -> Duration {
+        if self.animations_enabled {
+            Duration::from_millis(42)
+        } else {
+            Duration::from_millis(250)
+        }
+    }
+
+    /// Advance the animation frame by one tick. No-op when animations are off.
+    fn tick(&mut self) {
+        if self.animations_enabled {
+            self.animation_frame = (self.animation_frame + 1) % 48;
+        }
+    }
+
+    fn toggle_animations(&mut self) {
+        self.animations_enabled = !self.animations_enabled;
+        self.animation_frame = 0;
+    }
+
     /// Check if the database has changed since our last refresh, and reload if so.
     fn check_for_db_changes(&mut self) {
         let watermark = get_max_updated_at(&self.db).unwrap_or_default();
@@ -260,15 +749,35 @@ impl App {
         self.epics.get(self.selected_epic_idx)
     }
 
+    /// `(done, total)` task counts of a loaded epic, for its graph node.
+    pub fn epic_progress(&self, epic_id: &str) -> Option<(usize, usize)> {
+        self.epics
+            .iter()
+            .find(|e| e.id == epic_id)
+            .map(|e| (e.done_count as usize, e.task_count as usize))
+    }
+
     pub fn refresh_data(&mut self) {
-        self.projects = list_projects(&self.db, None).unwrap_or_default();
+        self.projects = list_projects(&self.db, None, ProjectSort::Created).unwrap_or_default();
         self.selected_project_idx = self.selected_project_idx.min(self.projects.len().saturating_sub(1));
 
         self.epics = self
             .selected_project()
-            .and_then(|p| list_epics(&self.db, Some(&p.id), None).ok())
+            .and_then(|p| {
+                list_epics(
+                    &self.db,
+                    &EpicListFilter {
+                        project_id: Some(&p.id),
+                        include_archived: self.show_archived,
+                        ..Default::default()
+                    },
+                )
+                .ok()
+            })
             .unwrap_or_default();
         self.selected_epic_idx = self.selected_epic_idx.min(self.epics.len().saturating_sub(1));
+        self.epic_list_offset
+            .set(self.epic_list_offset.get().min(self.selected_epic_idx));
 
         self.blocked_epic_ids = self
             .epics
@@ -295,6 +804,7 @@ impl App {
                     GraphLevel::Task => self.build_task_graph(),
                 }
             }
+            self.refresh_highlight();
         } else {
             self.invalidate_graph_caches();
         }
@@ -306,20 +816,59 @@ impl App {
 
         self.epic_status_counts = count_epics_by_status(&self.db, pid).unwrap_or_default();
         self.task_status_counts = count_tasks_by_status(&self.db, pid).unwrap_or_default();
-        self.blocked_count = get_blocked_items(&self.db, pid)
-            .map(|v| v.len())
-            .unwrap_or(0);
+        let blocked = get_blocked_items(&self.db, pid).unwrap_or_default();
+        self.blocked_count = blocked.len();
+        self.note_new_blocks(pid, &blocked);
         self.dep_display_rows = get_dependency_display_rows(&self.db, pid).unwrap_or_default();
         self.last_db_watermark = get_max_updated_at(&self.db).unwrap_or_default();
         self.last_refresh = Instant::now();
+        self.needs_redraw = true;
+    }
+
+    /// Flash a footer message and ring the bell when items of the project
+    /// became blocked since the previous refresh, e.g. by an MCP client
+    /// adding a dependency in the background. Switching projects only
+    /// resets the baseline.
+    fn note_new_blocks(&mut self, project_id: Option<&str>, blocked: &[BlockedItemRow]) {
+        let current: HashSet<String> = blocked.iter().map(|row| row.item_id.clone()).collect();
+        let previous = self.blocked_snapshot.take();
+        if let Some((previous_project, previous_ids)) = previous
+            && previous_project.as_deref() == project_id
+        {
+            let fresh = newly_blocked(&previous_ids, &current);
+            if let Some(first) = fresh.first() {
+                self.status_message = Some(match fresh.len() {
+                    1 => {
+                        let row = blocked.iter().find(|row| row.item_id == *first);
+                        format!("Newly blocked: {}", row.map_or(*first, |row| &row.title))
+                    }
+                    n => format!("{n} items newly blocked"),
+                });
+                self.bell_pending = true;
+            }
+        }
+        self.blocked_snapshot = Some((project_id.map(str::to_string), current));
     }
 
     pub fn refresh_tasks(&mut self) {
         self.tasks = self
             .selected_epic()
-            .and_then(|e| list_tasks(&self.db, Some(&e.id), None, None).ok())
+            .and_then(|e| {
+                let archived = self.show_archived;
+                list_tasks(
+                    &self.db,
+                    &TaskListFilter {
+                        epic_id: Some(&e.id),
+                        include_archived: archived,
+                        ..Default::default()
+                    },
+                )
+                .ok()
+            })
             .unwrap_or_default();
         self.selected_task_idx = self.selected_task_idx.min(self.tasks.len().saturating_sub(1));
+        self.task_list_offset
+            .set(self.task_list_offset.get().min(self.selected_task_idx));
 
         self.blocked_task_ids = self
             .tasks
@@ -328,21 +877,34 @@ impl App {
             .map(|t| t.id.clone())
             .collect();
 
-        self.task_blocker_names = self
+        self.task_blockers = self
             .blocked_task_ids
             .iter()
             .map(|task_id| {
-                let names = get_blockers(&self.db, &DependencyType::Task, task_id)
+                let blockers = get_blockers(&self.db, &DependencyType::Task, task_id)
                     .unwrap_or_default()
                     .into_iter()
                     .filter_map(|dep| {
                         get_task(&self.db, &dep.blocker_id)
                             .ok()
                             .flatten()
-                            .map(|t| t.title)
+                            .map(|t| TaskBlocker {
+                                id: t.id,
+                                epic_id: t.epic_id,
+                                title: t.title,
+                            })
                     })
                     .collect();
-                (task_id.clone(), names)
+                (task_id.clone(), blockers)
+            })
+            .collect();
+
+        self.task_checklists = self
+            .tasks
+            .iter()
+            .filter_map(|t| {
+                let items = list_checklist_items(&self.db, &t.id).unwrap_or_default();
+                (!items.is_empty()).then(|| (t.id.clone(), items))
             })
             .collect();
     }
@@ -352,13 +914,67 @@ impl App {
         self.tasks.get(self.selected_task_idx)
     }
 
+    /// Expand the selected task's description inline, or collapse it.
+    fn toggle_task_expansion(&mut self) {
+        let Some(id) = self.selected_task().map(|t| t.id.clone()) else {
+            return;
+        };
+        self.expanded_task = match self.expanded_task.take() {
+            Some(expanded) if expanded == id => None,
+            _ => Some(id),
+        };
+    }
+
+    /// Whether the Tasks panel shows `task_id` expanded: it was toggled
+    /// open and is still the selected task.
+    pub fn is_task_expanded(&self, task_id: &str) -> bool {
+        self.expanded_task.as_deref() == Some(task_id)
+            && self.selected_task().is_some_and(|t| t.id == task_id)
+    }
+
     fn handle_key(&mut self, key: KeyEvent) {
+        let key = normalize_key(key);
+        if self.status_message.take().is_some() {
+            self.needs_redraw = true;
+        }
+
+        // Modified keys never fall through to their plain counterparts.
+        if !key.modifiers.is_empty() {
+            self.handle_modified_key(key);
+            return;
+        }
+
         match self.mode {
             InputMode::Normal => self.handle_normal_key(key),
             InputMode::ProjectSelector => self.handle_selector_key(key),
+            InputMode::WorkspaceSelector => self.handle_workspace_key(key),
+            InputMode::BlockedList => self.handle_blocked_list_key(key),
             InputMode::TaskDetail => self.handle_task_detail_key(key),
             InputMode::HelpOverlay => self.handle_help_key(key),
             InputMode::GraphView => self.handle_graph_key(key),
+            InputMode::CommandPalette => self.handle_palette_key(key),
+            InputMode::ProjectCreate => self.handle_project_form_key(key),
+        }
+    }
+
+    /// Handle keys carrying `CONTROL`/`ALT` (or `SHIFT` on non-character
+    /// keys). Unbound combinations are ignored.
+    fn handle_modified_key(&mut self, key: KeyEvent) {
+        let ctrl = KeyModifiers::CONTROL;
+        match (self.mode, key.code, key.modifiers) {
+            (InputMode::Normal | InputMode::GraphView, KeyCode::Char('p'), m) if m == ctrl => {
+                self.open_command_palette();
+            }
+            (InputMode::GraphView, KeyCode::Char('f'), m) if m == ctrl => self.page_graph(true),
+            (InputMode::GraphView, KeyCode::Char('b'), m) if m == ctrl => self.page_graph(false),
+            (InputMode::ProjectCreate, KeyCode::Char('c'), m) if m == ctrl => {
+                self.leave_project_form(FormExit::Quit);
+            }
+            // AltGr arrives as CONTROL|ALT; the character is what was typed.
+            (InputMode::ProjectCreate, KeyCode::Char(_), m) if m == ctrl | KeyModifiers::ALT => {
+                self.handle_project_form_key(KeyEvent::from(key.code));
+            }
+            _ => {}
         }
     }
 
@@ -366,14 +982,15 @@ impl App {
         match key.code {
             KeyCode::Char('q') => self.running = false,
             KeyCode::Char('p') => self.open_project_selector(),
+            KeyCode::Char('n') if self.projects.is_empty() => self.open_project_form(),
+            KeyCode::Char('w') => self.open_workspace_selector(),
+            KeyCode::Char('b') => self.open_blocked_list(),
             KeyCode::Char('?') => self.mode = InputMode::HelpOverlay,
-            KeyCode::Char('d') => {
-                self.reset_scroll();
-                self.graph_mode = GraphLevel::Epic;
-                self.build_epic_graph();
-                self.focused_node = None;
-                self.mode = InputMode::GraphView;
-            }
+            KeyCode::Char(':') => self.open_command_palette(),
+            KeyCode::Char('a') => self.toggle_animations(),
+            KeyCode::Char('v') => self.cycle_layout(),
+            KeyCode::Char('d') => self.open_graph(),
+            KeyCode::Char('i') => self.show_full_ids = !self.show_full_ids,
             KeyCode::Tab => self.toggle_focus(),
             KeyCode::Char('h') | KeyCode::Left => self.focus_left(),
             KeyCode::Char('l') | KeyCode::Right => self.focus_right(),
@@ -382,16 +999,84 @@ impl App {
             KeyCode::Char('s') if self.focused_panel == FocusedPanel::Tasks => {
                 self.cycle_task_status();
             }
+            KeyCode::Char('o') if self.focused_panel == FocusedPanel::Tasks => {
+                self.toggle_task_expansion();
+            }
+            KeyCode::Char('J') if self.focused_panel == FocusedPanel::Epics => {
+                self.move_selected_epic(1);
+            }
+            KeyCode::Char('K') if self.focused_panel == FocusedPanel::Epics => {
+                self.move_selected_epic(-1);
+            }
             KeyCode::Enter
                 if self.focused_panel == FocusedPanel::Tasks
                     && self.selected_task().is_some() =>
             {
+                self.blocker_idx = 0;
                 self.mode = InputMode::TaskDetail;
             }
             _ => {}
         }
     }
 
+    /// Switch to the epic-level dependency graph.
+    fn open_graph(&mut self) {
+        self.reset_scroll();
+        self.graph_mode = GraphLevel::Epic;
+        self.build_epic_graph();
+        self.focused_node = None;
+        self.highlight_set = None;
+        self.mode = InputMode::GraphView;
+    }
+
+    fn open_command_palette(&mut self) {
+        self.palette_origin = self.mode;
+        self.palette_query.clear();
+        self.palette_idx = 0;
+        self.mode = InputMode::CommandPalette;
+    }
+
+    /// Palette actions matching the query, best match first. Equal scores
+    /// keep the table order.
+    pub fn palette_matches(&self) -> Vec<&'static PaletteAction> {
+        let mut scored: Vec<_> = PALETTE_ACTIONS
+            .iter()
+            .filter_map(|action| fuzzy_score(&self.palette_query, action.name).map(|s| (s, action)))
+            .collect();
+        scored.sort_by_key(|&(score, _)| std::cmp::Reverse(score));
+        scored.into_iter().map(|(_, action)| action).collect()
+    }
+
+    /// Typed characters edit the query; the arrow keys move through the
+    /// matches, since j/k are text here.
+    fn handle_palette_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => self.mode = self.palette_origin,
+            KeyCode::Enter => {
+                if let Some(action) = self.palette_matches().get(self.palette_idx) {
+                    self.mode = self.palette_origin;
+                    (action.run)(self);
+                }
+            }
+            KeyCode::Up | KeyCode::Down => {
+                let delta = if key.code == KeyCode::Down { 1 } else { -1 };
+                let len = self.palette_matches().len();
+                if let Some(next) = self.step_index(self.palette_idx, len, delta) {
+                    self.palette_idx = next;
+                }
+            }
+            KeyCode::Backspace => {
+                self.palette_query.pop();
+                self.palette_idx = 0;
+            }
+            KeyCode::Char(c) => {
+                self.palette_query.push(c);
+                self.palette_idx = 0;
+            }
+            _ => {}
+        }
+    }
+
     fn handle_help_key(&mut self, key: KeyEvent) {
         match key.code {
             KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('?') => {
@@ -402,14 +1087,62 @@ impl App {
     }
 
     fn handle_task_detail_key(&mut self, key: KeyEvent) {
+        let len = self.detail_blockers().len();
+        let step = vertical_step(key.code).and_then(|d| self.step_index(self.blocker_idx, len, d));
+        if let Some(next) = step {
+            self.blocker_idx = next;
+        }
         match key.code {
-            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Enter => {
+            KeyCode::Enter => {
+                self.mode = InputMode::Normal;
+                if let Some(blocker) = self.selected_blocker().cloned() {
+                    self.select_item(&DependencyType::Task, &blocker.epic_id, &blocker.id);
+                }
+            }
+            KeyCode::Char('y') => self.copy_detail_session_id(),
+            KeyCode::Char('i') => self.show_full_ids = !self.show_full_ids,
+            KeyCode::Esc | KeyCode::Char('q') => {
                 self.mode = InputMode::Normal;
             }
             _ => {}
         }
     }
 
+    /// Glyph set selected by the display settings.
+    pub fn glyphs(&self) -> theme::Glyphs {
+        theme::Glyphs { ascii: self.ascii_glyphs, high_contrast: self.high_contrast_symbols }
+    }
+
+    /// Session ID of the task shown in the detail view, when it is claimed.
+    pub fn detail_session_id(&self) -> Option<&str> {
+        self.selected_task()?.session_id.as_deref()
+    }
+
+    /// Copy the detail view's session ID, as `y` does there. Without a
+    /// session there is nothing to copy and nothing happens.
+    fn copy_detail_session_id(&mut self) {
+        let Some(session_id) = self.detail_session_id().map(String::from) else {
+            return;
+        };
+        self.status_message = Some(if self.copy_to_clipboard(&session_id) {
+            format!("Copied session: {session_id}")
+        } else {
+            "Clipboard unavailable".to_string()
+        });
+    }
+
+    /// Task blockers of the task shown in the detail view.
+    pub fn detail_blockers(&self) -> &[TaskBlocker] {
+        self.selected_task()
+            .and_then(|t| self.task_blockers.get(&t.id))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// The highlighted blocker, which Enter in the detail view jumps to.
+    pub fn selected_blocker(&self) -> Option<&TaskBlocker> {
+        self.detail_blockers().get(self.blocker_idx)
+    }
+
     fn handle_graph_key(&mut self, key: KeyEvent) {
         match key.code {
             KeyCode::Esc => {
@@ -424,6 +1157,7 @@ impl App {
                     self.exit_dual_to_single_epic();
                 }
                 self.focused_node = None;
+                self.highlight_set = None;
             }
             KeyCode::Char('2') => {
                 self.dual_pane = false;
@@ -431,6 +1165,7 @@ impl App {
                 self.graph_mode = GraphLevel::Task;
                 self.build_task_graph();
                 self.focused_node = None;
+                self.highlight_set = None;
             }
             KeyCode::Char('3') => {
                 if self.dual_pane {
@@ -442,12 +1177,30 @@ impl App {
                     self.epic_focused_node = None;
                     self.task_focused_node = None;
                 }
+                self.highlight_set = None;
+            }
+            KeyCode::Char(':') => self.open_command_palette(),
+            KeyCode::Char('a') => self.toggle_animations(),
+            KeyCode::Char('f') => self.toggle_highlight(),
+            KeyCode::Char('y') => self.copy_focused_summary(),
+            KeyCode::Char('m') => self.show_minimap = !self.show_minimap,
+            KeyCode::Char('e') => self.show_edges = !self.show_edges,
+            KeyCode::Char('c') => self.show_edge_counts = !self.show_edge_counts,
+            KeyCode::Char('D') => self.dim_completed = !self.dim_completed,
+            KeyCode::Char('o') => self.toggle_blocked_only(),
+            KeyCode::Char('A') => self.toggle_show_archived(),
+            KeyCode::Char('+') | KeyCode::Char('=') => {
+                self.set_node_width(self.node_width + NODE_WIDTH_STEP);
+            }
+            KeyCode::Char('-') => {
+                self.set_node_width(self.node_width.saturating_sub(NODE_WIDTH_STEP));
             }
             KeyCode::Tab if self.dual_pane => {
                 self.active_pane = match self.active_pane {
                     GraphPane::Left => GraphPane::Right,
                     GraphPane::Right => GraphPane::Left,
                 };
+                self.highlight_set = None;
             }
             // Arrow keys: node navigation
             KeyCode::Down | KeyCode::Up | KeyCode::Right | KeyCode::Left => {
@@ -460,30 +1213,61 @@ impl App {
                 self.navigate_graph_node(direction);
                 self.ensure_focused_node_visible();
                 self.sync_task_graph_to_focused_epic();
+                self.refresh_highlight();
             }
             // hjkl: viewport panning
-            KeyCode::Char('j') => {
-                let (_, max_y) = self.active_max_scroll();
+            KeyCode::Char('j') => self.pan_graph(GraphDirection::Down, Instant::now()),
+            KeyCode::Char('k') => self.pan_graph(GraphDirection::Up, Instant::now()),
+            KeyCode::Char('l') => self.pan_graph(GraphDirection::Right, Instant::now()),
+            KeyCode::Char('h') => self.pan_graph(GraphDirection::Left, Instant::now()),
+            // Jump to top/bottom
+            KeyCode::Char('g') => {
                 let (_, sy) = self.active_scroll_mut();
-                *sy = sy.saturating_add(1).min(max_y);
+                *sy = 0;
             }
-            KeyCode::Char('k') => {
+            KeyCode::Char('G') => {
+                let (_, max_y) = self.active_max_scroll();
                 let (_, sy) = self.active_scroll_mut();
-                *sy = sy.saturating_sub(1);
-            }
-            KeyCode::Char('l') => {
-                let (max_x, _) = self.active_max_scroll();
-                let (sx, _) = self.active_scroll_mut();
-                *sx = sx.saturating_add(1).min(max_x);
-            }
-            KeyCode::Char('h') => {
-                let (sx, _) = self.active_scroll_mut();
-                *sx = sx.saturating_sub(1);
+                *sy = max_y;
             }
+            // Page by one viewport height
+            KeyCode::PageDown => self.page_graph(true),
+            KeyCode::PageUp => self.page_graph(false),
             _ => {}
         }
     }
 
+    /// Pan the active pane's viewport, by more cells the longer the key repeats.
+    fn pan_graph(&mut self, direction: GraphDirection, now: Instant) {
+        let step = self.pan_acceleration.step(direction, now);
+        let (max_x, max_y) = self.active_max_scroll();
+        let (sx, sy) = self.active_scroll_mut();
+        match direction {
+            GraphDirection::Down => *sy = sy.saturating_add(step).min(max_y),
+            GraphDirection::Up => *sy = sy.saturating_sub(step),
+            GraphDirection::Right => *sx = sx.saturating_add(step).min(max_x),
+            GraphDirection::Left => *sx = sx.saturating_sub(step),
+        }
+    }
+
+    /// Approximate graph viewport height: terminal rows minus header,
+    /// summary and footer chrome (~7 rows).
+    fn graph_viewport_height(&self) -> usize {
+        (self.graph_viewport_size.1 as usize).saturating_sub(7)
+    }
+
+    /// Scroll the active pane by one viewport height, clamped to `max_scroll`.
+    fn page_graph(&mut self, down: bool) {
+        let page = self.graph_viewport_height().max(1);
+        let (_, max_y) = self.active_max_scroll();
+        let (_, sy) = self.active_scroll_mut();
+        *sy = if down {
+            sy.saturating_add(page).min(max_y)
+        } else {
+            sy.saturating_sub(page)
+        };
+    }
+
     fn active_max_scroll(&self) -> (usize, usize) {
         if self.dual_pane {
             match self.active_pane {
@@ -530,6 +1314,63 @@ impl App {
         }
     }
 
+    /// Turn the chain highlight on for the focused node, or off if it is
+    /// already on. Does nothing without a focused node.
+    fn toggle_highlight(&mut self) {
+        self.highlight_set = match self.highlight_set {
+            Some(_) => None,
+            None => self.focused_related_nodes(),
+        };
+    }
+
+    /// Recompute an active highlight after the focus or the graph changed.
+    fn refresh_highlight(&mut self) {
+        if self.highlight_set.is_some() {
+            self.highlight_set = self.focused_related_nodes();
+        }
+    }
+
+    fn focused_related_nodes(&self) -> Option<HashSet<String>> {
+        let id = self.active_focused_node()?;
+        let cache = self.active_graph_cache()?;
+        Some(cache.layout.related_nodes(id))
+    }
+
+    /// One-line summary of the focused node, as copied by `y`.
+    pub fn focused_node_summary(&self) -> Option<String> {
+        let id = self.active_focused_node()?;
+        match self.active_graph_cache()?.level {
+            GraphLevel::Epic => self.epics.iter().find(|e| e.id == id).map(epic_summary),
+            GraphLevel::Task => self.tasks.iter().find(|t| t.id == id).map(task_summary),
+        }
+    }
+
+    /// Copy the focused node's summary to the system clipboard and report
+    /// the outcome in the footer.
+    fn copy_focused_summary(&mut self) {
+        let Some(summary) = self.focused_node_summary() else {
+            self.status_message = Some("No node focused".to_string());
+            return;
+        };
+        self.status_message = Some(if self.copy_to_clipboard(&summary) {
+            format!("Copied: {summary}")
+        } else {
+            "Clipboard unavailable".to_string()
+        });
+    }
+
+    /// Put `text` on the system clipboard, opening it on first use. False
+    /// when there is no clipboard or the write fails.
+    fn copy_to_clipboard(&mut self, text: &str) -> bool {
+        if self.clipboard.is_none() {
+            self.clipboard = arboard::Clipboard::new().ok();
+        }
+        match &mut self.clipboard {
+            Some(clipboard) => clipboard.set_text(text.to_string()).is_ok(),
+            None => false,
+        }
+    }
+
     /// Returns the focused node ID for the current pane/mode.
     pub fn active_focused_node(&self) -> Option<&str> {
         if self.dual_pane {
@@ -659,24 +1500,19 @@ impl App {
             return;
         };
 
-        let has_progress = cache.level == GraphLevel::Epic;
+        let node_width = cache.node_width;
+        let has_progress = cache.level == GraphLevel::Epic
+            && graph_render::has_progress_bar(self.epic_progress(&focused_id));
         let default_height = if has_progress { NODE_HEIGHT_EPIC } else { NODE_HEIGHT_TASK };
         let node_height = cache
             .layout
             .nodes
             .get(&focused_id)
-            .map(|n| graph_render::node_height(&n.label, has_progress))
+            .map(|n| graph_render::node_height(&n.label, has_progress, node_width))
             .unwrap_or(default_height);
 
-        // Approximate viewport size: use stored terminal size minus chrome.
-        // In dual-pane mode, the viewport is roughly half the terminal width.
-        let vw = if self.dual_pane {
-            (self.graph_viewport_size.0 as usize) / 2
-        } else {
-            self.graph_viewport_size.0 as usize
-        };
-        // Subtract header/footer/summary chrome (~7 rows).
-        let vh = (self.graph_viewport_size.1 as usize).saturating_sub(7);
+        let vw = self.graph_pane_width();
+        let vh = self.graph_viewport_height();
 
         if vw == 0 || vh == 0 {
             return;
@@ -689,8 +1525,8 @@ impl App {
         // Horizontal
         if node_x < sx.saturating_add(padding) {
             *sx = node_x.saturating_sub(padding);
-        } else if node_x + NODE_WIDTH + padding > *sx + vw {
-            *sx = (node_x + NODE_WIDTH + padding).saturating_sub(vw);
+        } else if node_x + node_width + padding > *sx + vw {
+            *sx = (node_x + node_width + padding).saturating_sub(vw);
         }
 
         // Vertical
@@ -701,6 +1537,40 @@ impl App {
         }
     }
 
+    /// Change the node box width (clamped to the supported range) and
+    /// re-position any cached graphs to match.
+    pub fn set_node_width(&mut self, width: usize) {
+        self.node_width = width.clamp(MIN_NODE_WIDTH, MAX_NODE_WIDTH);
+        self.relayout_graphs();
+        self.ensure_focused_node_visible();
+    }
+
+    /// Approximate width of one graph pane: the terminal width, or half of
+    /// it in dual-pane mode.
+    fn graph_pane_width(&self) -> usize {
+        let width = self.graph_viewport_size.0 as usize;
+        if self.dual_pane { width / 2 } else { width }
+    }
+
+    /// Re-position cached graphs whose layout was computed for a different
+    /// pane or node width, e.g. after a terminal resize.
+    fn relayout_graphs(&mut self) {
+        let width = self.graph_pane_width();
+        let node_width = self.node_width;
+        for cache in [
+            &mut self.graph_cache,
+            &mut self.epic_graph_cache,
+            &mut self.task_graph_cache,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if cache.viewport_width != width || cache.node_width != node_width {
+                cache.relayout(width, node_width);
+            }
+        }
+    }
+
     fn exit_dual_to_single_epic(&mut self) {
         self.dual_pane = false;
         self.reset_scroll();
@@ -708,32 +1578,21 @@ impl App {
         self.build_epic_graph();
     }
 
-    fn node_label(short_id: &Option<String>, title: &str) -> String {
-        match short_id {
-            Some(sid) => format!("[{sid}] {title}"),
-            None => title.to_string(),
-        }
-    }
-
     pub fn build_epic_graph(&mut self) {
-        let nodes: Vec<Node> = self
-            .epics
-            .iter()
-            .map(|e| Node {
-                id: e.id.clone(),
-                label: Self::node_label(&e.short_id, &e.title),
-                status: e.status.clone(),
-                layer: None,
-                x_position: 0,
-            })
-            .collect();
-
-        let edges = self.collect_dependency_edges(
-            self.epics.iter().map(|e| &e.id),
-            &DependencyType::Epic,
+        let (nodes, edges) = epic_nodes_and_edges(&self.db, &self.epics);
+
+        let mut cache = build_graph_cache(
+            nodes,
+            edges,
+            NODE_HEIGHT_EPIC,
+            GraphLevel::Epic,
+            self.graph_pane_width(),
+            self.node_width,
+            self.blocked_only,
         );
-
-        self.graph_cache = Some(build_graph_cache(nodes, edges, NODE_HEIGHT_EPIC, GraphLevel::Epic));
+        cache.external_blockers =
+            external_blockers(&self.db, &cache.layout, &DependencyType::Epic);
+        self.graph_cache = Some(cache);
     }
 
     pub fn build_dual_graphs(&mut self) {
@@ -755,24 +1614,56 @@ impl App {
             return;
         }
 
-        let nodes: Vec<Node> = self
-            .tasks
-            .iter()
-            .map(|t| Node {
-                id: t.id.clone(),
-                label: Self::node_label(&t.short_id, &t.title),
-                status: t.status.clone(),
-                layer: None,
-                x_position: 0,
-            })
-            .collect();
+        let (nodes, edges) = task_nodes_and_edges(&self.db, &self.tasks);
 
-        let edges = self.collect_dependency_edges(
-            self.tasks.iter().map(|t| &t.id),
-            &DependencyType::Task,
+        let mut cache = build_graph_cache(
+            nodes,
+            edges,
+            NODE_HEIGHT_TASK,
+            GraphLevel::Task,
+            self.graph_pane_width(),
+            self.node_width,
+            self.blocked_only,
         );
+        cache.external_blockers =
+            external_blockers(&self.db, &cache.layout, &DependencyType::Task);
+        self.graph_cache = Some(cache);
+    }
+
+    /// Switch between the full graph and the blocked subgraph, rebuilding
+    /// whichever graphs are on screen.
+    fn toggle_blocked_only(&mut self) {
+        self.blocked_only = !self.blocked_only;
+        if self.dual_pane {
+            self.build_dual_graphs();
+            self.epic_focused_node = None;
+            self.task_focused_node = None;
+        } else {
+            self.reset_scroll();
+            match self.graph_mode {
+                GraphLevel::Epic => self.build_epic_graph(),
+                GraphLevel::Task => self.build_task_graph(),
+            }
+            self.focused_node = None;
+        }
+        self.highlight_set = None;
+        self.status_message = Some(if self.blocked_only {
+            "Showing blocked items only".to_string()
+        } else {
+            "Showing all items".to_string()
+        });
+    }
 
-        self.graph_cache = Some(build_graph_cache(nodes, edges, NODE_HEIGHT_TASK, GraphLevel::Task));
+    /// Show or hide archived epics and tasks, reloading the lists and
+    /// whichever graphs are on screen.
+    fn toggle_show_archived(&mut self) {
+        self.show_archived = !self.show_archived;
+        self.refresh_data();
+        self.status_message = Some(if self.show_archived {
+            "Showing archived items".to_string()
+        } else {
+            "Hiding archived items".to_string()
+        });
     }
 
     /// Clear all graph caches so they are rebuilt on next entry.
@@ -783,36 +1674,26 @@ impl App {
     }
 
     /// Collect outgoing dependency edges for the given item IDs and type.
-    fn collect_dependency_edges<'a>(
-        &self,
-        item_ids: impl Iterator<Item = &'a String>,
-        dep_type: &DependencyType,
-    ) -> Vec<Edge> {
-        let mut edges = Vec::new();
-        for id in item_ids {
-            if let Ok(deps) = get_blocked_by(&self.db, dep_type, id) {
-                for dep in deps {
-                    if &dep.blocked_type == dep_type {
-                        edges.push(Edge {
-                            from: id.clone(),
-                            to: dep.blocked_id,
-                        });
-                    }
-                }
-            }
-        }
-        edges
-    }
-
     fn toggle_focus(&mut self) {
-        self.focused_panel = match self.focused_panel {
-            FocusedPanel::Epics => FocusedPanel::Tasks,
-            FocusedPanel::Tasks => FocusedPanel::Dependencies,
-            FocusedPanel::Dependencies => FocusedPanel::Status,
-            FocusedPanel::Status => FocusedPanel::Epics,
+        self.focused_panel = match (self.focused_panel, self.layout_mode) {
+            (FocusedPanel::Epics, _) => FocusedPanel::Tasks,
+            (FocusedPanel::Tasks, LayoutMode::Grid) => FocusedPanel::Dependencies,
+            (FocusedPanel::Dependencies, _) => FocusedPanel::Status,
+            (FocusedPanel::Tasks | FocusedPanel::Status, _) => FocusedPanel::Epics,
         };
     }
 
+    /// Switch to the next panel layout. The list layout only shows epics and
+    /// tasks, so focus on a hidden panel falls back to the epic list.
+    fn cycle_layout(&mut self) {
+        self.layout_mode = self.layout_mode.next();
+        if self.layout_mode == LayoutMode::List
+            && !matches!(self.focused_panel, FocusedPanel::Epics | FocusedPanel::Tasks)
+        {
+            self.focused_panel = FocusedPanel::Epics;
+        }
+    }
+
     /// Switch focus between left/right panels on the same row.
     fn focus_left(&mut self) {
         self.focused_panel = match self.focused_panel {
@@ -830,19 +1711,29 @@ impl App {
         };
     }
 
+    /// Index `delta` steps from `current` in a list of `len`, wrapping or
+    /// stopping at the ends per [`App::wrap_navigation`]. `None` means stay.
+    fn step_index(&self, current: usize, len: usize, delta: isize) -> Option<usize> {
+        if self.wrap_navigation {
+            wrap_index(current, len, delta)
+        } else {
+            clamp_index(current, len, delta)
+        }
+    }
+
     /// Moves the selection cursor by `delta` (+1 for down, -1 for up) in the
-    /// currently focused panel, wrapping around at both ends.
+    /// currently focused panel.
     fn navigate(&mut self, delta: isize) {
         match self.focused_panel {
             FocusedPanel::Epics => {
-                if let Some(next) = wrap_index(self.selected_epic_idx, self.epics.len(), delta) {
+                if let Some(next) = self.step_index(self.selected_epic_idx, self.epics.len(), delta) {
                     self.selected_epic_idx = next;
                     self.selected_task_idx = 0;
                     self.refresh_tasks();
                 }
             }
             FocusedPanel::Tasks => {
-                if let Some(next) = wrap_index(self.selected_task_idx, self.tasks.len(), delta) {
+                if let Some(next) = self.step_index(self.selected_task_idx, self.tasks.len(), delta) {
                     self.selected_task_idx = next;
                 }
             }
@@ -869,20 +1760,41 @@ impl App {
                 status: Some(next),
                 ..Default::default()
             },
+            None,
         );
         self.refresh_data();
     }
 
-    fn handle_selector_key(&mut self, key: KeyEvent) {
-        let len = self.projects.len();
-        match key.code {
-            KeyCode::Char('j') | KeyCode::Down if len > 0 => {
-                self.selector_idx = (self.selector_idx + 1) % len;
-            }
-            KeyCode::Char('k') | KeyCode::Up if len > 0 => {
-                self.selector_idx = (self.selector_idx + len - 1) % len;
-            }
+    /// Swaps the selected epic with its neighbour `delta` places away and
+    /// saves the new order. The selection follows the moved epic; moving past
+    /// either end does nothing.
+    fn move_selected_epic(&mut self, delta: isize) {
+        let from = self.selected_epic_idx;
+        let Some(to) = from.checked_add_signed(delta).filter(|&i| i < self.epics.len()) else {
+            return;
+        };
+        let Some(project_id) = self.selected_project().map(|p| p.id.clone()) else {
+            return;
+        };
+        let mut order: Vec<String> = self.epics.iter().map(|e| e.id.clone()).collect();
+        order.swap(from, to);
+        if reorder_epics(&self.db, &project_id, &order).is_err() {
+            self.status_message = Some("Failed to reorder epics".to_string());
+            return;
+        }
+        self.selected_epic_idx = to;
+        self.refresh_data();
+    }
+
+    fn handle_selector_key(&mut self, key: KeyEvent) {
+        let len = self.projects.len();
+        let step = vertical_step(key.code).and_then(|d| self.step_index(self.selector_idx, len, d));
+        if let Some(next) = step {
+            self.selector_idx = next;
+        }
+        match key.code {
             KeyCode::Enter => self.confirm_project_selection(),
+            KeyCode::Char('n') => self.open_project_form(),
             KeyCode::Esc | KeyCode::Char('q') => {
                 self.mode = InputMode::Normal;
             }
@@ -890,6 +1802,89 @@ impl App {
         }
     }
 
+    fn open_project_form(&mut self) {
+        self.project_form = ProjectForm::default();
+        self.project_form_origin = self.mode;
+        self.mode = InputMode::ProjectCreate;
+    }
+
+    /// Typed characters go to the active field; Tab switches fields, Enter
+    /// creates the project and Esc goes back without creating anything.
+    /// While the discard prompt is open, `y` leaves and `n` or Esc returns
+    /// to editing.
+    fn handle_project_form_key(&mut self, key: KeyEvent) {
+        if let Some(exit) = self.project_form.confirm_discard {
+            match key.code {
+                KeyCode::Char('y') => self.exit_project_form(exit),
+                KeyCode::Char('n') | KeyCode::Esc => self.project_form.confirm_discard = None,
+                _ => {}
+            }
+            return;
+        }
+
+        let form = &mut self.project_form;
+        let field = if form.on_description { &mut form.description } else { &mut form.name };
+        match key.code {
+            KeyCode::Esc => self.leave_project_form(FormExit::Cancel),
+            KeyCode::Enter => self.submit_project_form(),
+            KeyCode::Tab | KeyCode::BackTab => form.on_description = !form.on_description,
+            KeyCode::Backspace if field.pop().is_some() => form.dirty = true,
+            KeyCode::Char(c) => {
+                field.push(c);
+                form.dirty = true;
+            }
+            _ => {}
+        }
+    }
+
+    /// Leave the form, first asking to discard typed text if there is any.
+    fn leave_project_form(&mut self, exit: FormExit) {
+        if self.project_form.dirty {
+            self.project_form.confirm_discard = Some(exit);
+        } else {
+            self.exit_project_form(exit);
+        }
+    }
+
+    fn exit_project_form(&mut self, exit: FormExit) {
+        self.project_form.confirm_discard = None;
+        match exit {
+            FormExit::Cancel => self.mode = self.project_form_origin,
+            FormExit::Quit => self.running = false,
+        }
+    }
+
+    /// Create the project from the form and make it the selected project.
+    /// A blank name keeps the form open with an error.
+    fn submit_project_form(&mut self) {
+        let name = self.project_form.name.trim();
+        if name.is_empty() {
+            self.project_form.error = Some("Project name must not be empty".to_string());
+            self.project_form.on_description = false;
+            return;
+        }
+        let input = CreateProjectInput {
+            name: name.to_string(),
+            description: self.project_form.description.trim().to_string(),
+        };
+        let project = match create_project(&self.db, input) {
+            Ok(project) => project,
+            Err(e) => {
+                log::error!("create_project error: {e:#}");
+                self.project_form.error = Some("Failed to create project".to_string());
+                return;
+            }
+        };
+
+        self.refresh_data();
+        if let Some(idx) = self.projects.iter().position(|p| p.id == project.id) {
+            self.selector_idx = idx;
+            self.confirm_project_selection();
+        }
+        self.mode = InputMode::Normal;
+        self.status_message = Some(format!("Created project {}", project.name));
+    }
+
     fn open_project_selector(&mut self) {
         if self.projects.is_empty() {
             return;
@@ -898,20 +1893,251 @@ impl App {
         self.mode = InputMode::ProjectSelector;
     }
 
+    fn handle_workspace_key(&mut self, key: KeyEvent) {
+        let len = self.workspaces.len();
+        let step = vertical_step(key.code).and_then(|d| self.step_index(self.workspace_idx, len, d));
+        if let Some(next) = step {
+            self.workspace_idx = next;
+        }
+        match key.code {
+            KeyCode::Enter => self.confirm_workspace_selection(),
+            KeyCode::Esc | KeyCode::Char('q') => {
+                self.mode = InputMode::Normal;
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_blocked_list_key(&mut self, key: KeyEvent) {
+        let len = self.blocked_items.len();
+        let step = vertical_step(key.code).and_then(|d| self.step_index(self.blocked_idx, len, d));
+        if let Some(next) = step {
+            self.blocked_idx = next;
+        }
+        match key.code {
+            KeyCode::Enter => self.jump_to_blocked_item(),
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Char('b') => {
+                self.mode = InputMode::Normal;
+            }
+            _ => {}
+        }
+    }
+
+    /// Label for a blocker, which may be an epic or a task.
+    fn item_label(&self, item_type: &DependencyType, id: &str) -> String {
+        let found = match item_type {
+            DependencyType::Epic => get_epic(&self.db, id)
+                .ok()
+                .flatten()
+                .map(|e| summary_head(&e.short_id, &e.title)),
+            DependencyType::Task => get_task(&self.db, id)
+                .ok()
+                .flatten()
+                .map(|t| summary_head(&t.short_id, &t.title)),
+        };
+        found.unwrap_or_else(|| id.to_string())
+    }
+
+    /// Blocked epics and tasks of the selected project, one entry per item
+    /// with all of its unfinished blockers, in [`get_blocked_items`] order.
+    fn load_blocked_items(&self) -> Vec<BlockedEntry> {
+        let pid = self.selected_project().map(|p| p.id.clone());
+        let rows = get_blocked_items(&self.db, pid.as_deref()).unwrap_or_default();
+
+        let mut entries: Vec<BlockedEntry> = Vec::new();
+        for row in rows {
+            let (Ok(item_type), Ok(blocker_type)) = (
+                row.item_type.parse::<DependencyType>(),
+                row.blocker_type.parse::<DependencyType>(),
+            ) else {
+                continue;
+            };
+            let blocker = self.item_label(&blocker_type, &row.blocker_id);
+            match entries
+                .iter_mut()
+                .find(|e| e.item_type == item_type && e.id == row.item_id)
+            {
+                Some(entry) => entry.blockers.push(blocker),
+                None => entries.push(BlockedEntry {
+                    label: self.item_label(&item_type, &row.item_id),
+                    item_type,
+                    id: row.item_id,
+                    blockers: vec![blocker],
+                }),
+            }
+        }
+        entries
+    }
+
+    fn open_blocked_list(&mut self) {
+        self.blocked_items = self.load_blocked_items();
+        if self.blocked_items.is_empty() {
+            self.status_message = Some("No blocked items".to_string());
+            return;
+        }
+        self.blocked_idx = 0;
+        self.mode = InputMode::BlockedList;
+    }
+
+    /// Close the blocked list and select the highlighted item: an epic in
+    /// the Epics panel, or a task in the Tasks panel under its epic.
+    fn jump_to_blocked_item(&mut self) {
+        self.mode = InputMode::Normal;
+        let Some(entry) = self.blocked_items.get(self.blocked_idx).cloned() else {
+            return;
+        };
+        let epic_id = match entry.item_type {
+            DependencyType::Epic => entry.id.clone(),
+            DependencyType::Task => match get_task(&self.db, &entry.id) {
+                Ok(Some(task)) => task.epic_id,
+                _ => return,
+            },
+        };
+        self.select_item(&entry.item_type, &epic_id, &entry.id);
+    }
+
+    /// Select an epic in the Epics panel, or a task of `epic_id` in the
+    /// Tasks panel. Does nothing when the epic isn't listed.
+    fn select_item(&mut self, item_type: &DependencyType, epic_id: &str, id: &str) {
+        let Some(epic_idx) = self.epics.iter().position(|e| e.id == epic_id) else {
+            return;
+        };
+        self.selected_epic_idx = epic_idx;
+        self.selected_task_idx = 0;
+        self.refresh_tasks();
+        self.focused_panel = FocusedPanel::Epics;
+        if *item_type == DependencyType::Task
+            && let Some(task_idx) = self.tasks.iter().position(|t| t.id == id)
+        {
+            self.selected_task_idx = task_idx;
+            self.focused_panel = FocusedPanel::Tasks;
+        }
+    }
+
+    fn open_workspace_selector(&mut self) {
+        let Some(dir) = &self.workspace_dir else {
+            self.status_message = Some("No workspace directory (HOME not set)".to_string());
+            return;
+        };
+        self.workspaces = match workspace::list_workspaces(dir) {
+            Ok(names) if !names.is_empty() => names,
+            Ok(_) => {
+                self.status_message = Some(format!("No workspaces in {}", dir.display()));
+                return;
+            }
+            Err(e) => {
+                self.status_message = Some(format!("Failed to list workspaces: {e}"));
+                return;
+            }
+        };
+        let current = self.workspace.as_deref().unwrap_or(DEFAULT_WORKSPACE);
+        self.workspace_idx = self.workspaces.iter().position(|w| w == current).unwrap_or(0);
+        self.mode = InputMode::WorkspaceSelector;
+    }
+
+    fn confirm_workspace_selection(&mut self) {
+        self.mode = InputMode::Normal;
+        let (Some(dir), Some(name)) = (&self.workspace_dir, self.workspaces.get(self.workspace_idx))
+        else {
+            return;
+        };
+        let name = name.clone();
+        let opened = workspace::workspace_path(dir, &name)
+            .and_then(|path| Database::open(&path))
+            .and_then(|db| db.migrate().map(|()| db));
+        let result = opened.and_then(|db| self.switch_database(db, Some(name.clone())));
+        if let Err(e) = result {
+            self.status_message = Some(format!("Failed to open workspace {name}: {e:#}"));
+        }
+    }
+
+    /// Reopen the app against another database, starting from fresh state
+    /// but keeping display preferences and database options.
+    pub fn switch_database(&mut self, db: Database, workspace: Option<String>) -> Result<()> {
+        db.set_auto_epic_status(self.db.auto_epic_status());
+        let mut next = App::new(db)?;
+        next.workspace = workspace;
+        next.workspace_dir = self.workspace_dir.take();
+        next.settings_dir = self.settings_dir.take();
+        next.animations_enabled = self.animations_enabled;
+        next.high_contrast_symbols = self.high_contrast_symbols;
+        next.ascii_glyphs = self.ascii_glyphs;
+        next.node_width = self.node_width;
+        next.show_minimap = self.show_minimap;
+        next.show_edges = self.show_edges;
+        next.show_edge_counts = self.show_edge_counts;
+        next.dim_completed = self.dim_completed;
+        next.show_full_ids = self.show_full_ids;
+        next.layout_mode = self.layout_mode;
+        next.wrap_navigation = self.wrap_navigation;
+        next.blocked_only = self.blocked_only;
+        next.show_archived = self.show_archived;
+        next.graph_viewport_size = self.graph_viewport_size;
+        next.clipboard = self.clipboard.take();
+        *self = next;
+        if self.show_archived {
+            self.refresh_data();
+        }
+        self.restore_last_project();
+        Ok(())
+    }
+
+    /// The open workspace's [`TuiState`] file, if its directory is known.
+    fn state_path(&self) -> Option<PathBuf> {
+        let name = self.workspace.as_deref().unwrap_or(DEFAULT_WORKSPACE);
+        self.workspace_dir.as_deref().map(|dir| TuiState::path_in(dir, name))
+    }
+
+    /// Switch to the project last opened in this workspace. Stays on the
+    /// first project when none was recorded or it has since been deleted.
+    pub fn restore_last_project(&mut self) {
+        let Some(path) = self.state_path() else {
+            return;
+        };
+        if let Some(id) = TuiState::load(&path).last_project_id {
+            self.select_project(&id);
+        }
+    }
+
+    /// Record the selected project as the one to reopen next time,
+    /// flashing a footer message if the state file cannot be written.
+    pub fn remember_project(&mut self) {
+        let (Some(path), Some(project)) = (self.state_path(), self.selected_project()) else {
+            return;
+        };
+        let mut state = TuiState::load(&path);
+        state.last_project_id = Some(project.id.clone());
+        if let Err(e) = state.save(&path) {
+            self.status_message = Some(format!("Failed to save {}: {e}", path.display()));
+        }
+    }
+
+    /// Switch to the project with `project_id`, if it is loaded.
+    pub fn select_project(&mut self, project_id: &str) {
+        if let Some(idx) = self.projects.iter().position(|p| p.id == project_id) {
+            self.selected_project_idx = idx;
+            self.selected_epic_idx = 0;
+            self.selected_task_idx = 0;
+            self.refresh_data();
+        }
+    }
+
     fn confirm_project_selection(&mut self) {
         self.selected_project_idx = self.selector_idx;
         self.selected_epic_idx = 0;
         self.selected_task_idx = 0;
         self.refresh_data();
         self.mode = InputMode::Normal;
+        self.remember_project();
 
         // Auto-initialize .blueprint/setting.json if the directory exists but the file doesn't
-        if let (Some(project), Ok(cwd)) = (self.selected_project(), std::env::current_dir())
-            && Settings::blueprint_dir_exists_in(&cwd)
-            && !Settings::exists_in(&cwd)
-            && let Err(e) = Settings::save_to(&cwd, &project.id)
+        let dir = self.settings_dir.clone().or_else(|| std::env::current_dir().ok());
+        if let (Some(project), Some(dir)) = (self.selected_project(), dir)
+            && Settings::blueprint_dir_exists_in(&dir)
+            && !Settings::exists_in(&dir)
+            && let Err(e) = Settings::save_to(&dir, &project.id)
         {
-            eprintln!("Warning: failed to write .blueprint/setting.json: {e}");
+            log::warn!("failed to write .blueprint/setting.json: {e}");
         }
     }
 
@@ -929,6 +2155,7 @@ mod tests {
     use crate::db::project::create_project;
     use crate::db::task::create_task;
     use crate::models::{AddDependencyInput, CreateEpicInput, CreateProjectInput, CreateTaskInput};
+    use crate::graph::DEFAULT_NODE_GAP;
     use tempfile::TempDir;
 
     fn open_temp_db() -> (Database, TempDir) {
@@ -987,6 +2214,59 @@ mod tests {
         assert_eq!(app.selected_project_idx, original_idx);
     }
 
+    #[test]
+    fn select_project_switches_by_id() {
+        let (mut app, _dir) = app_with_projects(3);
+        let target = app.projects[2].id.clone();
+        app.select_project(&target);
+        assert_eq!(app.selected_project().unwrap().id, target);
+
+        app.select_project("missing");
+        assert_eq!(app.selected_project_idx, 2);
+    }
+
+    #[test]
+    fn last_opened_project_is_restored_per_workspace() {
+        let (mut app, dir) = app_with_projects(3);
+        app.workspace_dir = Some(dir.path().to_path_buf());
+        app.handle_key(KeyEvent::from(KeyCode::Char('p')));
+        app.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+        let chosen = app.selected_project().unwrap().id.clone();
+        assert_ne!(app.selected_project_idx, 0);
+
+        let reopen = |workspace: Option<&str>| {
+            let db = Database::open(&dir.path().join("test.db")).unwrap();
+            let mut app = App::new(db).unwrap();
+            app.workspace = workspace.map(String::from);
+            app.workspace_dir = Some(dir.path().to_path_buf());
+            app.restore_last_project();
+            app
+        };
+        assert_eq!(reopen(None).selected_project().unwrap().id, chosen);
+        // Another workspace keeps its own record.
+        assert_eq!(reopen(Some("other")).selected_project_idx, 0);
+
+        // Deleted since: fall back to the first project.
+        crate::db::project::delete_project(&app.db, &chosen).unwrap();
+        let app = reopen(None);
+        assert_eq!(app.selected_project_idx, 0);
+        assert_eq!(app.projects.len(), 2);
+    }
+
+    #[test]
+    fn failing_to_remember_the_project_flashes_a_message() {
+        let (mut app, dir) = app_with_projects(2);
+        // A file where the data directory should be, so the state cannot be saved.
+        let not_a_dir = dir.path().join("not-a-dir");
+        std::fs::write(&not_a_dir, "").unwrap();
+        app.workspace_dir = Some(not_a_dir);
+        app.handle_key(KeyEvent::from(KeyCode::Char('p')));
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+        let message = app.status_message.clone().unwrap();
+        assert!(message.starts_with("Failed to save"), "{message}");
+    }
+
     #[test]
     fn enter_confirms_selection() {
         let (mut app, _dir) = app_with_projects(3);
@@ -998,6 +2278,121 @@ mod tests {
         assert_eq!(app.selected_project_idx, expected_idx);
     }
 
+    #[test]
+    fn n_in_selector_creates_and_selects_a_project() {
+        let (mut app, _dir) = app_with_projects(2);
+        let settings = TempDir::new().unwrap();
+        app.settings_dir = Some(settings.path().to_path_buf());
+        app.handle_key(KeyEvent::from(KeyCode::Char('p')));
+        app.handle_key(KeyEvent::from(KeyCode::Char('n')));
+        assert_eq!(app.mode, InputMode::ProjectCreate);
+
+        // Letters that are shortcuts elsewhere, `q` included, are text here.
+        type_text(&mut app, "Quarterly plan");
+        app.handle_key(KeyEvent::from(KeyCode::Tab));
+        type_text(&mut app, "Goals for Q3x");
+        app.handle_key(KeyEvent::from(KeyCode::Backspace));
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+
+        assert_eq!(app.mode, InputMode::Normal);
+        assert_eq!(app.projects.len(), 3);
+        let project = app.selected_project().unwrap();
+        assert_eq!(project.name, "Quarterly plan");
+        assert_eq!(project.description, "Goals for Q3");
+        assert_eq!(app.status_message.as_deref(), Some("Created project Quarterly plan"));
+    }
+
+    #[test]
+    fn n_opens_the_project_form_only_while_there_are_no_projects() {
+        let (mut app, _dir) = app_with_projects(0);
+        app.handle_key(KeyEvent::from(KeyCode::Char('n')));
+        assert_eq!(app.mode, InputMode::ProjectCreate);
+        app.handle_key(KeyEvent::from(KeyCode::Esc));
+        assert_eq!(app.mode, InputMode::Normal);
+
+        let (mut app, _dir) = app_with_projects(1);
+        app.handle_key(KeyEvent::from(KeyCode::Char('n')));
+        assert_eq!(app.mode, InputMode::Normal);
+    }
+
+    #[test]
+    fn project_form_rejects_a_blank_name_and_esc_returns_to_the_selector() {
+        let (mut app, _dir) = app_with_projects(1);
+        app.handle_key(KeyEvent::from(KeyCode::Char('p')));
+        app.handle_key(KeyEvent::from(KeyCode::Char('n')));
+        type_text(&mut app, "   ");
+        app.handle_key(KeyEvent::from(KeyCode::Tab));
+        type_text(&mut app, "no name");
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+
+        assert_eq!(app.mode, InputMode::ProjectCreate);
+        assert!(app.project_form.error.is_some());
+        assert!(!app.project_form.on_description, "focus goes back to the name");
+        assert_eq!(app.projects.len(), 1);
+
+        app.handle_key(KeyEvent::from(KeyCode::Esc));
+        app.handle_key(KeyEvent::from(KeyCode::Char('y')));
+        assert_eq!(app.mode, InputMode::ProjectSelector);
+    }
+
+    #[test]
+    fn leaving_a_dirty_project_form_asks_before_discarding() {
+        let (mut app, _dir) = app_with_projects(1);
+        app.handle_key(KeyEvent::from(KeyCode::Char('p')));
+        app.handle_key(KeyEvent::from(KeyCode::Char('n')));
+        type_text(&mut app, "Dra");
+
+        // Cancelling the prompt returns to editing with the text intact.
+        app.handle_key(KeyEvent::from(KeyCode::Esc));
+        assert_eq!(app.project_form.confirm_discard, Some(FormExit::Cancel));
+        assert_eq!(app.mode, InputMode::ProjectCreate);
+        app.handle_key(KeyEvent::from(KeyCode::Char('n')));
+        assert_eq!(app.project_form.confirm_discard, None);
+        type_text(&mut app, "ft");
+        assert_eq!(app.project_form.name, "Draft");
+
+        // Ctrl-C asks the same way; confirming quits.
+        let ctrl_c = KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL);
+        app.handle_key(ctrl_c);
+        assert_eq!(app.project_form.confirm_discard, Some(FormExit::Quit));
+        assert!(app.running);
+        app.handle_key(KeyEvent::from(KeyCode::Esc));
+        assert_eq!(app.mode, InputMode::ProjectCreate);
+        app.handle_key(ctrl_c);
+        app.handle_key(KeyEvent::from(KeyCode::Char('y')));
+        assert!(!app.running);
+        assert_eq!(app.projects.len(), 1, "nothing was created");
+    }
+
+    #[test]
+    fn leaving_a_clean_project_form_does_not_ask() {
+        let (mut app, _dir) = app_with_projects(1);
+        app.handle_key(KeyEvent::from(KeyCode::Char('p')));
+        app.handle_key(KeyEvent::from(KeyCode::Char('n')));
+        app.handle_key(KeyEvent::from(KeyCode::Esc));
+        assert_eq!(app.mode, InputMode::ProjectSelector);
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('n')));
+        app.handle_key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL));
+        assert!(!app.running);
+    }
+
+    #[test]
+    fn palette_creates_the_first_project() {
+        let (mut app, _dir) = app_with_projects(0);
+        let settings = TempDir::new().unwrap();
+        app.settings_dir = Some(settings.path().to_path_buf());
+        app.handle_key(KeyEvent::from(KeyCode::Char(':')));
+        type_text(&mut app, "create project");
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.mode, InputMode::ProjectCreate);
+
+        type_text(&mut app, "First");
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.mode, InputMode::Normal);
+        assert_eq!(app.selected_project().unwrap().name, "First");
+    }
+
     #[test]
     fn j_k_wrap_around() {
         let (mut app, _dir) = app_with_projects(3);
@@ -1017,6 +2412,50 @@ mod tests {
         assert_eq!(app.selector_idx, 2);
     }
 
+    #[test]
+    fn j_k_stop_at_the_ends_without_wrap_navigation() {
+        let (mut app, _dir) = app_with_projects(3);
+        app.wrap_navigation = false;
+        app.handle_key(KeyEvent::from(KeyCode::Char('p')));
+
+        app.selector_idx = 0;
+        app.handle_key(KeyEvent::from(KeyCode::Char('k')));
+        assert_eq!(app.selector_idx, 0);
+        app.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        app.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        app.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        assert_eq!(app.selector_idx, 2);
+    }
+
+    #[test]
+    fn epic_and_task_lists_wrap_or_clamp_at_the_ends() {
+        let (mut app, _dir) = app_with_epics(3);
+        app.selected_epic_idx = 2;
+        app.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        assert_eq!(app.selected_epic_idx, 0);
+        app.handle_key(KeyEvent::from(KeyCode::Char('k')));
+        assert_eq!(app.selected_epic_idx, 2);
+
+        app.wrap_navigation = false;
+        app.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        assert_eq!(app.selected_epic_idx, 2);
+        app.selected_epic_idx = 0;
+        app.handle_key(KeyEvent::from(KeyCode::Char('k')));
+        assert_eq!(app.selected_epic_idx, 0);
+        app.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        assert_eq!(app.selected_epic_idx, 1);
+    }
+
+    #[test]
+    fn clamp_index_stops_at_bounds() {
+        assert_eq!(clamp_index(0, 0, 1), None);
+        assert_eq!(clamp_index(0, 3, -1), None);
+        assert_eq!(clamp_index(2, 3, 1), None);
+        assert_eq!(clamp_index(1, 3, 1), Some(2));
+        assert_eq!(clamp_index(1, 3, -1), Some(0));
+        assert_eq!(clamp_index(0, 3, 5), Some(2));
+    }
+
     #[test]
     fn q_in_normal_quits() {
         let (mut app, _dir) = app_with_projects(1);
@@ -1034,6 +2473,86 @@ mod tests {
         assert!(app.running);
     }
 
+    fn type_text(app: &mut App, text: &str) {
+        for c in text.chars() {
+            app.handle_key(KeyEvent::from(KeyCode::Char(c)));
+        }
+    }
+
+    #[test]
+    fn fuzzy_score_matches_subsequences_and_prefers_word_starts() {
+        assert_eq!(fuzzy_score("", "Quit"), Some(0));
+        assert!(fuzzy_score("qt", "Quit").is_some());
+        assert!(fuzzy_score("QUIT", "quit").is_some());
+        assert_eq!(fuzzy_score("tq", "Quit"), None);
+        assert_eq!(fuzzy_score("x", "Quit"), None);
+
+        let project = fuzzy_score("sp", "Switch project").unwrap();
+        let workspace = fuzzy_score("sp", "Switch workspace").unwrap();
+        assert!(project > workspace);
+        assert!(fuzzy_score("min map", "Toggle minimap").is_some());
+    }
+
+    #[test]
+    fn palette_filters_actions_by_query() {
+        let (mut app, _dir) = app_with_epics(1);
+        app.handle_key(KeyEvent::from(KeyCode::Char(':')));
+        assert_eq!(app.mode, InputMode::CommandPalette);
+        assert_eq!(app.palette_matches().len(), PALETTE_ACTIONS.len());
+
+        type_text(&mut app, "sp");
+        let names: Vec<&str> = app.palette_matches().iter().map(|a| a.name).collect();
+        assert_eq!(names[0], "Switch project");
+        assert!(names.contains(&"Switch workspace"));
+        assert!(!names.contains(&"Quit"));
+
+        app.handle_key(KeyEvent::from(KeyCode::Backspace));
+        app.handle_key(KeyEvent::from(KeyCode::Backspace));
+        type_text(&mut app, "zzz");
+        assert!(app.palette_matches().is_empty());
+        // Enter with nothing to run keeps the palette open.
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.mode, InputMode::CommandPalette);
+
+        app.handle_key(KeyEvent::from(KeyCode::Esc));
+        assert_eq!(app.mode, InputMode::Normal);
+        assert!(app.running);
+    }
+
+    #[test]
+    fn palette_runs_the_selected_action() {
+        let (mut app, _dir) = app_with_epics(1);
+        app.handle_key(KeyEvent::from(KeyCode::Char(':')));
+        type_text(&mut app, "graph");
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.mode, InputMode::GraphView);
+
+        // Opened from the graph, an action returns there; letters that are
+        // graph keys only edit the query.
+        app.handle_key(KeyEvent::new(KeyCode::Char('p'), KeyModifiers::CONTROL));
+        assert_eq!(app.mode, InputMode::CommandPalette);
+        type_text(&mut app, "minimap");
+        assert!(app.show_minimap);
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.mode, InputMode::GraphView);
+        assert!(!app.show_minimap);
+
+        // Arrow keys pick among several matches.
+        app.handle_key(KeyEvent::from(KeyCode::Char(':')));
+        type_text(&mut app, "toggle");
+        let second = app.palette_matches()[1].name;
+        app.handle_key(KeyEvent::from(KeyCode::Down));
+        assert_eq!(app.palette_idx, 1);
+        assert_eq!(second, "Toggle minimap");
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert!(app.show_minimap);
+
+        app.handle_key(KeyEvent::from(KeyCode::Char(':')));
+        type_text(&mut app, "quit");
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert!(!app.running);
+    }
+
     fn app_with_epics(epic_count: usize) -> (App, TempDir) {
         let (db, dir) = open_temp_db();
         let project = create_project(
@@ -1051,7 +2570,9 @@ mod tests {
                     project_id: project.id.clone(),
                     title: format!("Epic {i}"),
                     description: String::new(),
+                    prd_id: None,
                 },
+                None,
             )
             .unwrap();
         }
@@ -1059,6 +2580,52 @@ mod tests {
         (app, dir)
     }
 
+    fn epic_titles(app: &App) -> Vec<&str> {
+        app.epics.iter().map(|e| e.title.as_str()).collect()
+    }
+
+    #[test]
+    fn shift_j_k_reorders_selected_epic() {
+        let (mut app, _dir) = app_with_epics(3);
+        assert_eq!(epic_titles(&app), ["Epic 2", "Epic 1", "Epic 0"]);
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('J'), KeyModifiers::SHIFT));
+        assert_eq!(epic_titles(&app), ["Epic 1", "Epic 2", "Epic 0"]);
+        assert_eq!(app.selected_epic().unwrap().title, "Epic 2");
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('J')));
+        app.handle_key(KeyEvent::from(KeyCode::Char('J')));
+        assert_eq!(epic_titles(&app), ["Epic 1", "Epic 0", "Epic 2"]);
+        assert_eq!(app.selected_epic_idx, 2);
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('K')));
+        assert_eq!(epic_titles(&app), ["Epic 1", "Epic 2", "Epic 0"]);
+        assert_eq!(app.selected_epic_idx, 1);
+
+        // The order is saved, so a fresh app sees it too.
+        let project_id = app.selected_project().unwrap().id.clone();
+        let listed: Vec<String> = list_epics(
+            &app.db,
+            &EpicListFilter {
+                project_id: Some(&project_id),
+                ..Default::default()
+            },
+        )
+        .unwrap()
+            .into_iter()
+            .map(|e| e.title)
+            .collect();
+        assert_eq!(listed, ["Epic 1", "Epic 2", "Epic 0"]);
+    }
+
+    #[test]
+    fn shift_j_only_reorders_when_epics_focused() {
+        let (mut app, _dir) = app_with_epics(2);
+        app.focused_panel = FocusedPanel::Tasks;
+        app.handle_key(KeyEvent::from(KeyCode::Char('J')));
+        assert_eq!(epic_titles(&app), ["Epic 1", "Epic 0"]);
+    }
+
     #[test]
     fn j_k_navigates_epics_with_wrapping() {
         let (mut app, _dir) = app_with_epics(3);
@@ -1079,6 +2646,53 @@ mod tests {
         assert_eq!(app.selected_epic_idx, 2);
     }
 
+    #[test]
+    fn list_offset_follows_selection() {
+        // 10 items in a 4-row viewport.
+        let mut offset = 0;
+        for selected in 0..4 {
+            offset = list_offset(selected, offset, 4, 10);
+            assert_eq!(offset, 0, "no scroll while selection fits");
+        }
+        offset = list_offset(4, offset, 4, 10);
+        assert_eq!(offset, 1, "scrolls by one when moving past the bottom");
+        offset = list_offset(9, offset, 4, 10);
+        assert_eq!(offset, 6, "jump to last item shows it on the bottom row");
+
+        // Moving back up inside the viewport keeps the offset.
+        offset = list_offset(7, offset, 4, 10);
+        assert_eq!(offset, 6);
+        offset = list_offset(2, offset, 4, 10);
+        assert_eq!(offset, 2, "moving above the viewport puts selection on top");
+
+        // Wrapping back to the first item scrolls to the top.
+        assert_eq!(list_offset(0, 6, 4, 10), 0);
+    }
+
+    #[test]
+    fn list_offset_clamps_to_content() {
+        assert_eq!(list_offset(2, 5, 10, 3), 0, "short list never scrolls");
+        assert_eq!(list_offset(9, 8, 4, 10), 6, "stale offset past the end is pulled back");
+        assert_eq!(list_offset(3, 0, 0, 10), 0, "zero-height viewport");
+    }
+
+    #[test]
+    fn refresh_clamps_list_offset() {
+        let (mut app, _dir) = app_with_epics(10);
+        app.selected_epic_idx = 9;
+        app.epic_list_offset.set(6);
+
+        // Shrink the project to two epics behind the app's back.
+        app.db
+            .conn()
+            .execute("DELETE FROM epics WHERE title NOT IN ('Epic 0', 'Epic 1')", [])
+            .unwrap();
+        app.refresh_data();
+
+        assert_eq!(app.selected_epic_idx, 1);
+        assert!(app.epic_list_offset.get() <= app.selected_epic_idx);
+    }
+
     #[test]
     fn j_k_noop_when_no_epics() {
         let (mut app, _dir) = app_with_projects(1);
@@ -1109,7 +2723,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "Epic A".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         let epic_b = create_epic(
@@ -1118,7 +2734,9 @@ mod tests {
                 project_id: project.id,
                 title: "Epic B".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         create_task(
@@ -1128,6 +2746,7 @@ mod tests {
                 title: "Task A".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -1138,6 +2757,7 @@ mod tests {
                 title: "Task B".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -1170,7 +2790,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "Blocker".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         let epic_b = create_epic(
@@ -1179,7 +2801,9 @@ mod tests {
                 project_id: project.id,
                 title: "Blocked".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
 
@@ -1192,6 +2816,7 @@ mod tests {
                 blocked_type: DependencyType::Epic,
                 blocked_id: epic_b.id.clone(),
             },
+            None,
         )
         .unwrap();
 
@@ -1218,7 +2843,9 @@ mod tests {
                 project_id: project.id,
                 title: "Epic".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         for i in 0..n {
@@ -1229,6 +2856,7 @@ mod tests {
                     title: format!("Task {i}"),
                     description: String::new(),
                     session_id: None,
+                    assignee: None,
                 },
             )
             .unwrap();
@@ -1255,6 +2883,26 @@ mod tests {
         assert_eq!(app.focused_panel, FocusedPanel::Epics);
     }
 
+    #[test]
+    fn v_cycles_layout_and_list_tab_skips_hidden_panels() {
+        let (mut app, _dir) = app_with_tasks(1);
+        assert_eq!(app.layout_mode, LayoutMode::Grid);
+        app.focused_panel = FocusedPanel::Status;
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('v')));
+        assert_eq!(app.layout_mode, LayoutMode::List);
+        assert_eq!(app.focused_panel, FocusedPanel::Epics);
+
+        app.handle_key(KeyEvent::from(KeyCode::Tab));
+        assert_eq!(app.focused_panel, FocusedPanel::Tasks);
+        app.handle_key(KeyEvent::from(KeyCode::Tab));
+        assert_eq!(app.focused_panel, FocusedPanel::Epics);
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('v')));
+        assert_eq!(app.layout_mode, LayoutMode::Grid);
+        assert_eq!(app.focused_panel, FocusedPanel::Epics);
+    }
+
     #[test]
     fn j_k_navigates_tasks_when_task_panel_focused() {
         let (mut app, _dir) = app_with_tasks(3);
@@ -1316,6 +2964,34 @@ mod tests {
         assert_eq!(app.tasks[0].status, ItemStatus::Todo);
     }
 
+    #[test]
+    fn o_toggles_inline_expansion_of_the_selected_task_only() {
+        let (mut app, _dir) = app_with_tasks(3);
+        app.focused_panel = FocusedPanel::Tasks;
+        app.selected_task_idx = 1;
+        let ids: Vec<String> = app.tasks.iter().map(|t| t.id.clone()).collect();
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('o')));
+        assert_eq!(app.expanded_task.as_deref(), Some(ids[1].as_str()));
+        assert!(app.is_task_expanded(&ids[1]));
+        assert!(!app.is_task_expanded(&ids[0]) && !app.is_task_expanded(&ids[2]));
+
+        // Expanding another task moves the expansion to it.
+        app.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        assert!(!app.is_task_expanded(&ids[1]));
+        app.handle_key(KeyEvent::from(KeyCode::Char('o')));
+        assert!(app.is_task_expanded(&ids[2]));
+        assert!(!app.is_task_expanded(&ids[1]));
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('o')));
+        assert_eq!(app.expanded_task, None);
+
+        // Only with the Tasks panel focused.
+        app.focused_panel = FocusedPanel::Epics;
+        app.handle_key(KeyEvent::from(KeyCode::Char('o')));
+        assert_eq!(app.expanded_task, None);
+    }
+
     #[test]
     fn s_persists_to_db() {
         let (mut app, _dir) = app_with_tasks(1);
@@ -1349,6 +3025,92 @@ mod tests {
         assert_eq!(app.mode, InputMode::TaskDetail);
     }
 
+    #[test]
+    fn y_in_task_detail_copies_the_session_id_only_when_claimed() {
+        let (mut app, _dir) = app_with_tasks(1);
+        app.focused_panel = FocusedPanel::Tasks;
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+
+        assert_eq!(app.detail_session_id(), None);
+        app.handle_key(KeyEvent::from(KeyCode::Char('y')));
+        assert_eq!(app.status_message, None);
+        assert_eq!(app.mode, InputMode::TaskDetail);
+
+        let update = UpdateTaskInput {
+            session_id: Some(Some("sess-4f2a".to_string())),
+            ..Default::default()
+        };
+        update_task(&app.db, &app.tasks[0].id, update, None).unwrap();
+        app.refresh_tasks();
+        assert_eq!(app.detail_session_id(), Some("sess-4f2a"));
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('y')));
+        let message = app.status_message.clone().unwrap();
+        assert!(
+            message == "Copied session: sess-4f2a" || message == "Clipboard unavailable",
+            "{message}"
+        );
+        assert_eq!(app.mode, InputMode::TaskDetail);
+    }
+
+    /// Open the detail view of the "Migrate" task from `app_with_blocked_items`.
+    fn open_migrate_detail(app: &mut App) {
+        app.selected_epic_idx = app.epics.iter().position(|e| e.title == "Second").unwrap();
+        app.refresh_tasks();
+        app.selected_task_idx = app.tasks.iter().position(|t| t.title == "Migrate").unwrap();
+        app.focused_panel = FocusedPanel::Tasks;
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.mode, InputMode::TaskDetail);
+    }
+
+    #[test]
+    fn task_detail_j_k_move_between_blockers() {
+        let (mut app, _dir) = app_with_blocked_items();
+        app.wrap_navigation = false;
+        open_migrate_detail(&mut app);
+        let mut titles: Vec<&str> =
+            app.detail_blockers().iter().map(|b| b.title.as_str()).collect();
+        titles.sort();
+        assert_eq!(titles, ["Schema", "Setup"]);
+        assert_eq!(app.blocker_idx, 0);
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        assert_eq!(app.blocker_idx, 1);
+        app.handle_key(KeyEvent::from(KeyCode::Down));
+        assert_eq!(app.blocker_idx, 1, "stops at the last blocker");
+        app.handle_key(KeyEvent::from(KeyCode::Char('k')));
+        assert_eq!(app.blocker_idx, 0);
+        assert_eq!(app.mode, InputMode::TaskDetail);
+
+        // Reopening starts from the first blocker again.
+        app.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        app.handle_key(KeyEvent::from(KeyCode::Esc));
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.blocker_idx, 0);
+    }
+
+    #[test]
+    fn task_detail_enter_jumps_to_the_selected_blocker() {
+        let (mut app, _dir) = app_with_blocked_items();
+        open_migrate_detail(&mut app);
+        app.blocker_idx = app.detail_blockers().iter().position(|b| b.title == "Setup").unwrap();
+        let target = app.selected_blocker().unwrap().clone();
+
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.mode, InputMode::Normal);
+        assert_eq!(app.selected_epic().unwrap().title, "First");
+        assert_eq!(app.selected_epic().unwrap().id, target.epic_id);
+        assert_eq!(app.selected_task().unwrap().id, target.id);
+        assert_eq!(app.focused_panel, FocusedPanel::Tasks);
+
+        // A task without blockers just closes the view.
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert!(app.selected_blocker().is_none());
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.mode, InputMode::Normal);
+        assert_eq!(app.selected_task().unwrap().title, "Setup");
+    }
+
     #[test]
     fn esc_closes_task_detail_popup() {
         let (mut app, _dir) = app_with_tasks(1);
@@ -1387,7 +3149,9 @@ mod tests {
                 project_id: project.id,
                 title: "Epic".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         let t1 = create_task(
@@ -1397,6 +3161,7 @@ mod tests {
                 title: "Blocker".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -1407,6 +3172,7 @@ mod tests {
                 title: "Blocked".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -1419,6 +3185,7 @@ mod tests {
                 blocked_type: DependencyType::Task,
                 blocked_id: t2.id.clone(),
             },
+            None,
         )
         .unwrap();
 
@@ -1430,6 +3197,7 @@ mod tests {
     #[test]
     fn test_status_counts_populated() {
         let (db, dir) = open_temp_db();
+        db.set_auto_epic_status(true);
         let project = create_project(
             &db,
             CreateProjectInput {
@@ -1444,7 +3212,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "Epic 1".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         let epic2 = create_epic(
@@ -1453,7 +3223,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "Epic 2".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         // Mark epic2 as in_progress
@@ -1464,6 +3236,7 @@ mod tests {
                 status: Some(ItemStatus::InProgress),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
 
@@ -1475,6 +3248,7 @@ mod tests {
                 title: "Task todo".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -1485,6 +3259,7 @@ mod tests {
                 title: "Task in_progress".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -1495,6 +3270,7 @@ mod tests {
                 title: "Task done".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -1506,6 +3282,7 @@ mod tests {
                 status: Some(ItemStatus::InProgress),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
         update_task(
@@ -1515,6 +3292,7 @@ mod tests {
                 status: Some(ItemStatus::Done),
                 ..Default::default()
             },
+            None,
         )
         .unwrap();
 
@@ -1535,6 +3313,237 @@ mod tests {
         drop(dir);
     }
 
+    /// E1 blocks E2; inside E2, task T1 and E1's task both block T2.
+    fn app_with_blocked_items() -> (App, TempDir) {
+        let (db, dir) = open_temp_db();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "P".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let make_epic = |title: &str| {
+            create_epic(
+                &db,
+                CreateEpicInput {
+                    project_id: project.id.clone(),
+                    title: title.to_string(),
+                    description: String::new(),
+                    prd_id: None,
+                },
+                None,
+            )
+            .unwrap()
+        };
+        let make_task = |epic: &Epic, title: &str| {
+            create_task(
+                &db,
+                CreateTaskInput {
+                    epic_id: epic.id.clone(),
+                    title: title.to_string(),
+                    description: String::new(),
+                    session_id: None,
+                    assignee: None,
+                },
+            )
+            .unwrap()
+        };
+        let block = |blocker: (DependencyType, &str), blocked: (DependencyType, &str)| {
+            add_dependency(
+                &db,
+                AddDependencyInput {
+                    blocker_type: blocker.0,
+                    blocker_id: blocker.1.to_string(),
+                    blocked_type: blocked.0,
+                    blocked_id: blocked.1.to_string(),
+                },
+                None,
+            )
+            .unwrap();
+        };
+
+        let e1 = make_epic("First");
+        let e2 = make_epic("Second");
+        let setup = make_task(&e1, "Setup");
+        let t1 = make_task(&e2, "Schema");
+        let t2 = make_task(&e2, "Migrate");
+        make_task(&e2, "Docs");
+        block((DependencyType::Epic, &e1.id), (DependencyType::Epic, &e2.id));
+        block((DependencyType::Task, &t1.id), (DependencyType::Task, &t2.id));
+        block((DependencyType::Task, &setup.id), (DependencyType::Task, &t2.id));
+
+        (App::new(db).unwrap(), dir)
+    }
+
+    /// Open the task graph of the "Second" epic from `app_with_blocked_items`.
+    fn open_second_epic_task_graph(app: &mut App) {
+        app.selected_epic_idx = app.epics.iter().position(|e| e.title == "Second").unwrap();
+        app.refresh_tasks();
+        app.handle_key(KeyEvent::from(KeyCode::Char('d')));
+        app.handle_key(KeyEvent::from(KeyCode::Char('2')));
+    }
+
+    fn graph_node_titles(app: &App) -> Vec<String> {
+        let layout = &app.graph_cache.as_ref().unwrap().layout;
+        let mut titles: Vec<String> = app
+            .tasks
+            .iter()
+            .filter(|t| layout.nodes.contains_key(&t.id))
+            .map(|t| t.title.clone())
+            .collect();
+        titles.sort();
+        titles
+    }
+
+    #[test]
+    fn o_limits_graph_to_blocked_chain() {
+        let (mut app, _dir) = app_with_blocked_items();
+        open_second_epic_task_graph(&mut app);
+        assert_eq!(graph_node_titles(&app), ["Docs", "Migrate", "Schema"]);
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('o')));
+        assert!(app.blocked_only);
+        let cache = app.graph_cache.as_ref().unwrap();
+        assert!(cache.layout.orphans.is_empty());
+        assert_eq!(cache.layout.edges.len(), 1);
+        assert_eq!(cache.node_positions.len(), 2);
+        assert_eq!(graph_node_titles(&app), ["Migrate", "Schema"]);
+
+        // The filter survives data refreshes and switching levels.
+        app.refresh_data();
+        assert_eq!(graph_node_titles(&app), ["Migrate", "Schema"]);
+        app.handle_key(KeyEvent::from(KeyCode::Char('1')));
+        assert_eq!(app.graph_cache.as_ref().unwrap().layout.nodes.len(), 2);
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('o')));
+        assert!(!app.blocked_only);
+        app.handle_key(KeyEvent::from(KeyCode::Char('2')));
+        assert_eq!(graph_node_titles(&app), ["Docs", "Migrate", "Schema"]);
+    }
+
+    #[test]
+    fn archived_items_stay_off_the_graph_until_shift_a() {
+        let (mut app, _dir) = app_with_blocked_items();
+        let docs = app.epics.iter().find(|e| e.title == "Second").map(|e| e.id.clone());
+        let docs = list_tasks(
+            &app.db,
+            &TaskListFilter {
+                epic_id: docs.as_deref(),
+                ..Default::default()
+            },
+        )
+        .unwrap()
+        .into_iter()
+        .find(|t| t.title == "Docs")
+        .unwrap();
+        crate::db::task::set_task_archived(&app.db, &docs.id, true, None).unwrap();
+        app.refresh_data();
+
+        open_second_epic_task_graph(&mut app);
+        assert!(!app.tasks.iter().any(|t| t.id == docs.id));
+        assert_eq!(graph_node_titles(&app), ["Migrate", "Schema"]);
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('A')));
+        assert!(app.show_archived);
+        assert_eq!(graph_node_titles(&app), ["Docs", "Migrate", "Schema"]);
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('A')));
+        assert_eq!(graph_node_titles(&app), ["Migrate", "Schema"]);
+    }
+
+    #[test]
+    fn blocked_only_hides_edges_from_done_blockers() {
+        let (mut app, _dir) = app_with_blocked_items();
+        open_second_epic_task_graph(&mut app);
+        let schema = app.tasks.iter().find(|t| t.title == "Schema").unwrap().id.clone();
+        update_task(
+            &app.db,
+            &schema,
+            UpdateTaskInput {
+                status: Some(ItemStatus::Done),
+                ..Default::default()
+            },
+            None,
+        )
+        .unwrap();
+        app.refresh_data();
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('o')));
+        assert!(graph_node_titles(&app).is_empty());
+    }
+
+    #[test]
+    fn b_lists_every_blocked_item_once_with_its_blockers() {
+        let (mut app, _dir) = app_with_blocked_items();
+        app.handle_key(KeyEvent::from(KeyCode::Char('b')));
+        assert_eq!(app.mode, InputMode::BlockedList);
+
+        let project_id = app.selected_project().unwrap().id.clone();
+        let mut expected: Vec<(String, String)> = get_blocked_items(&app.db, Some(&project_id))
+            .unwrap()
+            .into_iter()
+            .map(|row| (row.item_type, row.item_id))
+            .collect();
+        expected.dedup();
+        let listed: Vec<(String, String)> = app
+            .blocked_items
+            .iter()
+            .map(|e| (e.item_type.as_str().to_string(), e.id.clone()))
+            .collect();
+        assert_eq!(listed, expected);
+
+        let task = app
+            .blocked_items
+            .iter()
+            .find(|e| e.item_type == DependencyType::Task)
+            .unwrap();
+        assert_eq!(task.label, "E2-T2 Migrate");
+        let mut blockers = task.blockers.clone();
+        blockers.sort();
+        assert_eq!(blockers, ["E1-T1 Setup", "E2-T1 Schema"]);
+        let epic = app
+            .blocked_items
+            .iter()
+            .find(|e| e.item_type == DependencyType::Epic)
+            .unwrap();
+        assert_eq!(epic.blockers, ["E1 First"]);
+    }
+
+    #[test]
+    fn enter_in_blocked_list_selects_the_item() {
+        let (mut app, _dir) = app_with_blocked_items();
+        app.handle_key(KeyEvent::from(KeyCode::Char('b')));
+        let task_idx = app
+            .blocked_items
+            .iter()
+            .position(|e| e.item_type == DependencyType::Task)
+            .unwrap();
+        app.blocked_idx = task_idx;
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+
+        assert_eq!(app.mode, InputMode::Normal);
+        assert_eq!(app.focused_panel, FocusedPanel::Tasks);
+        assert_eq!(app.selected_epic().unwrap().title, "Second");
+        assert_eq!(app.selected_task().unwrap().title, "Migrate");
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('b')));
+        app.blocked_idx = 1 - task_idx;
+        app.focused_panel = FocusedPanel::Status;
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.focused_panel, FocusedPanel::Epics);
+        assert_eq!(app.selected_epic().unwrap().title, "Second");
+    }
+
+    #[test]
+    fn b_without_blocked_items_reports_it() {
+        let (mut app, _dir) = app_with_epics(2);
+        app.handle_key(KeyEvent::from(KeyCode::Char('b')));
+        assert_eq!(app.mode, InputMode::Normal);
+        assert_eq!(app.status_message.as_deref(), Some("No blocked items"));
+    }
+
     #[test]
     fn test_blocked_count_populated() {
         let (db, dir) = open_temp_db();
@@ -1552,7 +3561,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "E".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         let t1 = create_task(
@@ -1562,6 +3573,7 @@ mod tests {
                 title: "Blocker".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -1572,6 +3584,7 @@ mod tests {
                 title: "Blocked".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -1584,13 +3597,64 @@ mod tests {
                 blocked_type: DependencyType::Task,
                 blocked_id: t2.id.clone(),
             },
+            None,
         )
         .unwrap();
 
         let app = App::new(db).unwrap();
         assert_eq!(app.blocked_count, 1);
 
-        drop(dir);
+        drop(dir);
+    }
+
+    #[test]
+    fn newly_blocked_lists_only_ids_missing_before() {
+        let set = |ids: &[&str]| ids.iter().map(|id| id.to_string()).collect::<HashSet<_>>();
+        assert_eq!(newly_blocked(&set(&["a"]), &set(&["c", "a", "b"])), ["b", "c"]);
+        assert!(newly_blocked(&set(&["a", "b"]), &set(&["a"])).is_empty());
+        assert!(newly_blocked(&set(&[]), &set(&[])).is_empty());
+    }
+
+    #[test]
+    fn refresh_flashes_blocks_added_in_the_background() {
+        let (mut app, _dir) = app_with_tasks(4);
+        let ids: Vec<String> = app.tasks.iter().map(|t| t.id.clone()).collect();
+        let block = |app: &App, blocker: &str, blocked: &str| {
+            add_dependency(
+                &app.db,
+                AddDependencyInput {
+                    blocker_type: DependencyType::Task,
+                    blocker_id: blocker.to_string(),
+                    blocked_type: DependencyType::Task,
+                    blocked_id: blocked.to_string(),
+                },
+                None,
+            )
+            .unwrap();
+        };
+
+        // Nothing new since the first load.
+        app.refresh_data();
+        assert_eq!(app.status_message, None);
+        assert!(!app.bell_pending);
+
+        block(&app, &ids[0], &ids[1]);
+        app.refresh_data();
+        assert_eq!(app.status_message.as_deref(), Some("Newly blocked: Task 1"));
+        assert!(app.bell_pending);
+
+        // A second blocker on an already blocked task is not a new block.
+        app.status_message = None;
+        app.bell_pending = false;
+        block(&app, &ids[2], &ids[1]);
+        app.refresh_data();
+        assert_eq!(app.status_message, None);
+        assert!(!app.bell_pending);
+
+        block(&app, &ids[0], &ids[2]);
+        block(&app, &ids[0], &ids[3]);
+        app.refresh_data();
+        assert_eq!(app.status_message.as_deref(), Some("2 items newly blocked"));
     }
 
     #[test]
@@ -1610,7 +3674,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "E".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         let t1 = create_task(
@@ -1620,6 +3686,7 @@ mod tests {
                 title: "Task 1".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -1747,7 +3814,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "Epic A".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         let epic_b = create_epic(
@@ -1756,7 +3825,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "Epic B".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         let epic_c = create_epic(
@@ -1765,7 +3836,9 @@ mod tests {
                 project_id: project.id,
                 title: "Epic C".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
 
@@ -1778,6 +3851,7 @@ mod tests {
                 blocked_type: DependencyType::Epic,
                 blocked_id: epic_b.id.clone(),
             },
+            None,
         )
         .unwrap();
         add_dependency(
@@ -1788,6 +3862,7 @@ mod tests {
                 blocked_type: DependencyType::Epic,
                 blocked_id: epic_c.id.clone(),
             },
+            None,
         )
         .unwrap();
 
@@ -1870,7 +3945,9 @@ mod tests {
                 project_id: project.id,
                 title: "Epic".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         let t1 = create_task(
@@ -1880,6 +3957,7 @@ mod tests {
                 title: "Task A".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -1890,6 +3968,7 @@ mod tests {
                 title: "Task B".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -1900,6 +3979,7 @@ mod tests {
                 title: "Task C".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -1913,6 +3993,7 @@ mod tests {
                 blocked_type: DependencyType::Task,
                 blocked_id: t2.id.clone(),
             },
+            None,
         )
         .unwrap();
         add_dependency(
@@ -1923,6 +4004,7 @@ mod tests {
                 blocked_type: DependencyType::Task,
                 blocked_id: t3.id.clone(),
             },
+            None,
         )
         .unwrap();
 
@@ -1938,6 +4020,77 @@ mod tests {
         assert_eq!(cache.node_positions.len(), 3);
     }
 
+    #[test]
+    fn build_task_graph_flags_blockers_in_other_epics() {
+        let (db, _dir) = open_temp_db();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "P".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        let mut tasks = Vec::new();
+        for title in ["Backend", "Frontend"] {
+            let epic = create_epic(
+                &db,
+                CreateEpicInput {
+                    project_id: project.id.clone(),
+                    title: title.to_string(),
+                    description: String::new(),
+                    prd_id: None,
+                },
+                None,
+            )
+            .unwrap();
+            for task in ["API", "Client"] {
+                let input = CreateTaskInput {
+                    epic_id: epic.id.clone(),
+                    title: format!("{title} {task}"),
+                    description: String::new(),
+                    session_id: None,
+                    assignee: None,
+                };
+                tasks.push(create_task(&db, input).unwrap());
+            }
+        }
+        // Backend API blocks Frontend Client, across epics.
+        add_dependency(
+            &db,
+            AddDependencyInput {
+                blocker_type: DependencyType::Task,
+                blocker_id: tasks[0].id.clone(),
+                blocked_type: DependencyType::Task,
+                blocked_id: tasks[3].id.clone(),
+            },
+            None,
+        )
+        .unwrap();
+
+        let mut app = App::new(db).unwrap();
+        app.selected_epic_idx = app.epics.iter().position(|e| e.id == tasks[3].epic_id).unwrap();
+        app.refresh_data();
+        app.build_task_graph();
+        let cache = app.graph_cache.as_ref().unwrap();
+        assert!(cache.layout.edges.is_empty());
+        assert_eq!(cache.external_blockers, HashSet::from([tasks[3].id.clone()]));
+
+        // A finished blocker no longer holds anything up.
+        crate::db::task::update_task(
+            &app.db,
+            &tasks[0].id,
+            crate::models::UpdateTaskInput {
+                status: Some(ItemStatus::Done),
+                ..Default::default()
+            },
+            None,
+        )
+        .unwrap();
+        app.build_task_graph();
+        assert!(app.graph_cache.as_ref().unwrap().external_blockers.is_empty());
+    }
+
     #[test]
     fn build_task_graph_no_epic_selected_clears_cache() {
         let (mut app, _dir) = app_with_projects(1);
@@ -1999,6 +4152,47 @@ mod tests {
         assert_eq!(app.scroll_y, 1);
     }
 
+    #[test]
+    fn pan_acceleration_grows_while_repeating_and_resets() {
+        let mut accel = PanAcceleration::default();
+        let start = Instant::now();
+        let repeat = Duration::from_millis(30);
+        let steps: Vec<usize> = (0..12)
+            .map(|i| accel.step(GraphDirection::Down, start + repeat * i))
+            .collect();
+        assert_eq!(steps, [1, 1, 1, 1, 2, 2, 2, 2, 2, 2, 4, 4]);
+
+        // Changing direction starts over, as does the same key after a pause.
+        let t = start + repeat * 12;
+        assert_eq!(accel.step(GraphDirection::Up, t), 1);
+        for i in 1..12 {
+            accel.step(GraphDirection::Up, t + repeat * i);
+        }
+        assert_eq!(accel.step(GraphDirection::Up, t + repeat * 12), 4);
+        let later = t + repeat * 12 + PAN_REPEAT_WINDOW + Duration::from_millis(1);
+        assert_eq!(accel.step(GraphDirection::Up, later), 1);
+        // A gap exactly at the window still counts as a repeat.
+        assert_eq!(accel.step(GraphDirection::Up, later + PAN_REPEAT_WINDOW), 1);
+        assert_eq!(accel.streak, 1);
+    }
+
+    #[test]
+    fn held_pan_key_scrolls_faster_and_stays_clamped() {
+        let (mut app, _dir) = app_with_epics(2);
+        app.handle_key(KeyEvent::from(KeyCode::Char('d')));
+        app.max_scroll.set((100, 30));
+        let start = Instant::now();
+        for i in 0..12 {
+            app.pan_graph(GraphDirection::Down, start + Duration::from_millis(30) * i);
+        }
+        // 4 single steps, 6 double, 2 quadruple.
+        assert_eq!(app.scroll_y, 4 + 12 + 8);
+        app.pan_graph(GraphDirection::Down, start + Duration::from_millis(30) * 12);
+        app.pan_graph(GraphDirection::Down, start + Duration::from_millis(30) * 13);
+        assert_eq!(app.scroll_y, 30, "clamped to max_scroll");
+        assert_eq!(app.scroll_x, 0);
+    }
+
     #[test]
     fn scroll_h_l_adjusts_scroll_x() {
         let (mut app, _dir) = app_with_epics(2);
@@ -2298,7 +4492,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "A".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         let epic_b = create_epic(
@@ -2307,7 +4503,9 @@ mod tests {
                 project_id: project.id,
                 title: "B".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         // A blocks B → two layers
@@ -2319,6 +4517,7 @@ mod tests {
                 blocked_type: DependencyType::Epic,
                 blocked_id: epic_b.id.clone(),
             },
+            None,
         )
         .unwrap();
 
@@ -2447,7 +4646,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "Epic A".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         let epic_b = create_epic(
@@ -2456,7 +4657,9 @@ mod tests {
                 project_id: project.id.clone(),
                 title: "Epic B".to_string(),
                 description: String::new(),
+                prd_id: None,
             },
+            None,
         )
         .unwrap();
         // A blocks B so they're in separate layers
@@ -2468,6 +4671,7 @@ mod tests {
                 blocked_type: DependencyType::Epic,
                 blocked_id: epic_b.id.clone(),
             },
+            None,
         )
         .unwrap();
         // Create a task in each epic
@@ -2478,6 +4682,7 @@ mod tests {
                 title: "Task in A".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -2488,6 +4693,7 @@ mod tests {
                 title: "Task in B".to_string(),
                 description: String::new(),
                 session_id: None,
+                assignee: None,
             },
         )
         .unwrap();
@@ -2530,4 +4736,462 @@ mod tests {
         assert_eq!(app.task_scroll_x, 0);
         assert_eq!(app.task_scroll_y, 0);
     }
+
+    #[test]
+    fn tick_advances_animation_frame_when_enabled() {
+        let (mut app, _dir) = app_with_projects(0);
+        app.tick();
+        app.tick();
+        assert_eq!(app.animation_frame, 2);
+    }
+
+    #[test]
+    fn animation_frame_stays_zero_when_disabled() {
+        let (mut app, _dir) = app_with_projects(0);
+        app.animations_enabled = false;
+        for _ in 0..100 {
+            app.tick();
+        }
+        assert_eq!(app.animation_frame, 0);
+        assert_eq!(app.tick_interval(), Duration::from_millis(250));
+    }
+
+    #[test]
+    fn pressing_a_toggles_animations_and_resets_frame() {
+        let (mut app, _dir) = app_with_projects(0);
+        app.animation_frame = 17;
+        app.handle_key(KeyEvent::from(KeyCode::Char('a')));
+        assert!(!app.animations_enabled);
+        assert_eq!(app.animation_frame, 0);
+        app.tick();
+        assert_eq!(app.animation_frame, 0);
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('a')));
+        assert!(app.animations_enabled);
+        app.tick();
+        assert_eq!(app.animation_frame, 1);
+    }
+
+    #[test]
+    fn pressing_i_toggles_full_ids_in_lists_and_task_detail() {
+        let (mut app, _dir) = app_with_projects(0);
+        assert!(!app.show_full_ids);
+        app.handle_key(KeyEvent::from(KeyCode::Char('i')));
+        assert!(app.show_full_ids);
+
+        app.mode = InputMode::TaskDetail;
+        app.handle_key(KeyEvent::from(KeyCode::Char('i')));
+        assert!(!app.show_full_ids);
+        assert_eq!(app.mode, InputMode::TaskDetail);
+    }
+
+    #[test]
+    fn shift_g_jumps_to_clamped_bottom_and_g_to_top() {
+        let (mut app, _dir) = app_with_epics(2);
+        app.handle_key(KeyEvent::from(KeyCode::Char('d')));
+        app.max_scroll.set((10, 42));
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('G')));
+        assert_eq!(app.scroll_y, 42);
+        app.handle_key(KeyEvent::from(KeyCode::Char('g')));
+        assert_eq!(app.scroll_y, 0);
+    }
+
+    #[test]
+    fn paging_moves_by_viewport_height() {
+        let (mut app, _dir) = app_with_epics(2);
+        app.handle_key(KeyEvent::from(KeyCode::Char('d')));
+        app.graph_viewport_size = (120, 27);
+        app.max_scroll.set((0, 50));
+
+        app.handle_key(KeyEvent::from(KeyCode::PageDown));
+        assert_eq!(app.scroll_y, 20);
+        app.handle_key(KeyEvent::new(KeyCode::Char('f'), KeyModifiers::CONTROL));
+        assert_eq!(app.scroll_y, 40);
+        app.handle_key(KeyEvent::from(KeyCode::PageDown));
+        assert_eq!(app.scroll_y, 50, "paging is clamped to max_scroll");
+        app.handle_key(KeyEvent::new(KeyCode::Char('b'), KeyModifiers::CONTROL));
+        assert_eq!(app.scroll_y, 30);
+        app.handle_key(KeyEvent::from(KeyCode::PageUp));
+        app.handle_key(KeyEvent::from(KeyCode::PageUp));
+        assert_eq!(app.scroll_y, 0);
+    }
+
+    #[test]
+    fn paging_targets_active_pane_in_dual_mode() {
+        let (mut app, _dir) = app_with_epics(2);
+        app.handle_key(KeyEvent::from(KeyCode::Char('d')));
+        app.handle_key(KeyEvent::from(KeyCode::Char('3')));
+        assert!(app.dual_pane);
+        app.epic_max_scroll.set((0, 30));
+        app.task_max_scroll.set((0, 30));
+
+        app.handle_key(KeyEvent::from(KeyCode::Tab));
+        app.handle_key(KeyEvent::from(KeyCode::Char('G')));
+        assert_eq!(app.task_scroll_y, 30);
+        assert_eq!(app.epic_scroll_y, 0);
+    }
+
+    #[test]
+    fn normalize_key_folds_shift_into_chars() {
+        let key = normalize_key(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::SHIFT));
+        assert_eq!(key.code, KeyCode::Char('J'));
+        assert!(key.modifiers.is_empty());
+
+        let key = normalize_key(KeyEvent::new(KeyCode::Down, KeyModifiers::SHIFT));
+        assert_eq!(key.code, KeyCode::Down);
+        assert_eq!(key.modifiers, KeyModifiers::SHIFT);
+
+        let key = normalize_key(KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT));
+        assert_eq!(key, KeyEvent::from(KeyCode::BackTab));
+    }
+
+    #[test]
+    fn shift_tab_switches_project_form_fields() {
+        let (mut app, _dir) = app_with_projects(0);
+        app.handle_key(KeyEvent::from(KeyCode::Char('n')));
+        // crossterm reports Shift+Tab as BackTab with SHIFT set.
+        let shift_tab = KeyEvent::new(KeyCode::BackTab, KeyModifiers::SHIFT);
+        app.handle_key(shift_tab);
+        assert!(app.project_form.on_description);
+        app.handle_key(shift_tab);
+        assert!(!app.project_form.on_description);
+    }
+
+    #[test]
+    fn altgr_characters_are_typed_into_the_project_form() {
+        let (mut app, _dir) = app_with_projects(0);
+        app.handle_key(KeyEvent::from(KeyCode::Char('n')));
+        let altgr = KeyModifiers::CONTROL | KeyModifiers::ALT;
+        type_text(&mut app, "Team ");
+        app.handle_key(KeyEvent::new(KeyCode::Char('@'), altgr));
+        app.handle_key(KeyEvent::new(KeyCode::Char('€'), altgr));
+        assert_eq!(app.project_form.name, "Team @€");
+
+        // Plain Ctrl and Alt combinations still type nothing.
+        app.handle_key(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::ALT));
+        app.handle_key(KeyEvent::new(KeyCode::Char('x'), KeyModifiers::CONTROL));
+        assert_eq!(app.project_form.name, "Team @€");
+    }
+
+    #[test]
+    fn modified_keys_do_not_trigger_plain_actions() {
+        let (mut app, _dir) = app_with_epics(3);
+        app.focused_panel = FocusedPanel::Epics;
+        let start = app.selected_epic_idx;
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::CONTROL));
+        app.handle_key(KeyEvent::new(KeyCode::Char('j'), KeyModifiers::ALT));
+        app.handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::SHIFT));
+        assert_eq!(app.selected_epic_idx, start);
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('q'), KeyModifiers::ALT));
+        assert!(app.running);
+
+        // Plain keys keep their behavior.
+        app.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        assert_ne!(app.selected_epic_idx, start);
+    }
+
+    #[test]
+    fn shift_g_with_modifier_still_jumps_to_bottom() {
+        let (mut app, _dir) = app_with_epics(2);
+        app.handle_key(KeyEvent::from(KeyCode::Char('d')));
+        app.max_scroll.set((0, 12));
+
+        app.handle_key(KeyEvent::new(KeyCode::Char('G'), KeyModifiers::SHIFT));
+        assert_eq!(app.scroll_y, 12);
+    }
+
+    #[test]
+    fn ctrl_f_pages_but_plain_f_does_not() {
+        let (mut app, _dir) = app_with_epics(2);
+        app.handle_key(KeyEvent::from(KeyCode::Char('d')));
+        app.graph_viewport_size = (120, 17);
+        app.max_scroll.set((0, 50));
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('f')));
+        assert_eq!(app.scroll_y, 0);
+        app.handle_key(KeyEvent::new(KeyCode::Char('f'), KeyModifiers::CONTROL));
+        assert_eq!(app.scroll_y, 10);
+    }
+
+    #[test]
+    fn f_toggles_chain_highlight_around_focused_node() {
+        let (mut app, _dir) = app_with_epics(3);
+        let ids: Vec<String> = app.epics.iter().map(|e| e.id.clone()).collect();
+        // epics[0] blocks epics[1]; epics[2] is unrelated.
+        add_dependency(
+            &app.db,
+            AddDependencyInput {
+                blocker_type: DependencyType::Epic,
+                blocker_id: ids[0].clone(),
+                blocked_type: DependencyType::Epic,
+                blocked_id: ids[1].clone(),
+            },
+            None,
+        )
+        .unwrap();
+        app.refresh_data();
+        app.handle_key(KeyEvent::from(KeyCode::Char('d')));
+
+        // Nothing focused yet: `f` is a no-op.
+        app.handle_key(KeyEvent::from(KeyCode::Char('f')));
+        assert!(app.highlight_set.is_none());
+
+        app.handle_key(KeyEvent::from(KeyCode::Down));
+        assert_eq!(app.focused_node.as_deref(), Some(ids[0].as_str()));
+        app.handle_key(KeyEvent::from(KeyCode::Char('f')));
+        let set = app.highlight_set.clone().expect("highlight should be on");
+        assert!(set.contains(&ids[0]) && set.contains(&ids[1]));
+        assert!(!set.contains(&ids[2]));
+
+        // Moving focus to the orphan row recomputes the highlight.
+        app.handle_key(KeyEvent::from(KeyCode::Down));
+        app.handle_key(KeyEvent::from(KeyCode::Down));
+        assert_eq!(app.focused_node.as_deref(), Some(ids[2].as_str()));
+        assert_eq!(app.highlight_set, Some(HashSet::from([ids[2].clone()])));
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('f')));
+        assert!(app.highlight_set.is_none());
+    }
+
+    #[test]
+    fn chain_highlight_cleared_on_mode_switch() {
+        let (mut app, _dir) = app_with_epics(2);
+        app.handle_key(KeyEvent::from(KeyCode::Char('d')));
+        app.handle_key(KeyEvent::from(KeyCode::Down));
+        app.handle_key(KeyEvent::from(KeyCode::Char('f')));
+        assert!(app.highlight_set.is_some());
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('2')));
+        assert!(app.highlight_set.is_none());
+    }
+
+    // ==================== Viewport-aware layout tests ====================
+
+    fn wide_layer_layout() -> DagLayout {
+        // R blocks six children, plus two orphans.
+        let ids = ["R", "A", "B", "C", "D", "E", "F", "X", "Y"];
+        let nodes = ids
+            .iter()
+            .map(|id| Node {
+                id: id.to_string(),
+                label: id.to_string(),
+                status: ItemStatus::Todo,
+                layer: None,
+                x_position: 0,
+            })
+            .collect();
+        let edges = ids[1..7]
+            .iter()
+            .map(|id| Edge {
+                from: "R".to_string(),
+                to: id.to_string(),
+            })
+            .collect();
+        DagLayout::new(nodes, edges)
+    }
+
+    fn extent(positions: &HashMap<String, (usize, usize)>) -> (usize, usize) {
+        let max_x = positions.values().map(|&(x, _)| x + NODE_WIDTH).max().unwrap();
+        let max_y = positions.values().map(|&(_, y)| y).max().unwrap();
+        (max_x, max_y)
+    }
+
+    fn assert_no_overlap(positions: &HashMap<String, (usize, usize)>, node_height: usize) {
+        let boxes: Vec<(&String, (usize, usize))> =
+            positions.iter().map(|(id, &pos)| (id, pos)).collect();
+        for (i, &(a, (ax, ay))) in boxes.iter().enumerate() {
+            for &(b, (bx, by)) in &boxes[i + 1..] {
+                let apart_x = ax + NODE_WIDTH <= bx || bx + NODE_WIDTH <= ax;
+                let apart_y = ay + node_height <= by || by + node_height <= ay;
+                assert!(apart_x || apart_y, "{a} at ({ax}, {ay}) overlaps {b} at ({bx}, {by})");
+            }
+        }
+    }
+
+    #[test]
+    fn narrow_viewport_wraps_wide_layers() {
+        let layout = wide_layer_layout();
+        let height = NODE_HEIGHT_TASK + 1;
+        let narrow = position_nodes(&layout, NODE_HEIGHT_TASK, 80, NODE_WIDTH);
+        let wide = position_nodes(&layout, NODE_HEIGHT_TASK, 240, NODE_WIDTH);
+
+        let (narrow_w, narrow_h) = extent(&narrow);
+        let (wide_w, wide_h) = extent(&wide);
+        assert!(narrow_w <= 80, "narrow layout is {narrow_w} wide");
+        assert!(narrow_w < wide_w);
+        assert!(narrow_h > wide_h, "wrapping should add rows");
+        assert_no_overlap(&narrow, height);
+        assert_no_overlap(&wide, height);
+
+        // Every node of layer 1 sits below the root and above the orphans.
+        let root_y = narrow["R"].1;
+        let orphan_y = narrow["X"].1;
+        for id in ["A", "B", "C", "D", "E", "F"] {
+            assert!(root_y < narrow[id].1 && narrow[id].1 < orphan_y, "{id} out of band");
+        }
+    }
+
+    #[test]
+    fn unknown_viewport_width_keeps_single_row_layers() {
+        let layout = wide_layer_layout();
+        let positions = position_nodes(&layout, NODE_HEIGHT_TASK, 0, NODE_WIDTH);
+        let ys: HashSet<usize> = ["A", "B", "C", "D", "E", "F"]
+            .iter()
+            .map(|id| positions[*id].1)
+            .collect();
+        assert_eq!(ys.len(), 1);
+        assert_eq!(positions["B"].0 - positions["A"].0, NODE_WIDTH + DEFAULT_NODE_GAP);
+    }
+
+    #[test]
+    fn tiny_viewport_still_places_one_node_per_row() {
+        let layout = wide_layer_layout();
+        let positions = position_nodes(&layout, NODE_HEIGHT_TASK, 10, NODE_WIDTH);
+        assert!(positions.values().all(|&(x, _)| x == 1));
+        assert_no_overlap(&positions, NODE_HEIGHT_TASK + 1);
+    }
+
+    #[test]
+    fn relayout_graphs_follows_viewport_width() {
+        let (mut app, _dir) = app_with_epics(6);
+        app.graph_viewport_size = (240, 40);
+        app.handle_key(KeyEvent::from(KeyCode::Char('d')));
+        let wide = app.graph_cache.as_ref().unwrap().node_positions.clone();
+
+        app.graph_viewport_size = (70, 40);
+        app.relayout_graphs();
+        let cache = app.graph_cache.as_ref().unwrap();
+        assert_eq!(cache.viewport_width, 70);
+        assert_ne!(cache.node_positions, wide);
+        assert!(extent(&cache.node_positions).0 <= 70);
+    }
+
+    #[test]
+    fn m_toggles_minimap_in_graph_view() {
+        let (mut app, _dir) = app_with_epics(1);
+        app.handle_key(KeyEvent::from(KeyCode::Char('d')));
+        assert!(app.show_minimap);
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('m')));
+        assert!(!app.show_minimap);
+        app.handle_key(KeyEvent::from(KeyCode::Char('m')));
+        assert!(app.show_minimap);
+    }
+
+    #[test]
+    fn shift_d_toggles_dim_completed_in_graph_view() {
+        let (mut app, _dir) = app_with_epics(1);
+        app.handle_key(KeyEvent::from(KeyCode::Char('d')));
+        assert!(!app.dim_completed);
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('D')));
+        assert!(app.dim_completed);
+        app.handle_key(KeyEvent::from(KeyCode::Char('D')));
+        assert!(!app.dim_completed);
+    }
+
+    #[test]
+    fn plus_minus_resize_nodes_within_bounds() {
+        let (mut app, _dir) = app_with_epics(3);
+        app.handle_key(KeyEvent::from(KeyCode::Char('d')));
+        let before = app.graph_cache.as_ref().unwrap().node_positions.clone();
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('+')));
+        assert_eq!(app.node_width, NODE_WIDTH + NODE_WIDTH_STEP);
+        let cache = app.graph_cache.as_ref().unwrap();
+        assert_eq!(cache.node_width, app.node_width);
+        assert_ne!(cache.node_positions, before, "wider nodes need more spacing");
+
+        for _ in 0..20 {
+            app.handle_key(KeyEvent::from(KeyCode::Char('+')));
+        }
+        assert_eq!(app.node_width, MAX_NODE_WIDTH);
+        for _ in 0..20 {
+            app.handle_key(KeyEvent::from(KeyCode::Char('-')));
+        }
+        assert_eq!(app.node_width, MIN_NODE_WIDTH);
+    }
+
+    #[test]
+    fn focused_node_summary_for_epics_and_tasks() {
+        let (mut app, _dir) = app_with_tasks(2);
+        app.handle_key(KeyEvent::from(KeyCode::Char('d')));
+        assert_eq!(app.focused_node_summary(), None);
+
+        app.handle_key(KeyEvent::from(KeyCode::Down));
+        assert_eq!(
+            app.focused_node_summary().as_deref(),
+            Some("E1 Epic (todo, 0/2 tasks done)")
+        );
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('2')));
+        app.handle_key(KeyEvent::from(KeyCode::Down));
+        let focused = app.focused_node.clone().unwrap();
+        let task = app.tasks.iter().find(|t| t.id == focused).unwrap();
+        assert_eq!(
+            app.focused_node_summary(),
+            Some(format!("{} {} (todo)", task.short_id.as_deref().unwrap(), task.title))
+        );
+    }
+
+    #[test]
+    fn copy_without_focus_reports_until_next_key() {
+        let (mut app, _dir) = app_with_epics(1);
+        app.handle_key(KeyEvent::from(KeyCode::Char('d')));
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('y')));
+        assert_eq!(app.status_message.as_deref(), Some("No node focused"));
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        assert_eq!(app.status_message, None);
+    }
+
+    #[test]
+    fn workspace_switcher_reopens_app_on_selected_db() {
+        let (mut app, _dir) = app_with_projects(2);
+        app.node_width = MIN_NODE_WIDTH;
+        let home = TempDir::new().unwrap();
+        let ws_dir = home.path().join(".blueprint");
+        for (name, projects) in [("alpha", 0), ("beta", 3)] {
+            let db = Database::open(&ws_dir.join(format!("{name}.db"))).unwrap();
+            db.migrate().unwrap();
+            for i in 0..projects {
+                create_project(
+                    &db,
+                    CreateProjectInput {
+                        name: format!("{name} {i}"),
+                        description: String::new(),
+                    },
+                )
+                .unwrap();
+            }
+        }
+        app.workspace_dir = Some(ws_dir);
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('w')));
+        assert_eq!(app.mode, InputMode::WorkspaceSelector);
+        assert_eq!(app.workspaces, ["alpha", "beta"]);
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('j')));
+        app.handle_key(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.mode, InputMode::Normal);
+        assert_eq!(app.workspace.as_deref(), Some("beta"));
+        assert_eq!(app.projects.len(), 3);
+        assert!(app.projects.iter().all(|p| p.name.starts_with("beta")));
+        assert_eq!(app.node_width, MIN_NODE_WIDTH, "display settings survive the switch");
+    }
+
+    #[test]
+    fn workspace_switcher_reports_empty_directory() {
+        let (mut app, _dir) = app_with_projects(1);
+        let home = TempDir::new().unwrap();
+        app.workspace_dir = Some(home.path().join(".blueprint"));
+
+        app.handle_key(KeyEvent::from(KeyCode::Char('w')));
+        assert_eq!(app.mode, InputMode::Normal);
+        assert!(app.status_message.as_deref().unwrap().starts_with("No workspaces"));
+    }
 }
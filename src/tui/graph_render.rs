@@ -8,32 +8,32 @@ use std::collections::{HashMap, HashSet};
 
 use ratatui::style::Style;
 
+use crate::graph::{
+    DagLayout, NODE_HEIGHT_EPIC, NODE_HEIGHT_EPIC_2LINE, NODE_HEIGHT_TASK, NODE_HEIGHT_TASK_2LINE,
+};
 use crate::models::ItemStatus;
-use super::graph::DagLayout;
 use super::theme;
 
 // ── Constants ────────────────────────────────────────────────────────
 
-/// Total width of a rendered node box (including border characters).
-pub const NODE_WIDTH: usize = 30;
-
-/// Height of a task node (top border + title + bottom border).
-pub const NODE_HEIGHT_TASK: usize = 3;
+/// Narrowest node box the graph view allows.
+pub const MIN_NODE_WIDTH: usize = 20;
 
-/// Height of a task node with a 2-line title.
-pub const NODE_HEIGHT_TASK_2LINE: usize = 4;
+/// Widest node box the graph view allows.
+pub const MAX_NODE_WIDTH: usize = 60;
 
-/// Height of an epic node (top border + title + progress + bottom border).
-pub const NODE_HEIGHT_EPIC: usize = 4;
+/// Narrowest progress bar that still gets a percentage beside it.
+const MIN_LABELLED_BAR_WIDTH: usize = 6;
 
-/// Height of an epic node with a 2-line title.
-pub const NODE_HEIGHT_EPIC_2LINE: usize = 5;
-
-/// Interior width available for content (NODE_WIDTH minus the two border columns).
-const INNER_WIDTH: usize = NODE_WIDTH - 2;
+/// Interior width available for content (node width minus the two border columns).
+fn inner_width(node_width: usize) -> usize {
+    node_width - 2
+}
 
 /// Maximum characters available for title text per line (inner width minus symbol and spacing).
-const TITLE_BUDGET: usize = INNER_WIDTH - 3; // 1 leading space + 1 symbol + 1 space
+fn title_budget(node_width: usize) -> usize {
+    inner_width(node_width) - 3 // 1 leading space + 1 symbol + 1 space
+}
 
 // ── Cell ─────────────────────────────────────────────────────────────
 
@@ -90,6 +90,14 @@ impl Canvas {
         }
     }
 
+    /// Replace the style of the cell at `(x, y)`, keeping its character.
+    /// Out-of-bounds writes are silently ignored.
+    pub fn restyle(&mut self, x: usize, y: usize, style: Style) {
+        if x < self.width && y < self.height {
+            self.cells[y * self.width + x].style = style;
+        }
+    }
+
     /// Read the cell at `(x, y)`.
     ///
     /// # Panics
@@ -113,14 +121,62 @@ pub struct NodeBox {
     /// Current status -- determines border style/color and symbol.
     pub status: ItemStatus,
     /// For epic nodes: `(done_count, total_count)` to render a progress bar.
-    /// `None` for plain task nodes.
+    /// `None` for plain task nodes. An epic with no tasks is drawn as a
+    /// milestone instead, without the bar row.
     pub progress: Option<(usize, usize)>,
     /// Top-left X position on the canvas.
     pub x: usize,
     /// Top-left Y position on the canvas.
     pub y: usize,
+    /// Total box width including borders; the title budget scales with it.
+    pub width: usize,
     /// Whether this node is blocked by unfinished dependencies.
     pub blocked: bool,
+    /// Drawn entirely in [`theme::FADED`] because it lies outside the
+    /// highlighted subgraph.
+    pub dimmed: bool,
+    /// Glyph set for the border and symbols; both the ASCII set and
+    /// high-contrast markers flag blocked nodes with `!` on the top border.
+    pub glyphs: theme::Glyphs,
+    /// `(incoming, outgoing)` edge counts drawn as a `↑2 ↓3` badge on the
+    /// bottom border, or `None` for no badge.
+    pub edge_counts: Option<(usize, usize)>,
+    /// Whether an unfinished blocker of this node lies outside the graph,
+    /// marked on the top border since no edge leads in from it.
+    pub external_blocker: bool,
+    /// Draw a done node in [`theme::DARK_GREEN`] rather than full green, so
+    /// remaining work stands out.
+    pub dim_completed: bool,
+}
+
+impl NodeBox {
+    /// True for an epic node with no tasks.
+    pub fn is_milestone(&self) -> bool {
+        self.progress.is_some_and(|(_, total)| total == 0)
+    }
+}
+
+/// `(incoming, outgoing)` edge counts of every node in `layout` with at
+/// least one edge, for [`NodeBox::edge_counts`].
+pub fn edge_counts(layout: &DagLayout) -> HashMap<String, (usize, usize)> {
+    let mut counts: HashMap<String, (usize, usize)> = HashMap::new();
+    for edge in &layout.edges {
+        counts.entry(edge.to.clone()).or_default().0 += 1;
+        counts.entry(edge.from.clone()).or_default().1 += 1;
+    }
+    counts
+}
+
+/// Text of the edge count badge: `↑in ↓out`, or `^in vout` in the ASCII set.
+fn edge_count_badge((incoming, outgoing): (usize, usize), glyphs: theme::Glyphs) -> String {
+    let (up, down) = if glyphs.ascii { ('^', 'v') } else { ('\u{2191}', '\u{2193}') };
+    format!("{up}{incoming} {down}{outgoing}")
+}
+
+/// Whether a node with `progress` gets a progress bar row: epics with at
+/// least one task.
+pub fn has_progress_bar(progress: Option<(usize, usize)>) -> bool {
+    progress.is_some_and(|(_, total)| total > 0)
 }
 
 // ── Border helpers ───────────────────────────────────────────────────
@@ -146,6 +202,20 @@ pub fn border_style(status: &ItemStatus, animation_frame: u8, blocked: bool) ->
     }
 }
 
+/// Whether `node_box` is a done node drawn dimmed under
+/// [`NodeBox::dim_completed`]; a blocked node keeps its warning colors.
+fn is_dimmed_done(node_box: &NodeBox) -> bool {
+    node_box.dim_completed && node_box.status == ItemStatus::Done && !node_box.blocked
+}
+
+/// [`border_style`] of a node, with dimmed done nodes in [`theme::DARK_GREEN`].
+fn node_style(node_box: &NodeBox, animation_frame: u8) -> Style {
+    if is_dimmed_done(node_box) {
+        return Style::default().fg(theme::DARK_GREEN);
+    }
+    border_style(&node_box.status, animation_frame, node_box.blocked)
+}
+
 /// Border character set for a given status.
 #[derive(Clone, Copy)]
 struct BorderChars {
@@ -167,7 +237,25 @@ const DOUBLE_LINE_BORDERS: BorderChars = BorderChars {
     v: '\u{2551}',  // ║
 };
 
-fn border_chars(status: &ItemStatus, animation_frame: u8, blocked: bool) -> BorderChars {
+/// Plain ASCII border set used for every node in the ASCII glyph set.
+const ASCII_BORDERS: BorderChars = BorderChars {
+    tl: '+',
+    tr: '+',
+    bl: '+',
+    br: '+',
+    h: '-',
+    v: '|',
+};
+
+fn border_chars(
+    status: &ItemStatus,
+    animation_frame: u8,
+    blocked: bool,
+    ascii: bool,
+) -> BorderChars {
+    if ascii {
+        return ASCII_BORDERS;
+    }
     if blocked {
         return DOUBLE_LINE_BORDERS;
     }
@@ -197,16 +285,32 @@ fn border_chars(status: &ItemStatus, animation_frame: u8, blocked: bool) -> Bord
 /// the given `perimeter_index`.  The pattern has period 6: 3 bright cells
 /// (solid line, NEON_CYAN) followed by 3 dim cells (dashed line, BORDER_DIM).
 ///
-/// `is_horizontal` selects the line character orientation.
-fn marching_cell(perimeter_index: usize, animation_frame: u8, is_horizontal: bool) -> (char, Style) {
+/// `is_horizontal` selects the line character orientation; `ascii` swaps the
+/// lines for `-`/`|` and `.`/`:`.
+fn marching_cell(
+    perimeter_index: usize,
+    animation_frame: u8,
+    is_horizontal: bool,
+    ascii: bool,
+) -> (char, Style) {
     let phase = (perimeter_index + animation_frame as usize) % 6;
     if phase < 3 {
         // Bright segment: solid line
-        let ch = if is_horizontal { '\u{2500}' } else { '\u{2502}' }; // ─ │
+        let ch = match (is_horizontal, ascii) {
+            (true, false) => '\u{2500}',  // ─
+            (false, false) => '\u{2502}', // │
+            (true, true) => '-',
+            (false, true) => '|',
+        };
         (ch, Style::default().fg(theme::NEON_CYAN))
     } else {
         // Dim segment: dashed line
-        let ch = if is_horizontal { '\u{254C}' } else { '\u{254E}' }; // ╌ ╎
+        let ch = match (is_horizontal, ascii) {
+            (true, false) => '\u{254C}',  // ╌
+            (false, false) => '\u{254E}', // ╎
+            (true, true) => '.',
+            (false, true) => ':',
+        };
         (ch, Style::default().fg(theme::BORDER_DIM))
     }
 }
@@ -217,53 +321,63 @@ fn marching_cell(perimeter_index: usize, animation_frame: u8, is_horizontal: boo
 ///   top-left corner (0) → top edge → top-right corner → right edge →
 ///   bottom-right corner → bottom edge (reversed) → bottom-left corner →
 ///   left edge (reversed).
-fn render_marching_border(canvas: &mut Canvas, x: usize, y: usize, node_height: usize, animation_frame: u8) {
+fn render_marching_border(
+    canvas: &mut Canvas,
+    x: usize,
+    y: usize,
+    node_width: usize,
+    node_height: usize,
+    animation_frame: u8,
+    ascii: bool,
+) {
+    let corner = |rounded: char| if ascii { '+' } else { rounded };
     let mut p: usize = 0;
 
     // --- Top-left corner (index 0) ---
-    let (_, corner_style) = marching_cell(p, animation_frame, true);
-    canvas.put_char(x, y, '\u{256D}', corner_style); // ╭
+    let (_, corner_style) = marching_cell(p, animation_frame, true, ascii);
+    canvas.put_char(x, y, corner('\u{256D}'), corner_style); // ╭
     p += 1;
 
-    // --- Top edge (indices 1 .. NODE_WIDTH-2) ---
-    for i in 1..NODE_WIDTH - 1 {
-        let (ch, st) = marching_cell(p, animation_frame, true);
+    // --- Top edge (indices 1 .. node_width-2) ---
+    for i in 1..node_width - 1 {
+        let (ch, st) = marching_cell(p, animation_frame, true, ascii);
         canvas.put_char(x + i, y, ch, st);
         p += 1;
     }
 
     // --- Top-right corner ---
-    let (_, corner_style) = marching_cell(p, animation_frame, true);
-    canvas.put_char(x + NODE_WIDTH - 1, y, '\u{256E}', corner_style); // ╮
+    let (_, corner_style) = marching_cell(p, animation_frame, true, ascii);
+    canvas.put_char(x + node_width - 1, y, corner('\u{256E}'), corner_style); // ╮
     p += 1;
 
     // --- Right edge (top+1 .. bottom-1) ---
     for row in 1..node_height - 1 {
-        let (ch, st) = marching_cell(p, animation_frame, false);
-        canvas.put_char(x + NODE_WIDTH - 1, y + row, ch, st);
+        let (ch, st) = marching_cell(p, animation_frame, false, ascii);
+        canvas.put_char(x + node_width - 1, y + row, ch, st);
         p += 1;
     }
 
     // --- Bottom-right corner ---
-    let (_, corner_style) = marching_cell(p, animation_frame, true);
-    canvas.put_char(x + NODE_WIDTH - 1, y + node_height - 1, '\u{256F}', corner_style); // ╯
+    let (_, corner_style) = marching_cell(p, animation_frame, true, ascii);
+    let bottom_right = corner('\u{256F}'); // ╯
+    canvas.put_char(x + node_width - 1, y + node_height - 1, bottom_right, corner_style);
     p += 1;
 
     // --- Bottom edge (reversed: right-to-left, indices along the bottom) ---
-    for i in (1..NODE_WIDTH - 1).rev() {
-        let (ch, st) = marching_cell(p, animation_frame, true);
+    for i in (1..node_width - 1).rev() {
+        let (ch, st) = marching_cell(p, animation_frame, true, ascii);
         canvas.put_char(x + i, y + node_height - 1, ch, st);
         p += 1;
     }
 
     // --- Bottom-left corner ---
-    let (_, corner_style) = marching_cell(p, animation_frame, true);
-    canvas.put_char(x, y + node_height - 1, '\u{2570}', corner_style); // ╰
+    let (_, corner_style) = marching_cell(p, animation_frame, true, ascii);
+    canvas.put_char(x, y + node_height - 1, corner('\u{2570}'), corner_style); // ╰
     p += 1;
 
     // --- Left edge (reversed: bottom-1 .. top+1) ---
     for row in (1..node_height - 1).rev() {
-        let (ch, st) = marching_cell(p, animation_frame, false);
+        let (ch, st) = marching_cell(p, animation_frame, false, ascii);
         canvas.put_char(x, y + row, ch, st);
         p += 1;
     }
@@ -278,8 +392,8 @@ fn render_marching_border(canvas: &mut Canvas, x: usize, y: usize, node_height:
 /// on blocked nodes.
 /// Compute the height of a node based on whether it has a progress bar and
 /// whether its title needs two lines.
-pub fn node_height(title: &str, has_progress: bool) -> usize {
-    let needs_two_lines = title.chars().count() > TITLE_BUDGET;
+pub fn node_height(title: &str, has_progress: bool, node_width: usize) -> usize {
+    let needs_two_lines = title.chars().count() > title_budget(node_width);
     match (has_progress, needs_two_lines) {
         (true, true) => NODE_HEIGHT_EPIC_2LINE,
         (true, false) => NODE_HEIGHT_EPIC,
@@ -291,38 +405,70 @@ pub fn node_height(title: &str, has_progress: bool) -> usize {
 pub fn render_node(canvas: &mut Canvas, node_box: &NodeBox, animation_frame: u8) {
     let is_marching = node_box.status == ItemStatus::InProgress && !node_box.blocked;
 
-    let height = node_height(&node_box.title, node_box.progress.is_some());
+    let width = node_box.width;
+    let height = node_height(&node_box.title, has_progress_bar(node_box.progress), width);
 
     if is_marching {
         // Positionally-aware marching border
-        render_marching_border(canvas, node_box.x, node_box.y, height, animation_frame);
+        render_marching_border(
+            canvas,
+            node_box.x,
+            node_box.y,
+            width,
+            height,
+            animation_frame,
+            node_box.glyphs.ascii,
+        );
     } else {
         // Uniform border for Todo / Done / blocked
-        let bstyle = border_style(&node_box.status, animation_frame, node_box.blocked);
-        let bc = border_chars(&node_box.status, animation_frame, node_box.blocked);
+        let bstyle = node_style(node_box, animation_frame);
+        let bc = border_chars(
+            &node_box.status,
+            animation_frame,
+            node_box.blocked,
+            node_box.glyphs.ascii,
+        );
         let x = node_box.x;
         let y = node_box.y;
 
         // Top border
         canvas.put_char(x, y, bc.tl, bstyle);
-        for i in 1..NODE_WIDTH - 1 {
+        for i in 1..width - 1 {
             canvas.put_char(x + i, y, bc.h, bstyle);
         }
-        canvas.put_char(x + NODE_WIDTH - 1, y, bc.tr, bstyle);
+        canvas.put_char(x + width - 1, y, bc.tr, bstyle);
 
         // Side borders for inner rows
         let bottom_y = y + height - 1;
         for row in 1..height - 1 {
             canvas.put_char(x, y + row, bc.v, bstyle);
-            canvas.put_char(x + NODE_WIDTH - 1, y + row, bc.v, bstyle);
+            canvas.put_char(x + width - 1, y + row, bc.v, bstyle);
         }
 
         // Bottom border
         canvas.put_char(x, bottom_y, bc.bl, bstyle);
-        for i in 1..NODE_WIDTH - 1 {
+        for i in 1..width - 1 {
             canvas.put_char(x + i, bottom_y, bc.h, bstyle);
         }
-        canvas.put_char(x + NODE_WIDTH - 1, bottom_y, bc.br, bstyle);
+        canvas.put_char(x + width - 1, bottom_y, bc.br, bstyle);
+    }
+
+    if node_box.blocked && (node_box.glyphs.high_contrast || node_box.glyphs.ascii) {
+        canvas.put_str(
+            node_box.x + width - 3,
+            node_box.y,
+            theme::blocked_symbol(node_box.glyphs),
+            theme::blocked_style(),
+        );
+    }
+
+    if node_box.external_blocker {
+        canvas.put_str(
+            node_box.x + 2,
+            node_box.y,
+            theme::external_blocker_symbol(node_box.glyphs),
+            theme::blocked_style(),
+        );
     }
 
     // ── Content (shared by both paths) ──
@@ -332,46 +478,63 @@ pub fn render_node(canvas: &mut Canvas, node_box: &NodeBox, animation_frame: u8)
     let content_style = if is_marching {
         Style::default().fg(theme::NEON_CYAN)
     } else {
-        border_style(&node_box.status, animation_frame, node_box.blocked)
+        node_style(node_box, animation_frame)
     };
 
     // Title line(s)
     let title_y = y + 1;
 
-    let symbol = theme::status_symbol(&node_box.status);
-    let sym_style = theme::status_style(&node_box.status);
+    let symbol = if node_box.is_milestone() {
+        theme::milestone_symbol(node_box.glyphs)
+    } else {
+        theme::status_symbol(&node_box.status, node_box.glyphs)
+    };
+    let dimmed_done = is_dimmed_done(node_box);
+    let sym_style = if dimmed_done {
+        content_style
+    } else {
+        theme::status_style(&node_box.status)
+    };
 
     const SYMBOL_WIDTH: usize = 1;
 
-    let title_chars: Vec<char> = node_box.title.chars().collect();
-    let needs_two_lines = title_chars.len() > TITLE_BUDGET;
+    let budget = title_budget(width);
+    let needs_two_lines = node_box.title.chars().count() > budget;
 
     let (line1, line2) = if needs_two_lines {
-        let (l1, l2) = split_title_two_lines(&node_box.title, TITLE_BUDGET);
+        let (l1, l2) = split_title_two_lines(&node_box.title, budget);
         (l1, Some(l2))
     } else {
         (node_box.title.clone(), None)
     };
 
     // --- Line 1: symbol + first part of title ---
-    // Leading space
-    canvas.put_char(x + 1, title_y, ' ', content_style);
-
-    // Status symbol
     let symbol_x = x + 2;
-    canvas.put_str(symbol_x, title_y, symbol, sym_style);
+    if symbol.chars().count() == SYMBOL_WIDTH + 2 {
+        // An ASCII marker like `[x]` takes over the padding on both sides;
+        // its brackets already set it apart from the title.
+        canvas.put_str(x + 1, title_y, symbol, sym_style);
+    } else {
+        // Leading space
+        canvas.put_char(x + 1, title_y, ' ', content_style);
 
-    // Space after symbol
-    canvas.put_char(symbol_x + SYMBOL_WIDTH, title_y, ' ', content_style);
+        // Status symbol
+        canvas.put_str(symbol_x, title_y, symbol, sym_style);
+
+        // Space after symbol
+        canvas.put_char(symbol_x + SYMBOL_WIDTH, title_y, ' ', content_style);
+    }
 
     // Title text (line 1)
     let title_x = symbol_x + SYMBOL_WIDTH + 1;
-    let title_style = Style::default().fg(theme::TEXT_BRIGHT);
+    let title_color = if dimmed_done { theme::TEXT_DIM } else { theme::TEXT_BRIGHT };
+    let title_style = Style::default().fg(title_color);
     canvas.put_str(title_x, title_y, &line1, title_style);
 
     // Fill remaining inner space on line 1
+    let inner = inner_width(width);
     let used = 1 + SYMBOL_WIDTH + 1 + line1.chars().count();
-    for i in used..INNER_WIDTH {
+    for i in used..inner {
         canvas.put_char(x + 1 + i, title_y, ' ', content_style);
     }
 
@@ -385,51 +548,111 @@ pub fn render_node(canvas: &mut Canvas, node_box: &NodeBox, animation_frame: u8)
         }
         canvas.put_str(title_x, title_y2, l2, title_style);
         let used2 = prefix_len + l2.chars().count();
-        for i in used2..INNER_WIDTH {
+        for i in used2..inner {
             canvas.put_char(x + 1 + i, title_y2, ' ', content_style);
         }
     }
 
-    // Progress line (epic nodes only)
-    if let Some((done, total)) = node_box.progress {
+    // Progress line (epic nodes with tasks only)
+    if let Some((done, total)) = node_box.progress
+        && total > 0
+    {
         let progress_y = if line2.is_some() { y + 3 } else { y + 2 };
 
-        let bar_width = INNER_WIDTH.saturating_sub(4);
-        let bar = theme::progress_bar(done, total, bar_width);
+        // Completion percentage after the bar, rounded down so only a
+        // finished epic reads 100%, when the bar stays readable beside it.
+        let percent = format!(" {}%", done * 100 / total);
+        let full_bar_width = inner.saturating_sub(4);
+        let (bar_width, percent) = if full_bar_width >= percent.len() + MIN_LABELLED_BAR_WIDTH {
+            (full_bar_width - percent.len(), Some(percent))
+        } else {
+            (full_bar_width, None)
+        };
+        let bar = theme::progress_bar(done, total, bar_width, node_box.glyphs);
 
         canvas.put_char(x + 1, progress_y, ' ', content_style);
         canvas.put_char(x + 2, progress_y, '[', content_style);
-        let bar_style = Style::default().fg(theme::NEON_GREEN);
+        let bar_style = if dimmed_done {
+            content_style
+        } else {
+            Style::default().fg(theme::NEON_GREEN)
+        };
         canvas.put_str(x + 3, progress_y, &bar, bar_style);
         canvas.put_char(x + 3 + bar_width, progress_y, ']', content_style);
+        let mut used_progress = 1 + 1 + bar_width + 1;
+        if let Some(percent) = percent {
+            canvas.put_str(x + 4 + bar_width, progress_y, &percent, title_style);
+            used_progress += percent.len();
+        }
 
-        let used_progress = 1 + 1 + bar_width + 1;
-        for i in (1 + used_progress)..INNER_WIDTH {
+        for i in (1 + used_progress)..inner {
             canvas.put_char(x + 1 + i, progress_y, ' ', content_style);
         }
     }
+
+    // Edge count badge, right-aligned on the bottom border.
+    if let Some(counts) = node_box.edge_counts {
+        let badge = format!(" {} ", edge_count_badge(counts, node_box.glyphs));
+        let badge_len = badge.chars().count();
+        if badge_len + 2 < width {
+            let badge_style = Style::default().fg(theme::TEXT_BRIGHT);
+            canvas.put_str(x + width - 1 - badge_len, y + height - 1, &badge, badge_style);
+        }
+    }
+
+    if node_box.dimmed {
+        let faded = Style::default().fg(theme::FADED);
+        for row in 0..height {
+            for col in 0..width {
+                canvas.restyle(x + col, y + row, faded);
+            }
+        }
+    }
 }
 
 // ── Edge rendering ──────────────────────────────────────────────────
 
+/// Box dimensions of the nodes in one graph, used to route edges.
+pub struct NodeSizes<'a> {
+    pub width: usize,
+    /// Per-node heights; nodes missing from the map use `default_height`.
+    pub heights: &'a HashMap<String, usize>,
+    pub default_height: usize,
+}
+
+impl NodeSizes<'_> {
+    pub fn height(&self, id: &str) -> usize {
+        self.heights.get(id).copied().unwrap_or(self.default_height)
+    }
+}
+
 /// Draw directed edges between connected nodes on the canvas.
 ///
 /// Edges route from the bottom-center of the source node to the top-center
 /// of the target node, using straight vertical lines for same-column edges
 /// and L/Z-shaped routing for cross-column edges.
 ///
-/// `node_height` determines the vertical offset from each source node's
-/// top-left corner to the edge departure point (bottom-center).
+/// `sizes` gives each node's box: its height is the vertical offset from the
+/// source node's top-left corner to the edge departure point (bottom-center).
+///
+/// When `highlight` is set, edges that do not connect two highlighted nodes
+/// are drawn faded, as are edges touching a done node with `dim_completed`.
+/// With `ascii` the lines are drawn with `|`, `-`, `+` and a `v` arrow head.
 ///
 /// Existing non-space characters (i.e. node content) are never overwritten.
+#[allow(clippy::too_many_arguments)]
 pub fn render_edges(
     canvas: &mut Canvas,
     layout: &DagLayout,
     node_positions: &HashMap<String, (usize, usize)>,
     blocked_ids: &HashSet<String>,
-    node_heights: &HashMap<String, usize>,
-    default_height: usize,
+    sizes: &NodeSizes,
+    highlight: Option<&HashSet<String>>,
+    dim_completed: bool,
+    ascii: bool,
 ) {
+    let is_done = |id: &str| layout.nodes.get(id).is_some_and(|n| n.status == ItemStatus::Done);
+    let glyph = |unicode: char, plain: char| if ascii { plain } else { unicode };
     for edge in &layout.edges {
         let Some(&(from_x, from_y)) = node_positions.get(&edge.from) else {
             continue;
@@ -438,19 +661,23 @@ pub fn render_edges(
             continue;
         };
 
-        let style = if blocked_ids.contains(&edge.to) {
+        let outside_highlight =
+            highlight.is_some_and(|set| !set.contains(&edge.from) || !set.contains(&edge.to));
+        let completed = dim_completed && (is_done(&edge.from) || is_done(&edge.to));
+        let style = if outside_highlight || completed {
+            Style::default().fg(theme::FADED)
+        } else if blocked_ids.contains(&edge.to) {
             Style::default().fg(theme::NEON_PINK)
         } else {
             Style::default().fg(theme::NEON_CYAN)
         };
 
         // Source: bottom-center of `from` node.
-        let from_height = node_heights.get(&edge.from).copied().unwrap_or(default_height);
-        let src_x = from_x + NODE_WIDTH / 2;
-        let src_y = from_y + from_height; // one row below bottom border
+        let src_x = from_x + sizes.width / 2;
+        let src_y = from_y + sizes.height(&edge.from); // one row below bottom border
 
         // Target: top-center of `to` node, one row above.
-        let dst_x = to_x + NODE_WIDTH / 2;
+        let dst_x = to_x + sizes.width / 2;
         let dst_y = to_y.saturating_sub(1);
 
         if src_y > dst_y {
@@ -460,22 +687,22 @@ pub fn render_edges(
         if src_x == dst_x {
             // Straight vertical edge.
             for y in src_y..dst_y {
-                put_edge_char(canvas, src_x, y, '\u{2502}', style); // │
+                put_edge_char(canvas, src_x, y, glyph('\u{2502}', '|'), style); // │
             }
-            put_edge_char(canvas, dst_x, dst_y, '\u{25BC}', style); // ▼
+            put_edge_char(canvas, dst_x, dst_y, glyph('\u{25BC}', 'v'), style); // ▼
         } else {
             // L/Z-shaped routing.
             // Step 1: one cell down from source.
-            put_edge_char(canvas, src_x, src_y, '\u{2502}', style); // │
+            put_edge_char(canvas, src_x, src_y, glyph('\u{2502}', '|'), style); // │
 
             // Step 2: horizontal row at src_y + 1.
             let mid_y = src_y + 1;
 
             // Corner at the turn from vertical to horizontal.
             if dst_x > src_x {
-                put_edge_char(canvas, src_x, mid_y, '\u{2570}', style); // ╰
+                put_edge_char(canvas, src_x, mid_y, glyph('\u{2570}', '+'), style); // ╰
             } else {
-                put_edge_char(canvas, src_x, mid_y, '\u{256F}', style); // ╯
+                put_edge_char(canvas, src_x, mid_y, glyph('\u{256F}', '+'), style); // ╯
             }
 
             // Horizontal segment.
@@ -485,23 +712,23 @@ pub fn render_edges(
                 (dst_x + 1, src_x)
             };
             for x in hx_start..hx_end {
-                put_edge_char(canvas, x, mid_y, '\u{2500}', style); // ─
+                put_edge_char(canvas, x, mid_y, glyph('\u{2500}', '-'), style); // ─
             }
 
             // Corner at the turn from horizontal to vertical towards target.
             if dst_x > src_x {
-                put_edge_char(canvas, dst_x, mid_y, '\u{256E}', style); // ╮
+                put_edge_char(canvas, dst_x, mid_y, glyph('\u{256E}', '+'), style); // ╮
             } else {
-                put_edge_char(canvas, dst_x, mid_y, '\u{256D}', style); // ╭
+                put_edge_char(canvas, dst_x, mid_y, glyph('\u{256D}', '+'), style); // ╭
             }
 
             // Vertical segment down to target.
             for y in (mid_y + 1)..dst_y {
-                put_edge_char(canvas, dst_x, y, '\u{2502}', style); // │
+                put_edge_char(canvas, dst_x, y, glyph('\u{2502}', '|'), style); // │
             }
 
             // Arrow head at target.
-            put_edge_char(canvas, dst_x, dst_y, '\u{25BC}', style); // ▼
+            put_edge_char(canvas, dst_x, dst_y, glyph('\u{25BC}', 'v'), style); // ▼
         }
     }
 }
@@ -510,12 +737,21 @@ pub fn render_edges(
 ///
 /// Called after `render_node()` to draw a rounded single-line border one cell
 /// outside the node, leaving the inner status-based border fully visible.
-pub fn render_focus_highlight(canvas: &mut Canvas, x: usize, y: usize, node_height: usize) {
+/// With `ascii` the border is drawn with `+`, `-` and `|`.
+pub fn render_focus_highlight(
+    canvas: &mut Canvas,
+    x: usize,
+    y: usize,
+    node_width: usize,
+    node_height: usize,
+    ascii: bool,
+) {
+    let glyph = |unicode: char, plain: char| if ascii { plain } else { unicode };
     let style = Style::default()
         .fg(theme::NEON_MAGENTA)
         .add_modifier(ratatui::style::Modifier::BOLD);
 
-    let outer_w = NODE_WIDTH + 2;
+    let outer_w = node_width + 2;
     // Use wrapping_sub to get usize coordinates; when x or y is 0, ox/oy wrap
     // to usize::MAX. We use saturating_add for all subsequent arithmetic so
     // the result stays at usize::MAX and put_char clips it out-of-bounds.
@@ -523,25 +759,27 @@ pub fn render_focus_highlight(canvas: &mut Canvas, x: usize, y: usize, node_heig
     let oy = y.wrapping_sub(1);
 
     // Top border: ╭───╮ at oy
-    canvas.put_char(ox, oy, '\u{256D}', style); // ╭
+    canvas.put_char(ox, oy, glyph('\u{256D}', '+'), style); // ╭
     for i in 1..outer_w - 1 {
-        canvas.put_char(ox.saturating_add(i), oy, '\u{2500}', style); // ─
+        canvas.put_char(ox.saturating_add(i), oy, glyph('\u{2500}', '-'), style); // ─
     }
-    canvas.put_char(ox.saturating_add(outer_w - 1), oy, '\u{256E}', style); // ╮
+    canvas.put_char(ox.saturating_add(outer_w - 1), oy, glyph('\u{256E}', '+'), style); // ╮
 
     // Side borders: │ at ox and ox+outer_w-1 for each row of the node
+    let side = glyph('\u{2502}', '|'); // │
     for row in 0..node_height {
-        canvas.put_char(ox, y + row, '\u{2502}', style); // │
-        canvas.put_char(ox.saturating_add(outer_w - 1), y + row, '\u{2502}', style); // │
+        canvas.put_char(ox, y + row, side, style);
+        canvas.put_char(ox.saturating_add(outer_w - 1), y + row, side, style);
     }
 
     // Bottom border: ╰───╯ at y+node_height
     let bottom_oy = y + node_height;
-    canvas.put_char(ox, bottom_oy, '\u{2570}', style); // ╰
+    canvas.put_char(ox, bottom_oy, glyph('\u{2570}', '+'), style); // ╰
     for i in 1..outer_w - 1 {
-        canvas.put_char(ox.saturating_add(i), bottom_oy, '\u{2500}', style); // ─
+        canvas.put_char(ox.saturating_add(i), bottom_oy, glyph('\u{2500}', '-'), style); // ─
     }
-    canvas.put_char(ox.saturating_add(outer_w - 1), bottom_oy, '\u{256F}', style); // ╯
+    let bottom_right = glyph('\u{256F}', '+'); // ╯
+    canvas.put_char(ox.saturating_add(outer_w - 1), bottom_oy, bottom_right, style);
 }
 
 /// Place an edge character on the canvas, but only if the cell is currently a space.
@@ -596,6 +834,7 @@ fn truncate_with_ellipsis(s: &str, max_chars: usize) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::graph::NODE_WIDTH;
 
     fn canvas_row(canvas: &Canvas, y: usize) -> String {
         (0..canvas.width).map(|x| canvas.get(x, y).ch).collect()
@@ -670,7 +909,13 @@ mod tests {
             progress: None,
             x: 0,
             y: 0,
+            width: NODE_WIDTH,
             blocked: false,
+            dimmed: false,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
         };
         render_node(&mut canvas, &node, 0);
 
@@ -692,7 +937,13 @@ mod tests {
             progress: None,
             x: 0,
             y: 0,
+            width: NODE_WIDTH,
             blocked: false,
+            dimmed: false,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
         };
         render_node(&mut canvas, &node, 0);
 
@@ -709,7 +960,13 @@ mod tests {
             progress: None,
             x: 0,
             y: 0,
+            width: NODE_WIDTH,
             blocked: false,
+            dimmed: false,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
         };
         render_node(&mut canvas, &node, 0);
 
@@ -728,7 +985,13 @@ mod tests {
             progress: None,
             x: 0,
             y: 0,
+            width: NODE_WIDTH,
             blocked: false,
+            dimmed: false,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
         };
         render_node(&mut canvas, &node, 0);
 
@@ -752,7 +1015,13 @@ mod tests {
             progress: None,
             x: 0,
             y: 0,
+            width: NODE_WIDTH,
             blocked: false,
+            dimmed: false,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
         };
         render_node(&mut canvas, &node, 0);
 
@@ -781,11 +1050,17 @@ mod tests {
             progress: None,
             x: 0,
             y: 0,
+            width: NODE_WIDTH,
             blocked: false,
+            dimmed: false,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
         };
         render_node(&mut canvas, &node, 0);
 
-        // With TITLE_BUDGET=25, the title wraps at word boundary to 2 lines.
+        // With a title budget of 25, the title wraps at word boundary to 2 lines.
         // Line 1: "This Is A Very Long" (19 chars, breaks before "Title")
         let row1 = canvas_row(&canvas, 1);
         assert!(
@@ -819,7 +1094,13 @@ mod tests {
             progress: Some((3, 10)),
             x: 0,
             y: 0,
+            width: NODE_WIDTH,
             blocked: false,
+            dimmed: false,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
         };
         render_node(&mut canvas, &node, 0);
 
@@ -834,10 +1115,38 @@ mod tests {
             "progress bar chars"
         );
 
+        assert!(row2.contains("] 30%"), "{row2}");
         let row3 = canvas_row(&canvas, 3);
         assert!(row3.starts_with('\u{2570}'), "bottom border at row 3 for epic node");
     }
 
+    #[test]
+    fn epic_progress_percentage_rounds_down_and_fits_every_width() {
+        for width in [MIN_NODE_WIDTH, NODE_WIDTH, MAX_NODE_WIDTH] {
+            let mut canvas = Canvas::new(MAX_NODE_WIDTH + 2, 6);
+            let node = NodeBox {
+                title: "Epic".to_string(),
+                status: ItemStatus::Todo,
+                progress: Some((2, 3)),
+                x: 0,
+                y: 0,
+                width,
+                blocked: false,
+                dimmed: false,
+                glyphs: theme::Glyphs::default(),
+                edge_counts: None,
+                external_blocker: false,
+                dim_completed: false,
+            };
+            render_node(&mut canvas, &node, 0);
+
+            let row2: Vec<char> = canvas_row(&canvas, 2).chars().collect();
+            let inside: String = row2[1..width - 1].iter().collect();
+            assert!(inside.trim_end().ends_with("] 66%"), "{inside}");
+            assert_eq!(row2[width - 1], row2[0], "percentage must stay inside the border");
+        }
+    }
+
     #[test]
     fn render_epic_node_full_progress() {
         let mut canvas = Canvas::new(40, 6);
@@ -847,13 +1156,58 @@ mod tests {
             progress: Some((5, 5)),
             x: 0,
             y: 0,
+            width: NODE_WIDTH,
             blocked: false,
+            dimmed: false,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
         };
         render_node(&mut canvas, &node, 0);
 
         let row2 = canvas_row(&canvas, 2);
         assert!(!row2.contains('\u{2591}'), "full progress should have no empty blocks");
         assert!(row2.contains('\u{2588}'), "full progress should have filled blocks");
+        assert!(row2.contains("] 100%"), "{row2}");
+    }
+
+    #[test]
+    fn render_zero_task_epic_as_milestone() {
+        let mut canvas = Canvas::new(40, 6);
+        let node = NodeBox {
+            title: "Launch".to_string(),
+            status: ItemStatus::Todo,
+            progress: Some((0, 0)),
+            x: 0,
+            y: 0,
+            width: NODE_WIDTH,
+            blocked: false,
+            dimmed: false,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
+        };
+        assert!(node.is_milestone());
+        render_node(&mut canvas, &node, 0);
+
+        let row1 = canvas_row(&canvas, 1);
+        assert!(row1.contains(theme::MILESTONE_SYMBOL), "diamond glyph: {row1}");
+        let todo = theme::status_symbol(&ItemStatus::Todo, theme::Glyphs::default());
+        assert!(!row1.contains(todo), "{row1}");
+        let row2 = canvas_row(&canvas, 2);
+        assert!(row2.starts_with('\u{255A}'), "bottom border at task height: {row2}");
+        for y in 0..canvas.height {
+            assert!(!canvas_row(&canvas, y).contains('['), "no progress bar on row {y}");
+            assert!(!canvas_row(&canvas, y).contains('%'), "no percentage on row {y}");
+        }
+        assert!(canvas_row(&canvas, 3).trim().is_empty());
+
+        assert!(!has_progress_bar(node.progress));
+        let height = |progress| node_height(&node.title, has_progress_bar(progress), NODE_WIDTH);
+        assert_eq!(height(node.progress), NODE_HEIGHT_TASK);
+        assert_eq!(height(Some((1, 2))), NODE_HEIGHT_EPIC);
     }
 
     // ── Multiple nodes on canvas ────────────────────────────────
@@ -868,7 +1222,13 @@ mod tests {
             progress: None,
             x: 0,
             y: 0,
+            width: NODE_WIDTH,
             blocked: false,
+            dimmed: false,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
         };
         let node_b = NodeBox {
             title: "Beta".to_string(),
@@ -876,7 +1236,13 @@ mod tests {
             progress: None,
             x: 34,
             y: 5,
+            width: NODE_WIDTH,
             blocked: false,
+            dimmed: false,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
         };
 
         render_node(&mut canvas, &node_a, 0);
@@ -905,7 +1271,13 @@ mod tests {
             progress: None,
             x: 5,
             y: 3,
+            width: NODE_WIDTH,
             blocked: false,
+            dimmed: false,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
         };
         render_node(&mut canvas, &node, 0);
 
@@ -985,7 +1357,13 @@ mod tests {
             progress: None,
             x: 0,
             y: 0,
+            width: NODE_WIDTH,
             blocked: true,
+            dimmed: false,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
         };
         render_node(&mut canvas, &node, 0);
 
@@ -998,6 +1376,93 @@ mod tests {
         assert!(row2.contains('\u{255D}'), "blocked node should use double-line bottom-right");
     }
 
+    #[test]
+    fn high_contrast_node_uses_ascii_markers_without_moving_the_title() {
+        let mut canvas = Canvas::new(40, 6);
+        let mut node = NodeBox {
+            title: "Ship it".to_string(),
+            status: ItemStatus::Done,
+            progress: None,
+            x: 0,
+            y: 0,
+            width: NODE_WIDTH,
+            blocked: false,
+            dimmed: false,
+            glyphs: theme::Glyphs { high_contrast: true, ..theme::Glyphs::default() },
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
+        };
+        render_node(&mut canvas, &node, 0);
+        let row1 = canvas_row(&canvas, 1);
+        assert!(row1.chars().skip(1).collect::<String>().starts_with("[x]Ship it"), "{row1}");
+        assert!(!canvas_row(&canvas, 0).contains('!'));
+
+        node.status = ItemStatus::Todo;
+        node.blocked = true;
+        let mut canvas = Canvas::new(40, 6);
+        render_node(&mut canvas, &node, 0);
+        let row1 = canvas_row(&canvas, 1);
+        assert!(row1.chars().skip(1).collect::<String>().starts_with("[ ]Ship it"), "{row1}");
+        let row0: Vec<char> = canvas_row(&canvas, 0).chars().collect();
+        assert_eq!(row0[NODE_WIDTH - 3], '!');
+    }
+
+    #[test]
+    fn ascii_glyphs_draw_nodes_edges_and_focus_in_plain_ascii() {
+        let ascii = theme::Glyphs { ascii: true, ..theme::Glyphs::default() };
+        let node = |title: &str, status, progress, x, y, blocked| NodeBox {
+            title: title.to_string(),
+            status,
+            progress,
+            x,
+            y,
+            width: NODE_WIDTH,
+            blocked,
+            dimmed: false,
+            glyphs: ascii,
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
+        };
+        let mut running = node("Running", ItemStatus::InProgress, None, 2, 1, false);
+        running.edge_counts = Some((0, 1));
+        let waiting = node("Waiting", ItemStatus::Todo, None, 36, 8, true);
+        let epic = node("Epic", ItemStatus::Done, Some((1, 4)), 36, 1, false);
+
+        let mut canvas = Canvas::new(70, 12);
+        for frame in [0, 3] {
+            for n in [&running, &waiting, &epic] {
+                render_node(&mut canvas, n, frame);
+            }
+        }
+        let layout = DagLayout::new(
+            vec![make_node("A"), make_node("B")],
+            vec![make_edge("A", "B")],
+        );
+        let mut positions = HashMap::new();
+        positions.insert("A".to_string(), (2_usize, 1_usize));
+        positions.insert("B".to_string(), (36_usize, 8_usize));
+        let heights = HashMap::new();
+        let sizes = task_sizes(&heights);
+        render_edges(&mut canvas, &layout, &positions, &HashSet::new(), &sizes, None, false, true);
+        render_focus_highlight(&mut canvas, 2, 1, NODE_WIDTH, NODE_HEIGHT_TASK, true);
+
+        for y in 0..canvas.height {
+            let row = canvas_row(&canvas, y);
+            assert!(row.is_ascii(), "row {y} is not ASCII: {row}");
+        }
+        assert!(canvas_row(&canvas, 2).contains("> Running"));
+        assert!(canvas_row(&canvas, 9).contains("o Waiting"));
+        assert!(canvas_row(&canvas, 2).contains("x Epic"));
+        assert!(canvas_row(&canvas, 3).contains("[#"));
+        assert!(canvas_row(&canvas, 3).contains(" ^0 v1 "));
+        assert_eq!(canvas.get(36 + NODE_WIDTH - 3, 8).ch, '!');
+        assert_eq!(canvas.get(36, 8).ch, '+');
+        assert_eq!(canvas.get(1, 0).ch, '+');
+        assert_eq!(canvas.get(36 + NODE_WIDTH / 2, 7).ch, 'v');
+    }
+
     #[test]
     fn blocked_node_border_color_pulses() {
         // Bright phase (frame 0)
@@ -1008,7 +1473,13 @@ mod tests {
             progress: None,
             x: 0,
             y: 0,
+            width: NODE_WIDTH,
             blocked: true,
+            dimmed: false,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
         };
         render_node(&mut canvas, &node, 0);
         assert_eq!(canvas.get(0, 0).style.fg, Some(theme::NEON_ORANGE));
@@ -1024,7 +1495,7 @@ mod tests {
     #[test]
     fn border_chars_todo_unchanged_by_animation_frame() {
         for frame in 0..6 {
-            let bc = border_chars(&ItemStatus::Todo, frame, false);
+            let bc = border_chars(&ItemStatus::Todo, frame, false, false);
             assert_eq!(bc.h, '\u{2550}', "TODO horizontal unchanged at frame {frame}");
             assert_eq!(bc.v, '\u{2551}', "TODO vertical unchanged at frame {frame}");
         }
@@ -1033,7 +1504,7 @@ mod tests {
     #[test]
     fn border_chars_done_unchanged_by_animation_frame() {
         for frame in 0..6 {
-            let bc = border_chars(&ItemStatus::Done, frame, false);
+            let bc = border_chars(&ItemStatus::Done, frame, false, false);
             assert_eq!(bc.h, '\u{2550}', "DONE horizontal unchanged at frame {frame}");
             assert_eq!(bc.v, '\u{2551}', "DONE vertical unchanged at frame {frame}");
         }
@@ -1042,7 +1513,7 @@ mod tests {
     #[test]
     fn marching_cell_bright_when_phase_below_3() {
         // perimeter_index=0, frame=0 → phase = (0 + 0) % 6 = 0 → bright
-        let (ch, st) = marching_cell(0, 0, true);
+        let (ch, st) = marching_cell(0, 0, true, false);
         assert_eq!(ch, '\u{2500}', "bright horizontal = solid ─");
         assert_eq!(st.fg, Some(theme::NEON_CYAN));
     }
@@ -1050,32 +1521,32 @@ mod tests {
     #[test]
     fn marching_cell_dim_when_phase_ge_3() {
         // perimeter_index=3, frame=0 → phase = (3 + 0) % 6 = 3 → dim
-        let (ch, st) = marching_cell(3, 0, true);
+        let (ch, st) = marching_cell(3, 0, true, false);
         assert_eq!(ch, '\u{254C}', "dim horizontal = dashed ╌");
         assert_eq!(st.fg, Some(theme::BORDER_DIM));
     }
 
     #[test]
     fn marching_cell_vertical_chars() {
-        let (ch, _) = marching_cell(0, 0, false);
+        let (ch, _) = marching_cell(0, 0, false, false);
         assert_eq!(ch, '\u{2502}', "bright vertical = solid │");
 
-        let (ch, _) = marching_cell(3, 0, false);
+        let (ch, _) = marching_cell(3, 0, false, false);
         assert_eq!(ch, '\u{254E}', "dim vertical = dashed ╎");
     }
 
     #[test]
     fn marching_cell_phase_shifts_with_frame() {
         // perimeter_index=0, frame=0 → phase (0 + 0) % 6 = 0 (bright)
-        let (_, st) = marching_cell(0, 0, true);
+        let (_, st) = marching_cell(0, 0, true, false);
         assert_eq!(st.fg, Some(theme::NEON_CYAN));
 
         // perimeter_index=0, frame=6 → phase (0 + 6) % 6 = 0 (bright, wraps)
-        let (_, st) = marching_cell(0, 6, true);
+        let (_, st) = marching_cell(0, 6, true, false);
         assert_eq!(st.fg, Some(theme::NEON_CYAN));
 
         // perimeter_index=0, frame=3 → phase (0 + 3) % 6 = 3 (dim)
-        let (_, st) = marching_cell(0, 3, true);
+        let (_, st) = marching_cell(0, 3, true, false);
         assert_eq!(st.fg, Some(theme::BORDER_DIM));
     }
 
@@ -1088,7 +1559,13 @@ mod tests {
             progress: None,
             x: 0,
             y: 0,
+            width: NODE_WIDTH,
             blocked: false,
+            dimmed: false,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
         };
         render_node(&mut canvas, &node, 0);
 
@@ -1116,7 +1593,13 @@ mod tests {
             progress: None,
             x: 0,
             y: 0,
+            width: NODE_WIDTH,
             blocked: false,
+            dimmed: false,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
         };
         render_node(&mut canvas0, &node, 0);
         render_node(&mut canvas3, &node, 3);
@@ -1136,7 +1619,13 @@ mod tests {
             progress: Some((2, 5)),
             x: 0,
             y: 0,
+            width: NODE_WIDTH,
             blocked: false,
+            dimmed: false,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
         };
         render_node(&mut canvas, &node, 0);
 
@@ -1146,9 +1635,213 @@ mod tests {
         assert_eq!(canvas.get(NODE_WIDTH - 1, 3).ch, '\u{256F}', "bottom-right at row 3");
     }
 
+    #[test]
+    fn dimmed_node_keeps_glyphs_but_fades_every_cell() {
+        let mut canvas = Canvas::new(40, 6);
+        let node = NodeBox {
+            title: "Faded".to_string(),
+            status: ItemStatus::InProgress,
+            progress: None,
+            x: 0,
+            y: 0,
+            width: NODE_WIDTH,
+            blocked: false,
+            dimmed: true,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
+        };
+        render_node(&mut canvas, &node, 0);
+
+        assert_eq!(canvas.get(0, 0).ch, '\u{256D}');
+        assert!(canvas_row(&canvas, 1).contains("Faded"));
+        for y in 0..NODE_HEIGHT_TASK {
+            for x in 0..NODE_WIDTH {
+                assert_eq!(canvas.get(x, y).style.fg, Some(theme::FADED), "cell ({x}, {y})");
+            }
+        }
+    }
+
+    #[test]
+    fn node_border_and_title_budget_follow_width() {
+        let title = "This Is A Very Long Title That Should Be Truncated";
+        for width in [NODE_WIDTH, 56] {
+            let mut canvas = Canvas::new(60, 6);
+            let node = NodeBox {
+                title: title.to_string(),
+                status: ItemStatus::Todo,
+                progress: None,
+                x: 0,
+                y: 0,
+                width,
+                blocked: false,
+                dimmed: false,
+                glyphs: theme::Glyphs::default(),
+                edge_counts: None,
+                external_blocker: false,
+                dim_completed: false,
+            };
+            render_node(&mut canvas, &node, 0);
+
+            let top = canvas_row(&canvas, 0);
+            assert_eq!(top.chars().nth(width - 1), Some('\u{2557}'), "width {width}: {top}");
+            assert_eq!(top.chars().nth(width), Some(' '), "width {width}: {top}");
+            assert_eq!(title_budget(width), width - 5);
+        }
+
+        // 50 chars: two lines at the default width, one line at 56.
+        assert_eq!(node_height(title, false, NODE_WIDTH), NODE_HEIGHT_TASK_2LINE);
+        assert_eq!(node_height(title, false, 56), NODE_HEIGHT_TASK);
+        let mut canvas = Canvas::new(60, 6);
+        let node = NodeBox {
+            title: title.to_string(),
+            status: ItemStatus::Todo,
+            progress: None,
+            x: 0,
+            y: 0,
+            width: 56,
+            blocked: false,
+            dimmed: false,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
+        };
+        render_node(&mut canvas, &node, 0);
+        assert!(canvas_row(&canvas, 1).contains(title));
+        assert!(canvas_row(&canvas, 2).starts_with('\u{255A}'));
+    }
+
+    #[test]
+    fn dim_completed_draws_done_nodes_in_dark_green() {
+        let mut node = NodeBox {
+            title: "Shipped".to_string(),
+            status: ItemStatus::Done,
+            progress: None,
+            x: 0,
+            y: 0,
+            width: NODE_WIDTH,
+            blocked: false,
+            dimmed: false,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
+        };
+        let mut canvas = Canvas::new(NODE_WIDTH, NODE_HEIGHT_TASK);
+        render_node(&mut canvas, &node, 0);
+        assert_eq!(canvas.get(0, 0).style.fg, Some(theme::NEON_GREEN));
+        assert_eq!(canvas.get(2, 1).style.fg, Some(theme::NEON_GREEN));
+        assert_eq!(canvas.get(4, 1).style.fg, Some(theme::TEXT_BRIGHT));
+
+        node.dim_completed = true;
+        let mut canvas = Canvas::new(NODE_WIDTH, NODE_HEIGHT_TASK);
+        render_node(&mut canvas, &node, 0);
+        assert_eq!(canvas.get(0, 0).style.fg, Some(theme::DARK_GREEN));
+        assert_eq!(canvas.get(2, 1).style.fg, Some(theme::DARK_GREEN));
+        assert_eq!(canvas.get(4, 1).style.fg, Some(theme::TEXT_DIM));
+
+        // Remaining work keeps its colors.
+        node.status = ItemStatus::Todo;
+        let mut canvas = Canvas::new(NODE_WIDTH, NODE_HEIGHT_TASK);
+        render_node(&mut canvas, &node, 0);
+        assert_eq!(canvas.get(0, 0).style.fg, Some(theme::TEXT_DIM));
+    }
+
+    #[test]
+    fn external_blocker_is_marked_on_the_top_border() {
+        let mut node = NodeBox {
+            title: "Client".to_string(),
+            status: ItemStatus::Todo,
+            progress: None,
+            x: 0,
+            y: 0,
+            width: NODE_WIDTH,
+            blocked: true,
+            dimmed: false,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: None,
+            external_blocker: true,
+            dim_completed: false,
+        };
+        let mut canvas = Canvas::new(NODE_WIDTH, NODE_HEIGHT_TASK);
+        render_node(&mut canvas, &node, 0);
+        assert_eq!(canvas_row(&canvas, 0).chars().nth(2), Some('\u{21E0}'));
+
+        node.glyphs.ascii = true;
+        let mut canvas = Canvas::new(NODE_WIDTH, NODE_HEIGHT_TASK);
+        render_node(&mut canvas, &node, 0);
+        assert!(canvas_row(&canvas, 0).starts_with("+-<"), "{}", canvas_row(&canvas, 0));
+
+        node.external_blocker = false;
+        let mut canvas = Canvas::new(NODE_WIDTH, NODE_HEIGHT_TASK);
+        render_node(&mut canvas, &node, 0);
+        assert!(!canvas_row(&canvas, 0).contains('<'));
+    }
+
+    #[test]
+    fn edge_count_badge_shows_fan_in_and_fan_out() {
+        let layout = DagLayout::new(
+            vec![make_node("A"), make_node("B"), make_node("C"), make_node("D")],
+            vec![make_edge("A", "B"), make_edge("A", "C"), make_edge("B", "C")],
+        );
+        let counts = edge_counts(&layout);
+        assert_eq!(counts["A"], (0, 2));
+        assert_eq!(counts["B"], (1, 1));
+        assert_eq!(counts["C"], (2, 0));
+        assert!(!counts.contains_key("D"));
+
+        let mut node = NodeBox {
+            title: "Hub".to_string(),
+            status: ItemStatus::Todo,
+            progress: None,
+            x: 0,
+            y: 0,
+            width: NODE_WIDTH,
+            blocked: false,
+            dimmed: false,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: Some(counts["C"]),
+            external_blocker: false,
+            dim_completed: false,
+        };
+        let mut canvas = Canvas::new(NODE_WIDTH, NODE_HEIGHT_TASK);
+        render_node(&mut canvas, &node, 0);
+        let bottom = canvas_row(&canvas, NODE_HEIGHT_TASK - 1);
+        assert!(bottom.ends_with(" \u{2191}2 \u{2193}0 \u{255D}"), "{bottom}");
+
+        node.edge_counts = None;
+        let mut canvas = Canvas::new(NODE_WIDTH, NODE_HEIGHT_TASK);
+        render_node(&mut canvas, &node, 0);
+        assert!(!canvas_row(&canvas, NODE_HEIGHT_TASK - 1).contains('\u{2191}'));
+    }
+
+    #[test]
+    fn edges_leave_from_center_of_wide_nodes() {
+        let layout = DagLayout::new(
+            vec![make_node("A"), make_node("B")],
+            vec![make_edge("A", "B")],
+        );
+        let mut positions = HashMap::new();
+        positions.insert("A".to_string(), (0_usize, 0_usize));
+        positions.insert("B".to_string(), (0_usize, 5_usize));
+        let heights = HashMap::new();
+        let sizes = NodeSizes {
+            width: 50,
+            heights: &heights,
+            default_height: NODE_HEIGHT_TASK,
+        };
+
+        let mut canvas = Canvas::new(60, 10);
+        render_edges(&mut canvas, &layout, &positions, &HashSet::new(), &sizes, None, false, false);
+        assert_eq!(canvas.get(25, 3).ch, '\u{2502}');
+        assert_eq!(canvas.get(25, 4).ch, '\u{25BC}');
+    }
+
     // ── Edge rendering ─────────────────────────────────────────
 
-    use super::super::graph::{DagLayout, Edge, Node};
+    use crate::graph::{DagLayout, Edge, Node};
 
     fn make_node(id: &str) -> Node {
         Node {
@@ -1160,6 +1853,14 @@ mod tests {
         }
     }
 
+    fn task_sizes(heights: &HashMap<String, usize>) -> NodeSizes<'_> {
+        NodeSizes {
+            width: NODE_WIDTH,
+            heights,
+            default_height: NODE_HEIGHT_TASK,
+        }
+    }
+
     fn make_edge(from: &str, to: &str) -> Edge {
         Edge {
             from: from.to_string(),
@@ -1182,7 +1883,9 @@ mod tests {
         positions.insert("B".to_string(), (0_usize, 5_usize));
         let blocked = HashSet::new();
 
-        render_edges(&mut canvas, &layout, &positions, &blocked, &HashMap::new(), NODE_HEIGHT_TASK);
+        let heights = HashMap::new();
+        let sizes = task_sizes(&heights);
+        render_edges(&mut canvas, &layout, &positions, &blocked, &sizes, None, false, false);
 
         // src_x = 0 + 30/2 = 15, src_y = 0 + 3 = 3, dst_y = 5 - 1 = 4
         // Vertical │ at (15, 3), ▼ at (15, 4)
@@ -1206,7 +1909,9 @@ mod tests {
         positions.insert("B".to_string(), (34_usize, 6_usize));
         let blocked = HashSet::new();
 
-        render_edges(&mut canvas, &layout, &positions, &blocked, &HashMap::new(), NODE_HEIGHT_TASK);
+        let heights = HashMap::new();
+        let sizes = task_sizes(&heights);
+        render_edges(&mut canvas, &layout, &positions, &blocked, &sizes, None, false, false);
 
         // src_x = 0+15 = 15, src_y = 3, dst_x = 34+15 = 49, dst_y = 5
         assert_eq!(canvas.get(15, 3).ch, '\u{2502}'); // │ down from source
@@ -1230,7 +1935,9 @@ mod tests {
         positions.insert("B".to_string(), (0_usize, 6_usize));
         let blocked = HashSet::new();
 
-        render_edges(&mut canvas, &layout, &positions, &blocked, &HashMap::new(), NODE_HEIGHT_TASK);
+        let heights = HashMap::new();
+        let sizes = task_sizes(&heights);
+        render_edges(&mut canvas, &layout, &positions, &blocked, &sizes, None, false, false);
 
         // src_x = 34+15 = 49, src_y = 3, dst_x = 0+15 = 15, dst_y = 5
         assert_eq!(canvas.get(49, 3).ch, '\u{2502}'); // │ down from source
@@ -1256,7 +1963,9 @@ mod tests {
         let mut blocked = HashSet::new();
         blocked.insert("C".to_string());
 
-        render_edges(&mut canvas, &layout, &positions, &blocked, &HashMap::new(), NODE_HEIGHT_TASK);
+        let heights = HashMap::new();
+        let sizes = task_sizes(&heights);
+        render_edges(&mut canvas, &layout, &positions, &blocked, &sizes, None, false, false);
 
         // Edge A->B (not blocked) should be cyan.
         assert_eq!(canvas.get(15, 3).style.fg, Some(theme::NEON_CYAN));
@@ -1282,7 +1991,9 @@ mod tests {
         // Place a node character on the canvas first.
         canvas.put_char(15, 3, 'X', Style::default());
 
-        render_edges(&mut canvas, &layout, &positions, &blocked, &HashMap::new(), NODE_HEIGHT_TASK);
+        let heights = HashMap::new();
+        let sizes = task_sizes(&heights);
+        render_edges(&mut canvas, &layout, &positions, &blocked, &sizes, None, false, false);
 
         // The 'X' should NOT be overwritten by the edge character.
         assert_eq!(canvas.get(15, 3).ch, 'X');
@@ -1299,7 +2010,9 @@ mod tests {
         let positions = HashMap::new();
         let blocked = HashSet::new();
 
-        render_edges(&mut canvas, &layout, &positions, &blocked, &HashMap::new(), NODE_HEIGHT_TASK);
+        let heights = HashMap::new();
+        let sizes = task_sizes(&heights);
+        render_edges(&mut canvas, &layout, &positions, &blocked, &sizes, None, false, false);
 
         // Canvas should remain all spaces.
         for y in 0..canvas.height {
@@ -1321,7 +2034,13 @@ mod tests {
             progress: None,
             x: 2,
             y: 2,
+            width: NODE_WIDTH,
             blocked: false,
+            dimmed: false,
+            glyphs: theme::Glyphs::default(),
+            edge_counts: None,
+            external_blocker: false,
+            dim_completed: false,
         };
         render_node(&mut canvas, &node, 0);
 
@@ -1329,7 +2048,7 @@ mod tests {
         assert_eq!(canvas.get(2, 2).style.fg, Some(theme::TEXT_DIM));
         assert_eq!(canvas.get(2, 2).ch, '\u{2554}'); // inner top-left
 
-        render_focus_highlight(&mut canvas, 2, 2, NODE_HEIGHT_TASK);
+        render_focus_highlight(&mut canvas, 2, 2, NODE_WIDTH, NODE_HEIGHT_TASK, false);
 
         // Inner border should be UNCHANGED (still TEXT_DIM, still double-line)
         assert_eq!(canvas.get(2, 2).style.fg, Some(theme::TEXT_DIM));
@@ -1359,10 +2078,67 @@ mod tests {
     fn focus_highlight_epic_height() {
         // Node at (2,2), outer glow needs room: canvas 40 wide, 8 tall
         let mut canvas = Canvas::new(40, 8);
-        render_focus_highlight(&mut canvas, 2, 2, NODE_HEIGHT_EPIC);
+        render_focus_highlight(&mut canvas, 2, 2, NODE_WIDTH, NODE_HEIGHT_EPIC, false);
 
         // Bottom outer glow at y + NODE_HEIGHT_EPIC = 2 + 4 = 6
         assert_eq!(canvas.get(1, 6).ch, '\u{2570}'); // ╰
         assert_eq!(canvas.get(1, 6).style.fg, Some(theme::NEON_MAGENTA));
     }
+
+    #[test]
+    fn edges_outside_highlight_are_faded() {
+        // A → B, A → C; highlight covers only A and B.
+        let layout = DagLayout::new(
+            vec![make_node("A"), make_node("B"), make_node("C")],
+            vec![make_edge("A", "B"), make_edge("A", "C")],
+        );
+
+        let mut canvas = Canvas::new(80, 10);
+        let mut positions = HashMap::new();
+        positions.insert("A".to_string(), (0_usize, 0_usize));
+        positions.insert("B".to_string(), (0_usize, 5_usize));
+        positions.insert("C".to_string(), (34_usize, 5_usize));
+        let highlight: HashSet<String> = ["A", "B"].into_iter().map(String::from).collect();
+
+        render_edges(
+            &mut canvas,
+            &layout,
+            &positions,
+            &HashSet::new(),
+            &task_sizes(&HashMap::new()),
+            Some(&highlight),
+            false,
+            false,
+        );
+
+        // A → B stays bright; the A → C arrow into C is faded.
+        assert_eq!(canvas.get(15, 4).style.fg, Some(theme::NEON_CYAN));
+        assert_eq!(canvas.get(49, 4).style.fg, Some(theme::FADED));
+    }
+
+    #[test]
+    fn dim_completed_fades_edges_touching_done_nodes() {
+        // A (done) → B, C → D; only A → B touches a done node.
+        let mut done = make_node("A");
+        done.status = ItemStatus::Done;
+        let layout = DagLayout::new(
+            vec![done, make_node("B"), make_node("C"), make_node("D")],
+            vec![make_edge("A", "B"), make_edge("C", "D")],
+        );
+        let mut positions = HashMap::new();
+        positions.insert("A".to_string(), (0_usize, 0_usize));
+        positions.insert("B".to_string(), (0_usize, 5_usize));
+        positions.insert("C".to_string(), (34_usize, 0_usize));
+        positions.insert("D".to_string(), (34_usize, 5_usize));
+        let heights = HashMap::new();
+        let sizes = task_sizes(&heights);
+        let blocked = HashSet::new();
+
+        for (fade, expected) in [(false, theme::NEON_CYAN), (true, theme::FADED)] {
+            let mut canvas = Canvas::new(80, 10);
+            render_edges(&mut canvas, &layout, &positions, &blocked, &sizes, None, fade, false);
+            assert_eq!(canvas.get(15, 4).style.fg, Some(expected));
+            assert_eq!(canvas.get(49, 4).style.fg, Some(theme::NEON_CYAN));
+        }
+    }
 }
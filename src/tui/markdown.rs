@@ -0,0 +1,189 @@
+use ratatui::style::{Modifier, Style};
+use ratatui::text::{Line, Span};
+
+use super::theme;
+
+fn text_style() -> Style {
+    Style::default().fg(theme::TEXT_DIM)
+}
+
+fn bold_style() -> Style {
+    Style::default()
+        .fg(theme::TEXT_BRIGHT)
+        .add_modifier(Modifier::BOLD)
+}
+
+fn code_style() -> Style {
+    Style::default().fg(theme::NEON_GREEN)
+}
+
+fn marker_style() -> Style {
+    Style::default().fg(theme::NEON_CYAN)
+}
+
+fn heading_style() -> Style {
+    Style::default()
+        .fg(theme::NEON_MAGENTA)
+        .add_modifier(Modifier::BOLD)
+}
+
+/// Convert a markdown subset into styled lines: `#` headings, `-`/`*`/`+`
+/// bullets, numbered lists, fenced code blocks, and inline `**bold**` and
+/// `` `code` `` spans. Anything else renders as plain text, so unknown
+/// syntax degrades gracefully. Wrapping is left to the containing widget.
+pub fn markdown_to_lines(input: &str) -> Vec<Line<'static>> {
+    let mut lines = Vec::new();
+    let mut in_code_block = false;
+
+    for raw in input.lines() {
+        let trimmed = raw.trim_start();
+
+        if trimmed.starts_with("```") {
+            in_code_block = !in_code_block;
+            continue;
+        }
+        if in_code_block {
+            lines.push(Line::from(Span::styled(format!("  {raw}"), code_style())));
+            continue;
+        }
+
+        if let Some(heading) = parse_heading(trimmed) {
+            lines.push(Line::from(Span::styled(heading.to_string(), heading_style())));
+        } else if let Some(item) = ["- ", "* ", "+ "]
+            .iter()
+            .find_map(|marker| trimmed.strip_prefix(marker))
+        {
+            let mut spans = vec![Span::styled("  \u{2022} ", marker_style())];
+            spans.extend(parse_inline(item));
+            lines.push(Line::from(spans));
+        } else if let Some((number, item)) = parse_numbered(trimmed) {
+            let mut spans = vec![Span::styled(format!("  {number}. "), marker_style())];
+            spans.extend(parse_inline(item));
+            lines.push(Line::from(spans));
+        } else {
+            lines.push(Line::from(parse_inline(raw)));
+        }
+    }
+
+    lines
+}
+
+/// `# Title` through `###### Title` → `Title`.
+fn parse_heading(line: &str) -> Option<&str> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    if (1..=6).contains(&level) {
+        line[level..].strip_prefix(' ').map(str::trim)
+    } else {
+        None
+    }
+}
+
+/// `12. item` → `(12, "item")`.
+fn parse_numbered(line: &str) -> Option<(&str, &str)> {
+    let digits = line.chars().take_while(|c| c.is_ascii_digit()).count();
+    if digits == 0 {
+        return None;
+    }
+    line[digits..]
+        .strip_prefix(". ")
+        .map(|rest| (&line[..digits], rest))
+}
+
+/// Split a line into plain, `**bold**` and `` `code` `` spans. Unterminated
+/// markers are kept as literal text.
+fn parse_inline(text: &str) -> Vec<Span<'static>> {
+    let mut spans = Vec::new();
+    let mut plain = String::new();
+    let mut rest = text;
+
+    while !rest.is_empty() {
+        let styled = if let Some(after) = rest.strip_prefix("**") {
+            after
+                .find("**")
+                .filter(|&end| end > 0)
+                .map(|end| (&after[..end], &after[end + 2..], bold_style()))
+        } else if let Some(after) = rest.strip_prefix('`') {
+            after
+                .find('`')
+                .filter(|&end| end > 0)
+                .map(|end| (&after[..end], &after[end + 1..], code_style()))
+        } else {
+            None
+        };
+
+        match styled {
+            Some((content, remaining, style)) => {
+                if !plain.is_empty() {
+                    spans.push(Span::styled(std::mem::take(&mut plain), text_style()));
+                }
+                spans.push(Span::styled(content.to_string(), style));
+                rest = remaining;
+            }
+            None => {
+                let ch = rest.chars().next().expect("rest is non-empty");
+                plain.push(ch);
+                rest = &rest[ch.len_utf8()..];
+            }
+        }
+    }
+
+    if !plain.is_empty() || spans.is_empty() {
+        spans.push(Span::styled(plain, text_style()));
+    }
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contents(line: &Line) -> Vec<String> {
+        line.spans.iter().map(|s| s.content.to_string()).collect()
+    }
+
+    #[test]
+    fn bold_span_is_styled() {
+        let lines = markdown_to_lines("Ship **before** Friday");
+        assert_eq!(lines.len(), 1);
+        assert_eq!(contents(&lines[0]), vec!["Ship ", "before", " Friday"]);
+        assert_eq!(lines[0].spans[0].style, text_style());
+        assert_eq!(lines[0].spans[1].style, bold_style());
+    }
+
+    #[test]
+    fn bullet_list_items() {
+        let lines = markdown_to_lines("- first\n* second **item**\n1. numbered");
+        assert_eq!(lines.len(), 3);
+        assert_eq!(contents(&lines[0]), vec!["  \u{2022} ", "first"]);
+        assert_eq!(contents(&lines[1]), vec!["  \u{2022} ", "second ", "item"]);
+        assert_eq!(lines[1].spans[2].style, bold_style());
+        assert_eq!(contents(&lines[2]), vec!["  1. ", "numbered"]);
+    }
+
+    #[test]
+    fn code_spans_and_blocks() {
+        let lines = markdown_to_lines("Run `cargo test` now\n```\nlet x = **1**;\n```");
+        assert_eq!(contents(&lines[0]), vec!["Run ", "cargo test", " now"]);
+        assert_eq!(lines[0].spans[1].style, code_style());
+
+        // Inside a fence, markdown is not interpreted.
+        assert_eq!(lines.len(), 2);
+        assert_eq!(contents(&lines[1]), vec!["  let x = **1**;"]);
+        assert_eq!(lines[1].spans[0].style, code_style());
+    }
+
+    #[test]
+    fn headings_and_blank_lines() {
+        let lines = markdown_to_lines("## Goals\n\nplain");
+        assert_eq!(contents(&lines[0]), vec!["Goals"]);
+        assert_eq!(lines[0].spans[0].style, heading_style());
+        assert_eq!(contents(&lines[1]), vec![""]);
+        assert_eq!(contents(&lines[2]), vec!["plain"]);
+    }
+
+    #[test]
+    fn unterminated_markers_stay_literal() {
+        let lines = markdown_to_lines("a **b and `c and #tag");
+        assert_eq!(contents(&lines[0]), vec!["a **b and `c and #tag"]);
+    }
+}
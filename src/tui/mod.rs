@@ -1,12 +1,14 @@
 mod app;
-pub mod graph;
 pub mod graph_render;
+mod markdown;
+mod state;
 mod theme;
 mod ui;
 
 pub use app::App;
 
 use std::io::stdout;
+use std::path::Path;
 use std::panic;
 
 use anyhow::Result;
@@ -16,6 +18,9 @@ use ratatui::Terminal;
 use ratatui::backend::CrosstermBackend;
 
 use crate::db::Database;
+use crate::db::project::resolve_project_fuzzy;
+use crate::db::workspace;
+use crate::settings::Settings;
 
 /// Drop guard that restores terminal state when dropped.
 struct TerminalGuard;
@@ -27,9 +32,12 @@ impl Drop for TerminalGuard {
     }
 }
 
-pub fn run() -> Result<()> {
-    let db = Database::open_default()?;
+pub fn run(workspace: Option<&str>, data_dir: Option<&Path>, project: Option<&str>) -> Result<()> {
+    let base = workspace::configured_base(data_dir);
+    let db = Database::open_workspace(workspace, data_dir)?;
     db.migrate()?;
+    // Resolved before the terminal is taken over so a bad name reads plainly.
+    let project = project.map(|query| resolve_project_fuzzy(&db, query)).transpose()?;
 
     enable_raw_mode()?;
     execute!(stdout(), EnterAlternateScreen)?;
@@ -47,6 +55,31 @@ pub fn run() -> Result<()> {
     let mut terminal = Terminal::new(backend)?;
 
     let mut app = App::new(db)?;
+    app.workspace = workspace.map(String::from);
+    app.workspace_dir = workspace::data_dir(base.as_deref()).ok();
+    match &project {
+        Some(project) => {
+            app.select_project(&project.id);
+            app.remember_project();
+        }
+        None => app.restore_last_project(),
+    }
+    let settings = match &base {
+        Some(dir) => Settings::load_from(Some(dir.clone())),
+        None => Settings::load(),
+    };
+    app.settings_dir = base;
+    app.animations_enabled = settings.animations_enabled();
+    app.db.set_auto_epic_status(settings.auto_epic_status_enabled());
+    if let Some(width) = settings.node_width {
+        app.set_node_width(width);
+    }
+    app.layout_mode = settings.layout.unwrap_or_default();
+    app.wrap_navigation = settings.wrap_navigation_enabled();
+    app.high_contrast_symbols = settings.high_contrast_symbols_enabled();
+    app.dim_completed = settings.dim_completed_enabled();
+    app.ascii_glyphs = settings.ascii_glyphs_enabled()
+        || theme::ascii_requested(std::env::var(theme::ASCII_ENV).ok().as_deref());
     let result = app.run(&mut terminal);
 
     // Restore the original panic hook before returning
@@ -0,0 +1,65 @@
+//! What the TUI remembers between runs, kept per workspace in
+//! `<data dir>/<workspace>.tui.json` next to the workspace's database.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TuiState {
+    /// Project selected when the TUI last changed projects.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_project_id: Option<String>,
+}
+
+impl TuiState {
+    /// State file of `workspace` in the data directory `dir`.
+    pub fn path_in(dir: &Path, workspace: &str) -> PathBuf {
+        dir.join(format!("{workspace}.tui.json"))
+    }
+
+    /// Reads the state at `path`, or the default if it is missing or malformed.
+    pub fn load(path: &Path) -> Self {
+        fs::read_to_string(path)
+            .ok()
+            .and_then(|data| serde_json::from_str(&data).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(std::io::Error::other)?;
+        fs::write(path, json.as_bytes())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn save_then_load_round_trips() {
+        let dir = TempDir::new().unwrap();
+        let path = TuiState::path_in(&dir.path().join(".blueprint"), "client-a");
+        assert!(path.ends_with(".blueprint/client-a.tui.json"));
+        assert_eq!(TuiState::load(&path), TuiState::default());
+
+        let state = TuiState {
+            last_project_id: Some("01ABC".to_string()),
+        };
+        state.save(&path).unwrap();
+        assert_eq!(TuiState::load(&path), state);
+    }
+
+    #[test]
+    fn malformed_file_loads_as_default() {
+        let dir = TempDir::new().unwrap();
+        let path = TuiState::path_in(dir.path(), "blueprint");
+        fs::write(&path, "not json").unwrap();
+        assert_eq!(TuiState::load(&path), TuiState::default());
+    }
+}
@@ -15,6 +15,8 @@ pub const TEXT_BRIGHT: Color = Color::Rgb(0xff, 0xff, 0xff);
 pub const BORDER_DIM: Color = Color::Rgb(0x00, 0x5f, 0x5f);
 pub const BORDER_BRIGHT: Color = Color::Rgb(0x00, 0xff, 0xf5);
 pub const DARK_ORANGE: Color = Color::Rgb(0x66, 0x33, 0x11);
+pub const DARK_GREEN: Color = Color::Rgb(0x1d, 0x5c, 0x12);
+pub const FADED: Color = Color::Rgb(0x3a, 0x3a, 0x44);
 
 // ── Style presets ──────────────────────────────────────────────────
 
@@ -36,17 +38,62 @@ pub fn status_style(status: &ItemStatus) -> Style {
     }
 }
 
-pub fn status_symbol(status: &ItemStatus) -> &'static str {
-    match status {
-        ItemStatus::Todo => "■",
-        ItemStatus::InProgress => "▶",
-        ItemStatus::Done => "◉",
+// ── Glyph sets ─────────────────────────────────────────────────────
+
+/// Environment variable that forces the ASCII glyph set, for terminals and
+/// fonts without box-drawing or symbol characters.
+pub const ASCII_ENV: &str = "BLUEPRINT_ASCII";
+
+/// Which glyphs the symbol, border and edge helpers draw.
+///
+/// `ascii` (the `ascii_glyphs` setting or [`ASCII_ENV`]) restricts every
+/// glyph to plain ASCII; `high_contrast` (the `high_contrast_symbols`
+/// setting) swaps status symbols for checkbox-style markers.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Glyphs {
+    pub ascii: bool,
+    pub high_contrast: bool,
+}
+
+/// Whether a value of [`ASCII_ENV`] asks for the ASCII glyph set: anything
+/// other than unset, empty or `0`.
+pub fn ascii_requested(value: Option<&str>) -> bool {
+    value.is_some_and(|v| !v.is_empty() && v != "0")
+}
+
+/// Drawn in place of the status symbol on epics with no tasks.
+pub const MILESTONE_SYMBOL: &str = "◆";
+
+/// [`MILESTONE_SYMBOL`], or `*` in the ASCII glyph set.
+pub fn milestone_symbol(glyphs: Glyphs) -> &'static str {
+    if glyphs.ascii { "*" } else { MILESTONE_SYMBOL }
+}
+
+/// Glyph for a status. With `high_contrast` it is a checkbox-style ASCII
+/// marker whose shape alone tells the statuses apart, for readers who cannot
+/// rely on the colors; otherwise the ASCII set uses `o`, `>` and `x`.
+pub fn status_symbol(status: &ItemStatus, glyphs: Glyphs) -> &'static str {
+    if glyphs.high_contrast {
+        return match status {
+            ItemStatus::Todo => "[ ]",
+            ItemStatus::InProgress => "[~]",
+            ItemStatus::Done => "[x]",
+        };
+    }
+    match (status, glyphs.ascii) {
+        (ItemStatus::Todo, false) => "■",
+        (ItemStatus::InProgress, false) => "▶",
+        (ItemStatus::Done, false) => "◉",
+        (ItemStatus::Todo, true) => "o",
+        (ItemStatus::InProgress, true) => ">",
+        (ItemStatus::Done, true) => "x",
     }
 }
 
 pub fn project_status_style(status: &ProjectStatus) -> Style {
     match status {
         ProjectStatus::Active => Style::default().fg(NEON_GREEN),
+        ProjectStatus::OnHold => Style::default().fg(NEON_ORANGE).add_modifier(Modifier::ITALIC),
         ProjectStatus::Archived => Style::default().fg(TEXT_DIM),
     }
 }
@@ -55,10 +102,29 @@ pub fn project_status_style(status: &ProjectStatus) -> Style {
 
 pub const BLOCKED_SYMBOL: &str = "⚠";
 
+/// [`BLOCKED_SYMBOL`], or a plain `!` with `high_contrast` or in the ASCII set.
+pub fn blocked_symbol(glyphs: Glyphs) -> &'static str {
+    if glyphs.high_contrast || glyphs.ascii { "!" } else { BLOCKED_SYMBOL }
+}
+
+/// Marks a node held up by a blocker that is not in the graph on screen,
+/// such as a task in another epic.
+pub const EXTERNAL_BLOCKER_SYMBOL: &str = "⇠";
+
+/// [`EXTERNAL_BLOCKER_SYMBOL`], or `<` in the ASCII glyph set.
+pub fn external_blocker_symbol(glyphs: Glyphs) -> &'static str {
+    if glyphs.ascii { "<" } else { EXTERNAL_BLOCKER_SYMBOL }
+}
+
 // ── Session indicator ─────────────────────────────────────────────
 
 pub const SESSION_SYMBOL: &str = "\u{26A1}";
 
+/// [`SESSION_SYMBOL`], or `@` in the ASCII glyph set.
+pub fn session_symbol(glyphs: Glyphs) -> &'static str {
+    if glyphs.ascii { "@" } else { SESSION_SYMBOL }
+}
+
 pub fn session_style() -> Style {
     Style::default().fg(NEON_GREEN)
 }
@@ -69,14 +135,11 @@ pub fn blocked_style() -> Style {
 
 // ── Progress bar ───────────────────────────────────────────────────
 
-pub fn progress_bar(done: usize, total: usize, width: usize) -> String {
-    let filled = if total == 0 {
-        0
-    } else {
-        ((done * width) / total).min(width)
-    };
+pub fn progress_bar(done: usize, total: usize, width: usize, glyphs: Glyphs) -> String {
+    let filled = (done * width).checked_div(total).map_or(0, |v| v.min(width));
     let empty = width - filled;
-    "█".repeat(filled) + &"░".repeat(empty)
+    let (full, blank) = if glyphs.ascii { ("#", ".") } else { ("█", "░") };
+    full.repeat(filled) + &blank.repeat(empty)
 }
 
 // ── ASCII art header ───────────────────────────────────────────────
@@ -92,22 +155,22 @@ mod tests {
 
     #[test]
     fn progress_bar_empty() {
-        assert_eq!(progress_bar(0, 10, 10), "░░░░░░░░░░");
+        assert_eq!(progress_bar(0, 10, 10, Glyphs::default()), "░░░░░░░░░░");
     }
 
     #[test]
     fn progress_bar_half() {
-        assert_eq!(progress_bar(5, 10, 10), "█████░░░░░");
+        assert_eq!(progress_bar(5, 10, 10, Glyphs::default()), "█████░░░░░");
     }
 
     #[test]
     fn progress_bar_full() {
-        assert_eq!(progress_bar(10, 10, 10), "██████████");
+        assert_eq!(progress_bar(10, 10, 10, Glyphs::default()), "██████████");
     }
 
     #[test]
     fn progress_bar_zero_total() {
-        assert_eq!(progress_bar(0, 0, 10), "░░░░░░░░░░");
+        assert_eq!(progress_bar(0, 0, 10, Glyphs::default()), "░░░░░░░░░░");
     }
 
     #[test]
@@ -127,15 +190,57 @@ mod tests {
         let active_style = project_status_style(&ProjectStatus::Active);
         assert_eq!(active_style.fg, Some(NEON_GREEN));
 
+        let on_hold_style = project_status_style(&ProjectStatus::OnHold);
+        assert_eq!(on_hold_style.fg, Some(NEON_ORANGE));
+        assert!(on_hold_style.add_modifier.contains(Modifier::ITALIC));
+
         let archived_style = project_status_style(&ProjectStatus::Archived);
         assert_eq!(archived_style.fg, Some(TEXT_DIM));
     }
 
     #[test]
     fn status_symbol_returns_correct_char() {
-        assert_eq!(status_symbol(&ItemStatus::Todo), "■");
-        assert_eq!(status_symbol(&ItemStatus::InProgress), "▶");
-        assert_eq!(status_symbol(&ItemStatus::Done), "◉");
+        assert_eq!(status_symbol(&ItemStatus::Todo, Glyphs::default()), "■");
+        assert_eq!(status_symbol(&ItemStatus::InProgress, Glyphs::default()), "▶");
+        assert_eq!(status_symbol(&ItemStatus::Done, Glyphs::default()), "◉");
+        assert_eq!(blocked_symbol(Glyphs::default()), BLOCKED_SYMBOL);
+    }
+
+    #[test]
+    fn high_contrast_symbols_are_distinct_ascii_markers() {
+        let hc = Glyphs { high_contrast: true, ..Glyphs::default() };
+        assert_eq!(status_symbol(&ItemStatus::Todo, hc), "[ ]");
+        assert_eq!(status_symbol(&ItemStatus::InProgress, hc), "[~]");
+        assert_eq!(status_symbol(&ItemStatus::Done, hc), "[x]");
+        assert_eq!(blocked_symbol(hc), "!");
+        for status in [ItemStatus::Todo, ItemStatus::InProgress, ItemStatus::Done] {
+            assert!(status_symbol(&status, hc).is_ascii());
+        }
+    }
+
+    #[test]
+    fn ascii_glyph_set_uses_only_ascii() {
+        let ascii = Glyphs { ascii: true, ..Glyphs::default() };
+        assert_eq!(status_symbol(&ItemStatus::Todo, ascii), "o");
+        assert_eq!(status_symbol(&ItemStatus::InProgress, ascii), ">");
+        assert_eq!(status_symbol(&ItemStatus::Done, ascii), "x");
+        assert_eq!(blocked_symbol(ascii), "!");
+        assert_eq!(milestone_symbol(ascii), "*");
+        assert_eq!(session_symbol(ascii), "@");
+        assert_eq!(progress_bar(3, 10, 10, ascii), "###.......");
+
+        // High-contrast markers win over the plain ASCII status glyphs.
+        let both = Glyphs { ascii: true, high_contrast: true };
+        assert_eq!(status_symbol(&ItemStatus::Done, both), "[x]");
+    }
+
+    #[test]
+    fn ascii_requested_ignores_unset_empty_and_zero() {
+        assert!(!ascii_requested(None));
+        assert!(!ascii_requested(Some("")));
+        assert!(!ascii_requested(Some("0")));
+        assert!(ascii_requested(Some("1")));
+        assert!(ascii_requested(Some("true")));
     }
 
     #[test]
@@ -1,20 +1,31 @@
 use std::cell::Cell;
 use std::collections::HashMap;
 
-use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::layout::{Constraint, Direction, Layout, Margin, Rect};
 use ratatui::style::{Modifier, Style};
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Clear, List, ListItem, Paragraph};
+use ratatui::widgets::{
+    Block, Borders, Clear, List, ListItem, ListState, Paragraph, Scrollbar, ScrollbarOrientation,
+    ScrollbarState,
+};
 use ratatui::Frame;
 
-use crate::models::ItemStatus;
-use crate::tui::app::{App, FocusedPanel, GraphCache, GraphLevel, GraphPane, InputMode};
+use crate::db::workspace::DEFAULT_WORKSPACE;
+use crate::graph::{NODE_HEIGHT_EPIC, NODE_HEIGHT_TASK};
+use crate::models::{checklist_progress, ItemStatus};
+use crate::settings::LayoutMode;
+use crate::tui::app::{
+    App, FocusedPanel, GraphCache, GraphLevel, GraphPane, InputMode, list_offset,
+};
 use crate::tui::graph_render::{
-    Canvas, NodeBox, node_height, render_edges, render_focus_highlight, render_node,
-    NODE_HEIGHT_EPIC, NODE_HEIGHT_TASK,
+    Canvas, NodeBox, NodeSizes, edge_counts, has_progress_bar, node_height, render_edges,
+    render_focus_highlight, render_node,
 };
+use crate::tui::markdown;
 use crate::tui::theme;
 
+const GRAPH_HELP: &str = "  Esc: Back  1: Epics  2: Tasks  3: Dual  Tab: Pane  \u{2190}\u{2191}\u{2192}\u{2193}: Focus  hjkl: Scroll  g/G: Top/Bottom  PgUp/PgDn: Page  f: Chain  y: Copy  m: Map  e: Edges  c: Counts  D: Dim done  o: Blocked  +/-: Width  a: Anim";
+
 /// Bundles the per-pane graph rendering parameters so callers don't need to
 /// pass many individual fields.
 struct GraphPaneParams<'a> {
@@ -27,8 +38,13 @@ struct GraphPaneParams<'a> {
 }
 
 pub fn draw(frame: &mut Frame, app: &App) {
-    if app.mode == InputMode::GraphView {
+    let palette_over_graph =
+        app.mode == InputMode::CommandPalette && app.palette_origin == InputMode::GraphView;
+    if app.mode == InputMode::GraphView || palette_over_graph {
         draw_graph_view(frame, app);
+        if palette_over_graph {
+            draw_command_palette(frame, app);
+        }
         return;
     }
 
@@ -60,6 +76,11 @@ pub fn draw(frame: &mut Frame, app: &App) {
         Span::styled("│ ", Style::default().fg(theme::BORDER_DIM)),
         Span::styled(project_name, Style::default().fg(theme::NEON_MAGENTA)),
         Span::styled(" [p]", Style::default().fg(theme::TEXT_DIM)),
+        Span::styled(
+            format!("  {}", app.workspace.as_deref().unwrap_or(DEFAULT_WORKSPACE)),
+            Style::default().fg(theme::NEON_CYAN),
+        ),
+        Span::styled(" [w]", Style::default().fg(theme::TEXT_DIM)),
     ]))
     .block(
         Block::default()
@@ -69,40 +90,45 @@ pub fn draw(frame: &mut Frame, app: &App) {
     );
     frame.render_widget(header, chunks[0]);
 
-    // Body: 2x2 grid
-    let body_rows = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
-        .split(chunks[1]);
-
-    let top_panels = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(body_rows[0]);
-
-    let bottom_panels = Layout::default()
-        .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
-        .split(body_rows[1]);
-
-    draw_epic_list(frame, app, top_panels[0]);
-    draw_task_list(frame, app, top_panels[1]);
-    draw_deps_panel(frame, app, bottom_panels[0]);
-    draw_status_panel(frame, app, bottom_panels[1]);
+    // Body
+    for (panel, area) in body_panels(app.layout_mode, chunks[1]) {
+        match panel {
+            FocusedPanel::Epics => draw_epic_list(frame, app, area),
+            FocusedPanel::Tasks => draw_task_list(frame, app, area),
+            FocusedPanel::Dependencies => draw_deps_panel(frame, app, area),
+            FocusedPanel::Status => draw_status_panel(frame, app, area),
+        }
+    }
 
     // Footer
     let help_text = match app.mode {
         InputMode::Normal => {
-            "  q: Quit  p: Projects  Tab: Focus  h/l: Left/Right  j/k: Navigate  s: Status  ?: Help"
+            "  q: Quit  p: Projects  w: Workspaces  b: Blocked  Tab: Focus  h/l: Left/Right  j/k: Navigate  s: Status  v: Layout  :: Commands  ?: Help"
+        }
+        InputMode::ProjectSelector => "  j/k: Navigate  Enter: Select  n: New project  Esc: Cancel",
+        InputMode::WorkspaceSelector => "  j/k: Navigate  Enter: Select  Esc: Cancel",
+        InputMode::ProjectCreate if app.project_form.confirm_discard.is_some() => {
+            "  y: Discard  n/Esc: Keep editing"
+        }
+        InputMode::ProjectCreate => "  Tab: Next field  Enter: Create  Esc: Cancel  Ctrl-C: Quit",
+        InputMode::BlockedList => "  j/k: Navigate  Enter: Go to item  Esc: Cancel",
+        InputMode::TaskDetail => {
+            "  j/k: Blockers  Enter: Open blocker  y: Copy session  i: Full ID  Esc: Close"
+        }
+        InputMode::HelpOverlay => "  Esc: Close",
+        InputMode::GraphView => GRAPH_HELP,
+        InputMode::CommandPalette => {
+            "  Type to filter  \u{2191}/\u{2193}: Navigate  Enter: Run  Esc: Cancel"
         }
-        InputMode::ProjectSelector => "  j/k: Navigate  Enter: Select  Esc: Cancel",
-        InputMode::TaskDetail | InputMode::HelpOverlay => "  Esc: Close",
-        InputMode::GraphView => "  Esc: Back  1: Epics  2: Tasks  3: Dual  Tab: Pane  \u{2190}\u{2191}\u{2192}\u{2193}: Focus  hjkl: Scroll",
     };
-    let footer = Paragraph::new(Line::from(vec![Span::styled(
-        help_text,
-        Style::default().fg(theme::TEXT_DIM),
-    )]))
+    let footer_line = match &app.status_message {
+        Some(message) => Line::from(Span::styled(
+            format!("  {message}"),
+            Style::default().fg(theme::NEON_GREEN),
+        )),
+        None => Line::from(Span::styled(help_text, Style::default().fg(theme::TEXT_DIM))),
+    };
+    let footer = Paragraph::new(footer_line)
     .block(
         Block::default()
             .borders(Borders::ALL)
@@ -118,8 +144,12 @@ pub fn draw(frame: &mut Frame, app: &App) {
     // Popup overlay
     match app.mode {
         InputMode::ProjectSelector => draw_project_selector(frame, app),
+        InputMode::WorkspaceSelector => draw_workspace_selector(frame, app),
+        InputMode::BlockedList => draw_blocked_list(frame, app),
         InputMode::TaskDetail => draw_task_detail(frame, app),
         InputMode::HelpOverlay => draw_help_overlay(frame),
+        InputMode::CommandPalette => draw_command_palette(frame, app),
+        InputMode::ProjectCreate => draw_project_form(frame, app),
         InputMode::Normal | InputMode::GraphView => {}
     }
 }
@@ -145,9 +175,11 @@ fn selection_styles(is_selected: bool) -> (&'static str, Style, Style) {
     (marker, marker_style, title_style)
 }
 
-/// Returns a dim `[short_id] ` span if present, or `None`.
-fn short_id_span(short_id: Option<&str>) -> Option<Span<'static>> {
-    short_id.map(|sid| Span::styled(format!("[{sid}] "), Style::default().fg(theme::TEXT_DIM)))
+/// Returns a dim `[short_id] ` span if present, or `None`. With `full_id`
+/// it shows the full `id` instead, present or not.
+fn id_span(short_id: Option<&str>, id: &str, full_id: bool) -> Option<Span<'static>> {
+    let shown = if full_id { Some(id) } else { short_id };
+    shown.map(|sid| Span::styled(format!("[{sid}] "), Style::default().fg(theme::TEXT_DIM)))
 }
 
 /// Creates a styled panel block with the given title.
@@ -168,26 +200,143 @@ fn panel_block(title: &str, focused: bool) -> Block<'_> {
         .style(Style::default().bg(theme::BG))
 }
 
+/// Rows available for items inside a bordered list panel.
+fn list_viewport_height(area: Rect) -> usize {
+    area.height.saturating_sub(2) as usize
+}
+
+/// Whether `len` one-line items overflow a list `height` rows tall.
+fn needs_scrollbar(len: usize, height: usize) -> bool {
+    len > height
+}
+
+/// Renders one-line `items` in `block`, scrolled so `selected` stays in view.
+/// `offset` keeps the first visible row between frames; a scrollbar on the
+/// right border shows the position when the items don't fit.
+fn render_list(
+    frame: &mut Frame,
+    area: Rect,
+    block: Block,
+    items: Vec<ListItem>,
+    selected: usize,
+    offset: &Cell<usize>,
+) {
+    let height = list_viewport_height(area);
+    let len = items.len();
+    let mut state = ListState::default()
+        .with_offset(list_offset(selected, offset.get(), height, len))
+        .with_selected((selected < len).then_some(selected));
+    frame.render_stateful_widget(List::new(items).block(block), area, &mut state);
+    offset.set(state.offset());
+
+    if needs_scrollbar(len, height) {
+        // One position per possible first row, so the thumb reaches the
+        // bottom when the last item is shown.
+        let mut scroll = ScrollbarState::new(len - height + 1).position(state.offset());
+        let scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .track_style(Style::default().fg(theme::BORDER_DIM))
+            .thumb_style(Style::default().fg(theme::NEON_CYAN));
+        let track = area.inner(Margin {
+            vertical: 1,
+            horizontal: 0,
+        });
+        frame.render_stateful_widget(scrollbar, track, &mut scroll);
+    }
+}
+
+/// The panels shown for a layout and where each goes within `area`: a 2x2
+/// grid of all four, or a single column with epics over tasks.
+fn body_panels(mode: LayoutMode, area: Rect) -> Vec<(FocusedPanel, Rect)> {
+    match mode {
+        LayoutMode::Grid => {
+            let rows = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+                .split(area);
+            let halves = |row: Rect| {
+                Layout::default()
+                    .direction(Direction::Horizontal)
+                    .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+                    .split(row)
+            };
+            let (top, bottom) = (halves(rows[0]), halves(rows[1]));
+            vec![
+                (FocusedPanel::Epics, top[0]),
+                (FocusedPanel::Tasks, top[1]),
+                (FocusedPanel::Dependencies, bottom[0]),
+                (FocusedPanel::Status, bottom[1]),
+            ]
+        }
+        LayoutMode::List => {
+            let rows = Layout::default()
+                .direction(Direction::Vertical)
+                .constraints([Constraint::Percentage(35), Constraint::Percentage(65)])
+                .split(area);
+            vec![(FocusedPanel::Epics, rows[0]), (FocusedPanel::Tasks, rows[1])]
+        }
+    }
+}
+
+/// Dim guidance centered in a panel that has nothing to list, with an
+/// optional `hint` line below in the accent color.
+fn draw_empty_state(
+    frame: &mut Frame,
+    area: Rect,
+    block: Block,
+    message: &str,
+    hint: Option<&str>,
+) {
+    let mut lines = vec![Line::from(Span::styled(
+        message.to_string(),
+        Style::default().fg(theme::TEXT_DIM),
+    ))];
+    if let Some(hint) = hint {
+        let hint_style = Style::default().fg(theme::NEON_CYAN);
+        lines.push(Line::from(Span::styled(hint.to_string(), hint_style)));
+    }
+    let inner_height = area.height.saturating_sub(2) as usize;
+    let top = inner_height.saturating_sub(lines.len()) / 2;
+    let mut text = vec![Line::from(""); top];
+    text.extend(lines);
+    let paragraph = Paragraph::new(text)
+        .alignment(ratatui::layout::Alignment::Center)
+        .block(block);
+    frame.render_widget(paragraph, area);
+}
+
 fn draw_epic_list(frame: &mut Frame, app: &App, area: Rect) {
+    let focused = app.focused_panel == FocusedPanel::Epics;
+    if app.epics.is_empty() {
+        let (message, hint) = if app.projects.is_empty() {
+            ("No projects yet", "Create your first project (n)")
+        } else {
+            ("No epics in this project", "Agents add them with create_epic or feed_prd")
+        };
+        draw_empty_state(frame, area, panel_block(" Epics ", focused), message, Some(hint));
+        return;
+    }
+
     let list_items: Vec<ListItem> = app
         .epics
         .iter()
         .enumerate()
         .map(|(i, epic)| {
             let (marker, marker_style, title_style) = selection_styles(i == app.selected_epic_idx);
-            let symbol = theme::status_symbol(&epic.status);
+            let symbol = theme::status_symbol(&epic.status, app.glyphs());
             let status_style = theme::status_style(&epic.status);
 
             let mut spans = vec![
                 Span::styled(marker, marker_style),
                 Span::styled(format!("{symbol} "), status_style),
             ];
-            spans.extend(short_id_span(epic.short_id.as_deref()));
+            spans.extend(id_span(epic.short_id.as_deref(), &epic.id, app.show_full_ids));
             spans.push(Span::styled(&epic.title, title_style));
 
             if app.blocked_epic_ids.contains(&epic.id) {
                 spans.push(Span::styled(
-                    format!(" {}", theme::BLOCKED_SYMBOL),
+                    format!(" {}", theme::blocked_symbol(app.glyphs())),
                     theme::blocked_style(),
                 ));
             }
@@ -201,9 +350,14 @@ fn draw_epic_list(frame: &mut Frame, app: &App, area: Rect) {
         })
         .collect();
 
-    let focused = app.focused_panel == FocusedPanel::Epics;
-    let list = List::new(list_items).block(panel_block(" Epics ", focused));
-    frame.render_widget(list, area);
+    render_list(
+        frame,
+        area,
+        panel_block(" Epics ", focused),
+        list_items,
+        app.selected_epic_idx,
+        &app.epic_list_offset,
+    );
 }
 
 fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
@@ -228,6 +382,17 @@ fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
 
 fn draw_task_list(frame: &mut Frame, app: &App, area: Rect) {
     let focused = app.focused_panel == FocusedPanel::Tasks;
+    if app.tasks.is_empty() {
+        let (message, hint) = if app.projects.is_empty() {
+            ("Tasks show here once you have a project", None)
+        } else if app.epics.is_empty() {
+            ("Tasks show here once the project has epics", None)
+        } else {
+            ("No tasks in this epic", Some("Agents add them with create_task"))
+        };
+        draw_empty_state(frame, area, panel_block(" Tasks ", focused), message, hint);
+        return;
+    }
 
     let list_items: Vec<ListItem> = app
         .tasks
@@ -236,26 +401,26 @@ fn draw_task_list(frame: &mut Frame, app: &App, area: Rect) {
         .map(|(i, task)| {
             let (marker, marker_style, title_style) =
                 selection_styles(i == app.selected_task_idx);
-            let symbol = theme::status_symbol(&task.status);
+            let symbol = theme::status_symbol(&task.status, app.glyphs());
             let status_style = theme::status_style(&task.status);
 
             let mut spans = vec![
                 Span::styled(marker, marker_style),
                 Span::styled(format!("{symbol} "), status_style),
             ];
-            spans.extend(short_id_span(task.short_id.as_deref()));
+            spans.extend(id_span(task.short_id.as_deref(), &task.id, app.show_full_ids));
             spans.push(Span::styled(&task.title, title_style));
 
             if task.session_id.is_some() {
                 spans.push(Span::styled(
-                    format!(" {}", theme::SESSION_SYMBOL),
+                    format!(" {}", theme::session_symbol(app.glyphs())),
                     theme::session_style(),
                 ));
             }
 
             if app.blocked_task_ids.contains(&task.id) {
                 spans.push(Span::styled(
-                    format!(" {}", theme::BLOCKED_SYMBOL),
+                    format!(" {}", theme::blocked_symbol(app.glyphs())),
                     theme::blocked_style(),
                 ));
             }
@@ -265,12 +430,79 @@ fn draw_task_list(frame: &mut Frame, app: &App, area: Rect) {
                 status_style,
             ));
 
-            ListItem::new(Line::from(spans))
+            let mut lines = vec![Line::from(spans)];
+            if app.is_task_expanded(&task.id) {
+                let width = (area.width as usize).saturating_sub(2 + EXPANDED_INDENT.len());
+                lines.extend(expanded_description(&task.description, width));
+            }
+            ListItem::new(lines)
         })
         .collect();
 
-    let list = List::new(list_items).block(panel_block(" Tasks ", focused));
-    frame.render_widget(list, area);
+    render_list(
+        frame,
+        area,
+        panel_block(" Tasks ", focused),
+        list_items,
+        app.selected_task_idx,
+        &app.task_list_offset,
+    );
+}
+
+/// Indent of an expanded task's description under its row.
+const EXPANDED_INDENT: &str = "      ";
+/// Most description lines an expanded task shows before eliding the rest.
+const MAX_EXPANDED_LINES: usize = 8;
+
+/// An expanded task's description, wrapped to `width` columns and capped at
+/// [`MAX_EXPANDED_LINES`].
+fn expanded_description(description: &str, width: usize) -> Vec<Line<'static>> {
+    let style = Style::default().fg(theme::TEXT_DIM);
+    if description.trim().is_empty() {
+        return vec![Line::from(Span::styled(format!("{EXPANDED_INDENT}(no description)"), style))];
+    }
+    let mut wrapped: Vec<String> = description
+        .lines()
+        .flat_map(|line| wrap_words(line, width))
+        .collect();
+    if wrapped.len() > MAX_EXPANDED_LINES {
+        wrapped.truncate(MAX_EXPANDED_LINES);
+        wrapped.push("\u{2026}".to_string());
+    }
+    wrapped
+        .into_iter()
+        .map(|text| Line::from(Span::styled(format!("{EXPANDED_INDENT}{text}"), style)))
+        .collect()
+}
+
+/// Greedy word wrap of one line to `width` characters. Words longer than
+/// the width are split; a blank line stays one empty row.
+fn wrap_words(line: &str, width: usize) -> Vec<String> {
+    let width = width.max(1);
+    let mut rows = Vec::new();
+    let mut current = String::new();
+    for word in line.split_whitespace() {
+        let mut word: Vec<char> = word.chars().collect();
+        while word.len() > width {
+            if !current.is_empty() {
+                rows.push(std::mem::take(&mut current));
+            }
+            rows.push(word.drain(..width).collect());
+        }
+        let word: String = word.into_iter().collect();
+        let len = current.chars().count();
+        if len > 0 && len + 1 + word.chars().count() > width {
+            rows.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(&word);
+    }
+    if !current.is_empty() || rows.is_empty() {
+        rows.push(current);
+    }
+    rows
 }
 
 fn draw_task_detail(frame: &mut Frame, app: &App) {
@@ -280,10 +512,10 @@ fn draw_task_detail(frame: &mut Frame, app: &App) {
     let area = centered_rect(60, 50, frame.area());
     frame.render_widget(Clear, area);
 
-    let symbol = theme::status_symbol(&task.status);
+    let symbol = theme::status_symbol(&task.status, app.glyphs());
 
     let mut header_spans: Vec<Span> = Vec::new();
-    header_spans.extend(short_id_span(task.short_id.as_deref()));
+    header_spans.extend(id_span(task.short_id.as_deref(), &task.id, app.show_full_ids));
     header_spans.push(Span::styled(
         &task.title,
         Style::default()
@@ -307,41 +539,81 @@ fn draw_task_detail(frame: &mut Frame, app: &App) {
         Line::from(""),
     ];
 
-    if task.session_id.is_some() {
+    if let Some(assignee) = &task.assignee {
+        lines.push(Line::from(vec![
+            Span::styled("Assignee: ", Style::default().fg(theme::TEXT_DIM)),
+            Span::styled(assignee, Style::default().fg(theme::TEXT_BRIGHT)),
+        ]));
+        lines.push(Line::from(""));
+    }
+
+    if let Some(updated_by) = &task.updated_by {
+        lines.push(Line::from(vec![
+            Span::styled("Updated by: ", Style::default().fg(theme::TEXT_DIM)),
+            Span::styled(updated_by, Style::default().fg(theme::TEXT_BRIGHT)),
+        ]));
+        lines.push(Line::from(""));
+    }
+
+    if let Some(session_id) = &task.session_id {
         lines.push(Line::from(vec![
             Span::styled(
-                format!("{} Session: ", theme::SESSION_SYMBOL),
+                format!("{} Session: ", theme::session_symbol(app.glyphs())),
                 theme::session_style(),
             ),
-            Span::styled("active", Style::default().fg(theme::TEXT_DIM)),
+            Span::styled(session_id, Style::default().fg(theme::TEXT_BRIGHT)),
+            Span::styled("  (y to copy)", Style::default().fg(theme::TEXT_DIM)),
         ]));
         lines.push(Line::from(""));
     }
 
     if !task.description.is_empty() {
+        lines.extend(markdown::markdown_to_lines(&task.description));
+        lines.push(Line::from(""));
+    }
+
+    if let Some(items) = app.task_checklists.get(&task.id) {
+        let (done, total) = checklist_progress(items);
         lines.push(Line::from(Span::styled(
-            &task.description,
+            format!("Checklist {done}/{total}"),
             Style::default().fg(theme::TEXT_DIM),
         )));
+        for item in items {
+            let (mark, style) = if item.done {
+                ("[x] ", theme::status_style(&ItemStatus::Done))
+            } else {
+                ("[ ] ", Style::default().fg(theme::TEXT_BRIGHT))
+            };
+            lines.push(Line::from(vec![
+                Span::styled(mark, style),
+                Span::styled(&item.text, Style::default().fg(theme::TEXT_BRIGHT)),
+            ]));
+        }
         lines.push(Line::from(""));
     }
 
-    if let Some(blocker_names) = app.task_blocker_names.get(&task.id) {
-        lines.push(Line::from(vec![
-            Span::styled(
-                format!("{} Blocked by: ", theme::BLOCKED_SYMBOL),
-                theme::blocked_style(),
-            ),
-            Span::styled(
-                blocker_names.join(", "),
-                Style::default().fg(theme::TEXT_DIM),
+    let blockers = app.detail_blockers();
+    if !blockers.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!(
+                "{} Blocked by (j/k, Enter to open):",
+                theme::blocked_symbol(app.glyphs())
             ),
-        ]));
+            theme::blocked_style(),
+        )));
+        for (i, blocker) in blockers.iter().enumerate() {
+            let (marker, marker_style, title_style) = selection_styles(i == app.blocker_idx);
+            lines.push(Line::from(vec![
+                Span::styled(marker, marker_style),
+                Span::styled(&blocker.title, title_style),
+            ]));
+        }
     }
 
     let paragraph = Paragraph::new(lines)
         .block(panel_block(" Task Detail ", true))
-        .wrap(ratatui::widgets::Wrap { trim: true });
+        // No trimming so list and code indentation survives wrapping.
+        .wrap(ratatui::widgets::Wrap { trim: false });
     frame.render_widget(paragraph, area);
 }
 
@@ -367,7 +639,151 @@ fn draw_project_selector(frame: &mut Frame, app: &App) {
         })
         .collect();
 
-    let list = List::new(list_items).block(panel_block(" Select Project ", true));
+    render_list(
+        frame,
+        area,
+        panel_block(" Select Project ", true),
+        list_items,
+        app.selector_idx,
+        &app.selector_list_offset,
+    );
+}
+
+fn draw_project_form(frame: &mut Frame, app: &App) {
+    let area = centered_rect(50, 30, frame.area());
+    frame.render_widget(Clear, area);
+
+    let form = &app.project_form;
+    let label_style = Style::default().fg(theme::TEXT_DIM);
+    let field = |label: &'static str, value: &str, active: bool| {
+        let (marker, marker_style, value_style) = selection_styles(active);
+        let mut spans = vec![
+            Span::styled(marker, marker_style),
+            Span::styled(label, label_style),
+            Span::styled(value.to_string(), value_style),
+        ];
+        if active {
+            spans.push(Span::styled("_", label_style));
+        }
+        Line::from(spans)
+    };
+
+    let mut lines = vec![
+        Line::from(""),
+        field("Name:        ", &form.name, !form.on_description),
+        field("Description: ", &form.description, form.on_description),
+    ];
+    if let Some(error) = &form.error {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(format!("   {error}"), theme::blocked_style())));
+    }
+    if form.confirm_discard.is_some() {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "   Discard what you typed? (y/n)",
+            theme::blocked_style(),
+        )));
+    }
+
+    let paragraph = Paragraph::new(lines)
+        .block(panel_block(" New Project ", true))
+        .wrap(ratatui::widgets::Wrap { trim: false });
+    frame.render_widget(paragraph, area);
+}
+
+fn draw_workspace_selector(frame: &mut Frame, app: &App) {
+    let area = centered_rect(50, 60, frame.area());
+    frame.render_widget(Clear, area);
+
+    let current = app.workspace.as_deref().unwrap_or(DEFAULT_WORKSPACE);
+    let list_items: Vec<ListItem> = app
+        .workspaces
+        .iter()
+        .enumerate()
+        .map(|(i, name)| {
+            let (marker, marker_style, title_style) = selection_styles(i == app.workspace_idx);
+            let mut spans = vec![
+                Span::styled(marker, marker_style),
+                Span::styled(name.as_str(), title_style),
+            ];
+            if name == current {
+                spans.push(Span::styled(" (open)", Style::default().fg(theme::TEXT_DIM)));
+            }
+            ListItem::new(Line::from(spans))
+        })
+        .collect();
+
+    let list = List::new(list_items).block(panel_block(" Select Workspace ", true));
+    frame.render_widget(list, area);
+}
+
+fn draw_command_palette(frame: &mut Frame, app: &App) {
+    let area = centered_rect(50, 60, frame.area());
+    frame.render_widget(Clear, area);
+    let block = panel_block(" Command Palette ", true);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(0)])
+        .split(inner);
+    let prompt = Line::from(vec![
+        Span::styled(" : ", Style::default().fg(theme::NEON_CYAN)),
+        Span::styled(app.palette_query.as_str(), Style::default().fg(theme::TEXT_BRIGHT)),
+        Span::styled("_", Style::default().fg(theme::TEXT_DIM)),
+    ]);
+    frame.render_widget(Paragraph::new(prompt), rows[0]);
+
+    let matches = app.palette_matches();
+    if matches.is_empty() {
+        let empty = Span::styled("   No matching actions", Style::default().fg(theme::TEXT_DIM));
+        frame.render_widget(Paragraph::new(Line::from(empty)), rows[1]);
+        return;
+    }
+    let list_items: Vec<ListItem> = matches
+        .iter()
+        .enumerate()
+        .map(|(i, action)| {
+            let (marker, marker_style, title_style) = selection_styles(i == app.palette_idx);
+            ListItem::new(Line::from(vec![
+                Span::styled(marker, marker_style),
+                Span::styled(action.name, title_style),
+                Span::styled(format!("  {}", action.key), Style::default().fg(theme::TEXT_DIM)),
+            ]))
+        })
+        .collect();
+    frame.render_widget(List::new(list_items), rows[1]);
+}
+
+fn draw_blocked_list(frame: &mut Frame, app: &App) {
+    let area = centered_rect(60, 60, frame.area());
+    frame.render_widget(Clear, area);
+
+    let blocker_style = Style::default().fg(theme::NEON_ORANGE);
+    let list_items: Vec<ListItem> = app
+        .blocked_items
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let (marker, marker_style, title_style) = selection_styles(i == app.blocked_idx);
+            let kind = format!("[{}] ", entry.item_type.as_str());
+            ListItem::new(vec![
+                Line::from(vec![
+                    Span::styled(marker, marker_style),
+                    Span::styled(kind, Style::default().fg(theme::TEXT_DIM)),
+                    Span::styled(entry.label.as_str(), title_style),
+                ]),
+                Line::from(Span::styled(
+                    format!("      blocked by {}", entry.blockers.join(", ")),
+                    blocker_style,
+                )),
+            ])
+        })
+        .collect();
+
+    let title = format!(" Blocked Items ({}) ", app.blocked_items.len());
+    let list = List::new(list_items).block(panel_block(&title, true));
     frame.render_widget(list, area);
 }
 
@@ -383,7 +799,7 @@ fn progress_line(label: &str, counts: &HashMap<String, i64>, area_width: u16) ->
         .saturating_sub(label_str.len() as u16 + count_text.len() as u16 + 2)
         as usize;
 
-    let bar = theme::progress_bar(done, total, bar_width.max(1));
+    let bar = theme::progress_bar(done, total, bar_width.max(1), theme::Glyphs::default());
     let filled: String = bar.chars().filter(|&c| c == '\u{2588}').collect();
     let remaining: String = bar.chars().filter(|&c| c == '\u{2591}').collect();
 
@@ -397,6 +813,11 @@ fn progress_line(label: &str, counts: &HashMap<String, i64>, area_width: u16) ->
 
 fn draw_status_panel(frame: &mut Frame, app: &App, area: Rect) {
     let focused = app.focused_panel == FocusedPanel::Status;
+    if app.projects.is_empty() {
+        let block = panel_block(" Project Status ", focused);
+        draw_empty_state(frame, area, block, "Progress shows here once you have a project", None);
+        return;
+    }
 
     let blocked_style = if app.blocked_count > 0 {
         Style::default().fg(theme::NEON_ORANGE)
@@ -512,11 +933,20 @@ fn draw_help_overlay(frame: &mut Frame) {
         Line::from(Span::styled(" Actions", section_style)),
         key_line("Enter", "Open task detail"),
         key_line("s", "Cycle task status (todo \u{2192} in_progress \u{2192} done)"),
-        key_line("p", "Open project selector"),
+        key_line("o", "Expand/collapse the selected task's description"),
+        key_line("J/K", "Move selected epic down/up"),
+        key_line("p", "Open project selector (n there creates a project)"),
+        key_line("n", "Create a project while there are none yet"),
+        key_line("w", "Switch workspace database"),
+        key_line("b", "List blocked items and jump to one"),
         key_line("d", "Toggle dependency graph view"),
         Line::from(""),
         Line::from(Span::styled(" General", section_style)),
         key_line("?", "Toggle this help overlay"),
+        key_line(":, Ctrl-p", "Open the command palette"),
+        key_line("a", "Toggle animations"),
+        key_line("v", "Switch between grid and list layout"),
+        key_line("i", "Show full IDs in place of short IDs"),
         key_line("q", "Quit / Close overlay"),
         key_line("Esc", "Close overlay/popup"),
     ];
@@ -624,20 +1054,24 @@ fn draw_graph_view(frame: &mut Frame, app: &App) {
 
     // Summary bar
     if let Some(cache) = &app.graph_cache {
-        let summary = Paragraph::new(graph_summary_line(cache))
+        let summary = Paragraph::new(graph_summary_line(cache, app.show_edges))
             .style(Style::default().bg(theme::BG));
         frame.render_widget(summary, chunks[2]);
     }
 
     // Footer
-    draw_graph_footer(frame, chunks[3]);
+    draw_graph_footer(frame, app, chunks[3]);
 }
 
-fn draw_graph_footer(frame: &mut Frame, area: Rect) {
-    let footer = Paragraph::new(Line::from(vec![Span::styled(
-        "  Esc: Back  1: Epics  2: Tasks  3: Dual  Tab: Pane  \u{2190}\u{2191}\u{2192}\u{2193}: Focus  hjkl: Scroll",
-        Style::default().fg(theme::TEXT_DIM),
-    )]))
+fn draw_graph_footer(frame: &mut Frame, app: &App, area: Rect) {
+    let line = match &app.status_message {
+        Some(message) => Line::from(Span::styled(
+            format!("  {message}"),
+            Style::default().fg(theme::NEON_GREEN),
+        )),
+        None => Line::from(Span::styled(GRAPH_HELP, Style::default().fg(theme::TEXT_DIM))),
+    };
+    let footer = Paragraph::new(line)
     .block(
         Block::default()
             .borders(Borders::ALL)
@@ -659,17 +1093,6 @@ struct GraphSummary {
     done_count: usize,
 }
 
-/// Returns `true` if the node has any incoming edge from a non-done node.
-fn has_incomplete_blocker(node_id: &str, layout: &crate::tui::graph::DagLayout) -> bool {
-    layout.edges.iter().any(|e| {
-        e.to == node_id
-            && layout
-                .nodes
-                .get(&e.from)
-                .is_some_and(|src| src.status != ItemStatus::Done)
-    })
-}
-
 /// Compute summary statistics from a graph cache.
 fn compute_graph_summary(cache: &GraphCache) -> GraphSummary {
     let nodes = &cache.layout.nodes;
@@ -682,7 +1105,7 @@ fn compute_graph_summary(cache: &GraphCache) -> GraphSummary {
     let blocked_count = nodes
         .values()
         .filter(|n| n.status != ItemStatus::Done)
-        .filter(|n| has_incomplete_blocker(&n.id, &cache.layout))
+        .filter(|n| cache.layout.has_incomplete_blocker(&n.id))
         .count();
 
     GraphSummary {
@@ -694,7 +1117,22 @@ fn compute_graph_summary(cache: &GraphCache) -> GraphSummary {
 }
 
 /// Build the summary bar spans for the graph view footer.
-fn graph_summary_line(cache: &GraphCache) -> Line<'static> {
+/// Key to the edge colors, or a note that edges are hidden (`e`).
+fn edge_legend(show_edges: bool) -> Vec<Span<'static>> {
+    let sep = Style::default().fg(theme::TEXT_DIM);
+    if !show_edges {
+        return vec![Span::styled(" │ edges hidden (e)", sep)];
+    }
+    vec![
+        Span::styled(" │ ", sep),
+        Span::styled("── ", Style::default().fg(theme::NEON_CYAN)),
+        Span::styled("dependency  ", sep),
+        Span::styled("── ", Style::default().fg(theme::NEON_PINK)),
+        Span::styled("blocks a blocked item", sep),
+    ]
+}
+
+fn graph_summary_line(cache: &GraphCache, show_edges: bool) -> Line<'static> {
     let summary = compute_graph_summary(cache);
     let label = match cache.level {
         GraphLevel::Epic => "epics",
@@ -711,7 +1149,7 @@ fn graph_summary_line(cache: &GraphCache) -> Line<'static> {
     };
     let blocked = Style::default().fg(blocked_fg);
 
-    Line::from(vec![
+    let mut spans = vec![
         Span::styled("  ◉ ", cyan),
         Span::styled(format!("{} {}", summary.total_nodes, label), cyan),
         Span::styled(" │ ", sep),
@@ -720,7 +1158,9 @@ fn graph_summary_line(cache: &GraphCache) -> Line<'static> {
         Span::styled(format!("⚠ {} blocked", summary.blocked_count), blocked),
         Span::styled(" │ ", sep),
         Span::styled(format!("■ {} done", summary.done_count), green),
-    ])
+    ];
+    spans.extend(edge_legend(show_edges));
+    Line::from(spans)
 }
 
 fn draw_graph_canvas(frame: &mut Frame, app: &App, area: Rect) {
@@ -759,65 +1199,90 @@ fn draw_graph_pane(frame: &mut Frame, app: &App, area: Rect, params: &GraphPaneP
             GraphLevel::Task => (&app.blocked_task_ids, NODE_HEIGHT_TASK),
         };
 
+        let progress_of = |node_id: &str| match cache.level {
+            GraphLevel::Epic => app.epic_progress(node_id),
+            GraphLevel::Task => None,
+        };
+
         // Compute per-node heights based on title length and whether it has a progress bar.
         let mut per_node_heights: HashMap<String, usize> = HashMap::new();
-        for (node_id, _) in &cache.node_positions {
+        for node_id in cache.node_positions.keys() {
             if let Some(node) = cache.layout.nodes.get(node_id) {
-                let has_progress = cache.level == GraphLevel::Epic;
-                let h = node_height(&node.label, has_progress);
+                let has_progress = has_progress_bar(progress_of(node_id));
+                let h = node_height(&node.label, has_progress, cache.node_width);
                 per_node_heights.insert(node_id.clone(), h);
             }
         }
 
+        let sizes = NodeSizes {
+            width: cache.node_width,
+            heights: &per_node_heights,
+            default_height,
+        };
+
         // Compute the full canvas extent from node positions.
-        let (full_width, full_height) = graph_canvas_extent(cache, &per_node_heights, default_height);
+        let (full_width, full_height) = graph_canvas_extent(cache, &sizes);
 
         // Use the larger of the full extent or the viewport so nodes always render.
         let canvas_w = full_width.max(viewport_width);
         let canvas_h = full_height.max(viewport_height);
         let mut canvas = Canvas::new(canvas_w, canvas_h);
 
+        // The chain highlight only applies to the pane whose focused node it
+        // was computed from.
+        let highlight = app
+            .highlight_set
+            .as_ref()
+            .filter(|set| params.focused_node_id.is_some_and(|fid| set.contains(fid)));
+
         // Render nodes
+        let counts = if app.show_edge_counts {
+            edge_counts(&cache.layout)
+        } else {
+            HashMap::new()
+        };
         for (node_id, &(x, y)) in &cache.node_positions {
             if let Some(node) = cache.layout.nodes.get(node_id) {
-                let progress = match cache.level {
-                    GraphLevel::Epic => {
-                        app.epics
-                            .iter()
-                            .find(|e| e.id == *node_id)
-                            .map(|e| (e.done_count as usize, e.task_count as usize))
-                    }
-                    GraphLevel::Task => None,
-                };
-
                 let node_box = NodeBox {
                     title: node.label.clone(),
                     status: node.status.clone(),
-                    progress,
+                    progress: progress_of(node_id),
                     x,
                     y,
+                    width: cache.node_width,
                     blocked: blocked_ids.contains(node_id),
+                    dimmed: highlight.is_some_and(|set| !set.contains(node_id)),
+                    glyphs: app.glyphs(),
+                    edge_counts: app
+                        .show_edge_counts
+                        .then(|| counts.get(node_id).copied().unwrap_or_default()),
+                    external_blocker: cache.external_blockers.contains(node_id),
+                    dim_completed: app.dim_completed,
                 };
                 render_node(&mut canvas, &node_box, app.animation_frame);
             }
         }
 
         // Render edges
-        render_edges(
-            &mut canvas,
-            &cache.layout,
-            &cache.node_positions,
-            blocked_ids,
-            &per_node_heights,
-            default_height,
-        );
+        if app.show_edges {
+            render_edges(
+                &mut canvas,
+                &cache.layout,
+                &cache.node_positions,
+                blocked_ids,
+                &sizes,
+                highlight,
+                app.dim_completed,
+                app.ascii_glyphs,
+            );
+        }
 
         // Render focus highlight on the selected node
         if let Some(fid) = params.focused_node_id
             && let Some(&(fx, fy)) = cache.node_positions.get(fid)
         {
-            let fh = per_node_heights.get(fid).copied().unwrap_or(default_height);
-            render_focus_highlight(&mut canvas, fx, fy, fh);
+            let height = sizes.height(fid);
+            render_focus_highlight(&mut canvas, fx, fy, sizes.width, height, app.ascii_glyphs);
         }
 
         // Clamp scroll offsets to valid bounds.
@@ -846,6 +1311,22 @@ fn draw_graph_pane(frame: &mut Frame, app: &App, area: Rect, params: &GraphPaneP
         frame.render_widget(paragraph, area);
 
         render_scroll_indicators(frame, area, sx, sy, max_scroll_x, max_scroll_y);
+
+        if app.show_minimap && (max_scroll_x > 0 || max_scroll_y > 0) {
+            let node_rects: Vec<(usize, usize, usize, usize)> = cache
+                .node_positions
+                .iter()
+                .map(|(id, &(x, y))| (x, y, sizes.width, sizes.height(id)))
+                .collect();
+            draw_minimap(
+                frame,
+                area,
+                &node_rects,
+                (canvas_w, canvas_h),
+                (viewport_width, viewport_height),
+                (sx, sy),
+            );
+        }
     } else {
         let msg = match params.level {
             GraphLevel::Task if app.selected_epic().is_none() => "Select an epic first",
@@ -859,20 +1340,13 @@ fn draw_graph_pane(frame: &mut Frame, app: &App, area: Rect, params: &GraphPaneP
 }
 
 /// Compute the minimum canvas size needed to contain all nodes (with padding).
-fn graph_canvas_extent(
-    cache: &GraphCache,
-    per_node_heights: &HashMap<String, usize>,
-    default_height: usize,
-) -> (usize, usize) {
-    use crate::tui::graph_render::NODE_WIDTH;
-
+fn graph_canvas_extent(cache: &GraphCache, sizes: &NodeSizes) -> (usize, usize) {
     let mut max_x: usize = 0;
     let mut max_y: usize = 0;
 
     for (node_id, &(x, y)) in &cache.node_positions {
-        let h = per_node_heights.get(node_id).copied().unwrap_or(default_height);
-        max_x = max_x.max(x + NODE_WIDTH);
-        max_y = max_y.max(y + h);
+        max_x = max_x.max(x + sizes.width);
+        max_y = max_y.max(y + sizes.height(node_id));
     }
 
     // Add small padding for edge routing below the lowest nodes.
@@ -927,6 +1401,159 @@ fn render_scroll_indicators(
     }
 }
 
+/// Largest minimap size, borders included.
+const MINIMAP_MAX_WIDTH: u16 = 26;
+const MINIMAP_MAX_HEIGHT: u16 = 10;
+/// Minimap glyphs for increasing node density.
+const MINIMAP_SHADES: [char; 4] = [' ', '\u{2591}', '\u{2592}', '\u{2593}'];
+
+/// A rectangle of minimap cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct MiniRect {
+    x: usize,
+    y: usize,
+    width: usize,
+    height: usize,
+}
+
+/// Scale the canvas span `[start, start + len)` of an axis `extent` cells
+/// long onto `cells` minimap cells. The result is at least one cell and
+/// stays inside the grid.
+fn scale_span(start: usize, len: usize, extent: usize, cells: usize) -> (usize, usize) {
+    if extent == 0 || cells == 0 {
+        return (0, cells);
+    }
+    let first = (start * cells / extent).min(cells - 1);
+    let end = ((start + len) * cells).div_ceil(extent).clamp(first + 1, cells);
+    (first, end - first)
+}
+
+/// Where the viewport sits on a `grid`-sized minimap of a canvas `extent`
+/// cells large, scrolled to `scroll`.
+fn minimap_viewport(
+    extent: (usize, usize),
+    viewport: (usize, usize),
+    scroll: (usize, usize),
+    grid: (usize, usize),
+) -> MiniRect {
+    let (x, width) = scale_span(scroll.0, viewport.0, extent.0, grid.0);
+    let (y, height) = scale_span(scroll.1, viewport.1, extent.1, grid.1);
+    MiniRect { x, y, width, height }
+}
+
+/// Shade index into [`MINIMAP_SHADES`] for each minimap cell, row by row,
+/// from the share of its canvas cells covered by the `(x, y, width, height)`
+/// node rectangles.
+fn minimap_density(
+    node_rects: &[(usize, usize, usize, usize)],
+    extent: (usize, usize),
+    grid: (usize, usize),
+) -> Vec<Vec<usize>> {
+    let (gw, gh) = grid;
+    let mut covered = vec![vec![0usize; gw]; gh];
+    if extent.0 == 0 || extent.1 == 0 {
+        return covered;
+    }
+    for &(x, y, width, height) in node_rects {
+        for cy in y..(y + height).min(extent.1) {
+            for cx in x..(x + width).min(extent.0) {
+                covered[cy * gh / extent.1][cx * gw / extent.0] += 1;
+            }
+        }
+    }
+
+    let cell_area = (extent.0 * extent.1).div_ceil(gw * gh).max(1);
+    let top = MINIMAP_SHADES.len() - 1;
+    covered
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .map(|n| if n == 0 { 0 } else { (n * top).div_ceil(cell_area).clamp(1, top) })
+                .collect()
+        })
+        .collect()
+}
+
+/// Box-drawing glyph for minimap cell `(col, row)` if it lies on the
+/// outline of `rect`.
+fn minimap_outline(col: usize, row: usize, rect: MiniRect) -> Option<char> {
+    let (right, bottom) = (rect.x + rect.width - 1, rect.y + rect.height - 1);
+    if col < rect.x || col > right || row < rect.y || row > bottom {
+        return None;
+    }
+    let (left_edge, right_edge) = (col == rect.x, col == right);
+    let (top_edge, bottom_edge) = (row == rect.y, row == bottom);
+    let ch = match (rect.width, rect.height) {
+        (1, 1) => '\u{25A1}',
+        (1, _) => '\u{2502}',
+        (_, 1) => '\u{2500}',
+        _ => match (top_edge, bottom_edge, left_edge, right_edge) {
+            (true, _, true, _) => '\u{250C}',
+            (true, _, _, true) => '\u{2510}',
+            (_, true, true, _) => '\u{2514}',
+            (_, true, _, true) => '\u{2518}',
+            (true, _, _, _) | (_, true, _, _) => '\u{2500}',
+            (_, _, true, _) | (_, _, _, true) => '\u{2502}',
+            _ => return None,
+        },
+    };
+    Some(ch)
+}
+
+/// Draw a miniature of the whole graph canvas in the bottom-right corner of
+/// `area`, with the visible part outlined.
+fn draw_minimap(
+    frame: &mut Frame,
+    area: Rect,
+    node_rects: &[(usize, usize, usize, usize)],
+    extent: (usize, usize),
+    viewport: (usize, usize),
+    scroll: (usize, usize),
+) {
+    let width = MINIMAP_MAX_WIDTH.min(area.width / 3);
+    let height = MINIMAP_MAX_HEIGHT.min(area.height / 3);
+    if width < 6 || height < 4 {
+        return;
+    }
+    // Keep clear of the right-hand scroll indicator column and the bottom row.
+    let rect = Rect::new(
+        area.right().saturating_sub(width + 1),
+        area.bottom().saturating_sub(height + 1),
+        width,
+        height,
+    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(theme::BORDER_DIM))
+        .title(Span::styled(" map ", Style::default().fg(theme::TEXT_DIM)))
+        .style(Style::default().bg(theme::BG));
+    let inner = block.inner(rect);
+    let grid = (inner.width as usize, inner.height as usize);
+
+    let density = minimap_density(node_rects, extent, grid);
+    let view = minimap_viewport(extent, viewport, scroll, grid);
+    let shade_style = Style::default().fg(theme::NEON_CYAN);
+    let outline_style = Style::default().fg(theme::NEON_MAGENTA);
+    let lines: Vec<Line> = density
+        .iter()
+        .enumerate()
+        .map(|(row, shades)| {
+            let spans: Vec<Span> = shades
+                .iter()
+                .enumerate()
+                .map(|(col, &shade)| match minimap_outline(col, row, view) {
+                    Some(ch) => Span::styled(ch.to_string(), outline_style),
+                    None => Span::styled(MINIMAP_SHADES[shade].to_string(), shade_style),
+                })
+                .collect();
+            Line::from(spans)
+        })
+        .collect();
+
+    frame.render_widget(Clear, rect);
+    frame.render_widget(Paragraph::new(lines).block(block), rect);
+}
+
 fn draw_dual_pane_graph(frame: &mut Frame, app: &App) {
     // Fill the entire background
     let bg_block = Block::default().style(Style::default().bg(theme::BG));
@@ -987,7 +1614,7 @@ fn draw_dual_pane_graph(frame: &mut Frame, app: &App) {
     draw_dual_summary(frame, app, chunks[2]);
 
     // Footer
-    draw_graph_footer(frame, chunks[3]);
+    draw_graph_footer(frame, app, chunks[3]);
 }
 
 fn draw_dual_header(frame: &mut Frame, area: Rect) {
@@ -1039,7 +1666,7 @@ fn draw_dual_summary(frame: &mut Frame, app: &App, area: Rect) {
         GraphPane::Right => "Active: Tasks",
     };
 
-    let line = Line::from(vec![
+    let mut line = Line::from(vec![
         Span::styled("  ", sep),
         cache_stats_span("Epics", app.epic_graph_cache.as_ref()),
         Span::styled(" \u{2502} ", sep),
@@ -1047,6 +1674,7 @@ fn draw_dual_summary(frame: &mut Frame, app: &App, area: Rect) {
         Span::styled(" \u{2502} ", sep),
         Span::styled(pane_label, Style::default().fg(theme::NEON_MAGENTA)),
     ]);
+    line.spans.extend(edge_legend(app.show_edges));
 
     let summary = Paragraph::new(line).style(Style::default().bg(theme::BG));
     frame.render_widget(summary, area);
@@ -1055,7 +1683,7 @@ fn draw_dual_summary(frame: &mut Frame, app: &App, area: Rect) {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::tui::graph::{DagLayout, Edge, Node};
+    use crate::graph::{DagLayout, Edge, Node};
 
     fn test_node(id: &str, status: ItemStatus) -> Node {
         Node {
@@ -1079,6 +1707,9 @@ mod tests {
             layout: DagLayout::new(nodes, edges),
             node_positions: HashMap::new(),
             level,
+            viewport_width: 0,
+            node_width: crate::graph::NODE_WIDTH,
+            external_blockers: std::collections::HashSet::new(),
         }
     }
 
@@ -1186,7 +1817,7 @@ mod tests {
             vec![],
             GraphLevel::Epic,
         );
-        let line = graph_summary_line(&cache);
+        let line = graph_summary_line(&cache, true);
         let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
         assert!(text.contains("epics"), "expected 'epics' in: {text}");
     }
@@ -1198,7 +1829,7 @@ mod tests {
             vec![],
             GraphLevel::Task,
         );
-        let line = graph_summary_line(&cache);
+        let line = graph_summary_line(&cache, true);
         let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
         assert!(text.contains("tasks"), "expected 'tasks' in: {text}");
     }
@@ -1213,7 +1844,7 @@ mod tests {
             vec![test_edge("A", "B")],
             GraphLevel::Task,
         );
-        let line = graph_summary_line(&cache);
+        let line = graph_summary_line(&cache, true);
         let blocked_span = line.spans.iter().find(|s| s.content.contains("blocked")).unwrap();
         assert_eq!(blocked_span.style.fg, Some(theme::NEON_ORANGE));
     }
@@ -1225,11 +1856,70 @@ mod tests {
             vec![],
             GraphLevel::Epic,
         );
-        let line = graph_summary_line(&cache);
+        let line = graph_summary_line(&cache, true);
         let blocked_span = line.spans.iter().find(|s| s.content.contains("blocked")).unwrap();
         assert_eq!(blocked_span.style.fg, Some(theme::TEXT_DIM));
     }
 
+    #[test]
+    fn summary_legend_explains_edge_colors_or_notes_hidden_edges() {
+        let cache = test_cache(vec![test_node("A", ItemStatus::Todo)], vec![], GraphLevel::Epic);
+        let line = graph_summary_line(&cache, true);
+        let pink = line.spans.iter().position(|s| s.style.fg == Some(theme::NEON_PINK)).unwrap();
+        assert_eq!(line.spans[pink + 1].content, "blocks a blocked item");
+
+        let line = graph_summary_line(&cache, false);
+        let text: String = line.spans.iter().map(|s| s.content.as_ref()).collect();
+        assert!(text.ends_with("edges hidden (e)"), "{text}");
+        assert!(line.spans.iter().all(|s| s.style.fg != Some(theme::NEON_PINK)));
+    }
+
+    #[test]
+    fn hidden_edges_leave_only_the_nodes() {
+        use crate::db::seed::seed_demo_data;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let db = crate::db::Database::open(&dir.path().join("test.db")).unwrap();
+        db.migrate().unwrap();
+        seed_demo_data(&db).unwrap();
+        let mut app = App::new(db).unwrap();
+        app.build_epic_graph();
+        app.mode = InputMode::GraphView;
+        let nodes_drawn = |screen: &str| {
+            app.epics.iter().filter(|e| screen.contains(&e.title[..6])).count()
+        };
+
+        let with_edges = render_screen(&app);
+        assert!(with_edges.contains('\u{25BC}'), "arrowheads are drawn by default");
+        let visible = nodes_drawn(&with_edges);
+        assert!(visible > 0);
+
+        app.show_edges = false;
+        let without_edges = render_screen(&app);
+        assert!(!without_edges.contains('\u{25BC}'), "{without_edges}");
+        assert_eq!(nodes_drawn(&without_edges), visible);
+    }
+
+    #[test]
+    fn edge_count_badges_are_off_until_toggled() {
+        use crate::db::seed::seed_demo_data;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let db = crate::db::Database::open(&dir.path().join("test.db")).unwrap();
+        db.migrate().unwrap();
+        seed_demo_data(&db).unwrap();
+        let mut app = App::new(db).unwrap();
+        app.build_epic_graph();
+        app.mode = InputMode::GraphView;
+        // Root nodes have no incoming edges.
+        let root_badge = " \u{2191}0 \u{2193}";
+        assert!(!render_screen(&app).contains(root_badge));
+
+        app.show_edge_counts = true;
+        let screen = render_screen(&app);
+        assert!(screen.contains(root_badge), "{screen}");
+    }
+
     #[test]
     fn summary_done_color_green() {
         let cache = test_cache(
@@ -1237,7 +1927,7 @@ mod tests {
             vec![],
             GraphLevel::Epic,
         );
-        let line = graph_summary_line(&cache);
+        let line = graph_summary_line(&cache, true);
         let done_span = line.spans.iter().find(|s| s.content.contains("done")).unwrap();
         assert_eq!(done_span.style.fg, Some(theme::NEON_GREEN));
     }
@@ -1255,7 +1945,7 @@ mod tests {
             GraphPane::Right => "Active: Tasks",
         };
 
-        let spans = vec![
+        let spans = [
             Span::styled("  ", sep),
             cache_stats_span("Epics", epic_cache),
             Span::styled(" \u{2502} ", sep),
@@ -1303,35 +1993,35 @@ mod tests {
 
     fn epic_row_spans(epic: &Epic, is_selected: bool) -> Vec<Span<'_>> {
         let (marker, marker_style, title_style) = selection_styles(is_selected);
-        let symbol = theme::status_symbol(&epic.status);
+        let symbol = theme::status_symbol(&epic.status, theme::Glyphs::default());
         let status_style = theme::status_style(&epic.status);
 
         let mut spans = vec![
             Span::styled(marker, marker_style),
             Span::styled(format!("{symbol} "), status_style),
         ];
-        spans.extend(short_id_span(epic.short_id.as_deref()));
+        spans.extend(id_span(epic.short_id.as_deref(), &epic.id, false));
         spans.push(Span::styled(&epic.title, title_style));
         spans
     }
 
     fn task_row_spans(task: &BlueTask, is_selected: bool) -> Vec<Span<'_>> {
         let (marker, marker_style, title_style) = selection_styles(is_selected);
-        let symbol = theme::status_symbol(&task.status);
+        let symbol = theme::status_symbol(&task.status, theme::Glyphs::default());
         let status_style = theme::status_style(&task.status);
 
         let mut spans = vec![
             Span::styled(marker, marker_style),
             Span::styled(format!("{symbol} "), status_style),
         ];
-        spans.extend(short_id_span(task.short_id.as_deref()));
+        spans.extend(id_span(task.short_id.as_deref(), &task.id, false));
         spans.push(Span::styled(&task.title, title_style));
         spans
     }
 
     fn task_detail_header_spans(task: &BlueTask) -> Vec<Span<'_>> {
         let mut spans: Vec<Span> = Vec::new();
-        spans.extend(short_id_span(task.short_id.as_deref()));
+        spans.extend(id_span(task.short_id.as_deref(), &task.id, false));
         spans.push(Span::styled(
             &task.title,
             Style::default()
@@ -1349,8 +2039,12 @@ mod tests {
             description: String::new(),
             status: ItemStatus::Todo,
             short_id: short_id.map(String::from),
+            prd_id: None,
+            position: 0,
+            archived: false,
             created_at: String::new(),
             updated_at: String::new(),
+            updated_by: None,
             task_count: 0,
             done_count: 0,
         }
@@ -1366,7 +2060,10 @@ mod tests {
             short_id: short_id.map(String::from),
             created_at: String::new(),
             updated_at: String::new(),
+            updated_by: None,
             session_id: None,
+            assignee: None,
+            archived: false,
         }
     }
 
@@ -1411,6 +2108,20 @@ mod tests {
         );
     }
 
+    #[test]
+    fn id_span_shows_the_full_id_when_toggled() {
+        let id = "01J8Z6Q4R5S6T7V8W9X0Y1Z2A3";
+        let short = id_span(Some("E1"), id, false).unwrap();
+        assert_eq!(short.content, "[E1] ");
+        let full = id_span(Some("E1"), id, true).unwrap();
+        assert_eq!(full.content, format!("[{id}] "));
+        assert_eq!(full.style.fg, Some(theme::TEXT_DIM));
+
+        // Items without a short ID still show their full ID when toggled.
+        assert!(id_span(None, id, false).is_none());
+        assert!(id_span(None, id, true).is_some());
+    }
+
     #[test]
     fn short_id_span_uses_text_dim_color() {
         let epic = stub_epic(Some("E1"));
@@ -1458,4 +2169,344 @@ mod tests {
         assert_eq!(spans.len(), 1, "only the title span when short_id is None");
         assert_eq!(spans[0].content.as_ref(), "Test Task");
     }
+
+    fn mini(x: usize, y: usize, width: usize, height: usize) -> MiniRect {
+        MiniRect { x, y, width, height }
+    }
+
+    #[test]
+    fn minimap_viewport_tracks_scroll() {
+        // A 200x100 canvas on a 20x10 minimap: 10 canvas cells per cell.
+        let extent = (200, 100);
+        let viewport = (50, 30);
+        let grid = (20, 10);
+
+        assert_eq!(minimap_viewport(extent, viewport, (0, 0), grid), mini(0, 0, 5, 3));
+        assert_eq!(minimap_viewport(extent, viewport, (100, 40), grid), mini(10, 4, 5, 3));
+        // Scrolled to the far corner, the outline ends on the last cell.
+        assert_eq!(minimap_viewport(extent, viewport, (150, 70), grid), mini(15, 7, 5, 3));
+        // Offsets between cells round the outline outwards.
+        assert_eq!(minimap_viewport(extent, viewport, (5, 5), grid), mini(0, 0, 6, 4));
+    }
+
+    #[test]
+    fn minimap_viewport_never_collapses_or_overflows() {
+        // Tiny viewport on a huge canvas still gets one cell.
+        let rect = minimap_viewport((10_000, 10_000), (10, 10), (9_990, 0), (20, 8));
+        assert_eq!(rect, mini(19, 0, 1, 1));
+
+        // An axis that fits entirely spans the whole minimap.
+        let rect = minimap_viewport((300, 40), (100, 40), (200, 0), (20, 8));
+        assert_eq!(rect, mini(13, 0, 7, 8));
+    }
+
+    #[test]
+    fn minimap_density_shades_covered_cells() {
+        // 40x20 canvas on a 4x2 grid: each cell covers 10x10 canvas cells.
+        let rects = [(0, 0, 10, 10), (20, 0, 5, 4), (30, 10, 10, 10)];
+        let density = minimap_density(&rects, (40, 20), (4, 2));
+        assert_eq!(density, vec![vec![3, 0, 1, 0], vec![0, 0, 0, 3]]);
+    }
+
+    #[test]
+    fn minimap_outline_draws_box_around_viewport() {
+        let rect = mini(1, 1, 3, 3);
+        assert_eq!(minimap_outline(1, 1, rect), Some('\u{250C}'));
+        assert_eq!(minimap_outline(3, 3, rect), Some('\u{2518}'));
+        assert_eq!(minimap_outline(2, 1, rect), Some('\u{2500}'));
+        assert_eq!(minimap_outline(1, 2, rect), Some('\u{2502}'));
+        assert_eq!(minimap_outline(2, 2, rect), None);
+        assert_eq!(minimap_outline(0, 0, rect), None);
+        assert_eq!(minimap_outline(5, 5, mini(5, 5, 1, 1)), Some('\u{25A1}'));
+    }
+
+    fn panel_kinds(mode: LayoutMode) -> Vec<FocusedPanel> {
+        body_panels(mode, Rect::new(0, 0, 100, 40))
+            .into_iter()
+            .map(|(panel, _)| panel)
+            .collect()
+    }
+
+    #[test]
+    fn body_panels_per_layout() {
+        assert_eq!(
+            panel_kinds(LayoutMode::Grid),
+            [
+                FocusedPanel::Epics,
+                FocusedPanel::Tasks,
+                FocusedPanel::Dependencies,
+                FocusedPanel::Status,
+            ]
+        );
+        assert_eq!(panel_kinds(LayoutMode::List), [FocusedPanel::Epics, FocusedPanel::Tasks]);
+
+        // The list layout gives both panels the full width, tasks the larger share.
+        let list = body_panels(LayoutMode::List, Rect::new(0, 0, 100, 40));
+        assert!(list.iter().all(|(_, area)| area.width == 100));
+        assert!(list[1].1.height > list[0].1.height);
+        assert_eq!(list[0].1.y + list[0].1.height, list[1].1.y);
+    }
+
+    fn render_screen(app: &App) -> String {
+        let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(100, 40)).unwrap();
+        terminal.draw(|frame| draw(frame, app)).unwrap();
+        terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect()
+    }
+
+    #[test]
+    fn draw_renders_the_panels_of_each_layout() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let db = crate::db::Database::open(&dir.path().join("test.db")).unwrap();
+        db.migrate().unwrap();
+        let mut app = App::new(db).unwrap();
+
+        let grid = render_screen(&app);
+        for title in [" Epics ", " Tasks ", " Dependencies (mini) ", " Project Status "] {
+            assert!(grid.contains(title), "grid layout should draw {title}");
+        }
+
+        app.layout_mode = LayoutMode::List;
+        let list = render_screen(&app);
+        assert!(list.contains(" Epics ") && list.contains(" Tasks "));
+        assert!(!list.contains(" Dependencies (mini) "));
+        assert!(!list.contains(" Project Status "));
+    }
+
+    #[test]
+    fn empty_panels_explain_what_is_missing() {
+        use crate::db::epic::create_epic;
+        use crate::db::project::create_project;
+        use crate::models::{CreateEpicInput, CreateProjectInput};
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let db = crate::db::Database::open(&dir.path().join("test.db")).unwrap();
+        db.migrate().unwrap();
+        let mut app = App::new(db).unwrap();
+        let screen = render_screen(&app);
+        assert!(screen.contains("Create your first project (n)"), "{screen}");
+        assert!(screen.contains("Tasks show here once you have a project"));
+        assert!(screen.contains("Progress shows here once you have a project"));
+
+        let project = create_project(
+            &app.db,
+            CreateProjectInput { name: "P".to_string(), description: String::new() },
+        )
+        .unwrap();
+        app.refresh_data();
+        let screen = render_screen(&app);
+        assert!(screen.contains("No epics in this project"), "{screen}");
+        assert!(screen.contains("Tasks show here once the project has epics"));
+        assert!(!screen.contains("Create your first project"));
+        assert!(screen.contains("Blocked: 0 items"));
+
+        create_epic(
+            &app.db,
+            CreateEpicInput {
+                project_id: project.id,
+                title: "Search".to_string(),
+                description: String::new(),
+                prd_id: None,
+            },
+            None,
+        )
+        .unwrap();
+        app.refresh_data();
+        let screen = render_screen(&app);
+        assert!(screen.contains("Search"));
+        assert!(screen.contains("No tasks in this epic"), "{screen}");
+    }
+
+    #[test]
+    fn task_detail_shows_the_checklist_with_its_count() {
+        use crate::db::checklist::{add_checklist_item, toggle_checklist_item};
+        use crate::db::seed::seed_demo_data;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let db = crate::db::Database::open(&dir.path().join("test.db")).unwrap();
+        db.migrate().unwrap();
+        seed_demo_data(&db).unwrap();
+        let mut app = App::new(db).unwrap();
+        let task = app.selected_task().unwrap().clone();
+        let first = add_checklist_item(&app.db, &task.id, "Pick a schema", None).unwrap();
+        add_checklist_item(&app.db, &task.id, "Write the migration", None).unwrap();
+        toggle_checklist_item(&app.db, &first.id, None, None).unwrap();
+
+        app.refresh_tasks();
+        app.mode = InputMode::TaskDetail;
+        let screen = render_screen(&app);
+        assert!(screen.contains("Checklist 1/2"), "{screen}");
+        assert!(screen.contains("[x] Pick a schema"));
+        assert!(screen.contains("[ ] Write the migration"));
+    }
+
+    #[test]
+    fn task_detail_shows_who_last_updated_the_task() {
+        use crate::db::seed::seed_demo_data;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let db = crate::db::Database::open(&dir.path().join("test.db")).unwrap();
+        db.migrate().unwrap();
+        seed_demo_data(&db).unwrap();
+        let mut app = App::new(db).unwrap();
+        app.mode = InputMode::TaskDetail;
+        assert!(!render_screen(&app).contains("Updated by:"));
+
+        let idx = app.selected_task_idx;
+        app.tasks[idx].updated_by = Some("planner-agent".to_string());
+        let screen = render_screen(&app);
+        assert!(screen.contains("Updated by: planner-agent"), "{screen}");
+    }
+
+    #[test]
+    fn task_detail_shows_the_full_session_id() {
+        use crate::db::seed::seed_demo_data;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let db = crate::db::Database::open(&dir.path().join("test.db")).unwrap();
+        db.migrate().unwrap();
+        seed_demo_data(&db).unwrap();
+        let mut app = App::new(db).unwrap();
+        let idx = app.selected_task_idx;
+        app.tasks[idx].session_id = Some("session-7c1e9b".to_string());
+        app.mode = InputMode::TaskDetail;
+        let screen = render_screen(&app);
+        assert!(screen.contains("Session: session-7c1e9b  (y to copy)"), "{screen}");
+    }
+
+    #[test]
+    fn task_list_shows_full_ids_when_toggled() {
+        use crate::db::seed::seed_demo_data;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let db = crate::db::Database::open(&dir.path().join("test.db")).unwrap();
+        db.migrate().unwrap();
+        seed_demo_data(&db).unwrap();
+        let mut app = App::new(db).unwrap();
+        let task = app.selected_task().unwrap().clone();
+        let short = format!("[{}]", task.short_id.as_deref().unwrap());
+        let full = format!("[{}]", task.id);
+
+        let screen = render_screen(&app);
+        assert!(screen.contains(&short) && !screen.contains(&full), "{screen}");
+
+        app.show_full_ids = true;
+        let screen = render_screen(&app);
+        assert!(screen.contains(&full), "{screen}");
+    }
+
+    #[test]
+    fn wrap_words_breaks_at_spaces_and_splits_long_words() {
+        assert_eq!(wrap_words("one two three four", 9), ["one two", "three", "four"]);
+        assert_eq!(wrap_words("abcdefgh ij", 3), ["abc", "def", "gh", "ij"]);
+        assert_eq!(wrap_words("", 10), [""]);
+    }
+
+    #[test]
+    fn expanded_task_shows_its_description_under_the_row() {
+        use crate::db::seed::seed_demo_data;
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let db = crate::db::Database::open(&dir.path().join("test.db")).unwrap();
+        db.migrate().unwrap();
+        seed_demo_data(&db).unwrap();
+        let mut app = App::new(db).unwrap();
+        app.selected_epic_idx = app.epics.iter().position(|e| e.task_count >= 2).unwrap();
+        app.refresh_tasks();
+        app.tasks[0].description = "Alpha notes about the first task".to_string();
+        app.tasks[1].description = "Beta notes about the second task".to_string();
+
+        assert!(!render_screen(&app).contains("Alpha notes"));
+
+        app.expanded_task = Some(app.tasks[0].id.clone());
+        let screen = render_screen(&app);
+        assert!(screen.contains("Alpha notes about the first task"), "{screen}");
+        assert!(!screen.contains("Beta notes"));
+
+        // Moving the selection away hides it.
+        app.selected_task_idx = 1;
+        assert!(!render_screen(&app).contains("Alpha notes"));
+
+        let lines = expanded_description(&"word ".repeat(200), 20);
+        assert_eq!(lines.len(), MAX_EXPANDED_LINES + 1);
+    }
+
+    #[test]
+    fn scrollbar_only_when_items_overflow() {
+        assert!(!needs_scrollbar(0, 5));
+        assert!(!needs_scrollbar(5, 5));
+        assert!(needs_scrollbar(6, 5));
+        assert!(needs_scrollbar(1, 0));
+    }
+
+    #[test]
+    fn epic_list_state_follows_selection_and_shows_a_scrollbar() {
+        use crate::db::epic::create_epic;
+        use crate::db::project::create_project;
+        use crate::models::{CreateEpicInput, CreateProjectInput};
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let db = crate::db::Database::open(&dir.path().join("test.db")).unwrap();
+        db.migrate().unwrap();
+        let project = create_project(
+            &db,
+            CreateProjectInput {
+                name: "Long".to_string(),
+                description: String::new(),
+            },
+        )
+        .unwrap();
+        for i in 0..3 {
+            create_epic(
+                &db,
+                CreateEpicInput {
+                    project_id: project.id.clone(),
+                    title: format!("Epic {i:02}"),
+                    description: String::new(),
+                    prd_id: None,
+                },
+                None,
+            )
+            .unwrap();
+        }
+        let mut app = App::new(db).unwrap();
+        let short = render_screen(&app);
+        assert!(!short.contains('\u{2551}'), "no scrollbar track while the epics fit");
+
+        for i in 3..40 {
+            create_epic(
+                &app.db,
+                CreateEpicInput {
+                    project_id: project.id.clone(),
+                    title: format!("Epic {i:02}"),
+                    description: String::new(),
+                    prd_id: None,
+                },
+                None,
+            )
+            .unwrap();
+        }
+        app.refresh_data();
+        let screen = render_screen(&app);
+        assert_eq!(app.epic_list_offset.get(), 0);
+        assert!(screen.contains('\u{2551}'), "scrollbar track once the epics overflow");
+
+        app.selected_epic_idx = app.epics.len() - 1;
+        let last = app.epics[app.selected_epic_idx].title.clone();
+        let screen = render_screen(&app);
+        let offset = app.epic_list_offset.get();
+        assert!(offset > 0, "the list scrolled to the selection");
+        assert!(screen.contains(&last), "{screen}");
+        assert!(!screen.contains(&app.epics[offset - 1].title));
+
+        // Moving back up within the viewport keeps the offset.
+        app.selected_epic_idx -= 1;
+        render_screen(&app);
+        assert_eq!(app.epic_list_offset.get(), offset);
+    }
 }